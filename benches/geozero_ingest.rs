@@ -0,0 +1,83 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use geoarrow2::array::MutablePolygonArray;
+use geozero::{GeomProcessor, GeozeroGeometry};
+
+/// Counts calls to `realloc`, to compare how often the builders grow their buffers.
+struct CountingAllocator;
+
+static REALLOCS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        REALLOCS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// A stream of square polygons, like a reader that may not know the number of features up front.
+struct Squares {
+    count: usize,
+    hinted: bool,
+}
+
+impl GeozeroGeometry for Squares {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()> {
+        let size_hint = if self.hinted { self.count } else { 0 };
+        processor.geometrycollection_begin(size_hint, 0)?;
+        for idx in 0..self.count {
+            let (x, y) = (idx as f64, idx as f64);
+            processor.polygon_begin(true, 1, idx)?;
+            processor.linestring_begin(false, 5, 0)?;
+            processor.xy(x, y, 0)?;
+            processor.xy(x + 1., y, 1)?;
+            processor.xy(x + 1., y + 1., 2)?;
+            processor.xy(x, y + 1., 3)?;
+            processor.xy(x, y, 4)?;
+            processor.linestring_end(false, 0)?;
+            processor.polygon_end(true, idx)?;
+        }
+        processor.geometrycollection_end(0)
+    }
+}
+
+fn ingest(source: &Squares) -> MutablePolygonArray<i32> {
+    let mut array = MutablePolygonArray::new();
+    source.process_geom(&mut array).unwrap();
+    array
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    for hinted in [true, false] {
+        let source = Squares {
+            count: 100_000,
+            hinted,
+        };
+        let name = if hinted { "hinted" } else { "hint-less" };
+
+        let before = REALLOCS.load(Ordering::Relaxed);
+        ingest(&source);
+        let reallocs = REALLOCS.load(Ordering::Relaxed) - before;
+        println!("geozero ingest ({name}): {reallocs} reallocations");
+
+        c.bench_function(&format!("geozero ingest polygons ({name})"), |b| {
+            b.iter(|| ingest(&source))
+        });
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
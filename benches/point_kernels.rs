@@ -0,0 +1,82 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use geo::{BoundingRect as _, EuclideanDistance as _, Scale as _, Translate as _};
+use geoarrow2::algorithm::broadcasting::BroadcastablePrimitive;
+use geoarrow2::algorithm::geo::{BoundingRect, EuclideanDistance, Scale, Translate};
+use geoarrow2::array::{PointArray, RectArray};
+use geoarrow2::trait_::GeoArrayAccessor;
+use geoarrow2::GeometryArrayTrait;
+
+fn points() -> PointArray {
+    let points: Vec<geo::Point> = (0..100_000)
+        .map(|i| geo::point!(x: i as f64, y: (i % 360) as f64))
+        .collect();
+    points.into()
+}
+
+/// The generic path, through one geo object per row.
+fn via_geo(array: &PointArray) -> impl Iterator<Item = Option<geo::Point>> + '_ {
+    (0..array.len()).map(|i| array.get_as_geo(i))
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let array = points();
+    let other = points();
+
+    c.bench_function("translate PointArray", |b| {
+        b.iter(|| {
+            array.translate(
+                BroadcastablePrimitive::Scalar(1.),
+                BroadcastablePrimitive::Scalar(2.),
+            )
+        })
+    });
+    c.bench_function("translate PointArray via geo", |b| {
+        b.iter(|| {
+            let _out: PointArray = via_geo(&array)
+                .map(|g| g.map(|g| g.translate(1., 2.)))
+                .collect::<Vec<_>>()
+                .into();
+        })
+    });
+
+    c.bench_function("scale PointArray", |b| {
+        b.iter(|| array.scale(BroadcastablePrimitive::Scalar(2.)))
+    });
+    c.bench_function("scale PointArray via geo", |b| {
+        b.iter(|| {
+            let _out: PointArray = via_geo(&array)
+                .map(|g| g.map(|g| g.scale(2.)))
+                .collect::<Vec<_>>()
+                .into();
+        })
+    });
+
+    c.bench_function("bounding_rect PointArray", |b| {
+        b.iter(|| {
+            let _out: RectArray = array.bounding_rect();
+        })
+    });
+    c.bench_function("bounding_rect PointArray via geo", |b| {
+        b.iter(|| {
+            let _out: RectArray = via_geo(&array)
+                .map(|g| g.map(|g| g.bounding_rect()))
+                .collect::<Vec<_>>()
+                .into();
+        })
+    });
+
+    c.bench_function("euclidean_distance PointArray", |b| {
+        b.iter(|| array.euclidean_distance(&other))
+    });
+    c.bench_function("euclidean_distance PointArray via geo", |b| {
+        b.iter(|| {
+            let _out: Vec<Option<f64>> = via_geo(&array)
+                .zip(via_geo(&other))
+                .map(|(a, b)| Some(a?.euclidean_distance(&b?)))
+                .collect();
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,42 @@
+use arrow_array::StringArray;
+use criterion::{criterion_group, criterion_main, Criterion};
+use geoarrow2::error::ErrorPolicy;
+use geoarrow2::io::wkt::from_wkt_parallel;
+
+fn make_input(num_rows: usize) -> StringArray {
+    (0..num_rows)
+        .map(|i| {
+            let x = (i % 360) as f64 - 180.;
+            let y = (i % 180) as f64 - 90.;
+            Some(format!(
+                "POLYGON (({x} {y}, {} {y}, {} {}, {x} {}, {x} {y}))",
+                x + 0.5,
+                x + 0.5,
+                y + 0.5,
+                y + 0.5
+            ))
+        })
+        .collect()
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let input = make_input(100_000);
+
+    c.bench_function("parse 100k WKT polygons in parallel", |b| {
+        b.iter(|| {
+            let _values = from_wkt_parallel(&input, None, ErrorPolicy::Strict).unwrap();
+        })
+    });
+    c.bench_function(
+        "parse 100k WKT polygons in parallel, reporting errors",
+        |b| {
+            b.iter(|| {
+                let _values =
+                    from_wkt_parallel(&input, None, ErrorPolicy::NullifyAndReport).unwrap();
+            })
+        },
+    );
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
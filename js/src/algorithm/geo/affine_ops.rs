@@ -1,6 +1,6 @@
 use crate::array::*;
 use crate::broadcasting::BroadcastableAffine;
-use geoarrow::algorithm::broadcasting::BroadcastableVec;
+use geoarrow::prelude::BroadcastableVec;
 use wasm_bindgen::prelude::*;
 
 macro_rules! impl_rotate {
@@ -11,7 +11,7 @@ macro_rules! impl_rotate {
             /// geometries.
             #[wasm_bindgen(js_name = affineTransform)]
             pub fn affine_transform(&self, transform: BroadcastableAffine) -> Self {
-                use geoarrow::algorithm::geo::AffineOps;
+                use geoarrow::prelude::AffineOps;
                 match transform.0 {
                     BroadcastableVec::Array(arr) => {
                         AffineOps::affine_transform(&self.0, &arr).into()
@@ -8,14 +8,14 @@ macro_rules! impl_area {
             /// Unsigned planar area of a geometry.
             #[wasm_bindgen]
             pub fn area(&self) -> FloatArray {
-                use geoarrow::algorithm::geo::Area;
+                use geoarrow::prelude::Area;
                 FloatArray(Area::unsigned_area(&self.0))
             }
 
             /// Signed planar area of a geometry.
             #[wasm_bindgen(js_name = signedArea)]
             pub fn signed_area(&self) -> FloatArray {
-                use geoarrow::algorithm::geo::Area;
+                use geoarrow::prelude::Area;
                 FloatArray(Area::signed_area(&self.0))
             }
         }
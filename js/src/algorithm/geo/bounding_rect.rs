@@ -5,11 +5,11 @@ macro_rules! impl_bounding_rect {
     ($struct_name:ident) => {
         #[wasm_bindgen]
         impl $struct_name {
-            /// Return the bounding rectangle of a geometry
+            /// Return the bounding rectangle of each geometry, as a polygon
             #[wasm_bindgen(js_name = boundingRect)]
             pub fn bounding_rect(&self) -> PolygonArray {
-                use geoarrow::algorithm::geo::BoundingRect;
-                PolygonArray(BoundingRect::bounding_rect(&self.0))
+                use geoarrow::prelude::BoundingRect;
+                PolygonArray(BoundingRect::bounding_rect(&self.0).to_polygon_array())
             }
         }
     };
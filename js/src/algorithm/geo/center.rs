@@ -11,7 +11,7 @@ macro_rules! impl_center {
             /// that box
             #[wasm_bindgen]
             pub fn center(&self) -> PointArray {
-                use geoarrow::algorithm::geo::Center;
+                use geoarrow::prelude::Center;
                 PointArray(Center::center(&self.0))
             }
         }
@@ -15,7 +15,7 @@ macro_rules! impl_centroid {
             /// A non-convex object might have a centroid that _is outside the object itself_.
             #[wasm_bindgen]
             pub fn centroid(&self) -> PointArray {
-                use geoarrow::algorithm::geo::Centroid;
+                use geoarrow::prelude::Centroid;
                 PointArray(Centroid::centroid(&self.0))
             }
         }
@@ -18,7 +18,7 @@ macro_rules! impl_chaikin_smoothing {
             /// smoothes the corner between start and end of a closed linestring.
             #[wasm_bindgen(js_name = chaikinSmoothing)]
             pub fn chaikin_smoothing(&self, n_iterations: BroadcastableUint32) -> Self {
-                use geoarrow::algorithm::geo::ChaikinSmoothing;
+                use geoarrow::prelude::ChaikinSmoothing;
                 ChaikinSmoothing::chaikin_smoothing(&self.0, n_iterations.0).into()
             }
         }
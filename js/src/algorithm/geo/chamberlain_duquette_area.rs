@@ -8,7 +8,7 @@ macro_rules! impl_alg {
             /// Calculate the unsigned approximate geodesic area of a `Geometry`.
             #[wasm_bindgen(js_name = chamberlainDuquetteUnsignedArea)]
             pub fn chamberlain_duquette_unsigned_area(&self) -> FloatArray {
-                use geoarrow::algorithm::geo::ChamberlainDuquetteArea;
+                use geoarrow::prelude::ChamberlainDuquetteArea;
                 FloatArray(ChamberlainDuquetteArea::chamberlain_duquette_unsigned_area(
                     &self.0,
                 ))
@@ -17,7 +17,7 @@ macro_rules! impl_alg {
             /// Calculate the signed approximate geodesic area of a `Geometry`.
             #[wasm_bindgen(js_name = chamberlainDuquetteSignedArea)]
             pub fn chamberlain_duquette_signed_area(&self) -> FloatArray {
-                use geoarrow::algorithm::geo::ChamberlainDuquetteArea;
+                use geoarrow::prelude::ChamberlainDuquetteArea;
                 FloatArray(ChamberlainDuquetteArea::chamberlain_duquette_signed_area(
                     &self.0,
                 ))
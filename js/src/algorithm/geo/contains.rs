@@ -15,7 +15,7 @@ macro_rules! impl_contains {
             /// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
             #[wasm_bindgen]
             pub fn contains(&self, other: &$second) -> BooleanArray {
-                use geoarrow::algorithm::geo::Contains;
+                use geoarrow::prelude::Contains;
                 BooleanArray(Contains::contains(&self.0, &other.0))
             }
         }
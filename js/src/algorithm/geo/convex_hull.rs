@@ -14,7 +14,7 @@ macro_rules! impl_alg {
             /// <http://www.cs.princeton.edu/~dpd/Papers/BarberDobkinHuhdanpaa.pdf>
             #[wasm_bindgen(js_name = convexHull)]
             pub fn convex_hull(&self) -> PolygonArray {
-                use geoarrow::algorithm::geo::ConvexHull;
+                use geoarrow::prelude::ConvexHull;
                 PolygonArray(ConvexHull::convex_hull(&self.0))
             }
         }
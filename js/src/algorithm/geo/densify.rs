@@ -12,7 +12,7 @@ macro_rules! impl_densify {
             /// Note: `max_distance` must be greater than 0.
             #[wasm_bindgen(js_name = densify)]
             pub fn densify(&self, max_distance: BroadcastableFloat) -> Self {
-                use geoarrow::algorithm::geo::Densify;
+                use geoarrow::prelude::Densify;
                 Densify::densify(&self.0, max_distance.0).into()
             }
         }
@@ -12,7 +12,7 @@ macro_rules! impl_alg {
             /// be considered empty.
             #[wasm_bindgen(js_name = isEmpty)]
             pub fn is_empty(&self) -> BooleanArray {
-                use geoarrow::algorithm::geo::HasDimensions;
+                use geoarrow::prelude::HasDimensions;
                 BooleanArray(HasDimensions::is_empty(&self.0))
             }
         }
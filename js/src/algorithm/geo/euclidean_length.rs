@@ -8,7 +8,7 @@ macro_rules! impl_euclidean_length {
             /// Calculation of the length of a Line
             #[wasm_bindgen(js_name = euclideanLength)]
             pub fn euclidean_length(&self) -> FloatArray {
-                use geoarrow::algorithm::geo::EuclideanLength;
+                use geoarrow::prelude::EuclideanLength;
                 FloatArray(EuclideanLength::euclidean_length(&self.0))
             }
         }
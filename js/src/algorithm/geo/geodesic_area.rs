@@ -30,7 +30,7 @@ macro_rules! impl_geodesic_area {
             /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
             #[wasm_bindgen(js_name = geodesicAreaSigned)]
             pub fn geodesic_area_signed(&self) -> FloatArray {
-                use geoarrow::algorithm::geo::GeodesicArea;
+                use geoarrow::prelude::GeodesicArea;
                 FloatArray(GeodesicArea::geodesic_area_signed(&self.0))
             }
 
@@ -51,7 +51,7 @@ macro_rules! impl_geodesic_area {
             /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
             #[wasm_bindgen(js_name = geodesicAreaUnsigned)]
             pub fn geodesic_area_unsigned(&self) -> FloatArray {
-                use geoarrow::algorithm::geo::GeodesicArea;
+                use geoarrow::prelude::GeodesicArea;
                 FloatArray(GeodesicArea::geodesic_area_unsigned(&self.0))
             }
 
@@ -69,7 +69,7 @@ macro_rules! impl_geodesic_area {
             /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
             #[wasm_bindgen(js_name = geodesicPerimeter)]
             pub fn geodesic_perimeter(&self) -> FloatArray {
-                use geoarrow::algorithm::geo::GeodesicArea;
+                use geoarrow::prelude::GeodesicArea;
                 FloatArray(GeodesicArea::geodesic_perimeter(&self.0))
             }
 
@@ -104,7 +104,7 @@ macro_rules! impl_geodesic_area {
             // /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
             // #[wasm_bindgen]
             // pub fn geodesic_perimeter_area_signed(&self) -> (FloatArray, FloatArray) {
-            //     use geoarrow::algorithm::geo::GeodesicArea;
+            //     use geoarrow::prelude::GeodesicArea;
             //     let (perimeter, area) = GeodesicArea::geodesic_perimeter_area_signed(&self.0);
             //     (FloatArray(perimeter), FloatArray(area))
             // }
@@ -14,7 +14,7 @@ macro_rules! impl_geodesic_length {
             /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
             #[wasm_bindgen(js_name = geodesicLength)]
             pub fn geodesic_length(&self) -> FloatArray {
-                use geoarrow::algorithm::geo::GeodesicLength;
+                use geoarrow::prelude::GeodesicLength;
                 FloatArray(GeodesicLength::geodesic_length(&self.0))
             }
         }
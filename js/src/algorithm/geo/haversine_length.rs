@@ -13,7 +13,7 @@ macro_rules! impl_haversine_length {
             /// [recommendation of the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
             #[wasm_bindgen(js_name = haversineLength)]
             pub fn haversine_length(&self) -> FloatArray {
-                use geoarrow::algorithm::geo::HaversineLength;
+                use geoarrow::prelude::HaversineLength;
                 FloatArray(HaversineLength::haversine_length(&self.0))
             }
         }
@@ -1,7 +1,7 @@
 use crate::array::*;
 use crate::broadcasting::BroadcastableFloat;
 use crate::scalar::Point;
-use geoarrow::algorithm::broadcasting::BroadcastablePrimitive;
+use geoarrow::prelude::BroadcastablePrimitive;
 use wasm_bindgen::prelude::*;
 
 macro_rules! impl_rotate {
@@ -13,7 +13,7 @@ macro_rules! impl_rotate {
             /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
             #[wasm_bindgen(js_name = rotateAroundCentroid)]
             pub fn rotate_around_centroid(&self, degrees: BroadcastableFloat) -> Self {
-                use geoarrow::algorithm::geo::Rotate;
+                use geoarrow::prelude::Rotate;
                 match degrees.0 {
                     BroadcastablePrimitive::Array(arr) => {
                         Rotate::rotate_around_centroid(&self.0, &arr).into()
@@ -29,7 +29,7 @@ macro_rules! impl_rotate {
             /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
             #[wasm_bindgen(js_name = rotateAroundCenter)]
             pub fn rotate_around_center(&self, degrees: BroadcastableFloat) -> Self {
-                use geoarrow::algorithm::geo::Rotate;
+                use geoarrow::prelude::Rotate;
                 match degrees.0 {
                     BroadcastablePrimitive::Array(arr) => {
                         Rotate::rotate_around_center(&self.0, &arr).into()
@@ -45,7 +45,7 @@ macro_rules! impl_rotate {
             /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
             #[wasm_bindgen(js_name = rotateAroundPoint)]
             pub fn rotate_around_point(&self, degrees: BroadcastableFloat, point: Point) -> Self {
-                use geoarrow::algorithm::geo::Rotate;
+                use geoarrow::prelude::Rotate;
                 match degrees.0 {
                     BroadcastablePrimitive::Array(arr) => {
                         Rotate::rotate_around_point(&self.0, &arr, point.0.into()).into()
@@ -9,7 +9,7 @@ macro_rules! impl_scale {
             /// Scale a geometry from it's bounding box center.
             #[wasm_bindgen]
             pub fn scale(&self, scale_factor: BroadcastableFloat) -> Self {
-                use geoarrow::algorithm::geo::Scale;
+                use geoarrow::prelude::Scale;
                 Scale::scale(&self.0, scale_factor.0).into()
             }
 
@@ -22,7 +22,7 @@ macro_rules! impl_scale {
                 x_factor: BroadcastableFloat,
                 y_factor: BroadcastableFloat,
             ) -> Self {
-                use geoarrow::algorithm::geo::Scale;
+                use geoarrow::prelude::Scale;
                 Scale::scale_xy(&self.0, x_factor.0, y_factor.0).into()
             }
 
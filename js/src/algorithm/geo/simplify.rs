@@ -20,7 +20,7 @@ macro_rules! impl_simplify {
             /// geometry.
             #[wasm_bindgen]
             pub fn simplify(&self, epsilon: f64) -> Self {
-                use geoarrow::algorithm::geo::Simplify;
+                use geoarrow::prelude::Simplify;
                 Simplify::simplify(&self.0, &epsilon).into()
             }
         }
@@ -20,9 +20,18 @@ macro_rules! impl_simplify_vw {
             /// geometry.
             #[wasm_bindgen(js_name = simplifyVw)]
             pub fn simplify_vw(&self, epsilon: f64) -> Self {
-                use geoarrow::algorithm::geo::SimplifyVw;
+                use geoarrow::prelude::SimplifyVw;
                 SimplifyVw::simplify_vw(&self.0, &epsilon).into()
             }
+
+            /// Returns the simplified representation of a geometry, using a topology-preserving
+            /// variant of the Visvalingam-Whyatt algorithm, which doesn't remove a point if that
+            /// would make the geometry intersect itself.
+            #[wasm_bindgen(js_name = simplifyVwPreserve)]
+            pub fn simplify_vw_preserve(&self, epsilon: f64) -> Self {
+                use geoarrow::prelude::SimplifyVw;
+                SimplifyVw::simplify_vw_preserve(&self.0, &epsilon).into()
+            }
         }
     };
 }
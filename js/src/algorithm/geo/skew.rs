@@ -11,7 +11,7 @@ macro_rules! impl_skew {
             /// the x and y dimensions.
             #[wasm_bindgen]
             pub fn skew(&self, degrees: BroadcastableFloat) -> Self {
-                use geoarrow::algorithm::geo::Skew;
+                use geoarrow::prelude::Skew;
                 Skew::skew(&self.0, degrees.0).into()
             }
 
@@ -24,7 +24,7 @@ macro_rules! impl_skew {
                 degrees_x: BroadcastableFloat,
                 degrees_y: BroadcastableFloat,
             ) -> Self {
-                use geoarrow::algorithm::geo::Skew;
+                use geoarrow::prelude::Skew;
                 Skew::skew_xy(&self.0, degrees_x.0, degrees_y.0).into()
             }
 
@@ -41,7 +41,7 @@ macro_rules! impl_skew {
                 degrees_y: BroadcastableFloat,
                 origin: Point,
             ) -> Self {
-                use geoarrow::algorithm::geo::Skew;
+                use geoarrow::prelude::Skew;
                 Skew::skew_around_point(&self.0, degrees_x.0, degrees_y.0, origin.0.into()).into()
             }
         }
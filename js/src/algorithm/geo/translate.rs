@@ -13,7 +13,7 @@ macro_rules! impl_translate {
                 x_offset: BroadcastableFloat,
                 y_offset: BroadcastableFloat,
             ) -> Self {
-                use geoarrow::algorithm::geo::Translate;
+                use geoarrow::prelude::Translate;
                 Translate::translate(&self.0, x_offset.0, y_offset.0).into()
             }
         }
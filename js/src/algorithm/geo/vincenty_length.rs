@@ -11,7 +11,7 @@ macro_rules! impl_vincenty_length {
             /// [Vincenty’s formulae]: https://en.wikipedia.org/wiki/Vincenty%27s_formulae
             #[wasm_bindgen(js_name = vincentyLength)]
             pub fn vincenty_length(&self) -> WasmResult<FloatArray> {
-                use geoarrow::algorithm::geo::VincentyLength;
+                use geoarrow::prelude::VincentyLength;
                 Ok(FloatArray(VincentyLength::vincenty_length(&self.0)?))
             }
         }
@@ -0,0 +1,71 @@
+use crate::array::*;
+use crate::error::WasmResult;
+use wasm_bindgen::prelude::*;
+
+/// How `classify` splits values into classes.
+#[wasm_bindgen]
+pub enum ClassificationScheme {
+    /// Classes of equal width between the smallest and largest value.
+    EqualInterval,
+
+    /// Classes with about the same number of values each.
+    Quantile,
+
+    /// Jenks natural breaks, which minimize the squared deviations from the class means. This
+    /// takes time quadratic in the number of values.
+    NaturalBreaks,
+}
+
+impl From<ClassificationScheme> for geoarrow::algorithm::native::ClassificationScheme {
+    fn from(value: ClassificationScheme) -> Self {
+        use geoarrow::algorithm::native::ClassificationScheme as _ClassificationScheme;
+        match value {
+            ClassificationScheme::EqualInterval => _ClassificationScheme::EqualInterval,
+            ClassificationScheme::Quantile => _ClassificationScheme::Quantile,
+            ClassificationScheme::NaturalBreaks => _ClassificationScheme::NaturalBreaks,
+        }
+    }
+}
+
+/// The result of `classify`.
+#[wasm_bindgen]
+pub struct Classification {
+    class_ids: Vec<u8>,
+    breaks: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl Classification {
+    /// The class of each value, numbered from 0 for the class of the smallest values.
+    ///
+    /// Null and `NaN` values have class 255, which is never a valid class.
+    #[wasm_bindgen(getter, js_name = classIds)]
+    pub fn class_ids(&self) -> Vec<u8> {
+        self.class_ids.clone()
+    }
+
+    /// The largest value of each class, in increasing order.
+    #[wasm_bindgen(getter)]
+    pub fn breaks(&self) -> Vec<JsValue> {
+        self.breaks.iter().map(|b| JsValue::from(*b)).collect()
+    }
+}
+
+#[wasm_bindgen]
+impl FloatArray {
+    /// Split these values into at most `k` classes, e.g. to color the geometries of a choropleth
+    /// map.
+    ///
+    /// There may be fewer than `k` classes, when there are fewer distinct values or classes would
+    /// be empty. Equal values always share a class.
+    ///
+    /// Throws if `k` is zero or larger than 255.
+    pub fn classify(&self, scheme: ClassificationScheme, k: usize) -> WasmResult<Classification> {
+        let (class_ids, breaks) = geoarrow::algorithm::native::classify(&self.0, scheme.into(), k)?;
+        let class_ids = class_ids
+            .iter()
+            .map(|class_id| class_id.unwrap_or(u8::MAX))
+            .collect();
+        Ok(Classification { class_ids, breaks })
+    }
+}
@@ -1,5 +1,5 @@
 use crate::array::*;
-use geoarrow::GeometryArrayTrait;
+use geoarrow::prelude::GeometryArrayTrait;
 use wasm_bindgen::prelude::*;
 
 /// GeoArrow permits coordinate types to either be `Interleaved`, where the X and Y coordinates are
@@ -55,6 +55,19 @@ macro_rules! impl_coord_type {
             pub fn into_coord_type(self, coord_type: CoordType) -> Self {
                 self.0.into_coord_type(coord_type.into()).into()
             }
+
+            /// Round the coordinates of this geometry array to 32-bit floats, which WebGL vertex
+            /// buffers take directly. This loses precision.
+            #[wasm_bindgen(js_name = intoCoordFloat32)]
+            pub fn into_coord_float32(self) -> Self {
+                self.0.into_coord_float32().into()
+            }
+
+            /// Widen 32-bit coordinates of this geometry array back to 64-bit floats.
+            #[wasm_bindgen(js_name = intoCoordFloat64)]
+            pub fn into_coord_float64(self) -> Self {
+                self.0.into_coord_float64().into()
+            }
         }
     };
 }
@@ -1 +1,4 @@
+pub mod classify;
 pub mod coord_format;
+pub mod offset_curve;
+pub mod web_mercator;
@@ -0,0 +1,42 @@
+use crate::array::*;
+use crate::broadcasting::BroadcastableFloat;
+use wasm_bindgen::prelude::*;
+
+/// How the offset segments on the outside of a corner are connected.
+#[wasm_bindgen]
+pub enum JoinStyle {
+    /// Extend the offset segments until they meet, falling back to a bevel for sharp corners.
+    Miter,
+
+    /// Connect the ends of the offset segments with a straight segment.
+    Bevel,
+}
+
+#[wasm_bindgen]
+impl LineStringArray {
+    /// Offset each line by `distance`, to the left of its direction when positive and to the
+    /// right when negative.
+    ///
+    /// With the `Miter` join style, corners whose miter would extend more than `miterLimit`
+    /// times the distance from the corner are beveled instead.
+    ///
+    /// Self-intersections in the result, such as loops from offsetting a tight bend on its
+    /// inside, are not removed.
+    ///
+    /// Null lines and lines with fewer than two distinct points give null.
+    #[wasm_bindgen(js_name = offsetCurve)]
+    pub fn offset_curve(
+        &self,
+        distance: BroadcastableFloat,
+        join_style: JoinStyle,
+        miter_limit: f64,
+    ) -> LineStringArray {
+        use geoarrow::algorithm::native::JoinStyle as _JoinStyle;
+        use geoarrow::prelude::OffsetCurve;
+        let join_style = match join_style {
+            JoinStyle::Miter => _JoinStyle::Miter { limit: miter_limit },
+            JoinStyle::Bevel => _JoinStyle::Bevel,
+        };
+        OffsetCurve::offset_curve(&self.0, distance.0, join_style).into()
+    }
+}
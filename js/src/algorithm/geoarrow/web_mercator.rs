@@ -0,0 +1,41 @@
+use crate::array::*;
+use crate::error::WasmResult;
+use wasm_bindgen::prelude::*;
+
+macro_rules! impl_web_mercator {
+    ($struct_name:ident) => {
+        #[wasm_bindgen]
+        impl $struct_name {
+            /// Project longitude/latitude coordinates to Web Mercator (EPSG:3857), as map
+            /// renderers expect.
+            ///
+            /// Latitudes beyond ±85.0511° are clamped. If `nullOutOfRange` is true, geometries
+            /// with latitudes beyond ±90° or non-finite coordinates become null; otherwise they
+            /// throw an error.
+            #[wasm_bindgen(js_name = toWebMercator)]
+            pub fn to_web_mercator(&self, null_out_of_range: bool) -> WasmResult<$struct_name> {
+                use geoarrow::algorithm::native::OutOfRangePolicy;
+                use geoarrow::prelude::WebMercator;
+                let out_of_range = match null_out_of_range {
+                    true => OutOfRangePolicy::Null,
+                    false => OutOfRangePolicy::Error,
+                };
+                Ok(WebMercator::to_web_mercator(&self.0, out_of_range)?.into())
+            }
+
+            /// Convert Web Mercator (EPSG:3857) coordinates back to longitude and latitude.
+            #[wasm_bindgen(js_name = fromWebMercator)]
+            pub fn from_web_mercator(&self) -> $struct_name {
+                use geoarrow::prelude::WebMercator;
+                WebMercator::from_web_mercator(&self.0).into()
+            }
+        }
+    };
+}
+
+impl_web_mercator!(PointArray);
+impl_web_mercator!(LineStringArray);
+impl_web_mercator!(PolygonArray);
+impl_web_mercator!(MultiPointArray);
+impl_web_mercator!(MultiLineStringArray);
+impl_web_mercator!(MultiPolygonArray);
@@ -17,6 +17,8 @@ pub enum GeometryType {
     MultiPoint = 4,
     MultiLineString = 5,
     MultiPolygon = 6,
+    GeometryCollection = 7,
+    Rect = 8,
 }
 
 /// A GeometryArray that can be any of various underlying geometry types
@@ -66,7 +68,10 @@ impl GeometryArray {
             geoarrow::array::GeometryArray::MultiPoint(_) => GeometryType::MultiPoint,
             geoarrow::array::GeometryArray::MultiLineString(_) => GeometryType::MultiLineString,
             geoarrow::array::GeometryArray::MultiPolygon(_) => GeometryType::MultiPolygon,
-            geoarrow::array::GeometryArray::Rect(_) => unimplemented!(),
+            geoarrow::array::GeometryArray::GeometryCollection(_) => {
+                GeometryType::GeometryCollection
+            }
+            geoarrow::array::GeometryArray::Rect(_) => GeometryType::Rect,
         }
     }
 }
@@ -12,12 +12,9 @@ macro_rules! impl_geometry_array {
                 definition: &str,
                 direction: ReprojectDirection,
             ) -> WasmResult<GeometryArray> {
-                use geoarrow::algorithm::geodesy::reproject;
-                Ok(GeometryArray(reproject(
-                    &self.into(),
-                    definition,
-                    direction.into(),
-                )?))
+                use geoarrow::algorithm::geodesy::Reproject;
+                let arr: geoarrow::array::GeometryArray<i32> = self.into();
+                Ok(GeometryArray(arr.reproject(definition, direction.into())?))
             }
         }
     };
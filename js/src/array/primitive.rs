@@ -1,17 +1,105 @@
+use std::sync::Arc;
+
+use arrow_array::{Array, ArrayRef, RecordBatch};
+use arrow_ipc::writer::StreamWriter;
 use wasm_bindgen::prelude::*;
 
+use crate::error::WasmResult;
+
+/// Methods shared by the arrays of kernel results
+macro_rules! impl_primitive_array {
+    ($struct_name:ident) => {
+        #[wasm_bindgen]
+        impl $struct_name {
+            /// The number of values in this array, including nulls.
+            #[wasm_bindgen(getter)]
+            pub fn length(&self) -> usize {
+                self.0.len()
+            }
+
+            /// The validity bitmap of this array, or `undefined` if it has no nulls.
+            ///
+            /// Bits are packed least significant bit first, with a set bit for each valid value,
+            /// as in Arrow JS' `Data.nullBitmap`.
+            #[wasm_bindgen(js_name = nullBitmap)]
+            pub fn null_bitmap(&self) -> Option<Vec<u8>> {
+                self.0
+                    .nulls()
+                    .map(|nulls| nulls.inner().sliced().as_slice().to_vec())
+            }
+
+            /// The values of this array as a JavaScript array, with `null` for null values.
+            #[wasm_bindgen(js_name = toArray)]
+            pub fn to_array(&self) -> Vec<JsValue> {
+                (0..self.0.len())
+                    .map(|i| self.get(i).map_or(JsValue::NULL, JsValue::from))
+                    .collect()
+            }
+
+            /// Write this array to an Arrow IPC stream, as a table with a single column named
+            /// `values`.
+            ///
+            /// Pass the output to `tableFromIPC` in the Arrow JS bindings to add the column to an
+            /// existing table:
+            ///
+            /// ```js
+            /// import { tableFromIPC } from "apache-arrow";
+            ///
+            /// const area = tableFromIPC(polygonArray.area().toArrowIPC()).getChild("values");
+            /// ```
+            #[wasm_bindgen(js_name = toArrowIPC)]
+            pub fn to_arrow_ipc(&self) -> WasmResult<Vec<u8>> {
+                let column: ArrayRef = Arc::new(self.0.clone());
+                let batch =
+                    RecordBatch::try_from_iter_with_nullable(vec![("values", column, true)])?;
+
+                let mut writer = StreamWriter::try_new(Vec::new(), &batch.schema())?;
+                writer.write(&batch)?;
+                Ok(writer.into_inner()?)
+            }
+        }
+    };
+}
+
+/// An array of booleans, as returned by predicates such as `contains`.
 #[wasm_bindgen]
 pub struct BooleanArray(pub(crate) arrow_array::BooleanArray);
 
 #[wasm_bindgen]
 impl BooleanArray {
-    // TODO:
-    // #[wasm_bindgen]
-    // pub fn new() {
-    //     arrow2::array::BooleanArray::f
-    // }
+    #[wasm_bindgen(constructor)]
+    pub fn new(values: Vec<u8>) -> Self {
+        Self(
+            values
+                .into_iter()
+                .map(|v| v != 0)
+                .collect::<Vec<_>>()
+                .into(),
+        )
+    }
+
+    /// The value at index `i`, or `undefined` if it is null or out of bounds.
+    pub fn get(&self, i: usize) -> Option<bool> {
+        if i >= self.0.len() || self.0.is_null(i) {
+            return None;
+        }
+        Some(self.0.value(i))
+    }
+
+    /// The values of this array as a `Uint8Array` of zeros and ones.
+    ///
+    /// Null values are zero, so check [`nullBitmap`](Self::null_bitmap) to tell them apart.
+    #[wasm_bindgen(js_name = toTypedArray)]
+    pub fn to_typed_array(&self) -> Vec<u8> {
+        (0..self.0.len())
+            .map(|i| u8::from(self.get(i).unwrap_or(false)))
+            .collect()
+    }
 }
 
+impl_primitive_array!(BooleanArray);
+
+/// An array of floats, as returned by measures such as `area` and `haversineLength`.
 #[wasm_bindgen]
 pub struct FloatArray(pub(crate) arrow_array::Float64Array);
 
@@ -21,4 +109,22 @@ impl FloatArray {
     pub fn new(values: Vec<f64>) -> Self {
         Self(values.into())
     }
+
+    /// The value at index `i`, or `undefined` if it is null or out of bounds.
+    pub fn get(&self, i: usize) -> Option<f64> {
+        if i >= self.0.len() || self.0.is_null(i) {
+            return None;
+        }
+        Some(self.0.value(i))
+    }
+
+    /// The values of this array as a `Float64Array`, with `NaN` for null values.
+    #[wasm_bindgen(js_name = toTypedArray)]
+    pub fn to_typed_array(&self) -> Vec<f64> {
+        (0..self.0.len())
+            .map(|i| self.get(i).unwrap_or(f64::NAN))
+            .collect()
+    }
 }
+
+impl_primitive_array!(FloatArray);
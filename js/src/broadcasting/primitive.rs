@@ -1,5 +1,5 @@
 use arrow_array::types::{Float64Type, UInt32Type};
-use geoarrow::algorithm::broadcasting::BroadcastablePrimitive;
+use geoarrow::prelude::BroadcastablePrimitive;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -1,7 +1,7 @@
 use crate::array::*;
 use crate::ffi::FFIArrowArray;
 use arrow2::datatypes::Field;
-use geoarrow::GeometryArrayTrait;
+use geoarrow::prelude::GeometryArrayTrait;
 use wasm_bindgen::prelude::*;
 
 macro_rules! impl_to_ffi {
@@ -10,6 +10,8 @@ macro_rules! impl_to_ffi {
         impl $struct_name {
             #[wasm_bindgen(js_name = toFfi)]
             pub fn to_ffi(&self) -> FFIArrowArray {
+                // TODO: export the field from GeometryArrayTrait::to_field, which keeps the
+                // GeoArrow extension metadata, once FFIArrowArray is ported from arrow2 to arrow-rs
                 let arrow_array = self.0.clone().into_array_ref();
                 let field = Field::new("", arrow_array.data_type().clone(), true);
                 FFIArrowArray::new(&field, arrow_array)
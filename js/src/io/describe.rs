@@ -0,0 +1,33 @@
+use std::io::Cursor;
+
+use arrow_ipc::reader::StreamReader;
+use geoarrow::table::GeoTable;
+use wasm_bindgen::prelude::*;
+
+use crate::error::WasmResult;
+
+/// Summarize the geometry column and the attribute columns of an Arrow table.
+///
+/// Example:
+///
+/// ```js
+/// import { tableToIPC } from "apache-arrow";
+///
+/// const summary = describe(tableToIPC(table, "stream"), "geometry");
+/// console.log(summary.geometry.total_bounds);
+/// ```
+///
+/// @param ipcBytes Uint8Array containing an Arrow table in [IPC Stream format](https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format).
+/// @param geometryColumn The name of the geometry column.
+/// @returns An object with `num_rows`, a `geometry` column summary and an `attributes` array.
+#[wasm_bindgen]
+pub fn describe(ipc_bytes: &[u8], geometry_column: &str) -> WasmResult<JsValue> {
+    let reader = StreamReader::try_new(Cursor::new(ipc_bytes), None)?;
+    let schema = reader.schema();
+    let geometry_column_index = schema.index_of(geometry_column)?;
+    let batches = reader.collect::<Result<Vec<_>, _>>()?;
+
+    let table = GeoTable::try_new(schema, batches, geometry_column_index)?;
+    let summary = serde_json::to_string(&table.describe()?)?;
+    js_sys::JSON::parse(&summary).map_err(|_| JsError::new("Unable to parse the summary JSON"))
+}
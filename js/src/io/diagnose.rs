@@ -0,0 +1,35 @@
+use std::io::Cursor;
+
+use arrow_ipc::reader::StreamReader;
+use geoarrow::error::GeoArrowError;
+use geoarrow::io::record_batch::diagnose_geometry_field;
+use wasm_bindgen::prelude::*;
+
+use crate::error::WasmResult;
+
+/// Check whether a column of an Arrow table can be read as GeoArrow geometries.
+///
+/// Example:
+///
+/// ```js
+/// import { tableToIPC } from "apache-arrow";
+///
+/// console.log(diagnoseGeometryColumn(tableToIPC(table, "stream"), "geometry"));
+/// ```
+///
+/// @param ipcBytes Uint8Array containing an Arrow table in [IPC Stream format](https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format).
+/// @param columnName The name of the geometry column.
+/// @returns The GeoArrow type of the column, or a description of what would need to change for it to be readable.
+#[wasm_bindgen(js_name = diagnoseGeometryColumn)]
+pub fn diagnose_geometry_column(ipc_bytes: &[u8], column_name: &str) -> WasmResult<String> {
+    let reader = StreamReader::try_new(Cursor::new(ipc_bytes), None)?;
+    let schema = reader.schema();
+    let field = schema.field_with_name(column_name)?;
+
+    let report = match diagnose_geometry_field(field) {
+        Ok(data_type) => format!("Column \"{}\" is readable as {:?}.", column_name, data_type),
+        Err(GeoArrowError::General(message)) => message,
+        Err(err) => err.to_string(),
+    };
+    Ok(report)
+}
@@ -1,3 +1,5 @@
+pub mod describe;
+pub mod diagnose;
 #[cfg(feature = "flatgeobuf")]
 pub mod flatgeobuf;
 #[cfg(feature = "parquet")]
@@ -1,6 +1,7 @@
 import * as geoarrow from "../../pkg/node";
 import { parseField, parseVector } from "arrow-js-ffi";
-import { it } from "vitest";
+import { tableFromIPC } from "apache-arrow";
+import { expect, it } from "vitest";
 
 geoarrow.set_panic_hook();
 
@@ -33,3 +34,32 @@ it("hello world", () => {
   // console.log(field.metadata);
   // console.log(vector.getChildAt(0)?.toArray());
 });
+
+it("float array results", () => {
+  let values = new geoarrow.FloatArray(new Float64Array([1.5, 2.5, 3.5]));
+
+  expect(values.length).toBe(3);
+  expect(values.get(1)).toBe(2.5);
+  expect(values.get(3)).toBeUndefined();
+  expect(values.toArray()).toEqual([1.5, 2.5, 3.5]);
+  expect(Array.from(values.toTypedArray())).toEqual([1.5, 2.5, 3.5]);
+  expect(values.nullBitmap()).toBeUndefined();
+
+  let table = tableFromIPC(values.toArrowIPC());
+  expect(table.getChild("values")?.toArray()).toEqual(
+    new Float64Array([1.5, 2.5, 3.5])
+  );
+});
+
+it("boolean array results", () => {
+  let values = new geoarrow.BooleanArray(new Uint8Array([1, 0, 1]));
+
+  expect(values.length).toBe(3);
+  expect(values.get(0)).toBe(true);
+  expect(values.get(1)).toBe(false);
+  expect(values.toArray()).toEqual([true, false, true]);
+  expect(Array.from(values.toTypedArray())).toEqual([1, 0, 1]);
+
+  let table = tableFromIPC(values.toArrowIPC());
+  expect(table.getChild("values")?.get(2)).toBe(true);
+});
@@ -5,4 +5,5 @@ from .rust import (
     MultiPolygonArray,
     PointArray,
     PolygonArray,
+    describe,
 )
@@ -8,14 +8,14 @@ macro_rules! impl_area {
         impl $struct_name {
             /// Unsigned planar area of a geometry.
             pub fn area(&self, py: Python) -> PyResult<PyObject> {
-                use geoarrow::algorithm::geo::Area;
+                use geoarrow::prelude::Area;
                 let result = py.allow_threads(|| Area::unsigned_area(&self.0).to_boxed());
                 to_py_array(py, result)
             }
 
             /// Signed planar area of a geometry.
             pub fn signed_area(&self, py: Python) -> PyResult<PyObject> {
-                use geoarrow::algorithm::geo::Area;
+                use geoarrow::prelude::Area;
                 let result = py.allow_threads(|| Area::signed_area(&self.0).to_boxed());
                 to_py_array(py, result)
             }
@@ -7,7 +7,7 @@ macro_rules! impl_bounding_rect {
         impl $struct_name {
             /// Return the bounding rectangle of a geometry
             pub fn bounding_rect(&self) -> PolygonArray {
-                use geoarrow::algorithm::geo::BoundingRect;
+                use geoarrow::prelude::BoundingRect;
                 PolygonArray(BoundingRect::bounding_rect(&self.0))
             }
         }
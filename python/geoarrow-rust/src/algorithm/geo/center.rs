@@ -10,7 +10,7 @@ macro_rules! impl_center {
             /// This first computes the axis-aligned bounding rectangle, then takes the center of
             /// that box
             pub fn center(&self) -> PointArray {
-                use geoarrow::algorithm::geo::Center;
+                use geoarrow::prelude::Center;
                 PointArray(Center::center(&self.0))
             }
         }
@@ -14,7 +14,7 @@ macro_rules! impl_centroid {
             /// The geometric centroid of a convex object always lies in the object.
             /// A non-convex object might have a centroid that _is outside the object itself_.
             pub fn centroid(&self) -> PointArray {
-                use geoarrow::algorithm::geo::Centroid;
+                use geoarrow::prelude::Centroid;
                 PointArray(Centroid::centroid(&self.0))
             }
         }
@@ -17,7 +17,7 @@ macro_rules! impl_chaikin_smoothing {
             /// This implementation preserves the start and end vertices of an open linestring and
             /// smoothes the corner between start and end of a closed linestring.
             pub fn chaikin_smoothing(&self, n_iterations: BroadcastableUint32) -> Self {
-                use geoarrow::algorithm::geo::ChaikinSmoothing;
+                use geoarrow::prelude::ChaikinSmoothing;
                 ChaikinSmoothing::chaikin_smoothing(&self.0, n_iterations.0).into()
             }
         }
@@ -9,7 +9,7 @@ macro_rules! impl_alg {
         impl $struct_name {
             /// Calculate the unsigned approximate geodesic area of a `Geometry`.
             pub fn chamberlain_duquette_unsigned_area(&self, py: Python) -> PyResult<PyObject> {
-                use geoarrow::algorithm::geo::ChamberlainDuquetteArea;
+                use geoarrow::prelude::ChamberlainDuquetteArea;
                 let result = py.allow_threads(|| {
                     ChamberlainDuquetteArea::chamberlain_duquette_unsigned_area(&self.0).to_boxed()
                 });
@@ -18,7 +18,7 @@ macro_rules! impl_alg {
 
             /// Calculate the signed approximate geodesic area of a `Geometry`.
             pub fn chamberlain_duquette_signed_area(&self, py: Python) -> PyResult<PyObject> {
-                use geoarrow::algorithm::geo::ChamberlainDuquetteArea;
+                use geoarrow::prelude::ChamberlainDuquetteArea;
                 let result = py.allow_threads(|| {
                     ChamberlainDuquetteArea::chamberlain_duquette_signed_area(&self.0).to_boxed()
                 });
@@ -13,7 +13,7 @@ macro_rules! impl_alg {
             /// 1996)](https://dx.doi.org/10.1145%2F235815.235821) Original paper here:
             /// <http://www.cs.princeton.edu/~dpd/Papers/BarberDobkinHuhdanpaa.pdf>
             pub fn convex_hull(&self) -> PolygonArray {
-                use geoarrow::algorithm::geo::ConvexHull;
+                use geoarrow::prelude::ConvexHull;
                 PolygonArray(ConvexHull::convex_hull(&self.0))
             }
         }
@@ -13,7 +13,7 @@ macro_rules! impl_alg {
             /// Types like `Point`, which have at least one coordinate by construction, can never
             /// be considered empty.
             pub fn is_empty(&self, py: Python) -> PyResult<PyObject> {
-                use geoarrow::algorithm::geo::HasDimensions;
+                use geoarrow::prelude::HasDimensions;
                 let result = py.allow_threads(|| HasDimensions::is_empty(&self.0).to_boxed());
                 to_py_array(py, result)
             }
@@ -9,7 +9,7 @@ macro_rules! impl_euclidean_length {
         impl $struct_name {
             /// Calculation of the length of a Line
             pub fn euclidean_length(&self, py: Python) -> PyResult<PyObject> {
-                use geoarrow::algorithm::geo::EuclideanLength;
+                use geoarrow::prelude::EuclideanLength;
                 let result =
                     py.allow_threads(|| EuclideanLength::euclidean_length(&self.0).to_boxed());
                 to_py_array(py, result)
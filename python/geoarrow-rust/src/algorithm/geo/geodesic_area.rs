@@ -31,7 +31,7 @@ macro_rules! impl_geodesic_area {
             ///
             /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
             pub fn geodesic_area_signed(&self, py: Python) -> PyResult<PyObject> {
-                use geoarrow::algorithm::geo::GeodesicArea;
+                use geoarrow::prelude::GeodesicArea;
                 let result =
                     py.allow_threads(|| GeodesicArea::geodesic_area_signed(&self.0).to_boxed());
                 to_py_array(py, result)
@@ -53,7 +53,7 @@ macro_rules! impl_geodesic_area {
             ///
             /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
             pub fn geodesic_area_unsigned(&self, py: Python) -> PyResult<PyObject> {
-                use geoarrow::algorithm::geo::GeodesicArea;
+                use geoarrow::prelude::GeodesicArea;
                 let result =
                     py.allow_threads(|| GeodesicArea::geodesic_area_unsigned(&self.0).to_boxed());
                 to_py_array(py, result)
@@ -72,7 +72,7 @@ macro_rules! impl_geodesic_area {
             ///
             /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
             pub fn geodesic_perimeter(&self, py: Python) -> PyResult<PyObject> {
-                use geoarrow::algorithm::geo::GeodesicArea;
+                use geoarrow::prelude::GeodesicArea;
                 let result =
                     py.allow_threads(|| GeodesicArea::geodesic_perimeter(&self.0).to_boxed());
                 to_py_array(py, result)
@@ -109,7 +109,7 @@ macro_rules! impl_geodesic_area {
             // /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
             // #[pymethods]
             // pub fn geodesic_perimeter_area_signed(&self) -> (FloatArray, FloatArray) {
-            //     use geoarrow::algorithm::geo::GeodesicArea;
+            //     use geoarrow::prelude::GeodesicArea;
             //     let (perimeter, area) = GeodesicArea::geodesic_perimeter_area_signed(&self.0);
             //     (FloatArray(perimeter), FloatArray(area))
             // }
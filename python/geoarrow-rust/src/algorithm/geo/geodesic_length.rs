@@ -15,7 +15,7 @@ macro_rules! impl_geodesic_length {
             ///
             /// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
             pub fn geodesic_length(&self, py: Python) -> PyResult<PyObject> {
-                use geoarrow::algorithm::geo::GeodesicLength;
+                use geoarrow::prelude::GeodesicLength;
                 let result =
                     py.allow_threads(|| GeodesicLength::geodesic_length(&self.0).to_boxed());
                 to_py_array(py, result)
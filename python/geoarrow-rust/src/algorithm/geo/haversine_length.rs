@@ -14,7 +14,7 @@ macro_rules! impl_haversine_length {
             /// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the
             /// [recommendation of the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
             pub fn haversine_length(&self, py: Python) -> PyResult<PyObject> {
-                use geoarrow::algorithm::geo::HaversineLength;
+                use geoarrow::prelude::HaversineLength;
                 let result =
                     py.allow_threads(|| HaversineLength::haversine_length(&self.0).to_boxed());
                 to_py_array(py, result)
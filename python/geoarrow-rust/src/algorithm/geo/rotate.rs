@@ -1,7 +1,7 @@
 use crate::array::*;
 use crate::broadcasting::BroadcastableFloat;
 // use crate::scalar::Point;
-use geoarrow::algorithm::broadcasting::BroadcastablePrimitive;
+use geoarrow::prelude::BroadcastablePrimitive;
 use pyo3::prelude::*;
 
 macro_rules! impl_rotate {
@@ -12,7 +12,7 @@ macro_rules! impl_rotate {
             ///
             /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
             pub fn rotate_around_centroid(&self, degrees: BroadcastableFloat) -> Self {
-                use geoarrow::algorithm::geo::Rotate;
+                use geoarrow::prelude::Rotate;
                 match degrees.0 {
                     BroadcastablePrimitive::Array(arr) => {
                         Rotate::rotate_around_centroid(&self.0, &arr).into()
@@ -27,7 +27,7 @@ macro_rules! impl_rotate {
             ///
             /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
             pub fn rotate_around_center(&self, degrees: BroadcastableFloat) -> Self {
-                use geoarrow::algorithm::geo::Rotate;
+                use geoarrow::prelude::Rotate;
                 match degrees.0 {
                     BroadcastablePrimitive::Array(arr) => {
                         Rotate::rotate_around_center(&self.0, &arr).into()
@@ -42,7 +42,7 @@ macro_rules! impl_rotate {
             // ///
             // /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
             // pub fn rotate_around_point(&self, degrees: BroadcastableFloat, point: Point) -> Self {
-            //     use geoarrow::algorithm::geo::Rotate;
+            //     use geoarrow::prelude::Rotate;
             //     match degrees.0 {
             //         BroadcastablePrimitive::Array(arr) => {
             //             Rotate::rotate_around_point(&self.0, &arr, point.0.into()).into()
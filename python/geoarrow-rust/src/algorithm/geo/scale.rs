@@ -8,7 +8,7 @@ macro_rules! impl_scale {
         impl $struct_name {
             /// Scale a geometry from it's bounding box center.
             pub fn scale(&self, scale_factor: BroadcastableFloat) -> Self {
-                use geoarrow::algorithm::geo::Scale;
+                use geoarrow::prelude::Scale;
                 Scale::scale(&self.0, scale_factor.0).into()
             }
 
@@ -20,7 +20,7 @@ macro_rules! impl_scale {
                 x_factor: BroadcastableFloat,
                 y_factor: BroadcastableFloat,
             ) -> Self {
-                use geoarrow::algorithm::geo::Scale;
+                use geoarrow::prelude::Scale;
                 Scale::scale_xy(&self.0, x_factor.0, y_factor.0).into()
             }
 
@@ -19,7 +19,7 @@ macro_rules! impl_simplify {
             /// An epsilon less than or equal to zero will return an unaltered version of the
             /// geometry.
             pub fn simplify(&self, epsilon: f64) -> Self {
-                use geoarrow::algorithm::geo::Simplify;
+                use geoarrow::prelude::Simplify;
                 Simplify::simplify(&self.0, &epsilon).into()
             }
         }
@@ -10,7 +10,7 @@ macro_rules! impl_skew {
             /// An affine transformation which skews a geometry, sheared by a uniform angle along
             /// the x and y dimensions.
             pub fn skew(&self, degrees: BroadcastableFloat) -> Self {
-                use geoarrow::algorithm::geo::Skew;
+                use geoarrow::prelude::Skew;
                 Skew::skew(&self.0, degrees.0).into()
             }
 
@@ -22,7 +22,7 @@ macro_rules! impl_skew {
                 degrees_x: BroadcastableFloat,
                 degrees_y: BroadcastableFloat,
             ) -> Self {
-                use geoarrow::algorithm::geo::Skew;
+                use geoarrow::prelude::Skew;
                 Skew::skew_xy(&self.0, degrees_x.0, degrees_y.0).into()
             }
 
@@ -38,7 +38,7 @@ macro_rules! impl_skew {
             //     degrees_y: BroadcastableFloat,
             //     origin: Point,
             // ) -> Self {
-            //     use geoarrow::algorithm::geo::Skew;
+            //     use geoarrow::prelude::Skew;
             //     Skew::skew_around_point(&self.0, degrees_x.0, degrees_y.0, origin.0.into()).into()
             // }
         }
@@ -11,7 +11,7 @@ macro_rules! impl_vincenty_length {
             ///
             /// [Vincenty’s formulae]: https://en.wikipedia.org/wiki/Vincenty%27s_formulae
             pub fn vincenty_length(&self, py: Python) -> PyResult<PyObject> {
-                use geoarrow::algorithm::geo::VincentyLength;
+                use geoarrow::prelude::VincentyLength;
                 let result = py
                     .allow_threads(|| VincentyLength::vincenty_length(&self.0).unwrap().to_boxed());
                 to_py_array(py, result)
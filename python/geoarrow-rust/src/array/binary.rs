@@ -1,5 +1,8 @@
+use std::collections::HashMap;
+
 use crate::ffi::{from_py_array, to_py_array};
-use geoarrow::GeometryArrayTrait;
+use geoarrow::prelude::GeometryArrayTrait;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 /// An immutable array of WKB-formatted geometries in WebAssembly memory using GeoArrow's in-memory
@@ -7,6 +10,24 @@ use pyo3::prelude::*;
 #[pyclass]
 pub struct WKBArray(pub(crate) geoarrow::array::WKBArray<i32>);
 
+#[pymethods]
+impl WKBArray {
+    /// Count the geometries of each type, reading only the header of each WKB value.
+    ///
+    /// Returns a dict from geometry type name, such as `"Polygon"`, to count. Null values are
+    /// not counted.
+    fn value_counts_geometry_type(&self) -> PyResult<HashMap<String, usize>> {
+        let histogram = self
+            .0
+            .type_histogram()
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        Ok(histogram
+            .into_iter()
+            .map(|(geometry_type, count)| (format!("{:?}", geometry_type), count))
+            .collect())
+    }
+}
+
 impl From<geoarrow::array::WKBArray<i32>> for WKBArray {
     fn from(value: geoarrow::array::WKBArray<i32>) -> Self {
         Self(value)
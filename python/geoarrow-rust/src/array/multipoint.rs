@@ -1,5 +1,5 @@
 use crate::ffi::{from_py_array, to_py_array};
-use geoarrow::GeometryArrayTrait;
+use geoarrow::prelude::GeometryArrayTrait;
 use pyo3::prelude::*;
 
 /// An immutable array of MultiPoint geometries in WebAssembly memory using GeoArrow's in-memory
@@ -1,5 +1,5 @@
 use crate::ffi::{from_py_array, to_py_array};
-use geoarrow::GeometryArrayTrait;
+use geoarrow::prelude::GeometryArrayTrait;
 use pyo3::prelude::*;
 use pyo3::types::PyType;
 
@@ -1,5 +1,5 @@
 use arrow2::array::PrimitiveArray;
-use geoarrow::algorithm::broadcasting::BroadcastablePrimitive;
+use geoarrow::prelude::BroadcastablePrimitive;
 use pyo3::prelude::*;
 
 use crate::ffi::{from_py_array, to_py_array};
@@ -30,6 +30,8 @@ pub fn from_py_array(arrow_array: &PyAny) -> PyResult<Box<dyn Array>> {
 
 /// Arrow array to Python.
 pub fn to_py_array(py: Python, array: Box<dyn Array>) -> PyResult<PyObject> {
+    // TODO: export geometry arrays with the field from GeometryArrayTrait::to_field, which keeps
+    // the GeoArrow extension metadata, once these bindings are ported from arrow2 to arrow-rs
     let schema = Box::new(ffi::export_field_to_c(&Field::new(
         "",
         array.data_type().clone(),
@@ -3,6 +3,8 @@ pub mod algorithm;
 pub mod array;
 pub mod broadcasting;
 pub mod ffi;
+pub mod plot;
+pub mod table;
 
 /// Formats the sum of two numbers as string.
 #[pyfunction]
@@ -20,6 +22,7 @@ fn rust(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<array::MultiLineStringArray>()?;
     m.add_class::<array::MultiPolygonArray>()?;
 
+    m.add_function(wrap_pyfunction!(table::describe, m)?)?;
     m.add_function(wrap_pyfunction!(sum_as_string, m)?)?;
     Ok(())
 }
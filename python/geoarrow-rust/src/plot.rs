@@ -0,0 +1,108 @@
+//! Shape GeoArrow arrays into the arrays that matplotlib's collections take.
+
+use crate::array::*;
+use geoarrow::buffer::OffsetBufferUtils;
+use geoarrow::prelude::GeometryArrayTrait;
+use numpy::ndarray::{Array2, Array3};
+use numpy::{IntoPyArray, PyArray1, PyArray2, PyArray3};
+use pyo3::prelude::*;
+
+// Path codes from matplotlib.path.Path
+const MOVETO: u8 = 1;
+const LINETO: u8 = 2;
+const CLOSEPOLY: u8 = 79;
+
+#[pymethods]
+impl PolygonArray {
+    /// Convert to the vertices and codes of matplotlib paths, one path per polygon.
+    ///
+    /// Returns a tuple of:
+    ///
+    /// - `vertices`: an `(M, 2)` float array of all ring coordinates
+    /// - `codes`: an `(M,)` uint8 array of path codes, `MOVETO` at the start of each ring,
+    ///   `CLOSEPOLY` at its end and `LINETO` in between
+    /// - `path_offsets`: the start of each polygon's path in `vertices`, plus the total length
+    /// - `indices`: the row of each path in this array
+    ///
+    /// Null polygons are skipped, so `indices` maps paths back to rows. To build a
+    /// `PathCollection`, split `vertices` and `codes` at `path_offsets[1:-1]`.
+    #[allow(clippy::type_complexity)]
+    pub fn to_matplotlib<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<(
+        &'py PyArray2<f64>,
+        &'py PyArray1<u8>,
+        &'py PyArray1<i64>,
+        &'py PyArray1<i64>,
+    )> {
+        let (vertices, codes, path_offsets, indices) = py.allow_threads(|| {
+            let arr = &self.0;
+            let mut vertices = vec![];
+            let mut codes = vec![];
+            let mut path_offsets = vec![0];
+            let mut indices = vec![];
+            for geom_idx in (0..arr.len()).filter(|i| arr.is_valid(*i)) {
+                let (ring_start, ring_end) = arr.geom_offsets.start_end(geom_idx);
+                for ring_idx in ring_start..ring_end {
+                    let (start, end) = arr.ring_offsets.start_end(ring_idx);
+                    for coord_idx in start..end {
+                        vertices.push(arr.coords.get_x(coord_idx));
+                        vertices.push(arr.coords.get_y(coord_idx));
+                        codes.push(match coord_idx {
+                            i if i == start => MOVETO,
+                            i if i == end - 1 => CLOSEPOLY,
+                            _ => LINETO,
+                        });
+                    }
+                }
+                path_offsets.push(codes.len() as i64);
+                indices.push(geom_idx as i64);
+            }
+            (vertices, codes, path_offsets, indices)
+        });
+
+        let vertices = Array2::from_shape_vec((codes.len(), 2), vertices).unwrap();
+        Ok((
+            vertices.into_pyarray(py),
+            codes.into_pyarray(py),
+            path_offsets.into_pyarray(py),
+            indices.into_pyarray(py),
+        ))
+    }
+}
+
+#[pymethods]
+impl LineStringArray {
+    /// Convert to the segments of matplotlib's `LineCollection`.
+    ///
+    /// Returns a tuple of an `(N, 2, 2)` float array holding the start and end coordinates of
+    /// every segment of every line string, and the row of each segment in this array. Null line
+    /// strings are skipped.
+    pub fn to_segments<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<(&'py PyArray3<f64>, &'py PyArray1<i64>)> {
+        let (segments, indices) = py.allow_threads(|| {
+            let arr = &self.0;
+            let mut segments = vec![];
+            let mut indices = vec![];
+            for geom_idx in (0..arr.len()).filter(|i| arr.is_valid(*i)) {
+                let (start, end) = arr.geom_offsets.start_end(geom_idx);
+                for coord_idx in start..end.saturating_sub(1) {
+                    segments.extend_from_slice(&[
+                        arr.coords.get_x(coord_idx),
+                        arr.coords.get_y(coord_idx),
+                        arr.coords.get_x(coord_idx + 1),
+                        arr.coords.get_y(coord_idx + 1),
+                    ]);
+                    indices.push(geom_idx as i64);
+                }
+            }
+            (segments, indices)
+        });
+
+        let segments = Array3::from_shape_vec((indices.len(), 2, 2), segments).unwrap();
+        Ok((segments.into_pyarray(py), indices.into_pyarray(py)))
+    }
+}
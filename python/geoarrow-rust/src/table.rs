@@ -0,0 +1,37 @@
+use std::io::Cursor;
+
+use arrow_ipc::reader::StreamReader;
+use geoarrow::table::GeoTable;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Summarize the geometry column and the attribute columns of an Arrow table.
+///
+/// Args:
+///     ipc_bytes: An Arrow table serialized in the IPC stream format.
+///     geometry_column: The name of the geometry column.
+///
+/// Returns:
+///     A dict with `num_rows`, a `geometry` column summary and an `attributes` list.
+#[pyfunction]
+pub fn describe(py: Python, ipc_bytes: &[u8], geometry_column: &str) -> PyResult<PyObject> {
+    let reader = StreamReader::try_new(Cursor::new(ipc_bytes), None)
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let schema = reader.schema();
+    let geometry_column_index = schema
+        .index_of(geometry_column)
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let batches = reader
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+    let table = GeoTable::try_new(schema, batches, geometry_column_index)
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let summary = table
+        .describe()
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let summary =
+        serde_json::to_string(&summary).map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let json = py.import("json")?;
+    Ok(json.call_method1("loads", (summary,))?.into())
+}
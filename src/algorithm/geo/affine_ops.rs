@@ -1,4 +1,6 @@
+use crate::algorithm::native::transform::MapGeometryCoords;
 use crate::array::*;
+use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
 use geo::{AffineTransform, MapCoords};
 
@@ -6,12 +8,17 @@ use geo::{AffineTransform, MapCoords};
 /// [`skew`](AffineTransform::skew), or [`rotate`](AffineTransform::rotate) to geometries.
 ///
 /// Multiple transformations can be composed in order to be efficiently applied in a single
-/// operation. See [`AffineTransform`] for more on how to build up a transformation.
+/// operation. See [`AffineTransform`] for more on how to build up a transformation. Note that with
+/// the `geo` version used here, a chained operation such as
+/// [`rotated`](AffineTransform::rotated) is applied *before* the operations already in the
+/// transform, so chain them in the reverse of the order they should happen in.
 ///
-/// If you are not composing operations, traits that leverage this same machinery exist which might
-/// be more readable. See: [`Scale`](crate::algorithm::geo::Scale),
-/// [`Translate`](crate::algorithm::geo::Translate), [`Rotate`](crate::algorithm::geo::Rotate), and
-/// [`Skew`](crate::algorithm::geo::Skew).
+/// Native arrays are transformed in a single pass over a copy of their coordinate buffer, keeping
+/// their offsets and validity, and the one-off [`Scale`](crate::algorithm::geo::Scale),
+/// [`Translate`](crate::algorithm::geo::Translate), [`Rotate`](crate::algorithm::geo::Rotate) and
+/// [`Skew`](crate::algorithm::geo::Skew) traits are built on the same pass.
+///
+/// If you are not composing operations, those traits might be more readable.
 ///
 /// # Examples
 /// ## Build up transforms by beginning with a constructor, then chaining mutation operations
@@ -50,45 +57,40 @@ pub trait AffineOps<Rhs> {
 // │ Implementations for RHS scalars │
 // └─────────────────────────────────┘
 
-// Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
-impl AffineOps<AffineTransform> for PointArray {
-    fn affine_transform(&self, transform: &AffineTransform) -> Self {
-        let output_geoms: Vec<Option<geo::Point>> = self
-            .iter_geo()
-            .map(|maybe_g| maybe_g.map(|geom| geom.map_coords(|coord| transform.apply(coord))))
-            .collect();
-
-        output_geoms.into()
-    }
-}
-
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
-    ($type:ty, $geo_type:ty) => {
-        impl<O: OffsetSizeTrait> AffineOps<AffineTransform> for $type {
+/// Implementation that maps over the coordinate buffer, keeping offsets and validity
+macro_rules! coord_impl {
+    ($type:ty $(, $generic:ident)?) => {
+        impl$(<$generic: OffsetSizeTrait>)? AffineOps<AffineTransform> for $type {
             fn affine_transform(&self, transform: &AffineTransform) -> Self {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .map(|maybe_g| {
-                        maybe_g.map(|geom| geom.map_coords(|coord| transform.apply(coord)))
-                    })
-                    .collect();
-
-                output_geoms.into()
+                self.map_geometry_coords(|_, coord| transform.apply(coord))
+            }
+        }
+
+        impl$(<$generic: OffsetSizeTrait>)? AffineOps<Vec<AffineTransform>> for $type {
+            fn affine_transform(&self, transform: &Vec<AffineTransform>) -> Self {
+                assert_eq!(
+                    transform.len(),
+                    self.len(),
+                    "expected one affine transform per geometry"
+                );
+                self.map_geometry_coords(|geom_idx, coord| transform[geom_idx].apply(coord))
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, geo::LineString);
-iter_geo_impl!(PolygonArray<O>, geo::Polygon);
-iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
-iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
-iter_geo_impl!(WKBArray<O>, geo::Geometry);
+coord_impl!(PointArray);
+coord_impl!(LineStringArray<O>, O);
+coord_impl!(PolygonArray<O>, O);
+coord_impl!(MultiPointArray<O>, O);
+coord_impl!(MultiLineStringArray<O>, O);
+coord_impl!(MultiPolygonArray<O>, O);
+
+// WKB has no coordinate buffer, so it goes through geo objects
 
-impl<O: OffsetSizeTrait> AffineOps<AffineTransform> for MultiPointArray<O> {
+impl<O: OffsetSizeTrait> AffineOps<AffineTransform> for WKBArray<O> {
     fn affine_transform(&self, transform: &AffineTransform) -> Self {
-        let output_geoms: Vec<Option<geo::MultiPoint>> = self
+        let output_geoms: Vec<Option<geo::Geometry>> = self
             .iter_geo()
             .map(|maybe_g| maybe_g.map(|geom| geom.map_coords(|coord| transform.apply(coord))))
             .collect();
@@ -97,20 +99,9 @@ impl<O: OffsetSizeTrait> AffineOps<AffineTransform> for MultiPointArray<O> {
     }
 }
 
-impl<O: OffsetSizeTrait> AffineOps<AffineTransform> for GeometryArray<O> {
-    crate::geometry_array_delegate_impl! {
-        fn affine_transform(&self, transform: &AffineTransform) -> Self;
-    }
-}
-
-// ┌────────────────────────────────┐
-// │ Implementations for RHS arrays │
-// └────────────────────────────────┘
-
-// Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
-impl AffineOps<Vec<AffineTransform>> for PointArray {
+impl<O: OffsetSizeTrait> AffineOps<Vec<AffineTransform>> for WKBArray<O> {
     fn affine_transform(&self, transform: &Vec<AffineTransform>) -> Self {
-        let output_geoms: Vec<Option<geo::Point>> = self
+        let output_geoms: Vec<Option<geo::Geometry>> = self
             .iter_geo()
             .zip(transform.iter())
             .map(|(maybe_g, transform)| {
@@ -122,34 +113,29 @@ impl AffineOps<Vec<AffineTransform>> for PointArray {
     }
 }
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
-    ($type:ty, $geo_type:ty) => {
-        impl<O: OffsetSizeTrait> AffineOps<Vec<AffineTransform>> for $type {
-            fn affine_transform(&self, transform: &Vec<AffineTransform>) -> Self {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .zip(transform.iter())
-                    .map(|(maybe_g, transform)| {
-                        maybe_g.map(|geom| geom.map_coords(|coord| transform.apply(coord)))
-                    })
-                    .collect();
-
-                output_geoms.into()
-            }
-        }
-    };
-}
+// Geometry collections store their members in a mixed array, so they also go through geo objects
+
+impl<O: OffsetSizeTrait> AffineOps<AffineTransform> for GeometryCollectionArray<O> {
+    fn affine_transform(&self, transform: &AffineTransform) -> Self {
+        let output_geoms: Vec<Option<geo::GeometryCollection>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| geom.map_coords(|coord| transform.apply(coord))))
+            .collect();
 
-iter_geo_impl!(LineStringArray<O>, geo::LineString);
-iter_geo_impl!(PolygonArray<O>, geo::Polygon);
-iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
-iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
-iter_geo_impl!(WKBArray<O>, geo::Geometry);
+        // Transforming coordinates can't nest the collections read from this array
+        let output: Self = output_geoms.try_into().unwrap();
+        output.into_coord_type(self.coord_type())
+    }
+}
 
-impl<O: OffsetSizeTrait> AffineOps<Vec<AffineTransform>> for MultiPointArray<O> {
+impl<O: OffsetSizeTrait> AffineOps<Vec<AffineTransform>> for GeometryCollectionArray<O> {
     fn affine_transform(&self, transform: &Vec<AffineTransform>) -> Self {
-        let output_geoms: Vec<Option<geo::MultiPoint>> = self
+        assert_eq!(
+            transform.len(),
+            self.len(),
+            "expected one affine transform per geometry"
+        );
+        let output_geoms: Vec<Option<geo::GeometryCollection>> = self
             .iter_geo()
             .zip(transform.iter())
             .map(|(maybe_g, transform)| {
@@ -157,7 +143,15 @@ impl<O: OffsetSizeTrait> AffineOps<Vec<AffineTransform>> for MultiPointArray<O>
             })
             .collect();
 
-        output_geoms.into()
+        // Transforming coordinates can't nest the collections read from this array
+        let output: Self = output_geoms.try_into().unwrap();
+        output.into_coord_type(self.coord_type())
+    }
+}
+
+impl<O: OffsetSizeTrait> AffineOps<AffineTransform> for GeometryArray<O> {
+    crate::geometry_array_delegate_impl! {
+        fn affine_transform(&self, transform: &AffineTransform) -> Self;
     }
 }
 
@@ -166,3 +160,85 @@ impl<O: OffsetSizeTrait> AffineOps<Vec<AffineTransform>> for GeometryArray<O> {
         fn affine_transform(&self, transform: &Vec<AffineTransform>) -> Self;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeoArrayAccessor;
+    use approx::assert_relative_eq;
+    use geo::{line_string, point};
+
+    #[test]
+    fn keeps_offsets_and_validity() {
+        let array: LineStringArray<i32> = vec![
+            Some(line_string![(x: 1., y: 0.), (x: 2., y: 0.)]),
+            None,
+            Some(line_string![(x: 0., y: 1.), (x: 0., y: 2.), (x: 0., y: 3.)]),
+        ]
+        .into();
+        let array = array.into_coord_type(CoordType::Separated);
+        let rotated = array.affine_transform(&AffineTransform::rotate(90., point!(x: 0., y: 0.)));
+
+        assert_eq!(rotated.geom_offsets.as_ref(), array.geom_offsets.as_ref());
+        assert_eq!(rotated.validity, array.validity);
+        assert_eq!(rotated.coord_type(), CoordType::Separated);
+        assert_relative_eq!(
+            rotated.value_as_geo(0),
+            line_string![(x: 0., y: 1.), (x: 0., y: 2.)]
+        );
+        assert_relative_eq!(
+            rotated.value_as_geo(2),
+            line_string![(x: -1., y: 0.), (x: -2., y: 0.), (x: -3., y: 0.)]
+        );
+    }
+
+    #[test]
+    fn one_transform_per_geometry() {
+        let array: PointArray = vec![point!(x: 1., y: 1.), point!(x: 1., y: 1.)].into();
+        let transforms = vec![
+            AffineTransform::translate(1., 0.),
+            AffineTransform::translate(0., 1.),
+        ];
+        let translated = array.affine_transform(&transforms);
+        assert_eq!(translated.value_as_geo(0), point!(x: 2., y: 1.));
+        assert_eq!(translated.value_as_geo(1), point!(x: 1., y: 2.));
+    }
+
+    #[test]
+    fn composed_transform_equals_ops_in_order() {
+        use crate::algorithm::broadcasting::BroadcastablePrimitive;
+        use crate::algorithm::geo::{Rotate, Scale, Skew, Translate};
+        use crate::algorithm::native::EqualsExact;
+        use crate::test::polygon::{p0, p1};
+
+        let array: PolygonArray<i32> = vec![Some(p0()), None, Some(p1())].into();
+        let origin = point!(x: 3., y: -2.);
+
+        let step_by_step = array
+            .translate(
+                BroadcastablePrimitive::Scalar(1.5),
+                BroadcastablePrimitive::Scalar(-4.),
+            )
+            .scale_around_point(
+                BroadcastablePrimitive::Scalar(2.),
+                BroadcastablePrimitive::Scalar(0.5),
+                origin,
+            )
+            .rotate_around_point(&30., origin)
+            .skew_around_point(
+                BroadcastablePrimitive::Scalar(10.),
+                BroadcastablePrimitive::Scalar(-5.),
+                origin,
+            );
+
+        // Each chained operation applies before the ones already in the transform
+        let transform = AffineTransform::skew(10., -5., origin)
+            .rotated(30., origin)
+            .scaled(2., 0.5, origin)
+            .translated(1.5, -4.);
+        let composed = array.affine_transform(&transform);
+
+        assert!(composed.equals_exact(&step_by_step, 1e-9));
+        assert!(!composed.equals_exact(&array, 1e-9));
+    }
+}
@@ -1,7 +1,8 @@
 use crate::algorithm::geo::utils::zeroes;
+use crate::algorithm::geo::GeodesicArea;
 use crate::array::{
-    GeometryArray, LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray,
-    PointArray, PolygonArray, WKBArray,
+    Edges, GeometryArray, GeometryCollectionArray, LineStringArray, MultiLineStringArray,
+    MultiPointArray, MultiPolygonArray, PointArray, PolygonArray, RectArray, WKBArray,
 };
 use crate::GeometryArrayTrait;
 use arrow_array::builder::Float64Builder;
@@ -10,6 +11,9 @@ use geo::prelude::Area as GeoArea;
 
 /// Signed and unsigned planar area of a geometry.
 ///
+/// [`Area::area`] additionally honors the [`Edges`] of the array's metadata, computing the
+/// geodesic area in square meters (see [`GeodesicArea`]) when edges are spherical.
+///
 /// # Examples
 ///
 /// ```
@@ -44,6 +48,9 @@ pub trait Area {
     fn signed_area(&self) -> Float64Array;
 
     fn unsigned_area(&self) -> Float64Array;
+
+    /// The unsigned area, planar or geodesic depending on the edges of the array.
+    fn area(&self) -> Float64Array;
 }
 
 // Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
@@ -55,6 +62,10 @@ impl Area for PointArray {
     fn unsigned_area(&self) -> Float64Array {
         zeroes(self.len(), self.nulls())
     }
+
+    fn area(&self) -> Float64Array {
+        zeroes(self.len(), self.nulls())
+    }
 }
 
 /// Implementation where the result is zero.
@@ -68,6 +79,10 @@ macro_rules! zero_impl {
             fn unsigned_area(&self) -> Float64Array {
                 zeroes(self.len(), self.nulls())
             }
+
+            fn area(&self) -> Float64Array {
+                zeroes(self.len(), self.nulls())
+            }
         }
     };
 }
@@ -94,26 +109,61 @@ macro_rules! iter_geo_impl {
                 });
                 output_array.finish()
             }
+
+            fn area(&self) -> Float64Array {
+                match self.metadata().edges {
+                    Edges::Planar => self.unsigned_area(),
+                    Edges::Spherical => self.geodesic_area_unsigned(),
+                }
+            }
         }
     };
 }
 
 iter_geo_impl!(PolygonArray<O>);
 iter_geo_impl!(MultiPolygonArray<O>);
+iter_geo_impl!(GeometryCollectionArray<O>);
 iter_geo_impl!(WKBArray<O>);
 
+// The minimum corner of a rect is always its lower left corner, so its signed area is never
+// negative. Rect arrays have no edges metadata, and their area is always planar.
+impl Area for RectArray {
+    fn signed_area(&self) -> Float64Array {
+        self.unsigned_area()
+    }
+
+    fn unsigned_area(&self) -> Float64Array {
+        let mut output_array = Float64Builder::with_capacity(self.len());
+        self.iter_geo()
+            .for_each(|maybe_g| output_array.append_option(maybe_g.map(|g| g.unsigned_area())));
+        output_array.finish()
+    }
+
+    fn area(&self) -> Float64Array {
+        self.unsigned_area()
+    }
+}
+
 impl<O: OffsetSizeTrait> Area for GeometryArray<O> {
     crate::geometry_array_delegate_impl! {
+        with_rect;
+
         fn signed_area(&self) -> Float64Array;
 
         fn unsigned_area(&self) -> Float64Array;
+
+        fn area(&self) -> Float64Array;
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::test::polygon::p_array;
+    use crate::array::ArrayMetadata;
+    use crate::test::polygon::{p0, p1, p_array};
+    use crate::test::{linestring, point};
+    use arrow_array::Array;
+    use geo::Polygon;
 
     #[test]
     fn tmp() {
@@ -121,4 +171,97 @@ mod test {
         let area = arr.unsigned_area();
         assert_eq!(area, Float64Array::new(vec![28., 18.].into(), None));
     }
+
+    #[test]
+    fn area_honors_edges() {
+        let planar = p_array();
+        assert_eq!(planar.area(), planar.unsigned_area());
+
+        let spherical = planar.with_metadata(ArrayMetadata::new(Edges::Spherical));
+        assert_eq!(spherical.area(), spherical.geodesic_area_unsigned());
+        assert!(spherical.area().value(0) > 1e10);
+    }
+
+    fn reversed(polygon: Polygon) -> Polygon {
+        let (mut exterior, mut interiors) = polygon.into_inner();
+        exterior.0.reverse();
+        interiors.iter_mut().for_each(|ring| ring.0.reverse());
+        Polygon::new(exterior, interiors)
+    }
+
+    #[test]
+    fn orientation_affects_signed_area_only() {
+        let arr: PolygonArray<i32> = vec![
+            Some(p0()),
+            Some(reversed(p0())),
+            None,
+            Some(p1()),
+            Some(reversed(p1())),
+        ]
+        .into();
+
+        let signed = arr.signed_area();
+        assert_eq!(signed.value(0).abs(), 28.);
+        assert_eq!(signed.value(1), -signed.value(0));
+        assert_eq!(signed.value(3).abs(), 18.);
+        assert_eq!(signed.value(4), -signed.value(3));
+
+        let unsigned = arr.unsigned_area();
+        assert_eq!(unsigned.value(0), 28.);
+        assert_eq!(unsigned.value(1), 28.);
+        assert_eq!(unsigned.value(3), 18.);
+        assert_eq!(unsigned.value(4), 18.);
+
+        assert!(signed.is_null(2));
+        assert!(unsigned.is_null(2));
+
+        let multi_polygons: MultiPolygonArray<i32> = vec![
+            Some(geo::MultiPolygon::new(vec![p0()])),
+            Some(geo::MultiPolygon::new(vec![reversed(p0())])),
+            None,
+        ]
+        .into();
+        assert_eq!(
+            multi_polygons.signed_area(),
+            Float64Array::from(vec![Some(signed.value(0)), Some(signed.value(1)), None])
+        );
+        assert_eq!(
+            multi_polygons.unsigned_area(),
+            Float64Array::from(vec![Some(28.), Some(28.), None])
+        );
+    }
+
+    #[test]
+    fn non_areal_geometries_have_zero_area() {
+        let points: PointArray = vec![Some(point::p0()), None].into();
+        let expected = Float64Array::from(vec![Some(0.), None]);
+        assert_eq!(points.signed_area(), expected);
+        assert_eq!(points.unsigned_area(), expected);
+
+        let line_strings: LineStringArray<i32> = vec![Some(linestring::ls0()), None].into();
+        assert_eq!(line_strings.signed_area(), expected);
+        assert_eq!(line_strings.unsigned_area(), expected);
+
+        let geometries = GeometryArray::LineString(line_strings);
+        assert_eq!(geometries.unsigned_area(), expected);
+        let geometries = GeometryArray::Polygon(p_array());
+        assert_eq!(geometries.unsigned_area(), p_array().unsigned_area());
+    }
+
+    #[test]
+    fn geometry_collections() {
+        let collection = geo::GeometryCollection::new_from(vec![
+            point::p0().into(),
+            linestring::ls0().into(),
+            p0().into(),
+            p1().into(),
+        ]);
+        let arr: GeometryCollectionArray<i32> = vec![Some(collection), None].try_into().unwrap();
+        let expected = Float64Array::from(vec![Some(28. + 18.), None]);
+        assert_eq!(arr.unsigned_area(), expected);
+        assert_eq!(
+            GeometryArray::GeometryCollection(Box::new(arr)).area(),
+            expected
+        );
+    }
 }
@@ -1,16 +1,24 @@
+use crate::array::rect::MutableRectArray;
 use crate::array::*;
+use crate::buffer::OffsetBufferUtils;
+use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
+use arrow_buffer::{BooleanBufferBuilder, NullBuffer};
 use geo::algorithm::bounding_rect::BoundingRect as GeoBoundingRect;
-use geo::Polygon;
 
-/// Calculation of the bounding rectangle of a geometry.
-pub trait BoundingRect<O: OffsetSizeTrait> {
-    /// Return the bounding rectangle of a geometry
+/// Calculation of the bounding rectangle of each geometry in an array.
+///
+/// The output [`RectArray`] has one rectangle per input geometry, laid out as minx, miny, maxx,
+/// maxy. Null geometries and empty geometries, which have no bounds, are null in the output.
+pub trait BoundingRect {
+    /// Return the bounding rectangle of each geometry
     ///
     /// # Examples
     ///
     /// ```
-    /// use geo::BoundingRect;
+    /// use geoarrow2::algorithm::geo::BoundingRect;
+    /// use geoarrow2::array::LineStringArray;
+    /// use geoarrow2::trait_::GeoArrayAccessor;
     /// use geo::line_string;
     ///
     /// let line_string = line_string![
@@ -18,55 +26,252 @@ pub trait BoundingRect<O: OffsetSizeTrait> {
     ///     (x: 42.02f64, y: 116.34),
     ///     (x: 42.02f64, y: 118.34),
     /// ];
+    /// let array: LineStringArray<i32> = vec![line_string].into();
     ///
-    /// let bounding_rect = line_string.bounding_rect().unwrap();
+    /// let bounding_rect = array.bounding_rect().value_as_geo(0);
     ///
     /// assert_eq!(40.02f64, bounding_rect.min().x);
     /// assert_eq!(42.02f64, bounding_rect.max().x);
     /// assert_eq!(116.34, bounding_rect.min().y);
     /// assert_eq!(118.34, bounding_rect.max().y);
     /// ```
-    fn bounding_rect(&self) -> PolygonArray<O>;
+    fn bounding_rect(&self) -> RectArray;
 }
 
-impl<O: OffsetSizeTrait> BoundingRect<O> for PointArray {
-    fn bounding_rect(&self) -> PolygonArray<O> {
-        let output_geoms: Vec<Option<Polygon>> = self
-            .iter_geo()
-            .map(|maybe_g| maybe_g.map(|geom| geom.bounding_rect().to_polygon()))
-            .collect();
+/// Build a [`RectArray`] from the range of coordinates that makes up each geometry.
+///
+/// The coordinates of a geometry are contiguous in its [`CoordBuffer`], so the bounds of every
+/// geometry type are the bounds of a single coordinate range. Ranges of null geometries are not
+/// read. The input validity is reused as is unless some geometry is empty.
+fn bounding_rect_from_coord_ranges(
+    coords: &CoordBuffer,
+    validity: Option<&NullBuffer>,
+    len: usize,
+    coord_range: impl Fn(usize) -> (usize, usize),
+) -> RectArray {
+    let mut values = Vec::with_capacity(len * 4);
+    // Only allocated once the first empty geometry is found
+    let mut empty_validity: Option<BooleanBufferBuilder> = None;
 
-        output_geoms.into()
+    for geom_idx in 0..len {
+        let is_valid = validity.map_or(true, |validity| validity.is_valid(geom_idx));
+        let (start, end) = if is_valid {
+            coord_range(geom_idx)
+        } else {
+            (0, 0)
+        };
+
+        if is_valid && start == end && empty_validity.is_none() {
+            let mut builder = BooleanBufferBuilder::new(len);
+            for prev_idx in 0..geom_idx {
+                builder.append(validity.map_or(true, |validity| validity.is_valid(prev_idx)));
+            }
+            empty_validity = Some(builder);
+        }
+        if let Some(builder) = empty_validity.as_mut() {
+            builder.append(start != end);
+        }
+
+        if start == end {
+            // Null slots of a fixed size list still hold values
+            values.extend_from_slice(&[0., 0., 0., 0.]);
+            continue;
+        }
+
+        let (mut minx, mut miny) = (f64::INFINITY, f64::INFINITY);
+        let (mut maxx, mut maxy) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for coord_idx in start..end {
+            let (x, y) = (coords.get_x(coord_idx), coords.get_y(coord_idx));
+            minx = minx.min(x);
+            miny = miny.min(y);
+            maxx = maxx.max(x);
+            maxy = maxy.max(y);
+        }
+        values.extend_from_slice(&[minx, miny, maxx, maxy]);
+    }
+
+    let validity = match empty_validity {
+        Some(mut builder) => Some(NullBuffer::new(builder.finish())),
+        None => validity.cloned(),
+    };
+    RectArray::new(values.into(), validity)
+}
+
+impl BoundingRect for PointArray {
+    fn bounding_rect(&self) -> RectArray {
+        bounding_rect_from_coord_ranges(&self.coords, self.validity(), self.len(), |geom_idx| {
+            (geom_idx, geom_idx + 1)
+        })
     }
 }
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
+/// Implementation for arrays whose geom offsets point directly into the coordinates
+macro_rules! one_level_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> BoundingRect for $type {
+            fn bounding_rect(&self) -> RectArray {
+                bounding_rect_from_coord_ranges(
+                    &self.coords,
+                    self.validity(),
+                    self.len(),
+                    |geom_idx| self.geom_offsets.start_end(geom_idx),
+                )
+            }
+        }
+    };
+}
+
+one_level_impl!(LineStringArray<O>);
+one_level_impl!(MultiPointArray<O>);
+
+/// Implementation for arrays whose geom offsets point into the ring offsets
+macro_rules! two_level_impl {
     ($type:ty) => {
-        impl<O: OffsetSizeTrait> BoundingRect<O> for $type {
-            fn bounding_rect(&self) -> PolygonArray<O> {
-                let output_geoms: Vec<Option<Polygon>> = self
+        impl<O: OffsetSizeTrait> BoundingRect for $type {
+            fn bounding_rect(&self) -> RectArray {
+                bounding_rect_from_coord_ranges(
+                    &self.coords,
+                    self.validity(),
+                    self.len(),
+                    |geom_idx| {
+                        let (start, end) = self.geom_offsets.start_end(geom_idx);
+                        (
+                            self.ring_offsets[start].to_usize().unwrap(),
+                            self.ring_offsets[end].to_usize().unwrap(),
+                        )
+                    },
+                )
+            }
+        }
+    };
+}
+
+two_level_impl!(PolygonArray<O>);
+two_level_impl!(MultiLineStringArray<O>);
+
+impl<O: OffsetSizeTrait> BoundingRect for MultiPolygonArray<O> {
+    fn bounding_rect(&self) -> RectArray {
+        bounding_rect_from_coord_ranges(&self.coords, self.validity(), self.len(), |geom_idx| {
+            let (start, end) = self.geom_offsets.start_end(geom_idx);
+            let polygon_start = self.polygon_offsets[start].to_usize().unwrap();
+            let polygon_end = self.polygon_offsets[end].to_usize().unwrap();
+            (
+                self.ring_offsets[polygon_start].to_usize().unwrap(),
+                self.ring_offsets[polygon_end].to_usize().unwrap(),
+            )
+        })
+    }
+}
+
+impl BoundingRect for RectArray {
+    fn bounding_rect(&self) -> RectArray {
+        self.clone()
+    }
+}
+
+/// Implementation for arrays without a single coordinate buffer, which goes through geo objects
+macro_rules! geo_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> BoundingRect for $type {
+            fn bounding_rect(&self) -> RectArray {
+                let output_geoms: Vec<Option<geo::Rect>> = self
                     .iter_geo()
-                    .map(|maybe_g| {
-                        maybe_g.and_then(|geom| geom.bounding_rect().map(|rect| rect.to_polygon()))
-                    })
+                    .map(|maybe_g| maybe_g.and_then(|geom| geom.bounding_rect()))
                     .collect();
 
-                output_geoms.into()
+                MutableRectArray::from(output_geoms).into()
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>);
-iter_geo_impl!(PolygonArray<O>);
-iter_geo_impl!(MultiPointArray<O>);
-iter_geo_impl!(MultiLineStringArray<O>);
-iter_geo_impl!(MultiPolygonArray<O>);
-iter_geo_impl!(WKBArray<O>);
+geo_impl!(MixedGeometryArray<O>);
+geo_impl!(GeometryCollectionArray<O>);
+geo_impl!(WKBArray<O>);
 
-impl<O: OffsetSizeTrait> BoundingRect<O> for GeometryArray<O> {
-    crate::geometry_array_delegate_impl! {
-        fn bounding_rect(&self) -> PolygonArray<O>;
+impl<O: OffsetSizeTrait> BoundingRect for GeometryArray<O> {
+    fn bounding_rect(&self) -> RectArray {
+        match self {
+            GeometryArray::Point(arr) => arr.bounding_rect(),
+            GeometryArray::LineString(arr) => arr.bounding_rect(),
+            GeometryArray::Polygon(arr) => arr.bounding_rect(),
+            GeometryArray::MultiPoint(arr) => arr.bounding_rect(),
+            GeometryArray::MultiLineString(arr) => arr.bounding_rect(),
+            GeometryArray::MultiPolygon(arr) => arr.bounding_rect(),
+            GeometryArray::GeometryCollection(arr) => arr.bounding_rect(),
+            GeometryArray::Rect(arr) => arr.bounding_rect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::linestring::{ls0, ls1};
+    use crate::test::point::{p0, p1};
+    use crate::trait_::GeoArrayAccessor;
+    use geo::{coord, polygon, Rect};
+
+    #[test]
+    fn linestring_bounds() {
+        let arr: LineStringArray<i32> = vec![Some(ls0()), None, Some(ls1())].into();
+        let result = arr.bounding_rect();
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            result.value_as_geo(0),
+            Rect::new(coord! { x: 0., y: 1. }, coord! { x: 1., y: 2. })
+        );
+        assert!(result.is_null(1));
+        assert_eq!(
+            result.value_as_geo(2),
+            Rect::new(coord! { x: 3., y: 4. }, coord! { x: 5., y: 6. })
+        );
+        // Without empty geometries the input validity is reused
+        assert_eq!(result.validity(), arr.validity());
+    }
+
+    #[test]
+    fn empty_geometries_are_null() {
+        let empty_ring = geo::Polygon::new(geo::LineString::new(vec![]), vec![]);
+        let arr: PolygonArray<i32> = vec![
+            Some(polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 3.)]),
+            Some(empty_ring),
+            None,
+        ]
+        .into();
+        let result = arr.bounding_rect();
+
+        assert_eq!(
+            result.value_as_geo(0),
+            Rect::new(coord! { x: 0., y: 0. }, coord! { x: 2., y: 3. })
+        );
+        assert!(result.is_null(1));
+        assert!(result.is_null(2));
+
+        let empty_linestrings: LineStringArray<i32> = vec![geo::LineString::new(vec![])].into();
+        assert!(empty_linestrings.bounding_rect().is_null(0));
+    }
+
+    #[test]
+    fn coord_ranges_match_geo() {
+        let points: PointArray = vec![Some(p0()), None, Some(p1())].into();
+        let multi_polygons = crate::test::multipolygon::mp_array();
+        let arrays: Vec<GeometryArray<i32>> = vec![
+            GeometryArray::Point(points),
+            GeometryArray::Polygon(crate::test::polygon::p_array()),
+            GeometryArray::MultiLineString(crate::test::multilinestring::ml_array()),
+            GeometryArray::MultiPolygon(multi_polygons),
+        ];
+
+        for arr in arrays {
+            let result = arr.bounding_rect();
+            let expected: Vec<Option<Rect>> = (0..arr.len())
+                .map(|i| arr.get_as_geo(i).and_then(|g| g.bounding_rect()))
+                .collect();
+            let actual: Vec<Option<Rect>> =
+                (0..result.len()).map(|i| result.get_as_geo(i)).collect();
+            assert_eq!(actual, expected);
+        }
     }
 }
@@ -1,6 +1,6 @@
 use crate::array::{
-    GeometryArray, LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray,
-    MutablePointArray, PointArray, PolygonArray, WKBArray,
+    GeometryArray, GeometryCollectionArray, LineStringArray, MultiLineStringArray, MultiPointArray,
+    MultiPolygonArray, MutablePointArray, PointArray, PolygonArray, RectArray, WKBArray,
 };
 use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
@@ -43,10 +43,23 @@ iter_geo_impl!(PolygonArray<O>);
 iter_geo_impl!(MultiPointArray<O>);
 iter_geo_impl!(MultiLineStringArray<O>);
 iter_geo_impl!(MultiPolygonArray<O>);
+iter_geo_impl!(GeometryCollectionArray<O>);
 iter_geo_impl!(WKBArray<O>);
 
+impl Center for RectArray {
+    fn center(&self) -> PointArray {
+        let mut output_array = MutablePointArray::with_capacity(self.len());
+        self.iter_geo().for_each(|maybe_g| {
+            output_array.push_point(maybe_g.map(|rect| geo::Point(rect.center())).as_ref())
+        });
+        output_array.into()
+    }
+}
+
 impl<O: OffsetSizeTrait> Center for GeometryArray<O> {
     crate::geometry_array_delegate_impl! {
+        with_rect;
+
         fn center(&self) -> PointArray;
     }
 }
@@ -1,10 +1,20 @@
+use crate::algorithm::native::OutputOptions;
 use crate::array::{
-    GeometryArray, LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray,
-    MutablePointArray, PointArray, PolygonArray, WKBArray,
+    GeometryArray, GeometryCollectionArray, LineStringArray, MultiLineStringArray, MultiPointArray,
+    MultiPolygonArray, MutablePointArray, PointArray, PolygonArray, RectArray, WKBArray,
 };
+use crate::buffer::OffsetBufferUtils;
+#[cfg(feature = "geozero")]
+use crate::trait_::GeoArrayAccessor;
 use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
 use geo::algorithm::centroid::Centroid as GeoCentroid;
+#[cfg(feature = "geozero")]
+use geo::dimensions::Dimensions;
+#[cfg(feature = "geozero")]
+use geo::{EuclideanLength, HasDimensions, MapCoords};
+#[cfg(feature = "geozero")]
+use std::cmp::Ordering;
 
 /// Calculation of the centroid.
 /// The centroid is the arithmetic mean position of all points in the shape.
@@ -59,12 +69,53 @@ pub trait Centroid {
     /// );
     /// ```
     fn centroid(&self) -> PointArray;
+
+    /// Compute the centroids, building the output in the layout given by `options`.
+    fn centroid_with_options(&self, options: &OutputOptions) -> PointArray;
 }
 
 impl Centroid for PointArray {
     fn centroid(&self) -> PointArray {
         self.clone()
     }
+
+    fn centroid_with_options(&self, options: &OutputOptions) -> PointArray {
+        self.clone().into_coord_type(options.coord_type.clone())
+    }
+}
+
+// The centroid of a MultiPoint is the mean of its coordinates, so we compute it directly from the
+// coordinate buffer instead of materializing geo objects.
+impl<O: OffsetSizeTrait> Centroid for MultiPointArray<O> {
+    fn centroid(&self) -> PointArray {
+        self.centroid_with_options(&OutputOptions::from_array(self))
+    }
+
+    fn centroid_with_options(&self, options: &OutputOptions) -> PointArray {
+        let mut output_array =
+            MutablePointArray::with_capacity_and_coord_type(self.len(), options.coord_type.clone());
+        for geom_idx in 0..self.len() {
+            let (start_idx, end_idx) = self.geom_offsets.start_end(geom_idx);
+            if self.is_null(geom_idx) || start_idx == end_idx {
+                output_array.push_null();
+                continue;
+            }
+
+            let mut sum_x = 0.;
+            let mut sum_y = 0.;
+            for coord_idx in start_idx..end_idx {
+                sum_x += self.coords.get_x(coord_idx);
+                sum_y += self.coords.get_y(coord_idx);
+            }
+
+            let num_points = (end_idx - start_idx) as f64;
+            output_array.push_point(Some(&geo::Point::new(
+                sum_x / num_points,
+                sum_y / num_points,
+            )));
+        }
+        output_array.into()
+    }
 }
 
 /// Implementation that iterates over geo objects
@@ -72,7 +123,14 @@ macro_rules! iter_geo_impl {
     ($type:ty) => {
         impl<O: OffsetSizeTrait> Centroid for $type {
             fn centroid(&self) -> PointArray {
-                let mut output_array = MutablePointArray::with_capacity(self.len());
+                self.centroid_with_options(&OutputOptions::from_array(self))
+            }
+
+            fn centroid_with_options(&self, options: &OutputOptions) -> PointArray {
+                let mut output_array = MutablePointArray::with_capacity_and_coord_type(
+                    self.len(),
+                    options.coord_type.clone(),
+                );
                 self.iter_geo().for_each(|maybe_g| {
                     output_array.push_point(maybe_g.and_then(|g| g.centroid()).as_ref())
                 });
@@ -84,13 +142,473 @@ macro_rules! iter_geo_impl {
 
 iter_geo_impl!(LineStringArray<O>);
 iter_geo_impl!(PolygonArray<O>);
-iter_geo_impl!(MultiPointArray<O>);
 iter_geo_impl!(MultiLineStringArray<O>);
 iter_geo_impl!(MultiPolygonArray<O>);
+iter_geo_impl!(GeometryCollectionArray<O>);
+
+#[cfg(not(feature = "geozero"))]
 iter_geo_impl!(WKBArray<O>);
 
+// The centroid of a rect is its center
+impl Centroid for RectArray {
+    fn centroid(&self) -> PointArray {
+        self.centroid_with_options(&OutputOptions::from_array(self))
+    }
+
+    fn centroid_with_options(&self, options: &OutputOptions) -> PointArray {
+        let mut output_array =
+            MutablePointArray::with_capacity_and_coord_type(self.len(), options.coord_type.clone());
+        self.iter_geo().for_each(|maybe_g| {
+            output_array.push_point(maybe_g.map(|rect| geo::Point(rect.center())).as_ref())
+        });
+        output_array.into()
+    }
+}
+
+// WKB geometries are streamed through geozero into a centroid accumulator, so only the ring or
+// line string being read is buffered instead of the whole geometry.
+#[cfg(feature = "geozero")]
+impl<O: OffsetSizeTrait> Centroid for WKBArray<O> {
+    fn centroid(&self) -> PointArray {
+        self.centroid_with_options(&OutputOptions::from_array(self))
+    }
+
+    fn centroid_with_options(&self, options: &OutputOptions) -> PointArray {
+        let mut output_array =
+            MutablePointArray::with_capacity_and_coord_type(self.len(), options.coord_type.clone());
+        let mut processor = CentroidProcessor::default();
+        for i in 0..self.len() {
+            if self.is_null(i) {
+                output_array.push_null();
+                continue;
+            }
+
+            processor.reset();
+            let wkb = self.value(i);
+            let mut buf = wkb.as_ref();
+            geozero::wkb::process_wkb_geom(&mut buf, &mut processor).unwrap();
+            output_array.push_point(processor.op.centroid().as_ref());
+        }
+        output_array.into()
+    }
+}
+
 impl<O: OffsetSizeTrait> Centroid for GeometryArray<O> {
-    crate::geometry_array_delegate_impl! {
-        fn centroid(&self) -> PointArray;
+    fn centroid(&self) -> PointArray {
+        match self {
+            GeometryArray::Point(arr) => arr.centroid(),
+            GeometryArray::LineString(arr) => arr.centroid(),
+            GeometryArray::Polygon(arr) => arr.centroid(),
+            GeometryArray::MultiPoint(arr) => arr.centroid(),
+            GeometryArray::MultiLineString(arr) => arr.centroid(),
+            GeometryArray::MultiPolygon(arr) => arr.centroid(),
+            GeometryArray::GeometryCollection(arr) => arr.centroid(),
+            GeometryArray::Rect(arr) => arr.centroid(),
+        }
+    }
+
+    fn centroid_with_options(&self, options: &OutputOptions) -> PointArray {
+        match self {
+            GeometryArray::Point(arr) => arr.centroid_with_options(options),
+            GeometryArray::LineString(arr) => arr.centroid_with_options(options),
+            GeometryArray::Polygon(arr) => arr.centroid_with_options(options),
+            GeometryArray::MultiPoint(arr) => arr.centroid_with_options(options),
+            GeometryArray::MultiLineString(arr) => arr.centroid_with_options(options),
+            GeometryArray::MultiPolygon(arr) => arr.centroid_with_options(options),
+            GeometryArray::GeometryCollection(arr) => arr.centroid_with_options(options),
+            GeometryArray::Rect(arr) => arr.centroid_with_options(options),
+        }
+    }
+}
+
+/// A running centroid, weighted by the highest dimension seen so far.
+///
+/// This follows the `CentroidOperation` in [`geo`], so that streamed geometries get the same
+/// centroid as the materialized geo objects.
+#[cfg(feature = "geozero")]
+#[derive(Debug, Default, Clone, Copy)]
+struct CentroidOperation(Option<WeightedCentroid>);
+
+#[cfg(feature = "geozero")]
+#[derive(Debug, Clone, Copy)]
+struct WeightedCentroid {
+    weight: f64,
+    accumulated: geo::Coord,
+    dimensions: Dimensions,
+}
+
+#[cfg(feature = "geozero")]
+impl WeightedCentroid {
+    fn add_assign(&mut self, other: WeightedCentroid) {
+        match self.dimensions.cmp(&other.dimensions) {
+            Ordering::Less => *self = other,
+            Ordering::Greater => {}
+            Ordering::Equal => {
+                self.accumulated = self.accumulated + other.accumulated;
+                self.weight += other.weight;
+            }
+        }
+    }
+
+    fn sub_assign(&mut self, other: WeightedCentroid) {
+        match self.dimensions.cmp(&other.dimensions) {
+            Ordering::Less => *self = other,
+            Ordering::Greater => {}
+            Ordering::Equal => {
+                self.accumulated = self.accumulated - other.accumulated;
+                self.weight -= other.weight;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "geozero")]
+impl CentroidOperation {
+    fn centroid(&self) -> Option<geo::Point> {
+        self.0
+            .map(|weighted| geo::Point::from(weighted.accumulated / weighted.weight))
+    }
+
+    fn add_centroid(&mut self, dimensions: Dimensions, centroid: geo::Coord, weight: f64) {
+        self.add_weighted_centroid(WeightedCentroid {
+            weight,
+            accumulated: centroid * weight,
+            dimensions,
+        });
+    }
+
+    fn add_weighted_centroid(&mut self, other: WeightedCentroid) {
+        match self.0.as_mut() {
+            Some(centroid) => centroid.add_assign(other),
+            None => self.0 = Some(other),
+        }
+    }
+
+    fn add_coord(&mut self, coord: geo::Coord) {
+        self.add_centroid(Dimensions::ZeroDimensional, coord, 1.);
+    }
+
+    fn add_line(&mut self, line: geo::Line) {
+        match line.dimensions() {
+            Dimensions::ZeroDimensional => self.add_coord(line.start),
+            _ => self.add_centroid(
+                Dimensions::OneDimensional,
+                line.centroid().0,
+                line.euclidean_length(),
+            ),
+        }
+    }
+
+    fn add_line_string(&mut self, line_string: &geo::LineString) {
+        if line_string.0.len() == 1 {
+            self.add_coord(line_string.0[0]);
+            return;
+        }
+
+        for line in line_string.lines() {
+            self.add_line(line);
+        }
+    }
+
+    /// Add a closed ring, weighted by its area, or as a point or line string if it has no area.
+    fn add_ring(&mut self, ring: &geo::LineString) {
+        let area = ring_signed_area(ring);
+        if area == 0. {
+            match ring.dimensions() {
+                Dimensions::Empty => {}
+                Dimensions::ZeroDimensional => self.add_coord(ring.0[0]),
+                _ => self.add_line_string(ring),
+            }
+            return;
+        }
+
+        let shift = ring.0[0];
+        let accumulated = ring.lines().fold(geo::Coord::zero(), |accum, line| {
+            let line = line.map_coords(|c| c - shift);
+            accum + (line.end + line.start) * line.determinant()
+        });
+        let centroid = accumulated / (6. * area) + shift;
+        self.add_centroid(Dimensions::TwoDimensional, centroid, area.abs());
+    }
+}
+
+/// The signed area of a ring, computed like geo's polygon area, which is zero for rings that are
+/// not closed or have fewer than three coordinates.
+#[cfg(feature = "geozero")]
+fn ring_signed_area(ring: &geo::LineString) -> f64 {
+    if ring.0.len() < 3 || !ring.is_closed() {
+        return 0.;
+    }
+
+    let shift = ring.0[0];
+    let twice_area = ring.lines().fold(0., |accum, line| {
+        accum + line.map_coords(|c| c - shift).determinant()
+    });
+    twice_area / 2.
+}
+
+/// The rings of the polygon currently being streamed.
+#[cfg(feature = "geozero")]
+#[derive(Debug)]
+struct PolygonState {
+    exterior_ring: geo::LineString,
+    exterior: CentroidOperation,
+    interiors: CentroidOperation,
+}
+
+/// A [`GeomProcessor`](geozero::GeomProcessor) that accumulates the centroid of one geometry.
+#[cfg(feature = "geozero")]
+#[derive(Debug)]
+struct CentroidProcessor {
+    op: CentroidOperation,
+    /// Coordinates of the line string or ring being read. Reused between geometries.
+    line_string: geo::LineString,
+    in_line_string: bool,
+    polygon: Option<PolygonState>,
+}
+
+#[cfg(feature = "geozero")]
+impl Default for PolygonState {
+    fn default() -> Self {
+        Self {
+            exterior_ring: geo::LineString::new(vec![]),
+            exterior: CentroidOperation::default(),
+            interiors: CentroidOperation::default(),
+        }
+    }
+}
+
+#[cfg(feature = "geozero")]
+impl Default for CentroidProcessor {
+    fn default() -> Self {
+        Self {
+            op: CentroidOperation::default(),
+            line_string: geo::LineString::new(vec![]),
+            in_line_string: false,
+            polygon: None,
+        }
+    }
+}
+
+#[cfg(feature = "geozero")]
+impl CentroidProcessor {
+    fn reset(&mut self) {
+        self.op = CentroidOperation::default();
+        self.line_string.0.clear();
+        self.in_line_string = false;
+        self.polygon = None;
+    }
+}
+
+#[cfg(feature = "geozero")]
+impl geozero::GeomProcessor for CentroidProcessor {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> geozero::error::Result<()> {
+        let coord = geo::coord! { x: x, y: y };
+        if self.in_line_string {
+            self.line_string.0.push(coord);
+        } else {
+            self.op.add_coord(coord);
+        }
+        Ok(())
+    }
+
+    fn linestring_begin(
+        &mut self,
+        _tagged: bool,
+        size: usize,
+        _idx: usize,
+    ) -> geozero::error::Result<()> {
+        self.line_string.0.clear();
+        self.line_string.0.reserve(size);
+        self.in_line_string = true;
+        Ok(())
+    }
+
+    fn linestring_end(&mut self, _tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        self.in_line_string = false;
+        match self.polygon.as_mut() {
+            Some(polygon) => {
+                // geo::Polygon::new closes its rings
+                self.line_string.close();
+                if idx == 0 {
+                    polygon.exterior.add_ring(&self.line_string);
+                    std::mem::swap(&mut polygon.exterior_ring, &mut self.line_string);
+                } else {
+                    polygon.interiors.add_ring(&self.line_string);
+                }
+            }
+            None => self.op.add_line_string(&self.line_string),
+        }
+        Ok(())
+    }
+
+    fn polygon_begin(
+        &mut self,
+        _tagged: bool,
+        _size: usize,
+        _idx: usize,
+    ) -> geozero::error::Result<()> {
+        let mut polygon = self.polygon.take().unwrap_or_default();
+        polygon.exterior_ring.0.clear();
+        polygon.exterior = CentroidOperation::default();
+        polygon.interiors = CentroidOperation::default();
+        self.polygon = Some(polygon);
+        Ok(())
+    }
+
+    fn polygon_end(&mut self, _tagged: bool, _idx: usize) -> geozero::error::Result<()> {
+        let polygon = self.polygon.take().unwrap();
+        if let Some(mut weighted) = polygon.exterior.0 {
+            if let Some(interiors) = polygon.interiors.0 {
+                weighted.sub_assign(interiors);
+                if weighted.weight == 0. {
+                    // The interiors cover the exterior, so the polygon degenerates to its exterior
+                    // line string
+                    self.op.add_line_string(&polygon.exterior_ring);
+                    return Ok(());
+                }
+            }
+            self.op.add_weighted_centroid(weighted);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::CoordType;
+    use crate::trait_::GeoArrayAccessor;
+    use approx::assert_relative_eq;
+    use geo::{point, MultiPoint};
+
+    #[test]
+    fn multi_point_centroid_matches_geo() {
+        let multi_points: Vec<Option<MultiPoint>> = vec![
+            Some(
+                vec![
+                    point!(x: 0., y: 0.),
+                    point!(x: 4., y: 0.),
+                    point!(x: 2., y: 3.),
+                ]
+                .into(),
+            ),
+            None,
+            Some(vec![point!(x: -1.5, y: 2.25)].into()),
+            Some(MultiPoint::new(vec![])),
+            Some(
+                vec![
+                    point!(x: 1., y: 1.),
+                    point!(x: 1., y: 1.),
+                    point!(x: 7., y: -5.),
+                ]
+                .into(),
+            ),
+        ];
+        let array: MultiPointArray<i32> = multi_points.clone().into();
+        let centroids = array.centroid();
+
+        for (i, multi_point) in multi_points.iter().enumerate() {
+            match multi_point.as_ref().and_then(|mp| mp.centroid()) {
+                Some(expected) => {
+                    let actual = centroids.value_as_geo(i);
+                    assert_relative_eq!(actual.x(), expected.x());
+                    assert_relative_eq!(actual.y(), expected.y());
+                }
+                None => assert!(centroids.is_null(i)),
+            }
+        }
+    }
+
+    #[test]
+    fn geometry_collection_centroid() {
+        use geo::{polygon, Geometry, GeometryCollection};
+
+        // The point has a lower dimension than the square, so it doesn't move the centroid
+        let collection = GeometryCollection(vec![
+            Geometry::Point(point!(x: 10., y: 10.)),
+            Geometry::Polygon(polygon![
+                (x: 0., y: 0.),
+                (x: 2., y: 0.),
+                (x: 2., y: 2.),
+                (x: 0., y: 2.),
+                (x: 0., y: 0.),
+            ]),
+        ]);
+        let array: GeometryCollectionArray<i32> = vec![Some(collection), None].try_into().unwrap();
+        let centroids = GeometryArray::GeometryCollection(Box::new(array)).centroid();
+
+        assert_eq!(centroids.get_as_geo(0), Some(point!(x: 1., y: 1.)));
+        assert!(centroids.is_null(1));
+    }
+
+    #[test]
+    fn output_coord_type() {
+        let array = crate::test::polygon::p_array();
+        assert_eq!(array.centroid().coord_type(), CoordType::Interleaved);
+
+        let options = OutputOptions::default().with_coord_type(CoordType::Separated);
+        let centroids = array.centroid_with_options(&options);
+        assert_eq!(centroids.coord_type(), CoordType::Separated);
+        assert_eq!(
+            centroids,
+            array.centroid().into_coord_type(CoordType::Separated)
+        );
+
+        let separated = array.into_coord_type(CoordType::Separated);
+        assert_eq!(separated.centroid().coord_type(), CoordType::Separated);
+    }
+
+    #[test]
+    fn point_centroid_is_identity() {
+        let array = crate::test::point::point_array();
+        assert_eq!(array.centroid(), array);
+    }
+
+    #[cfg(feature = "geozero")]
+    #[test]
+    fn wkb_centroid_matches_geo() {
+        use geo::{line_string, polygon, Geometry, GeometryCollection, MultiPolygon};
+
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        let with_hole = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 6.), (x: 0., y: 6.)],
+            interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)]],
+        );
+        // The interior covers the whole exterior, so the centroid is that of the exterior ring
+        let covered = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+            interiors: [[(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)]],
+        );
+        let flat = polygon![(x: 0., y: 0.), (x: 5., y: 0.), (x: 2., y: 0.)];
+        let line = line_string![(x: 1., y: 1.), (x: 4., y: 5.), (x: 4., y: 9.)];
+
+        let geometries: Vec<Option<Geometry>> = vec![
+            Some(point!(x: 1.5, y: -2.).into()),
+            None,
+            Some(line.clone().into()),
+            Some(line_string![(x: 3., y: 3.)].into()),
+            Some(square.clone().into()),
+            Some(with_hole.clone().into()),
+            Some(covered.into()),
+            Some(flat.into()),
+            Some(MultiPoint::new(vec![point!(x: 0., y: 0.), point!(x: 3., y: 1.)]).into()),
+            Some(MultiPolygon::new(vec![square.clone(), with_hole]).into()),
+            Some(Geometry::GeometryCollection(GeometryCollection::new_from(
+                vec![point!(x: 100., y: 100.).into(), line.into(), square.into()],
+            ))),
+        ];
+        let array: WKBArray<i32> = geometries.clone().into();
+        let centroids = array.centroid();
+
+        for (i, geometry) in geometries.iter().enumerate() {
+            let expected = geometry.as_ref().and_then(|g| g.centroid());
+            assert_eq!(centroids.get_as_geo(i), expected, "geometry {i}");
+        }
     }
 }
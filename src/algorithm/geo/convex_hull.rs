@@ -1,10 +1,12 @@
+use crate::algorithm::native::OutputOptions;
 use crate::array::{
-    GeometryArray, LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray,
-    PointArray, PolygonArray, WKBArray,
+    GeometryArray, GeometryCollectionArray, LineStringArray, MixedGeometryArray,
+    MultiLineStringArray, MultiPointArray, MultiPolygonArray, MutablePolygonArray, PointArray,
+    PolygonArray, PolygonCapacity, RectArray, WKBArray,
 };
 use arrow_array::OffsetSizeTrait;
-use geo::algorithm::convex_hull::ConvexHull as GeoConvexHull;
-use geo::Polygon;
+use geo::algorithm::convex_hull::{quick_hull, ConvexHull as GeoConvexHull};
+use geo::{Coord, CoordsIter, Polygon};
 
 /// Returns the convex hull of a Polygon. The hull is always oriented counter-clockwise.
 ///
@@ -42,8 +44,16 @@ use geo::Polygon;
 /// let res = poly.convex_hull();
 /// assert_eq!(res.exterior(), &correct_hull);
 /// ```
+///
+/// Each geometry maps to the hull of all of its coordinates, so the interior rings of polygons
+/// don't change the output. Null geometries are null in the output, as are geometries whose hull
+/// has fewer than three vertices, such as a single point, a two-point line or collinear points,
+/// since those hulls are not valid polygons.
 pub trait ConvexHull<O: OffsetSizeTrait> {
     fn convex_hull(&self) -> PolygonArray<O>;
+
+    /// Compute the convex hulls, building the output in the layout given by `options`.
+    fn convex_hull_with_options(&self, options: &OutputOptions) -> PolygonArray<O>;
 }
 
 /// Implementation that iterates over geo objects
@@ -51,37 +61,148 @@ macro_rules! iter_geo_impl {
     ($type:ty) => {
         impl<O: OffsetSizeTrait> ConvexHull<O> for $type {
             fn convex_hull(&self) -> PolygonArray<O> {
+                self.convex_hull_with_options(&OutputOptions::from_array(self))
+            }
+
+            fn convex_hull_with_options(&self, options: &OutputOptions) -> PolygonArray<O> {
                 let output_geoms: Vec<Option<Polygon>> = self
                     .iter_geo()
-                    .map(|maybe_g| maybe_g.map(|geom| geom.convex_hull()))
+                    .map(|maybe_g| maybe_g.and_then(|geom| non_degenerate(geom.convex_hull())))
                     .collect();
 
-                output_geoms.into()
+                build_polygons(&output_geoms, options)
             }
         }
     };
 }
 
+/// The hull, or `None` if it has fewer than three distinct vertices
+fn non_degenerate(hull: Polygon) -> Option<Polygon> {
+    // The exterior ring is closed, so it repeats its first vertex
+    (hull.exterior().0.len() > 3).then_some(hull)
+}
+
+/// Build the hulls in the requested layout, sized so that no buffer is reallocated.
+fn build_polygons<O: OffsetSizeTrait>(
+    geoms: &[Option<Polygon>],
+    options: &OutputOptions,
+) -> PolygonArray<O> {
+    // Hulls have no interior rings
+    let hulls = geoms.iter().flatten();
+    let capacity = PolygonCapacity::new(
+        hulls.clone().map(|hull| hull.exterior().0.len()).sum(),
+        hulls.count(),
+        geoms.len(),
+    );
+
+    let mut output_array =
+        MutablePolygonArray::with_capacity_and_coord_type(capacity, options.coord_type.clone());
+    geoms
+        .iter()
+        .try_for_each(|maybe_hull| output_array.push_polygon(maybe_hull.as_ref()))
+        .unwrap();
+    output_array.into()
+}
+
 iter_geo_impl!(PointArray);
 iter_geo_impl!(LineStringArray<O>);
 iter_geo_impl!(PolygonArray<O>);
 iter_geo_impl!(MultiPointArray<O>);
 iter_geo_impl!(MultiLineStringArray<O>);
 iter_geo_impl!(MultiPolygonArray<O>);
+iter_geo_impl!(MixedGeometryArray<O>);
+iter_geo_impl!(GeometryCollectionArray<O>);
+iter_geo_impl!(RectArray);
 iter_geo_impl!(WKBArray<O>);
 
 impl<O: OffsetSizeTrait> ConvexHull<O> for GeometryArray<O> {
-    crate::geometry_array_delegate_impl! {
-        fn convex_hull(&self) -> PolygonArray<O>;
+    fn convex_hull(&self) -> PolygonArray<O> {
+        self.convex_hull_with_options(&OutputOptions::from_array(self))
+    }
+
+    fn convex_hull_with_options(&self, options: &OutputOptions) -> PolygonArray<O> {
+        match self {
+            GeometryArray::Point(arr) => arr.convex_hull_with_options(options),
+            GeometryArray::LineString(arr) => arr.convex_hull_with_options(options),
+            GeometryArray::Polygon(arr) => arr.convex_hull_with_options(options),
+            GeometryArray::MultiPoint(arr) => arr.convex_hull_with_options(options),
+            GeometryArray::MultiLineString(arr) => arr.convex_hull_with_options(options),
+            GeometryArray::MultiPolygon(arr) => arr.convex_hull_with_options(options),
+            GeometryArray::GeometryCollection(arr) => arr.convex_hull_with_options(options),
+            GeometryArray::Rect(arr) => arr.convex_hull_with_options(options),
+        }
+    }
+}
+
+/// The convex hull of all geometries in an array, as a single polygon.
+///
+/// Every coordinate of every non-null row goes into one hull computation, without computing a
+/// hull per row first.
+pub trait AggregateConvexHull {
+    /// Returns `None` if the array has no coordinates, e.g. if it is empty or all null. The hull
+    /// may be degenerate, as for an array of one point.
+    fn aggregate_convex_hull(&self) -> Option<Polygon>;
+}
+
+/// Implementation that collects the coordinates of geo objects
+macro_rules! aggregate_impl {
+    ($type:ty $(, $generic:ident)?) => {
+        impl$(<$generic: OffsetSizeTrait>)? AggregateConvexHull for $type {
+            fn aggregate_convex_hull(&self) -> Option<Polygon> {
+                let mut coords: Vec<Coord> = self
+                    .iter_geo()
+                    .flatten()
+                    .flat_map(|geom| geom.coords_iter().collect::<Vec<_>>())
+                    .collect();
+
+                if coords.is_empty() {
+                    return None;
+                }
+                Some(Polygon::new(quick_hull(&mut coords), vec![]))
+            }
+        }
+    };
+}
+
+aggregate_impl!(PointArray);
+aggregate_impl!(LineStringArray<O>, O);
+aggregate_impl!(PolygonArray<O>, O);
+aggregate_impl!(MultiPointArray<O>, O);
+aggregate_impl!(MultiLineStringArray<O>, O);
+aggregate_impl!(MultiPolygonArray<O>, O);
+aggregate_impl!(MixedGeometryArray<O>, O);
+aggregate_impl!(GeometryCollectionArray<O>, O);
+aggregate_impl!(RectArray);
+aggregate_impl!(WKBArray<O>, O);
+
+impl<O: OffsetSizeTrait> AggregateConvexHull for GeometryArray<O> {
+    fn aggregate_convex_hull(&self) -> Option<Polygon> {
+        match self {
+            GeometryArray::Point(arr) => arr.aggregate_convex_hull(),
+            GeometryArray::LineString(arr) => arr.aggregate_convex_hull(),
+            GeometryArray::Polygon(arr) => arr.aggregate_convex_hull(),
+            GeometryArray::MultiPoint(arr) => arr.aggregate_convex_hull(),
+            GeometryArray::MultiLineString(arr) => arr.aggregate_convex_hull(),
+            GeometryArray::MultiPolygon(arr) => arr.aggregate_convex_hull(),
+            GeometryArray::GeometryCollection(arr) => arr.aggregate_convex_hull(),
+            GeometryArray::Rect(arr) => arr.aggregate_convex_hull(),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::ConvexHull;
-    use crate::array::{LineStringArray, MultiPointArray};
+    use super::{AggregateConvexHull, ConvexHull};
+    use crate::algorithm::native::OutputOptions;
+    use crate::array::{
+        CoordBuffer, CoordType, GeometryArray, LineStringArray, MultiPointArray, PointArray,
+        PolygonArray,
+    };
+    use crate::test::polygon::p1;
     use crate::trait_::GeoArrayAccessor;
-    use geo::{line_string, polygon, MultiPoint, Point};
+    use crate::GeometryArrayTrait;
+    use arrow_array::Array;
+    use geo::{line_string, point, polygon, Area, MultiPoint, Point};
 
     #[test]
     fn convex_hull_for_multipoint() {
@@ -139,4 +260,92 @@ mod tests {
 
         assert_eq!(expected, result_array.get_as_geo(0).unwrap());
     }
+
+    #[test]
+    fn separated_output_from_interleaved_input() {
+        let input_array: MultiPointArray<i32> = vec![
+            Some(crate::test::multipoint::mp0()),
+            None,
+            Some(crate::test::multipoint::mp1()),
+        ]
+        .into();
+        assert_eq!(input_array.coord_type(), CoordType::Interleaved);
+
+        let options = OutputOptions::default().with_coord_type(CoordType::Separated);
+        let result_array = input_array.convex_hull_with_options(&options);
+        assert_eq!(result_array.coord_type(), CoordType::Separated);
+
+        let interleaved_result = input_array.convex_hull();
+        assert_eq!(interleaved_result.coord_type(), CoordType::Interleaved);
+        let cast_result: PolygonArray<i32> =
+            interleaved_result.into_coord_type(CoordType::Separated);
+        assert_eq!(result_array, cast_result);
+
+        // The separated buffers are allocated once, at their final size
+        let CoordBuffer::Separated(coords) = &result_array.coords else {
+            panic!("expected separated coordinates")
+        };
+        let num_coords = coords.len();
+        assert_eq!(coords.x.inner().capacity(), num_coords * 8);
+        assert_eq!(coords.y.inner().capacity(), num_coords * 8);
+        assert_eq!(
+            result_array.into_array_ref().get_array_memory_size(),
+            cast_result.into_array_ref().get_array_memory_size()
+        );
+    }
+
+    #[test]
+    fn hull_ignores_interior_rings() {
+        let input_array: PolygonArray<i32> = vec![Some(p1()), None].into();
+        let result_array = input_array.convex_hull();
+
+        let hull = result_array.value_as_geo(0);
+        assert!(hull.interiors().is_empty());
+        assert_eq!(hull.exterior().0.len(), 5);
+        // The hull of p1 is its 7 by 4 exterior
+        assert_eq!(hull.unsigned_area(), 28.);
+        assert!(result_array.is_null(1));
+    }
+
+    #[test]
+    fn degenerate_hulls_are_null() {
+        let points: PointArray = vec![point!(x: 0., y: 0.)].into();
+        let result_array: PolygonArray<i32> = points.convex_hull();
+        assert!(result_array.is_null(0));
+
+        let line_strings: LineStringArray<i32> = vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)],
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+        ]
+        .into();
+        let result_array = GeometryArray::LineString(line_strings).convex_hull();
+        assert!(result_array.is_null(0));
+        assert!(result_array.is_null(1));
+        assert!(result_array.is_valid(2));
+    }
+
+    #[test]
+    fn aggregate_hull() {
+        use crate::test::linestring::{ls0, ls1};
+        use geo::ConvexHull;
+
+        let input_array: LineStringArray<i32> = vec![Some(ls0()), None, Some(ls1())].into();
+        let combined = MultiPoint::from(
+            ls0()
+                .into_points()
+                .into_iter()
+                .chain(ls1().into_points())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            input_array.aggregate_convex_hull(),
+            Some(combined.convex_hull())
+        );
+
+        let empty: LineStringArray<i32> = Vec::<geo::LineString>::new().into();
+        assert_eq!(empty.aggregate_convex_hull(), None);
+        let all_null: LineStringArray<i32> = vec![None::<geo::LineString>].into();
+        assert_eq!(all_null.aggregate_convex_hull(), None);
+    }
 }
@@ -1,8 +1,10 @@
 use crate::algorithm::broadcasting::BroadcastablePrimitive;
 use crate::array::*;
+use crate::GeometryArrayTrait;
 use arrow_array::types::Float64Type;
 use arrow_array::OffsetSizeTrait;
 use geo::Densify as _Densify;
+use geo::HaversineIntermediate;
 
 /// Return a new linear geometry containing both existing and new interpolated coordinates with
 /// a maximum distance of `max_distance` between them.
@@ -25,7 +27,106 @@ pub trait Densify {
     // densify to non-self types
     type Output;
 
+    /// Densify using straight planar segments or great circle arcs, depending on the [`Edges`] of
+    /// the array's metadata.
     fn densify(&self, max_distance: BroadcastablePrimitive<Float64Type>) -> Self::Output;
+
+    /// Densify along straight planar segments, regardless of the array's metadata.
+    fn densify_euclidean(&self, max_distance: BroadcastablePrimitive<Float64Type>) -> Self::Output;
+
+    /// Densify along great circle arcs, regardless of the array's metadata. Coordinates must be
+    /// longitude and latitude and `max_distance` is in meters.
+    fn densify_haversine(&self, max_distance: BroadcastablePrimitive<Float64Type>) -> Self::Output;
+}
+
+/// Densify a geo geometry along great circle arcs.
+trait GeoDensifyHaversine {
+    fn densify_haversine(&self, max_distance: f64) -> Self;
+}
+
+impl GeoDensifyHaversine for geo::LineString {
+    fn densify_haversine(&self, max_distance: f64) -> Self {
+        let mut coords = Vec::with_capacity(self.0.len());
+        for line in self.lines() {
+            coords.push(line.start);
+            let start: geo::Point = line.start.into();
+            start
+                .haversine_intermediate_fill(&line.end.into(), max_distance, false)
+                .into_iter()
+                .for_each(|point| coords.push(point.0));
+        }
+        if let Some(last) = self.0.last() {
+            coords.push(*last);
+        }
+        geo::LineString::new(coords)
+    }
+}
+
+impl GeoDensifyHaversine for geo::Polygon {
+    fn densify_haversine(&self, max_distance: f64) -> Self {
+        geo::Polygon::new(
+            self.exterior().densify_haversine(max_distance),
+            self.interiors()
+                .iter()
+                .map(|ring| ring.densify_haversine(max_distance))
+                .collect(),
+        )
+    }
+}
+
+impl GeoDensifyHaversine for geo::MultiLineString {
+    fn densify_haversine(&self, max_distance: f64) -> Self {
+        geo::MultiLineString::new(
+            self.0
+                .iter()
+                .map(|line_string| line_string.densify_haversine(max_distance))
+                .collect(),
+        )
+    }
+}
+
+impl GeoDensifyHaversine for geo::MultiPolygon {
+    fn densify_haversine(&self, max_distance: f64) -> Self {
+        geo::MultiPolygon::new(
+            self.0
+                .iter()
+                .map(|polygon| polygon.densify_haversine(max_distance))
+                .collect(),
+        )
+    }
+}
+
+/// Close any unclosed polygon rings of a geo geometry, so that densifying also fills in the
+/// closing segment from the last vertex back to the first. Linear geometries are unchanged.
+trait CloseRings {
+    fn close_rings(self) -> Self;
+}
+
+impl CloseRings for geo::LineString {
+    fn close_rings(self) -> Self {
+        self
+    }
+}
+
+impl CloseRings for geo::MultiLineString {
+    fn close_rings(self) -> Self {
+        self
+    }
+}
+
+impl CloseRings for geo::Polygon {
+    fn close_rings(self) -> Self {
+        let (mut exterior, mut interiors) = self.into_inner();
+        exterior.close();
+        interiors.iter_mut().for_each(|ring| ring.close());
+        geo::Polygon::new(exterior, interiors)
+    }
+}
+
+impl CloseRings for geo::MultiPolygon {
+    fn close_rings(self) -> Self {
+        geo::MultiPolygon::new(self.0.into_iter().map(CloseRings::close_rings).collect())
+    }
 }
 
 /// Implementation that iterates over geo objects
@@ -35,15 +136,43 @@ macro_rules! iter_geo_impl {
             type Output = $type;
 
             fn densify(&self, max_distance: BroadcastablePrimitive<Float64Type>) -> Self::Output {
+                match self.metadata().edges {
+                    Edges::Planar => self.densify_euclidean(max_distance),
+                    Edges::Spherical => self.densify_haversine(max_distance),
+                }
+            }
+
+            fn densify_euclidean(
+                &self,
+                max_distance: BroadcastablePrimitive<Float64Type>,
+            ) -> Self::Output {
                 let output_geoms: Vec<Option<$geo_type>> = self
                     .iter_geo()
                     .zip(max_distance.into_iter())
                     .map(|(maybe_g, max_distance)| {
-                        maybe_g.map(|geom| geom.densify(max_distance.unwrap()))
+                        maybe_g.map(|geom| geom.close_rings().densify(max_distance.unwrap()))
                     })
                     .collect();
 
-                output_geoms.into()
+                let output: $type = output_geoms.into();
+                output.with_metadata(self.metadata().clone())
+            }
+
+            fn densify_haversine(
+                &self,
+                max_distance: BroadcastablePrimitive<Float64Type>,
+            ) -> Self::Output {
+                let output_geoms: Vec<Option<$geo_type>> = self
+                    .iter_geo()
+                    .zip(max_distance.into_iter())
+                    .map(|(maybe_g, max_distance)| {
+                        maybe_g
+                            .map(|geom| geom.close_rings().densify_haversine(max_distance.unwrap()))
+                    })
+                    .collect();
+
+                let output: $type = output_geoms.into();
+                output.with_metadata(self.metadata().clone())
             }
         }
     };
@@ -53,3 +182,65 @@ iter_geo_impl!(LineStringArray<O>, geo::LineString);
 iter_geo_impl!(PolygonArray<O>, geo::Polygon);
 iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
 iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::polygon::p0;
+    use crate::trait_::GeoArrayAccessor;
+    use arrow_buffer::OffsetBuffer;
+    use geo::line_string;
+
+    /// Assert that `ring` is closed and that points were inserted on its closing segment, which
+    /// for `p0()` runs along y = 45 from x = -104 back to x = -111.
+    fn assert_closing_segment_densified(ring: &geo::LineString) {
+        assert!(ring.is_closed());
+        let closing_segment_points = ring
+            .0
+            .iter()
+            .filter(|c| c.y == 45. && c.x > -111. && c.x < -104.)
+            .count();
+        assert!(closing_segment_points > 0);
+    }
+
+    #[test]
+    fn densify_polygon_closing_segment() {
+        let arr: PolygonArray<i32> = vec![p0()].into();
+        let densified = arr.densify(BroadcastablePrimitive::Scalar(1.));
+        assert_closing_segment_densified(densified.value_as_geo(0).exterior());
+
+        // The same ring without its repeated closing vertex
+        let polygon = p0();
+        let ring = &polygon.exterior().0;
+        let unclosed: Vec<geo::Coord> = ring[..ring.len() - 1].to_vec();
+        let coords = SeparatedCoordBuffer::new(
+            unclosed.iter().map(|c| c.x).collect::<Vec<_>>().into(),
+            unclosed.iter().map(|c| c.y).collect::<Vec<_>>().into(),
+        );
+        let arr = PolygonArray::<i32>::try_new(
+            CoordBuffer::Separated(coords),
+            OffsetBuffer::from_lengths([1]),
+            OffsetBuffer::from_lengths([unclosed.len()]),
+            None,
+        )
+        .unwrap();
+        let densified = arr.densify(BroadcastablePrimitive::Scalar(1.));
+        assert_closing_segment_densified(densified.value_as_geo(0).exterior());
+    }
+
+    #[test]
+    fn densify_honors_edges() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        let planar: LineStringArray<i32> = vec![line_string].into();
+
+        // 1 degree of longitude at the equator is ~111km, so splitting into 3 segments on the
+        // sphere but none in the plane
+        let densified = planar.densify(BroadcastablePrimitive::Scalar(50_000.));
+        assert_eq!(densified.value_as_geo(0).0.len(), 2);
+
+        let spherical = planar.with_metadata(ArrayMetadata::new(Edges::Spherical));
+        let densified = spherical.densify(BroadcastablePrimitive::Scalar(50_000.));
+        assert_eq!(densified.value_as_geo(0).0.len(), 4);
+        assert_eq!(densified.metadata().edges, Edges::Spherical);
+    }
+}
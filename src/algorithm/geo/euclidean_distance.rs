@@ -1,10 +1,16 @@
+use crate::algorithm::geo::utils::check_row_pairs;
 use crate::array::*;
+use crate::error::Result;
 use crate::scalar::*;
-use crate::trait_::{GeometryArrayTrait, GeometryScalarTrait};
+use crate::trait_::{GeoArrayAccessor, GeometryArrayTrait, GeometryScalarTrait};
 use arrow_array::builder::Float64Builder;
 use arrow_array::{Float64Array, OffsetSizeTrait};
 use geo::EuclideanDistance as _EuclideanDistance;
 
+/// Row-by-row planar distance between an array and another array of the same length, or a
+/// scalar geometry that is compared with every row.
+///
+/// A null on either side gives a null distance.
 pub trait EuclideanDistance<Rhs> {
     /// Returns the distance between two geometries
     ///
@@ -83,30 +89,56 @@ pub trait EuclideanDistance<Rhs> {
     ///
     /// assert_relative_eq!(distance, 1.1313708498984762);
     /// ```
-    fn euclidean_distance(&self, rhs: &Rhs) -> Float64Array;
+    ///
+    /// # Errors
+    ///
+    /// - if `rhs` is an array whose length differs from this array's
+    /// - if `rhs` is an array with different [`Edges`](crate::array::metadata::Edges)
+    fn euclidean_distance(&self, rhs: &Rhs) -> Result<Float64Array>;
+}
+
+/// Planar distance from each geometry of an array to a single point.
+///
+/// Null geometries give a null distance.
+pub trait EuclideanDistanceToPoint {
+    /// Returns the distance from each geometry to `point`
+    ///
+    /// ```
+    /// use geo::point;
+    /// use geoarrow2::algorithm::geo::EuclideanDistanceToPoint;
+    /// use geoarrow2::array::PointArray;
+    ///
+    /// let array: PointArray = vec![point!(x: 3., y: 4.)].into();
+    /// let distances = array.euclidean_distance_to_point(&point!(x: 0., y: 0.));
+    /// assert_eq!(distances.value(0), 5.);
+    /// ```
+    fn euclidean_distance_to_point(&self, point: &geo::Point) -> Float64Array;
 }
 
 // ┌────────────────────────────────┐
 // │ Implementations for RHS arrays │
 // └────────────────────────────────┘
 
-// Note: this implementation is outside the macro because it is not generic over O
+// Point distances are computed straight from the coordinate buffers, without going through geo
+// objects.
 impl EuclideanDistance<PointArray> for PointArray {
     /// Minimum distance between two Points
-    fn euclidean_distance(&self, other: &PointArray) -> Float64Array {
-        assert_eq!(self.len(), other.len());
+    fn euclidean_distance(&self, other: &PointArray) -> Result<Float64Array> {
+        check_row_pairs(self, other)?;
         let mut output_array = Float64Builder::with_capacity(self.len());
 
-        self.iter_geo()
-            .zip(other.iter_geo())
-            .for_each(|(first, second)| match (first, second) {
-                (Some(first), Some(second)) => {
-                    output_array.append_value(first.euclidean_distance(&second))
-                }
-                _ => output_array.append_null(),
-            });
+        for i in 0..self.len() {
+            if self.is_null(i) || other.is_null(i) {
+                output_array.append_null();
+                continue;
+            }
 
-        output_array.finish()
+            let first = geo::Point(self.coords.value(i).into());
+            let second = geo::Point(other.coords.value(i).into());
+            output_array.append_value(first.euclidean_distance(&second));
+        }
+
+        Ok(output_array.finish())
     }
 }
 
@@ -114,8 +146,8 @@ impl EuclideanDistance<PointArray> for PointArray {
 macro_rules! iter_geo_impl {
     ($first:ty, $second:ty) => {
         impl<'a, O: OffsetSizeTrait> EuclideanDistance<$second> for $first {
-            fn euclidean_distance(&self, other: &$second) -> Float64Array {
-                assert_eq!(self.len(), other.len());
+            fn euclidean_distance(&self, other: &$second) -> Result<Float64Array> {
+                check_row_pairs(self, other)?;
                 let mut output_array = Float64Builder::with_capacity(self.len());
 
                 self.iter_geo()
@@ -127,7 +159,7 @@ macro_rules! iter_geo_impl {
                         _ => output_array.append_null(),
                     });
 
-                output_array.finish()
+                Ok(output_array.finish())
             }
         }
     };
@@ -184,26 +216,61 @@ iter_geo_impl!(MultiPolygonArray<O>, PointArray);
 // │ Implementations for RHS scalars │
 // └─────────────────────────────────┘
 
-// Note: this implementation is outside the macro because it is not generic over O
-impl<'a> EuclideanDistance<Point<'a>> for PointArray {
-    /// Minimum distance between two Points
-    fn euclidean_distance(&self, other: &Point<'a>) -> Float64Array {
+// Like the array implementation above, this reads the coordinate buffer directly.
+impl EuclideanDistanceToPoint for PointArray {
+    fn euclidean_distance_to_point(&self, point: &geo::Point) -> Float64Array {
         let mut output_array = Float64Builder::with_capacity(self.len());
 
-        self.iter_geo().for_each(|maybe_point| {
-            let output = maybe_point.map(|point| point.euclidean_distance(&other.to_geo()));
-            output_array.append_option(output)
-        });
+        for i in 0..self.len() {
+            if self.is_null(i) {
+                output_array.append_null();
+                continue;
+            }
+
+            let first = geo::Point(self.coords.value(i).into());
+            output_array.append_value(first.euclidean_distance(point));
+        }
 
         output_array.finish()
     }
 }
 
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl_to_point {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> EuclideanDistanceToPoint for $type {
+            fn euclidean_distance_to_point(&self, point: &geo::Point) -> Float64Array {
+                let mut output_array = Float64Builder::with_capacity(self.len());
+
+                self.iter_geo().for_each(|maybe_geom| {
+                    output_array
+                        .append_option(maybe_geom.map(|geom| geom.euclidean_distance(point)))
+                });
+
+                output_array.finish()
+            }
+        }
+    };
+}
+
+iter_geo_impl_to_point!(LineStringArray<O>);
+iter_geo_impl_to_point!(PolygonArray<O>);
+iter_geo_impl_to_point!(MultiPointArray<O>);
+iter_geo_impl_to_point!(MultiLineStringArray<O>);
+iter_geo_impl_to_point!(MultiPolygonArray<O>);
+
+impl<'a> EuclideanDistance<Point<'a>> for PointArray {
+    /// Minimum distance between two Points
+    fn euclidean_distance(&self, other: &Point<'a>) -> Result<Float64Array> {
+        Ok(self.euclidean_distance_to_point(&other.to_geo()))
+    }
+}
+
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl_scalar {
     ($first:ty, $second:ty) => {
         impl<'a, O: OffsetSizeTrait> EuclideanDistance<$second> for $first {
-            fn euclidean_distance(&self, other: &$second) -> Float64Array {
+            fn euclidean_distance(&self, other: &$second) -> Result<Float64Array> {
                 let mut output_array = Float64Builder::with_capacity(self.len());
                 let other_geo = other.to_geo();
 
@@ -212,7 +279,7 @@ macro_rules! iter_geo_impl_scalar {
                     output_array.append_option(output)
                 });
 
-                output_array.finish()
+                Ok(output_array.finish())
             }
         }
     };
@@ -264,3 +331,75 @@ iter_geo_impl_scalar!(MultiPolygonArray<O>, Point<'a>);
 // iter_geo_impl_scalar!(MultiPolygonArray<O>, MultiPoint<'a, O>);
 // iter_geo_impl_scalar!(MultiPolygonArray<O>, MultiLineString<'a, O>);
 // iter_geo_impl_scalar!(MultiPolygonArray<O>, MultiPolygon<'a, O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point::{p0, p1, p2};
+    use arrow_array::Array;
+    use geo::{line_string, point};
+
+    #[test]
+    fn point_fast_path_matches_geo() {
+        let first: PointArray = vec![Some(p0()), None, Some(p1()), Some(p2())].into();
+        let second: PointArray = vec![Some(p2()), Some(p1()), None, Some(p0())].into();
+
+        let result = first.euclidean_distance(&second).unwrap();
+        for i in 0..first.len() {
+            match (first.get_as_geo(i), second.get_as_geo(i)) {
+                (Some(a), Some(b)) => assert_eq!(result.value(i), a.euclidean_distance(&b)),
+                _ => assert!(result.is_null(i)),
+            }
+        }
+
+        let result = first.euclidean_distance(&second.value(0)).unwrap();
+        assert!(result.is_null(1));
+        assert_eq!(result.value(3), p2().euclidean_distance(&p2()));
+    }
+
+    #[test]
+    fn planar_and_spherical() {
+        use crate::algorithm::geo::{Contains, Intersects, Within};
+        use crate::array::metadata::{ArrayMetadata, Edges};
+
+        let planar: PointArray = vec![p0(), p1()].into();
+        let spherical: PointArray = vec![p0(), p1()].into();
+        let spherical = spherical.with_metadata(ArrayMetadata::new(Edges::Spherical));
+
+        assert!(planar.euclidean_distance(&spherical).is_err());
+        assert!(planar.contains(&spherical).is_err());
+        assert!(planar.intersects(&spherical).is_err());
+        assert!(spherical.is_within(&planar).is_err());
+        assert!(spherical.euclidean_distance(&spherical).is_ok());
+    }
+
+    #[test]
+    fn point_to_line() {
+        let points: PointArray =
+            vec![Some(point!(x: 1., y: 2.)), Some(point!(x: 5., y: 1.)), None].into();
+        let lines: LineStringArray<i32> = vec![
+            Some(line_string![(x: 0., y: 0.), (x: 4., y: 0.)]),
+            Some(line_string![(x: 0., y: 0.), (x: 4., y: 0.)]),
+            Some(line_string![(x: 0., y: 0.), (x: 4., y: 0.)]),
+        ]
+        .into();
+
+        let result = points.euclidean_distance(&lines).unwrap();
+        assert_eq!(result.value(0), 2.);
+        assert_eq!(result.value(1), 2_f64.sqrt());
+        assert!(result.is_null(2));
+
+        let result = lines.euclidean_distance_to_point(&point!(x: 2., y: -3.));
+        assert_eq!(result.value(0), 3.);
+    }
+
+    #[test]
+    fn length_mismatch() {
+        let first: PointArray = vec![p0(), p1()].into();
+        let second: PointArray = vec![p2()].into();
+        assert!(first.euclidean_distance(&second).is_err());
+
+        let lines: LineStringArray<i32> = vec![line_string![(x: 0., y: 0.), (x: 4., y: 0.)]].into();
+        assert!(first.euclidean_distance(&lines).is_err());
+    }
+}
@@ -1,9 +1,9 @@
 use crate::algorithm::geo::utils::zeroes;
 use crate::array::*;
+use crate::buffer::OffsetBufferUtils;
 use crate::GeometryArrayTrait;
 use arrow_array::builder::Float64Builder;
 use arrow_array::{Float64Array, OffsetSizeTrait};
-use geo::EuclideanLength as _EuclideanLength;
 
 pub trait EuclideanLength {
     /// Calculation of the length of a Line
@@ -51,23 +51,53 @@ macro_rules! zero_impl {
 
 zero_impl!(MultiPointArray<O>);
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
-    ($type:ty) => {
-        impl<O: OffsetSizeTrait> EuclideanLength for $type {
-            fn euclidean_length(&self) -> Float64Array {
-                let mut output_array = Float64Builder::with_capacity(self.len());
-                self.iter_geo().for_each(|maybe_g| {
-                    output_array.append_option(maybe_g.map(|g| g.euclidean_length()))
-                });
-                output_array.finish()
+/// Sum the lengths of the segments between the coordinates `start..end`
+fn path_length(coords: &CoordBuffer, start: usize, end: usize) -> f64 {
+    (start + 1..end)
+        .map(|i| {
+            let dx = coords.get_x(i) - coords.get_x(i - 1);
+            let dy = coords.get_y(i) - coords.get_y(i - 1);
+            dx.hypot(dy)
+        })
+        .sum()
+}
+
+// The length is computed straight from the coordinate buffer instead of materializing geo objects.
+impl<O: OffsetSizeTrait> EuclideanLength for LineStringArray<O> {
+    fn euclidean_length(&self) -> Float64Array {
+        let mut output_array = Float64Builder::with_capacity(self.len());
+        for geom_idx in 0..self.len() {
+            if self.is_null(geom_idx) {
+                output_array.append_null();
+                continue;
             }
+            let (start, end) = self.geom_offsets.start_end(geom_idx);
+            output_array.append_value(path_length(&self.coords, start, end));
         }
-    };
+        output_array.finish()
+    }
 }
 
-iter_geo_impl!(LineStringArray<O>);
-iter_geo_impl!(MultiLineStringArray<O>);
+impl<O: OffsetSizeTrait> EuclideanLength for MultiLineStringArray<O> {
+    fn euclidean_length(&self) -> Float64Array {
+        let mut output_array = Float64Builder::with_capacity(self.len());
+        for geom_idx in 0..self.len() {
+            if self.is_null(geom_idx) {
+                output_array.append_null();
+                continue;
+            }
+            let (start, end) = self.geom_offsets.start_end(geom_idx);
+            let length = (start..end)
+                .map(|line_idx| {
+                    let (coord_start, coord_end) = self.ring_offsets.start_end(line_idx);
+                    path_length(&self.coords, coord_start, coord_end)
+                })
+                .sum();
+            output_array.append_value(length);
+        }
+        output_array.finish()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -93,4 +123,36 @@ mod tests {
         assert_eq!(expected, result_array.value(0).round());
         assert!(result_array.is_valid(0));
     }
+
+    #[test]
+    fn open_square_and_nulls() {
+        // Three sides of a unit square, left open
+        let open_square = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        let diagonal = line_string![(x: 0., y: 0.), (x: 3., y: 4.)];
+        let input_array: LineStringArray<i32> =
+            vec![Some(open_square.clone()), None, Some(diagonal.clone())].into();
+        let result_array = input_array.euclidean_length();
+        assert_eq!(result_array.value(0), 1. + 1. + 1.);
+        assert!(result_array.is_null(1));
+        assert_eq!(result_array.value(2), 5.);
+
+        let multi_array: MultiLineStringArray<i32> = vec![
+            Some(geo::MultiLineString(vec![open_square, diagonal])),
+            None,
+        ]
+        .into();
+        let result_array = multi_array.euclidean_length();
+        assert_eq!(result_array.value(0), 8.);
+        assert!(result_array.is_null(1));
+
+        let points: PointArray = vec![Some(geo::point!(x: 1., y: 2.)), None].into();
+        let result_array = points.euclidean_length();
+        assert_eq!(result_array.value(0), 0.);
+        assert!(result_array.is_null(1));
+    }
 }
@@ -309,6 +309,7 @@ macro_rules! iter_geo_impl {
 
 iter_geo_impl!(PolygonArray<O>);
 iter_geo_impl!(MultiPolygonArray<O>);
+iter_geo_impl!(GeometryCollectionArray<O>);
 iter_geo_impl!(WKBArray<O>);
 
 impl<O: OffsetSizeTrait> GeodesicArea for GeometryArray<O> {
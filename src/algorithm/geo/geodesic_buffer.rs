@@ -0,0 +1,238 @@
+use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::array::{MutablePolygonArray, PointArray, PolygonArray, PolygonCapacity};
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeoArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::types::Float64Type;
+use arrow_array::OffsetSizeTrait;
+use geo::{Coord, GeodesicDestination, GeodesicDistance, LineString, Point, Polygon};
+
+/// How [`geodesic_point_buffer`] writes circles that cross the antimeridian or enclose a pole.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AntimeridianHandling {
+    /// Keep longitudes continuous around the center, so a circle crossing the antimeridian
+    /// extends past ±180°.
+    #[default]
+    Unwrap,
+
+    /// Clamp longitudes to [-180°, 180°], cutting a circle off at the antimeridian.
+    Clamp,
+}
+
+/// Buffer lon/lat points by a distance in meters on the WGS84 ellipsoid.
+///
+/// Each circle is a ring of `n_segments` geodesic destination points around the compass from
+/// its center, starting due north and going clockwise. A circle that encloses a pole is closed
+/// along that pole's latitude, so that the ring still covers the pole.
+///
+/// Null points and null distances give null polygons.
+///
+/// # Errors
+///
+/// - if `n_segments` is less than 3
+pub fn geodesic_point_buffer<O: OffsetSizeTrait>(
+    array: &PointArray,
+    distance: BroadcastablePrimitive<Float64Type>,
+    n_segments: usize,
+    antimeridian: AntimeridianHandling,
+) -> Result<PolygonArray<O>> {
+    if n_segments < 3 {
+        return Err(GeoArrowError::General(
+            "geodesic buffer needs at least 3 segments".to_string(),
+        ));
+    }
+
+    // Pole-enclosing rings get up to four more vertices, which we don't reserve for
+    let capacity = PolygonCapacity::new(array.len() * (n_segments + 1), array.len(), array.len());
+    let mut output_array = MutablePolygonArray::with_capacity(capacity);
+    for (i, distance) in (0..array.len()).zip(&distance) {
+        let polygon = array
+            .get_as_geo(i)
+            .zip(distance)
+            .map(|(center, distance)| circle(center, distance, n_segments, antimeridian));
+        output_array.push_polygon(polygon.as_ref())?;
+    }
+    Ok(output_array.into())
+}
+
+fn circle(
+    center: Point,
+    distance: f64,
+    n_segments: usize,
+    antimeridian: AntimeridianHandling,
+) -> Polygon {
+    let north_pole = Point::new(center.x(), 90.);
+    let south_pole = Point::new(center.x(), -90.);
+    let pole_latitude = if center.geodesic_distance(&north_pole) < distance {
+        Some(90.)
+    } else if center.geodesic_distance(&south_pole) < distance {
+        Some(-90.)
+    } else {
+        None
+    };
+
+    // geo normalizes destination longitudes to [-180, 180], so unwrap them to be continuous,
+    // either around the center or, when circling a pole, from one vertex to the next
+    let mut previous_x = center.x();
+    let mut coords: Vec<Coord> = (0..n_segments)
+        .map(|segment| {
+            let bearing = 360. * segment as f64 / n_segments as f64;
+            let mut coord = center.geodesic_destination(bearing, distance).0;
+            coord.x = previous_x + wrap_longitude(coord.x - previous_x);
+            if pole_latitude.is_some() {
+                previous_x = coord.x;
+            }
+            coord
+        })
+        .collect();
+
+    if let Some(pole_latitude) = pole_latitude {
+        let window_center = match antimeridian {
+            AntimeridianHandling::Unwrap => center.x(),
+            AntimeridianHandling::Clamp => 0.,
+        };
+        close_around_pole(&mut coords, pole_latitude, window_center);
+    }
+    if antimeridian == AntimeridianHandling::Clamp {
+        coords
+            .iter_mut()
+            .for_each(|coord| coord.x = coord.x.clamp(-180., 180.));
+    }
+
+    Polygon::new(LineString::new(coords), vec![])
+}
+
+/// Close a ring that circles a pole along the pole latitude.
+///
+/// The longitudes of such a ring span a full turn, so the ring is cut where it leaves the
+/// 360° window around `window_center` and closed along the window edges.
+fn close_around_pole(coords: &mut Vec<Coord>, pole_latitude: f64, window_center: f64) {
+    let crossing = coords
+        .iter()
+        .position(|coord| (coord.x - window_center).abs() > 180.)
+        .unwrap_or(0);
+    coords.rotate_left(crossing);
+    coords
+        .iter_mut()
+        .for_each(|coord| coord.x = window_center + wrap_longitude(coord.x - window_center));
+
+    let first = coords[0];
+    let last = coords[coords.len() - 1];
+    let (start_x, end_x) = if first.x < last.x {
+        (window_center - 180., window_center + 180.)
+    } else {
+        (window_center + 180., window_center - 180.)
+    };
+    coords.extend([
+        geo::coord! { x: end_x, y: last.y },
+        geo::coord! { x: end_x, y: pole_latitude },
+        geo::coord! { x: start_x, y: pole_latitude },
+        geo::coord! { x: start_x, y: first.y },
+    ]);
+}
+
+/// Wrap a longitude difference or value into [-180, 180].
+fn wrap_longitude(x: f64) -> f64 {
+    (x + 180.).rem_euclid(360.) - 180.
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point::p0;
+    use arrow_array::Float64Array;
+    use geo::{point, Contains};
+
+    fn buffer(
+        points: Vec<Option<Point>>,
+        distance: f64,
+        antimeridian: AntimeridianHandling,
+    ) -> PolygonArray<i32> {
+        let array: PointArray = points.into();
+        geodesic_point_buffer(
+            &array,
+            BroadcastablePrimitive::Scalar(distance),
+            32,
+            antimeridian,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn ring_radius_matches_distance() {
+        let center = point!(x: 13.4, y: 52.5);
+        let result = buffer(vec![Some(center)], 5_000., AntimeridianHandling::Unwrap);
+        let polygon = result.value_as_geo(0);
+
+        assert!(polygon.exterior().is_closed());
+        assert_eq!(polygon.exterior().0.len(), 33);
+        for coord in polygon.exterior().coords() {
+            let radius = Point::from(*coord).geodesic_distance(&center);
+            assert!((radius - 5_000.).abs() / 5_000. < 1e-3);
+        }
+        assert!(polygon.contains(&center));
+    }
+
+    #[test]
+    fn nulls_and_broadcast_distances() {
+        let array: PointArray = vec![Some(p0()), None, Some(p0())].into();
+        let distances = Float64Array::from(vec![Some(100.), Some(100.), None]);
+        let result: PolygonArray<i32> = geodesic_point_buffer(
+            &array,
+            BroadcastablePrimitive::Array(distances),
+            8,
+            AntimeridianHandling::Unwrap,
+        )
+        .unwrap();
+        assert!(result.is_valid(0));
+        assert!(result.is_null(1));
+        assert!(result.is_null(2));
+
+        assert!(geodesic_point_buffer::<i32>(
+            &array,
+            BroadcastablePrimitive::Scalar(100.),
+            2,
+            AntimeridianHandling::Unwrap
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn antimeridian() {
+        let center = point!(x: 179.9, y: -16.);
+        let unwrapped = buffer(vec![Some(center)], 50_000., AntimeridianHandling::Unwrap);
+        let unwrapped = unwrapped.value_as_geo(0);
+        assert!(unwrapped.exterior().coords().any(|c| c.x > 180.));
+        assert!(unwrapped.exterior().coords().all(|c| c.x > 179.));
+        for coord in unwrapped.exterior().coords() {
+            let radius = Point::from(*coord).geodesic_distance(&center);
+            assert!((radius - 50_000.).abs() / 50_000. < 1e-3);
+        }
+
+        let clamped = buffer(vec![Some(center)], 50_000., AntimeridianHandling::Clamp);
+        let clamped = clamped.value_as_geo(0);
+        assert!(clamped.exterior().coords().all(|c| c.x <= 180.));
+        assert!(clamped.exterior().coords().any(|c| c.x == 180.));
+    }
+
+    #[test]
+    fn encloses_pole() {
+        let center = point!(x: 20., y: 89.);
+        for antimeridian in [AntimeridianHandling::Unwrap, AntimeridianHandling::Clamp] {
+            let result = buffer(vec![Some(center)], 200_000., antimeridian);
+            let polygon = result.value_as_geo(0);
+            assert!(polygon.exterior().is_closed());
+            assert!(polygon.contains(&center));
+            assert!(polygon.exterior().coords().any(|c| c.y == 90.));
+        }
+
+        let clamped = buffer(vec![Some(center)], 200_000., AntimeridianHandling::Clamp);
+        let xs: Vec<f64> = clamped
+            .value_as_geo(0)
+            .exterior()
+            .coords()
+            .map(|c| c.x)
+            .collect();
+        assert!(xs.iter().all(|x| (-180. ..=180.).contains(x)));
+    }
+}
@@ -87,6 +87,8 @@ iter_geo_impl!(MultiLineStringArray<O>);
 mod tests {
     use super::*;
     use crate::array::LineStringArray;
+    use crate::test::point::p0;
+    use approx::assert_relative_eq;
     use arrow_array::Array;
     use geo::line_string;
 
@@ -106,4 +108,23 @@ mod tests {
         assert_eq!(expected, result_array.value(0).round());
         assert!(result_array.is_valid(0));
     }
+
+    #[test]
+    fn one_degree_along_equator() {
+        let input_array: LineStringArray<i32> =
+            vec![Some(line_string![(x: 0., y: 0.), (x: 1., y: 0.)]), None].into();
+        let result_array = input_array.haversine_length();
+
+        // 2π × 6371.0088 km / 360
+        assert_relative_eq!(result_array.value(0), 111_195.08, epsilon = 0.01);
+        assert!(result_array.is_null(1));
+    }
+
+    #[test]
+    fn points_have_zero_length() {
+        let input_array: PointArray = vec![Some(p0()), None].into();
+        let result_array = input_array.haversine_length();
+        assert_eq!(result_array.value(0), 0.);
+        assert!(result_array.is_null(1));
+    }
 }
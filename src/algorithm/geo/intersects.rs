@@ -1,4 +1,6 @@
+use crate::algorithm::geo::utils::check_row_pairs;
 use crate::array::*;
+use crate::error::Result;
 use crate::scalar::*;
 use crate::trait_::GeometryScalarTrait;
 use crate::GeometryArrayTrait;
@@ -42,7 +44,11 @@ use geo::Intersects as _Intersects;
 /// assert!(!line_string_a.intersects(&line_string_c));
 /// ```
 pub trait Intersects<Rhs = Self> {
-    fn intersects(&self, rhs: &Rhs) -> BooleanArray;
+    /// # Errors
+    ///
+    /// - if `rhs` is an array whose length differs from this array's
+    /// - if `rhs` is an array with different [`Edges`](crate::array::metadata::Edges)
+    fn intersects(&self, rhs: &Rhs) -> Result<BooleanArray>;
 }
 
 // ┌────────────────────────────────┐
@@ -51,8 +57,8 @@ pub trait Intersects<Rhs = Self> {
 
 // Note: this implementation is outside the macro because it is not generic over O
 impl Intersects for PointArray {
-    fn intersects(&self, rhs: &Self) -> BooleanArray {
-        assert_eq!(self.len(), rhs.len());
+    fn intersects(&self, rhs: &Self) -> Result<BooleanArray> {
+        check_row_pairs(self, rhs)?;
 
         let mut output_array = BooleanBuilder::with_capacity(self.len());
 
@@ -63,7 +69,7 @@ impl Intersects for PointArray {
                 _ => output_array.append_null(),
             });
 
-        output_array.finish()
+        Ok(output_array.finish())
     }
 }
 
@@ -71,8 +77,8 @@ impl Intersects for PointArray {
 macro_rules! iter_geo_impl {
     ($first:ty, $second:ty) => {
         impl<'a, O: OffsetSizeTrait> Intersects<$second> for $first {
-            fn intersects(&self, rhs: &$second) -> BooleanArray {
-                assert_eq!(self.len(), rhs.len());
+            fn intersects(&self, rhs: &$second) -> Result<BooleanArray> {
+                check_row_pairs(self, rhs)?;
 
                 let mut output_array = BooleanBuilder::with_capacity(self.len());
 
@@ -85,7 +91,7 @@ macro_rules! iter_geo_impl {
                         _ => output_array.append_null(),
                     });
 
-                output_array.finish()
+                Ok(output_array.finish())
             }
         }
     };
@@ -144,7 +150,7 @@ iter_geo_impl!(MultiPolygonArray<O>, MultiPolygonArray<O>);
 
 // Note: this implementation is outside the macro because it is not generic over O
 impl<'a> Intersects<Point<'a>> for PointArray {
-    fn intersects(&self, rhs: &Point<'a>) -> BooleanArray {
+    fn intersects(&self, rhs: &Point<'a>) -> Result<BooleanArray> {
         let mut output_array = BooleanBuilder::with_capacity(self.len());
 
         self.iter_geo().for_each(|maybe_point| {
@@ -152,7 +158,7 @@ impl<'a> Intersects<Point<'a>> for PointArray {
             output_array.append_option(output)
         });
 
-        output_array.finish()
+        Ok(output_array.finish())
     }
 }
 
@@ -160,7 +166,7 @@ impl<'a> Intersects<Point<'a>> for PointArray {
 macro_rules! iter_geo_impl_scalar {
     ($first:ty, $second:ty) => {
         impl<'a, O: OffsetSizeTrait> Intersects<$second> for $first {
-            fn intersects(&self, rhs: &$second) -> BooleanArray {
+            fn intersects(&self, rhs: &$second) -> Result<BooleanArray> {
                 let mut output_array = BooleanBuilder::with_capacity(self.len());
                 let rhs_geo = rhs.to_geo();
 
@@ -169,7 +175,7 @@ macro_rules! iter_geo_impl_scalar {
                     output_array.append_option(output)
                 });
 
-                output_array.finish()
+                Ok(output_array.finish())
             }
         }
     };
@@ -0,0 +1,54 @@
+use crate::algorithm::geo::{EuclideanLength, HaversineLength};
+use crate::array::*;
+use crate::GeometryArrayTrait;
+use arrow_array::{Float64Array, OffsetSizeTrait};
+
+/// Calculate the length of linear geometries, honoring the [`Edges`] of the array's metadata.
+///
+/// Arrays with planar edges use [`EuclideanLength`], in units of the coordinates. Arrays with
+/// spherical edges use [`HaversineLength`], in meters. To choose the method explicitly regardless
+/// of the metadata, call those traits directly.
+///
+/// # Examples
+///
+/// ```
+/// use geo::line_string;
+/// use geoarrow2::algorithm::geo::Length;
+/// use geoarrow2::array::{ArrayMetadata, Edges, LineStringArray};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+/// let planar: LineStringArray<i32> = vec![line_string].into();
+/// assert_eq!(planar.length().value(0), 1.);
+///
+/// let spherical = planar.with_metadata(ArrayMetadata::new(Edges::Spherical));
+/// assert_eq!(spherical.length().value(0).round(), 111_195.);
+/// ```
+pub trait Length {
+    fn length(&self) -> Float64Array;
+}
+
+impl Length for PointArray {
+    fn length(&self) -> Float64Array {
+        match self.metadata().edges {
+            Edges::Planar => self.euclidean_length(),
+            Edges::Spherical => self.haversine_length(),
+        }
+    }
+}
+
+macro_rules! length_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> Length for $type {
+            fn length(&self) -> Float64Array {
+                match self.metadata().edges {
+                    Edges::Planar => self.euclidean_length(),
+                    Edges::Spherical => self.haversine_length(),
+                }
+            }
+        }
+    };
+}
+
+length_impl!(LineStringArray<O>);
+length_impl!(MultiPointArray<O>);
+length_impl!(MultiLineStringArray<O>);
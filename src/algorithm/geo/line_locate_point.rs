@@ -1,4 +1,6 @@
+use crate::algorithm::geo::utils::check_row_pairs;
 use crate::array::{LineStringArray, PointArray};
+use crate::error::Result;
 use crate::scalar::Point;
 use crate::trait_::GeometryScalarTrait;
 use crate::GeometryArrayTrait;
@@ -30,24 +32,29 @@ use geo::LineLocatePoint as _LineLocatePoint;
 /// ].into();
 /// let linestring_array: LineStringArray<i32> = vec![linestring].into();
 ///
-/// let result = linestring_array.line_locate_point(&point!(x: -1.0, y: 0.0));
+/// let result = linestring_array.line_locate_point(&point!(x: -1.0, y: 0.0)).unwrap();
 /// assert_eq!(result.value(0), 0.0);
 /// assert!(result.is_valid(0));
 ///
-/// let result = linestring_array.line_locate_point(&point!(x: -0.5, y: 0.0));
+/// let result = linestring_array.line_locate_point(&point!(x: -0.5, y: 0.0)).unwrap();
 /// assert_eq!(result.value(0), 0.25);
 /// assert!(result.is_valid(0));
 ///
-/// let result = linestring_array.line_locate_point(&point!(x: 0.0, y: 0.0));
+/// let result = linestring_array.line_locate_point(&point!(x: 0.0, y: 0.0)).unwrap();
 /// assert_eq!(result.value(0), 0.5);
 /// assert!(result.is_valid(0));
 /// ```
 pub trait LineLocatePoint<Rhs> {
-    fn line_locate_point(&self, p: &Rhs) -> Float64Array;
+    /// # Errors
+    ///
+    /// - if `p` is an array whose length differs from this array's
+    /// - if `p` is an array with different [`Edges`](crate::array::metadata::Edges)
+    fn line_locate_point(&self, p: &Rhs) -> Result<Float64Array>;
 }
 
 impl<O: OffsetSizeTrait> LineLocatePoint<PointArray> for LineStringArray<O> {
-    fn line_locate_point(&self, p: &PointArray) -> Float64Array {
+    fn line_locate_point(&self, p: &PointArray) -> Result<Float64Array> {
+        check_row_pairs(self, p)?;
         let mut output_array = Float64Builder::with_capacity(self.len());
 
         self.iter_geo()
@@ -59,12 +66,12 @@ impl<O: OffsetSizeTrait> LineLocatePoint<PointArray> for LineStringArray<O> {
                 _ => output_array.append_null(),
             });
 
-        output_array.finish()
+        Ok(output_array.finish())
     }
 }
 
 impl<'a, O: OffsetSizeTrait> LineLocatePoint<Point<'a>> for LineStringArray<O> {
-    fn line_locate_point(&self, p: &Point<'a>) -> Float64Array {
+    fn line_locate_point(&self, p: &Point<'a>) -> Result<Float64Array> {
         let mut output_array = Float64Builder::with_capacity(self.len());
 
         self.iter_geo().for_each(|maybe_line_string| {
@@ -73,12 +80,12 @@ impl<'a, O: OffsetSizeTrait> LineLocatePoint<Point<'a>> for LineStringArray<O> {
             output_array.append_option(output)
         });
 
-        output_array.finish()
+        Ok(output_array.finish())
     }
 }
 
 impl<O: OffsetSizeTrait> LineLocatePoint<geo::Point> for LineStringArray<O> {
-    fn line_locate_point(&self, p: &geo::Point) -> Float64Array {
+    fn line_locate_point(&self, p: &geo::Point) -> Result<Float64Array> {
         let mut output_array = Float64Builder::with_capacity(self.len());
 
         self.iter_geo().for_each(|maybe_line_string| {
@@ -86,6 +93,6 @@ impl<O: OffsetSizeTrait> LineLocatePoint<geo::Point> for LineStringArray<O> {
             output_array.append_option(output)
         });
 
-        output_array.finish()
+        Ok(output_array.finish())
     }
 }
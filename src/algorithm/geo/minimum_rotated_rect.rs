@@ -127,6 +127,9 @@ iter_geo_impl!(MultiLineStringArray<O>, i64);
 iter_geo_impl!(MultiPolygonArray<O>, i32);
 iter_geo_impl!(MultiPolygonArray<O>, i64);
 
+iter_geo_impl!(GeometryCollectionArray<O>, i32);
+iter_geo_impl!(GeometryCollectionArray<O>, i64);
+
 impl<O: OffsetSizeTrait> MinimumRotatedRect<i32> for GeometryArray<O> {
     crate::geometry_array_delegate_impl! {
         fn minimum_rotated_rect(&self) -> PolygonArray<i32>;
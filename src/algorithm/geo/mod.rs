@@ -40,7 +40,7 @@ pub use contains::Contains;
 
 /// Calculate the convex hull of geometries.
 pub mod convex_hull;
-pub use convex_hull::ConvexHull;
+pub use convex_hull::{AggregateConvexHull, ConvexHull};
 
 /// Densify linear geometry components
 pub mod densify;
@@ -57,12 +57,16 @@ pub use euclidean_length::EuclideanLength;
 
 /// Calculate the minimum Euclidean distance between two `Geometries`.
 pub mod euclidean_distance;
-pub use euclidean_distance::EuclideanDistance;
+pub use euclidean_distance::{EuclideanDistance, EuclideanDistanceToPoint};
 
 /// Calculate the Geodesic area and perimeter of polygons.
 pub mod geodesic_area;
 pub use geodesic_area::GeodesicArea;
 
+/// Buffer lon/lat points by a geodesic distance.
+pub mod geodesic_buffer;
+pub use geodesic_buffer::{geodesic_point_buffer, AntimeridianHandling};
+
 /// Calculate the Geodesic length of a line.
 pub mod geodesic_length;
 pub use geodesic_length::GeodesicLength;
@@ -75,6 +79,10 @@ pub use haversine_length::HaversineLength;
 pub mod intersects;
 pub use intersects::Intersects;
 
+/// Calculate the length of linear geometries according to their edge interpretation.
+pub mod length;
+pub use length::Length;
+
 /// Interpolate a point along a `LineStringArray`.
 pub mod line_interpolate_point;
 pub use line_interpolate_point::LineInterpolatePoint;
@@ -87,6 +95,10 @@ pub use line_locate_point::LineLocatePoint;
 pub mod minimum_rotated_rect;
 pub use minimum_rotated_rect::MinimumRotatedRect;
 
+/// Calculate the perimeter and compactness of polygons.
+pub mod perimeter;
+pub use perimeter::Perimeter;
+
 /// Remove (consecutive) repeated points
 pub mod remove_repeated_points;
 pub use remove_repeated_points::RemoveRepeatedPoints;
@@ -111,6 +123,10 @@ pub use simplify_vw::SimplifyVw;
 pub mod skew;
 pub use skew::Skew;
 
+/// Distances and speeds along tracks of consecutive points.
+pub mod track;
+pub use track::TrackOps;
+
 /// Translate geometries along the given offsets.
 pub mod translate;
 pub use translate::Translate;
@@ -119,6 +135,10 @@ pub use translate::Translate;
 pub mod vincenty_length;
 pub use vincenty_length::VincentyLength;
 
+/// Validate the winding order of polygon rings.
+pub mod winding;
+pub use winding::{ValidateWinding, WindingConvention};
+
 /// Determine whether `Geometry` `A` is completely within by `Geometry` `B`.
 pub mod within;
 pub use within::Within;
@@ -0,0 +1,173 @@
+use std::f64::consts::PI;
+
+use crate::array::*;
+use crate::GeometryArrayTrait;
+use arrow_array::builder::Float64Builder;
+use arrow_array::{Float64Array, OffsetSizeTrait};
+use geo::{Area, EuclideanLength, GeodesicLength};
+
+/// The perimeter of polygonal geometries, and shape metrics derived from it.
+///
+/// The perimeter is the summed length of the exterior and interior rings of every polygon. Null
+/// geometries give null, and empty geometries have a perimeter of 0.
+pub trait Perimeter {
+    /// The planar perimeter, in the units of the coordinates.
+    fn perimeter(&self) -> Float64Array;
+
+    /// The perimeter on an ellipsoidal model of the earth, in meters, as measured by
+    /// [`GeodesicLength`](crate::algorithm::geo::GeodesicLength).
+    fn geodesic_perimeter(&self) -> Float64Array;
+
+    /// The Polsby-Popper score `4πA / P²`, from the planar area `A` and perimeter `P`.
+    ///
+    /// This is 1 for a circle and approaches 0 for elongated or convoluted shapes. Geometries with
+    /// a perimeter of 0 give null.
+    fn polsby_popper(&self) -> Float64Array;
+
+    /// The isoperimetric quotient: the ratio of the area of a geometry to the area of the circle
+    /// with the same perimeter.
+    ///
+    /// This works out to the same value as [`polsby_popper`](Self::polsby_popper), under the name
+    /// used in landscape ecology.
+    fn isoperimetric_quotient(&self) -> Float64Array;
+}
+
+fn polygon_perimeter(polygon: &geo::Polygon, ring_length: impl Fn(&geo::LineString) -> f64) -> f64 {
+    ring_length(polygon.exterior()) + polygon.interiors().iter().map(ring_length).sum::<f64>()
+}
+
+/// `4πA / P²` in a single pass over the geometries.
+fn compactness<G: Area<f64>>(
+    geometries: impl Iterator<Item = Option<G>>,
+    len: usize,
+    perimeter: impl Fn(&G) -> f64,
+) -> Float64Array {
+    let mut output_array = Float64Builder::with_capacity(len);
+    for maybe_g in geometries {
+        let score = maybe_g.and_then(|g| {
+            let perimeter = perimeter(&g);
+            (perimeter > 0.).then(|| 4. * PI * g.unsigned_area() / perimeter.powi(2))
+        });
+        output_array.append_option(score);
+    }
+    output_array.finish()
+}
+
+impl<O: OffsetSizeTrait> Perimeter for PolygonArray<O> {
+    fn perimeter(&self) -> Float64Array {
+        let mut output_array = Float64Builder::with_capacity(self.len());
+        self.iter_geo().for_each(|maybe_g| {
+            output_array.append_option(
+                maybe_g.map(|g| polygon_perimeter(&g, |ring| ring.euclidean_length())),
+            )
+        });
+        output_array.finish()
+    }
+
+    fn geodesic_perimeter(&self) -> Float64Array {
+        let mut output_array = Float64Builder::with_capacity(self.len());
+        self.iter_geo().for_each(|maybe_g| {
+            output_array.append_option(
+                maybe_g.map(|g| polygon_perimeter(&g, |ring| ring.geodesic_length())),
+            )
+        });
+        output_array.finish()
+    }
+
+    fn polsby_popper(&self) -> Float64Array {
+        compactness(self.iter_geo(), self.len(), |g| {
+            polygon_perimeter(g, |ring| ring.euclidean_length())
+        })
+    }
+
+    fn isoperimetric_quotient(&self) -> Float64Array {
+        self.polsby_popper()
+    }
+}
+
+fn multi_polygon_perimeter(
+    multi_polygon: &geo::MultiPolygon,
+    ring_length: impl Fn(&geo::LineString) -> f64 + Copy,
+) -> f64 {
+    multi_polygon
+        .iter()
+        .map(|polygon| polygon_perimeter(polygon, ring_length))
+        .sum()
+}
+
+impl<O: OffsetSizeTrait> Perimeter for MultiPolygonArray<O> {
+    fn perimeter(&self) -> Float64Array {
+        let mut output_array = Float64Builder::with_capacity(self.len());
+        self.iter_geo().for_each(|maybe_g| {
+            output_array.append_option(
+                maybe_g.map(|g| multi_polygon_perimeter(&g, |ring| ring.euclidean_length())),
+            )
+        });
+        output_array.finish()
+    }
+
+    fn geodesic_perimeter(&self) -> Float64Array {
+        let mut output_array = Float64Builder::with_capacity(self.len());
+        self.iter_geo().for_each(|maybe_g| {
+            output_array.append_option(
+                maybe_g.map(|g| multi_polygon_perimeter(&g, |ring| ring.geodesic_length())),
+            )
+        });
+        output_array.finish()
+    }
+
+    fn polsby_popper(&self) -> Float64Array {
+        compactness(self.iter_geo(), self.len(), |g| {
+            multi_polygon_perimeter(g, |ring| ring.euclidean_length())
+        })
+    }
+
+    fn isoperimetric_quotient(&self) -> Float64Array {
+        self.polsby_popper()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::Array;
+    use geo::polygon;
+
+    #[test]
+    fn square_with_hole() {
+        let square = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+        );
+        let arr: PolygonArray<i32> = vec![Some(square), None, Some(polygon![])].into();
+
+        let perimeter = arr.perimeter();
+        assert_eq!(perimeter.value(0), 20.);
+        assert!(perimeter.is_null(1));
+        assert_eq!(perimeter.value(2), 0.);
+
+        let polsby_popper = arr.polsby_popper();
+        assert!((polsby_popper.value(0) - 4. * PI * 15. / 400.).abs() < 1e-12);
+        assert!(polsby_popper.is_null(1));
+        assert!(polsby_popper.is_null(2));
+        assert_eq!(arr.isoperimetric_quotient(), polsby_popper);
+
+        let multi: MultiPolygonArray<i32> = vec![Some(geo::MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+            polygon![(x: 5., y: 0.), (x: 6., y: 0.), (x: 6., y: 1.), (x: 5., y: 1.)],
+        ]))]
+        .into();
+        assert_eq!(multi.perimeter().value(0), 8.);
+        assert!((multi.polsby_popper().value(0) - 4. * PI * 2. / 64.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn geodesic_perimeter() {
+        // One degree squared at the equator
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let arr: PolygonArray<i32> = vec![square].into();
+        let perimeter = arr.geodesic_perimeter().value(0);
+        // About 111 km per side
+        assert!((perimeter / 4. - 111_000.).abs() < 500., "{}", perimeter);
+    }
+}
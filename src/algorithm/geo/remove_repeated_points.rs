@@ -1,4 +1,5 @@
 use crate::array::*;
+use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
 use geo::RemoveRepeatedPoints as _RemoveRepeatedPoints;
 
@@ -46,6 +47,19 @@ iter_geo_impl!(PolygonArray<O>, geo::Polygon);
 iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
 iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
 
+impl<O: OffsetSizeTrait> RemoveRepeatedPoints for GeometryCollectionArray<O> {
+    fn remove_repeated_points(&self) -> Self {
+        let output_geoms: Vec<Option<geo::GeometryCollection>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| geom.remove_repeated_points()))
+            .collect();
+
+        // Removing points can't nest the collections read from this array
+        let output: Self = output_geoms.try_into().unwrap();
+        output.into_coord_type(self.coord_type())
+    }
+}
+
 impl<O: OffsetSizeTrait> RemoveRepeatedPoints for GeometryArray<O> {
     fn remove_repeated_points(&self) -> Self {
         use GeometryArray::*;
@@ -57,6 +71,7 @@ impl<O: OffsetSizeTrait> RemoveRepeatedPoints for GeometryArray<O> {
             MultiPoint(arr) => MultiPoint(arr.remove_repeated_points()),
             MultiLineString(arr) => MultiLineString(arr.remove_repeated_points()),
             MultiPolygon(arr) => MultiPolygon(arr.remove_repeated_points()),
+            GeometryCollection(arr) => GeometryCollection(Box::new(arr.remove_repeated_points())),
             Rect(arr) => Rect(arr.clone()),
         }
     }
@@ -1,6 +1,9 @@
+use crate::algorithm::broadcasting::BroadcastablePrimitive;
 use crate::algorithm::geo::{AffineOps, Center, Centroid};
 use crate::array::MultiPointArray;
 use crate::array::*;
+use crate::GeometryArrayTrait;
+use arrow_array::types::Float64Type;
 use arrow_array::{Float64Array, OffsetSizeTrait};
 use geo::AffineTransform;
 
@@ -8,6 +11,13 @@ use geo::AffineTransform;
 ///
 /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
 ///
+/// The angle can be a single `f64` applied to every geometry, a [`Float64Array`] with one angle per
+/// geometry, or a [`BroadcastablePrimitive`] holding either of them.
+///
+/// # Panics
+///
+/// - if an array of angles doesn't have one angle per geometry
+///
 /// ## Performance
 ///
 /// If you will be performing multiple transformations, like
@@ -98,9 +108,19 @@ pub trait Rotate<DegreesT> {
 // │ Implementations for RHS arrays │
 // └────────────────────────────────┘
 
+/// Check that `degrees` has one angle for each of the `len` geometries it rotates.
+fn assert_degrees_len(degrees: &Float64Array, len: usize) {
+    assert_eq!(
+        degrees.len(),
+        len,
+        "expected one rotation angle per geometry"
+    );
+}
+
 // Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
 impl Rotate<Float64Array> for PointArray {
     fn rotate_around_centroid(&self, degrees: &Float64Array) -> Self {
+        assert_degrees_len(degrees, self.len());
         let centroids = self.centroid();
         let transforms: Vec<AffineTransform> = centroids
             .iter_geo_values()
@@ -111,6 +131,7 @@ impl Rotate<Float64Array> for PointArray {
     }
 
     fn rotate_around_center(&self, degrees: &Float64Array) -> Self {
+        assert_degrees_len(degrees, self.len());
         let centers = self.center();
         let transforms: Vec<AffineTransform> = centers
             .iter_geo_values()
@@ -121,6 +142,7 @@ impl Rotate<Float64Array> for PointArray {
     }
 
     fn rotate_around_point(&self, degrees: &Float64Array, point: geo::Point) -> Self {
+        assert_degrees_len(degrees, self.len());
         let transforms: Vec<AffineTransform> = degrees
             .values()
             .iter()
@@ -135,6 +157,7 @@ macro_rules! iter_geo_impl {
     ($type:ty) => {
         impl<O: OffsetSizeTrait> Rotate<Float64Array> for $type {
             fn rotate_around_centroid(&self, degrees: &Float64Array) -> $type {
+                assert_degrees_len(degrees, self.len());
                 let centroids = self.centroid();
                 let transforms: Vec<AffineTransform> = centroids
                     .iter_geo_values()
@@ -145,6 +168,7 @@ macro_rules! iter_geo_impl {
             }
 
             fn rotate_around_center(&self, degrees: &Float64Array) -> Self {
+                assert_degrees_len(degrees, self.len());
                 let centers = self.center();
                 let transforms: Vec<AffineTransform> = centers
                     .iter_geo_values()
@@ -155,6 +179,7 @@ macro_rules! iter_geo_impl {
             }
 
             fn rotate_around_point(&self, degrees: &Float64Array, point: geo::Point) -> Self {
+                assert_degrees_len(degrees, self.len());
                 let transforms: Vec<AffineTransform> = degrees
                     .values()
                     .iter()
@@ -171,6 +196,7 @@ iter_geo_impl!(PolygonArray<O>);
 iter_geo_impl!(MultiPointArray<O>);
 iter_geo_impl!(MultiLineStringArray<O>);
 iter_geo_impl!(MultiPolygonArray<O>);
+iter_geo_impl!(GeometryCollectionArray<O>);
 iter_geo_impl!(WKBArray<O>);
 
 impl<O: OffsetSizeTrait> Rotate<Float64Array> for GeometryArray<O> {
@@ -246,6 +272,7 @@ iter_geo_impl_scalar!(PolygonArray<O>);
 iter_geo_impl_scalar!(MultiPointArray<O>);
 iter_geo_impl_scalar!(MultiLineStringArray<O>);
 iter_geo_impl_scalar!(MultiPolygonArray<O>);
+iter_geo_impl_scalar!(GeometryCollectionArray<O>);
 iter_geo_impl_scalar!(WKBArray<O>);
 
 impl<O: OffsetSizeTrait> Rotate<f64> for GeometryArray<O> {
@@ -255,3 +282,102 @@ impl<O: OffsetSizeTrait> Rotate<f64> for GeometryArray<O> {
         fn rotate_around_point(&self, degrees: &f64, point: geo::Point) -> Self;
     }
 }
+
+// ┌───────────────────────────────────────┐
+// │ Implementations for broadcastable RHS │
+// └───────────────────────────────────────┘
+
+/// Implementation that dispatches to the scalar or array implementation
+macro_rules! broadcast_impl {
+    ($type:ty $(, $generic:ident)?) => {
+        impl$(<$generic: OffsetSizeTrait>)? Rotate<BroadcastablePrimitive<Float64Type>> for $type {
+            fn rotate_around_centroid(&self, degrees: &BroadcastablePrimitive<Float64Type>) -> Self {
+                match degrees {
+                    BroadcastablePrimitive::Scalar(degrees) => self.rotate_around_centroid(degrees),
+                    BroadcastablePrimitive::Array(degrees) => self.rotate_around_centroid(degrees),
+                }
+            }
+
+            fn rotate_around_center(&self, degrees: &BroadcastablePrimitive<Float64Type>) -> Self {
+                match degrees {
+                    BroadcastablePrimitive::Scalar(degrees) => self.rotate_around_center(degrees),
+                    BroadcastablePrimitive::Array(degrees) => self.rotate_around_center(degrees),
+                }
+            }
+
+            fn rotate_around_point(
+                &self,
+                degrees: &BroadcastablePrimitive<Float64Type>,
+                point: geo::Point,
+            ) -> Self {
+                match degrees {
+                    BroadcastablePrimitive::Scalar(degrees) => {
+                        self.rotate_around_point(degrees, point)
+                    }
+                    BroadcastablePrimitive::Array(degrees) => {
+                        self.rotate_around_point(degrees, point)
+                    }
+                }
+            }
+        }
+    };
+}
+
+broadcast_impl!(PointArray);
+broadcast_impl!(LineStringArray<O>, O);
+broadcast_impl!(PolygonArray<O>, O);
+broadcast_impl!(MultiPointArray<O>, O);
+broadcast_impl!(MultiLineStringArray<O>, O);
+broadcast_impl!(MultiPolygonArray<O>, O);
+broadcast_impl!(GeometryCollectionArray<O>, O);
+broadcast_impl!(WKBArray<O>, O);
+broadcast_impl!(GeometryArray<O>, O);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeoArrayAccessor;
+    use approx::assert_relative_eq;
+    use geo::{line_string, point};
+
+    #[test]
+    fn rotate_around_point_per_element() {
+        let arr: PointArray = vec![point!(x: 1., y: 0.), point!(x: 1., y: 0.)].into();
+        let degrees = BroadcastablePrimitive::Array(vec![90., 180.].into());
+        let rotated = arr.rotate_around_point(&degrees, point!(x: 0., y: 0.));
+
+        assert_eq!(rotated.len(), 2);
+        assert_relative_eq!(rotated.value_as_geo(0), point!(x: 0., y: 1.));
+        assert_relative_eq!(rotated.value_as_geo(1), point!(x: -1., y: 0.));
+
+        // A scalar angle rotates every geometry by the same amount
+        let rotated =
+            arr.rotate_around_point(&BroadcastablePrimitive::Scalar(180.), point!(x: 0., y: 0.));
+        assert_relative_eq!(rotated.value_as_geo(0), point!(x: -1., y: 0.));
+        assert_relative_eq!(rotated.value_as_geo(1), point!(x: -1., y: 0.));
+    }
+
+    #[test]
+    fn rotate_line_strings_around_origin() {
+        let arr: LineStringArray<i32> =
+            vec![Some(line_string![(x: 1., y: 0.), (x: 2., y: 0.)]), None].into();
+        let rotated = arr.rotate_around_point(&90., point!(x: 0., y: 0.));
+
+        assert_eq!(rotated.geom_offsets.as_ref(), arr.geom_offsets.as_ref());
+        assert!(rotated.is_null(1));
+        assert_relative_eq!(
+            rotated.value_as_geo(0),
+            line_string![(x: 0., y: 1.), (x: 0., y: 2.)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "one rotation angle per geometry")]
+    fn rotate_around_point_length_mismatch() {
+        let arr: PointArray = vec![point!(x: 1., y: 0.), point!(x: 1., y: 0.)].into();
+        let degrees = BroadcastablePrimitive::Array(vec![90.].into());
+        let rotated = arr.rotate_around_point(&degrees, point!(x: 0., y: 0.));
+        // Not reached: one angle for two points must panic
+        assert_eq!(rotated.len(), arr.len());
+    }
+}
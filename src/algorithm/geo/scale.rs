@@ -1,9 +1,10 @@
 use crate::algorithm::broadcasting::BroadcastablePrimitive;
-use crate::array::LineStringArray;
+use crate::algorithm::geo::{AffineOps, Center};
 use crate::array::*;
+use crate::GeometryArrayTrait;
 use arrow_array::types::Float64Type;
 use arrow_array::OffsetSizeTrait;
-use geo::Scale as _Scale;
+use geo::{AffineTransform, Scale as _Scale};
 
 /// An affine transformation which scales geometries up or down by a factor.
 ///
@@ -99,10 +100,73 @@ pub trait Scale {
     // fn scale_around_point_mut(&mut self, x_factor: BroadcastablePrimitive<Float64Type>, y_factor: BroadcastablePrimitive<Float64Type>, origin: geo::Point);
 }
 
-// Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
-impl Scale for PointArray {
+/// One scale transform per geometry, around the matching point of `origins`. A null factor is
+/// taken as one.
+fn scale_transforms(
+    origins: impl Iterator<Item = geo::Point>,
+    x_factor: &BroadcastablePrimitive<Float64Type>,
+    y_factor: &BroadcastablePrimitive<Float64Type>,
+) -> Vec<AffineTransform> {
+    origins
+        .zip(x_factor)
+        .zip(y_factor)
+        .map(|((origin, x_factor), y_factor)| {
+            AffineTransform::scale(x_factor.unwrap_or(1.), y_factor.unwrap_or(1.), origin)
+        })
+        .collect()
+}
+
+/// Implementation through [`AffineOps`], which rewrites a copy of the coordinate buffer and keeps
+/// the offsets and validity of the input
+macro_rules! affine_impl {
+    ($type:ty $(, $generic:ident)?) => {
+        impl$(<$generic: OffsetSizeTrait>)? Scale for $type {
+            fn scale(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self {
+                let centers = self.center();
+                let transforms =
+                    scale_transforms(centers.iter_geo_values(), &scale_factor, &scale_factor);
+                self.affine_transform(&transforms)
+            }
+
+            fn scale_xy(
+                &self,
+                x_factor: BroadcastablePrimitive<Float64Type>,
+                y_factor: BroadcastablePrimitive<Float64Type>,
+            ) -> Self {
+                // Like geo, scale each geometry around the center of its bounding rect
+                let centers = self.center();
+                let transforms =
+                    scale_transforms(centers.iter_geo_values(), &x_factor, &y_factor);
+                self.affine_transform(&transforms)
+            }
+
+            fn scale_around_point(
+                &self,
+                x_factor: BroadcastablePrimitive<Float64Type>,
+                y_factor: BroadcastablePrimitive<Float64Type>,
+                origin: geo::Point,
+            ) -> Self {
+                let origins = std::iter::repeat(origin).take(self.len());
+                let transforms = scale_transforms(origins, &x_factor, &y_factor);
+                self.affine_transform(&transforms)
+            }
+        }
+    };
+}
+
+affine_impl!(PointArray);
+affine_impl!(LineStringArray<O>, O);
+affine_impl!(PolygonArray<O>, O);
+affine_impl!(MultiPointArray<O>, O);
+affine_impl!(MultiLineStringArray<O>, O);
+affine_impl!(MultiPolygonArray<O>, O);
+affine_impl!(GeometryCollectionArray<O>, O);
+affine_impl!(WKBArray<O>, O);
+
+// Scaling keeps rectangles axis-aligned, so the output is still a RectArray
+impl Scale for RectArray {
     fn scale(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self {
-        let output_geoms: Vec<Option<geo::Point>> = self
+        let output_geoms: Vec<Option<geo::Rect>> = self
             .iter_geo()
             .zip(&scale_factor)
             .map(|(maybe_g, scale_factor)| maybe_g.map(|geom| geom.scale(scale_factor.unwrap())))
@@ -116,7 +180,7 @@ impl Scale for PointArray {
         x_factor: BroadcastablePrimitive<Float64Type>,
         y_factor: BroadcastablePrimitive<Float64Type>,
     ) -> Self {
-        let output_geoms: Vec<Option<geo::Point>> = self
+        let output_geoms: Vec<Option<geo::Rect>> = self
             .iter_geo()
             .zip(&x_factor)
             .zip(&y_factor)
@@ -134,7 +198,7 @@ impl Scale for PointArray {
         y_factor: BroadcastablePrimitive<Float64Type>,
         origin: geo::Point,
     ) -> Self {
-        let output_geoms: Vec<Option<geo::Point>> = self
+        let output_geoms: Vec<Option<geo::Rect>> = self
             .iter_geo()
             .zip(&x_factor)
             .zip(&y_factor)
@@ -149,71 +213,10 @@ impl Scale for PointArray {
     }
 }
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
-    ($type:ty, $geo_type:ty) => {
-        impl<O: OffsetSizeTrait> Scale for $type {
-            fn scale(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .zip(scale_factor.into_iter())
-                    .map(|(maybe_g, scale_factor)| {
-                        maybe_g.map(|geom| geom.scale(scale_factor.unwrap()))
-                    })
-                    .collect();
-
-                output_geoms.into()
-            }
-
-            fn scale_xy(
-                &self,
-                x_factor: BroadcastablePrimitive<Float64Type>,
-                y_factor: BroadcastablePrimitive<Float64Type>,
-            ) -> Self {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .zip(x_factor.into_iter())
-                    .zip(y_factor.into_iter())
-                    .map(|((maybe_g, x_factor), y_factor)| {
-                        maybe_g.map(|geom| geom.scale_xy(x_factor.unwrap(), y_factor.unwrap()))
-                    })
-                    .collect();
-
-                output_geoms.into()
-            }
-
-            fn scale_around_point(
-                &self,
-                x_factor: BroadcastablePrimitive<Float64Type>,
-                y_factor: BroadcastablePrimitive<Float64Type>,
-                origin: geo::Point,
-            ) -> Self {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .zip(x_factor.into_iter())
-                    .zip(y_factor.into_iter())
-                    .map(|((maybe_g, x_factor), y_factor)| {
-                        maybe_g.map(|geom| {
-                            geom.scale_around_point(x_factor.unwrap(), y_factor.unwrap(), origin)
-                        })
-                    })
-                    .collect();
-
-                output_geoms.into()
-            }
-        }
-    };
-}
-
-iter_geo_impl!(LineStringArray<O>, geo::LineString);
-iter_geo_impl!(PolygonArray<O>, geo::Polygon);
-iter_geo_impl!(MultiPointArray<O>, geo::MultiPoint);
-iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
-iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
-iter_geo_impl!(WKBArray<O>, geo::Geometry);
-
 impl<O: OffsetSizeTrait> Scale for GeometryArray<O> {
     crate::geometry_array_delegate_impl! {
+        with_rect;
+
         fn scale(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self;
 
         fn scale_xy(
@@ -230,3 +233,39 @@ impl<O: OffsetSizeTrait> Scale for GeometryArray<O> {
         ) -> Self;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point::{p0, p1};
+    use crate::trait_::GeoArrayAccessor;
+
+    #[test]
+    fn point_fast_path_matches_geo() {
+        let arr: PointArray = vec![Some(p0()), None, Some(p1())].into();
+        let origin = geo::point!(x: 0.5, y: 0.25);
+
+        let expected: Vec<Option<geo::Point>> = (0..arr.len())
+            .map(|i| arr.get_as_geo(i).map(|g| g.scale_xy(3., 0.1)))
+            .collect();
+        let result = arr.scale_xy(
+            BroadcastablePrimitive::Scalar(3.),
+            BroadcastablePrimitive::Scalar(0.1),
+        );
+        assert_eq!(result, expected.into());
+
+        let expected: Vec<Option<geo::Point>> = (0..arr.len())
+            .map(|i| {
+                arr.get_as_geo(i)
+                    .map(|g| g.scale_around_point(3., 0.1, origin))
+            })
+            .collect();
+        let result = arr.scale_around_point(
+            BroadcastablePrimitive::Scalar(3.),
+            BroadcastablePrimitive::Scalar(0.1),
+            origin,
+        );
+        assert_eq!(result, expected.into());
+        assert!(result.is_null(1));
+    }
+}
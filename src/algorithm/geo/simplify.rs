@@ -1,16 +1,22 @@
+use crate::algorithm::native::OutputOptions;
 use crate::array::*;
+use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
-use geo::Simplify as _Simplify;
+use geo::{CoordsIter, Simplify as _Simplify};
 
 /// Simplifies a geometry.
 ///
 /// The [Ramer–Douglas–Peucker
 /// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm) simplifies a
 /// linestring. Polygons are simplified by running the RDP algorithm on all their constituent
-/// rings. This may result in invalid Polygons, and has no guarantee of preserving topology.
+/// rings. This has no guarantee of preserving topology.
 ///
 /// Multi* objects are simplified by simplifying all their constituent geometries individually.
 ///
+/// Rings that end up with fewer than the four coordinates of a closed ring are dropped. A polygon
+/// whose exterior is dropped becomes empty, and is removed from its multi polygon. Null
+/// geometries stay null, and the output keeps the coordinate layout of the input.
+///
 /// An epsilon less than or equal to zero will return an unaltered version of the geometry.
 pub trait Simplify {
     /// Returns the simplified representation of a geometry, using the [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm) algorithm
@@ -44,6 +50,9 @@ pub trait Simplify {
     /// assert_eq!(expected, simplified_array.value_as_geo(0))
     /// ```
     fn simplify(&self, epsilon: &f64) -> Self;
+
+    /// Simplify each geometry, building the output in the layout given by `options`.
+    fn simplify_with_options(&self, epsilon: &f64, options: &OutputOptions) -> Self;
 }
 
 // Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
@@ -51,6 +60,10 @@ impl Simplify for PointArray {
     fn simplify(&self, _epsilon: &f64) -> Self {
         self.clone()
     }
+
+    fn simplify_with_options(&self, _epsilon: &f64, options: &OutputOptions) -> Self {
+        self.clone().into_coord_type(options.coord_type.clone())
+    }
 }
 
 /// Implementation that returns the identity
@@ -60,6 +73,10 @@ macro_rules! identity_impl {
             fn simplify(&self, _epsilon: &f64) -> Self {
                 self.clone()
             }
+
+            fn simplify_with_options(&self, _epsilon: &f64, options: &OutputOptions) -> Self {
+                self.clone().into_coord_type(options.coord_type.clone())
+            }
         }
     };
 }
@@ -67,25 +84,181 @@ macro_rules! identity_impl {
 identity_impl!(MultiPointArray<O>);
 
 /// Implementation that iterates over geo objects
+///
+/// The output builder is sized from the simplified geometries with `$capacity_fn`, and filled
+/// with `$push_func`.
 macro_rules! iter_geo_impl {
-    ($type:ty, $geo_type:ty) => {
+    ($type:ty, $geo_type:ty, $mutable_type:ident, $push_func:ident, $capacity_fn:ident) => {
         impl<O: OffsetSizeTrait> Simplify for $type {
             fn simplify(&self, epsilon: &f64) -> Self {
+                self.simplify_with_options(epsilon, &OutputOptions::from_array(self))
+            }
+
+            fn simplify_with_options(&self, epsilon: &f64, options: &OutputOptions) -> Self {
                 let output_geoms: Vec<Option<$geo_type>> = self
                     .iter_geo()
-                    .map(|maybe_g| maybe_g.map(|geom| geom.simplify(epsilon)))
+                    .map(|maybe_g| maybe_g.map(|geom| geom.simplify_rings(epsilon)))
                     .collect();
 
-                output_geoms.into()
+                let mut output_array = $mutable_type::with_capacity_and_coord_type(
+                    $capacity_fn(&output_geoms),
+                    options.coord_type.clone(),
+                );
+                output_geoms
+                    .iter()
+                    .try_for_each(|maybe_g| output_array.$push_func(maybe_g.as_ref()))
+                    .unwrap();
+                output_array.into()
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, geo::LineString);
-iter_geo_impl!(PolygonArray<O>, geo::Polygon);
-iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
-iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
+iter_geo_impl!(
+    LineStringArray<O>,
+    geo::LineString,
+    MutableLineStringArray,
+    push_line_string,
+    line_string_capacity
+);
+iter_geo_impl!(
+    PolygonArray<O>,
+    geo::Polygon,
+    MutablePolygonArray,
+    push_polygon,
+    polygon_capacity
+);
+iter_geo_impl!(
+    MultiLineStringArray<O>,
+    geo::MultiLineString,
+    MutableMultiLineStringArray,
+    push_multi_line_string,
+    multi_line_string_capacity
+);
+iter_geo_impl!(
+    MultiPolygonArray<O>,
+    geo::MultiPolygon,
+    MutableMultiPolygonArray,
+    push_multi_polygon,
+    multi_polygon_capacity
+);
+
+impl<O: OffsetSizeTrait> Simplify for GeometryCollectionArray<O> {
+    fn simplify(&self, epsilon: &f64) -> Self {
+        self.simplify_with_options(epsilon, &OutputOptions::from_array(self))
+    }
+
+    fn simplify_with_options(&self, epsilon: &f64, options: &OutputOptions) -> Self {
+        let output_geoms: Vec<Option<geo::GeometryCollection>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| geom.simplify_rings(epsilon)))
+            .collect();
+
+        // Simplifying members can't nest the collections read from this array
+        let output: Self = output_geoms.try_into().unwrap();
+        output.into_coord_type(options.coord_type.clone())
+    }
+}
+
+/// geo's simplification, without the rings that collapsed below four coordinates.
+trait SimplifyRings {
+    fn simplify_rings(&self, epsilon: &f64) -> Self;
+}
+
+fn is_degenerate_ring(ring: &geo::LineString) -> bool {
+    ring.0.len() < 4
+}
+
+impl SimplifyRings for geo::LineString {
+    fn simplify_rings(&self, epsilon: &f64) -> Self {
+        self.simplify(epsilon)
+    }
+}
+
+impl SimplifyRings for geo::MultiLineString {
+    fn simplify_rings(&self, epsilon: &f64) -> Self {
+        self.simplify(epsilon)
+    }
+}
+
+impl SimplifyRings for geo::Polygon {
+    fn simplify_rings(&self, epsilon: &f64) -> Self {
+        let (exterior, interiors) = self.simplify(epsilon).into_inner();
+        if is_degenerate_ring(&exterior) {
+            return geo::Polygon::new(geo::LineString::new(vec![]), vec![]);
+        }
+
+        let interiors = interiors
+            .into_iter()
+            .filter(|ring| !is_degenerate_ring(ring))
+            .collect();
+        geo::Polygon::new(exterior, interiors)
+    }
+}
+
+impl SimplifyRings for geo::MultiPolygon {
+    fn simplify_rings(&self, epsilon: &f64) -> Self {
+        let polygons = self
+            .iter()
+            .map(|polygon| polygon.simplify_rings(epsilon))
+            .filter(|polygon| !is_degenerate_ring(polygon.exterior()))
+            .collect();
+        geo::MultiPolygon::new(polygons)
+    }
+}
+
+/// Each member is simplified on its own, and points and multi points are kept as they are.
+impl SimplifyRings for geo::GeometryCollection {
+    fn simplify_rings(&self, epsilon: &f64) -> Self {
+        self.iter()
+            .map(|geom| match geom {
+                geo::Geometry::LineString(g) => g.simplify_rings(epsilon).into(),
+                geo::Geometry::Polygon(g) => g.simplify_rings(epsilon).into(),
+                geo::Geometry::MultiLineString(g) => g.simplify_rings(epsilon).into(),
+                geo::Geometry::MultiPolygon(g) => g.simplify_rings(epsilon).into(),
+                geom => geom.clone(),
+            })
+            .collect()
+    }
+}
+
+pub(super) fn line_string_capacity(geoms: &[Option<geo::LineString>]) -> LineStringCapacity {
+    let coord = geoms.iter().flatten().map(|g| g.0.len()).sum();
+    LineStringCapacity::new(coord, geoms.len())
+}
+
+pub(super) fn polygon_capacity(geoms: &[Option<geo::Polygon>]) -> PolygonCapacity {
+    let mut capacity = PolygonCapacity::new(0, 0, geoms.len());
+    for polygon in geoms.iter().flatten() {
+        capacity.ring += 1 + polygon.interiors().len();
+        capacity.coord += polygon.coords_count();
+    }
+    capacity
+}
+
+pub(super) fn multi_line_string_capacity(
+    geoms: &[Option<geo::MultiLineString>],
+) -> MultiLineStringCapacity {
+    let mut capacity = MultiLineStringCapacity::new(0, 0, geoms.len());
+    for multi_line_string in geoms.iter().flatten() {
+        capacity.ring += multi_line_string.0.len();
+        capacity.coord += multi_line_string.coords_count();
+    }
+    capacity
+}
+
+pub(super) fn multi_polygon_capacity(geoms: &[Option<geo::MultiPolygon>]) -> MultiPolygonCapacity {
+    let mut capacity = MultiPolygonCapacity::new(0, 0, 0, geoms.len());
+    for multi_polygon in geoms.iter().flatten() {
+        capacity.polygon += multi_polygon.0.len();
+        capacity.ring += multi_polygon
+            .iter()
+            .map(|polygon| 1 + polygon.interiors().len())
+            .sum::<usize>();
+        capacity.coord += multi_polygon.coords_count();
+    }
+    capacity
+}
 
 impl<O: OffsetSizeTrait> Simplify for GeometryArray<O> {
     fn simplify(&self, epsilon: &f64) -> Self {
@@ -98,6 +271,24 @@ impl<O: OffsetSizeTrait> Simplify for GeometryArray<O> {
             MultiPoint(arr) => MultiPoint(arr.simplify(epsilon)),
             MultiLineString(arr) => MultiLineString(arr.simplify(epsilon)),
             MultiPolygon(arr) => MultiPolygon(arr.simplify(epsilon)),
+            GeometryCollection(arr) => GeometryCollection(Box::new(arr.simplify(epsilon))),
+            Rect(arr) => Rect(arr.clone()),
+        }
+    }
+
+    fn simplify_with_options(&self, epsilon: &f64, options: &OutputOptions) -> Self {
+        use GeometryArray::*;
+
+        match self {
+            Point(arr) => Point(arr.simplify_with_options(epsilon, options)),
+            LineString(arr) => LineString(arr.simplify_with_options(epsilon, options)),
+            Polygon(arr) => Polygon(arr.simplify_with_options(epsilon, options)),
+            MultiPoint(arr) => MultiPoint(arr.simplify_with_options(epsilon, options)),
+            MultiLineString(arr) => MultiLineString(arr.simplify_with_options(epsilon, options)),
+            MultiPolygon(arr) => MultiPolygon(arr.simplify_with_options(epsilon, options)),
+            GeometryCollection(arr) => {
+                GeometryCollection(Box::new(arr.simplify_with_options(epsilon, options)))
+            }
             Rect(arr) => Rect(arr.clone()),
         }
     }
@@ -106,7 +297,7 @@ impl<O: OffsetSizeTrait> Simplify for GeometryArray<O> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::array::{LineStringArray, PolygonArray};
+    use crate::array::{LineStringArray, MultiPolygonArray, PolygonArray};
     use crate::trait_::GeoArrayAccessor;
     use geo::{line_string, polygon};
 
@@ -155,4 +346,92 @@ mod tests {
 
         assert_eq!(expected, result_array.get_as_geo(0).unwrap());
     }
+
+    #[test]
+    fn geometry_collections() {
+        let straight = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)];
+        let collection = geo::GeometryCollection::new_from(vec![
+            geo::point!(x: 1., y: 1.).into(),
+            straight.into(),
+        ]);
+        let input_array: GeometryCollectionArray<i32> =
+            vec![Some(collection), None].try_into().unwrap();
+        let input_array = GeometryArray::GeometryCollection(Box::new(input_array));
+
+        let GeometryArray::GeometryCollection(result_array) = input_array.simplify(&0.1) else {
+            panic!("expected a geometry collection array");
+        };
+        let expected = geo::GeometryCollection::new_from(vec![
+            geo::point!(x: 1., y: 1.).into(),
+            line_string![(x: 0., y: 0.), (x: 2., y: 2.)].into(),
+        ]);
+        assert_eq!(result_array.value_as_geo(0), expected);
+        assert!(result_array.is_null(1));
+    }
+
+    #[test]
+    fn straight_line_and_nulls() {
+        let straight = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)];
+        let input_array: LineStringArray<i32> = vec![Some(straight), None].into();
+        let result_array = input_array.simplify(&0.1);
+
+        assert_eq!(
+            result_array.value_as_geo(0),
+            line_string![(x: 0., y: 0.), (x: 2., y: 2.)]
+        );
+        assert!(result_array.is_null(1));
+        assert_eq!(result_array.validity(), input_array.validity());
+    }
+
+    #[test]
+    fn degenerate_rings_are_dropped() {
+        let exterior =
+            geo::LineString::from(vec![(0., 0.), (0., 10.), (10., 10.), (10., 0.), (0., 0.)]);
+        // A ring of three coordinates, which can't be closed
+        let degenerate = geo::LineString::from(vec![(1., 1.), (1., 2.), (1., 1.)]);
+        let hole = geo::LineString::from(vec![(4., 4.), (4., 6.), (6., 6.), (6., 4.), (4., 4.)]);
+
+        let with_holes =
+            geo::Polygon::new(exterior.clone(), vec![degenerate.clone(), hole.clone()]);
+        let collapsed = geo::Polygon::new(degenerate, vec![]);
+        let input_array: PolygonArray<i32> =
+            vec![Some(with_holes), Some(collapsed.clone()), None].into();
+        let result_array = input_array.simplify(&0.5);
+
+        assert_eq!(
+            result_array.value_as_geo(0),
+            geo::Polygon::new(exterior.clone(), vec![hole])
+        );
+        assert!(result_array.value_as_geo(1).exterior().0.is_empty());
+        assert!(result_array.is_null(2));
+
+        let multi_array: MultiPolygonArray<i32> = vec![geo::MultiPolygon::new(vec![
+            geo::Polygon::new(exterior.clone(), vec![]),
+            collapsed,
+        ])]
+        .into();
+        assert_eq!(
+            multi_array.simplify(&0.5).value_as_geo(0),
+            geo::MultiPolygon::new(vec![geo::Polygon::new(exterior, vec![])])
+        );
+    }
+
+    #[test]
+    fn output_layout() {
+        let arr = crate::test::multipolygon::mp_array();
+        let simplified = arr.simplify(&1.0);
+        assert_eq!(simplified.coord_type(), CoordType::Interleaved);
+
+        let options = OutputOptions::default().with_coord_type(CoordType::Separated);
+        let separated = arr.simplify_with_options(&1.0, &options);
+        assert_eq!(separated.coord_type(), CoordType::Separated);
+        assert_eq!(separated, simplified.into_coord_type(CoordType::Separated));
+
+        // Without options, the input layout is kept
+        let separated_input = arr.into_coord_type(CoordType::Separated);
+        assert_eq!(
+            separated_input.simplify(&1.0).coord_type(),
+            CoordType::Separated
+        );
+    }
 }
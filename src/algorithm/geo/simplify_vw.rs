@@ -1,6 +1,10 @@
+use crate::algorithm::geo::simplify::{
+    line_string_capacity, multi_line_string_capacity, multi_polygon_capacity, polygon_capacity,
+};
 use crate::array::*;
+use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
-use geo::SimplifyVw as _SimplifyVw;
+use geo::{SimplifyVw as _SimplifyVw, SimplifyVwPreserve};
 
 /// Simplifies a geometry.
 ///
@@ -8,6 +12,8 @@ use geo::SimplifyVw as _SimplifyVw;
 /// result in invalid Polygons, and has no guarantee of preserving topology. Multi* objects are
 /// simplified by simplifying all their constituent geometries individually.
 ///
+/// Null geometries stay null, and the output keeps the coordinate layout of the input.
+///
 /// An epsilon less than or equal to zero will return an unaltered version of the geometry.
 pub trait SimplifyVw {
     /// Returns the simplified representation of a geometry, using the [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263) algorithm
@@ -42,6 +48,15 @@ pub trait SimplifyVw {
     /// assert_eq!(expected, simplified_array.value_as_geo(0))
     /// ```
     fn simplify_vw(&self, epsilon: &f64) -> Self;
+
+    /// Returns the simplified representation of a geometry, using a topology-preserving variant of
+    /// the [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263)
+    /// algorithm.
+    ///
+    /// Points are not removed if that would make a segment intersect another segment of the
+    /// geometry, so rings stay valid and don't cross each other. The output can keep more points
+    /// than [`simplify_vw`](Self::simplify_vw).
+    fn simplify_vw_preserve(&self, epsilon: &f64) -> Self;
 }
 
 // Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
@@ -49,6 +64,10 @@ impl SimplifyVw for PointArray {
     fn simplify_vw(&self, _epsilon: &f64) -> Self {
         self.clone()
     }
+
+    fn simplify_vw_preserve(&self, _epsilon: &f64) -> Self {
+        self.clone()
+    }
 }
 
 /// Implementation that returns the identity
@@ -58,32 +77,134 @@ macro_rules! identity_impl {
             fn simplify_vw(&self, _epsilon: &f64) -> Self {
                 self.clone()
             }
+
+            fn simplify_vw_preserve(&self, _epsilon: &f64) -> Self {
+                self.clone()
+            }
         }
     };
 }
 
 identity_impl!(MultiPointArray<O>);
 
+/// Simplify each geometry of `$array` with `$method`, building the output in the coordinate layout
+/// of the input
+macro_rules! simplify_geoms {
+    ($array:expr, $method:ident, $epsilon:expr, $geo_type:ty, $mutable_type:ident, $push_func:ident, $capacity_fn:ident) => {{
+        let output_geoms: Vec<Option<$geo_type>> = $array
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| geom.$method($epsilon)))
+            .collect();
+
+        let mut output_array = $mutable_type::with_capacity_and_coord_type(
+            $capacity_fn(&output_geoms),
+            $array.coord_type(),
+        );
+        output_geoms
+            .iter()
+            .try_for_each(|maybe_g| output_array.$push_func(maybe_g.as_ref()))
+            .unwrap();
+        output_array.into()
+    }};
+}
+
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl {
-    ($type:ty, $geo_type:ty) => {
+    ($type:ty, $geo_type:ty, $mutable_type:ident, $push_func:ident, $capacity_fn:ident) => {
         impl<O: OffsetSizeTrait> SimplifyVw for $type {
             fn simplify_vw(&self, epsilon: &f64) -> Self {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .map(|maybe_g| maybe_g.map(|geom| geom.simplify_vw(epsilon)))
-                    .collect();
+                simplify_geoms!(
+                    self,
+                    simplify_vw,
+                    epsilon,
+                    $geo_type,
+                    $mutable_type,
+                    $push_func,
+                    $capacity_fn
+                )
+            }
 
-                output_geoms.into()
+            fn simplify_vw_preserve(&self, epsilon: &f64) -> Self {
+                simplify_geoms!(
+                    self,
+                    simplify_vw_preserve,
+                    epsilon,
+                    $geo_type,
+                    $mutable_type,
+                    $push_func,
+                    $capacity_fn
+                )
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, geo::LineString);
-iter_geo_impl!(PolygonArray<O>, geo::Polygon);
-iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
-iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
+iter_geo_impl!(
+    LineStringArray<O>,
+    geo::LineString,
+    MutableLineStringArray,
+    push_line_string,
+    line_string_capacity
+);
+iter_geo_impl!(
+    PolygonArray<O>,
+    geo::Polygon,
+    MutablePolygonArray,
+    push_polygon,
+    polygon_capacity
+);
+iter_geo_impl!(
+    MultiLineStringArray<O>,
+    geo::MultiLineString,
+    MutableMultiLineStringArray,
+    push_multi_line_string,
+    multi_line_string_capacity
+);
+iter_geo_impl!(
+    MultiPolygonArray<O>,
+    geo::MultiPolygon,
+    MutableMultiPolygonArray,
+    push_multi_polygon,
+    multi_polygon_capacity
+);
+
+/// Simplify the members of each collection of `$array` with `$method`, keeping points and multi
+/// points as they are, and build the output in the coordinate layout of the input
+macro_rules! simplify_collections {
+    ($array:expr, $method:ident, $epsilon:expr) => {{
+        let output_geoms: Vec<Option<geo::GeometryCollection>> = $array
+            .iter_geo()
+            .map(|maybe_g| {
+                maybe_g.map(|collection| {
+                    collection
+                        .into_iter()
+                        .map(|geom| match geom {
+                            geo::Geometry::LineString(g) => g.$method($epsilon).into(),
+                            geo::Geometry::Polygon(g) => g.$method($epsilon).into(),
+                            geo::Geometry::MultiLineString(g) => g.$method($epsilon).into(),
+                            geo::Geometry::MultiPolygon(g) => g.$method($epsilon).into(),
+                            geom => geom,
+                        })
+                        .collect()
+                })
+            })
+            .collect();
+
+        // Simplifying members can't nest the collections read from this array
+        let output: GeometryCollectionArray<_> = output_geoms.try_into().unwrap();
+        output.into_coord_type($array.coord_type())
+    }};
+}
+
+impl<O: OffsetSizeTrait> SimplifyVw for GeometryCollectionArray<O> {
+    fn simplify_vw(&self, epsilon: &f64) -> Self {
+        simplify_collections!(self, simplify_vw, epsilon)
+    }
+
+    fn simplify_vw_preserve(&self, epsilon: &f64) -> Self {
+        simplify_collections!(self, simplify_vw_preserve, epsilon)
+    }
+}
 
 impl<O: OffsetSizeTrait> SimplifyVw for GeometryArray<O> {
     fn simplify_vw(&self, epsilon: &f64) -> Self {
@@ -96,7 +217,89 @@ impl<O: OffsetSizeTrait> SimplifyVw for GeometryArray<O> {
             MultiPoint(arr) => MultiPoint(arr.simplify_vw(epsilon)),
             MultiLineString(arr) => MultiLineString(arr.simplify_vw(epsilon)),
             MultiPolygon(arr) => MultiPolygon(arr.simplify_vw(epsilon)),
+            GeometryCollection(arr) => GeometryCollection(Box::new(arr.simplify_vw(epsilon))),
             Rect(arr) => Rect(arr.clone()),
         }
     }
+
+    fn simplify_vw_preserve(&self, epsilon: &f64) -> Self {
+        use GeometryArray::*;
+
+        match self {
+            Point(arr) => Point(arr.simplify_vw_preserve(epsilon)),
+            LineString(arr) => LineString(arr.simplify_vw_preserve(epsilon)),
+            Polygon(arr) => Polygon(arr.simplify_vw_preserve(epsilon)),
+            MultiPoint(arr) => MultiPoint(arr.simplify_vw_preserve(epsilon)),
+            MultiLineString(arr) => MultiLineString(arr.simplify_vw_preserve(epsilon)),
+            MultiPolygon(arr) => MultiPolygon(arr.simplify_vw_preserve(epsilon)),
+            GeometryCollection(arr) => {
+                GeometryCollection(Box::new(arr.simplify_vw_preserve(epsilon)))
+            }
+            Rect(arr) => Rect(arr.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geo::Simplify;
+    use crate::trait_::GeoArrayAccessor;
+    use geo::{line_string, CoordsIter};
+
+    fn zigzag() -> geo::LineString {
+        line_string![
+            (x: 10., y: 60.),
+            (x: 135., y: 68.),
+            (x: 94., y: 48.),
+            (x: 126., y: 31.),
+            (x: 280., y: 19.),
+            (x: 117., y: 48.),
+            (x: 300., y: 40.),
+            (x: 301., y: 10.),
+        ]
+    }
+
+    #[test]
+    fn diverges_from_rdp() {
+        let input_array: LineStringArray<i32> = vec![zigzag()].into();
+
+        // VW removes points by the area of their triangle and RDP by their distance to the
+        // simplified line, so the same epsilon keeps very different numbers of points
+        let vw = input_array.simplify_vw(&30.).value_as_geo(0);
+        let rdp = input_array.simplify(&30.).value_as_geo(0);
+        assert_eq!(vw.coords_count(), 8);
+        assert_eq!(rdp.coords_count(), 2);
+    }
+
+    #[test]
+    fn preserve_topology() {
+        let input_array: LineStringArray<i32> = vec![Some(zigzag()), None].into();
+        let result_array = input_array.simplify_vw_preserve(&668.6);
+
+        let expected = line_string![
+            (x: 10., y: 60.),
+            (x: 126., y: 31.),
+            (x: 280., y: 19.),
+            (x: 117., y: 48.),
+            (x: 300., y: 40.),
+            (x: 301., y: 10.),
+        ];
+        assert_eq!(result_array.value_as_geo(0), expected);
+        assert!(result_array.is_null(1));
+        assert_eq!(result_array.validity(), input_array.validity());
+    }
+
+    #[test]
+    fn output_layout() {
+        let arr = crate::test::polygon::p_array().into_coord_type(CoordType::Separated);
+
+        let simplified = arr.simplify_vw(&1.);
+        assert_eq!(simplified.coord_type(), CoordType::Separated);
+        assert_eq!(simplified.validity(), arr.validity());
+
+        let preserved = arr.simplify_vw_preserve(&1.);
+        assert_eq!(preserved.coord_type(), CoordType::Separated);
+        assert_eq!(preserved.validity(), arr.validity());
+    }
 }
@@ -1,12 +1,16 @@
 use crate::algorithm::broadcasting::BroadcastablePrimitive;
-use crate::array::LineStringArray;
+use crate::algorithm::geo::{AffineOps, Center};
 use crate::array::*;
+use crate::GeometryArrayTrait;
 use arrow_array::types::Float64Type;
 use arrow_array::OffsetSizeTrait;
-use geo::Skew as _Skew;
+use geo::AffineTransform;
 
 /// An affine transformation which skews a geometry, sheared by angles along x and y dimensions.
 ///
+/// Angles are in degrees. The coordinates are rewritten in a copy of the coordinate buffer, so the
+/// output keeps the offsets and validity of the input.
+///
 /// ## Performance
 ///
 /// If you will be performing multiple transformations, like
@@ -134,118 +138,71 @@ pub trait Skew {
     // );
 }
 
-// Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
-impl Skew for PointArray {
-    fn skew(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self {
-        let output_geoms: Vec<Option<geo::Point>> = self
-            .iter_geo()
-            .zip(&scale_factor)
-            .map(|(maybe_g, scale_factor)| maybe_g.map(|geom| geom.skew(scale_factor.unwrap())))
-            .collect();
-
-        output_geoms.into()
-    }
-
-    fn skew_xy(
-        &self,
-        x_factor: BroadcastablePrimitive<Float64Type>,
-        y_factor: BroadcastablePrimitive<Float64Type>,
-    ) -> Self {
-        let output_geoms: Vec<Option<geo::Point>> = self
-            .iter_geo()
-            .zip(&x_factor)
-            .zip(&y_factor)
-            .map(|((maybe_g, x_factor), y_factor)| {
-                maybe_g.map(|geom| geom.skew_xy(x_factor.unwrap(), y_factor.unwrap()))
-            })
-            .collect();
-
-        output_geoms.into()
-    }
-
-    fn skew_around_point(
-        &self,
-        x_factor: BroadcastablePrimitive<Float64Type>,
-        y_factor: BroadcastablePrimitive<Float64Type>,
-        origin: geo::Point,
-    ) -> Self {
-        let output_geoms: Vec<Option<geo::Point>> = self
-            .iter_geo()
-            .zip(&x_factor)
-            .zip(&y_factor)
-            .map(|((maybe_g, x_factor), y_factor)| {
-                maybe_g.map(|geom| {
-                    geom.skew_around_point(x_factor.unwrap(), y_factor.unwrap(), origin)
-                })
-            })
-            .collect();
-
-        output_geoms.into()
-    }
+/// One skew transform per geometry, around the matching point of `origins`. A null angle is
+/// taken as zero.
+fn skew_transforms(
+    origins: impl Iterator<Item = geo::Point>,
+    x_degrees: &BroadcastablePrimitive<Float64Type>,
+    y_degrees: &BroadcastablePrimitive<Float64Type>,
+) -> Vec<AffineTransform> {
+    origins
+        .zip(x_degrees)
+        .zip(y_degrees)
+        .map(|((origin, x_degrees), y_degrees)| {
+            AffineTransform::skew(
+                x_degrees.unwrap_or_default(),
+                y_degrees.unwrap_or_default(),
+                origin,
+            )
+        })
+        .collect()
 }
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
-    ($type:ty, $geo_type:ty) => {
-        impl<O: OffsetSizeTrait> Skew for $type {
-            fn skew(&self, scale_factor: BroadcastablePrimitive<Float64Type>) -> Self {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .zip(scale_factor.into_iter())
-                    .map(|(maybe_g, scale_factor)| {
-                        maybe_g.map(|geom| geom.skew(scale_factor.unwrap()))
-                    })
-                    .collect();
-
-                output_geoms.into()
+/// Implementation through [`AffineOps`], which rewrites a copy of the coordinate buffer and keeps
+/// the offsets and validity of the input
+macro_rules! affine_impl {
+    ($type:ty $(, $generic:ident)?) => {
+        impl$(<$generic: OffsetSizeTrait>)? Skew for $type {
+            fn skew(&self, degrees: BroadcastablePrimitive<Float64Type>) -> Self {
+                let centers = self.center();
+                let transforms = skew_transforms(centers.iter_geo_values(), &degrees, &degrees);
+                self.affine_transform(&transforms)
             }
 
             fn skew_xy(
                 &self,
-                x_factor: BroadcastablePrimitive<Float64Type>,
-                y_factor: BroadcastablePrimitive<Float64Type>,
+                x_degrees: BroadcastablePrimitive<Float64Type>,
+                y_degrees: BroadcastablePrimitive<Float64Type>,
             ) -> Self {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .zip(x_factor.into_iter())
-                    .zip(y_factor.into_iter())
-                    .map(|((maybe_g, x_factor), y_factor)| {
-                        maybe_g.map(|geom| geom.skew_xy(x_factor.unwrap(), y_factor.unwrap()))
-                    })
-                    .collect();
-
-                output_geoms.into()
+                // Like geo, skew each geometry around the center of its bounding rect
+                let centers = self.center();
+                let transforms =
+                    skew_transforms(centers.iter_geo_values(), &x_degrees, &y_degrees);
+                self.affine_transform(&transforms)
             }
 
             fn skew_around_point(
                 &self,
-                x_factor: BroadcastablePrimitive<Float64Type>,
-                y_factor: BroadcastablePrimitive<Float64Type>,
+                x_degrees: BroadcastablePrimitive<Float64Type>,
+                y_degrees: BroadcastablePrimitive<Float64Type>,
                 origin: geo::Point,
             ) -> Self {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .zip(x_factor.into_iter())
-                    .zip(y_factor.into_iter())
-                    .map(|((maybe_g, x_factor), y_factor)| {
-                        maybe_g.map(|geom| {
-                            geom.skew_around_point(x_factor.unwrap(), y_factor.unwrap(), origin)
-                        })
-                    })
-                    .collect();
-
-                output_geoms.into()
+                let origins = std::iter::repeat(origin).take(self.len());
+                let transforms = skew_transforms(origins, &x_degrees, &y_degrees);
+                self.affine_transform(&transforms)
             }
         }
     };
 }
 
-iter_geo_impl!(LineStringArray<O>, geo::LineString);
-iter_geo_impl!(PolygonArray<O>, geo::Polygon);
-iter_geo_impl!(MultiPointArray<O>, geo::MultiPoint);
-iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
-iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
-iter_geo_impl!(WKBArray<O>, geo::Geometry);
+affine_impl!(PointArray);
+affine_impl!(LineStringArray<O>, O);
+affine_impl!(PolygonArray<O>, O);
+affine_impl!(MultiPointArray<O>, O);
+affine_impl!(MultiLineStringArray<O>, O);
+affine_impl!(MultiPolygonArray<O>, O);
+affine_impl!(GeometryCollectionArray<O>, O);
+affine_impl!(WKBArray<O>, O);
 
 impl<O: OffsetSizeTrait> Skew for GeometryArray<O> {
     crate::geometry_array_delegate_impl! {
@@ -265,3 +222,53 @@ impl<O: OffsetSizeTrait> Skew for GeometryArray<O> {
         ) -> Self;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::polygon::p0;
+    use crate::trait_::GeoArrayAccessor;
+    use geo::{line_string, point, Skew as _Skew};
+
+    #[test]
+    fn zero_skew_is_identity() {
+        let arr: PolygonArray<i32> = vec![Some(p0()), None].into();
+        let skewed = arr.skew(BroadcastablePrimitive::Scalar(0.));
+        assert_eq!(skewed, arr);
+        assert_eq!(skewed.geom_offsets.as_ref(), arr.geom_offsets.as_ref());
+        assert_eq!(skewed.ring_offsets.as_ref(), arr.ring_offsets.as_ref());
+        assert!(skewed.is_null(1));
+    }
+
+    #[test]
+    fn skew_xy_shears_each_axis_independently() {
+        let ls = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let arr: LineStringArray<i32> = vec![ls.clone()].into();
+        let origin = point!(x: 0., y: 0.);
+
+        // A shear along x moves x by y · tan(angle) and leaves y alone
+        let skewed = arr.skew_around_point(
+            BroadcastablePrimitive::Scalar(45.),
+            BroadcastablePrimitive::Scalar(0.),
+            origin,
+        );
+        let expected = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 20., y: 10.)];
+        approx::assert_relative_eq!(skewed.value_as_geo(0), expected, epsilon = 1e-9);
+
+        // And the other way around
+        let skewed = arr.skew_around_point(
+            BroadcastablePrimitive::Scalar(0.),
+            BroadcastablePrimitive::Scalar(45.),
+            origin,
+        );
+        let expected = line_string![(x: 0., y: 0.), (x: 10., y: 10.), (x: 10., y: 20.)];
+        approx::assert_relative_eq!(skewed.value_as_geo(0), expected, epsilon = 1e-9);
+
+        // Around the bounding rect center, like geo
+        let skewed = arr.skew_xy(
+            BroadcastablePrimitive::Scalar(30.),
+            BroadcastablePrimitive::Scalar(12.),
+        );
+        approx::assert_relative_eq!(skewed.value_as_geo(0), ls.skew_xy(30., 12.), epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,271 @@
+use std::ops::Range;
+
+use crate::array::PointArray;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeoArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow::compute::partition;
+use arrow_array::builder::Float64Builder;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use arrow_array::{Array, ArrayRef, Float64Array};
+use arrow_schema::{DataType, TimeUnit};
+use geo::HaversineDistance;
+
+/// Distances and speeds along tracks of consecutive points.
+///
+/// Rows are consecutive points of one or more tracks. If `partition_key` is given, each run of
+/// equal keys is a separate track, and distances and speeds never span two tracks; the input is
+/// expected to be sorted by track and time. Distances are [haversine](geo::HaversineDistance)
+/// distances in meters.
+pub trait TrackOps {
+    /// The total distance travelled along the trailing `window` points up to and including each
+    /// row.
+    ///
+    /// A window shorter than two points, or the first point of a track, has a distance of zero.
+    /// Null points have a null result and contribute no distance to their neighbors' windows.
+    ///
+    /// # Errors
+    ///
+    /// - if `window` is zero
+    /// - if `partition_key` has a different length than `self`
+    fn rolling_distance(
+        &self,
+        window: usize,
+        partition_key: Option<&ArrayRef>,
+    ) -> Result<Float64Array>;
+
+    /// The distance travelled from the start of each row's track up to and including that row.
+    ///
+    /// Null points are handled like in [`rolling_distance`](Self::rolling_distance).
+    ///
+    /// # Errors
+    ///
+    /// - if `partition_key` has a different length than `self`
+    fn cumulative_distance(&self, partition_key: Option<&ArrayRef>) -> Result<Float64Array>;
+
+    /// The speed, in meters per second, between each point and the previous point of its track.
+    ///
+    /// `timestamps` must be an Arrow timestamp array of any unit. The result is null for the
+    /// first point of each track, and where either point or timestamp of the pair is null or the
+    /// timestamps are equal.
+    ///
+    /// # Errors
+    ///
+    /// - if `timestamps` is not a timestamp array
+    /// - if `timestamps` or `partition_key` have a different length than `self`
+    fn speed(
+        &self,
+        timestamps: &dyn Array,
+        partition_key: Option<&ArrayRef>,
+    ) -> Result<Float64Array>;
+}
+
+impl TrackOps for PointArray {
+    fn rolling_distance(
+        &self,
+        window: usize,
+        partition_key: Option<&ArrayRef>,
+    ) -> Result<Float64Array> {
+        if window == 0 {
+            return Err(GeoArrowError::General(
+                "window must contain at least one point".to_string(),
+            ));
+        }
+
+        let ranges = track_ranges(self, partition_key)?;
+        let cumulative = cumulative_steps(self, &ranges);
+        let mut output_array = Float64Builder::with_capacity(self.len());
+        for range in ranges {
+            for i in range.clone() {
+                if self.is_null(i) {
+                    output_array.append_null();
+                    continue;
+                }
+
+                let window_start = range.start.max((i + 1).saturating_sub(window));
+                output_array.append_value(cumulative[i] - cumulative[window_start]);
+            }
+        }
+
+        Ok(output_array.finish())
+    }
+
+    fn cumulative_distance(&self, partition_key: Option<&ArrayRef>) -> Result<Float64Array> {
+        let cumulative = cumulative_steps(self, &track_ranges(self, partition_key)?);
+        let output_array: Float64Array = cumulative
+            .into_iter()
+            .enumerate()
+            .map(|(i, distance)| self.is_valid(i).then_some(distance))
+            .collect();
+        Ok(output_array)
+    }
+
+    fn speed(
+        &self,
+        timestamps: &dyn Array,
+        partition_key: Option<&ArrayRef>,
+    ) -> Result<Float64Array> {
+        let seconds_per_unit = match timestamps.data_type() {
+            DataType::Timestamp(TimeUnit::Second, _) => 1.,
+            DataType::Timestamp(TimeUnit::Millisecond, _) => 1e-3,
+            DataType::Timestamp(TimeUnit::Microsecond, _) => 1e-6,
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => 1e-9,
+            data_type => {
+                return Err(GeoArrowError::General(format!(
+                    "Expected a timestamp array, got {:?}",
+                    data_type
+                )))
+            }
+        };
+        if timestamps.len() != self.len() {
+            return Err(GeoArrowError::General(
+                "timestamps must have the same length as points".to_string(),
+            ));
+        }
+        let timestamps = arrow::compute::cast(timestamps, &DataType::Int64)?;
+        let timestamps = timestamps.as_primitive::<Int64Type>();
+
+        let mut output_array = Float64Builder::with_capacity(self.len());
+        for range in track_ranges(self, partition_key)? {
+            if range.is_empty() {
+                continue;
+            }
+
+            output_array.append_null();
+            for i in range.start + 1..range.end {
+                let speed = step_distance(self, i)
+                    .filter(|_| timestamps.is_valid(i - 1) && timestamps.is_valid(i))
+                    .and_then(|distance| {
+                        let seconds = (timestamps.value(i) - timestamps.value(i - 1)) as f64
+                            * seconds_per_unit;
+                        (seconds != 0.).then(|| distance / seconds)
+                    });
+                output_array.append_option(speed);
+            }
+        }
+
+        Ok(output_array.finish())
+    }
+}
+
+/// The row range of each track. Without a partition key, all rows are one track.
+fn track_ranges(
+    points: &PointArray,
+    partition_key: Option<&ArrayRef>,
+) -> Result<Vec<Range<usize>>> {
+    match partition_key {
+        None => {
+            let all_rows = 0..points.len();
+            Ok(vec![all_rows])
+        }
+        Some(key) => {
+            if key.len() != points.len() {
+                return Err(GeoArrowError::General(
+                    "partition key must have the same length as points".to_string(),
+                ));
+            }
+            Ok(partition(std::slice::from_ref(key))?.ranges())
+        }
+    }
+}
+
+/// The distance from the previous row to row `i`, if both are valid. `i` must not be the first
+/// row of a track.
+fn step_distance(points: &PointArray, i: usize) -> Option<f64> {
+    if points.is_null(i - 1) || points.is_null(i) {
+        return None;
+    }
+
+    Some(
+        points
+            .value_as_geo(i - 1)
+            .haversine_distance(&points.value_as_geo(i)),
+    )
+}
+
+/// The running sum of step distances within each track, where the first row of each track is
+/// zero.
+fn cumulative_steps(points: &PointArray, ranges: &[Range<usize>]) -> Vec<f64> {
+    let mut cumulative = vec![0.; points.len()];
+    for range in ranges {
+        for i in range.start + 1..range.end {
+            cumulative[i] = cumulative[i - 1] + step_distance(points, i).unwrap_or(0.);
+        }
+    }
+    cumulative
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::*;
+    use approx::assert_relative_eq;
+    use arrow_array::{StringArray, TimestampSecondArray};
+
+    /// Two tracks moving north along a meridian, one degree of latitude per point.
+    fn tracks() -> (PointArray, ArrayRef) {
+        let points: PointArray = vec![
+            Some(geo::point!(x: 0., y: 0.)),
+            Some(geo::point!(x: 0., y: 1.)),
+            Some(geo::point!(x: 0., y: 2.)),
+            Some(geo::point!(x: 0., y: 3.)),
+            Some(geo::point!(x: 10., y: 0.)),
+            None,
+            Some(geo::point!(x: 10., y: 2.)),
+        ]
+        .into();
+        let key: ArrayRef = Arc::new(StringArray::from(vec!["a", "a", "a", "a", "b", "b", "b"]));
+        (points, key)
+    }
+
+    fn one_degree() -> f64 {
+        geo::point!(x: 0., y: 0.).haversine_distance(&geo::point!(x: 0., y: 1.))
+    }
+
+    #[test]
+    fn cumulative() {
+        let (points, key) = tracks();
+        let result = points.cumulative_distance(Some(&key)).unwrap();
+        let expected = [0., 1., 2., 3., 0.];
+        for (i, expected) in expected.iter().enumerate() {
+            assert_relative_eq!(result.value(i), expected * one_degree(), epsilon = 1e-6);
+        }
+        assert!(result.is_null(5));
+        assert_relative_eq!(result.value(6), 0.);
+    }
+
+    #[test]
+    fn rolling() {
+        let (points, key) = tracks();
+        let result = points.rolling_distance(2, Some(&key)).unwrap();
+        let expected = [0., 1., 1., 1., 0.];
+        for (i, expected) in expected.iter().enumerate() {
+            assert_relative_eq!(result.value(i), expected * one_degree(), epsilon = 1e-6);
+        }
+
+        // Without the partition key, the first point of track b continues track a
+        let result = points.rolling_distance(3, None).unwrap();
+        assert_relative_eq!(result.value(3), 2. * one_degree());
+        assert!(result.value(4) > 3. * one_degree());
+
+        assert!(points.rolling_distance(0, None).is_err());
+    }
+
+    #[test]
+    fn speed_between_rows() {
+        let (points, key) = tracks();
+        let timestamps = TimestampSecondArray::from(vec![0, 100, 200, 200, 0, 100, 200]);
+        let result = points.speed(&timestamps, Some(&key)).unwrap();
+        assert!(result.is_null(0));
+        assert_relative_eq!(result.value(1), one_degree() / 100.);
+        assert_relative_eq!(result.value(2), one_degree() / 100.);
+        // Equal timestamps
+        assert!(result.is_null(3));
+        // Start of track b, and rows adjacent to the null point
+        assert!(result.is_null(4));
+        assert!(result.is_null(5));
+        assert!(result.is_null(6));
+    }
+}
@@ -1,8 +1,10 @@
 use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::algorithm::geo::AffineOps;
 use crate::array::*;
+use crate::GeometryArrayTrait;
 use arrow_array::types::Float64Type;
 use arrow_array::OffsetSizeTrait;
-use geo::Translate as _Translate;
+use geo::{AffineTransform, Translate as _Translate};
 
 pub trait Translate {
     /// Translate a Geometry along its axes by the given offsets
@@ -47,14 +49,49 @@ pub trait Translate {
     // fn translate_mut(&mut self, x_offset: T, y_offset: T);
 }
 
-// Note: this can't (easily) be parameterized in the macro because PointArray is not generic over O
-impl Translate for PointArray {
+/// Implementation through [`AffineOps`], which rewrites a copy of the coordinate buffer and keeps
+/// the offsets and validity of the input. A null offset is taken as zero.
+macro_rules! affine_impl {
+    ($type:ty $(, $generic:ident)?) => {
+        impl$(<$generic: OffsetSizeTrait>)? Translate for $type {
+            fn translate(
+                &self,
+                x_offset: BroadcastablePrimitive<Float64Type>,
+                y_offset: BroadcastablePrimitive<Float64Type>,
+            ) -> Self {
+                let transforms: Vec<AffineTransform> = (&x_offset)
+                    .into_iter()
+                    .zip(&y_offset)
+                    .take(self.len())
+                    .map(|(x_offset, y_offset)| {
+                        AffineTransform::translate(
+                            x_offset.unwrap_or_default(),
+                            y_offset.unwrap_or_default(),
+                        )
+                    })
+                    .collect();
+                self.affine_transform(&transforms)
+            }
+        }
+    };
+}
+
+affine_impl!(PointArray);
+affine_impl!(LineStringArray<O>, O);
+affine_impl!(PolygonArray<O>, O);
+affine_impl!(MultiPointArray<O>, O);
+affine_impl!(MultiLineStringArray<O>, O);
+affine_impl!(MultiPolygonArray<O>, O);
+affine_impl!(GeometryCollectionArray<O>, O);
+affine_impl!(WKBArray<O>, O);
+
+impl Translate for RectArray {
     fn translate(
         &self,
         x_offset: BroadcastablePrimitive<Float64Type>,
         y_offset: BroadcastablePrimitive<Float64Type>,
     ) -> Self {
-        let output_geoms: Vec<Option<geo::Point>> = self
+        let output_geoms: Vec<Option<geo::Rect>> = self
             .iter_geo()
             .zip(&x_offset)
             .zip(&y_offset)
@@ -67,39 +104,10 @@ impl Translate for PointArray {
     }
 }
 
-/// Implementation that iterates over geo objects
-macro_rules! iter_geo_impl {
-    ($type:ty, $geo_type:ty) => {
-        impl<O: OffsetSizeTrait> Translate for $type {
-            fn translate(
-                &self,
-                x_offset: BroadcastablePrimitive<Float64Type>,
-                y_offset: BroadcastablePrimitive<Float64Type>,
-            ) -> Self {
-                let output_geoms: Vec<Option<$geo_type>> = self
-                    .iter_geo()
-                    .zip(x_offset.into_iter())
-                    .zip(y_offset.into_iter())
-                    .map(|((maybe_g, x_offset), y_offset)| {
-                        maybe_g.map(|geom| geom.translate(x_offset.unwrap(), y_offset.unwrap()))
-                    })
-                    .collect();
-
-                output_geoms.into()
-            }
-        }
-    };
-}
-
-iter_geo_impl!(LineStringArray<O>, geo::LineString);
-iter_geo_impl!(PolygonArray<O>, geo::Polygon);
-iter_geo_impl!(MultiPointArray<O>, geo::MultiPoint);
-iter_geo_impl!(MultiLineStringArray<O>, geo::MultiLineString);
-iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon);
-iter_geo_impl!(WKBArray<O>, geo::Geometry);
-
 impl<O: OffsetSizeTrait> Translate for GeometryArray<O> {
     crate::geometry_array_delegate_impl! {
+        with_rect;
+
         fn translate(
             &self,
             x_offset: BroadcastablePrimitive<Float64Type>,
@@ -107,3 +115,25 @@ impl<O: OffsetSizeTrait> Translate for GeometryArray<O> {
         ) -> Self;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point::{p0, p1};
+    use crate::trait_::GeoArrayAccessor;
+
+    #[test]
+    fn point_fast_path_matches_geo() {
+        let arr: PointArray = vec![Some(p0()), None, Some(p1())].into();
+        let result = arr.translate(
+            BroadcastablePrimitive::Scalar(1.5),
+            BroadcastablePrimitive::Scalar(-2.),
+        );
+
+        let expected: Vec<Option<geo::Point>> = (0..arr.len())
+            .map(|i| arr.get_as_geo(i).map(|g| g.translate(1.5, -2.)))
+            .collect();
+        assert_eq!(result, expected.into());
+        assert!(result.is_null(1));
+    }
+}
@@ -1,23 +1,71 @@
 use arrow_array::Float64Array;
 use arrow_buffer::NullBuffer;
 
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+
 pub(crate) fn zeroes(len: usize, nulls: Option<&NullBuffer>) -> Float64Array {
     let values = vec![0.0f64; len];
     Float64Array::new(values.into(), nulls.cloned())
 }
 
+/// Check that two arrays can be combined row by row, which needs them to have the same length
+/// and the same [`Edges`](crate::array::metadata::Edges).
+pub(crate) fn check_row_pairs<'a>(
+    left: &impl GeometryArrayTrait<'a>,
+    right: &impl GeometryArrayTrait<'a>,
+) -> Result<()> {
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(format!(
+            "Expected arrays of the same length, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+    left.metadata().common_edges(right.metadata())?;
+    Ok(())
+}
+
 /// Implements the common pattern where a [`GeometryArray`][crate::array::GeometryArray] enum
 /// simply delegates its trait impl to it's inner type.
 ///
+/// Traits that are also implemented for [`RectArray`][crate::array::RectArray] start with
+/// `with_rect;` so that the `Rect` variant is delegated too.
+///
 // This is derived from geo https://github.com/georust/geo/blob/d4c858308ba910f69beab175e08af263b17c5f9f/geo/src/types.rs#L119-L158
 #[macro_export]
 macro_rules! geometry_array_delegate_impl {
+    (with_rect; $($a:tt)*) => { $crate::__geometry_array_delegate_impl_helper!{ GeometryArray, with_rect, $($a)* } };
     ($($a:tt)*) => { $crate::__geometry_array_delegate_impl_helper!{ GeometryArray, $($a)* } }
 }
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __geometry_array_delegate_impl_helper {
+    (
+        $enum:ident,
+        with_rect,
+        $(
+            $(#[$outer:meta])*
+            fn $func_name: ident(&$($self_life:lifetime)?self $(, $arg_name: ident: $arg_type: ty)*) -> $return: ty;
+         )+
+    ) => {
+            $(
+                $(#[$outer])*
+                fn $func_name(&$($self_life)? self, $($arg_name: $arg_type),*) -> $return {
+                    match self {
+                        $enum::Point(g) => g.$func_name($($arg_name),*).into(),
+                        $enum::LineString(g) => g.$func_name($($arg_name),*).into(),
+                        $enum::Polygon(g) => g.$func_name($($arg_name),*).into(),
+                        $enum::MultiPoint(g) => g.$func_name($($arg_name),*).into(),
+                        $enum::MultiLineString(g) => g.$func_name($($arg_name),*).into(),
+                        $enum::MultiPolygon(g) => g.$func_name($($arg_name),*).into(),
+                        $enum::GeometryCollection(g) => g.$func_name($($arg_name),*).into(),
+                        $enum::Rect(g) => g.$func_name($($arg_name),*).into(),
+                    }
+                }
+            )+
+        };
     (
         $enum:ident,
         $(
@@ -36,7 +84,7 @@ macro_rules! __geometry_array_delegate_impl_helper {
                         $enum::MultiPoint(g) => g.$func_name($($arg_name),*).into(),
                         $enum::MultiLineString(g) => g.$func_name($($arg_name),*).into(),
                         $enum::MultiPolygon(g) => g.$func_name($($arg_name),*).into(),
-                        // $enum::GeometryCollection(g) => g.$func_name($($arg_name),*).into(),
+                        $enum::GeometryCollection(g) => g.$func_name($($arg_name),*).into(),
                         $enum::Rect(_g) => todo!(),
                         // $enum::Rect(g) => g.$func_name($($arg_name),*).into(),
                         // $enum::Triangle(g) => g.$func_name($($arg_name),*).into(),
@@ -0,0 +1,132 @@
+use crate::array::*;
+use crate::trait_::GeoArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::builder::BooleanBuilder;
+use arrow_array::{BooleanArray, OffsetSizeTrait};
+use geo::winding_order::{Winding, WindingOrder};
+
+/// The expected winding order of polygon rings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WindingConvention {
+    /// Exterior rings are counter-clockwise and interior rings are clockwise, as required by OGC
+    /// Simple Features and GeoJSON ([RFC 7946](https://www.rfc-editor.org/rfc/rfc7946#section-3.1.6)).
+    #[default]
+    CounterClockwiseExterior,
+
+    /// Exterior rings are clockwise and interior rings are counter-clockwise, as used by ESRI
+    /// Shapefiles.
+    ClockwiseExterior,
+}
+
+impl WindingConvention {
+    fn exterior(&self) -> WindingOrder {
+        match self {
+            WindingConvention::CounterClockwiseExterior => WindingOrder::CounterClockwise,
+            WindingConvention::ClockwiseExterior => WindingOrder::Clockwise,
+        }
+    }
+
+    fn interior(&self) -> WindingOrder {
+        match self.exterior() {
+            WindingOrder::CounterClockwise => WindingOrder::Clockwise,
+            WindingOrder::Clockwise => WindingOrder::CounterClockwise,
+        }
+    }
+
+    fn is_valid(&self, polygon: &geo::Polygon) -> bool {
+        // Empty polygons have no rings to check
+        if polygon.exterior().0.is_empty() {
+            return true;
+        }
+
+        // Degenerate rings have no winding order, so they never match
+        polygon.exterior().winding_order() == Some(self.exterior())
+            && polygon
+                .interiors()
+                .iter()
+                .all(|ring| ring.winding_order() == Some(self.interior()))
+    }
+}
+
+/// Check the winding order of polygon rings.
+pub trait ValidateWinding {
+    /// Whether every ring of each geometry follows the
+    /// [OGC convention](WindingConvention::CounterClockwiseExterior): counter-clockwise exterior
+    /// rings and clockwise interior rings.
+    ///
+    /// Empty geometries are valid. Rings without a winding order, such as rings with fewer than
+    /// three distinct points, are not. Null geometries give null.
+    fn validate_winding(&self) -> BooleanArray {
+        self.validate_winding_with(WindingConvention::default())
+    }
+
+    /// Whether every ring of each geometry follows the given convention.
+    ///
+    /// See [`validate_winding`](Self::validate_winding) for how empty and degenerate rings are
+    /// handled.
+    fn validate_winding_with(&self, convention: WindingConvention) -> BooleanArray;
+}
+
+impl<O: OffsetSizeTrait> ValidateWinding for PolygonArray<O> {
+    fn validate_winding_with(&self, convention: WindingConvention) -> BooleanArray {
+        let mut output_array = BooleanBuilder::with_capacity(self.len());
+        for i in 0..self.len() {
+            output_array.append_option(self.get_as_geo(i).map(|g| convention.is_valid(&g)));
+        }
+        output_array.finish()
+    }
+}
+
+impl<O: OffsetSizeTrait> ValidateWinding for MultiPolygonArray<O> {
+    fn validate_winding_with(&self, convention: WindingConvention) -> BooleanArray {
+        let mut output_array = BooleanBuilder::with_capacity(self.len());
+        for i in 0..self.len() {
+            output_array.append_option(
+                self.get_as_geo(i)
+                    .map(|g| g.0.iter().all(|polygon| convention.is_valid(polygon))),
+            );
+        }
+        output_array.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::Array;
+    use geo::polygon;
+
+    #[test]
+    fn clockwise_exterior() {
+        let ccw = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let cw = polygon![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.), (x: 1., y: 0.)];
+        let arr: PolygonArray<i32> = vec![Some(ccw.clone()), Some(cw.clone()), None].into();
+
+        let result = arr.validate_winding();
+        assert!(result.value(0));
+        assert!(!result.value(1));
+        assert!(result.is_null(2));
+
+        let result = arr.validate_winding_with(WindingConvention::ClockwiseExterior);
+        assert!(!result.value(0));
+        assert!(result.value(1));
+
+        let multi: MultiPolygonArray<i32> =
+            vec![geo::MultiPolygon::new(vec![ccw.clone(), ccw, cw])].into();
+        assert!(!multi.validate_winding().value(0));
+    }
+
+    #[test]
+    fn interior_winding() {
+        let exterior = vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)];
+        let cw_interior = vec![(2., 2.), (2., 4.), (4., 4.), (4., 2.), (2., 2.)];
+        let ccw_interior = vec![(2., 2.), (4., 2.), (4., 4.), (2., 4.), (2., 2.)];
+        let valid = geo::Polygon::new(exterior.clone().into(), vec![cw_interior.into()]);
+        let invalid = geo::Polygon::new(exterior.into(), vec![ccw_interior.into()]);
+        let arr: PolygonArray<i32> = vec![valid, invalid].into();
+
+        let result = arr.validate_winding();
+        assert!(result.value(0));
+        assert!(!result.value(1));
+    }
+}
@@ -1,4 +1,6 @@
+use crate::algorithm::geo::utils::check_row_pairs;
 use crate::array::*;
+use crate::error::Result;
 use crate::scalar::*;
 use crate::trait_::GeometryScalarTrait;
 use crate::GeometryArrayTrait;
@@ -41,7 +43,11 @@ use geo::Within as _Within;
 ///
 /// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
 pub trait Within<Other = Self> {
-    fn is_within(&self, b: &Other) -> BooleanArray;
+    /// # Errors
+    ///
+    /// - if `b` is an array whose length differs from this array's
+    /// - if `b` is an array with different [`Edges`](crate::array::metadata::Edges)
+    fn is_within(&self, b: &Other) -> Result<BooleanArray>;
 }
 
 // ┌────────────────────────────────┐
@@ -50,8 +56,8 @@ pub trait Within<Other = Self> {
 
 // Note: this implementation is outside the macro because it is not generic over O
 impl Within for PointArray {
-    fn is_within(&self, rhs: &Self) -> BooleanArray {
-        assert_eq!(self.len(), rhs.len());
+    fn is_within(&self, rhs: &Self) -> Result<BooleanArray> {
+        check_row_pairs(self, rhs)?;
 
         let mut output_array = BooleanBuilder::with_capacity(self.len());
 
@@ -62,7 +68,7 @@ impl Within for PointArray {
                 _ => output_array.append_null(),
             });
 
-        output_array.finish()
+        Ok(output_array.finish())
     }
 }
 
@@ -70,8 +76,8 @@ impl Within for PointArray {
 macro_rules! iter_geo_impl {
     ($first:ty, $second:ty) => {
         impl<'a, O: OffsetSizeTrait> Within<$second> for $first {
-            fn is_within(&self, rhs: &$second) -> BooleanArray {
-                assert_eq!(self.len(), rhs.len());
+            fn is_within(&self, rhs: &$second) -> Result<BooleanArray> {
+                check_row_pairs(self, rhs)?;
 
                 let mut output_array = BooleanBuilder::with_capacity(self.len());
 
@@ -84,7 +90,7 @@ macro_rules! iter_geo_impl {
                         _ => output_array.append_null(),
                     });
 
-                output_array.finish()
+                Ok(output_array.finish())
             }
         }
     };
@@ -143,7 +149,7 @@ iter_geo_impl!(MultiPolygonArray<O>, MultiPolygonArray<O>);
 
 // Note: this implementation is outside the macro because it is not generic over O
 impl<'a> Within<Point<'a>> for PointArray {
-    fn is_within(&self, rhs: &Point<'a>) -> BooleanArray {
+    fn is_within(&self, rhs: &Point<'a>) -> Result<BooleanArray> {
         let mut output_array = BooleanBuilder::with_capacity(self.len());
 
         self.iter_geo().for_each(|maybe_point| {
@@ -151,7 +157,7 @@ impl<'a> Within<Point<'a>> for PointArray {
             output_array.append_option(output)
         });
 
-        output_array.finish()
+        Ok(output_array.finish())
     }
 }
 
@@ -159,7 +165,7 @@ impl<'a> Within<Point<'a>> for PointArray {
 macro_rules! iter_geo_impl_scalar {
     ($first:ty, $second:ty) => {
         impl<'a, O: OffsetSizeTrait> Within<$second> for $first {
-            fn is_within(&self, rhs: &$second) -> BooleanArray {
+            fn is_within(&self, rhs: &$second) -> Result<BooleanArray> {
                 let mut output_array = BooleanBuilder::with_capacity(self.len());
                 let rhs_geo = rhs.to_geo();
 
@@ -168,7 +174,7 @@ macro_rules! iter_geo_impl_scalar {
                     output_array.append_option(output)
                 });
 
-                output_array.finish()
+                Ok(output_array.finish())
             }
         }
     };
@@ -2,9 +2,9 @@
 //! geodesic operations.
 //!
 //! Note that this library does **not** aim to be a PROJ "rewrite in Rust". Consult the [library's
-//! documentation][geodesy] for how to construct the projection string to pass into `reproject`.
+//! documentation][geodesy] for how to construct the projection string to pass into [`Reproject::reproject`].
 
 mod reproject;
 
 pub use geodesy::Direction;
-pub use reproject::reproject;
+pub use reproject::{GeodesyTransform, Reproject};
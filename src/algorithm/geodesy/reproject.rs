@@ -1,31 +1,13 @@
-use crate::array::{CoordBuffer, GeometryArray, InterleavedCoordBuffer, SeparatedCoordBuffer};
-use crate::error::Result;
-use crate::GeometryArrayTrait;
+use crate::algorithm::native::transform::{
+    transform_geometry_array, CoordTransform, TransformOptions,
+};
+use crate::array::*;
+use crate::error::{GeoArrowError, Result};
 use arrow_array::OffsetSizeTrait;
 use geodesy::prelude::*;
 use geodesy::Coord;
 use geodesy::Direction;
 
-/// Wrapper object for applying coordinate operations slices
-struct InterleavedCoordsGeodesy<'a>(&'a mut [f64]);
-
-impl CoordinateSet for InterleavedCoordsGeodesy<'_> {
-    fn len(&self) -> usize {
-        self.0.len() / 2
-    }
-
-    fn get_coord(&self, index: usize) -> Coord {
-        Coord([self.0[index * 2], self.0[index * 2 + 1], 0., 0.])
-    }
-
-    fn set_coord(&mut self, index: usize, value: &Coord) {
-        let x = value[0];
-        let y = value[1];
-        self.0[index * 2] = x;
-        self.0[index * 2 + 1] = y;
-    }
-}
-
 /// Wrapper object for applying coordinate operations slices
 struct SeparatedCoordsGeodesy<'a> {
     x: &'a mut [f64],
@@ -47,86 +29,106 @@ impl CoordinateSet for SeparatedCoordsGeodesy<'_> {
     }
 }
 
-fn reproject_coords(
-    coords: &CoordBuffer,
-    definition: &str,
+/// A [`geodesy`] operation, applied in one direction, as a [`CoordTransform`].
+///
+/// geodesy sets the coordinates it fails to transform to NaN.
+pub struct GeodesyTransform {
+    context: Minimal,
+    operation: OpHandle,
     direction: Direction,
-) -> Result<CoordBuffer> {
-    let mut context = Minimal::new();
-    // TODO: fix error handling
-    let operation = context.op(definition).unwrap();
-
-    let new_coords = match coords {
-        CoordBuffer::Interleaved(coords) => {
-            let mut cloned_coords = coords.coords.to_vec();
-
-            let mut geodesy_coords = InterleavedCoordsGeodesy(&mut cloned_coords);
-            context
-                .apply(operation, direction, &mut geodesy_coords)
-                .unwrap();
+}
 
-            CoordBuffer::Interleaved(InterleavedCoordBuffer::new(cloned_coords.into()))
-        }
-        CoordBuffer::Separated(separated_coords) => {
-            let mut x_coords = separated_coords.x.to_vec();
-            let mut y_coords = separated_coords.x.to_vec();
-
-            let mut geodesy_coords = SeparatedCoordsGeodesy {
-                x: &mut x_coords,
-                y: &mut y_coords,
-            };
-            context
-                .apply(operation, direction, &mut geodesy_coords)
-                .unwrap();
-            CoordBuffer::Separated(SeparatedCoordBuffer::new(x_coords.into(), y_coords.into()))
-        }
-    };
+impl GeodesyTransform {
+    /// Parse the operation given by `definition`.
+    ///
+    /// # Errors
+    ///
+    /// - if geodesy can't parse `definition`
+    pub fn try_new(definition: &str, direction: Direction) -> Result<Self> {
+        let mut context = Minimal::new();
+        let operation = context
+            .op(definition)
+            .map_err(|err| GeoArrowError::General(err.to_string()))?;
+        Ok(Self {
+            context,
+            operation,
+            direction,
+        })
+    }
+}
 
-    Ok(new_coords)
+impl CoordTransform for GeodesyTransform {
+    fn transform_chunk(&self, xs: &mut [f64], ys: &mut [f64]) -> Result<()> {
+        let mut geodesy_coords = SeparatedCoordsGeodesy { x: xs, y: ys };
+        self.context
+            .apply(self.operation, self.direction, &mut geodesy_coords)
+            .map_err(|err| GeoArrowError::General(err.to_string()))?;
+        Ok(())
+    }
 }
 
-/// Reproject coordinates
-///
+/// Reproject coordinates using [`geodesy`].
+pub trait Reproject {
+    /// Apply the operation given by `definition` to every coordinate of the non-null geometries.
+    ///
+    /// # Errors
+    ///
+    /// - if geodesy can't parse `definition`
+    /// - if a geometry can't be transformed
+    fn reproject(&self, definition: &str, direction: Direction) -> Result<Self>
+    where
+        Self: Sized;
+}
 
-// NOTE: In the future this should probably take care to _not_ reproject coordinates that are set to null via the arrow validity bitmask. That could probably lead to
-pub fn reproject<O: OffsetSizeTrait>(
-    array: &GeometryArray<O>,
-    definition: &str,
-    direction: Direction,
-) -> Result<GeometryArray<O>> {
-    match array {
-        GeometryArray::Point(arr) => {
-            let new_coords = reproject_coords(&arr.coords, definition, direction)?;
-            Ok(GeometryArray::Point(arr.clone().with_coords(new_coords)))
-        }
-        GeometryArray::LineString(arr) => {
-            let new_coords = reproject_coords(&arr.coords, definition, direction)?;
-            Ok(GeometryArray::LineString(
-                arr.clone().with_coords(new_coords),
-            ))
-        }
-        GeometryArray::Polygon(arr) => {
-            let new_coords = reproject_coords(&arr.coords, definition, direction)?;
-            Ok(GeometryArray::Polygon(arr.clone().with_coords(new_coords)))
-        }
-        GeometryArray::MultiPoint(arr) => {
-            let new_coords = reproject_coords(&arr.coords, definition, direction)?;
-            Ok(GeometryArray::MultiPoint(
-                arr.clone().with_coords(new_coords),
-            ))
-        }
-        GeometryArray::MultiLineString(arr) => {
-            let new_coords = reproject_coords(&arr.coords, definition, direction)?;
-            Ok(GeometryArray::MultiLineString(
-                arr.clone().with_coords(new_coords),
-            ))
-        }
-        GeometryArray::MultiPolygon(arr) => {
-            let new_coords = reproject_coords(&arr.coords, definition, direction)?;
-            Ok(GeometryArray::MultiPolygon(
-                arr.clone().with_coords(new_coords),
-            ))
+impl Reproject for PointArray {
+    fn reproject(&self, definition: &str, direction: Direction) -> Result<Self> {
+        let transform = GeodesyTransform::try_new(definition, direction)?;
+        transform_geometry_array(self.clone(), &transform, &TransformOptions::default())
+    }
+}
+
+macro_rules! reproject_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> Reproject for $type {
+            fn reproject(&self, definition: &str, direction: Direction) -> Result<Self> {
+                let transform = GeodesyTransform::try_new(definition, direction)?;
+                transform_geometry_array(self.clone(), &transform, &TransformOptions::default())
+            }
         }
-        GeometryArray::Rect(_arr) => todo!(),
+    };
+}
+
+reproject_impl!(LineStringArray<O>);
+reproject_impl!(PolygonArray<O>);
+reproject_impl!(MultiPointArray<O>);
+reproject_impl!(MultiLineStringArray<O>);
+reproject_impl!(MultiPolygonArray<O>);
+reproject_impl!(GeometryArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeoArrayAccessor;
+    use crate::GeometryArrayTrait;
+    use geo::point;
+
+    #[test]
+    fn through_shared_kernel() {
+        let points: PointArray = vec![Some(point!(x: 1., y: 2.)), None].into();
+        let points = points.into_coord_type(CoordType::Separated);
+
+        let transform = GeodesyTransform::try_new("addone", Direction::Fwd).unwrap();
+        let out =
+            transform_geometry_array(points.clone(), &transform, &TransformOptions::default())
+                .unwrap();
+        assert_eq!(out.value_as_geo(0), point!(x: 2., y: 2.));
+        assert!(out.is_null(1));
+
+        let round_trip = out.reproject("addone", Direction::Inv).unwrap();
+        assert_eq!(round_trip.value_as_geo(0), point!(x: 1., y: 2.));
+
+        assert!(points
+            .reproject("not an operation", Direction::Fwd)
+            .is_err());
     }
 }
@@ -1,7 +1,7 @@
 use crate::algorithm::geo::utils::zeroes;
 use crate::array::{
-    GeometryArray, LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray,
-    PointArray, PolygonArray, WKBArray,
+    GeometryArray, GeometryCollectionArray, LineStringArray, MultiLineStringArray, MultiPointArray,
+    MultiPolygonArray, PointArray, PolygonArray, WKBArray,
 };
 use crate::error::Result;
 use crate::GeometryArrayTrait;
@@ -59,6 +59,7 @@ macro_rules! iter_geos_impl {
 
 iter_geos_impl!(PolygonArray<O>);
 iter_geos_impl!(MultiPolygonArray<O>);
+iter_geos_impl!(GeometryCollectionArray<O>);
 iter_geos_impl!(WKBArray<O>);
 
 impl<O: OffsetSizeTrait> Area for GeometryArray<O> {
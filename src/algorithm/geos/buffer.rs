@@ -1,35 +1,48 @@
-use crate::array::{PointArray, PolygonArray};
+use crate::algorithm::native::OutputOptions;
+use crate::array::{MutablePolygonArray, PointArray, PolygonArray};
 use crate::error::Result;
 use crate::GeometryArrayTrait;
 use geos::Geom;
 
+/// The area within `width` of each geometry, computed by GEOS.
 pub trait Buffer {
     type Output;
 
+    /// Buffer each geometry, approximating each quarter circle with `quadsegs` segments. The
+    /// output keeps the coordinate layout of the input.
     fn buffer(&self, width: f64, quadsegs: i32) -> Result<Self::Output>;
+
+    /// Buffer each geometry like [`buffer`](Self::buffer), building the output directly in the
+    /// layout given by `options`.
+    fn buffer_with_options(
+        &self,
+        width: f64,
+        quadsegs: i32,
+        options: &OutputOptions,
+    ) -> Result<Self::Output>;
 }
 
 impl Buffer for PointArray {
     type Output = PolygonArray<i32>;
 
     fn buffer(&self, width: f64, quadsegs: i32) -> Result<Self::Output> {
-        // NOTE: the bumpalo allocator didn't appear to make any perf difference with geos :shrug:
-        // Presumably GEOS is allocating on its own before we can put the geometry in the Bump?
-        let bump = bumpalo::Bump::new();
-
-        let mut geos_geoms = bumpalo::collections::Vec::with_capacity_in(self.len(), &bump);
-
-        for maybe_g in self.iter_geos() {
-            if let Some(g) = maybe_g {
-                let area = g.buffer(width, quadsegs)?;
-                geos_geoms.push(Some(area));
-            } else {
-                geos_geoms.push(None);
-            }
-        }
-
-        let polygon_array: PolygonArray<i32> = geos_geoms.try_into()?;
-        Ok(polygon_array)
+        self.buffer_with_options(width, quadsegs, &OutputOptions::from_array(self))
+    }
+
+    fn buffer_with_options(
+        &self,
+        width: f64,
+        quadsegs: i32,
+        options: &OutputOptions,
+    ) -> Result<Self::Output> {
+        let geos_geoms = self
+            .iter_geos()
+            .map(|maybe_g| maybe_g.map(|g| g.buffer(width, quadsegs)).transpose())
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let polygon_array =
+            MutablePolygonArray::<i32>::from_geos(geos_geoms, options.coord_type.clone())?;
+        Ok(polygon_array.into())
     }
 }
 
@@ -77,12 +90,36 @@ impl Buffer for PointArray {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::array::{CoordBuffer, CoordType};
     use crate::test::point::point_array;
 
     #[test]
     fn point_buffer() {
         let arr = point_array();
         let buffered = arr.buffer(1., 8).unwrap();
-        dbg!(buffered);
+        assert_eq!(buffered.len(), arr.len());
+        assert_eq!(buffered.coord_type(), CoordType::Interleaved);
+    }
+
+    #[test]
+    fn buffer_with_separated_output() {
+        let arr = point_array();
+        let options = OutputOptions::default().with_coord_type(CoordType::Separated);
+        let buffered = arr.buffer_with_options(1., 8, &options).unwrap();
+        assert_eq!(
+            buffered,
+            arr.buffer(1., 8)
+                .unwrap()
+                .into_coord_type(CoordType::Separated)
+        );
+
+        // The separated buffers are allocated once, at their final size, and no interleaved
+        // buffer is built on the way
+        let CoordBuffer::Separated(coords) = &buffered.coords else {
+            panic!("expected separated coordinates")
+        };
+        let num_coords = coords.len();
+        assert_eq!(coords.x.inner().capacity(), num_coords * 8);
+        assert_eq!(coords.y.inner().capacity(), num_coords * 8);
     }
 }
@@ -0,0 +1,78 @@
+use crate::array::{MultiPointArray, PolygonArray};
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+use arrow_array::OffsetSizeTrait;
+use geos::{Geom, GeometryTypes};
+
+/// Concave hull of a geometry, computed by GEOS.
+///
+/// This uses the GEOS algorithm, which gives different results than [`geo::ConcaveHull`].
+///
+/// This needs GEOS 3.11 or later, and is only available with the `geos-3-11` feature.
+pub trait ConcaveHull {
+    type Output;
+
+    /// `ratio` is between 0 and 1, where 1 gives the convex hull and smaller values give more
+    /// concave hulls. If `allow_holes` is true, the hull may contain holes.
+    ///
+    /// # Errors
+    ///
+    /// - if GEOS fails to compute a hull
+    /// - if a hull is not a polygon, e.g. because all points of a geometry are collinear
+    fn concave_hull_geos(&self, ratio: f64, allow_holes: bool) -> Result<Self::Output>;
+}
+
+impl<O: OffsetSizeTrait> ConcaveHull for MultiPointArray<O> {
+    type Output = PolygonArray<i32>;
+
+    fn concave_hull_geos(&self, ratio: f64, allow_holes: bool) -> Result<Self::Output> {
+        let mut geos_geoms = Vec::with_capacity(self.len());
+
+        for (geom_idx, maybe_g) in self.iter_geos().enumerate() {
+            if let Some(g) = maybe_g {
+                let hull = g.concave_hull(ratio, allow_holes)?;
+                if hull.geometry_type() != GeometryTypes::Polygon {
+                    return Err(GeoArrowError::General(format!(
+                        "Concave hull of geometry {} is a {:?}, not a polygon",
+                        geom_idx,
+                        hull.geometry_type()
+                    )));
+                }
+                geos_geoms.push(Some(hull));
+            } else {
+                geos_geoms.push(None);
+            }
+        }
+
+        geos_geoms.try_into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn concave_hull_is_valid() {
+        let points: geo::MultiPoint = vec![
+            (0., 0.),
+            (10., 0.),
+            (10., 10.),
+            (0., 10.),
+            (5., 1.),
+            (2., 5.),
+            (8., 5.),
+            (5., 9.),
+        ]
+        .into();
+        let arr: MultiPointArray<i32> = vec![Some(points), None].into();
+
+        let hulls = arr.concave_hull_geos(0.5, false).unwrap();
+        assert_eq!(hulls.len(), 2);
+        assert!(hulls.is_null(1));
+
+        let hull = hulls.value_as_geos(0);
+        assert!(hull.is_valid());
+        assert!(hull.area().unwrap() > 0.);
+    }
+}
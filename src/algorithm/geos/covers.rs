@@ -0,0 +1,174 @@
+use crate::algorithm::geo::utils::check_row_pairs;
+use crate::array::*;
+use crate::error::Result;
+use crate::GeometryArrayTrait;
+use arrow_array::builder::BooleanBuilder;
+use arrow_array::{BooleanArray, OffsetSizeTrait};
+use geos::Geom;
+
+/// Whether `self` covers `rhs`, computed by GEOS.
+///
+/// A geometry covers another if no point of the other lies in its exterior. Unlike
+/// [`Contains`](crate::algorithm::geo::Contains), this includes geometries that only touch the
+/// boundary: a point on the edge of a polygon is covered by the polygon, but not contained in it.
+///
+/// Rows where either geometry is null are null.
+pub trait Covers<Rhs = Self> {
+    /// Whether each geometry in `self` covers the geometry at the same position in `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// - if `self` and `rhs` have different lengths
+    /// - if `self` and `rhs` have different [`Edges`](crate::array::metadata::Edges)
+    /// - if GEOS fails to evaluate the predicate
+    fn covers(&self, rhs: &Rhs) -> Result<BooleanArray>;
+
+    /// Whether each geometry in `self` is covered by the geometry at the same position in `rhs`.
+    ///
+    /// # Errors
+    ///
+    /// - if `self` and `rhs` have different lengths
+    /// - if `self` and `rhs` have different [`Edges`](crate::array::metadata::Edges)
+    /// - if GEOS fails to evaluate the predicate
+    fn covered_by(&self, rhs: &Rhs) -> Result<BooleanArray>;
+}
+
+/// Evaluate a binary GEOS predicate row by row.
+macro_rules! geos_predicate {
+    ($self:ident, $rhs:ident, $predicate:ident) => {{
+        check_row_pairs($self, $rhs)?;
+
+        let mut output_array = BooleanBuilder::with_capacity($self.len());
+        for (first, second) in $self.iter_geos().zip($rhs.iter_geos()) {
+            match (first, second) {
+                (Some(first), Some(second)) => {
+                    output_array.append_value(first.$predicate(&second)?)
+                }
+                _ => output_array.append_null(),
+            }
+        }
+
+        Ok(output_array.finish())
+    }};
+}
+
+// Note: this implementation is outside the macro because it is not generic over O
+impl Covers for PointArray {
+    fn covers(&self, rhs: &Self) -> Result<BooleanArray> {
+        geos_predicate!(self, rhs, covers)
+    }
+
+    fn covered_by(&self, rhs: &Self) -> Result<BooleanArray> {
+        geos_predicate!(self, rhs, covered_by)
+    }
+}
+
+/// Implementation that iterates over GEOS geometries
+macro_rules! iter_geos_impl {
+    ($first:ty, $second:ty) => {
+        impl<O: OffsetSizeTrait> Covers<$second> for $first {
+            fn covers(&self, rhs: &$second) -> Result<BooleanArray> {
+                geos_predicate!(self, rhs, covers)
+            }
+
+            fn covered_by(&self, rhs: &$second) -> Result<BooleanArray> {
+                geos_predicate!(self, rhs, covered_by)
+            }
+        }
+    };
+}
+
+// Implementations on PointArray
+iter_geos_impl!(PointArray, LineStringArray<O>);
+iter_geos_impl!(PointArray, PolygonArray<O>);
+iter_geos_impl!(PointArray, MultiPointArray<O>);
+iter_geos_impl!(PointArray, MultiLineStringArray<O>);
+iter_geos_impl!(PointArray, MultiPolygonArray<O>);
+
+// Implementations on LineStringArray
+iter_geos_impl!(LineStringArray<O>, PointArray);
+iter_geos_impl!(LineStringArray<O>, LineStringArray<O>);
+iter_geos_impl!(LineStringArray<O>, PolygonArray<O>);
+iter_geos_impl!(LineStringArray<O>, MultiPointArray<O>);
+iter_geos_impl!(LineStringArray<O>, MultiLineStringArray<O>);
+iter_geos_impl!(LineStringArray<O>, MultiPolygonArray<O>);
+
+// Implementations on PolygonArray
+iter_geos_impl!(PolygonArray<O>, PointArray);
+iter_geos_impl!(PolygonArray<O>, LineStringArray<O>);
+iter_geos_impl!(PolygonArray<O>, PolygonArray<O>);
+iter_geos_impl!(PolygonArray<O>, MultiPointArray<O>);
+iter_geos_impl!(PolygonArray<O>, MultiLineStringArray<O>);
+iter_geos_impl!(PolygonArray<O>, MultiPolygonArray<O>);
+
+// Implementations on MultiPointArray
+iter_geos_impl!(MultiPointArray<O>, PointArray);
+iter_geos_impl!(MultiPointArray<O>, LineStringArray<O>);
+iter_geos_impl!(MultiPointArray<O>, PolygonArray<O>);
+iter_geos_impl!(MultiPointArray<O>, MultiPointArray<O>);
+iter_geos_impl!(MultiPointArray<O>, MultiLineStringArray<O>);
+iter_geos_impl!(MultiPointArray<O>, MultiPolygonArray<O>);
+
+// Implementations on MultiLineStringArray
+iter_geos_impl!(MultiLineStringArray<O>, PointArray);
+iter_geos_impl!(MultiLineStringArray<O>, LineStringArray<O>);
+iter_geos_impl!(MultiLineStringArray<O>, PolygonArray<O>);
+iter_geos_impl!(MultiLineStringArray<O>, MultiPointArray<O>);
+iter_geos_impl!(MultiLineStringArray<O>, MultiLineStringArray<O>);
+iter_geos_impl!(MultiLineStringArray<O>, MultiPolygonArray<O>);
+
+// Implementations on MultiPolygonArray
+iter_geos_impl!(MultiPolygonArray<O>, PointArray);
+iter_geos_impl!(MultiPolygonArray<O>, LineStringArray<O>);
+iter_geos_impl!(MultiPolygonArray<O>, PolygonArray<O>);
+iter_geos_impl!(MultiPolygonArray<O>, MultiPointArray<O>);
+iter_geos_impl!(MultiPolygonArray<O>, MultiLineStringArray<O>);
+iter_geos_impl!(MultiPolygonArray<O>, MultiPolygonArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geo::Contains;
+    use arrow_array::Array;
+    use geo::{point, polygon};
+
+    #[test]
+    fn boundary_is_covered_but_not_contained() {
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let polygons: PolygonArray<i32> = vec![
+            Some(square.clone()),
+            Some(square.clone()),
+            Some(square),
+            None,
+        ]
+        .into();
+        let points: PointArray = vec![
+            Some(point!(x: 1., y: 1.)),
+            Some(point!(x: 2., y: 1.)),
+            Some(point!(x: 3., y: 1.)),
+            Some(point!(x: 1., y: 1.)),
+        ]
+        .into();
+
+        let covers = polygons.covers(&points).unwrap();
+        assert_eq!(
+            covers.slice(0, 3),
+            BooleanArray::from(vec![true, true, false])
+        );
+        assert!(covers.is_null(3));
+
+        let contains = polygons.contains(&points).unwrap();
+        assert_eq!(
+            contains.slice(0, 3),
+            BooleanArray::from(vec![true, false, false])
+        );
+
+        let covered_by = points.covered_by(&polygons).unwrap();
+        assert_eq!(
+            covered_by.slice(0, 3),
+            BooleanArray::from(vec![true, true, false])
+        );
+
+        assert!(polygons.covers(&points.slice(0, 2)).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+use crate::array::{LineStringArray, MultiLineStringArray, PointArray};
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+use arrow_array::builder::Float64Builder;
+use arrow_array::{Float64Array, OffsetSizeTrait};
+use geos::Geom;
+
+/// Interpolate points along linear geometries and project points onto them, computed by GEOS.
+///
+/// Unlike [`LineInterpolatePoint`](crate::algorithm::geo::LineInterpolatePoint), this supports
+/// multilinestrings. Their parts are merged with GEOS's `line_merge` first, and the distance along
+/// the merged geometry is measured across its remaining parts in order.
+pub trait Interpolate {
+    /// The point at `fraction` of the length of each geometry, where 0 is the start and 1 the
+    /// end. Fractions outside this range are clamped.
+    ///
+    /// Null and empty geometries give null.
+    ///
+    /// # Errors
+    ///
+    /// - if GEOS fails to merge or interpolate a geometry
+    fn interpolate(&self, fraction: f64) -> Result<PointArray>;
+
+    /// The fraction of the length of each geometry at which the point at the same position in
+    /// `points` projects onto it. This is the inverse of [`interpolate`](Self::interpolate).
+    ///
+    /// Rows where either geometry is null or empty are null.
+    ///
+    /// # Errors
+    ///
+    /// - if `self` and `points` have different lengths
+    /// - if GEOS fails to merge a geometry or project a point
+    fn project(&self, points: &PointArray) -> Result<Float64Array>;
+}
+
+macro_rules! iter_geos_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> Interpolate for $type {
+            fn interpolate(&self, fraction: f64) -> Result<PointArray> {
+                let fraction = fraction.clamp(0., 1.);
+                let output_geoms = self
+                    .iter_geos()
+                    .map(|maybe_g| match maybe_g {
+                        Some(g) if !g.is_empty()? => {
+                            let point = g.line_merge()?.interpolate_normalized(fraction)?;
+                            let point: geo::Geometry = (&point).try_into()?;
+                            Ok(Some(
+                                geo::Point::try_from(point)
+                                    .map_err(|err| GeoArrowError::General(err.to_string()))?,
+                            ))
+                        }
+                        _ => Ok(None),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok(output_geoms.into())
+            }
+
+            fn project(&self, points: &PointArray) -> Result<Float64Array> {
+                if self.len() != points.len() {
+                    return Err(GeoArrowError::General(format!(
+                        "Expected arrays of the same length, got {} and {}",
+                        self.len(),
+                        points.len()
+                    )));
+                }
+
+                let mut output_array = Float64Builder::with_capacity(self.len());
+                for (maybe_g, maybe_point) in self.iter_geos().zip(points.iter_geos()) {
+                    match (maybe_g, maybe_point) {
+                        (Some(g), Some(point)) if !g.is_empty()? && !point.is_empty()? => {
+                            output_array.append_value(g.line_merge()?.project_normalized(&point)?)
+                        }
+                        _ => output_array.append_null(),
+                    }
+                }
+
+                Ok(output_array.finish())
+            }
+        }
+    };
+}
+
+iter_geos_impl!(LineStringArray<O>);
+iter_geos_impl!(MultiLineStringArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeoArrayAccessor;
+    use arrow_array::Array;
+    use geo::{line_string, point};
+
+    #[test]
+    fn multilinestring_midpoint() {
+        // Two touching parts of lengths 1 and 3, so the midpoint is 1 along the second part
+        let multi_line_string = geo::MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 1., y: 3.)],
+        ]);
+        let arr: MultiLineStringArray<i32> = vec![Some(multi_line_string), None].into();
+
+        let midpoints = arr.interpolate(0.5).unwrap();
+        assert_eq!(midpoints.len(), 2);
+        let midpoint = midpoints.value_as_geo(0);
+        assert!((midpoint.x() - 1.).abs() < 1e-9);
+        assert!((midpoint.y() - 1.).abs() < 1e-9);
+        assert!(midpoints.is_null(1));
+
+        let points: PointArray =
+            vec![Some(point!(x: 2., y: 1.)), Some(point!(x: 0., y: 0.))].into();
+        let fractions = arr.project(&points).unwrap();
+        assert!((fractions.value(0) - 0.5).abs() < 1e-9);
+        assert!(fractions.is_null(1));
+    }
+}
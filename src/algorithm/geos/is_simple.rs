@@ -0,0 +1,78 @@
+use crate::array::{LineStringArray, MultiLineStringArray};
+use crate::error::Result;
+use crate::GeometryArrayTrait;
+use arrow_array::builder::BooleanBuilder;
+use arrow_array::{BooleanArray, OffsetSizeTrait};
+use geos::Geom;
+
+/// Whether each geometry is simple, computed by GEOS.
+///
+/// A line string is simple if it doesn't cross or touch itself, except that a closed ring may end
+/// where it starts. A multi line string is simple if each of its line strings is simple and they
+/// only intersect at their end points.
+///
+/// Null geometries are null in the output.
+pub trait IsSimple {
+    /// # Errors
+    ///
+    /// - if GEOS fails to evaluate the predicate
+    fn is_simple(&self) -> Result<BooleanArray>;
+}
+
+/// Implementation that iterates over GEOS geometries
+macro_rules! iter_geos_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> IsSimple for $type {
+            fn is_simple(&self) -> Result<BooleanArray> {
+                let mut output_array = BooleanBuilder::with_capacity(self.len());
+
+                for maybe_g in self.iter_geos() {
+                    if let Some(g) = maybe_g {
+                        output_array.append_value(g.is_simple()?);
+                    } else {
+                        output_array.append_null();
+                    }
+                }
+
+                Ok(output_array.finish())
+            }
+        }
+    };
+}
+
+iter_geos_impl!(LineStringArray<O>);
+iter_geos_impl!(MultiLineStringArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{line_string, MultiLineString};
+
+    #[test]
+    fn self_crossing_line_string() {
+        let simple = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)];
+        // A bowtie, whose second segment crosses its fourth
+        let crossing = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 1.),
+            (x: 1., y: 0.),
+            (x: 0., y: 1.),
+        ];
+        let array: LineStringArray<i32> =
+            vec![Some(simple.clone()), Some(crossing.clone()), None].into();
+
+        let result = array.is_simple().unwrap();
+        assert!(result.value(0));
+        assert!(!result.value(1));
+        assert!(result.is_null(2));
+
+        let multi_array: MultiLineStringArray<i32> = vec![
+            MultiLineString::new(vec![simple]),
+            MultiLineString::new(vec![crossing]),
+        ]
+        .into();
+        let result = multi_array.is_simple().unwrap();
+        assert!(result.value(0));
+        assert!(!result.value(1));
+    }
+}
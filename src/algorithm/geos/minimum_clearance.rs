@@ -0,0 +1,104 @@
+use crate::array::{MultiPolygonArray, PolygonArray};
+use crate::error::Result;
+use crate::GeometryArrayTrait;
+use arrow_array::builder::Float64Builder;
+use arrow_array::{Float64Array, OffsetSizeTrait};
+use geos::Geom;
+
+/// Minimum clearance of each geometry, computed by GEOS.
+///
+/// The minimum clearance is the smallest distance a vertex can be moved to make the geometry
+/// invalid or collapse, i.e. the smallest distance between a vertex and a segment it isn't part
+/// of. It measures how robust a geometry is to coordinate rounding.
+///
+/// Null geometries, and geometries with no clearance such as empty polygons, are null in the
+/// output.
+pub trait MinimumClearance {
+    /// # Errors
+    ///
+    /// - if GEOS fails to compute the clearance
+    fn minimum_clearance(&self) -> Result<Float64Array>;
+}
+
+/// Minimum width of each geometry, computed by GEOS.
+///
+/// The minimum width is the smallest distance between two parallel lines enclosing the geometry,
+/// i.e. the width of the thinnest strip it fits in.
+///
+/// Null and empty geometries are null in the output.
+pub trait MinimumWidth {
+    /// # Errors
+    ///
+    /// - if GEOS fails to compute the width
+    fn minimum_width(&self) -> Result<Float64Array>;
+}
+
+/// Implementation that iterates over GEOS geometries
+macro_rules! iter_geos_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> MinimumClearance for $type {
+            fn minimum_clearance(&self) -> Result<Float64Array> {
+                let mut output_array = Float64Builder::with_capacity(self.len());
+
+                for maybe_g in self.iter_geos() {
+                    // GEOS reports an infinite clearance when there is no pair of vertex and
+                    // segment to measure
+                    let clearance = match maybe_g {
+                        Some(g) => Some(g.minimum_clearance()?).filter(|c| c.is_finite()),
+                        None => None,
+                    };
+                    output_array.append_option(clearance);
+                }
+
+                Ok(output_array.finish())
+            }
+        }
+
+        impl<O: OffsetSizeTrait> MinimumWidth for $type {
+            fn minimum_width(&self) -> Result<Float64Array> {
+                let mut output_array = Float64Builder::with_capacity(self.len());
+
+                for maybe_g in self.iter_geos() {
+                    match maybe_g {
+                        Some(g) if !g.is_empty()? => {
+                            // GEOS returns the segment across the geometry at its narrowest
+                            output_array.append_value(g.minimum_width()?.length()?);
+                        }
+                        _ => output_array.append_null(),
+                    }
+                }
+
+                Ok(output_array.finish())
+            }
+        }
+    };
+}
+
+iter_geos_impl!(PolygonArray<O>);
+iter_geos_impl!(MultiPolygonArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::Array;
+    use geo::polygon;
+
+    #[test]
+    fn rectangle() {
+        let rectangle = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        let array: PolygonArray<i32> = vec![Some(rectangle), None].into();
+
+        let clearance = array.minimum_clearance().unwrap();
+        assert_eq!(clearance.value(0), 4.);
+        assert!(clearance.is_null(1));
+
+        let width = array.minimum_width().unwrap();
+        assert!((width.value(0) - 4.).abs() < 1e-9);
+        assert!(width.is_null(1));
+    }
+}
@@ -1,2 +1,19 @@
 pub mod area;
 pub mod buffer;
+#[cfg(feature = "geos-3-11")]
+pub mod concave_hull;
+pub mod covers;
+pub mod interpolate;
+pub mod is_simple;
+pub mod minimum_clearance;
+pub mod simplify_valid;
+
+pub use area::Area;
+pub use buffer::Buffer;
+#[cfg(feature = "geos-3-11")]
+pub use concave_hull::ConcaveHull;
+pub use covers::Covers;
+pub use interpolate::Interpolate;
+pub use is_simple::IsSimple;
+pub use minimum_clearance::{MinimumClearance, MinimumWidth};
+pub use simplify_valid::SimplifyValid;
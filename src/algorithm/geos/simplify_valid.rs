@@ -0,0 +1,98 @@
+use crate::array::{MultiPolygonArray, PolygonArray};
+use crate::error::Result;
+use crate::GeometryArrayTrait;
+use arrow_array::OffsetSizeTrait;
+use geos::Geom;
+
+/// Simplify polygonal geometries with GEOS, then repair them so that every output is valid.
+///
+/// Topology-preserving simplification keeps rings from crossing each other, but it doesn't fix
+/// inputs that were invalid to begin with, such as self-intersecting rings. The simplified
+/// geometry is passed through GEOS's `make_valid`, whose polygonal parts form the output; any
+/// lines or points that `make_valid` produces from collapsed parts are dropped.
+pub trait SimplifyValid {
+    /// `tolerance` is the maximum distance between the original and simplified geometries.
+    ///
+    /// Null geometries stay null, and geometries that collapse entirely become empty
+    /// multipolygons.
+    ///
+    /// # Errors
+    ///
+    /// - if GEOS fails to simplify or repair a geometry
+    fn simplify_valid(&self, tolerance: f64) -> Result<MultiPolygonArray<i32>>;
+}
+
+/// Simplify and repair a single GEOS geometry, keeping its polygonal parts.
+fn simplify_valid_geometry(geom: &impl Geom, tolerance: f64) -> Result<geo::MultiPolygon> {
+    let valid = geom.topology_preserve_simplify(tolerance)?.make_valid()?;
+    let mut polygons = vec![];
+    collect_polygons(geo::Geometry::try_from(&valid)?, &mut polygons);
+    Ok(geo::MultiPolygon::new(polygons))
+}
+
+fn collect_polygons(geom: geo::Geometry, polygons: &mut Vec<geo::Polygon>) {
+    match geom {
+        geo::Geometry::Polygon(polygon) => polygons.push(polygon),
+        geo::Geometry::MultiPolygon(multi_polygon) => polygons.extend(multi_polygon),
+        geo::Geometry::GeometryCollection(collection) => collection
+            .into_iter()
+            .for_each(|geom| collect_polygons(geom, polygons)),
+        _ => {}
+    }
+}
+
+macro_rules! iter_geos_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> SimplifyValid for $type {
+            fn simplify_valid(&self, tolerance: f64) -> Result<MultiPolygonArray<i32>> {
+                let output_geoms = self
+                    .iter_geos()
+                    .map(|maybe_g| {
+                        maybe_g
+                            .map(|g| simplify_valid_geometry(&g, tolerance))
+                            .transpose()
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                Ok(output_geoms.into())
+            }
+        }
+    };
+}
+
+iter_geos_impl!(PolygonArray<O>);
+iter_geos_impl!(MultiPolygonArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geo::Simplify;
+    use geo::polygon;
+
+    #[test]
+    fn repairs_self_intersection() {
+        // A valid polygon with a narrow slit reaching down from its top edge to just above the
+        // dip in its bottom edge. Douglas-Peucker flattens the dip, which leaves the slit's tip
+        // below the new bottom edge and the ring self-intersecting.
+        let slit = polygon![
+            (x: 0., y: 0.),
+            (x: 5., y: -1.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 5.2, y: 10.),
+            (x: 5., y: -0.5),
+            (x: 4.8, y: 10.),
+            (x: 0., y: 10.),
+        ];
+        let arr: PolygonArray<i32> = vec![Some(slit), None].into();
+        assert!(arr.value_as_geos(0).is_valid());
+
+        let simplified = arr.simplify(&2.);
+        assert!(!simplified.value_as_geos(0).is_valid());
+
+        let result = arr.simplify_valid(2.).unwrap();
+        assert_eq!(result.len(), 2);
+        assert!(result.is_null(1));
+        assert!(result.value_as_geos(0).is_valid());
+    }
+}
@@ -0,0 +1,322 @@
+use crate::array::*;
+use crate::trait_::GeoArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::OffsetSizeTrait;
+use arrow_buffer::ScalarBuffer;
+use geo::{coord, CoordsIter, MapCoords};
+
+/// Swap the x and y values of every coordinate.
+///
+/// This is the fix for data that was written with latitude before longitude. Separated
+/// coordinates are swapped without copying; interleaved coordinates take a single pass over the
+/// buffer.
+pub trait SwapXY {
+    fn swap_xy(&self) -> Self;
+}
+
+impl SwapXY for CoordBuffer {
+    fn swap_xy(&self) -> Self {
+        match self {
+            CoordBuffer::Separated(cb) => {
+                CoordBuffer::Separated(SeparatedCoordBuffer::new(cb.y.clone(), cb.x.clone()))
+            }
+            CoordBuffer::Interleaved(cb) => {
+                let mut coords = Vec::with_capacity(cb.coords.len());
+                for xy in cb.coords.chunks_exact(2) {
+                    coords.push(xy[1]);
+                    coords.push(xy[0]);
+                }
+                CoordBuffer::Interleaved(InterleavedCoordBuffer::new(coords.into()))
+            }
+            CoordBuffer::Float32(cb) => {
+                let size = cb.dim().size();
+                let mut coords = Vec::with_capacity(cb.coords.len());
+                for values in cb.coords.chunks_exact(size) {
+                    coords.push(values[1]);
+                    coords.push(values[0]);
+                    coords.extend_from_slice(&values[2..]);
+                }
+                CoordBuffer::Float32(Float32CoordBuffer::new_with_dim(coords.into(), cb.dim()))
+            }
+        }
+    }
+}
+
+impl SwapXY for PointArray {
+    fn swap_xy(&self) -> Self {
+        self.clone().with_coords(self.coords.swap_xy())
+    }
+}
+
+macro_rules! swap_xy_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> SwapXY for $type {
+            fn swap_xy(&self) -> Self {
+                self.clone().with_coords(self.coords.swap_xy())
+            }
+        }
+    };
+}
+
+swap_xy_impl!(LineStringArray<O>);
+swap_xy_impl!(PolygonArray<O>);
+swap_xy_impl!(MultiPointArray<O>);
+swap_xy_impl!(MultiLineStringArray<O>);
+swap_xy_impl!(MultiPolygonArray<O>);
+
+impl SwapXY for RectArray {
+    fn swap_xy(&self) -> Self {
+        let mut values = Vec::with_capacity(self.len() * 4);
+        for i in 0..self.len() {
+            let rect = self.value_as_geo(i);
+            values.extend_from_slice(&[rect.min().y, rect.min().x, rect.max().y, rect.max().x]);
+        }
+        RectArray::new(ScalarBuffer::from(values), self.validity().cloned())
+    }
+}
+
+impl<O: OffsetSizeTrait> SwapXY for GeometryCollectionArray<O> {
+    fn swap_xy(&self) -> Self {
+        let collections: Vec<Option<geo::GeometryCollection>> = self
+            .iter_geo()
+            .map(|maybe_gc| maybe_gc.map(|gc| gc.map_coords(|c| coord! { x: c.y, y: c.x })))
+            .collect();
+        // Swapping coordinates can't nest the collections read from this array
+        let arr: GeometryCollectionArray<O> = collections.try_into().unwrap();
+        arr.into_coord_type(self.coord_type())
+    }
+}
+
+impl<O: OffsetSizeTrait> SwapXY for GeometryArray<O> {
+    fn swap_xy(&self) -> Self {
+        match self {
+            GeometryArray::Point(arr) => GeometryArray::Point(arr.swap_xy()),
+            GeometryArray::LineString(arr) => GeometryArray::LineString(arr.swap_xy()),
+            GeometryArray::Polygon(arr) => GeometryArray::Polygon(arr.swap_xy()),
+            GeometryArray::MultiPoint(arr) => GeometryArray::MultiPoint(arr.swap_xy()),
+            GeometryArray::MultiLineString(arr) => GeometryArray::MultiLineString(arr.swap_xy()),
+            GeometryArray::MultiPolygon(arr) => GeometryArray::MultiPolygon(arr.swap_xy()),
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(Box::new(arr.swap_xy()))
+            }
+            GeometryArray::Rect(arr) => GeometryArray::Rect(arr.swap_xy()),
+        }
+    }
+}
+
+/// The order of the two axes of geographic coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AxisOrder {
+    /// x is longitude and y is latitude, as GeoArrow, GeoJSON and WKB expect.
+    LonLat,
+    /// x is latitude and y is longitude.
+    LatLon,
+}
+
+/// The result of [`DetectAxisOrder::detect_axis_order`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AxisOrderGuess {
+    /// The coordinates only fit one axis order.
+    Confident(AxisOrder),
+    /// The coordinates fit both axis orders, because all values are within [-90, 90]. This is
+    /// also the result for arrays without any coordinates.
+    Ambiguous,
+    /// The coordinates fit neither axis order, so they are probably not longitude and latitude
+    /// at all.
+    NotGeographic,
+}
+
+/// Guess whether coordinates are stored as longitude/latitude or latitude/longitude.
+///
+/// This is a heuristic based on value ranges: longitude is within [-180, 180] and latitude
+/// within [-90, 90]. It can only be confident if some coordinate has an absolute value above 90,
+/// so data in a small region such as Scandinavia (longitude ≈ 10, latitude ≈ 60) is always
+/// [`AxisOrderGuess::Ambiguous`]. NaN coordinates, as used for empty points, are ignored.
+pub trait DetectAxisOrder {
+    fn detect_axis_order(&self) -> AxisOrderGuess;
+}
+
+/// Whether each axis order is consistent with all coordinates seen so far.
+struct AxisOrderFit {
+    lon_lat: bool,
+    lat_lon: bool,
+}
+
+impl AxisOrderFit {
+    fn new() -> Self {
+        Self {
+            lon_lat: true,
+            lat_lon: true,
+        }
+    }
+
+    fn add_xy(&mut self, x: f64, y: f64) {
+        if x.is_nan() || y.is_nan() {
+            return;
+        }
+
+        self.lon_lat &= is_lon(x) && is_lat(y);
+        self.lat_lon &= is_lat(x) && is_lon(y);
+    }
+
+    fn add_coords(&mut self, coords: &CoordBuffer) {
+        for i in 0..coords.len() {
+            self.add_xy(coords.get_x(i), coords.get_y(i));
+        }
+    }
+
+    fn guess(&self) -> AxisOrderGuess {
+        match (self.lon_lat, self.lat_lon) {
+            (true, true) => AxisOrderGuess::Ambiguous,
+            (true, false) => AxisOrderGuess::Confident(AxisOrder::LonLat),
+            (false, true) => AxisOrderGuess::Confident(AxisOrder::LatLon),
+            (false, false) => AxisOrderGuess::NotGeographic,
+        }
+    }
+}
+
+fn is_lon(value: f64) -> bool {
+    (-180.0..=180.0).contains(&value)
+}
+
+fn is_lat(value: f64) -> bool {
+    (-90.0..=90.0).contains(&value)
+}
+
+impl DetectAxisOrder for PointArray {
+    fn detect_axis_order(&self) -> AxisOrderGuess {
+        let mut fit = AxisOrderFit::new();
+        fit.add_coords(&self.coords);
+        fit.guess()
+    }
+}
+
+macro_rules! detect_axis_order_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> DetectAxisOrder for $type {
+            fn detect_axis_order(&self) -> AxisOrderGuess {
+                let mut fit = AxisOrderFit::new();
+                fit.add_coords(&self.coords);
+                fit.guess()
+            }
+        }
+    };
+}
+
+detect_axis_order_impl!(LineStringArray<O>);
+detect_axis_order_impl!(PolygonArray<O>);
+detect_axis_order_impl!(MultiPointArray<O>);
+detect_axis_order_impl!(MultiLineStringArray<O>);
+detect_axis_order_impl!(MultiPolygonArray<O>);
+
+impl DetectAxisOrder for RectArray {
+    fn detect_axis_order(&self) -> AxisOrderGuess {
+        let mut fit = AxisOrderFit::new();
+        for rect in (0..self.len()).filter_map(|i| self.get_as_geo(i)) {
+            fit.add_xy(rect.min().x, rect.min().y);
+            fit.add_xy(rect.max().x, rect.max().y);
+        }
+        fit.guess()
+    }
+}
+
+impl<O: OffsetSizeTrait> DetectAxisOrder for GeometryCollectionArray<O> {
+    fn detect_axis_order(&self) -> AxisOrderGuess {
+        let mut fit = AxisOrderFit::new();
+        for gc in self.iter_geo().flatten() {
+            gc.coords_iter().for_each(|c| fit.add_xy(c.x, c.y));
+        }
+        fit.guess()
+    }
+}
+
+impl<O: OffsetSizeTrait> DetectAxisOrder for GeometryArray<O> {
+    fn detect_axis_order(&self) -> AxisOrderGuess {
+        match self {
+            GeometryArray::Point(arr) => arr.detect_axis_order(),
+            GeometryArray::LineString(arr) => arr.detect_axis_order(),
+            GeometryArray::Polygon(arr) => arr.detect_axis_order(),
+            GeometryArray::MultiPoint(arr) => arr.detect_axis_order(),
+            GeometryArray::MultiLineString(arr) => arr.detect_axis_order(),
+            GeometryArray::MultiPolygon(arr) => arr.detect_axis_order(),
+            GeometryArray::GeometryCollection(arr) => arr.detect_axis_order(),
+            GeometryArray::Rect(arr) => arr.detect_axis_order(),
+        }
+    }
+}
+
+/// Up to `max_samples` coordinates that aren't valid longitude/latitude pairs, for use in error
+/// messages.
+pub(crate) fn sample_non_lon_lat_coords<O: OffsetSizeTrait>(
+    arr: &GeometryArray<O>,
+    max_samples: usize,
+) -> Vec<(f64, f64)> {
+    let coords = match arr {
+        GeometryArray::Point(arr) => &arr.coords,
+        GeometryArray::LineString(arr) => &arr.coords,
+        GeometryArray::Polygon(arr) => &arr.coords,
+        GeometryArray::MultiPoint(arr) => &arr.coords,
+        GeometryArray::MultiLineString(arr) => &arr.coords,
+        GeometryArray::MultiPolygon(arr) => &arr.coords,
+        GeometryArray::GeometryCollection(_) | GeometryArray::Rect(_) => return vec![],
+    };
+
+    (0..coords.len())
+        .map(|i| (coords.get_x(i), coords.get_y(i)))
+        .filter(|(x, y)| !(x.is_nan() || y.is_nan() || (is_lon(*x) && is_lat(*y))))
+        .take(max_samples)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn swap_xy() {
+        let arr = crate::test::linestring::ls_array();
+        let swapped = arr.swap_xy();
+        let expected: geo::LineString = vec![(1., 0.), (2., 1.)].into();
+        assert_eq!(swapped.value_as_geo(0), expected);
+
+        let interleaved = arr
+            .clone()
+            .into_coord_type(CoordType::Interleaved)
+            .swap_xy();
+        assert_eq!(interleaved.value_as_geo(0), expected);
+        assert_eq!(swapped.swap_xy(), arr);
+    }
+
+    #[test]
+    fn detect_axis_order() {
+        // Oslo and Stockholm
+        let scandinavia: PointArray = vec![
+            geo::point!(x: 10.75, y: 59.91),
+            geo::point!(x: 18.07, y: 59.33),
+        ]
+        .into();
+        assert_eq!(scandinavia.detect_axis_order(), AxisOrderGuess::Ambiguous);
+        assert_eq!(
+            scandinavia.swap_xy().detect_axis_order(),
+            AxisOrderGuess::Ambiguous
+        );
+
+        // Oslo and Anchorage
+        let lon_lat: PointArray = vec![
+            geo::point!(x: 10.75, y: 59.91),
+            geo::point!(x: -149.9, y: 61.22),
+        ]
+        .into();
+        assert_eq!(
+            lon_lat.detect_axis_order(),
+            AxisOrderGuess::Confident(AxisOrder::LonLat)
+        );
+        assert_eq!(
+            lon_lat.swap_xy().detect_axis_order(),
+            AxisOrderGuess::Confident(AxisOrder::LatLon)
+        );
+
+        let projected: PointArray = vec![geo::point!(x: 597_000., y: 6_643_000.)].into();
+        assert_eq!(projected.detect_axis_order(), AxisOrderGuess::NotGeographic);
+    }
+}
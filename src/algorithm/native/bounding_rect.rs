@@ -1,9 +1,12 @@
+//! Bounding rectangles computed by walking the coordinates of [geometry access
+//! traits](crate::geo_traits), without converting to `geo` objects.
+
+use crate::array::CoordBuffer;
 use crate::geo_traits::{
-    LineStringTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait,
-    PolygonTrait,
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
 };
-use crate::scalar::{LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
-use arrow_array::OffsetSizeTrait;
+use arrow_buffer::NullBuffer;
 use geo::{coord, Rect};
 
 #[derive(Debug, Clone, Copy)]
@@ -25,18 +28,93 @@ impl BoundingRect {
         }
     }
 
-    pub fn update(&mut self, point: impl PointTrait<T = f64>) {
-        if point.x() < self.minx {
-            self.minx = point.x();
+    /// Extend the rect to include a coordinate. NaN coordinates, as in empty points, are ignored.
+    fn update(&mut self, x: f64, y: f64) {
+        if x < self.minx {
+            self.minx = x;
+        }
+        if y < self.miny {
+            self.miny = y;
+        }
+        if x > self.maxx {
+            self.maxx = x;
+        }
+        if y > self.maxy {
+            self.maxy = y;
+        }
+    }
+
+    fn add_point(&mut self, point: &impl PointTrait<T = f64>) {
+        self.update(point.x(), point.y());
+    }
+
+    fn add_coord(&mut self, coord: &impl CoordTrait<T = f64>) {
+        self.update(coord.x(), coord.y());
+    }
+
+    fn add_line_string<'a>(&mut self, line_string: &impl LineStringTrait<'a, T = f64>) {
+        for coord_idx in 0..line_string.num_coords() {
+            self.add_coord(&line_string.coord(coord_idx).unwrap());
+        }
+    }
+
+    fn add_polygon<'a>(&mut self, polygon: &impl PolygonTrait<'a, T = f64>) {
+        // Interior rings lie inside the exterior, but are walked in case the polygon is invalid
+        if let Some(exterior) = polygon.exterior() {
+            self.add_line_string(&exterior);
+        }
+        for interior_idx in 0..polygon.num_interiors() {
+            self.add_line_string(&polygon.interior(interior_idx).unwrap());
+        }
+    }
+
+    fn add_multi_point<'a>(&mut self, multi_point: &impl MultiPointTrait<'a, T = f64>) {
+        for point_idx in 0..multi_point.num_points() {
+            self.add_point(&multi_point.point(point_idx).unwrap());
         }
-        if point.y() < self.miny {
-            self.miny = point.y();
+    }
+
+    fn add_multi_line_string<'a>(
+        &mut self,
+        multi_line_string: &impl MultiLineStringTrait<'a, T = f64>,
+    ) {
+        for line_idx in 0..multi_line_string.num_lines() {
+            self.add_line_string(&multi_line_string.line(line_idx).unwrap());
         }
-        if point.x() > self.maxx {
-            self.maxx = point.x();
+    }
+
+    fn add_multi_polygon<'a>(&mut self, multi_polygon: &impl MultiPolygonTrait<'a, T = f64>) {
+        for polygon_idx in 0..multi_polygon.num_polygons() {
+            self.add_polygon(&multi_polygon.polygon(polygon_idx).unwrap());
         }
-        if point.y() > self.maxy {
-            self.maxy = point.y();
+    }
+
+    fn add_rect<'a>(&mut self, rect: &impl RectTrait<'a, T = f64>) {
+        self.add_coord(&rect.lower());
+        self.add_coord(&rect.upper());
+    }
+
+    /// Extend the rect to include the coordinates `start..end` of a coordinate buffer, reading
+    /// the underlying buffers directly.
+    fn add_coord_range(&mut self, coords: &CoordBuffer, start: usize, end: usize) {
+        match coords {
+            CoordBuffer::Interleaved(c) => {
+                let size = c.dim().size();
+                for xy in c.coords[start * size..end * size].chunks_exact(size) {
+                    self.update(xy[0], xy[1]);
+                }
+            }
+            CoordBuffer::Separated(c) => {
+                for (x, y) in c.x[start..end].iter().zip(&c.y[start..end]) {
+                    self.update(*x, *y);
+                }
+            }
+            CoordBuffer::Float32(c) => {
+                let size = c.dim().size();
+                for xy in c.coords[start * size..end * size].chunks_exact(size) {
+                    self.update(xy[0].into(), xy[1].into());
+                }
+            }
         }
     }
 }
@@ -47,11 +125,16 @@ impl Default for BoundingRect {
     }
 }
 
-impl From<BoundingRect> for Rect {
+impl From<BoundingRect> for Option<Rect> {
     fn from(value: BoundingRect) -> Self {
+        // Nothing was added, so the bounds are still infinite
+        if value.minx > value.maxx {
+            return None;
+        }
+
         let min_coord = coord! { x: value.minx, y: value.miny };
         let max_coord = coord! { x: value.maxx, y: value.maxy };
-        Rect::new(min_coord, max_coord)
+        Some(Rect::new(min_coord, max_coord))
     }
 }
 
@@ -61,89 +144,226 @@ impl From<BoundingRect> for ([f64; 2], [f64; 2]) {
     }
 }
 
-pub fn bounding_rect_point(geom: &'_ Point) -> ([f64; 2], [f64; 2]) {
+/// The bounding rectangle of any geometry implementing [`GeometryTrait`], or `None` if it has no
+/// coordinates.
+///
+/// The members of a geometry collection are returned by value, so they can't be borrowed for the
+/// lifetime that [`GeometryTrait::as_type`] requires. Each member is converted to a
+/// [`geo::Geometry`] instead, and the bounds are the union of the members' bounds.
+pub fn bounding_rect<'a, G>(geom: &'a G) -> Option<Rect>
+where
+    G: GeometryTrait<'a, T = f64>,
+    <G::GeometryCollection as GeometryCollectionTrait<'a>>::ItemType: Into<geo::Geometry>,
+{
     let mut rect = BoundingRect::new();
-    rect.update(geom);
+    match geom.as_type() {
+        GeometryType::Point(g) => rect.add_point(g),
+        GeometryType::LineString(g) => rect.add_line_string(g),
+        GeometryType::Polygon(g) => rect.add_polygon(g),
+        GeometryType::MultiPoint(g) => rect.add_multi_point(g),
+        GeometryType::MultiLineString(g) => rect.add_multi_line_string(g),
+        GeometryType::MultiPolygon(g) => rect.add_multi_polygon(g),
+        GeometryType::GeometryCollection(g) => {
+            for member in g.geometries() {
+                let member: geo::Geometry = member.into();
+                if let Some(member_rect) = bounding_rect(&member) {
+                    rect.add_rect(&member_rect);
+                }
+            }
+        }
+        GeometryType::Rect(g) => rect.add_rect(g),
+    }
     rect.into()
 }
 
-pub fn bounding_rect_multipoint<O: OffsetSizeTrait>(
-    geom: &'_ MultiPoint<O>,
-) -> ([f64; 2], [f64; 2]) {
+/// The bounds of all valid geometries of an array that stores its coordinates in `coords`,
+/// where `coord_range` returns the range of coordinates of the geometry at an index.
+///
+/// Ranges are only read for valid geometries. When no geometry is null, the coordinates of all
+/// geometries are contiguous, so they are scanned as a single range.
+pub(crate) fn total_bounds_coords(
+    coords: &CoordBuffer,
+    validity: Option<&NullBuffer>,
+    len: usize,
+    coord_range: impl Fn(usize) -> (usize, usize),
+) -> Option<Rect> {
+    if len == 0 {
+        return None;
+    }
+
     let mut rect = BoundingRect::new();
-    for geom_idx in 0..geom.num_points() {
-        let point = geom.point(geom_idx).unwrap();
-        rect.update(point);
+    match validity.filter(|validity| validity.null_count() > 0) {
+        None => {
+            let (start, _) = coord_range(0);
+            let (_, end) = coord_range(len - 1);
+            rect.add_coord_range(coords, start, end);
+        }
+        Some(validity) => {
+            for geom_idx in validity.valid_indices() {
+                let (start, end) = coord_range(geom_idx);
+                rect.add_coord_range(coords, start, end);
+            }
+        }
     }
     rect.into()
 }
 
-pub fn bounding_rect_linestring<O: OffsetSizeTrait>(
-    geom: &'_ LineString<O>,
+/// The bounds of a set of rects, or `None` if there are none.
+pub(crate) fn total_bounds_rects(rects: impl IntoIterator<Item = Rect>) -> Option<Rect> {
+    let mut bounds = BoundingRect::new();
+    rects.into_iter().for_each(|rect| bounds.add_rect(&rect));
+    bounds.into()
+}
+
+// The per-type functions below return the corners of an R-tree envelope, which are infinite for
+// empty geometries.
+
+pub fn bounding_rect_point(geom: &impl PointTrait<T = f64>) -> ([f64; 2], [f64; 2]) {
+    let mut rect = BoundingRect::new();
+    rect.add_point(geom);
+    rect.into()
+}
+
+pub fn bounding_rect_multipoint<'a>(
+    geom: &impl MultiPointTrait<'a, T = f64>,
 ) -> ([f64; 2], [f64; 2]) {
     let mut rect = BoundingRect::new();
-    for geom_idx in 0..geom.num_coords() {
-        let point = geom.coord(geom_idx).unwrap();
-        rect.update(point);
-    }
+    rect.add_multi_point(geom);
     rect.into()
 }
 
-pub fn bounding_rect_multilinestring<O: OffsetSizeTrait>(
-    geom: &'_ MultiLineString<O>,
+pub fn bounding_rect_linestring<'a>(
+    geom: &impl LineStringTrait<'a, T = f64>,
 ) -> ([f64; 2], [f64; 2]) {
     let mut rect = BoundingRect::new();
-    for geom_idx in 0..geom.num_lines() {
-        let linestring = geom.line(geom_idx).unwrap();
-        for coord_idx in 0..linestring.num_coords() {
-            let point = linestring.coord(coord_idx).unwrap();
-            rect.update(point);
-        }
-    }
+    rect.add_line_string(geom);
     rect.into()
 }
 
-pub fn bounding_rect_polygon<O: OffsetSizeTrait>(geom: &'_ Polygon<O>) -> ([f64; 2], [f64; 2]) {
+pub fn bounding_rect_multilinestring<'a>(
+    geom: &impl MultiLineStringTrait<'a, T = f64>,
+) -> ([f64; 2], [f64; 2]) {
     let mut rect = BoundingRect::new();
-    let exterior_ring = geom.exterior().unwrap();
-    for coord_idx in 0..exterior_ring.num_coords() {
-        let point = exterior_ring.coord(coord_idx).unwrap();
-        rect.update(point);
-    }
+    rect.add_multi_line_string(geom);
+    rect.into()
+}
 
-    for interior_idx in 0..geom.num_interiors() {
-        let linestring = geom.interior(interior_idx).unwrap();
-        for coord_idx in 0..linestring.num_coords() {
-            let point = linestring.coord(coord_idx).unwrap();
-            rect.update(point);
-        }
-    }
+pub fn bounding_rect_polygon<'a>(geom: &impl PolygonTrait<'a, T = f64>) -> ([f64; 2], [f64; 2]) {
+    let mut rect = BoundingRect::new();
+    rect.add_polygon(geom);
     rect.into()
 }
 
-pub fn bounding_rect_multipolygon<O: OffsetSizeTrait>(
-    geom: &'_ MultiPolygon<O>,
+pub fn bounding_rect_multipolygon<'a>(
+    geom: &impl MultiPolygonTrait<'a, T = f64>,
 ) -> ([f64; 2], [f64; 2]) {
     let mut rect = BoundingRect::new();
-    for geom_idx in 0..geom.num_polygons() {
-        let polygon = geom.polygon(geom_idx).unwrap();
+    rect.add_multi_polygon(geom);
+    rect.into()
+}
 
-        let exterior_ring = polygon.exterior().unwrap();
-        for coord_idx in 0..exterior_ring.num_coords() {
-            let point = exterior_ring.coord(coord_idx).unwrap();
-            rect.update(point);
-        }
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{
+        GeometryArray, GeometryCollectionArray, LineStringArray, PointArray, PolygonArray,
+    };
+    use crate::scalar::Geometry;
+    use crate::test::polygon::p_array;
+    use crate::trait_::GeoArrayAccessor;
+    use crate::GeometryArrayTrait;
+    use geo::line_string;
+    use geo::BoundingRect as _;
 
-        for interior_idx in 0..polygon.num_interiors() {
-            let linestring = polygon.interior(interior_idx).unwrap();
-            for coord_idx in 0..linestring.num_coords() {
-                let point = linestring.coord(coord_idx).unwrap();
-                rect.update(point);
-            }
-        }
+    #[test]
+    fn scalar_polygon() {
+        let arr = p_array();
+        let geom = Geometry::Polygon(arr.value(1));
+        let rect = bounding_rect(&geom).unwrap();
+        assert_eq!(rect, Rect::new((-111., 41.), (-104., 45.)));
+        assert_eq!(Some(rect), arr.value_as_geo(1).bounding_rect());
+
+        let (lower, upper) = bounding_rect_polygon(&arr.value(1));
+        assert_eq!((lower, upper), ([-111., 41.], [-104., 45.]));
     }
 
-    rect.into()
-}
+    #[test]
+    fn empty() {
+        let geom = geo::Geometry::LineString(geo::LineString::<f64>::new(vec![]));
+        assert_eq!(bounding_rect(&geom), None);
+    }
+
+    #[test]
+    fn geometry_collection() {
+        let collection = geo::GeometryCollection::new_from(vec![
+            geo::point!(x: -10., y: 5.).into(),
+            line_string![(x: 0., y: 0.), (x: 3., y: 8.)].into(),
+        ]);
+        let arr: GeometryCollectionArray<i32> = vec![
+            collection.clone(),
+            geo::GeometryCollection::new_from(vec![]),
+        ]
+        .try_into()
+        .unwrap();
+
+        let expected = Rect::new((-10., 0.), (3., 8.));
+        let geom = Geometry::GeometryCollection(arr.value(0));
+        assert_eq!(bounding_rect(&geom), Some(expected));
+        let geom = geo::Geometry::GeometryCollection(collection);
+        assert_eq!(bounding_rect(&geom), Some(expected));
 
-// TODO: add tests from geo
+        let empty = Geometry::GeometryCollection(arr.value(1));
+        assert_eq!(bounding_rect(&empty), None);
+    }
+
+    #[test]
+    fn total_bounds_many_points() {
+        let num_points = 10_000;
+        let points: Vec<geo::Point> = (0..num_points)
+            .map(|i| geo::Point::new(i as f64 - 5000., (i % 100) as f64 * 0.5))
+            .collect();
+        let arr: PointArray = points.into();
+
+        assert_eq!(
+            arr.total_bounds(),
+            Some(Rect::new((-5000., 0.), (4999., 49.5)))
+        );
+
+        // Only the coordinates of the slice are scanned
+        let sliced = arr.slice(10, 20);
+        assert_eq!(
+            sliced.total_bounds(),
+            Some(Rect::new((-4990., 5.), (-4971., 14.5)))
+        );
+    }
+
+    #[test]
+    fn total_bounds_skips_nulls() {
+        // The null slots still hold coordinates, at the origin, far from the valid points
+        let points: PointArray = vec![
+            None,
+            Some(geo::point!(x: 10., y: 20.)),
+            None,
+            Some(geo::point!(x: 12., y: 25.)),
+        ]
+        .into();
+        assert_eq!(
+            points.total_bounds(),
+            Some(Rect::new((10., 20.), (12., 25.)))
+        );
+
+        let line_strings: LineStringArray<i32> = vec![
+            Some(line_string![(x: -1., y: -1.), (x: 100., y: 100.)]),
+            None,
+            Some(crate::test::linestring::ls1()),
+        ]
+        .into();
+        let arr = GeometryArray::LineString(line_strings.slice(1, 2));
+        assert_eq!(arr.total_bounds(), Some(Rect::new((3., 4.), (5., 6.))));
+
+        let all_null: PolygonArray<i32> = vec![None::<geo::Polygon>, None].into();
+        assert_eq!(all_null.total_bounds(), None);
+        let empty: PointArray = Vec::<geo::Point>::new().into();
+        assert_eq!(empty.total_bounds(), None);
+    }
+}
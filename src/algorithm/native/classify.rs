@@ -0,0 +1,369 @@
+use std::sync::Arc;
+
+use arrow::compute::{cast, concat};
+use arrow_array::{Array, ArrayRef, Float64Array, RecordBatch, UInt8Array};
+use arrow_schema::{DataType, Field, Schema};
+
+use crate::error::{GeoArrowError, Result};
+use crate::table::GeoTable;
+
+/// How [`classify`] splits values into classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassificationScheme {
+    /// Classes of equal width between the smallest and largest value.
+    EqualInterval,
+
+    /// Classes with about the same number of values each. Breaks are interpolated linearly
+    /// between values.
+    Quantile,
+
+    /// Jenks natural breaks, which minimize the sum of squared deviations from the class means.
+    ///
+    /// The breaks are optimal, computed with Fisher's dynamic programming algorithm. This takes
+    /// time quadratic in the number of values, so classify a sample of large inputs.
+    NaturalBreaks,
+}
+
+/// Split `values` into at most `k` classes, e.g. to color the geometries of a choropleth map.
+///
+/// Returns the class of each value, numbered from 0 for the class of the smallest values, and the
+/// breaks: the largest value of each class, in increasing order. A value belongs to the first
+/// class whose break is at least the value, so equal values always share a class.
+///
+/// There may be fewer than `k` classes, when there are fewer than `k` distinct values or when
+/// classes would be empty; the breaks only list classes that exist. Null and NaN values have a
+/// null class and are ignored when computing breaks. If no value is valid, there are no breaks.
+///
+/// # Errors
+///
+/// - if `k` is zero or larger than 255
+pub fn classify(
+    values: &Float64Array,
+    scheme: ClassificationScheme,
+    k: usize,
+) -> Result<(UInt8Array, Vec<f64>)> {
+    if k == 0 || k > u8::MAX as usize {
+        return Err(GeoArrowError::General(format!(
+            "The number of classes must be between 1 and 255, found {}",
+            k
+        )));
+    }
+
+    let mut sorted: Vec<f64> = values.iter().flatten().filter(|v| !v.is_nan()).collect();
+    sorted.sort_by(f64::total_cmp);
+
+    let mut breaks = if sorted.is_empty() {
+        vec![]
+    } else {
+        match scheme {
+            ClassificationScheme::EqualInterval => equal_interval_breaks(&sorted, k),
+            ClassificationScheme::Quantile => quantile_breaks(&sorted, k),
+            ClassificationScheme::NaturalBreaks => natural_breaks(&sorted, k),
+        }
+    };
+    breaks.dedup();
+    // Drop breaks of classes that no value falls in
+    let mut prev_break = f64::NEG_INFINITY;
+    breaks.retain(|b| {
+        let lower = prev_break;
+        prev_break = *b;
+        sorted.iter().any(|v| *v > lower && v <= b)
+    });
+
+    let class_ids = values
+        .iter()
+        .map(|maybe_v| {
+            maybe_v
+                .filter(|v| !v.is_nan())
+                .map(|v| breaks.partition_point(|b| *b < v).min(breaks.len() - 1) as u8)
+        })
+        .collect();
+    Ok((class_ids, breaks))
+}
+
+fn equal_interval_breaks(sorted: &[f64], k: usize) -> Vec<f64> {
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    let width = (max - min) / k as f64;
+    let mut breaks: Vec<f64> = (1..k).map(|i| min + width * i as f64).collect();
+    // Avoid rounding the largest value out of the last class
+    breaks.push(max);
+    breaks
+}
+
+fn quantile_breaks(sorted: &[f64], k: usize) -> Vec<f64> {
+    (1..=k)
+        .map(|i| {
+            let position = (sorted.len() - 1) as f64 * i as f64 / k as f64;
+            let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+            let fraction = position - lower as f64;
+            sorted[lower] + (sorted[upper] - sorted[lower]) * fraction
+        })
+        .collect()
+}
+
+fn natural_breaks(sorted: &[f64], k: usize) -> Vec<f64> {
+    let n = sorted.len();
+    let mut distinct = sorted.to_vec();
+    distinct.dedup();
+    let k = k.min(distinct.len());
+
+    // lower_class_limits[l][j] is the 1-based index of the first value of the last class, in the
+    // best split of the first l values into j classes, whose sum of squared deviations is
+    // variance_combinations[l][j]
+    let mut lower_class_limits = vec![vec![0usize; k + 1]; n + 1];
+    let mut variance_combinations = vec![vec![f64::INFINITY; k + 1]; n + 1];
+    for j in 1..=k {
+        lower_class_limits[1][j] = 1;
+        variance_combinations[1][j] = 0.;
+    }
+
+    for l in 2..=n {
+        let (mut sum, mut sum_squares, mut variance) = (0., 0., 0.);
+        for m in 1..=l {
+            // The candidate last class holds values lower..=l
+            let lower = l - m + 1;
+            let value = sorted[lower - 1];
+            sum += value;
+            sum_squares += value * value;
+            variance = sum_squares - sum * sum / m as f64;
+
+            if lower > 1 {
+                for j in 2..=k {
+                    let candidate = variance + variance_combinations[lower - 1][j - 1];
+                    if variance_combinations[l][j] >= candidate {
+                        lower_class_limits[l][j] = lower;
+                        variance_combinations[l][j] = candidate;
+                    }
+                }
+            }
+        }
+        lower_class_limits[l][1] = 1;
+        variance_combinations[l][1] = variance;
+    }
+
+    let mut breaks = vec![0.; k];
+    breaks[k - 1] = sorted[n - 1];
+    let mut end = n;
+    for j in (2..=k).rev() {
+        let lower = lower_class_limits[end][j];
+        breaks[j - 2] = sorted[lower - 2];
+        end = lower - 1;
+    }
+    breaks
+}
+
+impl GeoTable {
+    /// Classify the numeric column `name` with [`classify`], and append the class of each row as
+    /// a `UInt8` column named `{name}_class`.
+    ///
+    /// The values are cast to `Float64` first. Returns the new table, with the batches of this
+    /// one, and the breaks.
+    ///
+    /// # Errors
+    ///
+    /// - if there is no column named `name`
+    /// - if the column can't be cast to `Float64`
+    /// - if `k` is zero or larger than 255
+    pub fn classify_column(
+        &self,
+        name: &str,
+        scheme: ClassificationScheme,
+        k: usize,
+    ) -> Result<(GeoTable, Vec<f64>)> {
+        let schema = self.schema();
+        let column_idx = schema.index_of(name)?;
+        let chunks: Vec<&dyn Array> = self
+            .batches()
+            .iter()
+            .map(|batch| batch.column(column_idx).as_ref())
+            .collect();
+        let values = if chunks.is_empty() {
+            Float64Array::from(Vec::<f64>::new())
+        } else {
+            let column = cast(concat(&chunks)?.as_ref(), &DataType::Float64)?;
+            column
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .unwrap()
+                .clone()
+        };
+        let (class_ids, breaks) = classify(&values, scheme, k)?;
+
+        let mut fields: Vec<_> = schema.fields().iter().cloned().collect();
+        fields.push(Arc::new(Field::new(
+            format!("{}_class", name),
+            DataType::UInt8,
+            true,
+        )));
+        let output_schema = Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()));
+
+        let mut offset = 0;
+        let mut batches = Vec::with_capacity(self.batches().len());
+        for batch in self.batches() {
+            let mut columns = batch.columns().to_vec();
+            let batch_class_ids: ArrayRef = Arc::new(class_ids.slice(offset, batch.num_rows()));
+            columns.push(batch_class_ids);
+            offset += batch.num_rows();
+            batches.push(RecordBatch::try_new(output_schema.clone(), columns)?);
+        }
+
+        let table = GeoTable::try_new(output_schema, batches, self.geometry_column_index())?;
+        Ok((table, breaks))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::PointArray;
+    use crate::GeometryArrayTrait;
+    use arrow_array::Int32Array;
+    use geo::point;
+
+    fn class_ids(values: &[f64], scheme: ClassificationScheme, k: usize) -> Vec<Option<u8>> {
+        let (class_ids, _) = classify(&values.to_vec().into(), scheme, k).unwrap();
+        class_ids.iter().collect()
+    }
+
+    #[test]
+    fn equal_interval() {
+        let values = Float64Array::from(vec![Some(0.), Some(2.5), None, Some(10.), Some(f64::NAN)]);
+        let (class_ids, breaks) =
+            classify(&values, ClassificationScheme::EqualInterval, 4).unwrap();
+        // The classes (2.5, 5] and (5, 7.5] are empty
+        assert_eq!(breaks, vec![2.5, 10.]);
+        assert_eq!(
+            class_ids.iter().collect::<Vec<_>>(),
+            vec![Some(0), Some(0), None, Some(1), None]
+        );
+    }
+
+    #[test]
+    fn quantile() {
+        let values = [1., 2., 3., 4., 5., 6., 7., 8., 9.];
+        let (_, breaks) =
+            classify(&values.to_vec().into(), ClassificationScheme::Quantile, 3).unwrap();
+        let expected = [11. / 3., 19. / 3., 9.];
+        assert!(breaks
+            .iter()
+            .zip(expected)
+            .all(|(b, expected)| (b - expected).abs() < 1e-12));
+
+        // Ties stay in one class, even when it holds more than its share
+        assert_eq!(
+            class_ids(&[1., 1., 1., 1., 2.], ClassificationScheme::Quantile, 2),
+            vec![Some(0), Some(0), Some(0), Some(0), Some(1)]
+        );
+    }
+
+    /// The smallest sum of squared deviations from the class means over all splits of `sorted`
+    /// into `k` contiguous classes
+    fn brute_force_deviation(sorted: &[f64], k: usize) -> f64 {
+        let deviation = |class: &[f64]| {
+            let mean = class.iter().sum::<f64>() / class.len() as f64;
+            class.iter().map(|v| (v - mean).powi(2)).sum::<f64>()
+        };
+        if k == 1 {
+            return deviation(sorted);
+        }
+        (1..=sorted.len() - k + 1)
+            .map(|split| {
+                deviation(&sorted[..split]) + brute_force_deviation(&sorted[split..], k - 1)
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn natural_breaks() {
+        let values = [1., 2., 3., 10., 11., 12., 20., 21., 22.];
+        let (class_ids, breaks) = classify(
+            &values.to_vec().into(),
+            ClassificationScheme::NaturalBreaks,
+            3,
+        )
+        .unwrap();
+        assert_eq!(breaks, vec![3., 12., 22.]);
+        assert_eq!(class_ids.values().to_vec(), vec![0, 0, 0, 1, 1, 1, 2, 2, 2]);
+
+        let values = [
+            0.5, 1.7, 2.1, 4.8, 5.2, 5.3, 9.9, 13.1, 13.6, 14., 21.4, 22.9, 35., 36.2, 80.,
+        ];
+        for k in 2..=5 {
+            let (class_ids, breaks) = classify(
+                &values.to_vec().into(),
+                ClassificationScheme::NaturalBreaks,
+                k,
+            )
+            .unwrap();
+            assert_eq!(breaks.len(), k);
+            let deviation: f64 = (0..k as u8)
+                .map(|class| {
+                    let members: Vec<f64> = values
+                        .iter()
+                        .zip(class_ids.values())
+                        .filter(|(_, c)| **c == class)
+                        .map(|(v, _)| *v)
+                        .collect();
+                    brute_force_deviation(&members, 1)
+                })
+                .sum();
+            assert!((deviation - brute_force_deviation(&values, k)).abs() < 1e-9);
+        }
+
+        // Fewer distinct values than classes
+        let (_, breaks) = classify(
+            &vec![1., 1., 2.].into(),
+            ClassificationScheme::NaturalBreaks,
+            5,
+        )
+        .unwrap();
+        assert_eq!(breaks, vec![1., 2.]);
+    }
+
+    #[test]
+    fn invalid_k() {
+        let values = Float64Array::from(vec![1.]);
+        assert!(classify(&values, ClassificationScheme::Quantile, 0).is_err());
+        assert!(classify(&values, ClassificationScheme::Quantile, 256).is_err());
+
+        let (class_ids, breaks) = classify(
+            &Float64Array::from(vec![None]),
+            ClassificationScheme::Quantile,
+            2,
+        )
+        .unwrap();
+        assert!(breaks.is_empty());
+        assert!(class_ids.is_null(0));
+    }
+
+    #[test]
+    fn classify_column() {
+        let points: PointArray = vec![point!(x: 0., y: 0.); 4].into();
+        let population: ArrayRef = Arc::new(Int32Array::from(vec![10, 2000, 15, 1800]));
+        let field = Arc::new(Field::new("population", DataType::Int32, false));
+        let table = GeoTable::from_arrays(
+            vec![
+                (field, population),
+                points.into_array_ref_with_field("geometry"),
+            ],
+            1,
+        )
+        .unwrap();
+
+        let (table, breaks) = table
+            .classify_column("population", ClassificationScheme::NaturalBreaks, 2)
+            .unwrap();
+        assert_eq!(breaks, vec![15., 2000.]);
+        assert_eq!(table.schema().field(2).name(), "population_class");
+        let class_ids = table.batches()[0]
+            .column(2)
+            .as_any()
+            .downcast_ref::<UInt8Array>()
+            .unwrap();
+        assert_eq!(class_ids.values().to_vec(), vec![0, 1, 0, 1]);
+
+        assert!(table
+            .classify_column("missing", ClassificationScheme::Quantile, 2)
+            .is_err());
+    }
+}
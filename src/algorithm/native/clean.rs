@@ -0,0 +1,201 @@
+use crate::array::*;
+use crate::GeometryArrayTrait;
+use arrow_array::OffsetSizeTrait;
+use geo::RemoveRepeatedPoints;
+
+/// Repair common defects of linear and polygonal geometries, so that they are accepted by tools
+/// that follow the OGC simple features rules.
+///
+/// Cleaning takes these steps, in order:
+///
+/// 1. Remove consecutive repeated coordinates from every line string and ring, and repeated
+///    points from multipoints.
+/// 2. Close every polygon ring whose last coordinate differs from its first.
+/// 3. Drop degenerate rings, with fewer than three distinct vertices. A polygon whose exterior
+///    ring is degenerate becomes empty, including its interior rings. Line strings with fewer
+///    than two distinct points become empty.
+/// 4. Drop empty parts from multi line strings and multipolygons.
+///
+/// This doesn't fix self-intersections or wrong ring orientation. Points are returned as they
+/// are, and null geometries stay null.
+pub trait Clean {
+    fn clean(&self) -> Self;
+}
+
+fn clean_line_string(line_string: &geo::LineString) -> geo::LineString {
+    let line_string = line_string.remove_repeated_points();
+    if line_string.0.len() < 2 {
+        return geo::LineString::new(vec![]);
+    }
+    line_string
+}
+
+/// A cleaned ring, or `None` if it is degenerate.
+fn clean_ring(ring: &geo::LineString) -> Option<geo::LineString> {
+    let mut ring = ring.remove_repeated_points();
+    ring.close();
+    // A closed ring with three distinct vertices has four coordinates
+    (ring.0.len() >= 4).then_some(ring)
+}
+
+fn clean_polygon(polygon: &geo::Polygon) -> geo::Polygon {
+    let Some(exterior) = clean_ring(polygon.exterior()) else {
+        return geo::Polygon::new(geo::LineString::new(vec![]), vec![]);
+    };
+    let interiors = polygon.interiors().iter().filter_map(clean_ring).collect();
+    geo::Polygon::new(exterior, interiors)
+}
+
+fn clean_multi_line_string(multi_line_string: &geo::MultiLineString) -> geo::MultiLineString {
+    multi_line_string
+        .iter()
+        .map(clean_line_string)
+        .filter(|line_string| !line_string.0.is_empty())
+        .collect()
+}
+
+fn clean_multi_polygon(multi_polygon: &geo::MultiPolygon) -> geo::MultiPolygon {
+    multi_polygon
+        .iter()
+        .map(clean_polygon)
+        .filter(|polygon| !polygon.exterior().0.is_empty())
+        .collect()
+}
+
+fn clean_geometry_collection(
+    geometry_collection: &geo::GeometryCollection,
+) -> geo::GeometryCollection {
+    geometry_collection
+        .iter()
+        .map(|geom| match geom {
+            geo::Geometry::MultiPoint(g) => g.remove_repeated_points().into(),
+            geo::Geometry::LineString(g) => clean_line_string(g).into(),
+            geo::Geometry::Polygon(g) => clean_polygon(g).into(),
+            geo::Geometry::MultiLineString(g) => clean_multi_line_string(g).into(),
+            geo::Geometry::MultiPolygon(g) => clean_multi_polygon(g).into(),
+            geom => geom.clone(),
+        })
+        .collect()
+}
+
+// Note: this implementation is outside the macro because it is not generic over O
+impl Clean for PointArray {
+    fn clean(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl {
+    ($type:ty, $geo_type:ty, $clean_fn:expr) => {
+        impl<O: OffsetSizeTrait> Clean for $type {
+            fn clean(&self) -> Self {
+                let output_geoms: Vec<Option<$geo_type>> = self
+                    .iter_geo()
+                    .map(|maybe_g| maybe_g.map(|geom| $clean_fn(&geom)))
+                    .collect();
+
+                let output: Self = output_geoms.into();
+                output.with_metadata(self.metadata().clone())
+            }
+        }
+    };
+}
+
+iter_geo_impl!(
+    MultiPointArray<O>,
+    geo::MultiPoint,
+    geo::MultiPoint::remove_repeated_points
+);
+iter_geo_impl!(LineStringArray<O>, geo::LineString, clean_line_string);
+iter_geo_impl!(PolygonArray<O>, geo::Polygon, clean_polygon);
+iter_geo_impl!(
+    MultiLineStringArray<O>,
+    geo::MultiLineString,
+    clean_multi_line_string
+);
+iter_geo_impl!(MultiPolygonArray<O>, geo::MultiPolygon, clean_multi_polygon);
+
+// Collections are built fallibly, and don't carry metadata
+impl<O: OffsetSizeTrait> Clean for GeometryCollectionArray<O> {
+    fn clean(&self) -> Self {
+        let output_geoms: Vec<Option<geo::GeometryCollection>> = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(|geom| clean_geometry_collection(&geom)))
+            .collect();
+
+        // Cleaning members can't nest the collections read from this array
+        let output: Self = output_geoms.try_into().unwrap();
+        output.into_coord_type(self.coord_type())
+    }
+}
+
+impl<O: OffsetSizeTrait> Clean for GeometryArray<O> {
+    fn clean(&self) -> Self {
+        use GeometryArray::*;
+
+        match self {
+            Point(arr) => Point(arr.clean()),
+            LineString(arr) => LineString(arr.clean()),
+            Polygon(arr) => Polygon(arr.clean()),
+            MultiPoint(arr) => MultiPoint(arr.clean()),
+            MultiLineString(arr) => MultiLineString(arr.clean()),
+            MultiPolygon(arr) => MultiPolygon(arr.clean()),
+            GeometryCollection(arr) => GeometryCollection(Box::new(arr.clean())),
+            Rect(arr) => Rect(arr.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::geo_traits::{CoordTrait, LineStringTrait};
+    use crate::trait_::GeoArrayAccessor;
+    use arrow_buffer::OffsetBuffer;
+
+    fn ring_coords<'a>(ring: &'a impl LineStringTrait<'a, T = f64>) -> Vec<(f64, f64)> {
+        (0..ring.num_coords())
+            .map(|i| ring.coord(i).unwrap().x_y())
+            .collect()
+    }
+
+    #[test]
+    fn messy_polygon() {
+        // An unclosed exterior with duplicate vertices, and a hole that collapses to a line
+        let coords = vec![
+            0., 0., 4., 0., 4., 0., 4., 4., 4., 4., 4., 4., 0., 4., //
+            1., 1., 2., 2., 2., 2., 1., 1., //
+        ];
+        let coords = CoordBuffer::Interleaved(InterleavedCoordBuffer::new(coords.into()));
+        let geom_offsets = OffsetBuffer::new(vec![0, 2].into());
+        let ring_offsets = OffsetBuffer::new(vec![0, 7, 11].into());
+        let arr = PolygonArray::<i32>::new(coords, geom_offsets, ring_offsets, None);
+
+        let cleaned = arr.clean();
+        let polygon = cleaned.value(0);
+        assert_eq!(
+            ring_coords(&polygon.exterior().unwrap()),
+            vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]
+        );
+        assert_eq!(polygon.num_interiors(), 0);
+    }
+
+    #[test]
+    fn degenerate_parts() {
+        let line_strings: LineStringArray<i32> =
+            vec![Some(geo::LineString::from(vec![(1., 1.), (1., 1.)])), None].into();
+        let cleaned = line_strings.clean();
+        assert_eq!(cleaned.value(0).num_coords(), 0);
+        assert!(cleaned.is_null(1));
+
+        let sliver = geo::Polygon::new(vec![(0., 0.), (1., 1.), (0., 0.)].into(), vec![]);
+        let square = geo::Polygon::new(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.)].into(), vec![]);
+        let multi_polygons: MultiPolygonArray<i32> =
+            vec![Some(geo::MultiPolygon::new(vec![sliver, square.clone()]))].into();
+        assert_eq!(
+            multi_polygons.clean().value_as_geo(0),
+            geo::MultiPolygon::new(vec![square])
+        );
+    }
+}
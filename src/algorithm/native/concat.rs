@@ -0,0 +1,929 @@
+use crate::array::mutable_offset::OffsetsBuilder;
+use crate::array::*;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeoArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, OffsetSizeTrait};
+use arrow_buffer::{NullBuffer, NullBufferBuilder, OffsetBuffer, ScalarBuffer};
+
+/// How [`concat_promoting`] combines arrays whose geometry types have no common single-geometry
+/// type, such as points and polygons.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PromotionPolicy {
+    /// Combine the arrays into a [`MixedGeometryArray`].
+    #[default]
+    Mixed,
+
+    /// Encode every geometry as WKB.
+    WKB,
+}
+
+/// The output of [`concat_promoting`].
+#[derive(Debug, Clone)]
+pub enum PromotedGeometryArray<O: OffsetSizeTrait> {
+    /// All input arrays had the same geometry type, or single and multi variants of the same
+    /// geometry type.
+    Geometry(GeometryArray<O>),
+
+    /// The input arrays had unrelated geometry types, with [`PromotionPolicy::Mixed`].
+    Mixed(Box<MixedGeometryArray<O>>),
+
+    /// The input arrays had unrelated geometry types, with [`PromotionPolicy::WKB`].
+    WKB(WKBArray<O>),
+}
+
+/// The geometry type of one input array or geometry. Rects are handled as polygons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ChunkType {
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+}
+
+impl ChunkType {
+    pub(crate) fn of<O: OffsetSizeTrait>(arr: &GeometryArray<O>) -> Self {
+        match arr {
+            GeometryArray::Point(_) => ChunkType::Point,
+            GeometryArray::LineString(_) => ChunkType::LineString,
+            GeometryArray::Polygon(_) | GeometryArray::Rect(_) => ChunkType::Polygon,
+            GeometryArray::MultiPoint(_) => ChunkType::MultiPoint,
+            GeometryArray::MultiLineString(_) => ChunkType::MultiLineString,
+            GeometryArray::MultiPolygon(_) => ChunkType::MultiPolygon,
+            GeometryArray::GeometryCollection(_) => ChunkType::GeometryCollection,
+        }
+    }
+
+    pub(crate) fn of_geometry(geom: &geo::Geometry) -> Self {
+        match geom {
+            geo::Geometry::Point(_) => ChunkType::Point,
+            geo::Geometry::Line(_) | geo::Geometry::LineString(_) => ChunkType::LineString,
+            geo::Geometry::Polygon(_) | geo::Geometry::Rect(_) | geo::Geometry::Triangle(_) => {
+                ChunkType::Polygon
+            }
+            geo::Geometry::MultiPoint(_) => ChunkType::MultiPoint,
+            geo::Geometry::MultiLineString(_) => ChunkType::MultiLineString,
+            geo::Geometry::MultiPolygon(_) => ChunkType::MultiPolygon,
+            geo::Geometry::GeometryCollection(_) => ChunkType::GeometryCollection,
+        }
+    }
+
+    fn to_multi(self) -> Self {
+        match self {
+            ChunkType::Point | ChunkType::MultiPoint => ChunkType::MultiPoint,
+            ChunkType::LineString | ChunkType::MultiLineString => ChunkType::MultiLineString,
+            ChunkType::Polygon | ChunkType::MultiPolygon => ChunkType::MultiPolygon,
+            ChunkType::GeometryCollection => ChunkType::GeometryCollection,
+        }
+    }
+
+    /// Whether geometries of type `other` can be stored in an array of this type.
+    pub(crate) fn can_hold(self, other: Self) -> bool {
+        self == other || self == other.to_multi() || self == ChunkType::GeometryCollection
+    }
+}
+
+/// The least common geometry type of all chunks, if there is one.
+pub(crate) fn common_type(chunk_types: &[ChunkType]) -> Option<ChunkType> {
+    // A geometry collection can hold any of the other types
+    if chunk_types.contains(&ChunkType::GeometryCollection) {
+        return Some(ChunkType::GeometryCollection);
+    }
+
+    let first = chunk_types[0];
+    if chunk_types.iter().all(|t| *t == first) {
+        return Some(first);
+    }
+
+    let multi = first.to_multi();
+    chunk_types
+        .iter()
+        .all(|t| t.to_multi() == multi)
+        .then_some(multi)
+}
+
+/// A mutable array of one [`ChunkType`], which casts each geometry pushed to it to that type.
+pub(crate) enum GeometryBuilder<O: OffsetSizeTrait> {
+    Point(MutablePointArray),
+    LineString(MutableLineStringArray<O>),
+    Polygon(MutablePolygonArray<O>),
+    MultiPoint(MutableMultiPointArray<O>),
+    MultiLineString(MutableMultiLineStringArray<O>),
+    MultiPolygon(MutableMultiPolygonArray<O>),
+    GeometryCollection(Box<MutableGeometryCollectionArray<O>>),
+}
+
+impl<O: OffsetSizeTrait> GeometryBuilder<O> {
+    pub(crate) fn new(chunk_type: ChunkType, capacity: usize) -> Self {
+        match chunk_type {
+            ChunkType::Point => GeometryBuilder::Point(MutablePointArray::with_capacity(capacity)),
+            ChunkType::LineString => {
+                GeometryBuilder::LineString(MutableLineStringArray::with_capacities(0, capacity))
+            }
+            ChunkType::Polygon => {
+                GeometryBuilder::Polygon(MutablePolygonArray::with_capacities(0, 0, capacity))
+            }
+            ChunkType::MultiPoint => {
+                GeometryBuilder::MultiPoint(MutableMultiPointArray::with_capacities(0, capacity))
+            }
+            ChunkType::MultiLineString => GeometryBuilder::MultiLineString(
+                MutableMultiLineStringArray::with_capacities(0, 0, capacity),
+            ),
+            ChunkType::MultiPolygon => GeometryBuilder::MultiPolygon(
+                MutableMultiPolygonArray::with_capacities(0, 0, 0, capacity),
+            ),
+            ChunkType::GeometryCollection => GeometryBuilder::GeometryCollection(Box::new(
+                MutableGeometryCollectionArray::with_capacity(capacity),
+            )),
+        }
+    }
+
+    pub(crate) fn chunk_type(&self) -> ChunkType {
+        match self {
+            GeometryBuilder::Point(_) => ChunkType::Point,
+            GeometryBuilder::LineString(_) => ChunkType::LineString,
+            GeometryBuilder::Polygon(_) => ChunkType::Polygon,
+            GeometryBuilder::MultiPoint(_) => ChunkType::MultiPoint,
+            GeometryBuilder::MultiLineString(_) => ChunkType::MultiLineString,
+            GeometryBuilder::MultiPolygon(_) => ChunkType::MultiPolygon,
+            GeometryBuilder::GeometryCollection(_) => ChunkType::GeometryCollection,
+        }
+    }
+
+    /// Add a geometry, or a null, to the end of the array.
+    ///
+    /// # Errors
+    ///
+    /// - if the geometry can't be cast to the type of the array. Nothing is added in this case.
+    /// - if the geometry is a collection with a nested collection. Nothing is added in this case.
+    /// - if the array becomes too large for offsets of type `O`
+    pub(crate) fn push(&mut self, geom: Option<geo::Geometry>) -> Result<()> {
+        if let Some(geom) = &geom {
+            let geom_type = ChunkType::of_geometry(geom);
+            if !self.chunk_type().can_hold(geom_type) {
+                return Err(GeoArrowError::General(format!(
+                    "Cannot cast a {:?} geometry to {:?}",
+                    geom_type,
+                    self.chunk_type()
+                )));
+            }
+        }
+
+        match self {
+            GeometryBuilder::Point(builder) => {
+                builder.push_point(geom.map(into_point).as_ref());
+                Ok(())
+            }
+            GeometryBuilder::LineString(builder) => {
+                builder.push_line_string(geom.map(into_line_string).as_ref())
+            }
+            GeometryBuilder::Polygon(builder) => {
+                builder.push_polygon(geom.map(into_polygon).as_ref())
+            }
+            GeometryBuilder::MultiPoint(builder) => match geom {
+                Some(geo::Geometry::Point(g)) => builder.push_point(Some(&g)),
+                Some(g) => builder.push_multi_point(Some(&into_multi_point(g))),
+                None => builder.push_multi_point(None::<&geo::MultiPoint>),
+            },
+            GeometryBuilder::MultiLineString(builder) => match geom {
+                Some(g @ (geo::Geometry::Line(_) | geo::Geometry::LineString(_))) => {
+                    builder.push_line_string(Some(&into_line_string(g)))
+                }
+                Some(g) => builder.push_multi_line_string(Some(&into_multi_line_string(g))),
+                None => builder.push_multi_line_string(None::<&geo::MultiLineString>),
+            },
+            GeometryBuilder::MultiPolygon(builder) => match geom {
+                Some(geo::Geometry::MultiPolygon(g)) => builder.push_multi_polygon(Some(&g)),
+                Some(g) => builder.push_polygon(Some(&into_polygon(g))),
+                None => builder.push_multi_polygon(None::<&geo::MultiPolygon>),
+            },
+            GeometryBuilder::GeometryCollection(builder) => match geom {
+                Some(geo::Geometry::GeometryCollection(g)) => {
+                    builder.push_geometry_collection(Some(&g))
+                }
+                Some(g) => {
+                    builder.push_geometry_collection(Some(&geo::GeometryCollection(vec![g])))
+                }
+                None => {
+                    builder.push_null();
+                    Ok(())
+                }
+            },
+        }
+    }
+
+    pub(crate) fn finish(self) -> GeometryArray<O> {
+        match self {
+            GeometryBuilder::Point(builder) => GeometryArray::Point(builder.into()),
+            GeometryBuilder::LineString(builder) => GeometryArray::LineString(builder.into()),
+            GeometryBuilder::Polygon(builder) => GeometryArray::Polygon(builder.into()),
+            GeometryBuilder::MultiPoint(builder) => GeometryArray::MultiPoint(builder.into()),
+            GeometryBuilder::MultiLineString(builder) => {
+                GeometryArray::MultiLineString(builder.into())
+            }
+            GeometryBuilder::MultiPolygon(builder) => GeometryArray::MultiPolygon(builder.into()),
+            GeometryBuilder::GeometryCollection(builder) => {
+                GeometryArray::GeometryCollection(Box::new((*builder).into()))
+            }
+        }
+    }
+}
+
+/// Concatenate geometry arrays of possibly different types.
+///
+/// The arrays are cast to their least common geometry type before concatenating:
+///
+/// - arrays of the same type keep that type
+/// - a mix of single and multi geometries of the same kind, e.g. polygons and multi polygons,
+///   becomes the multi type
+/// - a mix that includes geometry collections becomes a geometry collection array, where each
+///   other geometry is a collection of one member
+/// - any other mix is combined according to `policy`
+///
+/// Arrays that all have the same type are concatenated with [`Concat`], which copies their
+/// buffers directly. Otherwise every geometry is copied through geo objects, and
+/// [`RectArray`]s are treated as polygon arrays. The output has the coordinate type and metadata
+/// of the first array.
+///
+/// # Errors
+///
+/// - if `arrays` is empty
+/// - if the output is too large for offsets of type `O`
+pub fn concat_promoting<O: OffsetSizeTrait>(
+    arrays: &[GeometryArray<O>],
+    policy: PromotionPolicy,
+) -> Result<PromotedGeometryArray<O>> {
+    let first = arrays.first().ok_or_else(|| {
+        GeoArrowError::General("Cannot concatenate an empty list of arrays".to_string())
+    })?;
+    if arrays
+        .iter()
+        .all(|arr| std::mem::discriminant(arr) == std::mem::discriminant(first))
+    {
+        let arrays: Vec<&GeometryArray<O>> = arrays.iter().collect();
+        return Ok(PromotedGeometryArray::Geometry(Concat::concat(&arrays)?));
+    }
+
+    let capacity = arrays.iter().map(|arr| arr.len()).sum();
+    let chunk_types: Vec<ChunkType> = arrays.iter().map(ChunkType::of).collect();
+    let Some(chunk_type) = common_type(&chunk_types) else {
+        return match policy {
+            PromotionPolicy::Mixed => {
+                let mixed = concat_mixed(arrays)?;
+                Ok(PromotedGeometryArray::Mixed(Box::new(mixed)))
+            }
+            PromotionPolicy::WKB => {
+                let mut geoms = Vec::with_capacity(capacity);
+                for_each_geometry(arrays, |_, geom| {
+                    geoms.push(geom.map(|g| match g {
+                        geo::Geometry::Rect(rect) => geo::Geometry::Polygon(rect.to_polygon()),
+                        g => g,
+                    }));
+                    Ok(())
+                })?;
+                Ok(PromotedGeometryArray::WKB(geoms.into()))
+            }
+        };
+    };
+
+    let mut builder = GeometryBuilder::new(chunk_type, capacity);
+    for_each_geometry(arrays, |_, geom| builder.push(geom))?;
+    Ok(PromotedGeometryArray::Geometry(
+        builder
+            .finish()
+            .into_coord_type(first.coord_type())
+            .with_metadata(first.metadata().clone()),
+    ))
+}
+
+/// Concatenate geometry arrays of any types into a [`MixedGeometryArray`], in order.
+///
+/// Unlike [`concat_promoting`], the output is always mixed, even when all arrays have the same
+/// type. [`RectArray`]s are stored as polygons. The output has the coordinate type of the first
+/// array, and an empty list of arrays gives an empty array.
+///
+/// # Errors
+///
+/// - if any array is a [`GeometryCollectionArray`], which a mixed array cannot hold
+/// - if the output is too large for offsets of type `O`
+pub fn concat_mixed<O: OffsetSizeTrait>(
+    arrays: &[GeometryArray<O>],
+) -> Result<MixedGeometryArray<O>> {
+    let chunk_types: Vec<ChunkType> = arrays.iter().map(ChunkType::of).collect();
+    if chunk_types.contains(&ChunkType::GeometryCollection) {
+        return Err(GeoArrowError::General(
+            "Geometry collections cannot be stored in a mixed geometry array".to_string(),
+        ));
+    }
+    let capacity = arrays.iter().map(|arr| arr.len()).sum();
+
+    let mut builder = MutableMixedGeometryArray::<O>::new();
+    builder.reserve_geometries(capacity);
+    for_each_geometry(arrays, |chunk_idx, geom| {
+        match geom {
+            Some(geo::Geometry::Point(g)) => builder.push_point(Some(&g)),
+            Some(geo::Geometry::LineString(g)) => builder.push_line_string(Some(&g))?,
+            Some(geo::Geometry::Polygon(g)) => builder.push_polygon(Some(&g))?,
+            Some(geo::Geometry::Rect(g)) => builder.push_polygon(Some(&g.to_polygon()))?,
+            Some(geo::Geometry::MultiPoint(g)) => builder.push_multi_point(Some(&g))?,
+            Some(geo::Geometry::MultiLineString(g)) => builder.push_multi_line_string(Some(&g))?,
+            Some(geo::Geometry::MultiPolygon(g)) => builder.push_multi_polygon(Some(&g))?,
+            Some(_) => unreachable!("geometry arrays only contain the types above"),
+            // A union has no validity of its own, so nulls are stored in the child array of
+            // the chunk's type
+            None => match chunk_types[chunk_idx] {
+                ChunkType::Point => builder.push_point(None::<&geo::Point>),
+                ChunkType::LineString => builder.push_line_string(None::<&geo::LineString>)?,
+                ChunkType::Polygon => builder.push_polygon(None::<&geo::Polygon>)?,
+                ChunkType::MultiPoint => builder.push_multi_point(None::<&geo::MultiPoint>)?,
+                ChunkType::MultiLineString => {
+                    builder.push_multi_line_string(None::<&geo::MultiLineString>)?
+                }
+                ChunkType::MultiPolygon => {
+                    builder.push_multi_polygon(None::<&geo::MultiPolygon>)?
+                }
+                ChunkType::GeometryCollection => unreachable!("checked above"),
+            },
+        };
+        Ok(())
+    })?;
+    let mixed: MixedGeometryArray<O> = builder.into();
+    Ok(match arrays.first() {
+        Some(first) => mixed.into_coord_type(first.coord_type()),
+        None => mixed,
+    })
+}
+
+/// Concatenate arrays of the same geometry type into one array.
+///
+/// Coordinates, offsets and validity are copied straight from the input buffers, without going
+/// through geo objects. Sliced arrays only contribute the coordinates of their own rows.
+///
+/// The output has the coordinate layout and metadata of the first array, and other arrays are cast
+/// to that layout. Coordinates are only stored as `f32` if all arrays store them that way. If some
+/// arrays have z or m values and others don't, the output has them, and they are NaN for the
+/// coordinates that lacked them.
+///
+/// Use [`concat_promoting`] to concatenate arrays of different geometry types.
+pub trait Concat: Sized {
+    /// # Errors
+    ///
+    /// - if `arrays` is empty
+    /// - if the output is too large for offsets of type `O`
+    /// - for [`GeometryArray`]s, if the arrays are of different geometry types
+    fn concat(arrays: &[&Self]) -> Result<Self>;
+}
+
+fn first<'a, T>(arrays: &[&'a T]) -> Result<&'a T> {
+    arrays.first().copied().ok_or_else(|| {
+        GeoArrowError::General("Cannot concatenate an empty list of arrays".to_string())
+    })
+}
+
+/// Concatenates ranges of coordinate buffers
+struct CoordConcat {
+    output: MutableCoordBuffer,
+    float32: bool,
+}
+
+impl CoordConcat {
+    fn new<'a>(coords: impl Iterator<Item = &'a CoordBuffer> + Clone) -> Self {
+        let first = coords.clone().next().expect("checked by first()");
+        let dim = Dimension::widest(coords.clone().map(|c| c.dim()));
+        let float32 = coords.clone().all(|c| c.is_float32());
+        Self {
+            output: MutableCoordBuffer::with_capacity_and_dim(0, first.coord_type(), dim),
+            float32,
+        }
+    }
+
+    fn extend(&mut self, coords: &CoordBuffer, (start, end): (usize, usize)) {
+        self.output.reserve(end - start);
+        for i in start..end {
+            self.output.push_xyzm(
+                coords.get_x(i),
+                coords.get_y(i),
+                coords.get_z(i).unwrap_or(f64::NAN),
+                coords.get_m(i).unwrap_or(f64::NAN),
+            );
+        }
+    }
+
+    fn finish(self) -> CoordBuffer {
+        let coords: CoordBuffer = self.output.into();
+        if self.float32 {
+            CoordBuffer::Float32(Float32CoordBuffer::from_coords(&coords))
+        } else {
+            coords
+        }
+    }
+}
+
+/// Append the offsets of rows `start..end`, continuing from the last offset in `output`, and
+/// return the range of child items those rows cover.
+fn extend_offsets<O: OffsetSizeTrait>(
+    output: &mut OffsetsBuilder<O>,
+    offsets: &OffsetBuffer<O>,
+    (start, end): (usize, usize),
+) -> Result<(usize, usize)> {
+    let offsets = &offsets[start..=end];
+    output.try_extend_from_lengths(
+        offsets
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).to_usize().unwrap()),
+    )?;
+    Ok((
+        offsets[0].to_usize().unwrap(),
+        offsets[offsets.len() - 1].to_usize().unwrap(),
+    ))
+}
+
+/// The validity of the concatenated arrays, given the validity and length of each, or `None` if
+/// no row is null.
+fn concat_validity<'a>(
+    arrays: impl Iterator<Item = (Option<&'a NullBuffer>, usize)>,
+) -> Option<NullBuffer> {
+    let mut output = NullBufferBuilder::new(0);
+    for (validity, len) in arrays {
+        match validity {
+            Some(validity) => validity.iter().for_each(|is_valid| output.append(is_valid)),
+            None => output.append_n_non_nulls(len),
+        }
+    }
+    output.finish()
+}
+
+impl Concat for PointArray {
+    fn concat(arrays: &[&Self]) -> Result<Self> {
+        let metadata = first(arrays)?.metadata().clone();
+        let mut coords = CoordConcat::new(arrays.iter().map(|arr| &arr.coords));
+        for arr in arrays {
+            coords.extend(&arr.coords, (0, arr.len()));
+        }
+        let validity = concat_validity(arrays.iter().map(|arr| (arr.validity(), arr.len())));
+        Ok(PointArray::try_new(coords.finish(), validity)?.with_metadata(metadata))
+    }
+}
+
+/// Implementation for arrays whose geom offsets point directly into the coordinates
+macro_rules! one_level_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> Concat for $type {
+            fn concat(arrays: &[&Self]) -> Result<Self> {
+                let metadata = first(arrays)?.metadata().clone();
+                let mut coords = CoordConcat::new(arrays.iter().map(|arr| &arr.coords));
+                let mut geom_offsets = OffsetsBuilder::new();
+                for arr in arrays {
+                    let coord_range =
+                        extend_offsets(&mut geom_offsets, &arr.geom_offsets, (0, arr.len()))?;
+                    coords.extend(&arr.coords, coord_range);
+                }
+                let validity =
+                    concat_validity(arrays.iter().map(|arr| (arr.validity(), arr.len())));
+                Ok(
+                    Self::try_new(coords.finish(), geom_offsets.into(), validity)?
+                        .with_metadata(metadata),
+                )
+            }
+        }
+    };
+}
+
+one_level_impl!(LineStringArray<O>);
+one_level_impl!(MultiPointArray<O>);
+
+/// Implementation for arrays whose geom offsets point into the ring offsets
+macro_rules! two_level_impl {
+    ($type:ty, $child_offsets:ident) => {
+        impl<O: OffsetSizeTrait> Concat for $type {
+            fn concat(arrays: &[&Self]) -> Result<Self> {
+                let metadata = first(arrays)?.metadata().clone();
+                let mut coords = CoordConcat::new(arrays.iter().map(|arr| &arr.coords));
+                let mut geom_offsets = OffsetsBuilder::new();
+                let mut child_offsets = OffsetsBuilder::new();
+                for arr in arrays {
+                    let child_range =
+                        extend_offsets(&mut geom_offsets, &arr.geom_offsets, (0, arr.len()))?;
+                    let coord_range =
+                        extend_offsets(&mut child_offsets, &arr.$child_offsets, child_range)?;
+                    coords.extend(&arr.coords, coord_range);
+                }
+                let validity =
+                    concat_validity(arrays.iter().map(|arr| (arr.validity(), arr.len())));
+                Ok(Self::try_new(
+                    coords.finish(),
+                    geom_offsets.into(),
+                    child_offsets.into(),
+                    validity,
+                )?
+                .with_metadata(metadata))
+            }
+        }
+    };
+}
+
+two_level_impl!(PolygonArray<O>, ring_offsets);
+two_level_impl!(MultiLineStringArray<O>, ring_offsets);
+
+impl<O: OffsetSizeTrait> Concat for MultiPolygonArray<O> {
+    fn concat(arrays: &[&Self]) -> Result<Self> {
+        let metadata = first(arrays)?.metadata().clone();
+        let mut coords = CoordConcat::new(arrays.iter().map(|arr| &arr.coords));
+        let mut geom_offsets = OffsetsBuilder::new();
+        let mut polygon_offsets = OffsetsBuilder::new();
+        let mut ring_offsets = OffsetsBuilder::new();
+        for arr in arrays {
+            let polygon_range =
+                extend_offsets(&mut geom_offsets, &arr.geom_offsets, (0, arr.len()))?;
+            let ring_range =
+                extend_offsets(&mut polygon_offsets, &arr.polygon_offsets, polygon_range)?;
+            let coord_range = extend_offsets(&mut ring_offsets, &arr.ring_offsets, ring_range)?;
+            coords.extend(&arr.coords, coord_range);
+        }
+        let validity = concat_validity(arrays.iter().map(|arr| (arr.validity(), arr.len())));
+        Ok(MultiPolygonArray::try_new(
+            coords.finish(),
+            geom_offsets.into(),
+            polygon_offsets.into(),
+            ring_offsets.into(),
+            validity,
+        )?
+        .with_metadata(metadata))
+    }
+}
+
+impl Concat for RectArray {
+    fn concat(arrays: &[&Self]) -> Result<Self> {
+        first(arrays)?;
+        let mut values = Vec::with_capacity(arrays.iter().map(|arr| arr.len() * 4).sum());
+        for arr in arrays {
+            for i in 0..arr.len() {
+                let rect = arr.value_as_geo(i);
+                values.extend_from_slice(&[rect.min().x, rect.min().y, rect.max().x, rect.max().y]);
+            }
+        }
+        let validity = concat_validity(arrays.iter().map(|arr| (arr.validity(), arr.len())));
+        Ok(RectArray::new(ScalarBuffer::from(values), validity))
+    }
+}
+
+impl<O: OffsetSizeTrait> Concat for WKBArray<O> {
+    fn concat(arrays: &[&Self]) -> Result<Self> {
+        first(arrays)?;
+        let binary_arrays: Vec<ArrayRef> = arrays
+            .iter()
+            .map(|arr| (*arr).clone().into_array_ref())
+            .collect();
+        // Arrow's concat doesn't check that the values fit offsets of type O
+        let num_bytes: usize = binary_arrays
+            .iter()
+            .map(|arr| {
+                let offsets = arr.as_binary::<O>().value_offsets();
+                (offsets[offsets.len() - 1] - offsets[0])
+                    .to_usize()
+                    .unwrap()
+            })
+            .sum();
+        O::from_usize(num_bytes).ok_or(GeoArrowError::Overflow)?;
+
+        let binary_arrays: Vec<&dyn Array> = binary_arrays.iter().map(|arr| arr.as_ref()).collect();
+        let output = arrow::compute::concat(&binary_arrays)?;
+        Ok(WKBArray::new(output.as_binary::<O>().clone()))
+    }
+}
+
+// Mixed and geometry collection arrays are rebuilt from geo objects
+
+impl<O: OffsetSizeTrait> Concat for MixedGeometryArray<O> {
+    fn concat(arrays: &[&Self]) -> Result<Self> {
+        let coord_type = first(arrays)?.coord_type();
+        let geoms: Vec<geo::Geometry> = arrays
+            .iter()
+            .flat_map(|arr| (0..arr.len()).map(|i| arr.value_as_geo(i)))
+            .collect();
+        let output: Self = geoms.try_into()?;
+        Ok(output.into_coord_type(coord_type))
+    }
+}
+
+impl<O: OffsetSizeTrait> Concat for GeometryCollectionArray<O> {
+    fn concat(arrays: &[&Self]) -> Result<Self> {
+        let coord_type = first(arrays)?.coord_type();
+        let geoms: Vec<Option<geo::GeometryCollection>> = arrays
+            .iter()
+            .flat_map(|arr| (0..arr.len()).map(|i| arr.get_as_geo(i)))
+            .collect();
+        let output: Self = geoms.try_into()?;
+        Ok(output.into_coord_type(coord_type))
+    }
+}
+
+impl<O: OffsetSizeTrait> Concat for GeometryArray<O> {
+    fn concat(arrays: &[&Self]) -> Result<Self> {
+        let first = first(arrays)?;
+        macro_rules! concat_variant {
+            ($variant:ident $(, $unbox:ident)?) => {{
+                let chunks = arrays
+                    .iter()
+                    .map(|arr| match arr {
+                        GeometryArray::$variant(arr) => Ok(arr$(.$unbox())?),
+                        _ => Err(GeoArrowError::General(format!(
+                            "Cannot concatenate arrays of different geometry types, found {:?} \
+                             and {:?}; use concat_promoting instead",
+                            first.data_type(),
+                            arr.data_type()
+                        ))),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Concat::concat(&chunks)?
+            }};
+        }
+
+        Ok(match first {
+            GeometryArray::Point(_) => GeometryArray::Point(concat_variant!(Point)),
+            GeometryArray::LineString(_) => GeometryArray::LineString(concat_variant!(LineString)),
+            GeometryArray::Polygon(_) => GeometryArray::Polygon(concat_variant!(Polygon)),
+            GeometryArray::MultiPoint(_) => GeometryArray::MultiPoint(concat_variant!(MultiPoint)),
+            GeometryArray::MultiLineString(_) => {
+                GeometryArray::MultiLineString(concat_variant!(MultiLineString))
+            }
+            GeometryArray::MultiPolygon(_) => {
+                GeometryArray::MultiPolygon(concat_variant!(MultiPolygon))
+            }
+            GeometryArray::GeometryCollection(_) => GeometryArray::GeometryCollection(Box::new(
+                concat_variant!(GeometryCollection, as_ref),
+            )),
+            GeometryArray::Rect(_) => GeometryArray::Rect(concat_variant!(Rect)),
+        })
+    }
+}
+
+/// Call `f` with the index of the array and the geometry for every row of every array.
+fn for_each_geometry<O: OffsetSizeTrait>(
+    arrays: &[GeometryArray<O>],
+    mut f: impl FnMut(usize, Option<geo::Geometry>) -> Result<()>,
+) -> Result<()> {
+    for (chunk_idx, arr) in arrays.iter().enumerate() {
+        for i in 0..arr.len() {
+            f(chunk_idx, arr.get_as_geo(i))?;
+        }
+    }
+    Ok(())
+}
+
+// The conversions below are only called on geometries whose type was checked with
+// `ChunkType::can_hold`.
+
+fn into_point(geom: geo::Geometry) -> geo::Point {
+    match geom {
+        geo::Geometry::Point(g) => g,
+        _ => unreachable!(),
+    }
+}
+
+fn into_line_string(geom: geo::Geometry) -> geo::LineString {
+    match geom {
+        geo::Geometry::LineString(g) => g,
+        geo::Geometry::Line(g) => g.into(),
+        _ => unreachable!(),
+    }
+}
+
+fn into_polygon(geom: geo::Geometry) -> geo::Polygon {
+    match geom {
+        geo::Geometry::Polygon(g) => g,
+        geo::Geometry::Rect(g) => g.to_polygon(),
+        geo::Geometry::Triangle(g) => g.to_polygon(),
+        _ => unreachable!(),
+    }
+}
+
+fn into_multi_point(geom: geo::Geometry) -> geo::MultiPoint {
+    match geom {
+        geo::Geometry::MultiPoint(g) => g,
+        _ => unreachable!(),
+    }
+}
+
+fn into_multi_line_string(geom: geo::Geometry) -> geo::MultiLineString {
+    match geom {
+        geo::Geometry::MultiLineString(g) => g,
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::multipoint::{mp0, mp1};
+    use crate::test::multipolygon::mp0 as mpoly0;
+    use crate::test::point::{p0, p1};
+    use crate::test::polygon::{p0 as poly0, p1 as poly1};
+
+    #[test]
+    fn polygon_and_multi_polygon() {
+        let polygons: PolygonArray<i32> = vec![Some(poly0()), None, Some(poly1())].into();
+        let multi_polygons: MultiPolygonArray<i32> = vec![Some(mpoly0()), None].into();
+        let arrays = [
+            GeometryArray::Polygon(polygons),
+            GeometryArray::MultiPolygon(multi_polygons),
+        ];
+
+        let result = concat_promoting(&arrays, PromotionPolicy::default()).unwrap();
+        let PromotedGeometryArray::Geometry(GeometryArray::MultiPolygon(arr)) = result else {
+            panic!("expected a multi polygon array")
+        };
+        assert_eq!(arr.len(), 5);
+        assert_eq!(arr.value_as_geo(0), geo::MultiPolygon::new(vec![poly0()]));
+        assert!(arr.is_null(1));
+        assert_eq!(arr.value_as_geo(2), geo::MultiPolygon::new(vec![poly1()]));
+        assert_eq!(arr.value_as_geo(3), mpoly0());
+        assert!(arr.is_null(4));
+
+        // The order of the chunks doesn't change the output type
+        let arrays = [arrays[1].clone(), arrays[0].clone()];
+        let result = concat_promoting(&arrays, PromotionPolicy::default()).unwrap();
+        assert!(matches!(
+            result,
+            PromotedGeometryArray::Geometry(GeometryArray::MultiPolygon(_))
+        ));
+    }
+
+    #[test]
+    fn point_and_multi_point() {
+        let points: PointArray = vec![Some(p0()), None].into();
+        let multi_points: MultiPointArray<i32> = vec![Some(mp0()), Some(mp1())].into();
+        let arrays = [
+            GeometryArray::Point(points),
+            GeometryArray::MultiPoint(multi_points),
+        ];
+
+        let result = concat_promoting(&arrays, PromotionPolicy::default()).unwrap();
+        let PromotedGeometryArray::Geometry(GeometryArray::MultiPoint(arr)) = result else {
+            panic!("expected a multi point array")
+        };
+        assert_eq!(arr.len(), 4);
+        assert_eq!(arr.value_as_geo(0), geo::MultiPoint::new(vec![p0()]));
+        assert!(arr.is_null(1));
+        assert_eq!(arr.value_as_geo(2), mp0());
+        assert_eq!(arr.value_as_geo(3), mp1());
+    }
+
+    #[test]
+    fn point_and_polygon() {
+        let points: PointArray = vec![Some(p0()), None, Some(p1())].into();
+        let polygons: PolygonArray<i32> = vec![None, Some(poly0())].into();
+        let arrays = [
+            GeometryArray::Point(points),
+            GeometryArray::Polygon(polygons),
+        ];
+
+        let result = concat_promoting(&arrays, PromotionPolicy::Mixed).unwrap();
+        let PromotedGeometryArray::Mixed(arr) = result else {
+            panic!("expected a mixed array")
+        };
+        assert_eq!(arr.len(), 5);
+        assert_eq!(arr.value_as_geo(0), geo::Geometry::Point(p0()));
+        assert_eq!(arr.value_as_geo(2), geo::Geometry::Point(p1()));
+        assert_eq!(arr.value_as_geo(4), geo::Geometry::Polygon(poly0()));
+        let nulls = arr.logical_nulls().unwrap();
+        let expected_nulls = [false, true, false, true, false];
+        for (i, expected) in expected_nulls.into_iter().enumerate() {
+            assert_eq!(nulls.is_null(i), expected);
+        }
+
+        let result = concat_promoting(&arrays, PromotionPolicy::WKB).unwrap();
+        let PromotedGeometryArray::WKB(arr) = result else {
+            panic!("expected a WKB array")
+        };
+        assert_eq!(arr.len(), 5);
+        assert_eq!(arr.null_count(), 2);
+        assert_eq!(arr.value_as_geo(4), geo::Geometry::Polygon(poly0()));
+    }
+
+    #[test]
+    fn promoting_keeps_coord_type() {
+        let points: PointArray = vec![p0(), p1()].into();
+        let multi_points: MultiPointArray<i32> = vec![mp0()].into();
+        let arrays = [
+            GeometryArray::Point(points.into_coord_type(CoordType::Separated)),
+            GeometryArray::MultiPoint(multi_points),
+        ];
+        let PromotedGeometryArray::Geometry(arr) =
+            concat_promoting(&arrays, PromotionPolicy::default()).unwrap()
+        else {
+            panic!("expected a geometry array")
+        };
+        assert!(matches!(arr, GeometryArray::MultiPoint(_)));
+        assert_eq!(arr.coord_type(), CoordType::Separated);
+
+        // Arrays of one type are concatenated by buffer, with the layout of the first array
+        let arrays = [arrays[1].clone(), arrays[1].clone()];
+        let PromotedGeometryArray::Geometry(arr) =
+            concat_promoting(&arrays, PromotionPolicy::default()).unwrap()
+        else {
+            panic!("expected a geometry array")
+        };
+        assert_eq!(arr.len(), 2);
+        assert_eq!(arr.coord_type(), CoordType::Interleaved);
+    }
+
+    #[test]
+    fn concat_mixed_point_and_polygon() {
+        let points: PointArray = vec![Some(p0()), None, Some(p1())].into();
+        let polygons: PolygonArray<i32> = vec![Some(poly0()), Some(poly1())].into();
+        let arrays = [
+            GeometryArray::Point(points),
+            GeometryArray::Polygon(polygons),
+        ];
+
+        let arr = concat_mixed(&arrays).unwrap();
+        assert_eq!(arr.len(), 5);
+        assert_eq!(arr.value_as_geo(0), geo::Geometry::Point(p0()));
+        assert!(arr.logical_nulls().unwrap().is_null(1));
+        assert_eq!(arr.value_as_geo(2), geo::Geometry::Point(p1()));
+        assert_eq!(arr.value_as_geo(3), geo::Geometry::Polygon(poly0()));
+        assert_eq!(arr.value_as_geo(4), geo::Geometry::Polygon(poly1()));
+
+        // Arrays of a single type are mixed too
+        let arr = concat_mixed(&arrays[..1]).unwrap();
+        assert_eq!(arr.len(), 3);
+
+        let collections = GeometryArray::GeometryCollection(Box::new(
+            crate::test::geometrycollection::gc_array(),
+        ));
+        assert!(concat_mixed(&[arrays[0].clone(), collections]).is_err());
+    }
+
+    #[test]
+    fn empty_input() {
+        let arrays: [GeometryArray<i32>; 0] = [];
+        assert!(concat_promoting(&arrays, PromotionPolicy::default()).is_err());
+        assert_eq!(concat_mixed(&arrays).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn concat_sliced_polygons() {
+        let polygons: PolygonArray<i32> = vec![Some(poly0()), None, Some(poly1())].into();
+        let sliced = polygons.slice(1, 2);
+        let separated = polygons.clone().into_coord_type(CoordType::Separated);
+
+        let result = PolygonArray::concat(&[&sliced, &separated]).unwrap();
+        assert_eq!(result.len(), 5);
+        assert_eq!(result.coord_type(), CoordType::Interleaved);
+        let expected = vec![None, Some(poly1()), Some(poly0()), None, Some(poly1())];
+        let actual: Vec<_> = (0..result.len()).map(|i| result.get_as_geo(i)).collect();
+        assert_eq!(actual, expected);
+        // Only the coordinates of the sliced rows are copied
+        assert_eq!(
+            result.coords.len(),
+            polygons.coords.len() + sliced.ring_offsets[3] as usize
+                - sliced.ring_offsets[1] as usize
+        );
+    }
+
+    #[test]
+    fn concat_points_without_nulls() {
+        let points: PointArray = vec![p0(), p1()].into();
+        let result = PointArray::concat(&[&points, &points.slice(1, 1)]).unwrap();
+        assert_eq!(result.len(), 3);
+        assert!(result.validity().is_none());
+        assert_eq!(result.value_as_geo(2), p1());
+
+        let float32 = points.clone().into_coord_float32();
+        assert!(PointArray::concat(&[&float32, &float32])
+            .unwrap()
+            .coords
+            .is_float32());
+        assert!(!PointArray::concat(&[&float32, &points])
+            .unwrap()
+            .coords
+            .is_float32());
+    }
+
+    #[test]
+    fn concat_geometry_arrays() {
+        let multi_points: MultiPointArray<i32> = vec![Some(mp0()), None].into();
+        let wkb: WKBArray<i32> = vec![Some(geo::Geometry::MultiPoint(mp1())), None].into();
+        let arrays = [
+            GeometryArray::MultiPoint(multi_points.clone()),
+            GeometryArray::MultiPoint(multi_points),
+        ];
+        let result = GeometryArray::concat(&[&arrays[0], &arrays[1]]).unwrap();
+        assert_eq!(result.len(), 4);
+        assert!(result.is_null(3));
+
+        let points = GeometryArray::Point(vec![p0()].into());
+        assert!(GeometryArray::concat(&[&arrays[0], &points]).is_err());
+        assert!(GeometryArray::<i32>::concat(&[]).is_err());
+
+        let result = WKBArray::concat(&[&wkb, &wkb.slice(0, 1)]).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.get_as_geo(2), Some(geo::Geometry::MultiPoint(mp1())));
+        assert!(result.is_null(1));
+    }
+}
@@ -0,0 +1,224 @@
+use arrow_array::builder::Float64Builder;
+use arrow_array::Float64Array;
+
+use crate::algorithm::broadcasting::BroadcastablePoint;
+use crate::array::PointArray;
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+
+/// The mean earth radius in meters, the same one used by geo's haversine algorithms.
+const MEAN_EARTH_RADIUS: f64 = 6371008.8;
+
+/// The distance in meters of each point from the great circle through its segment.
+///
+/// Points are longitude/latitude in degrees. Each point is measured against the segment from
+/// `seg_start` to `seg_end` in the same row, and either end may be a scalar that is used for
+/// every row. The distance is positive when the point is right of the path, looking from the start
+/// towards the end, and negative when it is left of it.
+///
+/// The result is null where the point or either end of the segment is null.
+///
+/// This uses the spherical formulas from [Movable Type], on a sphere with the same radius as
+/// [`HaversineLength`](crate::algorithm::geo::HaversineLength).
+///
+/// ```
+/// use geo::point;
+/// use geoarrow2::algorithm::broadcasting::BroadcastablePoint;
+/// use geoarrow2::algorithm::native::cross_track_distance;
+/// use geoarrow2::array::PointArray;
+///
+/// // A path along the equator, and a point one degree north of it
+/// let start: PointArray = vec![point!(x: 0., y: 0.)].into();
+/// let end: PointArray = vec![point!(x: 10., y: 0.)].into();
+/// let points: PointArray = vec![point!(x: 5., y: 1.)].into();
+///
+/// let distance = cross_track_distance(
+///     &points,
+///     &BroadcastablePoint::Array(start),
+///     &BroadcastablePoint::Array(end),
+/// )
+/// .unwrap();
+/// assert_eq!(distance.value(0).round(), -111_195.);
+/// ```
+///
+/// # Errors
+///
+/// - if `seg_start` or `seg_end` is an array of a different length than `points`
+///
+/// [Movable Type]: https://www.movable-type.co.uk/scripts/latlong.html#cross-track
+pub fn cross_track_distance(
+    points: &PointArray,
+    seg_start: &BroadcastablePoint,
+    seg_end: &BroadcastablePoint,
+) -> Result<Float64Array> {
+    track_kernel(points, seg_start, seg_end, |geometry| {
+        geometry.cross_track() * MEAN_EARTH_RADIUS
+    })
+}
+
+/// The distance in meters from the start of each segment to the point on its great circle
+/// closest to the point in the same row.
+///
+/// Points, broadcasting and nulls are handled like in [`cross_track_distance`]. The distance is
+/// negative when the closest point lies behind the start, looking towards the end.
+///
+/// # Errors
+///
+/// - if `seg_start` or `seg_end` is an array of a different length than `points`
+pub fn along_track_distance(
+    points: &PointArray,
+    seg_start: &BroadcastablePoint,
+    seg_end: &BroadcastablePoint,
+) -> Result<Float64Array> {
+    track_kernel(points, seg_start, seg_end, |geometry| {
+        let cross_track = geometry.cross_track();
+        // Rounding can push the ratio just past 1 for points on the great circle
+        let ratio = (geometry.distance.cos() / cross_track.cos().abs()).clamp(-1., 1.);
+        let sign = (geometry.path_bearing - geometry.point_bearing)
+            .cos()
+            .signum();
+        ratio.acos() * sign * MEAN_EARTH_RADIUS
+    })
+}
+
+/// The spherical quantities of a point relative to a segment, in radians.
+struct TrackGeometry {
+    /// The angular distance from the start of the segment to the point
+    distance: f64,
+    /// The initial bearing from the start of the segment to the point
+    point_bearing: f64,
+    /// The initial bearing from the start of the segment to its end
+    path_bearing: f64,
+}
+
+impl TrackGeometry {
+    fn new(point: (f64, f64), start: (f64, f64), end: (f64, f64)) -> Self {
+        let (lon1, lat1) = (start.0.to_radians(), start.1.to_radians());
+        let (lon2, lat2) = (end.0.to_radians(), end.1.to_radians());
+        let (lon3, lat3) = (point.0.to_radians(), point.1.to_radians());
+
+        let half_chord = ((lat3 - lat1) / 2.).sin().powi(2)
+            + lat1.cos() * lat3.cos() * ((lon3 - lon1) / 2.).sin().powi(2);
+        Self {
+            distance: 2. * half_chord.sqrt().asin(),
+            point_bearing: initial_bearing(lon1, lat1, lon3, lat3),
+            path_bearing: initial_bearing(lon1, lat1, lon2, lat2),
+        }
+    }
+
+    /// The signed angular distance from the point to the great circle
+    fn cross_track(&self) -> f64 {
+        (self.distance.sin() * (self.point_bearing - self.path_bearing).sin()).asin()
+    }
+}
+
+fn initial_bearing(lon1: f64, lat1: f64, lon2: f64, lat2: f64) -> f64 {
+    let delta_lon = lon2 - lon1;
+    let y = delta_lon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lon.cos();
+    y.atan2(x)
+}
+
+fn check_length(segment_end: &BroadcastablePoint, len: usize) -> Result<()> {
+    match segment_end {
+        BroadcastablePoint::Array(arr) if arr.len() != len => Err(GeoArrowError::General(
+            "segment ends must have the same length as points".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn track_kernel(
+    points: &PointArray,
+    seg_start: &BroadcastablePoint,
+    seg_end: &BroadcastablePoint,
+    op: impl Fn(TrackGeometry) -> f64,
+) -> Result<Float64Array> {
+    check_length(seg_start, points.len())?;
+    check_length(seg_end, points.len())?;
+
+    let mut output_array = Float64Builder::with_capacity(points.len());
+    points
+        .iter()
+        .zip(seg_start)
+        .zip(seg_end)
+        .for_each(|((point, start), end)| match (point, start, end) {
+            (Some(point), Some(start), Some(end)) => {
+                let geometry = TrackGeometry::new(
+                    (point.x(), point.y()),
+                    (start.x(), start.y()),
+                    (end.x(), end.y()),
+                );
+                output_array.append_value(op(geometry));
+            }
+            _ => output_array.append_null(),
+        });
+
+    Ok(output_array.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeoArrayAccessor;
+    use approx::assert_abs_diff_eq;
+    use arrow_array::Array;
+    use geo::point;
+
+    /// The path and points around the cross-track example on the Movable Type page, which gives
+    /// -307.5 m and 62.331 km for its first point with a radius of 6371 km.
+    fn movable_type_example() -> (PointArray, PointArray) {
+        let points: PointArray = vec![
+            Some(point!(x: -0.7972, y: 53.2611)),
+            Some(point!(x: -3., y: 53.2)),
+            None,
+        ]
+        .into();
+        let path: PointArray = vec![
+            point!(x: -1.7297, y: 53.3206),
+            point!(x: 0.1334, y: 53.1887),
+        ]
+        .into();
+        (points, path)
+    }
+
+    #[test]
+    fn reference_values() {
+        let (points, path) = movable_type_example();
+        let start = BroadcastablePoint::Scalar(path.value(0));
+        let end = BroadcastablePoint::Scalar(path.value(1));
+
+        let cross_track = cross_track_distance(&points, &start, &end).unwrap();
+        assert_abs_diff_eq!(cross_track.value(0), -307.549995, epsilon = 0.01);
+        assert_abs_diff_eq!(cross_track.value(1), 21434.823035, epsilon = 0.01);
+        assert!(cross_track.is_null(2));
+
+        let along_track = along_track_distance(&points, &start, &end).unwrap();
+        assert_abs_diff_eq!(along_track.value(0), 62331.579381, epsilon = 0.01);
+        // The second point lies behind the start of the path
+        assert_abs_diff_eq!(along_track.value(1), -82821.379320, epsilon = 0.01);
+        assert!(along_track.is_null(2));
+    }
+
+    #[test]
+    fn segments_per_row() {
+        let points: PointArray = vec![point!(x: 5., y: 1.), point!(x: 0., y: 2.)].into();
+        let start: PointArray = vec![Some(point!(x: 0., y: 0.)), None].into();
+        let end: PointArray = vec![point!(x: 10., y: 0.), point!(x: 0., y: 10.)].into();
+        let start = BroadcastablePoint::Array(start);
+        let end = BroadcastablePoint::Array(end);
+
+        let one_degree = MEAN_EARTH_RADIUS.to_radians();
+        let cross_track = cross_track_distance(&points, &start, &end).unwrap();
+        assert_abs_diff_eq!(cross_track.value(0), -one_degree, epsilon = 0.01);
+        assert!(cross_track.is_null(1));
+
+        let along_track = along_track_distance(&points, &start, &end).unwrap();
+        assert_abs_diff_eq!(along_track.value(0), 5. * one_degree, epsilon = 0.01);
+        assert!(along_track.is_null(1));
+
+        let short: PointArray = vec![point!(x: 0., y: 0.)].into();
+        let short = BroadcastablePoint::Array(short);
+        assert!(cross_track_distance(&points, &short, &end).is_err());
+    }
+}
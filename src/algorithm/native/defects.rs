@@ -0,0 +1,426 @@
+use arrow_array::builder::{BooleanBuilder, ListBuilder, UInt32Builder};
+use arrow_array::{BooleanArray, ListArray, OffsetSizeTrait};
+use geo::line_intersection::{line_intersection, LineIntersection};
+use geo::{Coord, CoordsIter, Geometry, Line};
+
+use crate::array::*;
+use crate::GeometryArrayTrait;
+
+/// Locate defects in geometries without changing them, e.g. to highlight them for review.
+///
+/// Vertex locations are indices into the coordinates of each geometry, in the order they are
+/// stored: the rings of a polygon follow each other, exterior first, and the parts of a multi
+/// geometry follow each other. Closing coordinates of rings are counted, so the indices match
+/// [`geo::CoordsIter::coords_iter`].
+///
+/// Null geometries are null in the output. Points, multipoints and rectangles never have
+/// defects.
+///
+/// See [`Clean`](crate::algorithm::native::Clean) to repair some of these defects.
+pub trait FindDefects {
+    /// The indices of vertices that are within `tolerance` of the vertex before them in the same
+    /// line string or ring. Use a tolerance of zero to find exact duplicates.
+    fn find_repeated_vertices(&self, tolerance: f64) -> ListArray;
+
+    /// The indices of vertices where the line string or ring turns back on itself, with an angle
+    /// between the incoming and outgoing segments of less than `max_angle_deg` degrees.
+    ///
+    /// The end points of a line string have no angle. The vertex where a ring closes is checked
+    /// and reported at its first occurrence. Vertices with a repeated neighbor are skipped.
+    fn find_spikes(&self, max_angle_deg: f64) -> ListArray;
+
+    /// Whether any two segments of each geometry intersect, other than consecutive segments
+    /// meeting at their shared vertex.
+    ///
+    /// Segments of different rings or parts of the same geometry count as intersecting if they
+    /// touch at all. This uses a sweep over the segments sorted by x, not GEOS.
+    fn find_self_intersections(&self) -> BooleanArray;
+}
+
+/// A line string or ring of a geometry
+struct Path<'a> {
+    coords: &'a [Coord],
+
+    /// The index of the first coordinate of this path within its geometry
+    start: usize,
+
+    closed: bool,
+}
+
+/// Collect the line strings and rings of `geom`, counting the coordinates of all other parts.
+fn collect_paths<'a>(geom: &'a Geometry, offset: &mut usize, paths: &mut Vec<Path<'a>>) {
+    let mut push = |coords: &'a [Coord], closed: bool, offset: &mut usize| {
+        paths.push(Path {
+            coords,
+            start: *offset,
+            closed,
+        });
+        *offset += coords.len();
+    };
+
+    match geom {
+        Geometry::LineString(line_string) => push(&line_string.0, false, offset),
+        Geometry::MultiLineString(multi_line_string) => multi_line_string
+            .iter()
+            .for_each(|line_string| push(&line_string.0, false, offset)),
+        Geometry::Polygon(polygon) => {
+            push(&polygon.exterior().0, true, offset);
+            polygon
+                .interiors()
+                .iter()
+                .for_each(|ring| push(&ring.0, true, offset));
+        }
+        Geometry::MultiPolygon(multi_polygon) => multi_polygon.iter().for_each(|polygon| {
+            push(&polygon.exterior().0, true, offset);
+            polygon
+                .interiors()
+                .iter()
+                .for_each(|ring| push(&ring.0, true, offset));
+        }),
+        Geometry::GeometryCollection(collection) => collection
+            .iter()
+            .for_each(|member| collect_paths(member, offset, paths)),
+        Geometry::Point(_)
+        | Geometry::Line(_)
+        | Geometry::MultiPoint(_)
+        | Geometry::Rect(_)
+        | Geometry::Triangle(_) => *offset += geom.coords_count(),
+    }
+}
+
+fn paths(geom: &Geometry) -> Vec<Path> {
+    let mut paths = vec![];
+    collect_paths(geom, &mut 0, &mut paths);
+    paths
+}
+
+fn distance(a: Coord, b: Coord) -> f64 {
+    (b.x - a.x).hypot(b.y - a.y)
+}
+
+fn repeated_vertices(geom: &Geometry, tolerance: f64) -> Vec<u32> {
+    paths(geom)
+        .iter()
+        .flat_map(|path| {
+            (1..path.coords.len())
+                .filter(|i| distance(path.coords[i - 1], path.coords[*i]) <= tolerance)
+                .map(|i| (path.start + i) as u32)
+        })
+        .collect()
+}
+
+/// The angle at `vertex` in degrees, or `None` if it coincides with a neighbor
+fn angle(prev: Coord, vertex: Coord, next: Coord) -> Option<f64> {
+    let (a, b) = (prev - vertex, next - vertex);
+    let lengths = distance(prev, vertex) * distance(next, vertex);
+    if lengths == 0. {
+        return None;
+    }
+    let cos = (a.x * b.x + a.y * b.y) / lengths;
+    Some(cos.clamp(-1., 1.).acos().to_degrees())
+}
+
+fn spikes(geom: &Geometry, max_angle_deg: f64) -> Vec<u32> {
+    let mut output = vec![];
+    for path in paths(geom) {
+        let coords = path.coords;
+        let is_ring = path.closed && coords.len() >= 4 && coords.first() == coords.last();
+        if is_ring {
+            // Skip the closing coordinate, and wrap around it instead
+            let n = coords.len() - 1;
+            for i in 0..n {
+                let (prev, next) = (coords[(i + n - 1) % n], coords[(i + 1) % n]);
+                if angle(prev, coords[i], next).is_some_and(|a| a < max_angle_deg) {
+                    output.push((path.start + i) as u32);
+                }
+            }
+        } else {
+            for i in 1..coords.len().saturating_sub(1) {
+                if angle(coords[i - 1], coords[i], coords[i + 1]).is_some_and(|a| a < max_angle_deg)
+                {
+                    output.push((path.start + i) as u32);
+                }
+            }
+        }
+    }
+    output
+}
+
+/// A non-degenerate segment of a path
+struct Segment {
+    line: Line,
+    path_idx: usize,
+
+    /// The index of this segment among the non-degenerate segments of its path
+    idx: usize,
+
+    /// The number of non-degenerate segments in its path
+    path_len: usize,
+
+    closed: bool,
+}
+
+impl Segment {
+    fn min_x(&self) -> f64 {
+        self.line.start.x.min(self.line.end.x)
+    }
+
+    fn max_x(&self) -> f64 {
+        self.line.start.x.max(self.line.end.x)
+    }
+
+    /// Whether the segments follow each other in the same path
+    fn is_adjacent(&self, other: &Segment) -> bool {
+        if self.path_idx != other.path_idx {
+            return false;
+        }
+        let (low, high) = (self.idx.min(other.idx), self.idx.max(other.idx));
+        high - low == 1 || (self.closed && low == 0 && high == self.path_len - 1)
+    }
+
+    fn intersects(&self, other: &Segment) -> bool {
+        let y_overlap = self.line.start.y.min(self.line.end.y)
+            <= other.line.start.y.max(other.line.end.y)
+            && other.line.start.y.min(other.line.end.y) <= self.line.start.y.max(self.line.end.y);
+        if !y_overlap {
+            return false;
+        }
+
+        match line_intersection(self.line, other.line) {
+            None => false,
+            // Consecutive segments always share a vertex, so only an overlap counts
+            Some(LineIntersection::SinglePoint { .. }) => !self.is_adjacent(other),
+            Some(LineIntersection::Collinear { intersection }) => {
+                !self.is_adjacent(other) || intersection.start != intersection.end
+            }
+        }
+    }
+}
+
+fn has_self_intersection(geom: &Geometry) -> bool {
+    let mut segments = vec![];
+    for (path_idx, path) in paths(geom).iter().enumerate() {
+        let lines: Vec<Line> = path
+            .coords
+            .windows(2)
+            .filter(|pair| pair[0] != pair[1])
+            .map(|pair| Line::new(pair[0], pair[1]))
+            .collect();
+        let path_len = lines.len();
+        segments.extend(lines.into_iter().enumerate().map(|(idx, line)| Segment {
+            line,
+            path_idx,
+            idx,
+            path_len,
+            closed: path.closed,
+        }));
+    }
+    segments.sort_by(|a, b| a.min_x().total_cmp(&b.min_x()));
+
+    // The segments whose x range may still overlap the next segment's
+    let mut active: Vec<&Segment> = vec![];
+    for segment in &segments {
+        active.retain(|other| other.max_x() >= segment.min_x());
+        if active.iter().any(|other| segment.intersects(other)) {
+            return true;
+        }
+        active.push(segment);
+    }
+    false
+}
+
+fn build_list(values: impl Iterator<Item = Option<Vec<u32>>>) -> ListArray {
+    let mut builder = ListBuilder::new(UInt32Builder::new());
+    for maybe_indices in values {
+        match maybe_indices {
+            Some(indices) => {
+                builder.values().append_slice(&indices);
+                builder.append(true);
+            }
+            None => builder.append(false),
+        }
+    }
+    builder.finish()
+}
+
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl {
+    ($type:ty, $into_geometry:expr $(, $generic:ident)?) => {
+        impl$(<$generic: OffsetSizeTrait>)? FindDefects for $type {
+            fn find_repeated_vertices(&self, tolerance: f64) -> ListArray {
+                build_list(self.iter_geo().map(|maybe_g| {
+                    maybe_g.map(|geom| repeated_vertices(&$into_geometry(geom), tolerance))
+                }))
+            }
+
+            fn find_spikes(&self, max_angle_deg: f64) -> ListArray {
+                build_list(
+                    self.iter_geo()
+                        .map(|maybe_g| maybe_g.map(|geom| spikes(&$into_geometry(geom), max_angle_deg))),
+                )
+            }
+
+            fn find_self_intersections(&self) -> BooleanArray {
+                let mut output_array = BooleanBuilder::with_capacity(self.len());
+                self.iter_geo().for_each(|maybe_g| {
+                    output_array.append_option(
+                        maybe_g.map(|geom| has_self_intersection(&$into_geometry(geom))),
+                    )
+                });
+                output_array.finish()
+            }
+        }
+    };
+}
+
+iter_geo_impl!(PointArray, Geometry::from);
+iter_geo_impl!(LineStringArray<O>, Geometry::from, O);
+iter_geo_impl!(PolygonArray<O>, Geometry::from, O);
+iter_geo_impl!(MultiPointArray<O>, Geometry::from, O);
+iter_geo_impl!(MultiLineStringArray<O>, Geometry::from, O);
+iter_geo_impl!(MultiPolygonArray<O>, Geometry::from, O);
+iter_geo_impl!(MixedGeometryArray<O>, Geometry::from, O);
+iter_geo_impl!(GeometryCollectionArray<O>, Geometry::GeometryCollection, O);
+iter_geo_impl!(RectArray, Geometry::from);
+iter_geo_impl!(WKBArray<O>, Geometry::from, O);
+
+impl<O: OffsetSizeTrait> FindDefects for GeometryArray<O> {
+    fn find_repeated_vertices(&self, tolerance: f64) -> ListArray {
+        match self {
+            GeometryArray::Point(arr) => arr.find_repeated_vertices(tolerance),
+            GeometryArray::LineString(arr) => arr.find_repeated_vertices(tolerance),
+            GeometryArray::Polygon(arr) => arr.find_repeated_vertices(tolerance),
+            GeometryArray::MultiPoint(arr) => arr.find_repeated_vertices(tolerance),
+            GeometryArray::MultiLineString(arr) => arr.find_repeated_vertices(tolerance),
+            GeometryArray::MultiPolygon(arr) => arr.find_repeated_vertices(tolerance),
+            GeometryArray::GeometryCollection(arr) => arr.find_repeated_vertices(tolerance),
+            GeometryArray::Rect(arr) => arr.find_repeated_vertices(tolerance),
+        }
+    }
+
+    fn find_spikes(&self, max_angle_deg: f64) -> ListArray {
+        match self {
+            GeometryArray::Point(arr) => arr.find_spikes(max_angle_deg),
+            GeometryArray::LineString(arr) => arr.find_spikes(max_angle_deg),
+            GeometryArray::Polygon(arr) => arr.find_spikes(max_angle_deg),
+            GeometryArray::MultiPoint(arr) => arr.find_spikes(max_angle_deg),
+            GeometryArray::MultiLineString(arr) => arr.find_spikes(max_angle_deg),
+            GeometryArray::MultiPolygon(arr) => arr.find_spikes(max_angle_deg),
+            GeometryArray::GeometryCollection(arr) => arr.find_spikes(max_angle_deg),
+            GeometryArray::Rect(arr) => arr.find_spikes(max_angle_deg),
+        }
+    }
+
+    fn find_self_intersections(&self) -> BooleanArray {
+        match self {
+            GeometryArray::Point(arr) => arr.find_self_intersections(),
+            GeometryArray::LineString(arr) => arr.find_self_intersections(),
+            GeometryArray::Polygon(arr) => arr.find_self_intersections(),
+            GeometryArray::MultiPoint(arr) => arr.find_self_intersections(),
+            GeometryArray::MultiLineString(arr) => arr.find_self_intersections(),
+            GeometryArray::MultiPolygon(arr) => arr.find_self_intersections(),
+            GeometryArray::GeometryCollection(arr) => arr.find_self_intersections(),
+            GeometryArray::Rect(arr) => arr.find_self_intersections(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt32Type;
+    use arrow_array::Array;
+    use geo::{line_string, polygon, MultiLineString};
+
+    fn indices(array: &ListArray, i: usize) -> Vec<u32> {
+        array
+            .value(i)
+            .as_primitive::<UInt32Type>()
+            .values()
+            .to_vec()
+    }
+
+    #[test]
+    fn repeated_vertices() {
+        let array: LineStringArray<i32> = vec![
+            Some(line_string![(x: 0., y: 0.), (x: 0., y: 0.), (x: 1., y: 0.), (x: 1.05, y: 0.)]),
+            None,
+        ]
+        .into();
+
+        let result = array.find_repeated_vertices(0.);
+        assert_eq!(indices(&result, 0), vec![1]);
+        assert!(result.is_null(1));
+
+        let result = array.find_repeated_vertices(0.1);
+        assert_eq!(indices(&result, 0), vec![1, 3]);
+
+        // Indices continue across the rings of a polygon
+        let polygon = geo::Polygon::new(
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 0.)],
+            vec![line_string![
+                (x: 1., y: 1.),
+                (x: 2., y: 1.),
+                (x: 2., y: 1.),
+                (x: 2., y: 2.),
+                (x: 1., y: 1.)
+            ]],
+        );
+        let array: PolygonArray<i32> = vec![polygon].into();
+        assert_eq!(indices(&array.find_repeated_vertices(0.), 0), vec![6]);
+    }
+
+    #[test]
+    fn spikes() {
+        // Goes out to (10, 0.1) and almost straight back
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.1),
+            (x: 1., y: 0.),
+            (x: 1., y: 5.),
+        ];
+        let array: LineStringArray<i32> = vec![line_string].into();
+        assert_eq!(indices(&array.find_spikes(5.), 0), vec![1]);
+        assert!(indices(&array.find_spikes(0.01), 0).is_empty());
+
+        // The spike is at the vertex where the ring closes
+        let polygon = polygon![
+            (x: 10., y: 0.1),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        let array: GeometryArray<i32> = GeometryArray::Polygon(vec![polygon].into());
+        assert_eq!(indices(&array.find_spikes(10.), 0), vec![0]);
+    }
+
+    #[test]
+    fn self_intersections() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let bowtie = polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 1., y: 0.), (x: 0., y: 1.)];
+        let array: PolygonArray<i32> = vec![Some(square), Some(bowtie), None].into();
+
+        let result = array.find_self_intersections();
+        assert!(!result.value(0));
+        assert!(result.value(1));
+        assert!(result.is_null(2));
+
+        // Doubling back along a segment overlaps the previous one
+        let array: LineStringArray<i32> = vec![
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.), (x: 2., y: 1.)],
+        ]
+        .into();
+        let result = array.find_self_intersections();
+        assert!(result.value(0));
+        // A repeated vertex is not an intersection
+        assert!(!result.value(1));
+
+        let crossing = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 0., y: 1.), (x: 1., y: 0.)],
+        ]);
+        let array: MultiLineStringArray<i32> = vec![crossing].into();
+        assert!(array.find_self_intersections().value(0));
+    }
+}
@@ -0,0 +1,75 @@
+use crate::array::PointArray;
+use crate::GeometryArrayTrait;
+
+/// All-pairs distances between the geometries of an array.
+pub trait DistanceMatrix {
+    /// The planar Euclidean distance between every pair of geometries, as a row-major `n × n`
+    /// matrix where `n` is the length of the array.
+    ///
+    /// Distances involving a null geometry are NaN.
+    ///
+    /// This takes O(n²) time and memory, so it is only meant for small arrays: 10,000 points
+    /// already produce an 800 MB matrix.
+    fn distance_matrix(&self) -> Vec<f64>;
+}
+
+impl DistanceMatrix for PointArray {
+    fn distance_matrix(&self) -> Vec<f64> {
+        let n = self.len();
+        let coords: Vec<Option<(f64, f64)>> = (0..n)
+            .map(|i| {
+                self.is_valid(i)
+                    .then(|| (self.coords.get_x(i), self.coords.get_y(i)))
+            })
+            .collect();
+
+        let mut matrix = vec![0.; n * n];
+        for i in 0..n {
+            for j in i + 1..n {
+                let distance = match (coords[i], coords[j]) {
+                    (Some((x1, y1)), Some((x2, y2))) => (x2 - x1).hypot(y2 - y1),
+                    _ => f64::NAN,
+                };
+                matrix[i * n + j] = distance;
+                matrix[j * n + i] = distance;
+            }
+            if coords[i].is_none() {
+                matrix[i * n + i] = f64::NAN;
+            }
+        }
+        matrix
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn three_points() {
+        let points: PointArray = vec![
+            geo::point!(x: 0., y: 0.),
+            geo::point!(x: 3., y: 0.),
+            geo::point!(x: 3., y: 4.),
+        ]
+        .into();
+        let matrix = points.distance_matrix();
+
+        #[rustfmt::skip]
+        let expected = [
+            0., 3., 5.,
+            3., 0., 4.,
+            5., 4., 0.,
+        ];
+        assert_eq!(matrix.len(), expected.len());
+        for (actual, expected) in matrix.iter().zip(expected) {
+            assert_relative_eq!(*actual, expected);
+        }
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(matrix[i * 3 + j], matrix[j * 3 + i]);
+            }
+        }
+    }
+}
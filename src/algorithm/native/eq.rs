@@ -1,7 +1,9 @@
+use crate::array::*;
 use crate::geo_traits::{
     CoordTrait, LineStringTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait,
     PointTrait, PolygonTrait,
 };
+use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 use geo::CoordFloat;
@@ -26,7 +28,7 @@ pub fn coord_eq<T: CoordFloat>(
     left: impl CoordTrait<T = T>,
     right: impl CoordTrait<T = T>,
 ) -> bool {
-    left.x_y() == right.x_y()
+    left.x_y() == right.x_y() && left.z() == right.z() && left.m() == right.m()
 }
 
 #[inline]
@@ -44,7 +46,7 @@ pub fn point_eq<T: CoordFloat>(
         }
     }
 
-    left.x_y() == right.x_y()
+    left.x_y() == right.x_y() && left.z() == right.z() && left.m() == right.m()
 }
 
 #[inline]
@@ -175,3 +177,152 @@ pub(crate) fn offset_buffer_eq<O: OffsetSizeTrait>(
 
     true
 }
+
+/// Compare arrays with a tolerance on their coordinates.
+///
+/// Offsets, validity and every other part of the layout must match exactly, while each x, y, z and
+/// m value may differ by up to `epsilon`. This is meant for arrays that went through
+/// floating-point computations, where the bit-exact [`PartialEq`] is too strict.
+///
+/// As with [`PartialEq`], the coordinates under null geometries are compared too, and two NaN
+/// values are equal, so that empty points compare equal.
+///
+/// ```
+/// use geoarrow2::algorithm::native::EqualsExact;
+/// use geoarrow2::array::PointArray;
+/// use geo::point;
+///
+/// let left: PointArray = vec![point!(x: 0.1 + 0.2, y: 1.)].into();
+/// let right: PointArray = vec![point!(x: 0.3, y: 1.)].into();
+/// assert!(left != right);
+/// assert!(left.equals_exact(&right, 1e-12));
+/// ```
+pub trait EqualsExact {
+    fn equals_exact(&self, other: &Self, epsilon: f64) -> bool;
+}
+
+/// Whether two values are within `epsilon` of each other, where two NaNs are equal.
+#[inline]
+pub(crate) fn value_eq_within(left: f64, right: f64, epsilon: f64) -> bool {
+    (left - right).abs() <= epsilon || (left.is_nan() && right.is_nan())
+}
+
+impl EqualsExact for CoordBuffer {
+    fn equals_exact(&self, other: &Self, epsilon: f64) -> bool {
+        if self.len() != other.len() || self.dim() != other.dim() {
+            return false;
+        }
+
+        let optional_eq = |left: Option<f64>, right: Option<f64>| match (left, right) {
+            (Some(left), Some(right)) => value_eq_within(left, right, epsilon),
+            (None, None) => true,
+            _ => false,
+        };
+        (0..self.len()).all(|i| {
+            value_eq_within(self.get_x(i), other.get_x(i), epsilon)
+                && value_eq_within(self.get_y(i), other.get_y(i), epsilon)
+                && optional_eq(self.get_z(i), other.get_z(i))
+                && optional_eq(self.get_m(i), other.get_m(i))
+        })
+    }
+}
+
+impl EqualsExact for PointArray {
+    fn equals_exact(&self, other: &Self, epsilon: f64) -> bool {
+        self.validity == other.validity && self.coords.equals_exact(&other.coords, epsilon)
+    }
+}
+
+macro_rules! equals_exact_impl {
+    ($type:ty, $($offsets:ident),+) => {
+        impl<O: OffsetSizeTrait> EqualsExact for $type {
+            fn equals_exact(&self, other: &Self, epsilon: f64) -> bool {
+                self.validity == other.validity
+                    $(&& offset_buffer_eq(&self.$offsets, &other.$offsets))+
+                    && self.coords.equals_exact(&other.coords, epsilon)
+            }
+        }
+    };
+}
+
+equals_exact_impl!(LineStringArray<O>, geom_offsets);
+equals_exact_impl!(PolygonArray<O>, geom_offsets, ring_offsets);
+equals_exact_impl!(MultiPointArray<O>, geom_offsets);
+equals_exact_impl!(MultiLineStringArray<O>, geom_offsets, ring_offsets);
+equals_exact_impl!(
+    MultiPolygonArray<O>,
+    geom_offsets,
+    polygon_offsets,
+    ring_offsets
+);
+
+impl<O: OffsetSizeTrait> EqualsExact for GeometryCollectionArray<O> {
+    fn equals_exact(&self, other: &Self, epsilon: f64) -> bool {
+        self.validity == other.validity
+            && offset_buffer_eq(&self.geom_offsets, &other.geom_offsets)
+            && self.array.equals_exact(&other.array, epsilon)
+    }
+}
+
+impl<O: OffsetSizeTrait> EqualsExact for GeometryArray<O> {
+    fn equals_exact(&self, other: &Self, epsilon: f64) -> bool {
+        match (self, other) {
+            (GeometryArray::Point(left), GeometryArray::Point(right)) => {
+                left.equals_exact(right, epsilon)
+            }
+            (GeometryArray::LineString(left), GeometryArray::LineString(right)) => {
+                left.equals_exact(right, epsilon)
+            }
+            (GeometryArray::Polygon(left), GeometryArray::Polygon(right)) => {
+                left.equals_exact(right, epsilon)
+            }
+            (GeometryArray::MultiPoint(left), GeometryArray::MultiPoint(right)) => {
+                left.equals_exact(right, epsilon)
+            }
+            (GeometryArray::MultiLineString(left), GeometryArray::MultiLineString(right)) => {
+                left.equals_exact(right, epsilon)
+            }
+            (GeometryArray::MultiPolygon(left), GeometryArray::MultiPolygon(right)) => {
+                left.equals_exact(right, epsilon)
+            }
+            (GeometryArray::GeometryCollection(left), GeometryArray::GeometryCollection(right)) => {
+                left.equals_exact(right, epsilon)
+            }
+            (GeometryArray::Rect(left), GeometryArray::Rect(right)) => {
+                left.equals_exact(right, epsilon)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::native::transform::MapGeometryCoords;
+    use crate::test::{linestring, polygon};
+
+    #[test]
+    fn equals_exact_within_epsilon() {
+        let left: PolygonArray<i32> = vec![Some(polygon::p0()), None, Some(polygon::p1())].into();
+        let right = left.clone().into_coord_type(CoordType::Separated);
+        let right = right.map_geometry_coords(|_, coord| {
+            geo::coord! {
+                x: coord.x + 1e-12,
+                y: coord.y - 1e-12,
+            }
+        });
+        assert!(left != right);
+        assert!(left.equals_exact(&right, 1e-9));
+        assert!(!left.equals_exact(&right, 1e-13));
+
+        // Offsets must match exactly
+        let other: PolygonArray<i32> = vec![Some(polygon::p1()), None, Some(polygon::p0())].into();
+        assert!(!left.equals_exact(&other, f64::INFINITY));
+
+        let left = GeometryArray::Polygon(left);
+        assert!(left.equals_exact(&GeometryArray::Polygon(right), 1e-9));
+        let line_strings: LineStringArray<i32> = vec![linestring::ls0()].into();
+        assert!(!left.equals_exact(&GeometryArray::LineString(line_strings), 1.));
+    }
+}
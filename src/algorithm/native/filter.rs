@@ -0,0 +1,185 @@
+use arrow::compute::filter;
+use arrow_array::{BooleanArray, OffsetSizeTrait};
+
+use crate::array::*;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::{FromArrow, GeoArrayAccessor, IntoArrow};
+use crate::GeometryArrayTrait;
+
+/// Keep the geometries of an array selected by a boolean mask.
+///
+/// The output is compacted: only the coordinates of kept geometries are copied into new buffers,
+/// so it never holds onto the buffers of the input. The output keeps the coordinate layout and
+/// metadata of the input.
+pub trait Filter: Sized {
+    /// Keep the geometries whose value in `mask` is true. Null mask values count as false.
+    ///
+    /// ```
+    /// use arrow_array::BooleanArray;
+    /// use geoarrow2::algorithm::native::Filter;
+    /// use geoarrow2::array::PointArray;
+    /// use geoarrow2::trait_::GeoArrayAccessor;
+    /// use geoarrow2::GeometryArrayTrait;
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![point!(x: 1., y: 1.), point!(x: 2., y: 2.)].into();
+    /// let mask = BooleanArray::from(vec![false, true]);
+    /// let filtered = array.filter(&mask).unwrap();
+    /// assert_eq!(filtered.len(), 1);
+    /// assert_eq!(filtered.value_as_geo(0), point!(x: 2., y: 2.));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - if `mask` doesn't have the length of this array
+    fn filter(&self, mask: &BooleanArray) -> Result<Self>;
+}
+
+fn check_mask_len(mask: &BooleanArray, len: usize) -> Result<()> {
+    if mask.len() != len {
+        return Err(GeoArrowError::General(format!(
+            "Expected a mask with {} values, one per geometry, found {}",
+            len,
+            mask.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Implementation through arrow's filter kernel on the storage array
+macro_rules! filter_impl {
+    ($type:ty $(, $generic:ident)?) => {
+        impl$(<$generic: OffsetSizeTrait>)? Filter for $type
+        where
+            $type: FromArrow,
+        {
+            fn filter(&self, mask: &BooleanArray) -> Result<Self> {
+                check_mask_len(mask, self.len())?;
+                let (array, field) = self.clone().into_arrow();
+                let output = filter(array.as_ref(), mask)?;
+                let output = Self::from_arrow(output.as_ref(), &field)?;
+                Ok(output.into_coord_type(self.coord_type()))
+            }
+        }
+    };
+}
+
+filter_impl!(PointArray);
+filter_impl!(LineStringArray<O>, O);
+filter_impl!(PolygonArray<O>, O);
+filter_impl!(MultiPointArray<O>, O);
+filter_impl!(MultiLineStringArray<O>, O);
+filter_impl!(MultiPolygonArray<O>, O);
+filter_impl!(RectArray);
+filter_impl!(WKBArray<O>, O);
+
+/// The indices of the geometries to keep
+fn kept_indices(mask: &BooleanArray) -> impl Iterator<Item = usize> + '_ {
+    mask.iter()
+        .enumerate()
+        .filter_map(|(i, keep)| keep.unwrap_or(false).then_some(i))
+}
+
+// As in SortByKey, dense unions are rebuilt from geo objects
+
+impl<O: OffsetSizeTrait> Filter for MixedGeometryArray<O> {
+    fn filter(&self, mask: &BooleanArray) -> Result<Self> {
+        check_mask_len(mask, self.len())?;
+        let output_geoms: Vec<geo::Geometry> =
+            kept_indices(mask).map(|i| self.value_as_geo(i)).collect();
+        let output: Self = output_geoms.try_into()?;
+        Ok(output.into_coord_type(self.coord_type()))
+    }
+}
+
+impl<O: OffsetSizeTrait> Filter for GeometryCollectionArray<O> {
+    fn filter(&self, mask: &BooleanArray) -> Result<Self> {
+        check_mask_len(mask, self.len())?;
+        let output_geoms: Vec<Option<geo::GeometryCollection>> =
+            kept_indices(mask).map(|i| self.get_as_geo(i)).collect();
+        let output: Self = output_geoms.try_into()?;
+        Ok(output.into_coord_type(self.coord_type()))
+    }
+}
+
+impl<O: OffsetSizeTrait> Filter for GeometryArray<O>
+where
+    LineStringArray<O>: FromArrow,
+    PolygonArray<O>: FromArrow,
+    MultiPointArray<O>: FromArrow,
+    MultiLineStringArray<O>: FromArrow,
+    MultiPolygonArray<O>: FromArrow,
+{
+    fn filter(&self, mask: &BooleanArray) -> Result<Self> {
+        Ok(match self {
+            GeometryArray::Point(arr) => GeometryArray::Point(arr.filter(mask)?),
+            GeometryArray::LineString(arr) => GeometryArray::LineString(arr.filter(mask)?),
+            GeometryArray::Polygon(arr) => GeometryArray::Polygon(arr.filter(mask)?),
+            GeometryArray::MultiPoint(arr) => GeometryArray::MultiPoint(arr.filter(mask)?),
+            GeometryArray::MultiLineString(arr) => {
+                GeometryArray::MultiLineString(arr.filter(mask)?)
+            }
+            GeometryArray::MultiPolygon(arr) => GeometryArray::MultiPolygon(arr.filter(mask)?),
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(Box::new(arr.filter(mask)?))
+            }
+            GeometryArray::Rect(arr) => GeometryArray::Rect(arr.filter(mask)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{multipolygon, point};
+
+    #[test]
+    fn null_mask_values_are_false() {
+        let array: PointArray = vec![
+            Some(point::p0()),
+            None,
+            Some(point::p1()),
+            Some(point::p2()),
+        ]
+        .into();
+        let mask = BooleanArray::from(vec![Some(true), Some(true), None, Some(true)]);
+        let filtered = array.filter(&mask).unwrap();
+        assert_eq!(filtered.len(), 3);
+        assert_eq!(filtered.value_as_geo(0), point::p0());
+        assert!(filtered.is_null(1));
+        assert_eq!(filtered.value_as_geo(2), point::p2());
+    }
+
+    #[test]
+    fn copies_only_kept_coords() {
+        let array: MultiPolygonArray<i32> = vec![multipolygon::mp0(), multipolygon::mp1()].into();
+        let array = array.into_coord_type(CoordType::Separated);
+        let filtered = array
+            .filter(&BooleanArray::from(vec![false, true]))
+            .unwrap();
+        assert_eq!(filtered.coord_type(), CoordType::Separated);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered.value_as_geo(0), multipolygon::mp1());
+        assert_eq!(
+            filtered.coords.len(),
+            geo::CoordsIter::coords_count(&multipolygon::mp1())
+        );
+
+        let geometry_array = GeometryArray::MultiPolygon(array);
+        let filtered = geometry_array
+            .filter(&BooleanArray::from(vec![true, false]))
+            .unwrap();
+        assert_eq!(
+            filtered.value_as_geo(0),
+            geo::Geometry::MultiPolygon(multipolygon::mp0())
+        );
+    }
+
+    #[test]
+    fn mask_length_mismatch() {
+        let array: PointArray = vec![point::p0()].into();
+        assert!(array
+            .filter(&BooleanArray::from(vec![true, false]))
+            .is_err());
+    }
+}
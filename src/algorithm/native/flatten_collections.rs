@@ -0,0 +1,214 @@
+use crate::array::mixed::array::GeometryType;
+use crate::array::{GeometryCollectionArray, MixedGeometryArray, WKBArray};
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeoArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::{OffsetSizeTrait, UInt32Array};
+
+/// Unnest geometry collections into the geometries they contain.
+pub trait FlattenCollections {
+    type Output;
+
+    /// Replace each geometry collection with its parts, recursing into nested collections up to
+    /// `max_depth` levels deep. Geometries that aren't collections are kept as they are, and
+    /// multi geometries are not split.
+    ///
+    /// If `geometry_type` is given, only parts of that type are kept, counting single and multi
+    /// geometries as the same type: [`GeometryType::Polygon`] keeps polygons and multipolygons.
+    ///
+    /// Returns the parts and, for each part, the index of the row it came from. Null rows and
+    /// empty collections produce no parts.
+    ///
+    /// # Errors
+    ///
+    /// - if a row has collections nested more than `max_depth` levels deep
+    fn flatten_collections(
+        &self,
+        max_depth: usize,
+        geometry_type: Option<GeometryType>,
+    ) -> Result<(Self::Output, UInt32Array)>;
+}
+
+/// Whether `geom` has the same type as `geometry_type`, ignoring the single/multi distinction.
+fn has_type(geom: &geo::Geometry, geometry_type: GeometryType) -> bool {
+    use GeometryType::*;
+    matches!(
+        (geom, geometry_type),
+        (
+            geo::Geometry::Point(_) | geo::Geometry::MultiPoint(_),
+            Point | MultiPoint
+        ) | (
+            geo::Geometry::LineString(_)
+                | geo::Geometry::Line(_)
+                | geo::Geometry::MultiLineString(_),
+            LineString | MultiLineString
+        ) | (
+            geo::Geometry::Polygon(_)
+                | geo::Geometry::MultiPolygon(_)
+                | geo::Geometry::Rect(_)
+                | geo::Geometry::Triangle(_),
+            Polygon | MultiPolygon
+        )
+    )
+}
+
+/// Push the parts of `geom`, which is nested in `depth` collections, onto `parts`.
+fn flatten_geometry(
+    geom: geo::Geometry,
+    depth: usize,
+    max_depth: usize,
+    geometry_type: Option<GeometryType>,
+    parts: &mut Vec<geo::Geometry>,
+) -> Result<()> {
+    match geom {
+        geo::Geometry::GeometryCollection(collection) => {
+            if depth == max_depth {
+                return Err(GeoArrowError::General(format!(
+                    "Geometry collections are nested more than {} levels deep",
+                    max_depth
+                )));
+            }
+            collection.into_iter().try_for_each(|geom| {
+                flatten_geometry(geom, depth + 1, max_depth, geometry_type, parts)
+            })
+        }
+        geo::Geometry::Line(line) => {
+            flatten_geometry(line.into(), depth, max_depth, geometry_type, parts)
+        }
+        geo::Geometry::Rect(rect) => {
+            flatten_geometry(rect.into(), depth, max_depth, geometry_type, parts)
+        }
+        geo::Geometry::Triangle(triangle) => {
+            flatten_geometry(triangle.into(), depth, max_depth, geometry_type, parts)
+        }
+        geom => {
+            if geometry_type.map_or(true, |geometry_type| has_type(&geom, geometry_type)) {
+                parts.push(geom);
+            }
+            Ok(())
+        }
+    }
+}
+
+fn flatten_rows<O: OffsetSizeTrait>(
+    rows: impl Iterator<Item = Option<geo::Geometry>>,
+    max_depth: usize,
+    geometry_type: Option<GeometryType>,
+) -> Result<(MixedGeometryArray<O>, UInt32Array)> {
+    let mut parts = vec![];
+    let mut parent_index = vec![];
+    for (row_idx, maybe_g) in rows.enumerate() {
+        if let Some(g) = maybe_g {
+            flatten_geometry(g, 0, max_depth, geometry_type, &mut parts).map_err(|err| {
+                GeoArrowError::General(format!("Failed to flatten row {}: {}", row_idx, err))
+            })?;
+            let row_idx = u32::try_from(row_idx).map_err(|_| GeoArrowError::Overflow)?;
+            parent_index.resize(parts.len(), row_idx);
+        }
+    }
+
+    Ok((parts.try_into()?, parent_index.into()))
+}
+
+impl<O: OffsetSizeTrait> FlattenCollections for GeometryCollectionArray<O> {
+    type Output = MixedGeometryArray<O>;
+
+    fn flatten_collections(
+        &self,
+        max_depth: usize,
+        geometry_type: Option<GeometryType>,
+    ) -> Result<(Self::Output, UInt32Array)> {
+        let rows = self
+            .iter_geo()
+            .map(|maybe_g| maybe_g.map(geo::Geometry::GeometryCollection));
+        flatten_rows(rows, max_depth, geometry_type)
+    }
+}
+
+impl<O: OffsetSizeTrait> FlattenCollections for MixedGeometryArray<O> {
+    type Output = MixedGeometryArray<O>;
+
+    fn flatten_collections(
+        &self,
+        max_depth: usize,
+        geometry_type: Option<GeometryType>,
+    ) -> Result<(Self::Output, UInt32Array)> {
+        flatten_rows(self.iter_geo(), max_depth, geometry_type)
+    }
+}
+
+impl<O: OffsetSizeTrait> FlattenCollections for WKBArray<O> {
+    type Output = MixedGeometryArray<O>;
+
+    fn flatten_collections(
+        &self,
+        max_depth: usize,
+        geometry_type: Option<GeometryType>,
+    ) -> Result<(Self::Output, UInt32Array)> {
+        // Null slots hold no WKB, so they must be skipped before parsing
+        let rows = (0..self.len()).map(|i| self.get_as_geo(i));
+        flatten_rows(rows, max_depth, geometry_type)
+    }
+}
+
+#[cfg(all(test, feature = "geozero"))]
+mod test {
+    use super::*;
+    use geo::{line_string, point, polygon};
+
+    fn osm_relations() -> WKBArray<i32> {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let inner = geo::GeometryCollection::new_from(vec![
+            square.clone().into(),
+            point!(x: 5., y: 5.).into(),
+        ]);
+        let nested = geo::GeometryCollection::new_from(vec![
+            line_string![(x: 0., y: 0.), (x: 2., y: 2.)].into(),
+            geo::Geometry::GeometryCollection(inner),
+        ]);
+        vec![
+            Some(geo::Geometry::GeometryCollection(nested)),
+            None,
+            Some(point!(x: 3., y: 3.).into()),
+            Some(geo::Geometry::GeometryCollection(
+                geo::GeometryCollection::new_from(vec![square.into()]),
+            )),
+        ]
+        .into()
+    }
+
+    #[test]
+    fn nested_collections() {
+        let arr = osm_relations();
+        let (parts, parent_index) = arr.flatten_collections(2, None).unwrap();
+
+        assert_eq!(parts.len(), 5);
+        assert_eq!(parent_index, UInt32Array::from(vec![0, 0, 0, 2, 3]));
+        assert!(matches!(
+            parts.value_as_geo(0),
+            geo::Geometry::MultiLineString(_)
+        ));
+        assert!(matches!(
+            parts.value_as_geo(1),
+            geo::Geometry::MultiPolygon(_)
+        ));
+        assert!(matches!(
+            parts.value_as_geo(2),
+            geo::Geometry::MultiPoint(_)
+        ));
+
+        let err = arr.flatten_collections(1, None).unwrap_err();
+        assert!(err.to_string().contains("row 0"));
+    }
+
+    #[test]
+    fn keep_one_geometry_type() {
+        let (polygons, parent_index) = osm_relations()
+            .flatten_collections(2, Some(GeometryType::Polygon))
+            .unwrap();
+
+        assert_eq!(parent_index, UInt32Array::from(vec![0, 3]));
+        assert!((0..polygons.len())
+            .all(|i| matches!(polygons.value_as_geo(i), geo::Geometry::MultiPolygon(_))));
+    }
+}
@@ -1,3 +1,46 @@
+pub mod axis_order;
 pub(crate) mod bounding_rect;
+pub mod classify;
+pub mod clean;
+pub mod concat;
+pub mod cross_track;
+pub mod defects;
+pub mod distance_matrix;
 pub mod eq;
+pub mod filter;
+pub mod flatten_collections;
+pub mod offset_curve;
+pub mod output_options;
+pub mod parts;
+pub mod precision;
+pub mod sort;
+pub mod take;
+pub mod transform;
 pub mod type_id;
+pub mod web_mercator;
+pub mod winding;
+
+pub use axis_order::{AxisOrder, AxisOrderGuess, DetectAxisOrder, SwapXY};
+pub use bounding_rect::bounding_rect;
+pub use classify::{classify, ClassificationScheme};
+pub use clean::Clean;
+pub use concat::{concat_mixed, concat_promoting, Concat, PromotedGeometryArray, PromotionPolicy};
+pub use cross_track::{along_track_distance, cross_track_distance};
+pub use defects::FindDefects;
+pub use distance_matrix::DistanceMatrix;
+pub use eq::EqualsExact;
+pub use filter::Filter;
+pub use flatten_collections::FlattenCollections;
+pub use offset_curve::{JoinStyle, OffsetCurve};
+pub use output_options::OutputOptions;
+pub use parts::MultiParts;
+pub use precision::{CoordinatePrecision, PrecisionStats};
+pub use sort::SortByKey;
+pub use take::Take;
+pub use transform::{
+    transform_geometry_array, CoordTransform, FnTransform, TransformCoords, TransformErrorPolicy,
+    TransformOptions,
+};
+pub use type_id::TypeIds;
+pub use web_mercator::{OutOfRangePolicy, WebMercator};
+pub use winding::FlipWinding;
@@ -0,0 +1,187 @@
+use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::array::LineStringArray;
+use arrow_array::types::Float64Type;
+use arrow_array::OffsetSizeTrait;
+use geo::{Coord, LineString};
+
+/// How the offset segments on the outside of a corner are connected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinStyle {
+    /// Extend the offset segments until they meet. If the meeting point is more than `limit`
+    /// times the offset distance away from the corner, fall back to a bevel.
+    Miter { limit: f64 },
+
+    /// Connect the ends of the offset segments with a straight segment.
+    Bevel,
+}
+
+impl Default for JoinStyle {
+    fn default() -> Self {
+        Self::Miter { limit: 5. }
+    }
+}
+
+/// Lines parallel to linear geometries, offset perpendicular to their direction.
+///
+/// This is computed in pure Rust, so it is also available without GEOS. It doesn't remove
+/// self-intersections: offsetting a tight bend on its inside by more than the bend's radius gives
+/// a small loop, where GEOS would cut it off.
+pub trait OffsetCurve {
+    type Output;
+
+    /// Offset each line by `distance`, to the left of its direction when positive and to the
+    /// right when negative.
+    ///
+    /// Null lines, null distances and lines with fewer than two distinct points give null.
+    fn offset_curve(
+        &self,
+        distance: BroadcastablePrimitive<Float64Type>,
+        join_style: JoinStyle,
+    ) -> Self::Output;
+}
+
+impl<O: OffsetSizeTrait> OffsetCurve for LineStringArray<O> {
+    type Output = LineStringArray<O>;
+
+    fn offset_curve(
+        &self,
+        distance: BroadcastablePrimitive<Float64Type>,
+        join_style: JoinStyle,
+    ) -> Self::Output {
+        let output_geoms: Vec<Option<LineString>> = self
+            .iter_geo()
+            .zip(&distance)
+            .map(|(maybe_g, distance)| offset_line_string(&maybe_g?, distance?, join_style))
+            .collect();
+
+        output_geoms.into()
+    }
+}
+
+/// The left-hand unit normal of the segment from `start` to `end`.
+fn unit_normal(start: Coord, end: Coord) -> Coord {
+    let delta = end - start;
+    let length = delta.x.hypot(delta.y);
+    Coord {
+        x: -delta.y / length,
+        y: delta.x / length,
+    }
+}
+
+fn offset_line_string(
+    line_string: &LineString,
+    distance: f64,
+    join_style: JoinStyle,
+) -> Option<LineString> {
+    let mut coords = line_string.0.clone();
+    coords.dedup();
+    if coords.len() < 2 {
+        return None;
+    }
+
+    let normals: Vec<Coord> = coords
+        .windows(2)
+        .map(|segment| unit_normal(segment[0], segment[1]))
+        .collect();
+
+    let mut output = Vec::with_capacity(coords.len() + 1);
+    output.push(coords[0] + normals[0] * distance);
+    for (vertex, normal) in coords[1..coords.len() - 1].iter().zip(normals.windows(2)) {
+        let (before, after) = (normal[0], normal[1]);
+        let cos = before.x * after.x + before.y * after.y;
+        // Positive for left turns, whose left side is the inside of the corner
+        let turn = before.x * after.y - before.y * after.x;
+        let outside = turn * distance < 0.;
+
+        // The offset segments meet at `vertex + distance * miter`, whose length is
+        // 1 / cos(angle / 2) = sqrt(2 / (1 + cos)) times the distance
+        let miter = (before + after) / (1. + cos);
+        let miter_ratio = (2. / (1. + cos)).sqrt();
+        let use_miter = match join_style {
+            _ if !miter_ratio.is_finite() => false,
+            _ if !outside => true,
+            JoinStyle::Miter { limit } => miter_ratio <= limit,
+            JoinStyle::Bevel => false,
+        };
+
+        if use_miter {
+            output.push(*vertex + miter * distance);
+        } else {
+            output.push(*vertex + before * distance);
+            output.push(*vertex + after * distance);
+        }
+    }
+    output.push(coords[coords.len() - 1] + normals[normals.len() - 1] * distance);
+
+    Some(LineString::new(output))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeoArrayAccessor;
+    use crate::GeometryArrayTrait;
+    use arrow_array::Float64Array;
+    use geo::line_string;
+
+    fn corner() -> LineStringArray<i32> {
+        vec![
+            Some(line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)]),
+            Some(line_string![(x: 5., y: 5.), (x: 5., y: 5.)]),
+            None,
+        ]
+        .into()
+    }
+
+    #[test]
+    fn inside_and_outside_corners() {
+        let arr = corner();
+
+        let left = arr.offset_curve(BroadcastablePrimitive::Scalar(1.), JoinStyle::Bevel);
+        assert_eq!(
+            left.value_as_geo(0),
+            line_string![(x: 0., y: 1.), (x: 9., y: 1.), (x: 9., y: 10.)]
+        );
+        assert!(left.is_null(1));
+        assert!(left.is_null(2));
+
+        let right_miter =
+            arr.offset_curve(BroadcastablePrimitive::Scalar(-1.), JoinStyle::default());
+        assert_eq!(
+            right_miter.value_as_geo(0),
+            line_string![(x: 0., y: -1.), (x: 11., y: -1.), (x: 11., y: 10.)]
+        );
+
+        let right_bevel = arr.offset_curve(BroadcastablePrimitive::Scalar(-1.), JoinStyle::Bevel);
+        assert_eq!(
+            right_bevel.value_as_geo(0),
+            line_string![(x: 0., y: -1.), (x: 10., y: -1.), (x: 11., y: 0.), (x: 11., y: 10.)]
+        );
+
+        // A right angle has a miter ratio of sqrt(2)
+        let right_limited = arr.offset_curve(
+            BroadcastablePrimitive::Scalar(-1.),
+            JoinStyle::Miter { limit: 1.2 },
+        );
+        assert_eq!(right_limited.value_as_geo(0), right_bevel.value_as_geo(0));
+    }
+
+    #[test]
+    fn broadcast_distances() {
+        let arr = corner();
+        let distances = Float64Array::from(vec![None, Some(1.), Some(1.)]);
+        let result = arr.offset_curve(BroadcastablePrimitive::Array(distances), JoinStyle::Bevel);
+        assert_eq!(result.len(), 3);
+        assert!((0..3).all(|i| result.is_null(i)));
+
+        let straight: LineStringArray<i32> = vec![Some(
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)],
+        )]
+        .into();
+        let result = straight.offset_curve(BroadcastablePrimitive::Scalar(2.), JoinStyle::Bevel);
+        assert_eq!(
+            result.value_as_geo(0),
+            line_string![(x: 0., y: 2.), (x: 1., y: 2.), (x: 2., y: 2.)]
+        );
+    }
+}
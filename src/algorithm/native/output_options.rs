@@ -0,0 +1,43 @@
+use crate::array::CoordType;
+use crate::GeometryArrayTrait;
+
+/// The layout of the arrays built by kernels that construct new geometries, such as
+/// [`Centroid`](crate::algorithm::geo::Centroid),
+/// [`ConvexHull`](crate::algorithm::geo::ConvexHull) and
+/// [`Simplify`](crate::algorithm::geo::Simplify).
+///
+/// The options are passed to each kernel's `*_with_options` method, and the output is built
+/// directly in the requested layout instead of being cast afterwards. The methods without options
+/// use [`OutputOptions::from_array`], so their output keeps the layout of their input.
+///
+/// There is no option for the offset type: kernels take it from a type parameter, as in
+/// [`ConvexHull<O>`](crate::algorithm::geo::ConvexHull), or from their input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputOptions {
+    /// The coordinate layout of the output.
+    pub coord_type: CoordType,
+}
+
+impl OutputOptions {
+    pub fn new(coord_type: CoordType) -> Self {
+        Self { coord_type }
+    }
+
+    /// The options that keep the layout of `array`.
+    pub fn from_array<'a>(array: &impl GeometryArrayTrait<'a>) -> Self {
+        Self::new(array.coord_type())
+    }
+
+    /// Set the coordinate layout of the output.
+    pub fn with_coord_type(mut self, coord_type: CoordType) -> Self {
+        self.coord_type = coord_type;
+        self
+    }
+}
+
+impl Default for OutputOptions {
+    /// Interleaved coordinates.
+    fn default() -> Self {
+        Self::new(CoordType::Interleaved)
+    }
+}
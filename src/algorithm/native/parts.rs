@@ -0,0 +1,182 @@
+use arrow_array::{OffsetSizeTrait, UInt32Array};
+use geo::{Area, EuclideanLength};
+
+use crate::array::*;
+use crate::buffer::offset_lengths;
+use crate::trait_::GeoArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// Select multi geometries by their number of parts, or select single parts out of them.
+///
+/// A common cleanup step is to keep only the mainland of a country, which is the
+/// [`largest_part`](Self::largest_part) of its multipolygon.
+pub trait MultiParts: Sized {
+    /// The array type of a single part.
+    type Part;
+
+    /// Keep the geometries with between `min` and `max` parts, inclusive.
+    ///
+    /// Returns the kept geometries and their indices in `self`. Null geometries are dropped, as
+    /// they have no number of parts.
+    fn filter_by_num_parts(&self, min: usize, max: usize) -> (Self, UInt32Array);
+
+    /// The largest part of each geometry: the polygon with the largest area, the line string with
+    /// the largest planar length, or the first point. The first part wins ties.
+    ///
+    /// Null and empty geometries give null.
+    fn largest_part(&self) -> Self::Part;
+
+    /// The part at index `n` of each geometry.
+    ///
+    /// Null geometries and geometries with `n` or fewer parts give null.
+    fn nth_part(&self, n: usize) -> Self::Part;
+}
+
+/// The position of the part with the largest `size`, the first one on ties.
+fn largest<T>(parts: &[T], size: impl Fn(&T) -> f64) -> Option<&T> {
+    parts
+        .iter()
+        .fold(None, |largest: Option<(&T, f64)>, part| {
+            let part_size = size(part);
+            match largest {
+                Some((_, largest_size)) if largest_size >= part_size => largest,
+                _ => Some((part, part_size)),
+            }
+        })
+        .map(|(part, _)| part)
+}
+
+macro_rules! multi_parts_impl {
+    ($type:ty, $part_type:ty, $geo_type:ty, $size:expr) => {
+        impl<O: OffsetSizeTrait> MultiParts for $type {
+            type Part = $part_type;
+
+            fn filter_by_num_parts(&self, min: usize, max: usize) -> (Self, UInt32Array) {
+                let kept_indices: Vec<u32> = offset_lengths(&self.geom_offsets)
+                    .enumerate()
+                    .filter(|(i, num_parts)| self.is_valid(*i) && (min..=max).contains(num_parts))
+                    .map(|(i, _)| i as u32)
+                    .collect();
+
+                let output_geoms: Vec<Option<$geo_type>> = kept_indices
+                    .iter()
+                    .map(|i| Some(self.value_as_geo(*i as usize)))
+                    .collect();
+                let output: Self = output_geoms.into();
+                (
+                    output.with_metadata(self.metadata().clone()),
+                    kept_indices.into(),
+                )
+            }
+
+            fn largest_part(&self) -> Self::Part {
+                let output_geoms: Vec<_> = self
+                    .iter_geo()
+                    .map(|maybe_g| maybe_g.and_then(|g| largest(&g.0, $size).cloned()))
+                    .collect();
+                let output: Self::Part = output_geoms.into();
+                output.with_metadata(self.metadata().clone())
+            }
+
+            fn nth_part(&self, n: usize) -> Self::Part {
+                let output_geoms: Vec<_> = self
+                    .iter_geo()
+                    .map(|maybe_g| maybe_g.and_then(|g| g.0.get(n).cloned()))
+                    .collect();
+                let output: Self::Part = output_geoms.into();
+                output.with_metadata(self.metadata().clone())
+            }
+        }
+    };
+}
+
+multi_parts_impl!(MultiPointArray<O>, PointArray, geo::MultiPoint, |_| 0.);
+multi_parts_impl!(
+    MultiLineStringArray<O>,
+    LineStringArray<O>,
+    geo::MultiLineString,
+    |part: &geo::LineString| part.euclidean_length()
+);
+multi_parts_impl!(
+    MultiPolygonArray<O>,
+    PolygonArray<O>,
+    geo::MultiPolygon,
+    |part: &geo::Polygon| part.unsigned_area()
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{line_string, point, polygon};
+
+    fn square(x: f64, size: f64) -> geo::Polygon {
+        polygon![(x: x, y: 0.), (x: x + size, y: 0.), (x: x + size, y: size), (x: x, y: size)]
+    }
+
+    fn countries() -> MultiPolygonArray<i32> {
+        vec![
+            Some(geo::MultiPolygon::new(vec![
+                square(0., 1.),
+                square(10., 5.),
+                square(20., 2.),
+            ])),
+            None,
+            Some(geo::MultiPolygon::new(vec![square(0., 3.)])),
+            Some(geo::MultiPolygon::new(vec![])),
+        ]
+        .into()
+    }
+
+    #[test]
+    fn filter_by_num_parts() {
+        let (filtered, kept_indices) = countries().filter_by_num_parts(1, 2);
+        assert_eq!(kept_indices, UInt32Array::from(vec![2]));
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered.value_as_geo(0).0, vec![square(0., 3.)]);
+
+        let (filtered, kept_indices) = countries().filter_by_num_parts(0, usize::MAX);
+        assert_eq!(kept_indices, UInt32Array::from(vec![0, 2, 3]));
+        assert_eq!(filtered.len(), 3);
+    }
+
+    #[test]
+    fn largest_part() {
+        let mainland = countries().largest_part();
+        assert_eq!(mainland.len(), 4);
+        assert_eq!(mainland.get_as_geo(0), Some(square(10., 5.)));
+        assert!(mainland.is_null(1));
+        assert_eq!(mainland.get_as_geo(2), Some(square(0., 3.)));
+        assert!(mainland.is_null(3));
+
+        let lines: MultiLineStringArray<i32> = vec![Some(geo::MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 0., y: 0.), (x: 0., y: 2.)],
+            line_string![(x: 5., y: 0.), (x: 5., y: 2.)],
+        ]))]
+        .into();
+        assert_eq!(
+            lines.largest_part().value_as_geo(0),
+            line_string![(x: 0., y: 0.), (x: 0., y: 2.)]
+        );
+    }
+
+    #[test]
+    fn nth_part() {
+        let second = countries().nth_part(1);
+        assert_eq!(second.get_as_geo(0), Some(square(10., 5.)));
+        assert!((1..4).all(|i| second.is_null(i)));
+
+        let points: MultiPointArray<i32> = vec![
+            Some(vec![point!(x: 1., y: 2.), point!(x: 3., y: 4.)].into()),
+            None,
+        ]
+        .into();
+        let first = points.nth_part(0);
+        assert_eq!(first.get_as_geo(0), Some(point!(x: 1., y: 2.)));
+        assert!(first.is_null(1));
+        assert_eq!(
+            points.largest_part().get_as_geo(0),
+            Some(point!(x: 1., y: 2.))
+        );
+    }
+}
@@ -0,0 +1,281 @@
+use std::fmt::Write;
+
+use arrow_array::OffsetSizeTrait;
+use arrow_buffer::{NullBuffer, OffsetBuffer};
+
+use crate::algorithm::native::transform::nested_coord_range;
+use crate::array::*;
+use crate::trait_::GeoArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// How much precision the x and y values of an array use, from [`CoordinatePrecision`].
+///
+/// Only the coordinates of non-null geometries are read, and non-finite values are skipped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrecisionStats {
+    /// The largest number of decimal places in the shortest representation of a value that
+    /// parses back to it, so `1.25` has 2 and `3.0` has 0.
+    ///
+    /// Writing coordinates with this many decimals loses nothing.
+    pub max_decimal_places: usize,
+
+    /// The smallest absolute value, or `None` if there are no values.
+    pub min_magnitude: Option<f64>,
+
+    /// The largest absolute value, or `None` if there are no values.
+    pub max_magnitude: Option<f64>,
+
+    /// The smallest nonzero difference in x or in y between consecutive vertices of a line
+    /// string, ring or multi point, or `None` if there are no two distinct consecutive vertices.
+    pub min_nonzero_delta: Option<f64>,
+}
+
+/// Report how much coordinate precision the geometries of an array actually use.
+///
+/// This helps pick a precision for writers that round coordinates, such as
+/// [`write_wkt`](crate::io::wkt::write_wkt) with
+/// [`WktWriterOptions::lossless`](crate::io::wkt::WktWriterOptions::lossless), or a grid size to
+/// snap to.
+///
+/// ```
+/// use geoarrow2::algorithm::native::CoordinatePrecision;
+/// use geoarrow2::array::LineStringArray;
+/// use geo::line_string;
+///
+/// let array: LineStringArray<i32> =
+///     vec![line_string![(x: 1.5, y: -2.), (x: 1.75, y: 10.)]].into();
+/// let stats = array.coordinate_precision_stats();
+/// assert_eq!(stats.max_decimal_places, 2);
+/// assert_eq!(stats.min_magnitude, Some(1.5));
+/// assert_eq!(stats.max_magnitude, Some(10.));
+/// assert_eq!(stats.min_nonzero_delta, Some(0.25));
+/// ```
+pub trait CoordinatePrecision {
+    /// Compute the precision statistics in one pass over the coordinates.
+    fn coordinate_precision_stats(&self) -> PrecisionStats;
+}
+
+struct PrecisionFit {
+    stats: PrecisionStats,
+    buf: String,
+}
+
+impl PrecisionFit {
+    fn new() -> Self {
+        Self {
+            stats: PrecisionStats {
+                max_decimal_places: 0,
+                min_magnitude: None,
+                max_magnitude: None,
+                min_nonzero_delta: None,
+            },
+            buf: String::new(),
+        }
+    }
+
+    fn add_value(&mut self, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+
+        // f64's Display writes the shortest representation that round trips, never in
+        // exponent notation
+        self.buf.clear();
+        write!(self.buf, "{}", value.abs()).unwrap();
+        let decimal_places = self.buf.find('.').map_or(0, |i| self.buf.len() - i - 1);
+        let stats = &mut self.stats;
+        stats.max_decimal_places = stats.max_decimal_places.max(decimal_places);
+
+        let magnitude = value.abs();
+        stats.min_magnitude = Some(stats.min_magnitude.map_or(magnitude, |m| m.min(magnitude)));
+        stats.max_magnitude = Some(stats.max_magnitude.map_or(magnitude, |m| m.max(magnitude)));
+    }
+
+    fn add_delta(&mut self, delta: f64) {
+        let delta = delta.abs();
+        if delta > 0. && delta.is_finite() {
+            let min_delta = &mut self.stats.min_nonzero_delta;
+            *min_delta = Some(min_delta.map_or(delta, |d| d.min(delta)));
+        }
+    }
+
+    /// Add a sequence of consecutive vertices.
+    fn add_part(&mut self, coords: impl IntoIterator<Item = (f64, f64)>) {
+        let mut previous: Option<(f64, f64)> = None;
+        for (x, y) in coords {
+            self.add_value(x);
+            self.add_value(y);
+            if let Some((prev_x, prev_y)) = previous {
+                self.add_delta(x - prev_x);
+                self.add_delta(y - prev_y);
+            }
+            previous = Some((x, y));
+        }
+    }
+
+    fn add_coord_range(&mut self, coords: &CoordBuffer, range: std::ops::Range<usize>) {
+        self.add_part(range.map(|i| (coords.get_x(i), coords.get_y(i))));
+    }
+
+    /// Add the valid geometries of a native array, whose offsets go from the geometry level down
+    /// to the coordinate level.
+    fn add_geometries<O: OffsetSizeTrait>(
+        &mut self,
+        coords: &CoordBuffer,
+        len: usize,
+        nulls: Option<&NullBuffer>,
+        offsets: &[&OffsetBuffer<O>],
+    ) {
+        // The last level of offsets splits the coordinates into line strings, rings or multi
+        // points
+        let (parent_offsets, part_offsets) = offsets.split_at(offsets.len() - 1);
+        let part_offsets = part_offsets[0];
+        for geom_idx in (0..len).filter(|&i| nulls.map_or(true, |nulls| nulls.is_valid(i))) {
+            for part_idx in nested_coord_range(parent_offsets, geom_idx) {
+                let start = part_offsets[part_idx].to_usize().unwrap();
+                let end = part_offsets[part_idx + 1].to_usize().unwrap();
+                self.add_coord_range(coords, start..end);
+            }
+        }
+    }
+
+    fn add_geo_geometry(&mut self, geometry: &geo::Geometry) {
+        let line_coords = |line_string: &geo::LineString| -> Vec<(f64, f64)> {
+            line_string.coords().map(|c| (c.x, c.y)).collect()
+        };
+        let add_polygon = |fit: &mut Self, polygon: &geo::Polygon| {
+            fit.add_part(line_coords(polygon.exterior()));
+            polygon
+                .interiors()
+                .iter()
+                .for_each(|ring| fit.add_part(line_coords(ring)));
+        };
+
+        match geometry {
+            geo::Geometry::Point(point) => self.add_part([point.x_y()]),
+            geo::Geometry::Line(line) => self.add_part([line.start.x_y(), line.end.x_y()]),
+            geo::Geometry::LineString(line_string) => self.add_part(line_coords(line_string)),
+            geo::Geometry::Polygon(polygon) => add_polygon(self, polygon),
+            geo::Geometry::MultiPoint(multi_point) => {
+                self.add_part(multi_point.iter().map(|point| point.x_y()))
+            }
+            geo::Geometry::MultiLineString(multi_line_string) => multi_line_string
+                .iter()
+                .for_each(|line_string| self.add_part(line_coords(line_string))),
+            geo::Geometry::MultiPolygon(multi_polygon) => multi_polygon
+                .iter()
+                .for_each(|polygon| add_polygon(self, polygon)),
+            geo::Geometry::Rect(rect) => add_polygon(self, &rect.to_polygon()),
+            geo::Geometry::Triangle(triangle) => add_polygon(self, &triangle.to_polygon()),
+            geo::Geometry::GeometryCollection(collection) => collection
+                .iter()
+                .for_each(|geometry| self.add_geo_geometry(geometry)),
+        }
+    }
+}
+
+impl CoordinatePrecision for PointArray {
+    fn coordinate_precision_stats(&self) -> PrecisionStats {
+        let mut fit = PrecisionFit::new();
+        for i in (0..self.len()).filter(|&i| self.is_valid(i)) {
+            fit.add_coord_range(&self.coords, i..i + 1);
+        }
+        fit.stats
+    }
+}
+
+macro_rules! precision_impl {
+    ($type:ty, $($offsets:ident),+) => {
+        impl<O: OffsetSizeTrait> CoordinatePrecision for $type {
+            fn coordinate_precision_stats(&self) -> PrecisionStats {
+                let mut fit = PrecisionFit::new();
+                fit.add_geometries(
+                    &self.coords,
+                    self.len(),
+                    self.validity.as_ref(),
+                    &[$(&self.$offsets),+],
+                );
+                fit.stats
+            }
+        }
+    };
+}
+
+precision_impl!(LineStringArray<O>, geom_offsets);
+precision_impl!(PolygonArray<O>, geom_offsets, ring_offsets);
+precision_impl!(MultiPointArray<O>, geom_offsets);
+precision_impl!(MultiLineStringArray<O>, geom_offsets, ring_offsets);
+precision_impl!(
+    MultiPolygonArray<O>,
+    geom_offsets,
+    polygon_offsets,
+    ring_offsets
+);
+
+// Rects and geometry collections don't store their coordinates in a coord buffer, so they are read
+// through geo objects
+
+impl CoordinatePrecision for RectArray {
+    fn coordinate_precision_stats(&self) -> PrecisionStats {
+        let mut fit = PrecisionFit::new();
+        for rect in (0..self.len()).filter_map(|i| self.get_as_geo(i)) {
+            fit.add_geo_geometry(&geo::Geometry::Rect(rect));
+        }
+        fit.stats
+    }
+}
+
+impl<O: OffsetSizeTrait> CoordinatePrecision for GeometryCollectionArray<O> {
+    fn coordinate_precision_stats(&self) -> PrecisionStats {
+        let mut fit = PrecisionFit::new();
+        for collection in self.iter_geo().flatten() {
+            fit.add_geo_geometry(&geo::Geometry::GeometryCollection(collection));
+        }
+        fit.stats
+    }
+}
+
+impl<O: OffsetSizeTrait> CoordinatePrecision for GeometryArray<O> {
+    fn coordinate_precision_stats(&self) -> PrecisionStats {
+        match self {
+            GeometryArray::Point(arr) => arr.coordinate_precision_stats(),
+            GeometryArray::LineString(arr) => arr.coordinate_precision_stats(),
+            GeometryArray::Polygon(arr) => arr.coordinate_precision_stats(),
+            GeometryArray::MultiPoint(arr) => arr.coordinate_precision_stats(),
+            GeometryArray::MultiLineString(arr) => arr.coordinate_precision_stats(),
+            GeometryArray::MultiPolygon(arr) => arr.coordinate_precision_stats(),
+            GeometryArray::GeometryCollection(arr) => arr.coordinate_precision_stats(),
+            GeometryArray::Rect(arr) => arr.coordinate_precision_stats(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{point, polygon};
+
+    #[test]
+    fn skips_nulls_and_ring_boundaries() {
+        let array: PolygonArray<i32> = vec![
+            Some(polygon!(
+                exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 0.)],
+                interiors: [[(x: 0.5, y: 3.), (x: 1.625, y: 3.), (x: 1.625, y: 2.)]],
+            )),
+            None,
+        ]
+        .into();
+        let stats = array.coordinate_precision_stats();
+        assert_eq!(stats.max_decimal_places, 3);
+        assert_eq!(stats.min_magnitude, Some(0.));
+        assert_eq!(stats.max_magnitude, Some(4.));
+        // The last vertex of the exterior and the first of the interior are not consecutive
+        assert_eq!(stats.min_nonzero_delta, Some(1.));
+
+        // The null point stores 0, 0 in the coord buffer
+        let array: PointArray = vec![Some(point!(x: 10.5, y: 20.)), None].into();
+        let stats = array.coordinate_precision_stats();
+        assert_eq!(stats.min_magnitude, Some(10.5));
+        assert_eq!(stats.min_nonzero_delta, None);
+    }
+}
@@ -0,0 +1,282 @@
+use std::sync::Arc;
+
+use arrow::compute::{concat, lexsort_to_indices, take, SortColumn, SortOptions};
+use arrow_array::{Array, ArrayRef, OffsetSizeTrait, RecordBatch, UInt32Array};
+
+use crate::algorithm::geo::{Area, EuclideanLength};
+use crate::algorithm::native::take::Take;
+use crate::array::*;
+use crate::error::{GeoArrowError, Result};
+use crate::table::GeoTable;
+use crate::GeometryArrayTrait;
+
+/// Reorder the geometries of an array by the values of a companion key column.
+///
+/// The sort is stable: geometries with equal keys keep their relative order, whatever the
+/// direction of the sort. Null keys are placed first or last according to
+/// [`SortOptions::nulls_first`]. Null geometries are moved like any other geometry.
+///
+/// Geometries are reordered with [`Take`], so the output is never a view of the input. The output
+/// keeps the coordinate layout and metadata of the input.
+pub trait SortByKey: Sized {
+    /// Sort the geometries by `keys`, which has one value per geometry.
+    ///
+    /// ```
+    /// use arrow::compute::SortOptions;
+    /// use arrow_array::Int32Array;
+    /// use geoarrow2::algorithm::native::SortByKey;
+    /// use geoarrow2::array::PointArray;
+    /// use geoarrow2::trait_::GeoArrayAccessor;
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![point!(x: 1., y: 1.), point!(x: 2., y: 2.)].into();
+    /// let keys = Int32Array::from(vec![20, 10]);
+    /// let sorted = array.sort_by_key(&keys, SortOptions::default()).unwrap();
+    /// assert_eq!(sorted.value_as_geo(0), point!(x: 2., y: 2.));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - if `keys` doesn't have the length of this array
+    /// - if arrow can't sort the data type of `keys`
+    fn sort_by_key(&self, keys: &dyn Array, options: SortOptions) -> Result<Self>;
+
+    /// Sort the geometries by their unsigned planar area.
+    ///
+    /// Use [`SortOptions::descending`] to put the largest geometries first, e.g. to draw large
+    /// polygons before the small ones on top of them.
+    fn sort_by_area(&self, options: SortOptions) -> Result<Self>
+    where
+        Self: Area,
+    {
+        self.sort_by_key(&self.unsigned_area(), options)
+    }
+
+    /// Sort the geometries by their planar length.
+    fn sort_by_length(&self, options: SortOptions) -> Result<Self>
+    where
+        Self: EuclideanLength,
+    {
+        self.sort_by_key(&self.euclidean_length(), options)
+    }
+}
+
+/// The permutation that sorts `keys` stably.
+///
+/// `lexsort` doesn't promise a stable order, so ties are broken by the original row index.
+fn sort_indices(keys: &dyn Array, len: usize, options: SortOptions) -> Result<UInt32Array> {
+    if keys.len() != len {
+        return Err(GeoArrowError::General(format!(
+            "Expected {} sort keys, one per row, found {}",
+            len,
+            keys.len()
+        )));
+    }
+
+    let row_indices: ArrayRef = Arc::new(UInt32Array::from_iter_values(0..len as u32));
+    let columns = [
+        SortColumn {
+            values: arrow::array::make_array(keys.to_data()),
+            options: Some(options),
+        },
+        SortColumn {
+            values: row_indices,
+            options: None,
+        },
+    ];
+    Ok(lexsort_to_indices(&columns, None)?)
+}
+
+macro_rules! sort_impl {
+    ($type:ty $(, $generic:ident)?) => {
+        impl$(<$generic: OffsetSizeTrait>)? SortByKey for $type
+        where
+            $type: Take,
+        {
+            fn sort_by_key(&self, keys: &dyn Array, options: SortOptions) -> Result<Self> {
+                self.take(&sort_indices(keys, self.len(), options)?)
+            }
+        }
+    };
+}
+
+sort_impl!(PointArray);
+sort_impl!(LineStringArray<O>, O);
+sort_impl!(PolygonArray<O>, O);
+sort_impl!(MultiPointArray<O>, O);
+sort_impl!(MultiLineStringArray<O>, O);
+sort_impl!(MultiPolygonArray<O>, O);
+sort_impl!(RectArray);
+sort_impl!(WKBArray<O>, O);
+sort_impl!(MixedGeometryArray<O>, O);
+sort_impl!(GeometryCollectionArray<O>, O);
+sort_impl!(GeometryArray<O>, O);
+
+impl GeoTable {
+    /// Reorder the rows of this table by the values of `keys`, which has one value per row of the
+    /// whole table.
+    ///
+    /// All columns are reordered together and the output has a single batch. See [`SortByKey`]
+    /// for how keys are ordered.
+    ///
+    /// # Errors
+    ///
+    /// - if `keys` doesn't have one value per row
+    /// - if arrow can't sort the data type of `keys` or reorder one of the columns
+    pub fn sort_by_key(&self, keys: &dyn Array, options: SortOptions) -> Result<GeoTable> {
+        let num_rows = self.batches().iter().map(|batch| batch.num_rows()).sum();
+        let indices = sort_indices(keys, num_rows, options)?;
+
+        let schema = self.schema();
+        let columns = (0..schema.fields().len())
+            .map(|column_idx| {
+                let chunks: Vec<&dyn Array> = self
+                    .batches()
+                    .iter()
+                    .map(|batch| batch.column(column_idx).as_ref())
+                    .collect();
+                let column = if chunks.is_empty() {
+                    arrow_array::new_empty_array(schema.field(column_idx).data_type())
+                } else {
+                    concat(&chunks)?
+                };
+                Ok(take(column.as_ref(), &indices, None)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let batch = RecordBatch::try_new(schema.clone(), columns)?;
+        GeoTable::try_new(schema.clone(), vec![batch], self.geometry_column_index())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeoArrayAccessor;
+    use arrow_array::{Float64Array, Int32Array};
+    use geo::{line_string, point, polygon};
+
+    fn square(size: f64) -> geo::Polygon {
+        polygon![(x: 0., y: 0.), (x: size, y: 0.), (x: size, y: size), (x: 0., y: size)]
+    }
+
+    #[test]
+    fn stable_with_nulls() {
+        let array: PointArray = vec![
+            Some(point!(x: 0., y: 0.)),
+            Some(point!(x: 1., y: 1.)),
+            None,
+            Some(point!(x: 3., y: 3.)),
+            Some(point!(x: 4., y: 4.)),
+        ]
+        .into();
+        let keys = Int32Array::from(vec![Some(2), Some(1), Some(2), None, Some(1)]);
+
+        let sorted = array.sort_by_key(&keys, SortOptions::default()).unwrap();
+        let xs: Vec<Option<f64>> = (0..sorted.len())
+            .map(|i| sorted.get_as_geo(i).map(|p| p.x()))
+            .collect();
+        assert_eq!(xs, vec![Some(3.), Some(1.), Some(4.), Some(0.), None]);
+        assert!(sorted.is_null(4));
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let sorted = array.sort_by_key(&keys, options).unwrap();
+        let xs: Vec<Option<f64>> = (0..sorted.len())
+            .map(|i| sorted.get_as_geo(i).map(|p| p.x()))
+            .collect();
+        assert_eq!(xs, vec![Some(0.), None, Some(1.), Some(4.), Some(3.)]);
+    }
+
+    #[test]
+    fn keeps_coord_type() {
+        let array: PolygonArray<i32> = vec![square(1.), square(3.), square(2.)].into();
+        let array = array.into_coord_type(CoordType::Separated);
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let sorted = array.sort_by_area(options).unwrap();
+        assert_eq!(sorted.coord_type(), CoordType::Separated);
+        assert_eq!(sorted.value_as_geo(0), square(3.));
+        assert_eq!(sorted.value_as_geo(1), square(2.));
+        assert_eq!(sorted.value_as_geo(2), square(1.));
+    }
+
+    #[test]
+    fn sort_by_length() {
+        let array: LineStringArray<i32> = vec![
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+        ]
+        .into();
+        let sorted = array.sort_by_length(SortOptions::default()).unwrap();
+        assert_eq!(
+            sorted.value_as_geo(0),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)]
+        );
+
+        let keys = Int32Array::from(vec![0, 1]);
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let sorted = GeometryArray::LineString(sorted)
+            .sort_by_key(&keys, options)
+            .unwrap();
+        assert_eq!(
+            sorted.value_as_geo(0),
+            geo::Geometry::LineString(line_string![(x: 0., y: 0.), (x: 2., y: 0.)])
+        );
+    }
+
+    #[test]
+    fn length_mismatch() {
+        let array: PointArray = vec![point!(x: 0., y: 0.)].into();
+        let keys = Float64Array::from(vec![1., 2.]);
+        assert!(array.sort_by_key(&keys, SortOptions::default()).is_err());
+    }
+
+    #[test]
+    fn table_across_batches() {
+        let points: PointArray = vec![
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 1.),
+            point!(x: 2., y: 2.),
+        ]
+        .into();
+        let ids: ArrayRef = Arc::new(Int32Array::from(vec![0, 1, 2]));
+        let id_field = Arc::new(arrow_schema::Field::new(
+            "id",
+            arrow_schema::DataType::Int32,
+            false,
+        ));
+        let table = GeoTable::from_arrays(
+            vec![
+                (id_field, ids),
+                points.into_array_ref_with_field("geometry"),
+            ],
+            1,
+        )
+        .unwrap();
+        let (schema, batches, _) = table.into_inner();
+        let batches = vec![batches[0].slice(0, 2), batches[0].slice(2, 1)];
+        let table = GeoTable::try_new(schema, batches, 1).unwrap();
+
+        let keys = Float64Array::from(vec![3., 1., 2.]);
+        let sorted = table.sort_by_key(&keys, SortOptions::default()).unwrap();
+        assert_eq!(sorted.batches().len(), 1);
+        let batch = &sorted.batches()[0];
+        let ids = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(ids.values(), &[1, 2, 0]);
+        let points = PointArray::try_from(batch.column(1).as_ref()).unwrap();
+        assert_eq!(points.value_as_geo(0), point!(x: 1., y: 1.));
+    }
+}
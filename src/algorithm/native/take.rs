@@ -0,0 +1,254 @@
+use arrow::compute::take;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{UInt32Type, UInt64Type};
+use arrow_array::{make_array, new_null_array, Array, ArrayRef, OffsetSizeTrait, UInt64Array};
+use arrow_buffer::NullBuffer;
+use arrow_schema::DataType;
+
+use crate::array::*;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::{FromArrow, GeoArrayAccessor, IntoArrow};
+use crate::GeometryArrayTrait;
+
+/// Gather the geometries of an array at the given indices, like arrow's take kernel.
+///
+/// Indices may come in any order and repeat. The output is always built in new coordinate
+/// buffers and offsets, never as a view of the input, and keeps the coordinate layout and
+/// metadata of the input.
+pub trait Take: Sized {
+    /// Take the geometries at `indices`, a `UInt32Array` or `UInt64Array`. Null indices give null
+    /// geometries.
+    ///
+    /// ```
+    /// use arrow_array::UInt32Array;
+    /// use geoarrow2::algorithm::native::Take;
+    /// use geoarrow2::array::PointArray;
+    /// use geoarrow2::trait_::GeoArrayAccessor;
+    /// use geoarrow2::GeometryArrayTrait;
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![point!(x: 1., y: 1.), point!(x: 2., y: 2.)].into();
+    /// let taken = array.take(&UInt32Array::from(vec![Some(1), None, Some(1)])).unwrap();
+    /// assert_eq!(taken.value_as_geo(0), point!(x: 2., y: 2.));
+    /// assert!(taken.is_null(1));
+    /// assert_eq!(taken.value_as_geo(2), point!(x: 2., y: 2.));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - if `indices` is not a `UInt32Array` or `UInt64Array`
+    /// - if an index is out of bounds
+    fn take(&self, indices: &dyn Array) -> Result<Self>;
+}
+
+/// The indices as `usize`, after checking their type and that they are less than `len`.
+fn checked_indices(indices: &dyn Array, len: usize) -> Result<Vec<Option<usize>>> {
+    let values: Vec<Option<u64>> = match indices.data_type() {
+        DataType::UInt32 => indices
+            .as_primitive::<UInt32Type>()
+            .iter()
+            .map(|maybe_i| maybe_i.map(u64::from))
+            .collect(),
+        DataType::UInt64 => indices.as_primitive::<UInt64Type>().iter().collect(),
+        data_type => {
+            return Err(GeoArrowError::General(format!(
+                "Expected UInt32 or UInt64 indices, found {}",
+                data_type
+            )))
+        }
+    };
+
+    values
+        .into_iter()
+        .map(|maybe_i| {
+            maybe_i
+                .map(|i| match usize::try_from(i) {
+                    Ok(i) if i < len => Ok(i),
+                    _ => Err(GeoArrowError::General(format!(
+                        "Index {} is out of bounds for an array of length {}",
+                        i, len
+                    ))),
+                })
+                .transpose()
+        })
+        .collect()
+}
+
+/// Take `indices` from a storage array with arrow's take kernel.
+///
+/// arrow's kernel panics on null indices into fixed size lists, which store interleaved points
+/// and rects, so there null indices are taken as the first element and nulled afterwards.
+fn take_storage(array: &dyn Array, indices: &[Option<usize>]) -> Result<ArrayRef> {
+    if !matches!(array.data_type(), DataType::FixedSizeList(..)) {
+        let indices: UInt64Array = indices.iter().map(|i| i.map(|i| i as u64)).collect();
+        return Ok(take(array, &indices, None)?);
+    }
+    if array.is_empty() {
+        // All indices are null
+        return Ok(new_null_array(array.data_type(), indices.len()));
+    }
+
+    let values = UInt64Array::from_iter_values(indices.iter().map(|i| i.unwrap_or(0) as u64));
+    let output = take(array, &values, None)?;
+    if indices.iter().all(Option::is_some) {
+        return Ok(output);
+    }
+
+    let index_nulls = NullBuffer::from(indices.iter().map(Option::is_some).collect::<Vec<_>>());
+    let nulls = NullBuffer::union(output.nulls(), Some(&index_nulls));
+    let data = output.to_data().into_builder().nulls(nulls).build()?;
+    Ok(make_array(data))
+}
+
+/// Implementation through arrow's take kernel on the storage array
+macro_rules! take_impl {
+    ($type:ty $(, $generic:ident)?) => {
+        impl$(<$generic: OffsetSizeTrait>)? Take for $type
+        where
+            $type: FromArrow,
+        {
+            fn take(&self, indices: &dyn Array) -> Result<Self> {
+                let indices = checked_indices(indices, self.len())?;
+                let (array, field) = self.clone().into_arrow();
+                let output = take_storage(array.as_ref(), &indices)?;
+                let output = Self::from_arrow(output.as_ref(), &field)?;
+                Ok(output.into_coord_type(self.coord_type()))
+            }
+        }
+    };
+}
+
+take_impl!(PointArray);
+take_impl!(LineStringArray<O>, O);
+take_impl!(PolygonArray<O>, O);
+take_impl!(MultiPointArray<O>, O);
+take_impl!(MultiLineStringArray<O>, O);
+take_impl!(MultiPolygonArray<O>, O);
+take_impl!(RectArray);
+take_impl!(WKBArray<O>, O);
+
+// Mixed and geometry collection arrays are stored as arrow dense unions, which arrow's take kernel
+// doesn't support, so they are rebuilt from geo objects instead
+
+impl<O: OffsetSizeTrait> Take for MixedGeometryArray<O> {
+    fn take(&self, indices: &dyn Array) -> Result<Self> {
+        let output_geoms: Vec<Option<geo::Geometry>> = checked_indices(indices, self.len())?
+            .into_iter()
+            .map(|maybe_i| maybe_i.and_then(|i| self.get_as_geo(i)))
+            .collect();
+
+        let mut output_array = MutableMixedGeometryArray::<O>::new();
+        for maybe_geom in output_geoms.iter() {
+            match maybe_geom {
+                Some(geom) => output_array.push_geometry(geom)?,
+                // A mixed array has no validity of its own, so nulls are stored as null points
+                None => output_array.push_point(None::<&geo::Point>),
+            }
+        }
+        let output: Self = output_array.into();
+        Ok(output.into_coord_type(self.coord_type()))
+    }
+}
+
+impl<O: OffsetSizeTrait> Take for GeometryCollectionArray<O> {
+    fn take(&self, indices: &dyn Array) -> Result<Self> {
+        let output_geoms: Vec<Option<geo::GeometryCollection>> =
+            checked_indices(indices, self.len())?
+                .into_iter()
+                .map(|maybe_i| maybe_i.and_then(|i| self.get_as_geo(i)))
+                .collect();
+        let output: Self = output_geoms.try_into()?;
+        Ok(output.into_coord_type(self.coord_type()))
+    }
+}
+
+impl<O: OffsetSizeTrait> Take for GeometryArray<O>
+where
+    LineStringArray<O>: FromArrow,
+    PolygonArray<O>: FromArrow,
+    MultiPointArray<O>: FromArrow,
+    MultiLineStringArray<O>: FromArrow,
+    MultiPolygonArray<O>: FromArrow,
+{
+    fn take(&self, indices: &dyn Array) -> Result<Self> {
+        Ok(match self {
+            GeometryArray::Point(arr) => GeometryArray::Point(arr.take(indices)?),
+            GeometryArray::LineString(arr) => GeometryArray::LineString(arr.take(indices)?),
+            GeometryArray::Polygon(arr) => GeometryArray::Polygon(arr.take(indices)?),
+            GeometryArray::MultiPoint(arr) => GeometryArray::MultiPoint(arr.take(indices)?),
+            GeometryArray::MultiLineString(arr) => {
+                GeometryArray::MultiLineString(arr.take(indices)?)
+            }
+            GeometryArray::MultiPolygon(arr) => GeometryArray::MultiPolygon(arr.take(indices)?),
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(Box::new(arr.take(indices)?))
+            }
+            GeometryArray::Rect(arr) => GeometryArray::Rect(arr.take(indices)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{linestring, point, polygon};
+    use arrow_array::{Int32Array, UInt32Array, UInt64Array};
+
+    #[test]
+    fn repeated_and_null_indices() {
+        let array: LineStringArray<i32> = vec![linestring::ls0(), linestring::ls1()].into();
+        let array = array.into_coord_type(CoordType::Separated);
+        let indices = UInt64Array::from(vec![Some(1), None, Some(1), Some(0)]);
+
+        let taken = array.take(&indices).unwrap();
+        assert_eq!(taken.len(), 4);
+        assert_eq!(taken.coord_type(), CoordType::Separated);
+        assert_eq!(taken.value_as_geo(0), linestring::ls1());
+        assert!(taken.is_null(1));
+        assert_eq!(taken.value_as_geo(2), linestring::ls1());
+        assert_eq!(taken.value_as_geo(3), linestring::ls0());
+        // The coordinates of repeated geometries are copied, not shared
+        assert_eq!(
+            taken.coords.len(),
+            2 * geo::CoordsIter::coords_count(&linestring::ls1())
+                + geo::CoordsIter::coords_count(&linestring::ls0())
+        );
+    }
+
+    #[test]
+    fn null_indices_into_interleaved_points() {
+        let array: PointArray = vec![point::p0(), point::p1()].into();
+        let taken = array.take(&UInt32Array::from(vec![None, Some(1)])).unwrap();
+        assert!(taken.is_null(0));
+        assert_eq!(taken.value_as_geo(1), point::p1());
+
+        let empty: PointArray = Vec::<geo::Point>::new().into();
+        let taken = empty.take(&UInt32Array::from(vec![None])).unwrap();
+        assert!(taken.is_null(0));
+    }
+
+    #[test]
+    fn mixed() {
+        let array: MixedGeometryArray<i32> = vec![
+            geo::Geometry::Polygon(polygon::p0()),
+            geo::Geometry::LineString(linestring::ls0()),
+        ]
+        .try_into()
+        .unwrap();
+        let taken = array.take(&UInt32Array::from(vec![None, Some(0)])).unwrap();
+        // Mixed arrays store nulls in their children
+        assert!(taken.logical_nulls().unwrap().is_null(0));
+        assert_eq!(taken.value_as_geo(1), array.value_as_geo(0));
+    }
+
+    #[test]
+    fn invalid_indices() {
+        let array: LineStringArray<i32> = vec![linestring::ls0()].into();
+        let err = array.take(&UInt32Array::from(vec![0, 1])).unwrap_err();
+        assert!(err.to_string().contains("Index 1 is out of bounds"));
+        assert!(array.take(&Int32Array::from(vec![0])).is_err());
+
+        let array = GeometryArray::LineString(array);
+        assert!(array.take(&UInt64Array::from(vec![u64::MAX])).is_err());
+    }
+}
@@ -0,0 +1,624 @@
+use std::ops::Range;
+
+use arrow_array::OffsetSizeTrait;
+use arrow_buffer::{NullBuffer, OffsetBuffer, ScalarBuffer};
+
+use crate::array::*;
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+
+/// A transformation of x/y coordinates, such as a reprojection, that
+/// [`transform_geometry_array`] applies to the coordinates of an array.
+///
+/// Coordinates are passed in chunks, so that implementations can amortize the cost of each call
+/// into a projection library. A coordinate that can't be transformed should be set to a
+/// non-finite value such as NaN, or make the whole chunk return an error. Either way, the
+/// geometry it belongs to is handled according to the [`TransformErrorPolicy`].
+///
+/// z and m values are left unchanged.
+pub trait CoordTransform {
+    /// Transform each coordinate `(xs[i], ys[i])` in place. Both slices have the same length.
+    ///
+    /// # Errors
+    ///
+    /// - if a coordinate of the chunk can't be transformed
+    fn transform_chunk(&self, xs: &mut [f64], ys: &mut [f64]) -> Result<()>;
+}
+
+/// Adapts a closure that transforms a single `(x, y)` coordinate into a [`CoordTransform`].
+///
+/// Return a non-finite value for coordinates that can't be transformed.
+///
+/// ```
+/// use geoarrow2::algorithm::native::{transform_geometry_array, FnTransform, TransformOptions};
+/// use geoarrow2::array::PointArray;
+/// use geoarrow2::trait_::GeoArrayAccessor;
+/// use geo::point;
+///
+/// let array: PointArray = vec![point!(x: 1., y: 2.)].into();
+/// let swap = FnTransform(|x, y| (y, x));
+/// let swapped = transform_geometry_array(array, &swap, &TransformOptions::default()).unwrap();
+/// assert_eq!(swapped.value_as_geo(0), point!(x: 2., y: 1.));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FnTransform<F>(pub F);
+
+impl<F: Fn(f64, f64) -> (f64, f64)> CoordTransform for FnTransform<F> {
+    fn transform_chunk(&self, xs: &mut [f64], ys: &mut [f64]) -> Result<()> {
+        for (x, y) in xs.iter_mut().zip(ys.iter_mut()) {
+            (*x, *y) = (self.0)(*x, *y);
+        }
+        Ok(())
+    }
+}
+
+/// What [`transform_geometry_array`] does with a geometry that has a coordinate that can't be
+/// transformed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TransformErrorPolicy {
+    /// Return an error.
+    #[default]
+    Fail,
+
+    /// Replace the geometry with null.
+    Nullify,
+}
+
+/// Options for [`transform_geometry_array`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransformOptions {
+    /// The largest number of coordinates passed to [`CoordTransform::transform_chunk`] at once.
+    pub chunk_size: usize,
+
+    /// What to do with geometries that can't be transformed.
+    pub on_error: TransformErrorPolicy,
+}
+
+impl TransformOptions {
+    pub fn new(chunk_size: usize, on_error: TransformErrorPolicy) -> Self {
+        Self {
+            chunk_size,
+            on_error,
+        }
+    }
+
+    /// Set the largest number of coordinates transformed at once.
+    pub fn with_chunk_size(self, chunk_size: usize) -> Self {
+        Self { chunk_size, ..self }
+    }
+
+    /// Set what to do with geometries that can't be transformed.
+    pub fn with_on_error(self, on_error: TransformErrorPolicy) -> Self {
+        Self { on_error, ..self }
+    }
+}
+
+impl Default for TransformOptions {
+    /// Chunks of 1024 coordinates, failing on the first geometry that can't be transformed.
+    fn default() -> Self {
+        Self::new(1024, TransformErrorPolicy::Fail)
+    }
+}
+
+/// An array whose coordinates can be transformed by [`transform_geometry_array`].
+pub trait TransformCoords: Sized {
+    /// Transform the coordinates of this array. See [`transform_geometry_array`].
+    fn transform_coords<T: CoordTransform + ?Sized>(
+        self,
+        transform: &T,
+        options: &TransformOptions,
+    ) -> Result<Self>;
+}
+
+/// Apply `transform` to the coordinates of every non-null geometry of `array`.
+///
+/// The array is consumed so that its coordinate buffers can be transformed in place when nothing
+/// else references them; otherwise they are copied. 32-bit coordinates are transformed, and
+/// returned, as `f64`, since rounding back to `f32` could lose meters of precision. Null
+/// geometries are left untouched and empty geometries stay empty.
+///
+/// A geometry can't be transformed if a chunk holding one of its coordinates returns an error, or
+/// one of its transformed coordinates is not finite. The geometries that can't be transformed are
+/// handled according to [`TransformOptions::on_error`].
+///
+/// # Errors
+///
+/// - with [`TransformErrorPolicy::Fail`], if a geometry can't be transformed
+/// - for rect and geometry collection arrays
+pub fn transform_geometry_array<A: TransformCoords, T: CoordTransform + ?Sized>(
+    array: A,
+    transform: &T,
+    options: &TransformOptions,
+) -> Result<A> {
+    array.transform_coords(transform, options)
+}
+
+/// Take the values of a buffer, reusing its allocation when nothing else references it.
+fn into_vec(buffer: ScalarBuffer<f64>) -> Vec<f64> {
+    buffer
+        .into_inner()
+        .into_vec()
+        .unwrap_or_else(|buffer| buffer.typed_data::<f64>().to_vec())
+}
+
+/// The x and y values of a coordinate buffer, taken out to be mutated.
+enum XYValues {
+    Interleaved {
+        values: Vec<f64>,
+        dim: Dimension,
+    },
+    Separated {
+        x: Vec<f64>,
+        y: Vec<f64>,
+        z: Option<ScalarBuffer<f64>>,
+        m: Option<ScalarBuffer<f64>>,
+    },
+}
+
+impl XYValues {
+    fn new(coords: CoordBuffer) -> Self {
+        match coords {
+            CoordBuffer::Interleaved(cb) => {
+                let dim = cb.dim();
+                XYValues::Interleaved {
+                    values: into_vec(cb.coords),
+                    dim,
+                }
+            }
+            CoordBuffer::Separated(cb) => XYValues::Separated {
+                x: into_vec(cb.x),
+                y: into_vec(cb.y),
+                z: cb.z,
+                m: cb.m,
+            },
+            CoordBuffer::Float32(cb) => Self::new(CoordBuffer::Interleaved(cb.to_float64())),
+        }
+    }
+
+    fn get(&self, i: usize) -> (f64, f64) {
+        match self {
+            XYValues::Interleaved { values, dim } => {
+                (values[i * dim.size()], values[i * dim.size() + 1])
+            }
+            XYValues::Separated { x, y, .. } => (x[i], y[i]),
+        }
+    }
+
+    fn set(&mut self, i: usize, (new_x, new_y): (f64, f64)) {
+        match self {
+            XYValues::Interleaved { values, dim } => {
+                values[i * dim.size()] = new_x;
+                values[i * dim.size() + 1] = new_y;
+            }
+            XYValues::Separated { x, y, .. } => {
+                x[i] = new_x;
+                y[i] = new_y;
+            }
+        }
+    }
+
+    fn into_coords(self) -> CoordBuffer {
+        match self {
+            XYValues::Interleaved { values, dim } => {
+                CoordBuffer::Interleaved(InterleavedCoordBuffer::new_with_dim(values.into(), dim))
+            }
+            XYValues::Separated { x, y, z, m } => CoordBuffer::Separated(SeparatedCoordBuffer {
+                x: x.into(),
+                y: y.into(),
+                z,
+                m,
+            }),
+        }
+    }
+}
+
+/// A chunk of coordinates gathered from the buffer, to be transformed together
+struct Chunk {
+    coord_indices: Vec<usize>,
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+}
+
+impl Chunk {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            coord_indices: Vec::with_capacity(capacity),
+            xs: Vec::with_capacity(capacity),
+            ys: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn push(&mut self, coord_idx: usize, (x, y): (f64, f64)) {
+        self.coord_indices.push(coord_idx);
+        self.xs.push(x);
+        self.ys.push(y);
+    }
+
+    /// Transform the gathered coordinates and write them back to `values`.
+    fn flush<T: CoordTransform + ?Sized>(
+        &mut self,
+        values: &mut XYValues,
+        transform: &T,
+        on_error: TransformErrorPolicy,
+    ) -> Result<()> {
+        if let Err(err) = transform.transform_chunk(&mut self.xs, &mut self.ys) {
+            if on_error == TransformErrorPolicy::Fail {
+                return Err(err);
+            }
+
+            // Find the coordinates that failed by transforming them one at a time, starting over
+            // from the untouched values in the buffer
+            for (i, coord_idx) in self.coord_indices.iter().enumerate() {
+                let (mut x, mut y) = values.get(*coord_idx);
+                let transformed = transform
+                    .transform_chunk(std::slice::from_mut(&mut x), std::slice::from_mut(&mut y));
+                (self.xs[i], self.ys[i]) = match transformed {
+                    Ok(()) => (x, y),
+                    Err(_) => (f64::NAN, f64::NAN),
+                };
+            }
+        }
+
+        for (i, coord_idx) in self.coord_indices.iter().enumerate() {
+            values.set(*coord_idx, (self.xs[i], self.ys[i]));
+        }
+        self.coord_indices.clear();
+        self.xs.clear();
+        self.ys.clear();
+        Ok(())
+    }
+}
+
+/// Transform the coordinates of the non-null geometries of an array, returning the new
+/// coordinates and validity.
+///
+/// `coord_range` gives the range of coordinates that geometry `i` uses.
+fn transform_coord_buffer<T: CoordTransform + ?Sized>(
+    coords: CoordBuffer,
+    len: usize,
+    nulls: Option<&NullBuffer>,
+    coord_range: impl Fn(usize) -> Range<usize>,
+    transform: &T,
+    options: &TransformOptions,
+) -> Result<(CoordBuffer, Option<NullBuffer>)> {
+    let is_valid = |i: usize| nulls.map_or(true, |nulls| nulls.is_valid(i));
+    let mut values = XYValues::new(coords);
+
+    let chunk_size = options.chunk_size.max(1);
+    let mut chunk = Chunk::with_capacity(chunk_size);
+    for geom_idx in (0..len).filter(|&i| is_valid(i)) {
+        for coord_idx in coord_range(geom_idx) {
+            chunk.push(coord_idx, values.get(coord_idx));
+            if chunk.coord_indices.len() == chunk_size {
+                chunk.flush(&mut values, transform, options.on_error)?;
+            }
+        }
+    }
+    chunk.flush(&mut values, transform, options.on_error)?;
+
+    let failed: Vec<usize> = (0..len)
+        .filter(|&i| {
+            is_valid(i)
+                && coord_range(i).any(|coord_idx| {
+                    let (x, y) = values.get(coord_idx);
+                    !(x.is_finite() && y.is_finite())
+                })
+        })
+        .collect();
+
+    let validity = match failed.first() {
+        None => nulls.cloned(),
+        Some(first_failed) if options.on_error == TransformErrorPolicy::Fail => {
+            return Err(GeoArrowError::General(format!(
+                "Geometry {} has coordinates that could not be transformed",
+                first_failed
+            )));
+        }
+        Some(_) => {
+            let mut validity: Vec<bool> = (0..len).map(is_valid).collect();
+            failed.iter().for_each(|&i| validity[i] = false);
+            Some(NullBuffer::from(validity))
+        }
+    };
+
+    Ok((values.into_coords(), validity))
+}
+
+/// Arrays whose coordinates can be rewritten geometry by geometry, for transforms that can't fail
+/// such as affine ones. Offsets and validity are kept as they are.
+pub(crate) trait MapGeometryCoords {
+    /// Map each coordinate of each non-null geometry with `f(geom_idx, coord)`.
+    fn map_geometry_coords(&self, f: impl Fn(usize, geo::Coord) -> geo::Coord) -> Self;
+}
+
+/// Map the coordinates of the non-null geometries of an array.
+///
+/// `coord_range` gives the range of coordinates that geometry `i` uses.
+fn map_coord_buffer(
+    coords: CoordBuffer,
+    len: usize,
+    nulls: Option<&NullBuffer>,
+    coord_range: impl Fn(usize) -> Range<usize>,
+    f: impl Fn(usize, geo::Coord) -> geo::Coord,
+) -> CoordBuffer {
+    let mut values = XYValues::new(coords);
+    for geom_idx in (0..len).filter(|&i| nulls.map_or(true, |nulls| nulls.is_valid(i))) {
+        for coord_idx in coord_range(geom_idx) {
+            let (x, y) = values.get(coord_idx);
+            let coord = f(geom_idx, geo::Coord { x, y });
+            values.set(coord_idx, (coord.x, coord.y));
+        }
+    }
+    values.into_coords()
+}
+
+/// A placeholder left in an array while its coordinates are being transformed
+fn empty_coords() -> CoordBuffer {
+    CoordBuffer::Interleaved(InterleavedCoordBuffer::new(Vec::<f64>::new().into()))
+}
+
+/// The range of coordinates of each geometry, given the array's offsets from the geometry
+/// level down to the coordinate level.
+pub(crate) fn nested_coord_range<O: OffsetSizeTrait>(
+    offsets: &[&OffsetBuffer<O>],
+    i: usize,
+) -> Range<usize> {
+    let (mut start, mut end) = (i, i + 1);
+    for level in offsets {
+        start = level[start].to_usize().unwrap();
+        end = level[end].to_usize().unwrap();
+    }
+    start..end
+}
+
+impl TransformCoords for PointArray {
+    fn transform_coords<T: CoordTransform + ?Sized>(
+        mut self,
+        transform: &T,
+        options: &TransformOptions,
+    ) -> Result<Self> {
+        let len = self.len();
+        let coords = std::mem::replace(&mut self.coords, empty_coords());
+        let (coords, validity) = transform_coord_buffer(
+            coords,
+            len,
+            self.validity.as_ref(),
+            |i| i..i + 1,
+            transform,
+            options,
+        )?;
+        self.coords = coords;
+        self.validity = validity;
+        Ok(self)
+    }
+}
+
+impl MapGeometryCoords for PointArray {
+    fn map_geometry_coords(&self, f: impl Fn(usize, geo::Coord) -> geo::Coord) -> Self {
+        let mut output = self.clone();
+        let coords = std::mem::replace(&mut output.coords, empty_coords());
+        output.coords =
+            map_coord_buffer(coords, self.len(), self.validity.as_ref(), |i| i..i + 1, f);
+        output
+    }
+}
+
+macro_rules! transform_impl {
+    ($type:ty, $($offsets:ident),+) => {
+        impl<O: OffsetSizeTrait> TransformCoords for $type {
+            fn transform_coords<T: CoordTransform + ?Sized>(
+                mut self,
+                transform: &T,
+                options: &TransformOptions,
+            ) -> Result<Self> {
+                let len = self.len();
+                let coords = std::mem::replace(&mut self.coords, empty_coords());
+                let offsets = [$(&self.$offsets),+];
+                let (coords, validity) = transform_coord_buffer(
+                    coords,
+                    len,
+                    self.validity.as_ref(),
+                    |i| nested_coord_range(&offsets, i),
+                    transform,
+                    options,
+                )?;
+                self.coords = coords;
+                self.validity = validity;
+                Ok(self)
+            }
+        }
+
+        impl<O: OffsetSizeTrait> MapGeometryCoords for $type {
+            fn map_geometry_coords(&self, f: impl Fn(usize, geo::Coord) -> geo::Coord) -> Self {
+                let mut output = self.clone();
+                let coords = std::mem::replace(&mut output.coords, empty_coords());
+                let offsets = [$(&self.$offsets),+];
+                output.coords = map_coord_buffer(
+                    coords,
+                    self.len(),
+                    self.validity.as_ref(),
+                    |i| nested_coord_range(&offsets, i),
+                    f,
+                );
+                output
+            }
+        }
+    };
+}
+
+transform_impl!(LineStringArray<O>, geom_offsets);
+transform_impl!(PolygonArray<O>, geom_offsets, ring_offsets);
+transform_impl!(MultiPointArray<O>, geom_offsets);
+transform_impl!(MultiLineStringArray<O>, geom_offsets, ring_offsets);
+transform_impl!(
+    MultiPolygonArray<O>,
+    geom_offsets,
+    polygon_offsets,
+    ring_offsets
+);
+
+impl<O: OffsetSizeTrait> TransformCoords for GeometryArray<O> {
+    fn transform_coords<T: CoordTransform + ?Sized>(
+        self,
+        transform: &T,
+        options: &TransformOptions,
+    ) -> Result<Self> {
+        Ok(match self {
+            GeometryArray::Point(arr) => {
+                GeometryArray::Point(arr.transform_coords(transform, options)?)
+            }
+            GeometryArray::LineString(arr) => {
+                GeometryArray::LineString(arr.transform_coords(transform, options)?)
+            }
+            GeometryArray::Polygon(arr) => {
+                GeometryArray::Polygon(arr.transform_coords(transform, options)?)
+            }
+            GeometryArray::MultiPoint(arr) => {
+                GeometryArray::MultiPoint(arr.transform_coords(transform, options)?)
+            }
+            GeometryArray::MultiLineString(arr) => {
+                GeometryArray::MultiLineString(arr.transform_coords(transform, options)?)
+            }
+            GeometryArray::MultiPolygon(arr) => {
+                GeometryArray::MultiPolygon(arr.transform_coords(transform, options)?)
+            }
+            // The corners of a transformed rect are generally not the corners of the transformed
+            // area, so rects are not transformed coordinate by coordinate
+            GeometryArray::Rect(_) => {
+                return Err(GeoArrowError::General(
+                    "Rect arrays can't be transformed coordinate by coordinate".to_string(),
+                ))
+            }
+            GeometryArray::GeometryCollection(_) => {
+                return Err(GeoArrowError::General(
+                    "Transforming geometry collection arrays is not supported".to_string(),
+                ))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeoArrayAccessor;
+    use geo::{line_string, point};
+    use std::cell::Cell;
+
+    /// Shifts coordinates, failing the whole chunk if one of them has a negative x
+    struct ShiftNonNegative {
+        calls: Cell<usize>,
+    }
+
+    impl CoordTransform for ShiftNonNegative {
+        fn transform_chunk(&self, xs: &mut [f64], ys: &mut [f64]) -> Result<()> {
+            self.calls.set(self.calls.get() + 1);
+            for (x, y) in xs.iter_mut().zip(ys.iter_mut()) {
+                if *x < 0. {
+                    return Err(GeoArrowError::General("negative x".to_string()));
+                }
+                (*x, *y) = (*x + 10., *y + 20.);
+            }
+            Ok(())
+        }
+    }
+
+    fn line_strings() -> LineStringArray<i32> {
+        vec![
+            Some(line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)]),
+            None,
+            Some(line_string![(x: -1., y: 0.), (x: 1., y: 0.)]),
+            Some(line_string![(x: 3., y: 3.), (x: 4., y: 4.)]),
+        ]
+        .into()
+    }
+
+    #[test]
+    fn chunks_and_error_policies() {
+        let shift = ShiftNonNegative {
+            calls: Cell::new(0),
+        };
+        let options = TransformOptions::default().with_chunk_size(2);
+        assert!(transform_geometry_array(line_strings(), &shift, &options).is_err());
+
+        shift.calls.set(0);
+        let options = options.with_on_error(TransformErrorPolicy::Nullify);
+        let output = transform_geometry_array(line_strings(), &shift, &options).unwrap();
+        assert_eq!(
+            output.value_as_geo(0),
+            line_string![(x: 10., y: 20.), (x: 11., y: 21.), (x: 12., y: 22.)]
+        );
+        assert!(output.is_null(1));
+        assert!(output.is_null(2));
+        assert_eq!(
+            output.value_as_geo(3),
+            line_string![(x: 13., y: 23.), (x: 14., y: 24.)]
+        );
+        // Four chunks of the seven coordinates of valid geometries, and one call for each of the
+        // two coordinates of the failed chunk
+        assert_eq!(shift.calls.get(), 4 + 2);
+    }
+
+    #[test]
+    fn closure_keeps_layout() {
+        let array: MultiPointArray<i32> = vec![
+            Some(geo::MultiPoint(vec![
+                point!(x: 1., y: 2.),
+                point!(x: 3., y: 4.),
+            ])),
+            Some(geo::MultiPoint(vec![])),
+        ]
+        .into();
+        let array = array.into_coord_type(CoordType::Separated);
+        let double = FnTransform(|x, y| (x * 2., y * 2.));
+        let output =
+            transform_geometry_array(array, &double, &TransformOptions::default()).unwrap();
+        assert_eq!(output.coord_type(), CoordType::Separated);
+        assert_eq!(
+            output.value_as_geo(0),
+            geo::MultiPoint(vec![point!(x: 2., y: 4.), point!(x: 6., y: 8.)])
+        );
+        assert!(output.is_valid(1));
+
+        let mut coords =
+            MutableCoordBuffer::with_capacity_and_dim(1, CoordType::Interleaved, Dimension::XYZ);
+        coords.push_xyzm(1., 2., 3., 4.);
+        let points = PointArray::new(coords.into(), None);
+        let output =
+            transform_geometry_array(points, &double, &TransformOptions::default()).unwrap();
+        assert_eq!(output.coords.get_x(0), 2.);
+        assert_eq!(output.coords.get_y(0), 4.);
+        assert_eq!(output.coords.get_z(0), Some(3.));
+    }
+
+    #[test]
+    fn non_finite_output_fails() {
+        let array: PointArray = vec![point!(x: 1., y: 2.), point!(x: -1., y: 2.)].into();
+        let sqrt = FnTransform(|x: f64, y: f64| (x.sqrt(), y));
+        let err = transform_geometry_array(array.clone(), &sqrt, &TransformOptions::default())
+            .unwrap_err();
+        assert!(err.to_string().contains("Geometry 1"));
+
+        let options = TransformOptions::default().with_on_error(TransformErrorPolicy::Nullify);
+        let output = transform_geometry_array(array, &sqrt, &options).unwrap();
+        assert!(output.is_valid(0));
+        assert!(output.is_null(1));
+    }
+
+    #[test]
+    fn in_place_when_unique() {
+        let array: PointArray = vec![point!(x: 1., y: 2.), point!(x: 3., y: 4.)].into();
+        let CoordBuffer::Interleaved(cb) = &array.coords else {
+            unreachable!()
+        };
+        let ptr = cb.coords.as_ptr();
+
+        let shift = FnTransform(|x, y| (x + 1., y));
+        let output = transform_geometry_array(array, &shift, &TransformOptions::default()).unwrap();
+        let CoordBuffer::Interleaved(cb) = &output.coords else {
+            unreachable!()
+        };
+        assert_eq!(cb.coords.as_ptr(), ptr);
+        assert_eq!(output.value_as_geo(1), point!(x: 4., y: 4.));
+    }
+}
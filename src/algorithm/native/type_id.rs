@@ -73,6 +73,7 @@ constant_impl!(PolygonArray<O>, 3);
 constant_impl!(MultiPointArray<O>, 4);
 constant_impl!(MultiLineStringArray<O>, 5);
 constant_impl!(MultiPolygonArray<O>, 6);
+constant_impl!(GeometryCollectionArray<O>, 7);
 
 impl<O: OffsetSizeTrait> TypeIds for MixedGeometryArray<O> {
     fn get_type_ids(&self) -> Int8Array {
@@ -88,6 +89,7 @@ impl<O: OffsetSizeTrait> TypeIds for MixedGeometryArray<O> {
                 MultiPoint(_) => 4,
                 MultiLineString(_) => 5,
                 MultiPolygon(_) => 6,
+                GeometryCollection(_) => 7,
             }))
         });
         output_array.finish()
@@ -106,6 +108,7 @@ impl<O: OffsetSizeTrait> TypeIds for MixedGeometryArray<O> {
                 MultiPoint(_) => 4,
                 MultiLineString(_) => 5,
                 MultiPolygon(_) => 6,
+                GeometryCollection(_) => 7,
             };
             values.insert(type_id);
         });
@@ -0,0 +1,444 @@
+use std::f64::consts::PI;
+use std::ops::Range;
+
+use arrow_array::OffsetSizeTrait;
+use arrow_buffer::{NullBuffer, ScalarBuffer};
+use geo::{CoordsIter, MapCoords};
+use serde_json::json;
+
+use crate::algorithm::native::transform::{
+    transform_geometry_array, CoordTransform, TransformErrorPolicy, TransformOptions,
+};
+use crate::array::metadata::{ArrayMetadata, Edges};
+use crate::array::*;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeoArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// The radius of the sphere used by Web Mercator, in meters.
+const RADIUS: f64 = 6_378_137.;
+
+/// The latitude at which Web Mercator's square extent ends, about 85.0511°.
+pub const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// What [`WebMercator::to_web_mercator`] does with geometries that have coordinates outside the
+/// range of longitude and latitude.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutOfRangePolicy {
+    /// Return an error.
+    #[default]
+    Error,
+
+    /// Replace the geometry with null.
+    Null,
+}
+
+/// Convert between longitude/latitude (EPSG:4326) and Web Mercator (EPSG:3857) coordinates.
+///
+/// This uses the closed-form spherical Mercator formulas, so it needs neither PROJ nor any other
+/// feature flag. Both directions set the `crs` of the array metadata to the target CRS, and set
+/// its edges to planar.
+pub trait WebMercator: Sized {
+    /// Project longitude/latitude coordinates in degrees to Web Mercator coordinates in meters.
+    ///
+    /// Latitudes beyond ±[`MAX_LATITUDE`], which Web Mercator can't show, are clamped to it.
+    /// Coordinates with a latitude beyond ±90° or a non-finite value are out of range, and
+    /// handled according to `out_of_range`.
+    ///
+    /// # Errors
+    ///
+    /// - if a geometry is out of range and `out_of_range` is [`OutOfRangePolicy::Error`]
+    fn to_web_mercator(&self, out_of_range: OutOfRangePolicy) -> Result<Self>;
+
+    /// Convert Web Mercator coordinates in meters to longitude/latitude coordinates in degrees.
+    ///
+    /// Geometries with a non-finite coordinate become null.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_web_mercator(&self) -> Self;
+}
+
+fn forward(lon: f64, lat: f64) -> (f64, f64) {
+    let lat = lat.clamp(-MAX_LATITUDE, MAX_LATITUDE);
+    let x = RADIUS * lon.to_radians();
+    let y = RADIUS * (PI / 4. + lat.to_radians() / 2.).tan().ln();
+    (x, y)
+}
+
+fn inverse(x: f64, y: f64) -> (f64, f64) {
+    let lon = (x / RADIUS).to_degrees();
+    let lat = (2. * (y / RADIUS).exp().atan() - PI / 2.).to_degrees();
+    (lon, lat)
+}
+
+fn in_range(lon: f64, lat: f64) -> bool {
+    lon.is_finite() && lat.is_finite() && lat.abs() <= 90.
+}
+
+/// The forward formulas, setting coordinates that are out of range to NaN
+struct ToWebMercator;
+
+impl CoordTransform for ToWebMercator {
+    fn transform_chunk(&self, xs: &mut [f64], ys: &mut [f64]) -> Result<()> {
+        for (x, y) in xs.iter_mut().zip(ys.iter_mut()) {
+            (*x, *y) = if in_range(*x, *y) {
+                forward(*x, *y)
+            } else {
+                (f64::NAN, f64::NAN)
+            };
+        }
+        Ok(())
+    }
+}
+
+/// The inverse formulas
+struct FromWebMercator;
+
+impl CoordTransform for FromWebMercator {
+    fn transform_chunk(&self, xs: &mut [f64], ys: &mut [f64]) -> Result<()> {
+        for (x, y) in xs.iter_mut().zip(ys.iter_mut()) {
+            (*x, *y) = inverse(*x, *y);
+        }
+        Ok(())
+    }
+}
+
+fn transform_options(out_of_range: OutOfRangePolicy) -> TransformOptions {
+    let on_error = match out_of_range {
+        OutOfRangePolicy::Error => TransformErrorPolicy::Fail,
+        OutOfRangePolicy::Null => TransformErrorPolicy::Nullify,
+    };
+    TransformOptions::default().with_on_error(on_error)
+}
+
+/// Check the coordinates of every valid geometry, returning the new validity.
+///
+/// `coord_range` gives the range of coordinates that geometry `i` uses.
+fn check_range(
+    len: usize,
+    nulls: Option<&NullBuffer>,
+    coords: &CoordBuffer,
+    coord_range: impl Fn(usize) -> Range<usize>,
+    out_of_range: OutOfRangePolicy,
+) -> Result<Option<NullBuffer>> {
+    let is_valid = |i: usize| nulls.map_or(true, |nulls| nulls.is_valid(i));
+    let invalid: Vec<usize> = (0..len)
+        .filter(|&i| {
+            is_valid(i)
+                && coord_range(i)
+                    .any(|coord_idx| !in_range(coords.get_x(coord_idx), coords.get_y(coord_idx)))
+        })
+        .collect();
+
+    let Some(first_invalid) = invalid.first() else {
+        return Ok(nulls.cloned());
+    };
+    if out_of_range == OutOfRangePolicy::Error {
+        return Err(out_of_range_error(*first_invalid));
+    }
+
+    let mut validity: Vec<bool> = (0..len).map(is_valid).collect();
+    invalid.iter().for_each(|&i| validity[i] = false);
+    Ok(Some(NullBuffer::from(validity)))
+}
+
+fn out_of_range_error(geom_idx: usize) -> GeoArrowError {
+    GeoArrowError::General(format!(
+        "Geometry {} has coordinates outside the range of longitude and latitude",
+        geom_idx
+    ))
+}
+
+fn projected_metadata(crs: &str) -> ArrayMetadata {
+    ArrayMetadata::new(Edges::Planar).with_crs(Some(json!(crs)))
+}
+
+macro_rules! web_mercator_impl {
+    ($type:ty $(, $generic:ident)?) => {
+        impl$(<$generic: OffsetSizeTrait>)? WebMercator for $type {
+            fn to_web_mercator(&self, out_of_range: OutOfRangePolicy) -> Result<Self> {
+                let output = transform_geometry_array(
+                    self.clone(),
+                    &ToWebMercator,
+                    &transform_options(out_of_range),
+                )?;
+                Ok(output.with_metadata(projected_metadata("EPSG:3857")))
+            }
+
+            fn from_web_mercator(&self) -> Self {
+                // Nullifying geometries never fails
+                let output = transform_geometry_array(
+                    self.clone(),
+                    &FromWebMercator,
+                    &transform_options(OutOfRangePolicy::Null),
+                )
+                .unwrap();
+                output.with_metadata(projected_metadata("EPSG:4326"))
+            }
+        }
+    };
+}
+
+web_mercator_impl!(PointArray);
+web_mercator_impl!(LineStringArray<O>, O);
+web_mercator_impl!(PolygonArray<O>, O);
+web_mercator_impl!(MultiPointArray<O>, O);
+web_mercator_impl!(MultiLineStringArray<O>, O);
+web_mercator_impl!(MultiPolygonArray<O>, O);
+
+/// Map the corners of each rect. Both conversions preserve the order of coordinates, so the
+/// corners stay the minimum and maximum.
+fn map_rects(
+    arr: &RectArray,
+    f: impl Fn(f64, f64) -> (f64, f64),
+    validity: Option<NullBuffer>,
+) -> RectArray {
+    let mut values = Vec::with_capacity(arr.len() * 4);
+    for i in 0..arr.len() {
+        let rect = arr.value_as_geo(i);
+        let (minx, miny) = f(rect.min().x, rect.min().y);
+        let (maxx, maxy) = f(rect.max().x, rect.max().y);
+        values.extend_from_slice(&[minx, miny, maxx, maxy]);
+    }
+    RectArray::new(ScalarBuffer::from(values), validity)
+}
+
+impl WebMercator for RectArray {
+    fn to_web_mercator(&self, out_of_range: OutOfRangePolicy) -> Result<Self> {
+        let corners: Vec<f64> = (0..self.len())
+            .flat_map(|i| {
+                let rect = self.value_as_geo(i);
+                [rect.min().x, rect.min().y, rect.max().x, rect.max().y]
+            })
+            .collect();
+        let corners = CoordBuffer::Interleaved(InterleavedCoordBuffer::new(corners.into()));
+        let validity = check_range(
+            self.len(),
+            self.nulls(),
+            &corners,
+            |i| 2 * i..2 * i + 2,
+            out_of_range,
+        )?;
+        Ok(map_rects(self, forward, validity))
+    }
+
+    fn from_web_mercator(&self) -> Self {
+        map_rects(self, inverse, self.nulls().cloned())
+    }
+}
+
+/// Map each coordinate of each collection, which is null if any mapped coordinate is not finite.
+///
+/// Collections store their members in a mixed array, so they are mapped through geo objects.
+fn map_collections<O: OffsetSizeTrait>(
+    arr: &GeometryCollectionArray<O>,
+    f: impl Fn(f64, f64) -> (f64, f64),
+) -> GeometryCollectionArray<O> {
+    let output_geoms: Vec<Option<geo::GeometryCollection>> = arr
+        .iter_geo()
+        .map(|maybe_g| {
+            let g = maybe_g?.map_coords(|coord| f(coord.x, coord.y).into());
+            let is_finite = g
+                .coords_iter()
+                .all(|coord| coord.x.is_finite() && coord.y.is_finite());
+            is_finite.then_some(g)
+        })
+        .collect();
+
+    // Mapping coordinates can't nest the collections read from this array
+    let output: GeometryCollectionArray<O> = output_geoms.try_into().unwrap();
+    output.into_coord_type(arr.coord_type())
+}
+
+/// Geometry collection arrays have no metadata, so their `crs` is not set.
+impl<O: OffsetSizeTrait> WebMercator for GeometryCollectionArray<O> {
+    fn to_web_mercator(&self, out_of_range: OutOfRangePolicy) -> Result<Self> {
+        let first_invalid = self.iter_geo().enumerate().find_map(|(i, maybe_g)| {
+            let g = maybe_g?;
+            let is_in_range = g.coords_iter().all(|coord| in_range(coord.x, coord.y));
+            (!is_in_range).then_some(i)
+        });
+        if let (Some(i), OutOfRangePolicy::Error) = (first_invalid, out_of_range) {
+            return Err(out_of_range_error(i));
+        }
+
+        // Out of range coordinates become NaN, which makes their collection null
+        Ok(map_collections(self, |x, y| {
+            if in_range(x, y) {
+                forward(x, y)
+            } else {
+                (f64::NAN, f64::NAN)
+            }
+        }))
+    }
+
+    fn from_web_mercator(&self) -> Self {
+        map_collections(self, inverse)
+    }
+}
+
+impl<O: OffsetSizeTrait> WebMercator for GeometryArray<O> {
+    fn to_web_mercator(&self, out_of_range: OutOfRangePolicy) -> Result<Self> {
+        let result = match self {
+            GeometryArray::Point(arr) => GeometryArray::Point(arr.to_web_mercator(out_of_range)?),
+            GeometryArray::LineString(arr) => {
+                GeometryArray::LineString(arr.to_web_mercator(out_of_range)?)
+            }
+            GeometryArray::Polygon(arr) => {
+                GeometryArray::Polygon(arr.to_web_mercator(out_of_range)?)
+            }
+            GeometryArray::MultiPoint(arr) => {
+                GeometryArray::MultiPoint(arr.to_web_mercator(out_of_range)?)
+            }
+            GeometryArray::MultiLineString(arr) => {
+                GeometryArray::MultiLineString(arr.to_web_mercator(out_of_range)?)
+            }
+            GeometryArray::MultiPolygon(arr) => {
+                GeometryArray::MultiPolygon(arr.to_web_mercator(out_of_range)?)
+            }
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(Box::new(arr.to_web_mercator(out_of_range)?))
+            }
+            GeometryArray::Rect(arr) => GeometryArray::Rect(arr.to_web_mercator(out_of_range)?),
+        };
+        Ok(result)
+    }
+
+    fn from_web_mercator(&self) -> Self {
+        match self {
+            GeometryArray::Point(arr) => GeometryArray::Point(arr.from_web_mercator()),
+            GeometryArray::LineString(arr) => GeometryArray::LineString(arr.from_web_mercator()),
+            GeometryArray::Polygon(arr) => GeometryArray::Polygon(arr.from_web_mercator()),
+            GeometryArray::MultiPoint(arr) => GeometryArray::MultiPoint(arr.from_web_mercator()),
+            GeometryArray::MultiLineString(arr) => {
+                GeometryArray::MultiLineString(arr.from_web_mercator())
+            }
+            GeometryArray::MultiPolygon(arr) => {
+                GeometryArray::MultiPolygon(arr.from_web_mercator())
+            }
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(Box::new(arr.from_web_mercator()))
+            }
+            GeometryArray::Rect(arr) => GeometryArray::Rect(arr.from_web_mercator()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use geo::{line_string, point, polygon};
+
+    /// The largest coordinate of Web Mercator's square extent, as computed by PROJ.
+    const EXTENT: f64 = 20_037_508.342_789_244;
+
+    #[test]
+    fn matches_proj() {
+        // Reference values from `cs2cs EPSG:4326 EPSG:3857`, which takes latitude first
+        let cases = [
+            ((1., 0.), (111_319.490_793_273_57, 0.)),
+            ((0., 1.), (0., 111_325.142_866_385_1)),
+            ((180., MAX_LATITUDE), (EXTENT, EXTENT)),
+            ((-180., -MAX_LATITUDE), (-EXTENT, -EXTENT)),
+        ];
+        let points: PointArray = cases
+            .iter()
+            .map(|((lon, lat), _)| Some(point!(x: *lon, y: *lat)))
+            .collect::<Vec<_>>()
+            .into();
+
+        let projected = points.to_web_mercator(OutOfRangePolicy::Error).unwrap();
+        for (i, (_, (x, y))) in cases.iter().enumerate() {
+            let point = projected.value_as_geo(i);
+            assert!((point.x() - x).abs() < 1e-6, "{} != {}", point.x(), x);
+            assert!((point.y() - y).abs() < 1e-6, "{} != {}", point.y(), y);
+        }
+        assert_eq!(projected.metadata().crs, Some(json!("EPSG:3857")));
+
+        let round_trip = projected.from_web_mercator();
+        for (i, ((lon, lat), _)) in cases.iter().enumerate() {
+            let point = round_trip.value_as_geo(i);
+            assert!((point.x() - lon).abs() < 1e-9);
+            assert!((point.y() - lat).abs() < 1e-9);
+        }
+        assert_eq!(round_trip.metadata().crs, Some(json!("EPSG:4326")));
+    }
+
+    #[test]
+    fn clamps_and_handles_out_of_range() {
+        let polygons: PolygonArray<i32> = vec![
+            Some(polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 89.), (x: 0., y: 89.)]),
+            Some(polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 95.)]),
+            None,
+        ]
+        .into();
+
+        assert!(polygons.to_web_mercator(OutOfRangePolicy::Error).is_err());
+
+        let projected = polygons.to_web_mercator(OutOfRangePolicy::Null).unwrap();
+        assert!(projected.is_valid(0));
+        assert!(projected.is_null(1));
+        assert!(projected.is_null(2));
+        let max_y = projected
+            .value_as_geo(0)
+            .exterior()
+            .coords()
+            .map(|coord| coord.y)
+            .fold(f64::MIN, f64::max);
+        assert!((max_y - EXTENT).abs() < 1e-6);
+
+        let line_strings: LineStringArray<i32> =
+            vec![Some(line_string![(x: 0., y: 0.), (x: f64::NAN, y: 0.)])].into();
+        let err = line_strings
+            .to_web_mercator(OutOfRangePolicy::Error)
+            .unwrap_err();
+        assert!(err.to_string().contains("Geometry 0"));
+    }
+
+    #[test]
+    fn geometry_collections_round_trip() {
+        let collection = geo::GeometryCollection::new_from(vec![
+            point!(x: 10., y: 20.).into(),
+            line_string![(x: -120., y: 45.), (x: 170., y: -60.)].into(),
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)].into(),
+        ]);
+        let out_of_range = geo::GeometryCollection::new_from(vec![
+            point!(x: 0., y: 0.).into(),
+            point!(x: 0., y: 95.).into(),
+        ]);
+        let collections: GeometryCollectionArray<i32> =
+            vec![Some(collection.clone()), None, Some(out_of_range)]
+                .try_into()
+                .unwrap();
+        let collections = GeometryArray::GeometryCollection(Box::new(collections));
+
+        let err = collections
+            .to_web_mercator(OutOfRangePolicy::Error)
+            .unwrap_err();
+        assert!(err.to_string().contains("Geometry 2"));
+
+        let projected = collections.to_web_mercator(OutOfRangePolicy::Null).unwrap();
+        let GeometryArray::GeometryCollection(projected) = projected else {
+            panic!("expected a geometry collection array");
+        };
+        let (x, y) = forward(10., 20.);
+        assert_eq!(
+            projected.value_as_geo(0).0[0],
+            geo::Geometry::Point(point!(x: x, y: y))
+        );
+        assert!(projected.is_null(1));
+        assert!(projected.is_null(2));
+
+        let round_trip = projected.from_web_mercator();
+        let expected = collection.coords_iter();
+        let actual = round_trip.value_as_geo(0);
+        for (actual, expected) in actual.coords_iter().zip(expected) {
+            assert!((actual.x - expected.x).abs() < 1e-9);
+            assert!((actual.y - expected.y).abs() < 1e-9);
+        }
+        assert_eq!(
+            round_trip.value_as_geo(0).coords_count(),
+            collection.coords_count()
+        );
+        assert!(round_trip.is_null(1));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::array::*;
+use crate::GeometryArrayTrait;
+use arrow_array::OffsetSizeTrait;
+use arrow_buffer::OffsetBuffer;
+
+/// Reverse the coordinate order of every ring, which flips its winding order.
+///
+/// The rings are reversed in place in a copy of the coordinate buffer and the offsets are reused
+/// as they are, so this is much cheaper than orienting each ring with [`geo::Orient`] when every
+/// ring is known to be wound the wrong way.
+pub trait FlipWinding {
+    fn flip_winding(&self) -> Self;
+}
+
+/// Copy `coords` with the coordinates between each pair of consecutive ring offsets reversed.
+fn reverse_rings<O: OffsetSizeTrait>(
+    coords: &CoordBuffer,
+    ring_offsets: &OffsetBuffer<O>,
+) -> CoordBuffer {
+    let mut indices: Vec<usize> = (0..coords.len()).collect();
+    for ring in ring_offsets.windows(2) {
+        indices[ring[0].as_usize()..ring[1].as_usize()].reverse();
+    }
+
+    match coords {
+        CoordBuffer::Interleaved(cb) => {
+            let mut values = Vec::with_capacity(cb.coords.len());
+            for i in indices {
+                values.extend_from_slice(&cb.coords[i * 2..i * 2 + 2]);
+            }
+            CoordBuffer::Interleaved(InterleavedCoordBuffer::new(values.into()))
+        }
+        CoordBuffer::Separated(cb) => {
+            let x: Vec<f64> = indices.iter().map(|i| cb.x[*i]).collect();
+            let y: Vec<f64> = indices.iter().map(|i| cb.y[*i]).collect();
+            CoordBuffer::Separated(SeparatedCoordBuffer::new(x.into(), y.into()))
+        }
+        CoordBuffer::Float32(cb) => {
+            let size = cb.dim().size();
+            let mut values = Vec::with_capacity(cb.coords.len());
+            for i in indices {
+                values.extend_from_slice(&cb.coords[i * size..(i + 1) * size]);
+            }
+            CoordBuffer::Float32(Float32CoordBuffer::new_with_dim(values.into(), cb.dim()))
+        }
+    }
+}
+
+macro_rules! flip_winding_impl {
+    ($type:ty) => {
+        impl<O: OffsetSizeTrait> FlipWinding for $type {
+            fn flip_winding(&self) -> Self {
+                self.clone()
+                    .with_coords(reverse_rings(&self.coords, &self.ring_offsets))
+            }
+        }
+    };
+}
+
+flip_winding_impl!(PolygonArray<O>);
+flip_winding_impl!(MultiPolygonArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::multipolygon;
+    use crate::trait_::GeoArrayAccessor;
+    use geo::{Orient, Winding};
+
+    #[test]
+    fn flip_twice() {
+        let arr: MultiPolygonArray<i32> =
+            vec![Some(multipolygon::mp0()), None, Some(multipolygon::mp1())].into();
+        let flipped = arr.flip_winding();
+        assert!(flipped.is_null(1));
+
+        for i in [0, 2] {
+            let original = arr.value_as_geo(i);
+            let flipped = flipped.value_as_geo(i);
+            for (original, flipped) in original.iter().zip(flipped.iter()) {
+                let mut reversed: Vec<_> = original.exterior().0.clone();
+                reversed.reverse();
+                assert_eq!(flipped.exterior().0, reversed);
+                assert_eq!(flipped.exterior().is_cw(), !original.exterior().is_cw());
+            }
+            // Orienting doesn't depend on the input winding
+            assert_eq!(
+                flipped.orient(geo::orient::Direction::Default),
+                original.orient(geo::orient::Direction::Default)
+            );
+        }
+
+        let flipped_twice = flipped.flip_winding();
+        for i in [0, 2] {
+            assert_eq!(flipped_twice.value_as_geo(i), arr.value_as_geo(i));
+        }
+        assert_eq!(flipped_twice.coords, arr.coords);
+    }
+}
@@ -1,36 +1,75 @@
+use crate::algorithm::native::transform::{
+    transform_geometry_array, CoordTransform, TransformErrorPolicy, TransformOptions,
+};
 use crate::array::*;
 use crate::error::Result;
 use crate::GeometryArrayTrait;
-use proj::{Proj, Transform};
+use arrow_array::OffsetSizeTrait;
+use proj::Proj;
 
 /// Reproject an array using PROJ
 pub trait Reproject {
+    /// Reproject an array, failing if PROJ can't transform a coordinate of a non-null geometry or
+    /// the result isn't finite.
     fn reproject(&self, proj: &Proj) -> Result<Self>
     where
         Self: Sized;
+
+    /// Reproject an array, nulling out the geometries that can't be transformed instead of
+    /// failing.
+    ///
+    /// A geometry fails if PROJ can't transform any one of its coordinates or the result isn't
+    /// finite, such as for a coordinate outside the domain of the target projection. Returns the
+    /// reprojected array and the indices of the geometries that failed, in ascending order. Null
+    /// input geometries stay null and are not reported.
+    fn reproject_with_errors(&self, proj: &Proj) -> (Self, Vec<usize>)
+    where
+        Self: Sized;
 }
 
-impl Reproject for PointArray {
-    fn reproject(&self, proj: &Proj) -> Result<Self> {
-        let mut output_array = MutablePointArray::with_capacity(self.len());
+impl CoordTransform for Proj {
+    fn transform_chunk(&self, xs: &mut [f64], ys: &mut [f64]) -> Result<()> {
+        for (x, y) in xs.iter_mut().zip(ys.iter_mut()) {
+            let converted: geo::Coord = self.convert(geo::Coord { x: *x, y: *y })?;
+            (*x, *y) = (converted.x, converted.y);
+        }
+        Ok(())
+    }
+}
 
-        for maybe_geom in self.iter_geo() {
-            if let Some(mut geom) = maybe_geom {
-                geom.transform(proj)?;
-                output_array.push_point(Some(&geom));
-            } else {
-                output_array.push_null()
+macro_rules! reproject_impl {
+    ($type:ty $(, $generic:ident)?) => {
+        impl$(<$generic: OffsetSizeTrait>)? Reproject for $type {
+            fn reproject(&self, proj: &Proj) -> Result<Self> {
+                transform_geometry_array(self.clone(), proj, &TransformOptions::default())
             }
-        }
 
-        Ok(output_array.into())
-    }
+            fn reproject_with_errors(&self, proj: &Proj) -> (Self, Vec<usize>) {
+                let options =
+                    TransformOptions::default().with_on_error(TransformErrorPolicy::Nullify);
+                // Nullifying geometries never fails
+                let output = transform_geometry_array(self.clone(), proj, &options).unwrap();
+                let failed = (0..self.len())
+                    .filter(|&i| self.is_valid(i) && output.is_null(i))
+                    .collect();
+                (output, failed)
+            }
+        }
+    };
 }
 
+reproject_impl!(PointArray);
+reproject_impl!(LineStringArray<O>, O);
+reproject_impl!(PolygonArray<O>, O);
+reproject_impl!(MultiPointArray<O>, O);
+reproject_impl!(MultiLineStringArray<O>, O);
+reproject_impl!(MultiPolygonArray<O>, O);
+
 #[cfg(test)]
 mod test {
     use crate::trait_::GeoArrayAccessor;
     use approx::assert_relative_eq;
+    use geo::line_string;
 
     use super::*;
     use crate::test::point::{p0, p1, p2};
@@ -49,4 +88,39 @@ mod test {
         assert_relative_eq!(out.value_as_geo(0).y(), 111325.1428663851);
         dbg!(out);
     }
+
+    #[test]
+    fn reports_failed_indices() {
+        // A latitude of 100 degrees is outside the domain of Web Mercator
+        let point_array: PointArray = vec![
+            Some(p0()),
+            None,
+            Some(geo::point!(x: 0., y: 100.)),
+            Some(p1()),
+        ]
+        .into();
+        let proj = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+
+        let (out, failed) = point_array.reproject_with_errors(&proj);
+        assert_eq!(failed, vec![2]);
+        assert_eq!(out.len(), 4);
+        assert!(out.is_valid(0));
+        assert!(out.is_null(1));
+        assert!(out.is_null(2));
+        assert!(out.is_valid(3));
+        assert_relative_eq!(out.value_as_geo(0).y(), 111325.1428663851);
+    }
+
+    #[test]
+    fn through_shared_kernel() {
+        let line_strings: LineStringArray<i32> =
+            vec![line_string![(x: 0., y: 0.), (x: 0., y: 1.)]].into();
+        let proj = Proj::new_known_crs("EPSG:4326", "EPSG:3857", None).unwrap();
+
+        let options = TransformOptions::default().with_chunk_size(1);
+        let out = transform_geometry_array(line_strings, &proj, &options).unwrap();
+        let coords: Vec<geo::Coord> = out.value_as_geo(0).into_inner();
+        assert_eq!(coords[0], geo::Coord { x: 0., y: 0. });
+        assert_relative_eq!(coords[1].y, 111325.1428663851);
+    }
 }
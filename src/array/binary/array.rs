@@ -1,20 +1,25 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32};
+use crate::algorithm::native::bounding_rect::total_bounds_rects;
 use crate::array::zip_validity::ZipValidity;
 use crate::array::{CoordType, MutableWKBArray};
+use crate::buffer::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32};
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
+use crate::io::wkb::reader::WKBGeometryType;
 use crate::scalar::WKB;
-// use crate::util::{owned_slice_offsets, owned_slice_validity};
+// use crate::buffer::owned_slice_offsets;
+// use crate::util::owned_slice_validity;
 use crate::trait_::GeoArrayAccessor;
+use crate::util::validity_memory_size;
 use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
 use arrow_array::{Array, BinaryArray, GenericBinaryArray, LargeBinaryArray};
 use arrow_buffer::bit_iterator::BitIterator;
 use arrow_buffer::NullBuffer;
 use arrow_schema::{DataType, Field};
+use geo::BoundingRect;
 
 /// An immutable array of WKB geometries using GeoArrow's in-memory representation.
 ///
@@ -45,6 +50,53 @@ impl<O: OffsetSizeTrait> WKBArray<O> {
         self.len() == 0
     }
 
+    /// Count the geometries of each type, reading only the header of each value.
+    ///
+    /// Both EWKB, where Z, M and SRID are flagged in the high bits of the type, and ISO WKB, where
+    /// they add 1000, 2000 or 3000 to it, are counted by their base type. Null values are not
+    /// counted.
+    ///
+    /// # Errors
+    ///
+    /// - if a value is shorter than its header, has an invalid byte order, or an unknown type
+    pub fn type_histogram(&self) -> crate::error::Result<HashMap<WKBGeometryType, usize>> {
+        let mut histogram = HashMap::new();
+        for i in 0..self.len() {
+            if self.is_null(i) {
+                continue;
+            }
+
+            let buf = self.0.value(i);
+            let header: [u8; 4] = buf
+                .get(1..5)
+                .and_then(|header| header.try_into().ok())
+                .ok_or_else(|| {
+                    GeoArrowError::General(format!("WKB value {} is shorter than its header", i))
+                })?;
+            let type_code = match buf[0] {
+                0 => u32::from_be_bytes(header),
+                1 => u32::from_le_bytes(header),
+                byte_order => {
+                    return Err(GeoArrowError::General(format!(
+                        "WKB value {} has invalid byte order {}",
+                        i, byte_order
+                    )))
+                }
+            };
+            // Drop the EWKB Z, M and SRID flags, then the ISO dimension
+            let geometry_type = WKBGeometryType::try_from((type_code & 0x1FFF_FFFF) % 1000)
+                .map_err(|_| {
+                    GeoArrowError::General(format!(
+                        "WKB value {} has unknown geometry type {}",
+                        i, type_code
+                    ))
+                })?;
+            *histogram.entry(geometry_type).or_insert(0) += 1;
+        }
+
+        Ok(histogram)
+    }
+
     // pub fn with_validity(&self, validity: Option<NullBuffer>) -> Self {
     //     WKBArray::new(self.0.clone().with_validity(validity))
     // }
@@ -97,17 +149,42 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for WKBArray<O> {
         self
     }
 
+    /// WKB always stores `f64` coordinates, so this returns the array unchanged.
+    fn into_coord_float32(self) -> Self {
+        self
+    }
+
+    fn into_coord_float64(self) -> Self {
+        self
+    }
+
     /// Returns the number of geometries in this array
     #[inline]
     fn len(&self) -> usize {
         self.0.len()
     }
 
+    fn memory_size(&self) -> usize {
+        // Only the values that the offsets point to, in case the array is sliced
+        let offsets = self.0.value_offsets();
+        let values_len = (offsets[offsets.len() - 1] - offsets[0]).as_usize();
+        values_len + std::mem::size_of_val(offsets) + validity_memory_size(self.0.nulls())
+    }
+
     /// Returns the optional validity.
     fn validity(&self) -> Option<&NullBuffer> {
         self.0.nulls()
     }
 
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        // Null slots hold no WKB to parse, so they are skipped before conversion
+        total_bounds_rects(
+            (0..self.len())
+                .filter_map(|i| self.get_as_geo(i))
+                .filter_map(|geom| geom.bounding_rect()),
+        )
+    }
+
     /// Slices this [`WKBArray`] in place.
     /// # Panic
     /// This function panics iff `offset + length > self.len()`.
@@ -318,3 +395,50 @@ impl<O: OffsetSizeTrait> From<bumpalo::collections::Vec<'_, Option<geo::Geometry
         mut_arr.into()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn type_histogram() {
+        let values: Vec<Option<&[u8]>> = vec![
+            // Little-endian point
+            Some(&[1, 1, 0, 0, 0]),
+            // Big-endian ISO polygon Z
+            Some(&[0, 0, 0, 0x03, 0xEB]),
+            // Little-endian EWKB point with Z and SRID flags
+            Some(&[1, 1, 0, 0, 0xA0]),
+            None,
+        ];
+        let arr = WKBArray::new(BinaryArray::from(values));
+        let histogram = arr.type_histogram().unwrap();
+        assert_eq!(histogram.len(), 2);
+        assert_eq!(histogram[&WKBGeometryType::Point], 2);
+        assert_eq!(histogram[&WKBGeometryType::Polygon], 1);
+
+        let invalid: Vec<&[u8]> = vec![&[1, 9, 0, 0, 0]];
+        assert!(WKBArray::new(BinaryArray::from(invalid))
+            .type_histogram()
+            .is_err());
+        let truncated: Vec<&[u8]> = vec![&[1, 1]];
+        assert!(WKBArray::new(BinaryArray::from(truncated))
+            .type_histogram()
+            .is_err());
+    }
+
+    #[cfg(feature = "geozero")]
+    #[test]
+    fn total_bounds_skips_nulls() {
+        let arr: WKBArray<i32> = vec![
+            Some(geo::Geometry::Point(geo::point!(x: 1., y: 2.))),
+            None,
+            Some(geo::Geometry::Point(geo::point!(x: -3., y: 4.))),
+        ]
+        .into();
+        assert_eq!(
+            arr.total_bounds(),
+            Some(geo::Rect::new((-3., 2.), (1., 4.)))
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
+use crate::algorithm::native::bounding_rect::total_bounds_coords;
+use crate::algorithm::native::eq::coord_eq;
 use crate::array::{
-    CoordType, InterleavedCoordBuffer, MutableInterleavedCoordBuffer, MutableSeparatedCoordBuffer,
+    CoordType, Dimension, Float32CoordBuffer, InterleavedCoordBuffer, MutableCoordBuffer,
     SeparatedCoordBuffer,
 };
 use crate::error::GeoArrowError;
@@ -11,16 +13,18 @@ use crate::GeometryArrayTrait;
 use arrow_array::{Array, FixedSizeListArray, StructArray};
 use arrow_buffer::NullBuffer;
 use arrow_schema::{DataType, Field};
-use itertools::Itertools;
 
 /// An Arrow representation of an array of coordinates.
 ///
 /// As defined in the GeoArrow spec, coordinates can either be interleaved (i.e. a single array of
 /// XYXYXY) or separated (i.e. two arrays, one XXX and another YYY).
 ///
-/// This CoordBuffer abstracts over an `InterleavedCoordBuffer` and a `SeparatedCoordBuffer`.
+/// This CoordBuffer abstracts over an `InterleavedCoordBuffer` and a `SeparatedCoordBuffer`, as
+/// well as a `Float32CoordBuffer` of interleaved 32-bit coordinates, which is created by
+/// [`GeometryArrayTrait::into_coord_float32`].
 ///
-/// For now all coordinate buffers support only two dimensions.
+/// Coordinates may have z and m values, as given by [`CoordBuffer::dim`]. Conversions to `geo`
+/// types drop them, and they can be read with [`CoordBuffer::get_z`] and [`CoordBuffer::get_m`].
 ///
 /// This is named `CoordBuffer` instead of `CoordArray` because the buffer does not store its own
 /// validity bitmask. Rather the geometry arrays that build on top of this maintain their own
@@ -29,6 +33,7 @@ use itertools::Itertools;
 pub enum CoordBuffer {
     Interleaved(InterleavedCoordBuffer),
     Separated(SeparatedCoordBuffer),
+    Float32(Float32CoordBuffer),
 }
 
 impl CoordBuffer {
@@ -41,6 +46,71 @@ impl CoordBuffer {
         let geo_coord: geo::Coord = self.value(i).into();
         geo_coord.y
     }
+
+    /// The z value of the coordinate at index `i`, or `None` if this buffer has XY coordinates.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn get_z(&self, i: usize) -> Option<f64> {
+        match self {
+            CoordBuffer::Interleaved(c) => c.get_z(i),
+            CoordBuffer::Separated(c) => c.get_z(i),
+            CoordBuffer::Float32(c) => c.get_z(i),
+        }
+    }
+
+    /// The m value of the coordinate at index `i`, or `None` if this buffer has no measures.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn get_m(&self, i: usize) -> Option<f64> {
+        match self {
+            CoordBuffer::Interleaved(c) => c.get_m(i),
+            CoordBuffer::Separated(c) => c.get_m(i),
+            CoordBuffer::Float32(c) => c.get_m(i),
+        }
+    }
+
+    /// The dimension of the coordinates in this buffer.
+    pub fn dim(&self) -> Dimension {
+        match self {
+            CoordBuffer::Interleaved(c) => c.dim(),
+            CoordBuffer::Separated(c) => c.dim(),
+            CoordBuffer::Float32(c) => c.dim(),
+        }
+    }
+
+    /// Convert the coordinates to `dim`, keeping the coordinate layout.
+    ///
+    /// This is lossy: z and m values that `dim` doesn't have are dropped, so that for example a
+    /// measured array can be cast to XY. Values that `dim` has but this buffer doesn't are NaN.
+    /// The buffer is returned as is if it already has this dimension.
+    pub fn into_dim(self, dim: Dimension) -> Self {
+        if self.dim() == dim {
+            return self;
+        }
+        if let CoordBuffer::Float32(cb) = self {
+            let float64 = CoordBuffer::Interleaved(cb.to_float64()).into_dim(dim);
+            return CoordBuffer::Float32(Float32CoordBuffer::from_coords(&float64));
+        }
+
+        let mut new_buffer =
+            MutableCoordBuffer::with_capacity_and_dim(self.len(), self.coord_type(), dim);
+        (0..self.len()).for_each(|i| new_buffer.push_coord(self.value(i)));
+        new_buffer.into()
+    }
+
+    /// Whether the coordinates are stored as 32-bit floats.
+    pub fn is_float32(&self) -> bool {
+        matches!(self, CoordBuffer::Float32(_))
+    }
+
+    /// Iterator over all coordinates in this buffer, as [`geo::Coord`]s
+    pub fn geo_coords_iter(&self) -> impl Iterator<Item = geo::Coord> + '_ {
+        (0..self.len()).map(|i| self.value(i).into())
+    }
 }
 
 impl<'a> GeometryArrayTrait<'a> for CoordBuffer {
@@ -56,6 +126,7 @@ impl<'a> GeometryArrayTrait<'a> for CoordBuffer {
         match self {
             CoordBuffer::Interleaved(c) => c.storage_type(),
             CoordBuffer::Separated(c) => c.storage_type(),
+            CoordBuffer::Float32(c) => c.storage_type(),
         }
     }
 
@@ -71,6 +142,7 @@ impl<'a> GeometryArrayTrait<'a> for CoordBuffer {
         match self {
             CoordBuffer::Interleaved(c) => c.into_array_ref(),
             CoordBuffer::Separated(c) => c.into_array_ref(),
+            CoordBuffer::Float32(c) => c.into_array_ref(),
         }
     }
 
@@ -83,28 +155,39 @@ impl<'a> GeometryArrayTrait<'a> for CoordBuffer {
         match self {
             CoordBuffer::Interleaved(cb) => cb.coord_type(),
             CoordBuffer::Separated(cb) => cb.coord_type(),
+            CoordBuffer::Float32(cb) => cb.coord_type(),
         }
     }
 
+    /// Cast to `coord_type`, keeping the dimension.
+    ///
+    /// 32-bit coordinates are always interleaved, so casting them to separated coordinates widens
+    /// them to `f64`.
     fn into_coord_type(self, coord_type: CoordType) -> Self {
-        match (self, coord_type) {
-            (CoordBuffer::Interleaved(cb), CoordType::Interleaved) => CoordBuffer::Interleaved(cb),
-            (CoordBuffer::Interleaved(cb), CoordType::Separated) => {
-                let mut new_buffer = MutableSeparatedCoordBuffer::with_capacity(cb.len());
-                cb.coords
-                    .into_iter()
-                    .tuples()
-                    .for_each(|(x, y)| new_buffer.push_xy(*x, *y));
-                CoordBuffer::Separated(new_buffer.into())
-            }
-            (CoordBuffer::Separated(cb), CoordType::Separated) => CoordBuffer::Separated(cb),
-            (CoordBuffer::Separated(cb), CoordType::Interleaved) => {
-                let mut new_buffer = MutableInterleavedCoordBuffer::with_capacity(cb.len());
-                cb.x.into_iter()
-                    .zip(cb.y.iter())
-                    .for_each(|(x, y)| new_buffer.push_xy(*x, *y));
-                CoordBuffer::Interleaved(new_buffer.into())
-            }
+        if self.coord_type() == coord_type {
+            return self;
+        }
+
+        let mut new_buffer =
+            MutableCoordBuffer::with_capacity_and_dim(self.len(), coord_type, self.dim());
+        (0..self.len()).for_each(|i| new_buffer.push_coord(self.value(i)));
+        new_buffer.into()
+    }
+
+    /// The buffer is returned as is if it already holds 32-bit coordinates.
+    fn into_coord_float32(self) -> Self {
+        match self {
+            CoordBuffer::Float32(cb) => CoordBuffer::Float32(cb),
+            cb => CoordBuffer::Float32(Float32CoordBuffer::from_coords(&cb)),
+        }
+    }
+
+    /// 32-bit coordinates are widened into an [`InterleavedCoordBuffer`], and other buffers are
+    /// returned as is.
+    fn into_coord_float64(self) -> Self {
+        match self {
+            CoordBuffer::Float32(cb) => CoordBuffer::Interleaved(cb.to_float64()),
+            cb => cb,
         }
     }
 
@@ -112,6 +195,15 @@ impl<'a> GeometryArrayTrait<'a> for CoordBuffer {
         match self {
             CoordBuffer::Interleaved(c) => c.len(),
             CoordBuffer::Separated(c) => c.len(),
+            CoordBuffer::Float32(c) => c.len(),
+        }
+    }
+
+    fn memory_size(&self) -> usize {
+        match self {
+            CoordBuffer::Interleaved(cb) => cb.memory_size(),
+            CoordBuffer::Separated(cb) => cb.memory_size(),
+            CoordBuffer::Float32(cb) => cb.memory_size(),
         }
     }
 
@@ -119,10 +211,15 @@ impl<'a> GeometryArrayTrait<'a> for CoordBuffer {
         panic!("coordinate arrays don't have their own validity arrays")
     }
 
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        total_bounds_coords(self, None, 1, |_| (0, self.len()))
+    }
+
     fn slice(&self, offset: usize, length: usize) -> Self {
         match self {
             CoordBuffer::Interleaved(c) => CoordBuffer::Interleaved(c.slice(offset, length)),
             CoordBuffer::Separated(c) => CoordBuffer::Separated(c.slice(offset, length)),
+            CoordBuffer::Float32(c) => CoordBuffer::Float32(c.slice(offset, length)),
         }
     }
 
@@ -132,6 +229,7 @@ impl<'a> GeometryArrayTrait<'a> for CoordBuffer {
                 CoordBuffer::Interleaved(cb.owned_slice(offset, length))
             }
             CoordBuffer::Separated(cb) => CoordBuffer::Separated(cb.owned_slice(offset, length)),
+            CoordBuffer::Float32(cb) => CoordBuffer::Float32(cb.owned_slice(offset, length)),
         }
     }
 }
@@ -144,6 +242,7 @@ impl<'a> GeoArrayAccessor<'a> for CoordBuffer {
         match self {
             CoordBuffer::Interleaved(c) => Coord::Interleaved(c.value(index)),
             CoordBuffer::Separated(c) => Coord::Separated(c.value(index)),
+            CoordBuffer::Float32(c) => Coord::Float32(c.value(index)),
         }
     }
 }
@@ -159,7 +258,7 @@ impl TryFrom<&dyn Array> for CoordBuffer {
             }
             DataType::FixedSizeList(_, _) => {
                 let downcasted = value.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
-                Ok(CoordBuffer::Interleaved(downcasted.try_into()?))
+                downcasted.try_into()
             }
             _ => Err(GeoArrowError::General(format!(
                 "Unexpected type: {:?}",
@@ -169,28 +268,26 @@ impl TryFrom<&dyn Array> for CoordBuffer {
     }
 }
 
+impl TryFrom<&FixedSizeListArray> for CoordBuffer {
+    type Error = GeoArrowError;
+
+    /// Read `Float32` coordinates into a [`Float32CoordBuffer`] and other coordinates into an
+    /// [`InterleavedCoordBuffer`].
+    fn try_from(value: &FixedSizeListArray) -> Result<Self, Self::Error> {
+        match value.value_type() {
+            DataType::Float32 => Ok(CoordBuffer::Float32(value.try_into()?)),
+            _ => Ok(CoordBuffer::Interleaved(value.try_into()?)),
+        }
+    }
+}
+
 impl PartialEq for CoordBuffer {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (CoordBuffer::Interleaved(a), CoordBuffer::Interleaved(b)) => PartialEq::eq(a, b),
-            (CoordBuffer::Interleaved(left), CoordBuffer::Separated(right)) => {
-                if left.len() != right.len() {
-                    return false;
-                }
-
-                for i in 0..left.len() {
-                    let left_coord = left.value(i);
-                    let right_coord = right.value(i);
-
-                    if left_coord != right_coord {
-                        return false;
-                    }
-                }
-
-                true
-            }
             (CoordBuffer::Separated(a), CoordBuffer::Separated(b)) => PartialEq::eq(a, b),
-            (CoordBuffer::Separated(left), CoordBuffer::Interleaved(right)) => {
+            (CoordBuffer::Float32(a), CoordBuffer::Float32(b)) => PartialEq::eq(a, b),
+            (left, right) => {
                 if left.len() != right.len() {
                     return false;
                 }
@@ -199,7 +296,7 @@ impl PartialEq for CoordBuffer {
                     let left_coord = left.value(i);
                     let right_coord = right.value(i);
 
-                    if left_coord != right_coord {
+                    if !coord_eq(&left_coord, &right_coord) {
                         return false;
                     }
                 }
@@ -213,9 +310,25 @@ impl PartialEq for CoordBuffer {
 #[cfg(test)]
 mod test {
     use crate::error::Result;
+    use crate::geo_traits::CoordTrait;
 
     use super::*;
 
+    #[test]
+    fn fixed_size_list_roundtrip() -> Result<()> {
+        let buf = CoordBuffer::Interleaved(vec![0., 3., 1., 4., 2., 5.].try_into()?);
+        let array_ref = buf.clone().into_array_ref();
+        let list_array = array_ref
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+
+        let round_trip: CoordBuffer = list_array.try_into()?;
+        assert_eq!(round_trip.coord_type(), CoordType::Interleaved);
+        assert_eq!(round_trip, buf);
+        Ok(())
+    }
+
     #[test]
     fn test_eq_both_interleaved() -> Result<()> {
         let coords1 = vec![0., 3., 1., 4., 2., 5.];
@@ -255,4 +368,49 @@ mod test {
         assert_eq!(buf1, buf2);
         Ok(())
     }
+
+    #[test]
+    fn xyz_into_coord_type() -> Result<()> {
+        let interleaved = CoordBuffer::Interleaved(InterleavedCoordBuffer::new_with_dim(
+            vec![0., 3., 6., 1., 4., 7.].into(),
+            Dimension::XYZ,
+        ));
+        let separated = interleaved.clone().into_coord_type(CoordType::Separated);
+        assert_eq!(separated.dim(), Dimension::XYZ);
+        assert_eq!(separated.get_z(1), Some(7.));
+        assert_eq!(separated, interleaved);
+
+        let round_trip = separated.into_coord_type(CoordType::Interleaved);
+        assert_eq!(round_trip, interleaved);
+
+        // The same x and y with different dimensions are not equal
+        let xy = CoordBuffer::Interleaved(vec![0., 3., 1., 4.].try_into()?);
+        assert_ne!(xy, interleaved);
+        Ok(())
+    }
+
+    #[test]
+    fn measured_into_dim() {
+        let mut builder =
+            MutableCoordBuffer::with_capacity_and_dim(2, CoordType::Separated, Dimension::XYZM);
+        builder.push_xyzm(0., 1., 2., 3.);
+        builder.push_xym(4., 5., 6.);
+        let measured: CoordBuffer = builder.into();
+        // push_xym leaves the z value of an XYZM buffer NaN
+        assert!(measured.get_z(1).unwrap().is_nan());
+        assert_eq!(measured.get_m(1), Some(6.));
+
+        // Casting to XY drops the z and m values
+        let xy = measured.clone().into_dim(Dimension::XY);
+        assert_eq!(xy.dim(), Dimension::XY);
+        assert_eq!(xy.get_m(0), None);
+        assert_eq!(xy.value(1).x_y(), (4., 5.));
+        assert_ne!(xy, measured);
+
+        // Casting to XYM keeps the m values
+        let xym = measured.into_dim(Dimension::XYM);
+        assert_eq!(xym.get_z(0), None);
+        assert_eq!(xym.get_m(0), Some(3.));
+        assert_eq!(xym.get_m(1), Some(6.));
+    }
 }
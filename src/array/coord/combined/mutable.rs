@@ -1,4 +1,7 @@
-use crate::array::{CoordBuffer, MutableInterleavedCoordBuffer, MutableSeparatedCoordBuffer};
+use crate::array::{
+    CoordBuffer, CoordType, Dimension, MutableInterleavedCoordBuffer, MutableSeparatedCoordBuffer,
+};
+use crate::geo_traits::CoordTrait;
 
 #[derive(Debug, Clone)]
 pub enum MutableCoordBuffer {
@@ -14,6 +17,32 @@ impl MutableCoordBuffer {
         }
     }
 
+    /// Creates a new empty buffer of the given layout, with room for `capacity` coordinates.
+    pub fn with_capacity(capacity: usize, coord_type: CoordType) -> Self {
+        Self::with_capacity_and_dim(capacity, coord_type, Dimension::XY)
+    }
+
+    /// Creates a new empty buffer of the given layout and dimension, with room for `capacity`
+    /// coordinates.
+    pub fn with_capacity_and_dim(capacity: usize, coord_type: CoordType, dim: Dimension) -> Self {
+        match coord_type {
+            CoordType::Interleaved => MutableCoordBuffer::Interleaved(
+                MutableInterleavedCoordBuffer::with_capacity_and_dim(capacity, dim),
+            ),
+            CoordType::Separated => MutableCoordBuffer::Separated(
+                MutableSeparatedCoordBuffer::with_capacity_and_dim(capacity, dim),
+            ),
+        }
+    }
+
+    /// The dimension of the coordinates in this buffer.
+    pub fn dim(&self) -> Dimension {
+        match self {
+            MutableCoordBuffer::Interleaved(cb) => cb.dim(),
+            MutableCoordBuffer::Separated(cb) => cb.dim(),
+        }
+    }
+
     /// Reserves capacity for at least `additional` more coordinates to be inserted
     /// in the given `Vec<T>`. The collection may reserve more space to
     /// speculatively avoid frequent reallocations. After calling `reserve`,
@@ -53,6 +82,42 @@ impl MutableCoordBuffer {
         }
     }
 
+    /// The coordinate at index `i`.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn coord(&self, i: usize) -> geo::Coord {
+        match self {
+            MutableCoordBuffer::Interleaved(cb) => cb.coord(i),
+            MutableCoordBuffer::Separated(cb) => cb.coord(i),
+        }
+    }
+
+    /// The z value of the coordinate at index `i`, or `None` if this buffer has no z values.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn get_z(&self, i: usize) -> Option<f64> {
+        match self {
+            MutableCoordBuffer::Interleaved(cb) => cb.get_z(i),
+            MutableCoordBuffer::Separated(cb) => cb.get_z(i),
+        }
+    }
+
+    /// The m value of the coordinate at index `i`, or `None` if this buffer has no measures.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn get_m(&self, i: usize) -> Option<f64> {
+        match self {
+            MutableCoordBuffer::Interleaved(cb) => cb.get_m(i),
+            MutableCoordBuffer::Separated(cb) => cb.get_m(i),
+        }
+    }
+
     pub fn set_coord(&mut self, i: usize, coord: geo::Coord) {
         match self {
             MutableCoordBuffer::Interleaved(cb) => cb.set_coord(i, coord),
@@ -60,7 +125,11 @@ impl MutableCoordBuffer {
         }
     }
 
-    pub fn push_coord(&mut self, coord: geo::Coord) {
+    /// Add a coordinate to the end of this buffer.
+    ///
+    /// The buffer stores `NaN` for a z or m value that the coordinate doesn't have, and drops the
+    /// values that its dimension doesn't have.
+    pub fn push_coord(&mut self, coord: impl CoordTrait<T = f64>) {
         match self {
             MutableCoordBuffer::Interleaved(cb) => cb.push_coord(coord),
             MutableCoordBuffer::Separated(cb) => cb.push_coord(coord),
@@ -81,6 +150,32 @@ impl MutableCoordBuffer {
         }
     }
 
+    /// Add a coordinate to the end of this buffer, dropping the z value if the buffer has none.
+    pub fn push_xyz(&mut self, x: f64, y: f64, z: f64) {
+        match self {
+            MutableCoordBuffer::Interleaved(cb) => cb.push_xyz(x, y, z),
+            MutableCoordBuffer::Separated(cb) => cb.push_xyz(x, y, z),
+        }
+    }
+
+    /// Add a measured coordinate to the end of this buffer, dropping the m value if the buffer
+    /// has none.
+    pub fn push_xym(&mut self, x: f64, y: f64, m: f64) {
+        match self {
+            MutableCoordBuffer::Interleaved(cb) => cb.push_xym(x, y, m),
+            MutableCoordBuffer::Separated(cb) => cb.push_xym(x, y, m),
+        }
+    }
+
+    /// Add a coordinate to the end of this buffer, dropping the z and m values it has no room
+    /// for.
+    pub fn push_xyzm(&mut self, x: f64, y: f64, z: f64, m: f64) {
+        match self {
+            MutableCoordBuffer::Interleaved(cb) => cb.push_xyzm(x, y, z, m),
+            MutableCoordBuffer::Separated(cb) => cb.push_xyzm(x, y, z, m),
+        }
+    }
+
     pub fn len(&self) -> usize {
         match self {
             MutableCoordBuffer::Interleaved(cb) => cb.len(),
@@ -0,0 +1,269 @@
+use std::sync::Arc;
+
+use crate::array::{CoordBuffer, CoordType, Dimension, InterleavedCoordBuffer};
+use crate::error::{GeoArrowError, Result};
+use crate::geo_traits::CoordTrait;
+use crate::scalar::Float32Coord;
+use crate::trait_::GeoArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::{Array, FixedSizeListArray, Float32Array};
+use arrow_buffer::{NullBuffer, ScalarBuffer};
+use arrow_schema::{DataType, Field};
+
+/// An array of coordinates stored as 32-bit floats, interleaved in a single buffer.
+///
+/// This halves the memory of an [`InterleavedCoordBuffer`] for consumers such as WebGL vertex
+/// buffers that only take `f32` values. Coordinates are read as `f64`, so scalars and
+/// `value_as_geo` behave as with 64-bit coordinates, and kernels that compute new coordinates
+/// write them as `f64`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Float32CoordBuffer {
+    pub coords: ScalarBuffer<f32>,
+
+    /// The number of values per coordinate in `coords`
+    dim: Dimension,
+}
+
+impl Float32CoordBuffer {
+    /// Construct a new Float32CoordBuffer whose coordinates have `dim` values each
+    ///
+    /// # Panics
+    ///
+    /// - if the buffer length is not a multiple of the coordinate size
+    pub fn new_with_dim(coords: ScalarBuffer<f32>, dim: Dimension) -> Self {
+        Self::try_new_with_dim(coords, dim).unwrap()
+    }
+
+    /// Construct a new Float32CoordBuffer whose coordinates have `dim` values each
+    ///
+    /// # Errors
+    ///
+    /// - if the buffer length is not a multiple of the coordinate size
+    pub fn try_new_with_dim(coords: ScalarBuffer<f32>, dim: Dimension) -> Result<Self> {
+        if coords.len() % dim.size() != 0 {
+            return Err(GeoArrowError::General(format!(
+                "coordinate buffer length must be a multiple of {}",
+                dim.size()
+            )));
+        }
+        Ok(Self { coords, dim })
+    }
+
+    /// Round the coordinates of `coords` to the nearest `f32`, keeping their dimension.
+    pub fn from_coords(coords: &CoordBuffer) -> Self {
+        let dim = coords.dim();
+        let mut values = Vec::with_capacity(coords.len() * dim.size());
+        for i in 0..coords.len() {
+            let coord = coords.value(i);
+            values.push(coord.x() as f32);
+            values.push(coord.y() as f32);
+            if dim.has_z() {
+                values.push(coord.z().unwrap() as f32);
+            }
+            if dim.has_m() {
+                values.push(coord.m().unwrap() as f32);
+            }
+        }
+        Self::new_with_dim(values.into(), dim)
+    }
+
+    /// Widen the coordinates to `f64`, keeping the interleaved layout.
+    pub fn to_float64(&self) -> InterleavedCoordBuffer {
+        let values: Vec<f64> = self.coords.iter().map(|value| f64::from(*value)).collect();
+        InterleavedCoordBuffer::new_with_dim(values.into(), self.dim)
+    }
+
+    /// The dimension of the coordinates in this buffer.
+    pub fn dim(&self) -> Dimension {
+        self.dim
+    }
+
+    /// The z value of the coordinate at index `i`, or `None` for coordinates without one.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn get_z(&self, i: usize) -> Option<f64> {
+        self.value(i).z()
+    }
+
+    /// The m value of the coordinate at index `i`, or `None` for coordinates without a measure.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn get_m(&self, i: usize) -> Option<f64> {
+        self.value(i).m()
+    }
+
+    pub fn values_array(&self) -> Float32Array {
+        Float32Array::new(self.coords.clone(), None)
+    }
+
+    pub fn values_field(&self) -> Field {
+        Field::new(self.dim.interleaved_field_name(), DataType::Float32, false)
+    }
+}
+
+impl<'a> GeometryArrayTrait<'a> for Float32CoordBuffer {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn data_type(&self) -> &crate::datatypes::GeoDataType {
+        panic!("Coordinate arrays do not have a GeoDataType.")
+    }
+
+    fn storage_type(&self) -> DataType {
+        DataType::FixedSizeList(Arc::new(self.values_field()), self.dim.size() as i32)
+    }
+
+    fn extension_field(&self) -> Arc<Field> {
+        panic!("Coordinate arrays do not have an extension name.")
+    }
+
+    fn extension_name(&self) -> &str {
+        panic!("Coordinate arrays do not have an extension name.")
+    }
+
+    fn into_array_ref(self) -> Arc<dyn Array> {
+        Arc::new(FixedSizeListArray::from(self))
+    }
+
+    fn with_coords(self, _coords: CoordBuffer) -> Self {
+        unimplemented!();
+    }
+
+    fn coord_type(&self) -> CoordType {
+        CoordType::Interleaved
+    }
+
+    fn into_coord_type(self, _coord_type: CoordType) -> Self {
+        panic!("into_coord_type only implemented on CoordBuffer");
+    }
+
+    fn into_coord_float32(self) -> Self {
+        panic!("into_coord_float32 only implemented on CoordBuffer");
+    }
+
+    fn into_coord_float64(self) -> Self {
+        panic!("into_coord_float64 only implemented on CoordBuffer");
+    }
+
+    fn len(&self) -> usize {
+        self.coords.len() / self.dim.size()
+    }
+
+    fn memory_size(&self) -> usize {
+        std::mem::size_of_val(self.coords.as_ref())
+    }
+
+    fn validity(&self) -> Option<&NullBuffer> {
+        panic!("coordinate arrays don't have their own validity arrays")
+    }
+
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        CoordBuffer::Float32(self.clone()).total_bounds()
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+        let size = self.dim.size();
+        Self {
+            coords: self.coords.slice(offset * size, length * size),
+            dim: self.dim,
+        }
+    }
+
+    fn owned_slice(&self, offset: usize, length: usize) -> Self {
+        let buffer = self.slice(offset, length);
+        Self::new_with_dim(buffer.coords.to_vec().into(), self.dim)
+    }
+}
+
+impl<'a> GeoArrayAccessor<'a> for Float32CoordBuffer {
+    type Item = Float32Coord<'a>;
+    type ItemGeo = geo::Coord;
+
+    unsafe fn value_unchecked(&'a self, index: usize) -> Self::Item {
+        Float32Coord {
+            coords: &self.coords,
+            i: index,
+            dim: self.dim,
+        }
+    }
+}
+
+impl From<Float32CoordBuffer> for FixedSizeListArray {
+    fn from(value: Float32CoordBuffer) -> Self {
+        FixedSizeListArray::new(
+            Arc::new(value.values_field()),
+            value.dim.size() as i32,
+            Arc::new(value.values_array()),
+            None,
+        )
+    }
+}
+
+impl TryFrom<&FixedSizeListArray> for Float32CoordBuffer {
+    type Error = GeoArrowError;
+
+    /// Read coordinates from a `FixedSizeList<Float32>` of size 2 to 4 without copying, with
+    /// the same dimensions as [`InterleavedCoordBuffer`].
+    ///
+    /// # Errors
+    ///
+    /// - if the list size is not 2, 3 or 4
+    /// - if the values are not `Float32`
+    fn try_from(value: &FixedSizeListArray) -> std::result::Result<Self, Self::Error> {
+        let field_name = match value.data_type() {
+            DataType::FixedSizeList(field, _) => field.name().as_str(),
+            _ => unreachable!(),
+        };
+        let dim = Dimension::from_interleaved_field(field_name, value.value_length() as usize)
+            .ok_or_else(|| {
+                GeoArrowError::General(format!(
+                    "Expected this FixedSizeListArray to have size 2, 3 or 4, got {}",
+                    value.value_length()
+                ))
+            })?;
+
+        let coord_array_values = value
+            .values()
+            .as_any()
+            .downcast_ref::<Float32Array>()
+            .ok_or_else(|| {
+                GeoArrowError::General(format!(
+                    "Expected Float32 coordinates, got {}",
+                    value.values().data_type()
+                ))
+            })?;
+
+        Ok(Float32CoordBuffer::new_with_dim(
+            coord_array_values.values().clone(),
+            dim,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip_through_arrow() {
+        let coords = CoordBuffer::Interleaved(vec![0.5, 1., 2.25, 3.].try_into().unwrap());
+        let float32 = Float32CoordBuffer::from_coords(&coords);
+        assert_eq!(float32.len(), 2);
+
+        let list_array = FixedSizeListArray::from(float32.clone());
+        assert_eq!(list_array.value_type(), DataType::Float32);
+
+        let round_trip: Float32CoordBuffer = (&list_array).try_into().unwrap();
+        assert_eq!(round_trip, float32);
+        assert_eq!(CoordBuffer::Interleaved(round_trip.to_float64()), coords);
+    }
+}
@@ -0,0 +1,3 @@
+mod array;
+
+pub use array::Float32CoordBuffer;
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use crate::array::CoordType;
+use crate::array::{CoordBuffer, CoordType, Dimension};
 use crate::error::{GeoArrowError, Result};
 use crate::scalar::InterleavedCoord;
 use crate::trait_::GeoArrayAccessor;
@@ -9,17 +9,22 @@ use arrow_array::{Array, FixedSizeListArray, Float64Array};
 use arrow_buffer::{NullBuffer, ScalarBuffer};
 use arrow_schema::{DataType, Field};
 
-/// A an array of XY coordinates stored interleaved in a single buffer.
+/// A an array of coordinates stored interleaved in a single buffer, with two to four values per
+/// coordinate depending on its [`Dimension`].
 #[derive(Debug, Clone, PartialEq)]
 pub struct InterleavedCoordBuffer {
     pub coords: ScalarBuffer<f64>,
+
+    /// The number of values per coordinate in `coords`
+    dim: Dimension,
 }
 
-fn check(coords: &ScalarBuffer<f64>) -> Result<()> {
-    if coords.len() % 2 != 0 {
-        return Err(GeoArrowError::General(
-            "x and y arrays must have the same length".to_string(),
-        ));
+fn check(coords: &ScalarBuffer<f64>, dim: Dimension) -> Result<()> {
+    if coords.len() % dim.size() != 0 {
+        return Err(GeoArrowError::General(format!(
+            "coordinate buffer length must be a multiple of {}",
+            dim.size()
+        )));
     }
 
     Ok(())
@@ -32,8 +37,7 @@ impl InterleavedCoordBuffer {
     ///
     /// - if the coordinate buffer have different lengths
     pub fn new(coords: ScalarBuffer<f64>) -> Self {
-        check(&coords).unwrap();
-        Self { coords }
+        Self::new_with_dim(coords, Dimension::XY)
     }
 
     /// Construct a new InterleavedCoordBuffer
@@ -42,8 +46,53 @@ impl InterleavedCoordBuffer {
     ///
     /// - if the coordinate buffer have different lengths
     pub fn try_new(coords: ScalarBuffer<f64>) -> Result<Self> {
-        check(&coords)?;
-        Ok(Self { coords })
+        Self::try_new_with_dim(coords, Dimension::XY)
+    }
+
+    /// Construct a new InterleavedCoordBuffer whose coordinates have `dim` values each
+    ///
+    /// # Panics
+    ///
+    /// - if the buffer length is not a multiple of the coordinate size
+    pub fn new_with_dim(coords: ScalarBuffer<f64>, dim: Dimension) -> Self {
+        Self::try_new_with_dim(coords, dim).unwrap()
+    }
+
+    /// Construct a new InterleavedCoordBuffer whose coordinates have `dim` values each
+    ///
+    /// # Errors
+    ///
+    /// - if the buffer length is not a multiple of the coordinate size
+    pub fn try_new_with_dim(coords: ScalarBuffer<f64>, dim: Dimension) -> Result<Self> {
+        check(&coords, dim)?;
+        Ok(Self { coords, dim })
+    }
+
+    /// The dimension of the coordinates in this buffer.
+    pub fn dim(&self) -> Dimension {
+        self.dim
+    }
+
+    /// The z value of the coordinate at index `i`, or `None` for XY coordinates.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn get_z(&self, i: usize) -> Option<f64> {
+        self.dim
+            .z_index()
+            .map(|index| self.coords[i * self.dim.size() + index])
+    }
+
+    /// The m value of the coordinate at index `i`, or `None` for coordinates without a measure.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn get_m(&self, i: usize) -> Option<f64> {
+        self.dim
+            .m_index()
+            .map(|index| self.coords[i * self.dim.size() + index])
     }
 
     pub fn values_array(&self) -> Float64Array {
@@ -51,7 +100,7 @@ impl InterleavedCoordBuffer {
     }
 
     pub fn values_field(&self) -> Field {
-        Field::new("xy", DataType::Float64, false)
+        Field::new(self.dim.interleaved_field_name(), DataType::Float64, false)
     }
 }
 
@@ -65,7 +114,7 @@ impl<'a> GeometryArrayTrait<'a> for InterleavedCoordBuffer {
     }
 
     fn storage_type(&self) -> DataType {
-        DataType::FixedSizeList(Arc::new(self.values_field()), 2)
+        DataType::FixedSizeList(Arc::new(self.values_field()), self.dim.size() as i32)
     }
 
     fn extension_field(&self) -> Arc<Field> {
@@ -77,12 +126,7 @@ impl<'a> GeometryArrayTrait<'a> for InterleavedCoordBuffer {
     }
 
     fn into_array_ref(self) -> Arc<dyn Array> {
-        Arc::new(FixedSizeListArray::new(
-            Arc::new(self.values_field()),
-            2,
-            Arc::new(self.values_array()),
-            None,
-        ))
+        Arc::new(FixedSizeListArray::from(self))
     }
 
     fn with_coords(self, _coords: crate::array::CoordBuffer) -> Self {
@@ -97,27 +141,45 @@ impl<'a> GeometryArrayTrait<'a> for InterleavedCoordBuffer {
         panic!("into_coord_type only implemented on CoordBuffer");
     }
 
+    fn into_coord_float32(self) -> Self {
+        panic!("into_coord_float32 only implemented on CoordBuffer");
+    }
+
+    fn into_coord_float64(self) -> Self {
+        panic!("into_coord_float64 only implemented on CoordBuffer");
+    }
+
     fn len(&self) -> usize {
-        self.coords.len() / 2
+        self.coords.len() / self.dim.size()
+    }
+
+    fn memory_size(&self) -> usize {
+        std::mem::size_of_val(self.coords.as_ref())
     }
 
     fn validity(&self) -> Option<&NullBuffer> {
         panic!("coordinate arrays don't have their own validity arrays")
     }
 
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        CoordBuffer::Interleaved(self.clone()).total_bounds()
+    }
+
     fn slice(&self, offset: usize, length: usize) -> Self {
         assert!(
             offset + length <= self.len(),
             "offset + length may not exceed length of array"
         );
+        let size = self.dim.size();
         Self {
-            coords: self.coords.slice(offset * 2, length * 2),
+            coords: self.coords.slice(offset * size, length * size),
+            dim: self.dim,
         }
     }
 
     fn owned_slice(&self, offset: usize, length: usize) -> Self {
         let buffer = self.slice(offset, length);
-        Self::new(buffer.coords.to_vec().into())
+        Self::new_with_dim(buffer.coords.to_vec().into(), self.dim)
     }
 }
 
@@ -129,28 +191,63 @@ impl<'a> GeoArrayAccessor<'a> for InterleavedCoordBuffer {
         InterleavedCoord {
             coords: &self.coords,
             i: index,
+            dim: self.dim,
         }
     }
 }
 
+impl From<InterleavedCoordBuffer> for FixedSizeListArray {
+    fn from(value: InterleavedCoordBuffer) -> Self {
+        FixedSizeListArray::new(
+            Arc::new(value.values_field()),
+            value.dim.size() as i32,
+            Arc::new(value.values_array()),
+            None,
+        )
+    }
+}
+
 impl TryFrom<&FixedSizeListArray> for InterleavedCoordBuffer {
     type Error = GeoArrowError;
 
+    /// Read coordinates from a `FixedSizeList<Float64, 2>` (XY), `FixedSizeList<Float64, 3>` (XYZ,
+    /// or XYM if the child field is named `xym`) or `FixedSizeList<Float64, 4>` (XYZM) without
+    /// copying.
+    ///
+    /// Null slots are read like any other: the validity of point arrays is stored on the list
+    /// array, and is kept by the geometry array rather than the coordinates.
+    ///
+    /// # Errors
+    ///
+    /// - if the list size is not 2, 3 or 4
+    /// - if the values are not `Float64`
     fn try_from(value: &FixedSizeListArray) -> std::result::Result<Self, Self::Error> {
-        if value.value_length() != 2 {
-            return Err(GeoArrowError::General(
-                "Expected this FixedSizeListArray to have size 2".to_string(),
-            ));
-        }
+        let field_name = match value.data_type() {
+            DataType::FixedSizeList(field, _) => field.name().as_str(),
+            _ => unreachable!(),
+        };
+        let dim = Dimension::from_interleaved_field(field_name, value.value_length() as usize)
+            .ok_or_else(|| {
+                GeoArrowError::General(format!(
+                    "Expected this FixedSizeListArray to have size 2, 3 or 4, got {}",
+                    value.value_length()
+                ))
+            })?;
 
         let coord_array_values = value
             .values()
             .as_any()
             .downcast_ref::<Float64Array>()
-            .unwrap();
-
-        Ok(InterleavedCoordBuffer::new(
+            .ok_or_else(|| {
+                GeoArrowError::General(format!(
+                    "Expected Float64 coordinates, got {}",
+                    value.values().data_type()
+                ))
+            })?;
+
+        Ok(InterleavedCoordBuffer::new_with_dim(
             coord_array_values.values().clone(),
+            dim,
         ))
     }
 }
@@ -177,4 +274,79 @@ mod test {
 
         assert_eq!(buf1, buf2);
     }
+
+    #[test]
+    fn fixed_size_list_roundtrip() {
+        let buf = InterleavedCoordBuffer::new(vec![0., 3., 1., 4., 2., 5.].into());
+        let array = FixedSizeListArray::from(buf.clone());
+        assert_eq!(array.len(), 3);
+        assert_eq!(InterleavedCoordBuffer::try_from(&array).unwrap(), buf);
+
+        // Slicing the list array slices its values
+        let sliced = array.slice(1, 2);
+        let expected = InterleavedCoordBuffer::new(vec![1., 4., 2., 5.].into());
+        assert_eq!(InterleavedCoordBuffer::try_from(&sliced).unwrap(), expected);
+    }
+
+    #[test]
+    fn fixed_size_list_errors() {
+        let field = Arc::new(Field::new("xy", DataType::Float64, true));
+        let values = Arc::new(Float64Array::from(vec![0., 1., 2., 3., 4.]));
+        let quintuples = FixedSizeListArray::new(field, 5, values, None);
+        assert!(InterleavedCoordBuffer::try_from(&quintuples).is_err());
+
+        let field = Arc::new(Field::new("xy", DataType::Float32, false));
+        let values = Arc::new(arrow_array::Float32Array::from(vec![0., 1.]));
+        let float32 = FixedSizeListArray::new(field, 2, values, None);
+        assert!(InterleavedCoordBuffer::try_from(&float32).is_err());
+    }
+
+    #[test]
+    fn xyz_fixed_size_list_roundtrip() {
+        let buf = InterleavedCoordBuffer::new_with_dim(
+            vec![0., 3., 6., 1., 4., 7.].into(),
+            Dimension::XYZ,
+        );
+        assert_eq!(buf.len(), 2);
+        assert_eq!(buf.get_z(1), Some(7.));
+
+        let array = FixedSizeListArray::from(buf.clone());
+        assert_eq!(array.value_length(), 3);
+        assert_eq!(InterleavedCoordBuffer::try_from(&array).unwrap(), buf);
+
+        let sliced = buf.slice(1, 1);
+        assert_eq!(sliced.coords.to_vec(), vec![1., 4., 7.]);
+        assert_eq!(sliced.get_z(0), Some(7.));
+    }
+
+    #[test]
+    fn measured_fixed_size_list_roundtrip() {
+        let xym = InterleavedCoordBuffer::new_with_dim(
+            vec![0., 3., 6., 1., 4., 7.].into(),
+            Dimension::XYM,
+        );
+        assert_eq!(xym.get_z(0), None);
+        assert_eq!(xym.get_m(1), Some(7.));
+
+        // The field name tells XYM apart from XYZ, which has the same list size
+        let array = FixedSizeListArray::from(xym.clone());
+        assert_eq!(InterleavedCoordBuffer::try_from(&array).unwrap(), xym);
+
+        let xyzm = InterleavedCoordBuffer::new_with_dim(
+            vec![0., 3., 6., 9., 1., 4., 7., 10.].into(),
+            Dimension::XYZM,
+        );
+        assert_eq!(xyzm.len(), 2);
+        assert_eq!(xyzm.get_z(1), Some(7.));
+        assert_eq!(xyzm.get_m(1), Some(10.));
+        let array = FixedSizeListArray::from(xyzm.clone());
+        assert_eq!(array.value_length(), 4);
+        assert_eq!(InterleavedCoordBuffer::try_from(&array).unwrap(), xyzm);
+
+        // The buffer length must be a multiple of the coordinate size
+        assert!(
+            InterleavedCoordBuffer::try_new_with_dim(vec![0., 1., 2.].into(), Dimension::XYZM)
+                .is_err()
+        );
+    }
 }
@@ -1,9 +1,12 @@
-use crate::array::InterleavedCoordBuffer;
+use crate::array::{Dimension, InterleavedCoordBuffer};
 use crate::geo_traits::CoordTrait;
 
 #[derive(Debug, Clone)]
 pub struct MutableInterleavedCoordBuffer {
     pub coords: Vec<f64>,
+
+    /// The number of values per coordinate in `coords`
+    dim: Dimension,
 }
 
 impl MutableInterleavedCoordBuffer {
@@ -12,8 +15,14 @@ impl MutableInterleavedCoordBuffer {
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_dim(capacity, Dimension::XY)
+    }
+
+    /// Creates a new empty buffer of `dim` coordinates, with room for `capacity` of them.
+    pub fn with_capacity_and_dim(capacity: usize, dim: Dimension) -> Self {
         Self {
-            coords: Vec::with_capacity(capacity * 2),
+            coords: Vec::with_capacity(capacity * dim.size()),
+            dim,
         }
     }
 
@@ -21,16 +30,22 @@ impl MutableInterleavedCoordBuffer {
     pub fn initialize(len: usize) -> Self {
         Self {
             coords: vec![0.0f64; len * 2],
+            dim: Dimension::XY,
         }
     }
 
+    /// The dimension of the coordinates in this buffer.
+    pub fn dim(&self) -> Dimension {
+        self.dim
+    }
+
     /// Reserves capacity for at least `additional` more coordinates to be inserted
     /// in the given `Vec<T>`. The collection may reserve more space to
     /// speculatively avoid frequent reallocations. After calling `reserve`,
     /// capacity will be greater than or equal to `self.len() + additional`.
     /// Does nothing if capacity is already sufficient.
     pub fn reserve(&mut self, additional: usize) {
-        self.coords.reserve(additional * 2);
+        self.coords.reserve(additional * self.dim.size());
     }
 
     /// Reserves the minimum capacity for at least `additional` more coordinates to
@@ -46,36 +61,101 @@ impl MutableInterleavedCoordBuffer {
     ///
     /// [`reserve`]: Vec::reserve
     pub fn reserve_exact(&mut self, additional: usize) {
-        self.coords.reserve_exact(additional * 2);
+        self.coords.reserve_exact(additional * self.dim.size());
     }
 
     /// Returns the total number of coordinates the vector can hold without reallocating.
     pub fn capacity(&self) -> usize {
-        self.coords.capacity() / 2
+        self.coords.capacity() / self.dim.size()
+    }
+
+    /// The coordinate at index `i`.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn coord(&self, i: usize) -> geo::Coord {
+        let size = self.dim.size();
+        geo::coord! { x: self.coords[i * size], y: self.coords[i * size + 1] }
+    }
+
+    /// The z value of the coordinate at index `i`, or `None` if this buffer is XY.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn get_z(&self, i: usize) -> Option<f64> {
+        self.dim
+            .z_index()
+            .map(|index| self.coords[i * self.dim.size() + index])
+    }
+
+    /// The m value of the coordinate at index `i`, or `None` if this buffer has no measures.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn get_m(&self, i: usize) -> Option<f64> {
+        self.dim
+            .m_index()
+            .map(|index| self.coords[i * self.dim.size() + index])
     }
 
     pub fn set_coord(&mut self, i: usize, coord: geo::Coord) {
-        self.coords[i * 2] = coord.x;
-        self.coords[i * 2 + 1] = coord.y;
+        self.set_xy(i, coord.x, coord.y);
     }
 
+    /// Add a coordinate to the end of this buffer.
+    ///
+    /// The buffer stores `NaN` for a z or m value that the coordinate doesn't have, and drops the
+    /// values that its dimension doesn't have.
     pub fn push_coord(&mut self, coord: impl CoordTrait<T = f64>) {
-        self.coords.push(coord.x());
-        self.coords.push(coord.y());
+        self.push_ordinates(coord.x(), coord.y(), coord.z(), coord.m());
+    }
+
+    fn push_ordinates(&mut self, x: f64, y: f64, z: Option<f64>, m: Option<f64>) {
+        self.coords.push(x);
+        self.coords.push(y);
+        if self.dim.has_z() {
+            self.coords.push(z.unwrap_or(f64::NAN));
+        }
+        if self.dim.has_m() {
+            self.coords.push(m.unwrap_or(f64::NAN));
+        }
     }
 
+    /// Set the x and y values of the coordinate at index `i`, keeping its z and m values.
     pub fn set_xy(&mut self, i: usize, x: f64, y: f64) {
-        self.coords[i * 2] = x;
-        self.coords[i * 2 + 1] = y;
+        let size = self.dim.size();
+        self.coords[i * size] = x;
+        self.coords[i * size + 1] = y;
     }
 
+    /// Add a coordinate to the end of this buffer, with `NaN` z and m values if the buffer has
+    /// them.
     pub fn push_xy(&mut self, x: f64, y: f64) {
-        self.coords.push(x);
-        self.coords.push(y);
+        self.push_ordinates(x, y, None, None);
+    }
+
+    /// Add a coordinate to the end of this buffer, dropping the z value if the buffer has none.
+    pub fn push_xyz(&mut self, x: f64, y: f64, z: f64) {
+        self.push_ordinates(x, y, Some(z), None);
+    }
+
+    /// Add a measured coordinate to the end of this buffer, dropping the m value if the buffer
+    /// has none.
+    pub fn push_xym(&mut self, x: f64, y: f64, m: f64) {
+        self.push_ordinates(x, y, None, Some(m));
+    }
+
+    /// Add a coordinate to the end of this buffer, dropping the z and m values it has no room
+    /// for.
+    pub fn push_xyzm(&mut self, x: f64, y: f64, z: f64, m: f64) {
+        self.push_ordinates(x, y, Some(z), Some(m));
     }
 
     pub fn len(&self) -> usize {
-        self.coords.len() / 2
+        self.coords.len() / self.dim.size()
     }
 
     pub fn is_empty(&self) -> bool {
@@ -91,6 +171,6 @@ impl Default for MutableInterleavedCoordBuffer {
 
 impl From<MutableInterleavedCoordBuffer> for InterleavedCoordBuffer {
     fn from(value: MutableInterleavedCoordBuffer) -> Self {
-        InterleavedCoordBuffer::new(value.coords.into())
+        InterleavedCoordBuffer::new_with_dim(value.coords.into(), value.dim)
     }
 }
@@ -2,13 +2,16 @@
 //! types.
 //!
 //! Coordinates can be either _interleaved_, where they're represented as a `FixedSizeList`, or
-//! _separated_, where they're represented with a `StructArray`.
+//! _separated_, where they're represented with a `StructArray`. Interleaved coordinates can also be
+//! stored as 32-bit floats.
 
 pub mod combined;
+pub mod float32;
 pub mod interleaved;
 pub mod separated;
 
 pub use combined::{CoordBuffer, MutableCoordBuffer};
+pub use float32::Float32CoordBuffer;
 pub use interleaved::{InterleavedCoordBuffer, MutableInterleavedCoordBuffer};
 pub use separated::{MutableSeparatedCoordBuffer, SeparatedCoordBuffer};
 
@@ -17,3 +20,118 @@ pub enum CoordType {
     Interleaved,
     Separated,
 }
+
+/// The dimensions of the coordinates in a buffer.
+///
+/// Coordinates with a z value or an m (measure) value are stored as a `FixedSizeList` of 3 or 4
+/// values when interleaved, whose child field is named after the dimension, such as `xym`. When
+/// separated, they are stored as a struct with `x`, `y` and then `z` and/or `m` fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Dimension {
+    #[default]
+    XY,
+    XYZ,
+    XYM,
+    XYZM,
+}
+
+impl Dimension {
+    /// The dimension with or without z and m values.
+    pub fn from_flags(has_z: bool, has_m: bool) -> Self {
+        match (has_z, has_m) {
+            (false, false) => Dimension::XY,
+            (true, false) => Dimension::XYZ,
+            (false, true) => Dimension::XYM,
+            (true, true) => Dimension::XYZM,
+        }
+    }
+
+    /// The number of values that make up each coordinate.
+    pub fn size(&self) -> usize {
+        match self {
+            Dimension::XY => 2,
+            Dimension::XYZ | Dimension::XYM => 3,
+            Dimension::XYZM => 4,
+        }
+    }
+
+    /// Whether coordinates have a z value.
+    pub fn has_z(&self) -> bool {
+        matches!(self, Dimension::XYZ | Dimension::XYZM)
+    }
+
+    /// Whether coordinates have an m value.
+    pub fn has_m(&self) -> bool {
+        matches!(self, Dimension::XYM | Dimension::XYZM)
+    }
+
+    /// The position of the z value within an interleaved coordinate.
+    pub(crate) fn z_index(&self) -> Option<usize> {
+        self.has_z().then_some(2)
+    }
+
+    /// The position of the m value within an interleaved coordinate, which follows z if there is
+    /// one.
+    pub(crate) fn m_index(&self) -> Option<usize> {
+        self.has_m().then_some(self.size() - 1)
+    }
+
+    /// The dimension that holds the coordinates of all of `dims`, which has z values if any of
+    /// them does, and m values if any of them does.
+    pub(crate) fn widest(dims: impl IntoIterator<Item = Dimension>) -> Self {
+        let (has_z, has_m) = dims
+            .into_iter()
+            .fold((false, false), |(has_z, has_m), dim| {
+                (has_z || dim.has_z(), has_m || dim.has_m())
+            });
+        Self::from_flags(has_z, has_m)
+    }
+
+    /// The dimension with `size` values per coordinate, if there is one.
+    ///
+    /// Three values are read as XYZ, as XYM can't be told apart by its size alone.
+    pub fn from_size(size: usize) -> Option<Self> {
+        match size {
+            2 => Some(Dimension::XY),
+            3 => Some(Dimension::XYZ),
+            4 => Some(Dimension::XYZM),
+            _ => None,
+        }
+    }
+
+    /// The name of the child field of an interleaved coordinate list, such as `xyz`.
+    pub(crate) fn interleaved_field_name(&self) -> &'static str {
+        match self {
+            Dimension::XY => "xy",
+            Dimension::XYZ => "xyz",
+            Dimension::XYM => "xym",
+            Dimension::XYZM => "xyzm",
+        }
+    }
+
+    /// The dimension of an interleaved coordinate list with `size` values per coordinate and a
+    /// child field named `field_name`.
+    ///
+    /// The name tells XYZ and XYM apart. Other names fall back to [`Dimension::from_size`].
+    pub(crate) fn from_interleaved_field(field_name: &str, size: usize) -> Option<Self> {
+        match (field_name, size) {
+            ("xym", 3) => Some(Dimension::XYM),
+            _ => Self::from_size(size),
+        }
+    }
+
+    /// The dimension of a separated coordinate struct with fields named `field_names`, which are
+    /// `x` and `y` followed by `z` and/or `m`.
+    pub(crate) fn from_separated_fields<'a>(
+        field_names: impl IntoIterator<Item = &'a str>,
+    ) -> Option<Self> {
+        let names: Vec<&str> = field_names.into_iter().collect();
+        match names.as_slice() {
+            [_, _] => Some(Dimension::XY),
+            [_, _, "m"] => Some(Dimension::XYM),
+            [_, _, _] => Some(Dimension::XYZ),
+            [_, _, _, _] => Some(Dimension::XYZM),
+            _ => None,
+        }
+    }
+}
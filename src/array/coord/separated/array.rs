@@ -5,7 +5,7 @@ use arrow_array::{Array, Float64Array, StructArray};
 use arrow_buffer::{NullBuffer, ScalarBuffer};
 use arrow_schema::{DataType, Field};
 
-use crate::array::CoordType;
+use crate::array::{CoordBuffer, CoordType, Dimension};
 use crate::error::{GeoArrowError, Result};
 use crate::scalar::SeparatedCoord;
 use crate::trait_::GeoArrayAccessor;
@@ -15,12 +15,24 @@ use crate::GeometryArrayTrait;
 pub struct SeparatedCoordBuffer {
     pub x: ScalarBuffer<f64>,
     pub y: ScalarBuffer<f64>,
+
+    /// The z values, which are only present for XYZ and XYZM coordinates
+    pub z: Option<ScalarBuffer<f64>>,
+
+    /// The m values, which are only present for XYM and XYZM coordinates
+    pub m: Option<ScalarBuffer<f64>>,
 }
 
-fn check(x: &ScalarBuffer<f64>, y: &ScalarBuffer<f64>) -> Result<()> {
-    if x.len() != y.len() {
+fn check(
+    x: &ScalarBuffer<f64>,
+    y: &ScalarBuffer<f64>,
+    z: Option<&ScalarBuffer<f64>>,
+    m: Option<&ScalarBuffer<f64>>,
+) -> Result<()> {
+    let has_len = |values: Option<&ScalarBuffer<f64>>| values.map_or(true, |v| v.len() == x.len());
+    if x.len() != y.len() || !has_len(z) || !has_len(m) {
         return Err(GeoArrowError::General(
-            "x and y arrays must have the same length".to_string(),
+            "x, y, z and m arrays must have the same length".to_string(),
         ));
     }
 
@@ -34,8 +46,7 @@ impl SeparatedCoordBuffer {
     ///
     /// - if the x and y buffers have different lengths
     pub fn new(x: ScalarBuffer<f64>, y: ScalarBuffer<f64>) -> Self {
-        check(&x, &y).unwrap();
-        Self { x, y }
+        Self::try_new(x, y).unwrap()
     }
 
     /// Construct a new SeparatedCoordBuffer
@@ -44,22 +55,113 @@ impl SeparatedCoordBuffer {
     ///
     /// - if the x and y buffers have different lengths
     pub fn try_new(x: ScalarBuffer<f64>, y: ScalarBuffer<f64>) -> Result<Self> {
-        check(&x, &y)?;
-        Ok(Self { x, y })
+        Self::try_new_with_z(x, y, None)
+    }
+
+    /// Construct a new SeparatedCoordBuffer, with XYZ coordinates if `z` is given
+    ///
+    /// # Panics
+    ///
+    /// - if the x, y and z buffers have different lengths
+    pub fn new_with_z(
+        x: ScalarBuffer<f64>,
+        y: ScalarBuffer<f64>,
+        z: Option<ScalarBuffer<f64>>,
+    ) -> Self {
+        Self::try_new_with_z(x, y, z).unwrap()
+    }
+
+    /// Construct a new SeparatedCoordBuffer, with XYZ coordinates if `z` is given
+    ///
+    /// # Errors
+    ///
+    /// - if the x, y and z buffers have different lengths
+    pub fn try_new_with_z(
+        x: ScalarBuffer<f64>,
+        y: ScalarBuffer<f64>,
+        z: Option<ScalarBuffer<f64>>,
+    ) -> Result<Self> {
+        Self::try_new_with_zm(x, y, z, None)
+    }
+
+    /// Construct a new SeparatedCoordBuffer, with z and m values if they are given
+    ///
+    /// # Panics
+    ///
+    /// - if the x, y, z and m buffers have different lengths
+    pub fn new_with_zm(
+        x: ScalarBuffer<f64>,
+        y: ScalarBuffer<f64>,
+        z: Option<ScalarBuffer<f64>>,
+        m: Option<ScalarBuffer<f64>>,
+    ) -> Self {
+        Self::try_new_with_zm(x, y, z, m).unwrap()
+    }
+
+    /// Construct a new SeparatedCoordBuffer, with z and m values if they are given
+    ///
+    /// # Errors
+    ///
+    /// - if the x, y, z and m buffers have different lengths
+    pub fn try_new_with_zm(
+        x: ScalarBuffer<f64>,
+        y: ScalarBuffer<f64>,
+        z: Option<ScalarBuffer<f64>>,
+        m: Option<ScalarBuffer<f64>>,
+    ) -> Result<Self> {
+        check(&x, &y, z.as_ref(), m.as_ref())?;
+        Ok(Self { x, y, z, m })
+    }
+
+    /// The dimension of the coordinates in this buffer.
+    pub fn dim(&self) -> Dimension {
+        Dimension::from_flags(self.z.is_some(), self.m.is_some())
+    }
+
+    /// The z value of the coordinate at index `i`, or `None` for XY coordinates.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn get_z(&self, i: usize) -> Option<f64> {
+        self.z.as_ref().map(|z| z[i])
+    }
+
+    /// The m value of the coordinate at index `i`, or `None` for coordinates without a measure.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn get_m(&self, i: usize) -> Option<f64> {
+        self.m.as_ref().map(|m| m[i])
     }
 
     pub fn values_array(&self) -> Vec<Arc<dyn Array>> {
-        vec![
+        let mut arrays: Vec<Arc<dyn Array>> = vec![
             Arc::new(Float64Array::new(self.x.clone(), None)),
             Arc::new(Float64Array::new(self.y.clone(), None)),
-        ]
+        ];
+        if let Some(z) = &self.z {
+            arrays.push(Arc::new(Float64Array::new(z.clone(), None)));
+        }
+        if let Some(m) = &self.m {
+            arrays.push(Arc::new(Float64Array::new(m.clone(), None)));
+        }
+        arrays
     }
 
     pub fn values_field(&self) -> Vec<Field> {
-        vec![
+        let mut fields = vec![
             Field::new("x", DataType::Float64, false),
             Field::new("y", DataType::Float64, false),
-        ]
+        ];
+        if self.z.is_some() {
+            fields.push(Field::new("z", DataType::Float64, false));
+        }
+        if self.m.is_some() {
+            fields.push(Field::new("m", DataType::Float64, false));
+        }
+        fields
     }
 }
 
@@ -104,14 +206,39 @@ impl<'a> GeometryArrayTrait<'a> for SeparatedCoordBuffer {
         panic!("into_coord_type only implemented on CoordBuffer");
     }
 
+    fn into_coord_float32(self) -> Self {
+        panic!("into_coord_float32 only implemented on CoordBuffer");
+    }
+
+    fn into_coord_float64(self) -> Self {
+        panic!("into_coord_float64 only implemented on CoordBuffer");
+    }
+
     fn len(&self) -> usize {
         self.x.len()
     }
 
+    fn memory_size(&self) -> usize {
+        [
+            Some(&self.x),
+            Some(&self.y),
+            self.z.as_ref(),
+            self.m.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|values| std::mem::size_of_val(values.as_ref()))
+        .sum()
+    }
+
     fn validity(&self) -> Option<&NullBuffer> {
         panic!("coordinate arrays don't have their own validity arrays")
     }
 
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        CoordBuffer::Separated(self.clone()).total_bounds()
+    }
+
     fn slice(&self, offset: usize, length: usize) -> Self {
         assert!(
             offset + length <= self.len(),
@@ -120,12 +247,19 @@ impl<'a> GeometryArrayTrait<'a> for SeparatedCoordBuffer {
         Self {
             x: self.x.slice(offset, length),
             y: self.y.slice(offset, length),
+            z: self.z.as_ref().map(|z| z.slice(offset, length)),
+            m: self.m.as_ref().map(|m| m.slice(offset, length)),
         }
     }
 
     fn owned_slice(&self, offset: usize, length: usize) -> Self {
         let buffer = self.slice(offset, length);
-        Self::new(buffer.x.to_vec().into(), buffer.y.to_vec().into())
+        Self::new_with_zm(
+            buffer.x.to_vec().into(),
+            buffer.y.to_vec().into(),
+            buffer.z.map(|z| z.to_vec().into()),
+            buffer.m.map(|m| m.to_vec().into()),
+        )
     }
 }
 
@@ -137,6 +271,8 @@ impl<'a> GeoArrayAccessor<'a> for SeparatedCoordBuffer {
         SeparatedCoord {
             x: &self.x,
             y: &self.y,
+            z: self.z.as_ref(),
+            m: self.m.as_ref(),
             i: index,
         }
     }
@@ -145,22 +281,47 @@ impl<'a> GeoArrayAccessor<'a> for SeparatedCoordBuffer {
 impl TryFrom<&StructArray> for SeparatedCoordBuffer {
     type Error = GeoArrowError;
 
+    /// Read coordinates from a struct of `x` and `y` arrays, followed by a `z` array, an `m` array
+    /// or both.
+    ///
+    /// A third array is read as z unless its field is named `m`.
+    ///
+    /// # Errors
+    ///
+    /// - if the struct does not have two to four child arrays
+    /// - if the child arrays are not `Float64`
     fn try_from(value: &StructArray) -> Result<Self> {
         let arrays = value.columns();
 
-        if !arrays.len() == 2 {
-            return Err(GeoArrowError::General(
-                "Expected two child arrays of this StructArray.".to_string(),
-            ));
-        }
-
-        let x_array_values = arrays[0].as_any().downcast_ref::<Float64Array>().unwrap();
-        let y_array_values = arrays[1].as_any().downcast_ref::<Float64Array>().unwrap();
-
-        Ok(SeparatedCoordBuffer::new(
-            x_array_values.values().clone(),
-            y_array_values.values().clone(),
-        ))
+        let dim = Dimension::from_separated_fields(
+            value.fields().iter().map(|field| field.name().as_str()),
+        )
+        .ok_or_else(|| {
+            GeoArrowError::General(format!(
+                "Expected two to four child arrays of this StructArray, got {}",
+                arrays.len()
+            ))
+        })?;
+
+        let values = arrays
+            .iter()
+            .map(|array| {
+                array
+                    .as_any()
+                    .downcast_ref::<Float64Array>()
+                    .map(|array| array.values().clone())
+                    .ok_or_else(|| {
+                        GeoArrowError::General(format!(
+                            "Expected Float64 coordinates, got {}",
+                            array.data_type()
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let z = dim.z_index().map(|index| values[index].clone());
+        let m = dim.m_index().map(|index| values[index].clone());
+        SeparatedCoordBuffer::try_new_with_zm(values[0].clone(), values[1].clone(), z, m)
     }
 }
 
@@ -191,4 +352,61 @@ mod test {
 
         assert_eq!(buf1, buf2);
     }
+
+    #[test]
+    fn xyz_struct_roundtrip() {
+        let buf = SeparatedCoordBuffer::new_with_z(
+            vec![0., 1.].into(),
+            vec![3., 4.].into(),
+            Some(vec![6., 7.].into()),
+        );
+        assert_eq!(buf.dim(), Dimension::XYZ);
+
+        let array = buf.clone().into_array_ref();
+        let struct_array = array.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(struct_array.num_columns(), 3);
+        assert_eq!(SeparatedCoordBuffer::try_from(struct_array).unwrap(), buf);
+
+        let sliced = buf.slice(1, 1);
+        assert_eq!(sliced.get_z(0), Some(7.));
+    }
+
+    #[test]
+    fn measured_struct_roundtrip() {
+        let xym = SeparatedCoordBuffer::new_with_zm(
+            vec![0., 1.].into(),
+            vec![3., 4.].into(),
+            None,
+            Some(vec![6., 7.].into()),
+        );
+        assert_eq!(xym.dim(), Dimension::XYM);
+        assert_eq!(xym.get_z(1), None);
+        assert_eq!(xym.get_m(1), Some(7.));
+
+        let array = xym.clone().into_array_ref();
+        let struct_array = array.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(struct_array.column_names(), vec!["x", "y", "m"]);
+        assert_eq!(SeparatedCoordBuffer::try_from(struct_array).unwrap(), xym);
+
+        let xyzm = SeparatedCoordBuffer::new_with_zm(
+            vec![0., 1.].into(),
+            vec![3., 4.].into(),
+            Some(vec![6., 7.].into()),
+            Some(vec![9., 10.].into()),
+        );
+        assert_eq!(xyzm.dim(), Dimension::XYZM);
+        let array = xyzm.clone().into_array_ref();
+        let struct_array = array.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(SeparatedCoordBuffer::try_from(struct_array).unwrap(), xyzm);
+        assert_eq!(xyzm.slice(1, 1).get_m(0), Some(10.));
+
+        // Every ordinate must have a value for each coordinate
+        assert!(SeparatedCoordBuffer::try_new_with_zm(
+            vec![0., 1.].into(),
+            vec![3., 4.].into(),
+            None,
+            Some(vec![6.].into()),
+        )
+        .is_err());
+    }
 }
@@ -1,9 +1,16 @@
-use crate::array::SeparatedCoordBuffer;
+use crate::array::{Dimension, SeparatedCoordBuffer};
+use crate::geo_traits::CoordTrait;
 
 #[derive(Debug, Clone)]
 pub struct MutableSeparatedCoordBuffer {
     x: Vec<f64>,
     y: Vec<f64>,
+
+    /// The z values, which are only kept for XYZ and XYZM coordinates
+    z: Option<Vec<f64>>,
+
+    /// The m values, which are only kept for XYM and XYZM coordinates
+    m: Option<Vec<f64>>,
 }
 
 impl MutableSeparatedCoordBuffer {
@@ -13,13 +20,25 @@ impl MutableSeparatedCoordBuffer {
     }
 
     pub fn from_vecs(x: Vec<f64>, y: Vec<f64>) -> Self {
-        Self { x, y }
+        Self {
+            x,
+            y,
+            z: None,
+            m: None,
+        }
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_dim(capacity, Dimension::XY)
+    }
+
+    /// Creates a new empty buffer of `dim` coordinates, with room for `capacity` of them.
+    pub fn with_capacity_and_dim(capacity: usize, dim: Dimension) -> Self {
         Self {
             x: Vec::with_capacity(capacity),
             y: Vec::with_capacity(capacity),
+            z: dim.has_z().then(|| Vec::with_capacity(capacity)),
+            m: dim.has_m().then(|| Vec::with_capacity(capacity)),
         }
     }
 
@@ -28,9 +47,16 @@ impl MutableSeparatedCoordBuffer {
         Self {
             x: vec![0.0f64; len],
             y: vec![0.0f64; len],
+            z: None,
+            m: None,
         }
     }
 
+    /// The dimension of the coordinates in this buffer.
+    pub fn dim(&self) -> Dimension {
+        Dimension::from_flags(self.z.is_some(), self.m.is_some())
+    }
+
     /// Reserves capacity for at least `additional` more coordinates to be inserted
     /// in the given `Vec<T>`. The collection may reserve more space to
     /// speculatively avoid frequent reallocations. After calling `reserve`,
@@ -39,6 +65,9 @@ impl MutableSeparatedCoordBuffer {
     pub fn reserve(&mut self, additional: usize) {
         self.x.reserve(additional);
         self.y.reserve(additional);
+        for values in [self.z.as_mut(), self.m.as_mut()].into_iter().flatten() {
+            values.reserve(additional);
+        }
     }
 
     /// Reserves the minimum capacity for at least `additional` more coordinates to
@@ -56,6 +85,9 @@ impl MutableSeparatedCoordBuffer {
     pub fn reserve_exact(&mut self, additional: usize) {
         self.x.reserve_exact(additional);
         self.y.reserve_exact(additional);
+        for values in [self.z.as_mut(), self.m.as_mut()].into_iter().flatten() {
+            values.reserve_exact(additional);
+        }
     }
 
     /// Returns the total number of coordinates the vector can hold without reallocating.
@@ -63,24 +95,84 @@ impl MutableSeparatedCoordBuffer {
         self.x.capacity()
     }
 
+    /// The coordinate at index `i`.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn coord(&self, i: usize) -> geo::Coord {
+        geo::coord! { x: self.x[i], y: self.y[i] }
+    }
+
+    /// The z value of the coordinate at index `i`, or `None` if this buffer has no z values.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn get_z(&self, i: usize) -> Option<f64> {
+        self.z.as_ref().map(|z| z[i])
+    }
+
+    /// The m value of the coordinate at index `i`, or `None` if this buffer has no measures.
+    ///
+    /// # Panics
+    ///
+    /// - if `i` is out of bounds
+    pub fn get_m(&self, i: usize) -> Option<f64> {
+        self.m.as_ref().map(|m| m[i])
+    }
+
     pub fn set_coord(&mut self, i: usize, coord: geo::Coord) {
         self.x[i] = coord.x;
         self.y[i] = coord.y;
     }
 
-    pub fn push_coord(&mut self, coord: geo::Coord) {
-        self.x.push(coord.x);
-        self.y.push(coord.y);
+    /// Add a coordinate to the end of this buffer.
+    ///
+    /// The buffer stores `NaN` for a z or m value that the coordinate doesn't have, and drops the
+    /// values that its dimension doesn't have.
+    pub fn push_coord(&mut self, coord: impl CoordTrait<T = f64>) {
+        self.push_ordinates(coord.x(), coord.y(), coord.z(), coord.m());
+    }
+
+    fn push_ordinates(&mut self, x: f64, y: f64, z: Option<f64>, m: Option<f64>) {
+        self.x.push(x);
+        self.y.push(y);
+        if let Some(z_values) = self.z.as_mut() {
+            z_values.push(z.unwrap_or(f64::NAN));
+        }
+        if let Some(m_values) = self.m.as_mut() {
+            m_values.push(m.unwrap_or(f64::NAN));
+        }
     }
 
+    /// Set the x and y values of the coordinate at index `i`, keeping its z and m values.
     pub fn set_xy(&mut self, i: usize, x: f64, y: f64) {
         self.x[i] = x;
         self.y[i] = y;
     }
 
+    /// Add a coordinate to the end of this buffer, with `NaN` z and m values if the buffer has
+    /// them.
     pub fn push_xy(&mut self, x: f64, y: f64) {
-        self.x.push(x);
-        self.y.push(y);
+        self.push_ordinates(x, y, None, None);
+    }
+
+    /// Add a coordinate to the end of this buffer, dropping the z value if the buffer has none.
+    pub fn push_xyz(&mut self, x: f64, y: f64, z: f64) {
+        self.push_ordinates(x, y, Some(z), None);
+    }
+
+    /// Add a measured coordinate to the end of this buffer, dropping the m value if the buffer
+    /// has none.
+    pub fn push_xym(&mut self, x: f64, y: f64, m: f64) {
+        self.push_ordinates(x, y, None, Some(m));
+    }
+
+    /// Add a coordinate to the end of this buffer, dropping the z and m values it has no room
+    /// for.
+    pub fn push_xyzm(&mut self, x: f64, y: f64, z: f64, m: f64) {
+        self.push_ordinates(x, y, Some(z), Some(m));
     }
 
     pub fn len(&self) -> usize {
@@ -100,6 +192,11 @@ impl Default for MutableSeparatedCoordBuffer {
 
 impl From<MutableSeparatedCoordBuffer> for SeparatedCoordBuffer {
     fn from(value: MutableSeparatedCoordBuffer) -> Self {
-        SeparatedCoordBuffer::new(value.x.into(), value.y.into())
+        SeparatedCoordBuffer::new_with_zm(
+            value.x.into(),
+            value.y.into(),
+            value.z.map(Into::into),
+            value.m.map(Into::into),
+        )
     }
 }
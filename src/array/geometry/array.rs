@@ -7,8 +7,8 @@ use arrow_schema::{DataType, Field};
 use crate::algorithm::native::type_id::TypeIds;
 // use crate::algorithm::native::type_id::TypeIds;
 use crate::array::{
-    LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray, PointArray,
-    PolygonArray, RectArray, WKBArray,
+    ArrayMetadata, GeometryCollectionArray, LineStringArray, MultiLineStringArray, MultiPointArray,
+    MultiPolygonArray, PointArray, PolygonArray, RectArray, WKBArray,
 };
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
@@ -29,9 +29,37 @@ pub enum GeometryArray<O: OffsetSizeTrait> {
     MultiPoint(MultiPointArray<O>),
     MultiLineString(MultiLineStringArray<O>),
     MultiPolygon(MultiPolygonArray<O>),
+    GeometryCollection(Box<GeometryCollectionArray<O>>),
     Rect(RectArray),
 }
 
+impl<O: OffsetSizeTrait> GeometryArray<O> {
+    /// Replace the [`ArrayMetadata`] of the underlying array.
+    ///
+    /// [`GeometryCollectionArray`] and [`RectArray`] do not store metadata, so this is a no-op for
+    /// the `GeometryCollection` and `Rect` variants.
+    pub fn with_metadata(self, metadata: ArrayMetadata) -> Self {
+        match self {
+            GeometryArray::Point(arr) => GeometryArray::Point(arr.with_metadata(metadata)),
+            GeometryArray::LineString(arr) => {
+                GeometryArray::LineString(arr.with_metadata(metadata))
+            }
+            GeometryArray::Polygon(arr) => GeometryArray::Polygon(arr.with_metadata(metadata)),
+            GeometryArray::MultiPoint(arr) => {
+                GeometryArray::MultiPoint(arr.with_metadata(metadata))
+            }
+            GeometryArray::MultiLineString(arr) => {
+                GeometryArray::MultiLineString(arr.with_metadata(metadata))
+            }
+            GeometryArray::MultiPolygon(arr) => {
+                GeometryArray::MultiPolygon(arr.with_metadata(metadata))
+            }
+            GeometryArray::GeometryCollection(arr) => GeometryArray::GeometryCollection(arr),
+            GeometryArray::Rect(arr) => GeometryArray::Rect(arr),
+        }
+    }
+}
+
 impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryArray<O> {
     fn as_any(&self) -> &dyn std::any::Any {
         // Note: I don't think this will work because you presumably can't downcast past the
@@ -43,6 +71,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryArray<O> {
             GeometryArray::MultiPoint(arr) => arr.as_any(),
             GeometryArray::MultiLineString(arr) => arr.as_any(),
             GeometryArray::MultiPolygon(arr) => arr.as_any(),
+            GeometryArray::GeometryCollection(arr) => arr.as_any(),
             GeometryArray::Rect(arr) => arr.as_any(),
         }
     }
@@ -55,6 +84,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryArray<O> {
             GeometryArray::MultiPoint(arr) => arr.data_type(),
             GeometryArray::MultiLineString(arr) => arr.data_type(),
             GeometryArray::MultiPolygon(arr) => arr.data_type(),
+            GeometryArray::GeometryCollection(arr) => arr.data_type(),
             GeometryArray::Rect(arr) => arr.data_type(),
         }
     }
@@ -67,6 +97,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryArray<O> {
             GeometryArray::MultiPoint(arr) => arr.storage_type(),
             GeometryArray::MultiLineString(arr) => arr.storage_type(),
             GeometryArray::MultiPolygon(arr) => arr.storage_type(),
+            GeometryArray::GeometryCollection(arr) => arr.storage_type(),
             GeometryArray::Rect(arr) => arr.storage_type(),
         }
     }
@@ -79,6 +110,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryArray<O> {
             GeometryArray::MultiPoint(arr) => arr.extension_field(),
             GeometryArray::MultiLineString(arr) => arr.extension_field(),
             GeometryArray::MultiPolygon(arr) => arr.extension_field(),
+            GeometryArray::GeometryCollection(arr) => arr.extension_field(),
             GeometryArray::Rect(arr) => arr.extension_field(),
         }
     }
@@ -91,10 +123,24 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryArray<O> {
             GeometryArray::MultiPoint(arr) => arr.extension_name(),
             GeometryArray::MultiLineString(arr) => arr.extension_name(),
             GeometryArray::MultiPolygon(arr) => arr.extension_name(),
+            GeometryArray::GeometryCollection(arr) => arr.extension_name(),
             GeometryArray::Rect(arr) => arr.extension_name(),
         }
     }
 
+    fn metadata(&self) -> &ArrayMetadata {
+        match self {
+            GeometryArray::Point(arr) => arr.metadata(),
+            GeometryArray::LineString(arr) => arr.metadata(),
+            GeometryArray::Polygon(arr) => arr.metadata(),
+            GeometryArray::MultiPoint(arr) => arr.metadata(),
+            GeometryArray::MultiLineString(arr) => arr.metadata(),
+            GeometryArray::MultiPolygon(arr) => arr.metadata(),
+            GeometryArray::GeometryCollection(arr) => arr.metadata(),
+            GeometryArray::Rect(arr) => arr.metadata(),
+        }
+    }
+
     fn into_array_ref(self) -> Arc<dyn Array> {
         match self {
             GeometryArray::Point(arr) => arr.into_array_ref(),
@@ -103,6 +149,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryArray<O> {
             GeometryArray::MultiPoint(arr) => arr.into_array_ref(),
             GeometryArray::MultiLineString(arr) => arr.into_array_ref(),
             GeometryArray::MultiPolygon(arr) => arr.into_array_ref(),
+            GeometryArray::GeometryCollection(arr) => arr.into_array_ref(),
             GeometryArray::Rect(arr) => arr.into_array_ref(),
         }
     }
@@ -119,6 +166,9 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryArray<O> {
             GeometryArray::MultiPolygon(arr) => {
                 GeometryArray::MultiPolygon(arr.with_coords(coords))
             }
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(Box::new(arr.with_coords(coords)))
+            }
             GeometryArray::Rect(arr) => GeometryArray::Rect(arr.with_coords(coords)),
         }
     }
@@ -131,6 +181,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryArray<O> {
             GeometryArray::MultiPoint(arr) => arr.coord_type(),
             GeometryArray::MultiLineString(arr) => arr.coord_type(),
             GeometryArray::MultiPolygon(arr) => arr.coord_type(),
+            GeometryArray::GeometryCollection(arr) => arr.coord_type(),
             GeometryArray::Rect(arr) => arr.coord_type(),
         }
     }
@@ -151,10 +202,51 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryArray<O> {
             GeometryArray::MultiPolygon(arr) => {
                 GeometryArray::MultiPolygon(arr.into_coord_type(coord_type))
             }
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(Box::new(arr.into_coord_type(coord_type)))
+            }
             GeometryArray::Rect(arr) => GeometryArray::Rect(arr.into_coord_type(coord_type)),
         }
     }
 
+    fn into_coord_float32(self) -> Self {
+        match self {
+            GeometryArray::Point(arr) => GeometryArray::Point(arr.into_coord_float32()),
+            GeometryArray::LineString(arr) => GeometryArray::LineString(arr.into_coord_float32()),
+            GeometryArray::Polygon(arr) => GeometryArray::Polygon(arr.into_coord_float32()),
+            GeometryArray::MultiPoint(arr) => GeometryArray::MultiPoint(arr.into_coord_float32()),
+            GeometryArray::MultiLineString(arr) => {
+                GeometryArray::MultiLineString(arr.into_coord_float32())
+            }
+            GeometryArray::MultiPolygon(arr) => {
+                GeometryArray::MultiPolygon(arr.into_coord_float32())
+            }
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(Box::new(arr.into_coord_float32()))
+            }
+            GeometryArray::Rect(arr) => GeometryArray::Rect(arr.into_coord_float32()),
+        }
+    }
+
+    fn into_coord_float64(self) -> Self {
+        match self {
+            GeometryArray::Point(arr) => GeometryArray::Point(arr.into_coord_float64()),
+            GeometryArray::LineString(arr) => GeometryArray::LineString(arr.into_coord_float64()),
+            GeometryArray::Polygon(arr) => GeometryArray::Polygon(arr.into_coord_float64()),
+            GeometryArray::MultiPoint(arr) => GeometryArray::MultiPoint(arr.into_coord_float64()),
+            GeometryArray::MultiLineString(arr) => {
+                GeometryArray::MultiLineString(arr.into_coord_float64())
+            }
+            GeometryArray::MultiPolygon(arr) => {
+                GeometryArray::MultiPolygon(arr.into_coord_float64())
+            }
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(Box::new(arr.into_coord_float64()))
+            }
+            GeometryArray::Rect(arr) => GeometryArray::Rect(arr.into_coord_float64()),
+        }
+    }
+
     /// The length of the [`GeometryArray`]. Every array has a length corresponding to the number
     /// of geometries it contains.
     fn len(&self) -> usize {
@@ -165,10 +257,24 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryArray<O> {
             GeometryArray::MultiPoint(arr) => arr.len(),
             GeometryArray::MultiLineString(arr) => arr.len(),
             GeometryArray::MultiPolygon(arr) => arr.len(),
+            GeometryArray::GeometryCollection(arr) => arr.len(),
             GeometryArray::Rect(arr) => arr.len(),
         }
     }
 
+    fn memory_size(&self) -> usize {
+        match self {
+            GeometryArray::Point(arr) => arr.memory_size(),
+            GeometryArray::LineString(arr) => arr.memory_size(),
+            GeometryArray::Polygon(arr) => arr.memory_size(),
+            GeometryArray::MultiPoint(arr) => arr.memory_size(),
+            GeometryArray::MultiLineString(arr) => arr.memory_size(),
+            GeometryArray::MultiPolygon(arr) => arr.memory_size(),
+            GeometryArray::GeometryCollection(arr) => arr.memory_size(),
+            GeometryArray::Rect(arr) => arr.memory_size(),
+        }
+    }
+
     /// The validity of the [`GeometryArray`]: every array has an optional [`Bitmap`] that, when
     /// available specifies whether the geometry at a given slot is valid or not (null). When the
     /// validity is [`None`], all slots are valid.
@@ -180,10 +286,24 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryArray<O> {
             GeometryArray::MultiPoint(arr) => arr.nulls(),
             GeometryArray::MultiLineString(arr) => arr.nulls(),
             GeometryArray::MultiPolygon(arr) => arr.nulls(),
+            GeometryArray::GeometryCollection(arr) => arr.nulls(),
             GeometryArray::Rect(arr) => arr.nulls(),
         }
     }
 
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        match self {
+            GeometryArray::Point(arr) => arr.total_bounds(),
+            GeometryArray::LineString(arr) => arr.total_bounds(),
+            GeometryArray::Polygon(arr) => arr.total_bounds(),
+            GeometryArray::MultiPoint(arr) => arr.total_bounds(),
+            GeometryArray::MultiLineString(arr) => arr.total_bounds(),
+            GeometryArray::MultiPolygon(arr) => arr.total_bounds(),
+            GeometryArray::GeometryCollection(arr) => arr.total_bounds(),
+            GeometryArray::Rect(arr) => arr.total_bounds(),
+        }
+    }
+
     /// Slices the [`GeometryArray`] in place
     /// # Implementation
     /// This operation is `O(1)` over `len`, as it amounts to increase two ref counts
@@ -202,6 +322,9 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryArray<O> {
             GeometryArray::MultiPolygon(arr) => {
                 GeometryArray::MultiPolygon(arr.slice(offset, length))
             }
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(Box::new(arr.slice(offset, length)))
+            }
             GeometryArray::Rect(arr) => GeometryArray::Rect(arr.slice(offset, length)),
         }
     }
@@ -222,6 +345,9 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryArray<O> {
             GeometryArray::MultiPolygon(arr) => {
                 GeometryArray::MultiPolygon(arr.owned_slice(offset, length))
             }
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(Box::new(arr.owned_slice(offset, length)))
+            }
             GeometryArray::Rect(arr) => GeometryArray::Rect(arr.owned_slice(offset, length)),
         }
     }
@@ -246,6 +372,9 @@ impl<'a, O: OffsetSizeTrait> GeoArrayAccessor<'a> for GeometryArray<O> {
                 Geometry::MultiLineString(arr.value_unchecked(index))
             }
             GeometryArray::MultiPolygon(arr) => Geometry::MultiPolygon(arr.value_unchecked(index)),
+            GeometryArray::GeometryCollection(arr) => {
+                Geometry::GeometryCollection(arr.value_unchecked(index))
+            }
             GeometryArray::Rect(arr) => Geometry::Rect(arr.value_unchecked(index)),
         }
     }
@@ -263,6 +392,10 @@ impl TryFrom<(&Field, &dyn Array)> for GeometryArray<i32> {
                 "geoarrow.multipoint" => Ok(GeometryArray::MultiPoint(array.try_into()?)),
                 "geoarrow.multilinestring" => Ok(GeometryArray::MultiLineString(array.try_into()?)),
                 "geoarrow.multipolygon" => Ok(GeometryArray::MultiPolygon(array.try_into()?)),
+                "geoarrow.geometrycollection" => Ok(GeometryArray::GeometryCollection(Box::new(
+                    array.try_into()?,
+                ))),
+                "geoarrow._rect" => Ok(GeometryArray::Rect(array.try_into()?)),
                 // TODO: create a top-level API that parses any named geoarrow array?
                 // "geoarrow.wkb" => Ok(GeometryArray::WKB(array.try_into()?)),
                 _ => Err(GeoArrowError::General(format!(
@@ -270,7 +403,7 @@ impl TryFrom<(&Field, &dyn Array)> for GeometryArray<i32> {
                     extension_name
                 ))),
             };
-            geom_arr
+            Ok(geom_arr?.with_metadata(ArrayMetadata::from_field(field)?))
         } else {
             // TODO: better error here, and document that arrays without geoarrow extension
             // metadata should use TryFrom for a specific geometry type directly, instead of using
@@ -294,6 +427,10 @@ impl TryFrom<(&Field, &dyn Array)> for GeometryArray<i64> {
                 "geoarrow.multipoint" => Ok(GeometryArray::MultiPoint(array.try_into()?)),
                 "geoarrow.multilinestring" => Ok(GeometryArray::MultiLineString(array.try_into()?)),
                 "geoarrow.multipolygon" => Ok(GeometryArray::MultiPolygon(array.try_into()?)),
+                "geoarrow.geometrycollection" => Ok(GeometryArray::GeometryCollection(Box::new(
+                    array.try_into()?,
+                ))),
+                "geoarrow._rect" => Ok(GeometryArray::Rect(array.try_into()?)),
                 // TODO: create a top-level API that parses any named geoarrow array?
                 // "geoarrow.wkb" => Ok(GeometryArray::WKB(array.try_into()?)),
                 _ => Err(GeoArrowError::General(format!(
@@ -301,7 +438,7 @@ impl TryFrom<(&Field, &dyn Array)> for GeometryArray<i64> {
                     extension_name
                 ))),
             };
-            geom_arr
+            Ok(geom_arr?.with_metadata(ArrayMetadata::from_field(field)?))
         } else {
             // TODO: better error here, and document that arrays without geoarrow extension
             // metadata should use TryFrom for a specific geometry type directly, instead of using
@@ -350,6 +487,18 @@ impl<O: OffsetSizeTrait> From<MultiPolygonArray<O>> for GeometryArray<O> {
     }
 }
 
+impl<O: OffsetSizeTrait> From<GeometryCollectionArray<O>> for GeometryArray<O> {
+    fn from(value: GeometryCollectionArray<O>) -> Self {
+        GeometryArray::GeometryCollection(Box::new(value))
+    }
+}
+
+impl<O: OffsetSizeTrait> From<RectArray> for GeometryArray<O> {
+    fn from(value: RectArray) -> Self {
+        GeometryArray::Rect(value)
+    }
+}
+
 impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for GeometryArray<O> {
     type Error = GeoArrowError;
     fn try_from(value: WKBArray<O>) -> Result<Self, Self::Error> {
@@ -361,6 +510,13 @@ impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for GeometryArray<O> {
             ));
         }
 
+        // Any other geometries are stored alongside the collections, as collections of one member
+        if type_ids.contains(&7) {
+            return Ok(GeometryArray::GeometryCollection(Box::new(
+                value.try_into()?,
+            )));
+        }
+
         if type_ids.len() == 1 {
             if type_ids.contains(&0) {
                 return Ok(GeometryArray::Point(value.try_into()?));
@@ -387,7 +543,7 @@ impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for GeometryArray<O> {
             }
         }
 
-        if type_ids.len() == 3 {
+        if type_ids.len() == 2 {
             if type_ids.contains(&0) && type_ids.contains(&4) {
                 return Ok(GeometryArray::MultiPoint(value.try_into()?));
             }
@@ -416,6 +572,9 @@ impl From<GeometryArray<i32>> for GeometryArray<i64> {
             GeometryArray::MultiPoint(arr) => GeometryArray::MultiPoint(arr.into()),
             GeometryArray::MultiLineString(arr) => GeometryArray::MultiLineString(arr.into()),
             GeometryArray::MultiPolygon(arr) => GeometryArray::MultiPolygon(arr.into()),
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(Box::new((*arr).into()))
+            }
             GeometryArray::Rect(arr) => GeometryArray::Rect(arr),
         }
     }
@@ -432,7 +591,32 @@ impl TryFrom<GeometryArray<i64>> for GeometryArray<i32> {
             GeometryArray::MultiPoint(arr) => GeometryArray::MultiPoint(arr.try_into()?),
             GeometryArray::MultiLineString(arr) => GeometryArray::MultiLineString(arr.try_into()?),
             GeometryArray::MultiPolygon(arr) => GeometryArray::MultiPolygon(arr.try_into()?),
+            GeometryArray::GeometryCollection(arr) => {
+                GeometryArray::GeometryCollection(Box::new((*arr).try_into()?))
+            }
             GeometryArray::Rect(arr) => GeometryArray::Rect(arr),
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::multipoint::mp0;
+    use crate::test::point::p0;
+    use crate::trait_::GeoArrayAccessor;
+
+    #[test]
+    fn wkb_of_single_and_multi_points() {
+        let wkb_array: WKBArray<i32> = vec![
+            Some(geo::Geometry::Point(p0())),
+            Some(geo::Geometry::MultiPoint(mp0())),
+        ]
+        .into();
+        let GeometryArray::MultiPoint(arr) = wkb_array.try_into().unwrap() else {
+            panic!("expected a multi point array");
+        };
+        assert_eq!(arr.value_as_geo(0), geo::MultiPoint::new(vec![p0()]));
+        assert_eq!(arr.value_as_geo(1), mp0());
+    }
+}
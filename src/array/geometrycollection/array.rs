@@ -1,17 +1,26 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use arrow_array::{Array, GenericListArray, OffsetSizeTrait};
+use arrow_array::{
+    Array, GenericListArray, LargeListArray, ListArray, OffsetSizeTrait, UnionArray,
+};
 use arrow_buffer::bit_iterator::BitIterator;
 use arrow_buffer::{NullBuffer, OffsetBuffer};
 use arrow_schema::{DataType, Field};
 
+use crate::algorithm::native::bounding_rect::total_bounds_rects;
 use crate::array::zip_validity::ZipValidity;
-use crate::array::{CoordBuffer, CoordType, MixedGeometryArray};
+use crate::array::{
+    CoordBuffer, CoordType, MixedGeometryArray, MutableGeometryCollectionArray, WKBArray,
+};
+use crate::buffer::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32};
 use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
 use crate::scalar::GeometryCollection;
 use crate::trait_::GeoArrayAccessor;
+use crate::util::{offsets_memory_size, validity_memory_size};
 use crate::GeometryArrayTrait;
+use geo::BoundingRect;
 
 /// An immutable array of GeometryCollection geometries using GeoArrow's in-memory representation.
 ///
@@ -56,17 +65,10 @@ impl<O: OffsetSizeTrait> GeometryCollectionArray<O> {
         }
     }
 
+    /// The field of the list items, which are the members of the collections.
     fn mixed_field(&self) -> Arc<Field> {
         self.array.extension_field()
     }
-
-    fn geometries_field(&self) -> Arc<Field> {
-        let name = "geometries";
-        match O::IS_LARGE {
-            true => Field::new_large_list(name, self.mixed_field(), false).into(),
-            false => Field::new_list(name, self.mixed_field(), false).into(),
-        }
-    }
 }
 
 impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryCollectionArray<O> {
@@ -79,7 +81,10 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryCollectionArray<
     }
 
     fn storage_type(&self) -> DataType {
-        todo!()
+        match O::IS_LARGE {
+            true => DataType::LargeList(self.mixed_field()),
+            false => DataType::List(self.mixed_field()),
+        }
     }
 
     fn extension_field(&self) -> Arc<Field> {
@@ -96,27 +101,55 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryCollectionArray<
     }
 
     fn into_array_ref(self) -> Arc<dyn Array> {
-        let geometries_field = self.geometries_field();
+        let mixed_field = self.mixed_field();
         let validity = self.validity;
         let values = self.array.into_array_ref();
         Arc::new(GenericListArray::new(
-            geometries_field,
+            mixed_field,
             self.geom_offsets,
             values,
             validity,
         ))
     }
 
-    fn with_coords(self, _coords: CoordBuffer) -> Self {
-        todo!()
+    fn with_coords(self, coords: CoordBuffer) -> Self {
+        Self::new(
+            self.array.with_coords(coords),
+            self.geom_offsets,
+            self.validity,
+        )
     }
 
     fn coord_type(&self) -> CoordType {
-        todo!()
+        match &self.data_type {
+            GeoDataType::GeometryCollection(coord_type)
+            | GeoDataType::LargeGeometryCollection(coord_type) => coord_type.clone(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn into_coord_type(self, coord_type: CoordType) -> Self {
+        Self::new(
+            self.array.into_coord_type(coord_type),
+            self.geom_offsets,
+            self.validity,
+        )
     }
 
-    fn into_coord_type(self, _coord_type: CoordType) -> Self {
-        todo!()
+    fn into_coord_float32(self) -> Self {
+        Self::new(
+            self.array.into_coord_float32(),
+            self.geom_offsets,
+            self.validity,
+        )
+    }
+
+    fn into_coord_float64(self) -> Self {
+        Self::new(
+            self.array.into_coord_float64(),
+            self.geom_offsets,
+            self.validity,
+        )
     }
 
     /// Returns the number of geometries in this array
@@ -126,12 +159,26 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryCollectionArray<
         self.geom_offsets.len() - 1
     }
 
+    fn memory_size(&self) -> usize {
+        self.array.memory_size()
+            + offsets_memory_size(&self.geom_offsets)
+            + validity_memory_size(self.validity.as_ref())
+    }
+
     /// Returns the optional validity.
     #[inline]
     fn validity(&self) -> Option<&NullBuffer> {
         self.validity.as_ref()
     }
 
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        total_bounds_rects(
+            self.iter_geo()
+                .flatten()
+                .filter_map(|geom| geom.bounding_rect()),
+        )
+    }
+
     /// Slices this [`GeometryCollectionArray`] in place.
     ///
     /// # Implementation
@@ -165,8 +212,23 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for GeometryCollectionArray<
         }
     }
 
-    fn owned_slice(&self, _offset: usize, _length: usize) -> Self {
-        todo!()
+    /// Copies the collections in the slice, and only their members, into new arrays.
+    fn owned_slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+
+        let mut builder = MutableGeometryCollectionArray::<O>::with_capacity(length);
+        for i in offset..offset + length {
+            // Collections read from this array have no nested collections, and the slice can't
+            // hold more members than the source array
+            builder
+                .push_geometry_collection(self.get_as_geo(i).as_ref())
+                .unwrap();
+        }
+        let arr: Self = builder.into();
+        arr.into_coord_type(self.coord_type())
     }
 }
 
@@ -231,3 +293,213 @@ impl<O: OffsetSizeTrait> GeometryCollectionArray<O> {
         ZipValidity::new_with_validity(self.iter_geos_values(), self.nulls())
     }
 }
+
+/// Import a list whose items are a dense union of the member geometries.
+macro_rules! list_try_from_impl {
+    ($offset_type:ty) => {
+        impl TryFrom<&GenericListArray<$offset_type>> for GeometryCollectionArray<$offset_type> {
+            type Error = GeoArrowError;
+
+            fn try_from(value: &GenericListArray<$offset_type>) -> Result<Self> {
+                let union_array = value
+                    .values()
+                    .as_any()
+                    .downcast_ref::<UnionArray>()
+                    .ok_or_else(|| {
+                        GeoArrowError::General("Expected a list of a union array".to_string())
+                    })?;
+                Ok(Self::new(
+                    union_array.try_into()?,
+                    value.offsets().clone(),
+                    value.nulls().cloned(),
+                ))
+            }
+        }
+    };
+}
+
+list_try_from_impl!(i32);
+list_try_from_impl!(i64);
+
+impl TryFrom<&dyn Array> for GeometryCollectionArray<i32> {
+    type Error = GeoArrowError;
+
+    fn try_from(value: &dyn Array) -> Result<Self> {
+        match value.data_type() {
+            DataType::List(_) => {
+                let downcasted = value.as_any().downcast_ref::<ListArray>().unwrap();
+                downcasted.try_into()
+            }
+            DataType::LargeList(_) => {
+                let downcasted = value.as_any().downcast_ref::<LargeListArray>().unwrap();
+                let geom_array: GeometryCollectionArray<i64> = downcasted.try_into()?;
+                geom_array.try_into()
+            }
+            _ => Err(GeoArrowError::General(format!(
+                "Unexpected type: {:?}",
+                value.data_type()
+            ))),
+        }
+    }
+}
+
+impl TryFrom<&dyn Array> for GeometryCollectionArray<i64> {
+    type Error = GeoArrowError;
+
+    fn try_from(value: &dyn Array) -> Result<Self> {
+        match value.data_type() {
+            DataType::List(_) => {
+                let downcasted = value.as_any().downcast_ref::<ListArray>().unwrap();
+                let geom_array: GeometryCollectionArray<i32> = downcasted.try_into()?;
+                Ok(geom_array.into())
+            }
+            DataType::LargeList(_) => {
+                let downcasted = value.as_any().downcast_ref::<LargeListArray>().unwrap();
+                downcasted.try_into()
+            }
+            _ => Err(GeoArrowError::General(format!(
+                "Unexpected type: {:?}",
+                value.data_type()
+            ))),
+        }
+    }
+}
+
+impl<O: OffsetSizeTrait> TryFrom<Vec<Option<geo::GeometryCollection>>>
+    for GeometryCollectionArray<O>
+{
+    type Error = GeoArrowError;
+
+    fn try_from(other: Vec<Option<geo::GeometryCollection>>) -> Result<Self> {
+        let mut_arr: MutableGeometryCollectionArray<O> = other.try_into()?;
+        Ok(mut_arr.into())
+    }
+}
+
+impl<O: OffsetSizeTrait> TryFrom<Vec<geo::GeometryCollection>> for GeometryCollectionArray<O> {
+    type Error = GeoArrowError;
+
+    fn try_from(other: Vec<geo::GeometryCollection>) -> Result<Self> {
+        let mut_arr: MutableGeometryCollectionArray<O> = other.try_into()?;
+        Ok(mut_arr.into())
+    }
+}
+
+/// WKB geometries that aren't a GeometryCollection become a collection of one member.
+impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for GeometryCollectionArray<O> {
+    type Error = GeoArrowError;
+
+    fn try_from(value: WKBArray<O>) -> Result<Self> {
+        let mut_arr: MutableGeometryCollectionArray<O> = value.try_into()?;
+        Ok(mut_arr.into())
+    }
+}
+
+impl From<GeometryCollectionArray<i32>> for GeometryCollectionArray<i64> {
+    fn from(value: GeometryCollectionArray<i32>) -> Self {
+        Self::new(
+            value.array.into(),
+            offsets_buffer_i32_to_i64(&value.geom_offsets),
+            value.validity,
+        )
+    }
+}
+
+impl TryFrom<GeometryCollectionArray<i64>> for GeometryCollectionArray<i32> {
+    type Error = GeoArrowError;
+
+    fn try_from(value: GeometryCollectionArray<i64>) -> Result<Self> {
+        Ok(Self::new(
+            value.array.try_into()?,
+            offsets_buffer_i64_to_i32(&value.geom_offsets)?,
+            value.validity,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::GeometryArray;
+    use crate::geo_traits::GeometryCollectionTrait;
+    use crate::test::geometrycollection::{gc0, gc1, gc_array};
+    use crate::test::point::p0;
+    use crate::trait_::FromArrow;
+
+    #[test]
+    fn geo_roundtrip_accurate_option_vec() {
+        let arr: GeometryCollectionArray<i64> =
+            vec![Some(gc0()), None, Some(gc1())].try_into().unwrap();
+        assert_eq!(arr.get_as_geo(0), Some(gc0()));
+        assert_eq!(arr.get_as_geo(1), None);
+        assert_eq!(arr.get_as_geo(2), Some(gc1()));
+
+        let scalar = arr.value(2);
+        assert_eq!(scalar.num_geometries(), 3);
+        assert!(scalar.geometry(3).is_none());
+    }
+
+    #[test]
+    fn nested_collections_are_rejected() {
+        let nested = geo::GeometryCollection(vec![geo::Geometry::GeometryCollection(gc0())]);
+        let result: Result<GeometryCollectionArray<i32>> = vec![nested].try_into();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn slice() {
+        let arr = gc_array();
+        let sliced = arr.slice(1, 1);
+        assert_eq!(sliced.len(), 1);
+        assert_eq!(sliced.get_as_geo(0), Some(gc1()));
+
+        let owned = arr.owned_slice(1, 1);
+        assert_eq!(owned.len(), 1);
+        assert_eq!(owned.array.len(), 3);
+        assert_eq!(owned.get_as_geo(0), Some(gc1()));
+    }
+
+    #[test]
+    fn arrow_roundtrip() {
+        let arr = gc_array();
+        let field = arr.extension_field();
+        let array_ref = arr.clone().into_array_ref();
+        assert_eq!(array_ref.data_type(), field.data_type());
+
+        let new_arr = GeometryCollectionArray::<i32>::from_arrow(&array_ref, &field).unwrap();
+        assert_eq!(new_arr.get_as_geo(0), Some(gc0()));
+        assert_eq!(new_arr.get_as_geo(1), Some(gc1()));
+
+        let large_arr = GeometryCollectionArray::<i64>::from_arrow(&array_ref, &field).unwrap();
+        assert_eq!(large_arr.get_as_geo(1), Some(gc1()));
+
+        let geometry_array =
+            GeometryArray::<i32>::try_from((field.as_ref(), array_ref.as_ref())).unwrap();
+        assert!(matches!(
+            geometry_array,
+            GeometryArray::GeometryCollection(_)
+        ));
+    }
+
+    #[test]
+    fn from_wkb_wraps_other_geometries() {
+        let orig_arr = gc_array();
+        let mut wkb: Vec<Option<geo::Geometry>> = orig_arr
+            .iter_geo()
+            .map(|gc| gc.map(geo::Geometry::GeometryCollection))
+            .collect();
+        wkb.push(Some(geo::Geometry::Point(p0())));
+        let wkb_arr: WKBArray<i32> = wkb.into();
+
+        let geometry_array: GeometryArray<i32> = wkb_arr.try_into().unwrap();
+        let GeometryArray::GeometryCollection(arr) = geometry_array else {
+            panic!("expected a geometry collection array")
+        };
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr.get_as_geo(1), Some(gc1()));
+        assert_eq!(
+            arr.get_as_geo(2),
+            Some(geo::GeometryCollection(vec![geo::Geometry::Point(p0())]))
+        );
+    }
+}
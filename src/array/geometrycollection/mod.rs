@@ -1,5 +1,10 @@
-pub mod array;
-pub mod iterator;
+//! Contains the [`GeometryCollectionArray`] and [`MutableGeometryCollectionArray`] for arrays of
+//! GeometryCollection geometries.
 
 pub use array::GeometryCollectionArray;
 pub use iterator::GeometryCollectionArrayIter;
+pub use mutable::MutableGeometryCollectionArray;
+
+pub mod array;
+pub mod iterator;
+mod mutable;
@@ -0,0 +1,251 @@
+use std::sync::Arc;
+
+use crate::array::mutable_offset::OffsetsBuilder;
+use crate::array::{
+    GeometryCollectionArray, MixedGeometryArray, MutableMixedGeometryArray, WKBArray,
+};
+use crate::error::{GeoArrowError, Result};
+use crate::io::wkb::reader::geometry::WKBGeometry;
+use crate::scalar::WKB;
+use crate::trait_::{GeometryArrayTrait, MutableGeometryArray};
+use arrow_array::{Array, OffsetSizeTrait};
+use arrow_buffer::NullBufferBuilder;
+
+/// The Arrow equivalent to `Vec<Option<GeometryCollection>>`.
+/// Converting a [`MutableGeometryCollectionArray`] into a [`GeometryCollectionArray`] is `O(1)`.
+///
+/// The members of every collection are stored in a single child [`MutableMixedGeometryArray`].
+/// Nested geometry collections are not supported.
+#[derive(Debug)]
+pub struct MutableGeometryCollectionArray<O: OffsetSizeTrait> {
+    geoms: MutableMixedGeometryArray<O>,
+
+    geom_offsets: OffsetsBuilder<O>,
+
+    /// Validity is only defined at the geometry level
+    validity: NullBufferBuilder,
+}
+
+impl<O: OffsetSizeTrait> MutableGeometryCollectionArray<O> {
+    /// Creates a new empty [`MutableGeometryCollectionArray`].
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new [`MutableGeometryCollectionArray`] with capacity for `geom_capacity`
+    /// collections. The members of each collection are not preallocated.
+    pub fn with_capacity(geom_capacity: usize) -> Self {
+        Self {
+            geoms: MutableMixedGeometryArray::new(),
+            geom_offsets: OffsetsBuilder::with_capacity(geom_capacity),
+            validity: NullBufferBuilder::new(geom_capacity),
+        }
+    }
+
+    /// Extract the low-level APIs from the [`MutableGeometryCollectionArray`].
+    pub fn into_inner(
+        self,
+    ) -> (
+        MutableMixedGeometryArray<O>,
+        OffsetsBuilder<O>,
+        NullBufferBuilder,
+    ) {
+        (self.geoms, self.geom_offsets, self.validity)
+    }
+
+    pub fn into_array_ref(self) -> Arc<dyn Array> {
+        let arr: GeometryCollectionArray<O> = self.into();
+        arr.into_array_ref()
+    }
+
+    /// Add a new GeometryCollection to the end of this array.
+    ///
+    /// Members that are a [`geo::Line`] are stored as LineStrings, and members that are a
+    /// [`geo::Rect`] or [`geo::Triangle`] are stored as Polygons.
+    ///
+    /// Unlike the other builders, this takes a `geo` collection rather than any
+    /// [`GeometryCollectionTrait`](crate::geo_traits::GeometryCollectionTrait): the trait yields
+    /// its members by value, and they can't be borrowed for the lifetime that
+    /// [`GeometryTrait`](crate::geo_traits::GeometryTrait) requires. WKB collections are pushed
+    /// without going through `geo` with [`push_wkb_geometry`](Self::push_wkb_geometry).
+    ///
+    /// # Errors
+    ///
+    /// This function errors iff:
+    ///
+    /// - a member is itself a geometry collection. Nothing is added in this case.
+    /// - the new last item is larger than what O supports
+    pub fn push_geometry_collection(
+        &mut self,
+        value: Option<&geo::GeometryCollection>,
+    ) -> Result<()> {
+        let Some(geometry_collection) = value else {
+            self.push_null();
+            return Ok(());
+        };
+        if geometry_collection
+            .iter()
+            .any(|geom| matches!(geom, geo::Geometry::GeometryCollection(_)))
+        {
+            return Err(nested_collection_error());
+        }
+
+        for geom in geometry_collection.iter() {
+            match geom {
+                geo::Geometry::Point(g) => self.geoms.push_point(Some(g)),
+                geo::Geometry::Line(g) => self
+                    .geoms
+                    .push_line_string(Some(&geo::LineString::from(*g)))?,
+                geo::Geometry::LineString(g) => self.geoms.push_line_string(Some(g))?,
+                geo::Geometry::Polygon(g) => self.geoms.push_polygon(Some(g))?,
+                geo::Geometry::MultiPoint(g) => self.geoms.push_multi_point(Some(g))?,
+                geo::Geometry::MultiLineString(g) => self.geoms.push_multi_line_string(Some(g))?,
+                geo::Geometry::MultiPolygon(g) => self.geoms.push_multi_polygon(Some(g))?,
+                geo::Geometry::Rect(g) => self.geoms.push_polygon(Some(&g.to_polygon()))?,
+                geo::Geometry::Triangle(g) => self.geoms.push_polygon(Some(&g.to_polygon()))?,
+                geo::Geometry::GeometryCollection(_) => unreachable!(),
+            }
+        }
+        self.try_push_length(geometry_collection.0.len())
+    }
+
+    /// Add a new WKB geometry to the end of this array.
+    ///
+    /// A WKB GeometryCollection is added as it is, and any other geometry is added as a
+    /// collection with that one member.
+    ///
+    /// # Errors
+    ///
+    /// This function errors iff:
+    ///
+    /// - a member of the collection is itself a geometry collection. Nothing is added in this
+    ///   case.
+    /// - the new last item is larger than what O supports
+    pub fn push_wkb_geometry(&mut self, value: Option<&WKBGeometry>) -> Result<()> {
+        let members = match value {
+            None => {
+                self.push_null();
+                return Ok(());
+            }
+            Some(WKBGeometry::GeometryCollection(geometry_collection)) => {
+                geometry_collection.geometries.as_slice()
+            }
+            Some(geom) => std::slice::from_ref(geom),
+        };
+        if members
+            .iter()
+            .any(|geom| matches!(geom, WKBGeometry::GeometryCollection(_)))
+        {
+            return Err(nested_collection_error());
+        }
+
+        for geom in members {
+            self.geoms.push_geometry(geom)?;
+        }
+        self.try_push_length(members.len())
+    }
+
+    /// Push a null collection, with no members.
+    #[inline]
+    pub fn push_null(&mut self) {
+        self.geom_offsets.extend_constant(1);
+        self.validity.append_null();
+    }
+
+    /// Add a valid collection of `geom_offsets_length` members, which must already have been
+    /// pushed into the child array.
+    ///
+    /// # Errors
+    ///
+    /// This function errors iff the new last item is larger than what O supports.
+    #[inline]
+    fn try_push_length(&mut self, geom_offsets_length: usize) -> Result<()> {
+        self.geom_offsets.try_push_usize(geom_offsets_length)?;
+        self.validity.append(true);
+        Ok(())
+    }
+}
+
+fn nested_collection_error() -> GeoArrowError {
+    GeoArrowError::General("nested geometry collections are not supported".to_string())
+}
+
+impl<O: OffsetSizeTrait> Default for MutableGeometryCollectionArray<O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<O: OffsetSizeTrait> MutableGeometryArray for MutableGeometryCollectionArray<O> {
+    fn len(&self) -> usize {
+        self.geom_offsets.len_proxy()
+    }
+
+    fn validity(&self) -> &NullBufferBuilder {
+        &self.validity
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn into_array_ref(self) -> Arc<dyn Array> {
+        self.into_array_ref()
+    }
+}
+
+impl<O: OffsetSizeTrait> From<MutableGeometryCollectionArray<O>> for GeometryCollectionArray<O> {
+    fn from(mut other: MutableGeometryCollectionArray<O>) -> Self {
+        let validity = other.validity.finish_cloned();
+        other.geom_offsets.shrink_to_fit();
+
+        let array: MixedGeometryArray<O> = other.geoms.into();
+        Self::new(array, other.geom_offsets.into(), validity)
+    }
+}
+
+impl<O: OffsetSizeTrait> TryFrom<Vec<Option<geo::GeometryCollection>>>
+    for MutableGeometryCollectionArray<O>
+{
+    type Error = GeoArrowError;
+
+    fn try_from(geoms: Vec<Option<geo::GeometryCollection>>) -> Result<Self> {
+        let mut array = Self::with_capacity(geoms.len());
+        geoms
+            .iter()
+            .try_for_each(|maybe_gc| array.push_geometry_collection(maybe_gc.as_ref()))?;
+        Ok(array)
+    }
+}
+
+impl<O: OffsetSizeTrait> TryFrom<Vec<geo::GeometryCollection>>
+    for MutableGeometryCollectionArray<O>
+{
+    type Error = GeoArrowError;
+
+    fn try_from(geoms: Vec<geo::GeometryCollection>) -> Result<Self> {
+        let mut array = Self::with_capacity(geoms.len());
+        geoms
+            .iter()
+            .try_for_each(|gc| array.push_geometry_collection(Some(gc)))?;
+        Ok(array)
+    }
+}
+
+impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for MutableGeometryCollectionArray<O> {
+    type Error = GeoArrowError;
+
+    fn try_from(value: WKBArray<O>) -> Result<Self> {
+        let mut array = Self::with_capacity(value.len());
+        let wkb_objects: Vec<Option<WKB<'_, O>>> = value.iter().collect();
+        for maybe_wkb in wkb_objects.iter() {
+            let wkb_object = maybe_wkb.as_ref().map(|wkb| wkb.to_wkb_object());
+            array.push_wkb_geometry(wkb_object.as_ref())?;
+        }
+        Ok(array)
+    }
+}
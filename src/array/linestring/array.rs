@@ -1,17 +1,24 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::algorithm::native::bounding_rect::total_bounds_coords;
 use crate::algorithm::native::eq::offset_buffer_eq;
-use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
 use crate::array::zip_validity::ZipValidity;
-use crate::array::{CoordBuffer, CoordType, MultiPointArray, WKBArray};
+use crate::array::{
+    ArrayMetadata, CoordBuffer, CoordType, MultiPointArray, SeparatedCoordBuffer, WKBArray,
+};
+use crate::buffer::owned_slice_offsets;
+use crate::buffer::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
+use crate::geo_traits::LineStringTrait;
 use crate::scalar::LineString;
 use crate::trait_::GeoArrayAccessor;
-use crate::util::{owned_slice_offsets, owned_slice_validity};
+use crate::util::{offsets_memory_size, owned_slice_validity, validity_memory_size};
 use crate::GeometryArrayTrait;
-use arrow_array::{Array, ArrayRef, GenericListArray, LargeListArray, ListArray, OffsetSizeTrait};
+use arrow_array::{
+    Array, ArrayRef, Float64Array, GenericListArray, LargeListArray, ListArray, OffsetSizeTrait,
+};
 use arrow_buffer::bit_iterator::BitIterator;
 use arrow_buffer::{NullBuffer, OffsetBuffer};
 use arrow_schema::{DataType, Field, FieldRef};
@@ -27,6 +34,9 @@ pub struct LineStringArray<O: OffsetSizeTrait> {
     // Always GeoDataType::LineString or GeoDataType::LargeLineString
     data_type: GeoDataType,
 
+    /// Array-level metadata, such as how to interpret edges
+    metadata: ArrayMetadata,
+
     pub coords: CoordBuffer,
 
     /// Offsets into the coordinate array where each geometry starts
@@ -91,19 +101,83 @@ impl<O: OffsetSizeTrait> LineStringArray<O> {
         validity: Option<NullBuffer>,
     ) -> Result<Self> {
         check(&coords, validity.as_ref().map(|v| v.len()), &geom_offsets)?;
+        // Safety: the invariants were checked above
+        Ok(unsafe { Self::new_unchecked(coords, geom_offsets, validity) })
+    }
 
+    /// Create a new LineStringArray from parts, without validating them
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(1)`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    ///
+    /// - if the validity is not `None`, its length equals the number of geometries, i.e. one
+    ///   less than the length of `geom_offsets`
+    /// - the last geometry offset equals the number of coordinates, so that every geometry
+    ///   offset indexes into `coords`
+    ///
+    /// Geometry offsets past the end of the coordinates make other Arrow implementations, such as
+    /// consumers of the C Data Interface, read out of bounds when they access the vertices of a
+    /// line string. Within this crate, they lead to panics.
+    pub unsafe fn new_unchecked(
+        coords: CoordBuffer,
+        geom_offsets: OffsetBuffer<O>,
+        validity: Option<NullBuffer>,
+    ) -> Self {
         let coord_type = coords.coord_type();
+        let dim = coords.dim();
         let data_type = match O::IS_LARGE {
-            true => GeoDataType::LargeLineString(coord_type),
-            false => GeoDataType::LineString(coord_type),
+            true => GeoDataType::LargeLineString(coord_type, dim),
+            false => GeoDataType::LineString(coord_type, dim),
         };
 
-        Ok(Self {
+        Self {
             data_type,
+            metadata: Default::default(),
             coords,
             geom_offsets,
             validity,
-        })
+        }
+    }
+
+    /// Create a new LineStringArray from existing coordinate and offset buffers, without copying.
+    ///
+    /// The x and y arrays hold the coordinates of all geometries back to back, and
+    /// `geom_offsets` gives the index into them where each geometry starts. The resulting array
+    /// uses separated coordinates and has no null geometries.
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(1)`.
+    ///
+    /// # Errors
+    ///
+    /// - if the x and y arrays have different lengths
+    /// - if the x or y array contains nulls
+    /// - if the largest geometry offset does not match the number of coordinates
+    pub fn from_raw_parts(
+        coords_x: Float64Array,
+        coords_y: Float64Array,
+        geom_offsets: OffsetBuffer<O>,
+    ) -> Result<Self> {
+        if coords_x.null_count() > 0 || coords_y.null_count() > 0 {
+            return Err(GeoArrowError::General(
+                "coordinate arrays must not contain nulls".to_string(),
+            ));
+        }
+
+        let coords =
+            SeparatedCoordBuffer::try_new(coords_x.values().clone(), coords_y.values().clone())?;
+        Self::try_new(CoordBuffer::Separated(coords), geom_offsets, None)
+    }
+
+    /// Replace the [`ArrayMetadata`] of this array.
+    pub fn with_metadata(self, metadata: ArrayMetadata) -> Self {
+        Self { metadata, ..self }
     }
 
     fn vertices_field(&self) -> Arc<Field> {
@@ -137,6 +211,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for LineStringArray<O> {
             "ARROW:extension:name".to_string(),
             self.extension_name().to_string(),
         );
+        self.metadata.write_to_field_metadata(&mut field_metadata);
         Arc::new(Field::new("", self.storage_type(), true).with_metadata(field_metadata))
     }
 
@@ -144,6 +219,10 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for LineStringArray<O> {
         "geoarrow.linestring"
     }
 
+    fn metadata(&self) -> &ArrayMetadata {
+        &self.metadata
+    }
+
     fn into_array_ref(self) -> ArrayRef {
         let vertices_field = self.vertices_field();
         let validity = self.validity;
@@ -158,7 +237,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for LineStringArray<O> {
 
     fn with_coords(self, coords: CoordBuffer) -> Self {
         assert_eq!(coords.len(), self.coords.len());
-        Self::new(coords, self.geom_offsets, self.validity)
+        Self::new(coords, self.geom_offsets, self.validity).with_metadata(self.metadata)
     }
 
     fn coord_type(&self) -> CoordType {
@@ -171,6 +250,25 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for LineStringArray<O> {
             self.geom_offsets,
             self.validity,
         )
+        .with_metadata(self.metadata)
+    }
+
+    fn into_coord_float32(self) -> Self {
+        Self::new(
+            self.coords.into_coord_float32(),
+            self.geom_offsets,
+            self.validity,
+        )
+        .with_metadata(self.metadata)
+    }
+
+    fn into_coord_float64(self) -> Self {
+        Self::new(
+            self.coords.into_coord_float64(),
+            self.geom_offsets,
+            self.validity,
+        )
+        .with_metadata(self.metadata)
     }
 
     /// Returns the number of geometries in this array
@@ -180,12 +278,24 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for LineStringArray<O> {
         self.geom_offsets.len() - 1
     }
 
+    fn memory_size(&self) -> usize {
+        self.coords.memory_size()
+            + offsets_memory_size(&self.geom_offsets)
+            + validity_memory_size(self.validity.as_ref())
+    }
+
     /// Returns the optional validity.
     #[inline]
     fn validity(&self) -> Option<&NullBuffer> {
         self.validity.as_ref()
     }
 
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        total_bounds_coords(&self.coords, self.validity(), self.len(), |geom_idx| {
+            self.geom_offsets.start_end(geom_idx)
+        })
+    }
+
     /// Slices this [`LineStringArray`] in place.
     ///
     /// # Implementation
@@ -213,6 +323,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for LineStringArray<O> {
         // would be in the wrong location.
         Self {
             data_type: self.data_type.clone(),
+            metadata: self.metadata.clone(),
             coords: self.coords.clone(),
             geom_offsets: self.geom_offsets.slice(offset, length),
             validity: self.validity.as_ref().map(|v| v.slice(offset, length)),
@@ -238,7 +349,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for LineStringArray<O> {
 
         let validity = owned_slice_validity(self.nulls(), offset, length);
 
-        Self::new(coords, geom_offsets, validity)
+        Self::new(coords, geom_offsets, validity).with_metadata(self.metadata.clone())
     }
 }
 
@@ -253,6 +364,16 @@ impl<'a, O: OffsetSizeTrait> GeoArrayAccessor<'a> for LineStringArray<O> {
 
 // Implement geometry accessors
 impl<O: OffsetSizeTrait> LineStringArray<O> {
+    /// Iterator over the coordinates of all geometries in this array, in order, as
+    /// [`geo::Coord`]s
+    ///
+    /// Null geometries are stored with empty offsets, so they have no coordinates to yield.
+    pub fn geo_coords_iter(&self) -> impl Iterator<Item = geo::Coord> + '_ {
+        let start = self.geom_offsets[0].to_usize().unwrap();
+        let end = self.geom_offsets.last().to_usize().unwrap();
+        (start..end).map(|i| self.coords.value(i).into())
+    }
+
     /// Iterator over geo Geometry objects, not looking at validity
     pub fn iter_geo_values(&self) -> impl Iterator<Item = geo::LineString> + '_ {
         (0..self.len()).map(|i| self.value_as_geo(i))
@@ -359,6 +480,16 @@ impl<O: OffsetSizeTrait> From<Vec<Option<geo::LineString>>> for LineStringArray<
     }
 }
 
+impl<'a, O: OffsetSizeTrait, G: LineStringTrait<'a, T = f64>> FromIterator<Option<G>>
+    for LineStringArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        iter.into_iter()
+            .collect::<MutableLineStringArray<O>>()
+            .into()
+    }
+}
+
 impl<O: OffsetSizeTrait> From<Vec<geo::LineString>> for LineStringArray<O> {
     fn from(other: Vec<geo::LineString>) -> Self {
         let mut_arr: MutableLineStringArray<O> = other.into();
@@ -386,9 +517,13 @@ impl<O: OffsetSizeTrait> From<bumpalo::collections::Vec<'_, geo::LineString>>
 
 /// LineString and MultiPoint have the same layout, so enable conversions between the two to change
 /// the semantic type
+///
+/// This is a reinterpretation of the same buffers, not a geometric operation: each line string's
+/// vertices become the points of a multipoint, in the same order. Coordinates, offsets, validity
+/// and metadata are reused without copying.
 impl<O: OffsetSizeTrait> From<LineStringArray<O>> for MultiPointArray<O> {
     fn from(value: LineStringArray<O>) -> Self {
-        Self::new(value.coords, value.geom_offsets, value.validity)
+        Self::new(value.coords, value.geom_offsets, value.validity).with_metadata(value.metadata)
     }
 }
 
@@ -454,6 +589,7 @@ mod test {
         example_linestring_interleaved, example_linestring_separated, example_linestring_wkb,
     };
     use crate::test::linestring::{ls0, ls1};
+    use arrow_array::{FixedSizeListArray, ListArray};
 
     use super::*;
 
@@ -472,6 +608,86 @@ mod test {
         assert_eq!(arr.get_as_geo(2), None);
     }
 
+    #[test]
+    fn memory_size() {
+        let small: LineStringArray<i32> = vec![ls0()].into();
+        let large: LineStringArray<i32> = vec![Some(ls0()), Some(ls1()), None].into();
+        assert!(large.memory_size() > small.memory_size());
+
+        // Two xy coordinates of 8 bytes each and two i32 offsets
+        assert_eq!(small.memory_size(), 2 * 2 * 8 + 2 * 4);
+
+        // A slice counts the coordinates of the rows it doesn't view
+        let sliced = large.slice(0, 1);
+        assert_eq!(sliced.memory_size(), large.coords.memory_size() + 2 * 4 + 1);
+
+        let wkb_small: WKBArray<i32> = (&small).into();
+        let wkb_large: WKBArray<i32> = (&large).into();
+        assert!(wkb_large.memory_size() > wkb_small.memory_size());
+    }
+
+    #[test]
+    fn from_raw_parts() {
+        let x = Float64Array::from(vec![0., 1., 3., 5.]);
+        let y = Float64Array::from(vec![1., 2., 4., 6.]);
+        let geom_offsets = OffsetBuffer::new(vec![0, 2, 4].into());
+        let arr = LineStringArray::<i32>::from_raw_parts(x, y, geom_offsets).unwrap();
+        assert_eq!(arr.len(), 2);
+        assert_eq!(arr.value_as_geo(0), ls0());
+        assert_eq!(arr.value_as_geo(1), ls1());
+
+        let x = Float64Array::from(vec![0., 1., 3.]);
+        let y = Float64Array::from(vec![1., 2., 4., 6.]);
+        let geom_offsets = OffsetBuffer::new(vec![0, 2, 4].into());
+        assert!(LineStringArray::<i32>::from_raw_parts(x, y, geom_offsets).is_err());
+
+        let x = Float64Array::from(vec![0., 1., 3., 5.]);
+        let y = Float64Array::from(vec![1., 2., 4., 6.]);
+        let geom_offsets = OffsetBuffer::new(vec![0, 2, 3].into());
+        assert!(LineStringArray::<i32>::from_raw_parts(x, y, geom_offsets).is_err());
+    }
+
+    #[test]
+    fn geo_coords_iter() {
+        let arr: LineStringArray<i32> = vec![Some(ls0()), None, Some(ls1())].into();
+        let all_coords = geo::LineString::from_iter(arr.geo_coords_iter());
+        let expected: geo::LineString = vec![(0., 1.), (1., 2.), (3., 4.), (5., 6.)].into();
+        assert_eq!(all_coords, expected);
+
+        let sliced = arr.slice(1, 2);
+        let sliced_coords = geo::LineString::from_iter(sliced.geo_coords_iter());
+        assert_eq!(sliced_coords, ls1());
+    }
+
+    #[test]
+    fn new_unchecked_matches_try_new() {
+        let arr: LineStringArray<i32> = vec![ls0(), ls1()].into();
+        let unchecked = unsafe {
+            LineStringArray::new_unchecked(
+                arr.coords.clone(),
+                arr.geom_offsets.clone(),
+                arr.validity.clone(),
+            )
+        };
+        assert_eq!(unchecked, arr);
+    }
+
+    #[test]
+    fn multi_point_roundtrip() {
+        let arr: LineStringArray<i32> = vec![Some(ls0()), None, Some(ls1())].into();
+        let multi_point_arr: MultiPointArray<i32> = arr.clone().into();
+        assert_eq!(multi_point_arr.len(), 3);
+        assert!(multi_point_arr.is_null(1));
+
+        let expected: geo::MultiPoint = ls0().into_points().into();
+        assert_eq!(multi_point_arr.value_as_geo(0), expected);
+        let expected: geo::MultiPoint = ls1().into_points().into();
+        assert_eq!(multi_point_arr.value_as_geo(2), expected);
+
+        let roundtrip: LineStringArray<i32> = multi_point_arr.into();
+        assert_eq!(roundtrip, arr);
+    }
+
     // #[test]
     // fn rstar_integration() {
     //     let arr: LineStringArray = vec![ls0(), ls1()].into();
@@ -496,6 +712,31 @@ mod test {
         assert_eq!(sliced.get_as_geo(0), Some(ls1()));
     }
 
+    #[test]
+    fn slice_validity_aligns_with_geometries() {
+        let arr: LineStringArray<i32> = vec![Some(ls0()), None, Some(ls1()), None].into();
+        let first_x = |arr: &LineStringArray<i32>, i: usize| arr.value(i).coord(0).unwrap().x();
+
+        // Nulls at both ends of the slice
+        let sliced = arr.slice(1, 3);
+        assert_eq!(sliced.len(), 3);
+        assert_eq!(sliced.nulls().unwrap().null_count(), 2);
+        assert!(sliced.is_null(0));
+        assert!(sliced.is_valid(1));
+        assert_eq!(first_x(&sliced, 1), first_x(&arr, 2));
+        assert!(sliced.is_null(2));
+
+        // A valid geometry at the start, a null at the end
+        let sliced = arr.slice(2, 2);
+        assert!(sliced.is_valid(0));
+        assert_eq!(sliced.value(0).num_coords(), arr.value(2).num_coords());
+        assert!(sliced.is_null(1));
+
+        let owned = arr.owned_slice(1, 3);
+        assert_eq!(owned.nulls(), arr.slice(1, 3).nulls());
+        assert_eq!(first_x(&owned, 1), first_x(&arr, 2));
+    }
+
     #[test]
     fn owned_slice() {
         let arr: LineStringArray<i64> = vec![ls0(), ls1()].into();
@@ -529,4 +770,30 @@ mod test {
 
         assert_eq!(linestring_arr, parsed_linestring_arr);
     }
+
+    #[test]
+    fn float32_coords() {
+        let arr: LineStringArray<i32> = vec![Some(ls0()), None, Some(ls1())].into();
+        let float32 = arr
+            .clone()
+            .into_coord_type(CoordType::Separated)
+            .into_coord_float32();
+        assert!(float32.coords.is_float32());
+        assert_eq!(float32.coord_type(), CoordType::Interleaved);
+        assert_eq!(float32.value_as_geo(2), ls1());
+        assert!(float32.is_null(1));
+
+        let array_ref = float32.clone().into_array_ref();
+        let list_array = array_ref.as_any().downcast_ref::<ListArray>().unwrap();
+        let coords_array = list_array
+            .values()
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+        assert_eq!(coords_array.value_type(), DataType::Float32);
+
+        let round_trip: LineStringArray<i32> = array_ref.as_ref().try_into().unwrap();
+        assert_eq!(round_trip, float32);
+        assert_eq!(round_trip.into_coord_float64(), arr);
+    }
 }
@@ -0,0 +1,15 @@
+/// The number of coordinates and geometries a [`MutableLineStringArray`] can hold, or should
+/// reserve room for.
+///
+/// [`MutableLineStringArray`]: super::MutableLineStringArray
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LineStringCapacity {
+    pub coord: usize,
+    pub geom: usize,
+}
+
+impl LineStringCapacity {
+    pub fn new(coord: usize, geom: usize) -> Self {
+        Self { coord, geom }
+    }
+}
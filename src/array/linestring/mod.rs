@@ -2,9 +2,11 @@
 //! geometries.
 
 pub use array::LineStringArray;
+pub use capacity::LineStringCapacity;
 pub use iterator::LineStringArrayIter;
 pub use mutable::MutableLineStringArray;
 
 mod array;
+mod capacity;
 pub mod iterator;
 pub(crate) mod mutable;
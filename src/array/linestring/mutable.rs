@@ -1,11 +1,11 @@
 // use super::array::check;
 use crate::array::mutable_offset::OffsetsBuilder;
 use crate::array::{
-    LineStringArray, MutableCoordBuffer, MutableInterleavedCoordBuffer, MutableMultiPointArray,
-    WKBArray,
+    CoordType, Dimension, LineStringArray, LineStringCapacity, MutableCoordBuffer,
+    MutableMultiPointArray, WKBArray,
 };
 use crate::error::{GeoArrowError, Result};
-use crate::geo_traits::{CoordTrait, LineStringTrait};
+use crate::geo_traits::LineStringTrait;
 use crate::io::wkb::reader::linestring::WKBLineString;
 use crate::scalar::WKB;
 use crate::GeometryArrayTrait;
@@ -33,41 +33,63 @@ impl<'a, O: OffsetSizeTrait> MutableLineStringArray<O> {
         Self::with_capacities(0, 0)
     }
 
-    /// Creates a new [`MutableLineStringArray`] with a capacity.
+    /// Creates a new [`MutableLineStringArray`] with the given capacities and no validity.
     pub fn with_capacities(coord_capacity: usize, geom_capacity: usize) -> Self {
-        let coords = MutableInterleavedCoordBuffer::with_capacity(coord_capacity);
+        Self::with_capacity(LineStringCapacity::new(coord_capacity, geom_capacity))
+    }
+
+    /// Creates a new [`MutableLineStringArray`] with the given capacity and no validity.
+    pub fn with_capacity(capacity: LineStringCapacity) -> Self {
+        Self::with_capacity_and_coord_type(capacity, CoordType::Interleaved)
+    }
+
+    /// Creates a new [`MutableLineStringArray`] with the given capacity and no validity, storing coordinates
+    /// in the given layout.
+    pub fn with_capacity_and_coord_type(
+        capacity: LineStringCapacity,
+        coord_type: CoordType,
+    ) -> Self {
+        Self::with_capacity_coord_type_and_dim(capacity, coord_type, Dimension::XY)
+    }
+
+    /// Creates a new [`MutableLineStringArray`] with the given capacity and no validity, storing coordinates
+    /// of the given dimension in the given layout.
+    pub fn with_capacity_coord_type_and_dim(
+        capacity: LineStringCapacity,
+        coord_type: CoordType,
+        dim: Dimension,
+    ) -> Self {
         Self {
-            coords: MutableCoordBuffer::Interleaved(coords),
-            geom_offsets: OffsetsBuilder::with_capacity(geom_capacity),
-            validity: NullBufferBuilder::new(geom_capacity),
+            coords: MutableCoordBuffer::with_capacity_and_dim(capacity.coord, coord_type, dim),
+            geom_offsets: OffsetsBuilder::with_capacity(capacity.geom),
+            validity: NullBufferBuilder::new(capacity.geom),
         }
     }
 
-    /// Reserves capacity for at least `additional` more LineStrings to be inserted
-    /// in the given `Vec<T>`. The collection may reserve more space to
-    /// speculatively avoid frequent reallocations. After calling `reserve`,
-    /// capacity will be greater than or equal to `self.len() + additional`.
-    /// Does nothing if capacity is already sufficient.
-    pub fn reserve(&mut self, coord_additional: usize, geom_additional: usize) {
-        self.coords.reserve(coord_additional);
-        self.geom_offsets.reserve(geom_additional);
+    /// Reserves capacity for at least `additional` more LineStrings and their parts. Like
+    /// [`Vec::reserve`], this may over-allocate, so that repeated small reservations grow the
+    /// buffers geometrically. Does nothing if capacity is already sufficient.
+    pub fn reserve(&mut self, additional: LineStringCapacity) {
+        self.coords.reserve(additional.coord);
+        self.geom_offsets.reserve(additional.geom);
     }
 
-    /// Reserves the minimum capacity for at least `additional` more LineStrings to
-    /// be inserted in the given `Vec<T>`. Unlike [`reserve`], this will not
-    /// deliberately over-allocate to speculatively avoid frequent allocations.
-    /// After calling `reserve_exact`, capacity will be greater than or equal to
-    /// `self.len() + additional`. Does nothing if the capacity is already
-    /// sufficient.
-    ///
-    /// Note that the allocator may give the collection more space than it
-    /// requests. Therefore, capacity can not be relied upon to be precisely
-    /// minimal. Prefer [`reserve`] if future insertions are expected.
+    /// Reserves the minimum capacity for at least `additional` more LineStrings and their parts.
+    /// Unlike [`reserve`], this will not deliberately over-allocate, so calling it once per
+    /// geometry reallocates on every call. Prefer [`reserve`] if future insertions are expected.
     ///
-    /// [`reserve`]: Vec::reserve
-    pub fn reserve_exact(&mut self, coord_additional: usize, geom_additional: usize) {
-        self.coords.reserve_exact(coord_additional);
-        self.geom_offsets.reserve(geom_additional);
+    /// [`reserve`]: Self::reserve
+    pub fn reserve_exact(&mut self, additional: LineStringCapacity) {
+        self.coords.reserve_exact(additional.coord);
+        self.geom_offsets.reserve_exact(additional.geom);
+    }
+
+    /// The number of coordinates and parts this array can hold without reallocating.
+    pub fn capacity(&self) -> LineStringCapacity {
+        LineStringCapacity {
+            coord: self.coords.capacity(),
+            geom: self.geom_offsets.capacity(),
+        }
     }
 
     /// The canonical method to create a [`MutableLineStringArray`] out of its internal components.
@@ -106,6 +128,9 @@ impl<'a, O: OffsetSizeTrait> MutableLineStringArray<O> {
 
     /// Add a new LineString to the end of this array.
     ///
+    /// Coordinates are read through [`LineStringTrait`], so any line type implementing it can be
+    /// pushed, not only `geo` and GeoArrow line strings.
+    ///
     /// # Errors
     ///
     /// This function errors iff the new last item is larger than what O supports.
@@ -117,7 +142,7 @@ impl<'a, O: OffsetSizeTrait> MutableLineStringArray<O> {
             let num_coords = line_string.num_coords();
             for coord_idx in 0..num_coords {
                 let coord = line_string.coord(coord_idx).unwrap();
-                self.coords.push_xy(coord.x(), coord.y());
+                self.coords.push_coord(coord);
             }
             self.try_push_length(num_coords)?;
         } else {
@@ -126,6 +151,39 @@ impl<'a, O: OffsetSizeTrait> MutableLineStringArray<O> {
         Ok(())
     }
 
+    /// Push each item of `geoms`, which are line strings or nulls.
+    ///
+    /// # Panics
+    ///
+    /// - if the offsets overflow `O`
+    fn extend_from_iter(
+        &mut self,
+        geoms: impl Iterator<Item = Option<impl LineStringTrait<'a, T = f64>>>,
+    ) {
+        geoms
+            .into_iter()
+            .try_for_each(|maybe_line_string| self.push_line_string(maybe_line_string.as_ref()))
+            .unwrap();
+    }
+
+    /// Creates a new [`MutableLineStringArray`] from an iterator of line strings, reserving
+    /// `capacity` up front.
+    ///
+    /// If `capacity` covers every item, as when it was computed in a first pass over them, the
+    /// coordinates are copied once with no reallocation.
+    ///
+    /// # Panics
+    ///
+    /// - if the offsets overflow `O`
+    pub fn from_iter_with_capacity(
+        geoms: impl Iterator<Item = Option<impl LineStringTrait<'a, T = f64>>>,
+        capacity: LineStringCapacity,
+    ) -> Self {
+        let mut array = Self::with_capacity(capacity);
+        array.extend_from_iter(geoms);
+        array
+    }
+
     /// Push a raw coordinate to the underlying coordinate array.
     ///
     /// # Safety
@@ -137,6 +195,28 @@ impl<'a, O: OffsetSizeTrait> MutableLineStringArray<O> {
         Ok(())
     }
 
+    /// Push a raw measured coordinate to the underlying coordinate array.
+    ///
+    /// # Safety
+    ///
+    /// This is marked as unsafe because care must be taken to ensure that pushing raw coordinates
+    /// to the array upholds the necessary invariants of the array.
+    pub unsafe fn push_xym(&mut self, x: f64, y: f64, m: f64) -> Result<()> {
+        self.coords.push_xym(x, y, m);
+        Ok(())
+    }
+
+    /// Push a raw coordinate with z and m values to the underlying coordinate array.
+    ///
+    /// # Safety
+    ///
+    /// This is marked as unsafe because care must be taken to ensure that pushing raw coordinates
+    /// to the array upholds the necessary invariants of the array.
+    pub unsafe fn push_xyzm(&mut self, x: f64, y: f64, z: f64, m: f64) -> Result<()> {
+        self.coords.push_xyzm(x, y, z, m);
+        Ok(())
+    }
+
     /// Needs to be called when a valid value was extended to this array.
     /// This is a relatively low level function, prefer `try_push` when you can.
     #[inline]
@@ -189,21 +269,40 @@ pub(crate) fn second_pass<'a, O: OffsetSizeTrait>(
     geoms: impl Iterator<Item = Option<impl LineStringTrait<'a, T = f64>>>,
     coord_capacity: usize,
     geom_capacity: usize,
+    dim: Dimension,
 ) -> MutableLineStringArray<O> {
-    let mut array = MutableLineStringArray::with_capacities(coord_capacity, geom_capacity);
-
-    geoms
-        .into_iter()
-        .try_for_each(|maybe_multi_point| array.push_line_string(maybe_multi_point.as_ref()))
-        .unwrap();
-
+    let capacity = LineStringCapacity::new(coord_capacity, geom_capacity);
+    let mut array = MutableLineStringArray::with_capacity_coord_type_and_dim(
+        capacity,
+        CoordType::Interleaved,
+        dim,
+    );
+    array.extend_from_iter(geoms);
     array
 }
 
+impl<'a, O: OffsetSizeTrait, G: LineStringTrait<'a, T = f64>> FromIterator<Option<G>>
+    for MutableLineStringArray<O>
+{
+    /// Collect line strings into a new array. Use
+    /// [`from_iter_with_capacity`](MutableLineStringArray::from_iter_with_capacity) when the sizes
+    /// are known up front.
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend_from_iter(iter.into_iter());
+        array
+    }
+}
+
 impl<O: OffsetSizeTrait> From<Vec<geo::LineString>> for MutableLineStringArray<O> {
     fn from(geoms: Vec<geo::LineString>) -> Self {
         let (coord_capacity, geom_capacity) = first_pass(geoms.iter().map(Some), geoms.len());
-        second_pass(geoms.into_iter().map(Some), coord_capacity, geom_capacity)
+        second_pass(
+            geoms.into_iter().map(Some),
+            coord_capacity,
+            geom_capacity,
+            Dimension::XY,
+        )
     }
 }
 
@@ -211,7 +310,12 @@ impl<O: OffsetSizeTrait> From<Vec<Option<geo::LineString>>> for MutableLineStrin
     fn from(geoms: Vec<Option<geo::LineString>>) -> Self {
         let (coord_capacity, geom_capacity) =
             first_pass(geoms.iter().map(|x| x.as_ref()), geoms.len());
-        second_pass(geoms.into_iter(), coord_capacity, geom_capacity)
+        second_pass(
+            geoms.into_iter(),
+            coord_capacity,
+            geom_capacity,
+            Dimension::XY,
+        )
     }
 }
 
@@ -220,7 +324,12 @@ impl<O: OffsetSizeTrait> From<bumpalo::collections::Vec<'_, geo::LineString>>
 {
     fn from(geoms: bumpalo::collections::Vec<'_, geo::LineString>) -> Self {
         let (coord_capacity, geom_capacity) = first_pass(geoms.iter().map(Some), geoms.len());
-        second_pass(geoms.into_iter().map(Some), coord_capacity, geom_capacity)
+        second_pass(
+            geoms.into_iter().map(Some),
+            coord_capacity,
+            geom_capacity,
+            Dimension::XY,
+        )
     }
 }
 
@@ -230,7 +339,12 @@ impl<O: OffsetSizeTrait> From<bumpalo::collections::Vec<'_, Option<geo::LineStri
     fn from(geoms: bumpalo::collections::Vec<'_, Option<geo::LineString>>) -> Self {
         let (coord_capacity, geom_capacity) =
             first_pass(geoms.iter().map(|x| x.as_ref()), geoms.len());
-        second_pass(geoms.into_iter(), coord_capacity, geom_capacity)
+        second_pass(
+            geoms.into_iter(),
+            coord_capacity,
+            geom_capacity,
+            Dimension::XY,
+        )
     }
 }
 
@@ -249,10 +363,13 @@ impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for MutableLineStringArray<O> {
             .collect();
         let (coord_capacity, geom_capacity) =
             first_pass(wkb_objects2.iter().map(|item| item.as_ref()), value.len());
+        // Keep the z values if any geometry has them
+        let dim = Dimension::widest(wkb_objects2.iter().flatten().map(|geom| geom.dim()));
         Ok(second_pass(
             wkb_objects2.iter().map(|item| item.as_ref()),
             coord_capacity,
             geom_capacity,
+            dim,
         ))
     }
 }
@@ -264,3 +381,66 @@ impl<O: OffsetSizeTrait> From<MutableLineStringArray<O>> for MutableMultiPointAr
         Self::try_new(value.coords, value.geom_offsets, value.validity).unwrap()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::linestring::{ls0, ls1};
+    use crate::trait_::GeoArrayAccessor;
+    use std::iter::Cloned;
+    use std::slice::Iter;
+
+    /// A line string type defined outside the crate, stored as coordinate tuples.
+    struct TupleLine(Vec<(f64, f64)>);
+
+    impl<'a> LineStringTrait<'a> for TupleLine {
+        type T = f64;
+        type ItemType = (f64, f64);
+        type Iter = Cloned<Iter<'a, (f64, f64)>>;
+
+        fn coords(&'a self) -> Self::Iter {
+            self.0.iter().cloned()
+        }
+
+        fn num_coords(&self) -> usize {
+            self.0.len()
+        }
+
+        fn coord(&self, i: usize) -> Option<Self::ItemType> {
+            self.0.get(i).copied()
+        }
+    }
+
+    #[test]
+    fn push_line_string_via_trait() {
+        let mut builder = MutableLineStringArray::<i32>::new();
+        builder.push_line_string(Some(&ls0())).unwrap();
+        builder.push_line_string(None::<&geo::LineString>).unwrap();
+        builder
+            .push_line_string(Some(&TupleLine(vec![(3., 4.), (5., 6.)])))
+            .unwrap();
+        let arr: LineStringArray<i32> = builder.into();
+
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr.get_as_geo(0), Some(ls0()));
+        assert!(arr.is_null(1));
+        assert_eq!(arr.get_as_geo(2), Some(ls1()));
+    }
+
+    #[test]
+    fn collect_borrowed_scalars() {
+        let arr: LineStringArray<i32> = vec![Some(ls0()), None, Some(ls1())].into();
+
+        // The scalars borrow the coordinates of `arr`, which are copied once into the new array
+        let collected: LineStringArray<i32> = arr.iter().collect();
+        assert_eq!(collected, arr);
+
+        let (coord_capacity, geom_capacity) = first_pass(arr.iter(), arr.len());
+        let capacity = LineStringCapacity::new(coord_capacity, geom_capacity);
+        let builder = MutableLineStringArray::<i64>::from_iter_with_capacity(arr.iter(), capacity);
+        // Nothing was reallocated past the exact capacity
+        assert_eq!(builder.capacity(), capacity);
+        let collected: LineStringArray<i64> = builder.into();
+        assert_eq!(collected.get_as_geo(2), Some(ls1()));
+    }
+}
@@ -0,0 +1,162 @@
+//! Array-level metadata, stored in the `ARROW:extension:metadata` key of a GeoArrow field.
+
+use std::collections::HashMap;
+
+use arrow_schema::Field;
+use serde_json::{json, Value};
+
+use crate::error::{GeoArrowError, Result};
+
+/// How to interpret the edges between two consecutive vertices of a geometry.
+///
+/// Corresponds to the `edges` key of the [GeoArrow extension
+/// metadata](https://github.com/geoarrow/geoarrow/blob/main/extension-types.md#extension-metadata).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Edges {
+    /// Edges are straight lines in the coordinate plane. This is the default when the metadata
+    /// has no `edges` key.
+    #[default]
+    Planar,
+
+    /// Edges are the shortest path on the surface of a sphere, and coordinates are longitude and
+    /// latitude.
+    Spherical,
+}
+
+/// Metadata that applies to every geometry in an array.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArrayMetadata {
+    pub edges: Edges,
+
+    /// The coordinate reference system, as stored in the `crs` key: usually a PROJJSON object or
+    /// a string such as `"EPSG:4326"`. `None` if the CRS is unknown.
+    pub crs: Option<Value>,
+}
+
+/// Metadata of arrays that don't store their own.
+pub(crate) static DEFAULT_METADATA: ArrayMetadata = ArrayMetadata {
+    edges: Edges::Planar,
+    crs: None,
+};
+
+impl ArrayMetadata {
+    pub fn new(edges: Edges) -> Self {
+        Self { edges, crs: None }
+    }
+
+    /// Replace the coordinate reference system.
+    pub fn with_crs(self, crs: Option<Value>) -> Self {
+        Self { crs, ..self }
+    }
+
+    /// Parse the metadata from a JSON string, as stored in `ARROW:extension:metadata`.
+    ///
+    /// Unknown keys are ignored.
+    pub fn from_json(s: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(s).map_err(|err| {
+            GeoArrowError::General(format!("Invalid extension metadata: {}", err))
+        })?;
+
+        let edges = match value.get("edges").and_then(|v| v.as_str()) {
+            None | Some("planar") => Edges::Planar,
+            Some("spherical") => Edges::Spherical,
+            Some(other) => {
+                return Err(GeoArrowError::General(format!(
+                    "Unknown edges value: {}",
+                    other
+                )))
+            }
+        };
+
+        let crs = value.get("crs").filter(|crs| !crs.is_null()).cloned();
+
+        Ok(Self { edges, crs })
+    }
+
+    /// Parse the metadata from the `ARROW:extension:metadata` key of a field, if it exists.
+    pub fn from_field(field: &Field) -> Result<Self> {
+        match field.metadata().get("ARROW:extension:metadata") {
+            Some(s) => Self::from_json(s),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Serialize the metadata to the JSON string stored in `ARROW:extension:metadata`.
+    pub fn to_json(&self) -> String {
+        let mut value = json!({});
+        if self.edges == Edges::Spherical {
+            value["edges"] = json!("spherical");
+        }
+        if let Some(crs) = &self.crs {
+            value["crs"] = crs.clone();
+        }
+        value.to_string()
+    }
+
+    /// Add the `ARROW:extension:metadata` key to field metadata, unless this is the default
+    /// metadata, in which case the key is omitted.
+    pub(crate) fn write_to_field_metadata(&self, field_metadata: &mut HashMap<String, String>) {
+        if self != &DEFAULT_METADATA {
+            field_metadata.insert("ARROW:extension:metadata".to_string(), self.to_json());
+        }
+    }
+
+    /// The edges shared by both inputs of a binary operation.
+    ///
+    /// Errors if one input has planar and the other has spherical edges, because there's no
+    /// correct way to compare the two.
+    pub fn common_edges(&self, other: &ArrayMetadata) -> Result<Edges> {
+        if self.edges != other.edges {
+            return Err(GeoArrowError::General(format!(
+                "Cannot combine arrays with {:?} and {:?} edges",
+                self.edges, other.edges
+            )));
+        }
+
+        Ok(self.edges)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::GeometryArray;
+    use crate::GeometryArrayTrait;
+
+    #[test]
+    fn json_roundtrip() {
+        let metadata = ArrayMetadata::new(Edges::Spherical);
+        assert_eq!(metadata.to_json(), r#"{"edges":"spherical"}"#);
+        assert_eq!(
+            ArrayMetadata::from_json(&metadata.to_json()).unwrap(),
+            metadata
+        );
+
+        let with_crs = ArrayMetadata::from_json(r#"{"crs": "EPSG:4326", "other": 1}"#).unwrap();
+        assert_eq!(
+            with_crs,
+            ArrayMetadata::default().with_crs(Some(json!("EPSG:4326")))
+        );
+        assert_eq!(with_crs.to_json(), r#"{"crs":"EPSG:4326"}"#);
+        assert!(ArrayMetadata::from_json(r#"{"edges": "geodesic"}"#).is_err());
+    }
+
+    #[test]
+    fn field_roundtrip() {
+        let array =
+            crate::test::linestring::ls_array().with_metadata(ArrayMetadata::new(Edges::Spherical));
+        let field = array.extension_field();
+        let array_ref = array.into_array_ref();
+
+        let parsed: GeometryArray<i32> = (field.as_ref(), array_ref.as_ref()).try_into().unwrap();
+        assert_eq!(parsed.metadata().edges, Edges::Spherical);
+    }
+
+    #[test]
+    fn common_edges() {
+        let planar = ArrayMetadata::default();
+        let spherical = ArrayMetadata::new(Edges::Spherical);
+        assert_eq!(planar.common_edges(&planar).unwrap(), Edges::Planar);
+        assert!(planar.common_edges(&spherical).is_err());
+    }
+}
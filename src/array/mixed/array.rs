@@ -1,20 +1,23 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use arrow_array::{Array, OffsetSizeTrait, UnionArray};
+use arrow_array::{Array, ArrayRef, OffsetSizeTrait, UnionArray};
 use arrow_buffer::{NullBuffer, ScalarBuffer};
 use arrow_schema::{DataType, Field, UnionFields, UnionMode};
 
+use crate::algorithm::native::bounding_rect::total_bounds_rects;
+use crate::algorithm::native::eq::EqualsExact;
 use crate::array::mixed::mutable::MutableMixedGeometryArray;
 use crate::array::{
-    CoordType, LineStringArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray,
-    PointArray, PolygonArray,
+    CoordBuffer, CoordType, LineStringArray, MultiLineStringArray, MultiPointArray,
+    MultiPolygonArray, PointArray, PolygonArray, WKBArray,
 };
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
 use crate::scalar::Geometry;
-use crate::trait_::GeoArrayAccessor;
+use crate::trait_::{FromArrow, GeoArrayAccessor};
 use crate::GeometryArrayTrait;
+use geo::BoundingRect;
 
 /// # Invariants
 ///
@@ -87,7 +90,7 @@ pub struct MixedGeometryArray<O: OffsetSizeTrait> {
     slice_offset: usize,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum GeometryType {
     Point = 0,
     LineString = 1,
@@ -155,16 +158,8 @@ impl<O: OffsetSizeTrait> MixedGeometryArray<O> {
             Some(GeometryType::MultiPolygon),
         ];
 
-        // let coord_type = coords.coord_type();
-        // TODO: use correct coord type
-        let coord_type = CoordType::Interleaved;
-        let data_type = match O::IS_LARGE {
-            true => GeoDataType::LargeMixed(coord_type),
-            false => GeoDataType::Mixed(coord_type),
-        };
-
         Self {
-            data_type,
+            data_type: mixed_data_type::<O>(CoordType::Interleaved),
             types,
             offsets,
             map: default_ordering,
@@ -176,6 +171,83 @@ impl<O: OffsetSizeTrait> MixedGeometryArray<O> {
             multi_polygons,
             slice_offset: 0,
         }
+        .with_inferred_coord_type()
+    }
+
+    /// Set the data type from the coord type of the first non-empty child, defaulting to
+    /// interleaved if all children are empty.
+    fn with_inferred_coord_type(mut self) -> Self {
+        let coord_type = [
+            (self.points.len(), self.points.coord_type()),
+            (self.line_strings.len(), self.line_strings.coord_type()),
+            (self.polygons.len(), self.polygons.coord_type()),
+            (self.multi_points.len(), self.multi_points.coord_type()),
+            (
+                self.multi_line_strings.len(),
+                self.multi_line_strings.coord_type(),
+            ),
+            (self.multi_polygons.len(), self.multi_polygons.coord_type()),
+        ]
+        .into_iter()
+        .find_map(|(len, coord_type)| (len > 0).then_some(coord_type))
+        .unwrap_or(CoordType::Interleaved);
+        self.data_type = mixed_data_type::<O>(coord_type);
+        self
+    }
+
+    /// The length of the child array holding geometries of type `geometry_type`.
+    fn child_len(&self, geometry_type: GeometryType) -> usize {
+        match geometry_type {
+            GeometryType::Point => self.points.len(),
+            GeometryType::LineString => self.line_strings.len(),
+            GeometryType::Polygon => self.polygons.len(),
+            GeometryType::MultiPoint => self.multi_points.len(),
+            GeometryType::MultiLineString => self.multi_line_strings.len(),
+            GeometryType::MultiPolygon => self.multi_polygons.len(),
+        }
+    }
+
+    fn child_field(&self, geometry_type: GeometryType) -> Arc<Field> {
+        match geometry_type {
+            GeometryType::Point => self.points.extension_field(),
+            GeometryType::LineString => self.line_strings.extension_field(),
+            GeometryType::Polygon => self.polygons.extension_field(),
+            GeometryType::MultiPoint => self.multi_points.extension_field(),
+            GeometryType::MultiLineString => self.multi_line_strings.extension_field(),
+            GeometryType::MultiPolygon => self.multi_polygons.extension_field(),
+        }
+    }
+
+    fn child_array_ref(&self, geometry_type: GeometryType) -> ArrayRef {
+        match geometry_type {
+            GeometryType::Point => self.points.clone().into_array_ref(),
+            GeometryType::LineString => self.line_strings.clone().into_array_ref(),
+            GeometryType::Polygon => self.polygons.clone().into_array_ref(),
+            GeometryType::MultiPoint => self.multi_points.clone().into_array_ref(),
+            GeometryType::MultiLineString => self.multi_line_strings.clone().into_array_ref(),
+            GeometryType::MultiPolygon => self.multi_polygons.clone().into_array_ref(),
+        }
+    }
+
+    /// The union type ids of the non-empty children, with their geometry types.
+    ///
+    /// Empty children can't be referenced by any slot, so they're left out of the union.
+    fn union_children(&self) -> Vec<(i8, GeometryType)> {
+        self.map
+            .iter()
+            .enumerate()
+            .filter_map(|(type_id, geometry_type)| {
+                let geometry_type = (*geometry_type)?;
+                (self.child_len(geometry_type) > 0).then_some((type_id as i8, geometry_type))
+            })
+            .collect()
+    }
+}
+
+fn mixed_data_type<O: OffsetSizeTrait>(coord_type: CoordType) -> GeoDataType {
+    match O::IS_LARGE {
+        true => GeoDataType::LargeMixed(coord_type),
+        false => GeoDataType::Mixed(coord_type),
     }
 }
 
@@ -189,34 +261,11 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MixedGeometryArray<O> {
     }
 
     fn storage_type(&self) -> DataType {
-        let mut fields: Vec<Arc<Field>> = vec![];
-        let mut type_ids = vec![];
-
-        if self.points.len() > 0 {
-            fields.push(self.points.extension_field());
-            type_ids.push(0);
-        }
-        if self.line_strings.len() > 0 {
-            fields.push(self.line_strings.extension_field());
-            type_ids.push(1);
-        }
-        if self.polygons.len() > 0 {
-            fields.push(self.polygons.extension_field());
-            type_ids.push(2);
-        }
-        if self.multi_points.len() > 0 {
-            fields.push(self.multi_points.extension_field());
-            type_ids.push(3);
-        }
-        if self.multi_line_strings.len() > 0 {
-            fields.push(self.multi_line_strings.extension_field());
-            type_ids.push(4);
-        }
-        if self.multi_polygons.len() > 0 {
-            fields.push(self.multi_polygons.extension_field());
-            type_ids.push(5);
-        }
-
+        let (type_ids, fields): (Vec<i8>, Vec<Arc<Field>>) = self
+            .union_children()
+            .into_iter()
+            .map(|(type_id, geometry_type)| (type_id, self.child_field(geometry_type)))
+            .unzip();
         let union_fields = UnionFields::new(type_ids, fields);
         DataType::Union(union_fields, UnionMode::Dense)
     }
@@ -234,20 +283,115 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MixedGeometryArray<O> {
         "geoarrow.mixed"
     }
 
+    /// Export to a dense union array, with one child for each geometry type present.
+    ///
+    /// Sliced arrays export their children in full, with offsets into them.
     fn into_array_ref(self) -> Arc<dyn Array> {
-        todo!()
+        let (type_ids, children): (Vec<i8>, Vec<(Field, ArrayRef)>) = self
+            .union_children()
+            .into_iter()
+            .map(|(type_id, geometry_type)| {
+                let field = self.child_field(geometry_type).as_ref().clone();
+                (type_id, (field, self.child_array_ref(geometry_type)))
+            })
+            .unzip();
+
+        // Safety: every type id in `types` points to a non-empty child, and every offset is within
+        // that child. `UnionArray::try_new` is not used because it rejects offsets past the
+        // union's own length, which are valid in a sliced array.
+        let union_array = unsafe {
+            UnionArray::new_unchecked(
+                &type_ids,
+                self.types.into_inner(),
+                Some(self.offsets.into_inner()),
+                children,
+            )
+        };
+        Arc::new(union_array)
+    }
+
+    /// Replace the coordinates of all children, where `coords` holds the coordinates of the
+    /// points, line strings, polygons, multi points, multi line strings and multi polygons in
+    /// that order.
+    ///
+    /// # Panics
+    ///
+    /// - if the length of `coords` doesn't match the total number of coordinates
+    fn with_coords(self, coords: CoordBuffer) -> Self {
+        let num_coords = [
+            self.points.coords.len(),
+            self.line_strings.coords.len(),
+            self.polygons.coords.len(),
+            self.multi_points.coords.len(),
+            self.multi_line_strings.coords.len(),
+            self.multi_polygons.coords.len(),
+        ];
+        assert_eq!(coords.len(), num_coords.iter().sum::<usize>());
+
+        let mut start = 0;
+        let mut next_coords = |len: usize| {
+            let child_coords = coords.slice(start, len);
+            start += len;
+            child_coords
+        };
+        Self {
+            points: self.points.with_coords(next_coords(num_coords[0])),
+            line_strings: self.line_strings.with_coords(next_coords(num_coords[1])),
+            polygons: self.polygons.with_coords(next_coords(num_coords[2])),
+            multi_points: self.multi_points.with_coords(next_coords(num_coords[3])),
+            multi_line_strings: self
+                .multi_line_strings
+                .with_coords(next_coords(num_coords[4])),
+            multi_polygons: self.multi_polygons.with_coords(next_coords(num_coords[5])),
+            ..self
+        }
+        .with_inferred_coord_type()
     }
 
-    fn with_coords(self, _coords: crate::array::CoordBuffer) -> Self {
-        todo!();
+    fn coord_type(&self) -> CoordType {
+        match &self.data_type {
+            GeoDataType::Mixed(coord_type) | GeoDataType::LargeMixed(coord_type) => {
+                coord_type.clone()
+            }
+            _ => unreachable!(),
+        }
     }
 
-    fn coord_type(&self) -> crate::array::CoordType {
-        todo!();
+    fn into_coord_type(self, coord_type: CoordType) -> Self {
+        Self {
+            data_type: mixed_data_type::<O>(coord_type.clone()),
+            points: self.points.into_coord_type(coord_type.clone()),
+            line_strings: self.line_strings.into_coord_type(coord_type.clone()),
+            polygons: self.polygons.into_coord_type(coord_type.clone()),
+            multi_points: self.multi_points.into_coord_type(coord_type.clone()),
+            multi_line_strings: self.multi_line_strings.into_coord_type(coord_type.clone()),
+            multi_polygons: self.multi_polygons.into_coord_type(coord_type),
+            ..self
+        }
+    }
+
+    fn into_coord_float32(self) -> Self {
+        Self {
+            points: self.points.into_coord_float32(),
+            line_strings: self.line_strings.into_coord_float32(),
+            polygons: self.polygons.into_coord_float32(),
+            multi_points: self.multi_points.into_coord_float32(),
+            multi_line_strings: self.multi_line_strings.into_coord_float32(),
+            multi_polygons: self.multi_polygons.into_coord_float32(),
+            ..self
+        }
     }
 
-    fn into_coord_type(self, _coord_type: crate::array::CoordType) -> Self {
-        todo!();
+    fn into_coord_float64(self) -> Self {
+        Self {
+            points: self.points.into_coord_float64(),
+            line_strings: self.line_strings.into_coord_float64(),
+            polygons: self.polygons.into_coord_float64(),
+            multi_points: self.multi_points.into_coord_float64(),
+            multi_line_strings: self.multi_line_strings.into_coord_float64(),
+            multi_polygons: self.multi_polygons.into_coord_float64(),
+            ..self
+        }
     }
 
     /// Returns the number of geometries in this array
@@ -257,12 +401,54 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MixedGeometryArray<O> {
         self.types.len()
     }
 
+    fn memory_size(&self) -> usize {
+        std::mem::size_of_val(self.types.as_ref())
+            + std::mem::size_of_val(self.offsets.as_ref())
+            + self.points.memory_size()
+            + self.line_strings.memory_size()
+            + self.polygons.memory_size()
+            + self.multi_points.memory_size()
+            + self.multi_line_strings.memory_size()
+            + self.multi_polygons.memory_size()
+    }
+
     /// Returns the optional validity.
     #[inline]
     fn validity(&self) -> Option<&NullBuffer> {
         None
     }
 
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        // Nulls are stored in the child arrays
+        let nulls = self.logical_nulls();
+        total_bounds_rects(
+            (0..self.len())
+                .filter(|i| nulls.as_ref().map_or(true, |nulls| nulls.is_valid(*i)))
+                .filter_map(|i| self.value_as_geo(i).bounding_rect()),
+        )
+    }
+
+    /// Like an Arrow union, this array has no validity of its own, and a slot is null when the
+    /// child slot it points to is null.
+    fn logical_nulls(&self) -> Option<NullBuffer> {
+        let validity: Vec<bool> = (0..self.len())
+            .map(|i| {
+                let child_index = self.types[i];
+                let offset = self.offsets[i] as usize;
+                match self.map[child_index as usize].unwrap() {
+                    GeometryType::Point => self.points.is_valid(offset),
+                    GeometryType::LineString => self.line_strings.is_valid(offset),
+                    GeometryType::Polygon => self.polygons.is_valid(offset),
+                    GeometryType::MultiPoint => self.multi_points.is_valid(offset),
+                    GeometryType::MultiLineString => self.multi_line_strings.is_valid(offset),
+                    GeometryType::MultiPolygon => self.multi_polygons.is_valid(offset),
+                }
+            })
+            .collect();
+        let nulls = NullBuffer::from(validity);
+        (nulls.null_count() > 0).then_some(nulls)
+    }
+
     /// Slices this [`MixedGeometryArray`] in place.
     ///
     /// # Implementation
@@ -293,8 +479,26 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MixedGeometryArray<O> {
         }
     }
 
-    fn owned_slice(&self, _offset: usize, _length: usize) -> Self {
-        todo!()
+    /// Copies the geometries in the slice into new child arrays, keeping their types. Null slots
+    /// become null points.
+    fn owned_slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "offset + length may not exceed length of array"
+        );
+
+        let nulls = self.logical_nulls();
+        let mut builder = MutableMixedGeometryArray::<O>::new();
+        builder.reserve_geometries(length);
+        for i in offset..offset + length {
+            if nulls.as_ref().is_some_and(|nulls| nulls.is_null(i)) {
+                builder.push_point(None::<&geo::Point>);
+            } else {
+                // The slice can't hold more than the source array, so this can't overflow
+                builder.push_geometry(&self.value(i)).unwrap();
+            }
+        }
+        builder.into()
     }
 }
 
@@ -303,12 +507,8 @@ impl<'a, O: OffsetSizeTrait> GeoArrayAccessor<'a> for MixedGeometryArray<O> {
     type ItemGeo = geo::Geometry;
 
     unsafe fn value_unchecked(&'a self, index: usize) -> Self::Item {
-        dbg!(&self.types);
         let child_index = self.types[index];
-        dbg!(child_index);
         let offset = self.offsets[index] as usize;
-        dbg!(offset);
-        dbg!(&self.map);
         let geometry_type = self.map[child_index as usize].unwrap();
 
         match geometry_type {
@@ -326,6 +526,23 @@ impl<'a, O: OffsetSizeTrait> GeoArrayAccessor<'a> for MixedGeometryArray<O> {
 
 // Implement geometry accessors
 impl<O: OffsetSizeTrait> MixedGeometryArray<O> {
+    /// Count the geometries of each type from the type ids, without touching the child arrays.
+    ///
+    /// Types that don't occur are not included.
+    pub fn type_ids_histogram(&self) -> HashMap<GeometryType, usize> {
+        let mut counts = [0; 6];
+        self.types
+            .iter()
+            .for_each(|type_id| counts[*type_id as usize] += 1);
+
+        counts
+            .into_iter()
+            .enumerate()
+            .filter(|(_, count)| *count > 0)
+            .map(|(type_id, count)| (self.map[type_id].unwrap(), count))
+            .collect()
+    }
+
     /// Iterator over geo Geometry objects, not looking at validity
     pub fn iter_geo_values(&self) -> impl Iterator<Item = geo::Geometry> + '_ {
         (0..self.len()).map(|i| self.value_as_geo(i))
@@ -361,183 +578,149 @@ impl<O: OffsetSizeTrait> MixedGeometryArray<O> {
     }
 }
 
-impl TryFrom<&UnionArray> for MixedGeometryArray<i32> {
-    type Error = GeoArrowError;
+/// Import a dense union whose children carry GeoArrow extension names, in any order.
+macro_rules! union_try_from_impl {
+    ($offset_type:ty) => {
+        impl TryFrom<&UnionArray> for MixedGeometryArray<$offset_type> {
+            type Error = GeoArrowError;
+
+            fn try_from(value: &UnionArray) -> std::result::Result<Self, Self::Error> {
+                let fields = match value.data_type() {
+                    DataType::Union(fields, UnionMode::Dense) => fields,
+                    _ => {
+                        return Err(GeoArrowError::General(
+                            "Expected a dense union array".to_string(),
+                        ))
+                    }
+                };
+
+                // Children that aren't in the union stay empty
+                let mut arr = MixedGeometryArray::new(
+                    value.type_ids().clone(),
+                    value.offsets().unwrap().clone(),
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                );
+                arr.map = [None; 6];
+
+                for (type_id, field) in fields.iter() {
+                    if !(0..6).contains(&type_id) {
+                        return Err(GeoArrowError::General(format!(
+                            "Union type id {} is out of range for a mixed geometry array",
+                            type_id
+                        )));
+                    }
+
+                    let child = value.child(type_id).as_ref();
+                    let extension_name = field
+                        .metadata()
+                        .get("ARROW:extension:name")
+                        .map(String::as_str);
+                    let geometry_type = match extension_name {
+                        Some("geoarrow.point") => {
+                            arr.points = FromArrow::from_arrow(child, field)?;
+                            GeometryType::Point
+                        }
+                        Some("geoarrow.linestring") => {
+                            arr.line_strings = FromArrow::from_arrow(child, field)?;
+                            GeometryType::LineString
+                        }
+                        Some("geoarrow.polygon") => {
+                            arr.polygons = FromArrow::from_arrow(child, field)?;
+                            GeometryType::Polygon
+                        }
+                        Some("geoarrow.multipoint") => {
+                            arr.multi_points = FromArrow::from_arrow(child, field)?;
+                            GeometryType::MultiPoint
+                        }
+                        Some("geoarrow.multilinestring") => {
+                            arr.multi_line_strings = FromArrow::from_arrow(child, field)?;
+                            GeometryType::MultiLineString
+                        }
+                        Some("geoarrow.multipolygon") => {
+                            arr.multi_polygons = FromArrow::from_arrow(child, field)?;
+                            GeometryType::MultiPolygon
+                        }
+                        _ => {
+                            return Err(GeoArrowError::General(format!(
+                                "Union child \"{}\" is not a GeoArrow point, line string, \
+                                 polygon or multi geometry",
+                                field.name()
+                            )))
+                        }
+                    };
+
+                    // So when a slot has type id 3, `map[3]` gives the child array to look in
+                    arr.map[type_id as usize] = Some(geometry_type);
+                }
+
+                Ok(arr.with_inferred_coord_type())
+            }
+        }
+    };
+}
 
-    fn try_from(_value: &UnionArray) -> std::result::Result<Self, Self::Error> {
-        todo!()
-        // let types = value.types().clone();
-        // let offsets = value.offsets().unwrap().clone();
-        // let child_arrays = value.fields();
-
-        // // Need to construct the mapping from the logical ordering to the physical ordering
-        // let map = match value.data_type() {
-        //     DataType::Union(fields, _mode) => {
-        //         let mut map: [Option<GeometryType>; 6] = [None, None, None, None, None, None];
-        //         assert!(ids.len() < 6);
-        //         for (pos, &id) in ids.iter().enumerate() {
-        //             let geom_type: GeometryType = match fields[pos].data_type() {
-        //                 DataType::Extension(ext_name, _, _) => (ext_name).into(),
-        //                 _ => panic!(),
-        //             };
-
-        //             // Set this geometry type in the lookup table
-        //             // So when you see `type: 3`, then you look up index `map[3]`, which gives you
-        //             // a geometry type. Then that geometry type is looked up in the primitive
-        //             // arrays.
-        //             map[id as usize] = Some(geom_type);
-        //         }
-
-        //         map
-        //     }
-        //     DataType::Union(_, None, _) => {
-        //         // return default ordering
-        //         [
-        //             Some(GeometryType::Point),
-        //             Some(GeometryType::LineString),
-        //             Some(GeometryType::Polygon),
-        //             Some(GeometryType::MultiPoint),
-        //             Some(GeometryType::MultiLineString),
-        //             Some(GeometryType::MultiPolygon),
-        //         ]
-        //     }
-        //     _ => panic!(),
-        // };
-
-        // let mut points: Option<PointArray> = None;
-        // let mut line_strings: Option<LineStringArray<i32>> = None;
-        // let mut polygons: Option<PolygonArray<i32>> = None;
-        // let mut multi_points: Option<MultiPointArray<i32>> = None;
-        // let mut multi_line_strings: Option<MultiLineStringArray<i32>> = None;
-        // let mut multi_polygons: Option<MultiPolygonArray<i32>> = None;
-
-        // for field in child_arrays {
-        //     let geometry_array: GeometryArray<i32> = field.as_ref().try_into().unwrap();
-        //     match geometry_array {
-        //         GeometryArray::Point(arr) => {
-        //             points = Some(arr);
-        //         }
-        //         GeometryArray::LineString(arr) => {
-        //             line_strings = Some(arr);
-        //         }
-        //         GeometryArray::Polygon(arr) => {
-        //             polygons = Some(arr);
-        //         }
-        //         GeometryArray::MultiPoint(arr) => {
-        //             multi_points = Some(arr);
-        //         }
-        //         GeometryArray::MultiLineString(arr) => {
-        //             multi_line_strings = Some(arr);
-        //         }
-        //         GeometryArray::MultiPolygon(arr) => {
-        //             multi_polygons = Some(arr);
-        //         }
-        //         _ => todo!(),
-        //     }
-        // }
-
-        // Ok(Self {
-        //     types,
-        //     offsets,
-        //     map,
-        //     points: points.unwrap_or_default(),
-        //     line_strings: line_strings.unwrap_or_default(),
-        //     polygons: polygons.unwrap_or_default(),
-        //     multi_points: multi_points.unwrap_or_default(),
-        //     multi_line_strings: multi_line_strings.unwrap_or_default(),
-        //     multi_polygons: multi_polygons.unwrap_or_default(),
-        //     slice_offset: 0,
-        // })
+union_try_from_impl!(i32);
+union_try_from_impl!(i64);
+
+impl<O: OffsetSizeTrait> EqualsExact for MixedGeometryArray<O> {
+    fn equals_exact(&self, other: &Self, epsilon: f64) -> bool {
+        self.types == other.types
+            && self.offsets == other.offsets
+            && self.map == other.map
+            && self.slice_offset == other.slice_offset
+            && self.points.equals_exact(&other.points, epsilon)
+            && self.line_strings.equals_exact(&other.line_strings, epsilon)
+            && self.polygons.equals_exact(&other.polygons, epsilon)
+            && self.multi_points.equals_exact(&other.multi_points, epsilon)
+            && self
+                .multi_line_strings
+                .equals_exact(&other.multi_line_strings, epsilon)
+            && self
+                .multi_polygons
+                .equals_exact(&other.multi_polygons, epsilon)
+    }
+}
+
+impl From<MixedGeometryArray<i32>> for MixedGeometryArray<i64> {
+    fn from(value: MixedGeometryArray<i32>) -> Self {
+        Self {
+            data_type: mixed_data_type::<i64>(value.coord_type()),
+            types: value.types,
+            offsets: value.offsets,
+            map: value.map,
+            points: value.points,
+            line_strings: value.line_strings.into(),
+            polygons: value.polygons.into(),
+            multi_points: value.multi_points.into(),
+            multi_line_strings: value.multi_line_strings.into(),
+            multi_polygons: value.multi_polygons.into(),
+            slice_offset: value.slice_offset,
+        }
     }
 }
 
-impl TryFrom<&UnionArray> for MixedGeometryArray<i64> {
+impl TryFrom<MixedGeometryArray<i64>> for MixedGeometryArray<i32> {
     type Error = GeoArrowError;
 
-    fn try_from(_value: &UnionArray) -> std::result::Result<Self, Self::Error> {
-        todo!()
-        // let types = value.types().clone();
-        // let offsets = value.offsets().unwrap().clone();
-        // let child_arrays = value.fields();
-
-        // // Need to construct the mapping from the logical ordering to the physical ordering
-        // let map = match value.data_type() {
-        //     DataType::Union(fields, Some(ids), _mode) => {
-        //         let mut map: [Option<GeometryType>; 6] = [None, None, None, None, None, None];
-        //         assert!(ids.len() < 6);
-        //         for (pos, &id) in ids.iter().enumerate() {
-        //             let geom_type: GeometryType = match fields[pos].data_type() {
-        //                 DataType::Extension(ext_name, _, _) => (ext_name).into(),
-        //                 _ => panic!(),
-        //             };
-
-        //             // Set this geometry type in the lookup table
-        //             // So when you see `type: 3`, then you look up index `map[3]`, which gives you
-        //             // a geometry type. Then that geometry type is looked up in the primitive
-        //             // arrays.
-        //             map[id as usize] = Some(geom_type);
-        //         }
-
-        //         map
-        //     }
-        //     DataType::Union(_, None, _) => {
-        //         // return default ordering
-        //         [
-        //             Some(GeometryType::Point),
-        //             Some(GeometryType::LineString),
-        //             Some(GeometryType::Polygon),
-        //             Some(GeometryType::MultiPoint),
-        //             Some(GeometryType::MultiLineString),
-        //             Some(GeometryType::MultiPolygon),
-        //         ]
-        //     }
-        //     _ => panic!(),
-        // };
-
-        // let mut points: Option<PointArray> = None;
-        // let mut line_strings: Option<LineStringArray<i64>> = None;
-        // let mut polygons: Option<PolygonArray<i64>> = None;
-        // let mut multi_points: Option<MultiPointArray<i64>> = None;
-        // let mut multi_line_strings: Option<MultiLineStringArray<i64>> = None;
-        // let mut multi_polygons: Option<MultiPolygonArray<i64>> = None;
-
-        // for field in child_arrays {
-        //     let geometry_array: GeometryArray<i64> = field.as_ref().try_into().unwrap();
-        //     match geometry_array {
-        //         GeometryArray::Point(arr) => {
-        //             points = Some(arr);
-        //         }
-        //         GeometryArray::LineString(arr) => {
-        //             line_strings = Some(arr);
-        //         }
-        //         GeometryArray::Polygon(arr) => {
-        //             polygons = Some(arr);
-        //         }
-        //         GeometryArray::MultiPoint(arr) => {
-        //             multi_points = Some(arr);
-        //         }
-        //         GeometryArray::MultiLineString(arr) => {
-        //             multi_line_strings = Some(arr);
-        //         }
-        //         GeometryArray::MultiPolygon(arr) => {
-        //             multi_polygons = Some(arr);
-        //         }
-        //         _ => todo!(),
-        //     }
-        // }
-
-        // Ok(Self {
-        //     types,
-        //     offsets,
-        //     map,
-        //     points: points.unwrap_or_default(),
-        //     line_strings: line_strings.unwrap_or_default(),
-        //     polygons: polygons.unwrap_or_default(),
-        //     multi_points: multi_points.unwrap_or_default(),
-        //     multi_line_strings: multi_line_strings.unwrap_or_default(),
-        //     multi_polygons: multi_polygons.unwrap_or_default(),
-        //     slice_offset: 0,
-        // })
+    fn try_from(value: MixedGeometryArray<i64>) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            data_type: mixed_data_type::<i32>(value.coord_type()),
+            types: value.types,
+            offsets: value.offsets,
+            map: value.map,
+            points: value.points,
+            line_strings: value.line_strings.try_into()?,
+            polygons: value.polygons.try_into()?,
+            multi_points: value.multi_points.try_into()?,
+            multi_line_strings: value.multi_line_strings.try_into()?,
+            multi_polygons: value.multi_polygons.try_into()?,
+            slice_offset: value.slice_offset,
+        })
     }
 }
 
@@ -550,6 +733,15 @@ impl<O: OffsetSizeTrait> TryFrom<Vec<geo::Geometry>> for MixedGeometryArray<O> {
     }
 }
 
+impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for MixedGeometryArray<O> {
+    type Error = GeoArrowError;
+
+    fn try_from(value: WKBArray<O>) -> std::result::Result<Self, Self::Error> {
+        let mut_arr: MutableMixedGeometryArray<O> = value.try_into()?;
+        Ok(mut_arr.into())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -608,9 +800,8 @@ mod test {
         assert_eq!(arr.value_as_geo(5), geoms[5]);
     }
 
-    #[ignore = "Something wrong in arrow-rs transition"]
     #[test]
-    fn arrow2_roundtrip() {
+    fn arrow_roundtrip() {
         let geoms: Vec<geo::Geometry> = vec![
             geo::Geometry::Point(point::p0()),
             geo::Geometry::LineString(linestring::ls0()),
@@ -621,12 +812,27 @@ mod test {
         ];
         let arr: MixedGeometryArray<i32> = geoms.clone().try_into().unwrap();
 
-        // Round trip to/from arrow2
+        // Round trip to/from arrow
         let arrow_array_ref = arr.into_array_ref();
         let arrow_array = arrow_array_ref
             .as_any()
             .downcast_ref::<UnionArray>()
             .unwrap();
+        let DataType::Union(fields, UnionMode::Dense) = arrow_array.data_type() else {
+            panic!("expected a dense union");
+        };
+        let extension_names: Vec<&str> = fields
+            .iter()
+            .map(|(_, field)| field.metadata()["ARROW:extension:name"].as_str())
+            .collect();
+        assert_eq!(
+            extension_names,
+            vec![
+                "geoarrow.multipoint",
+                "geoarrow.multilinestring",
+                "geoarrow.multipolygon"
+            ]
+        );
         let round_trip_arr: MixedGeometryArray<i32> = arrow_array.try_into().unwrap();
 
         assert_eq!(
@@ -645,4 +851,55 @@ mod test {
         assert_eq!(round_trip_arr.value_as_geo(4), geoms[4]);
         assert_eq!(round_trip_arr.value_as_geo(5), geoms[5]);
     }
+
+    #[test]
+    fn type_ids_histogram() {
+        let geoms: Vec<geo::Geometry> = vec![
+            geo::Geometry::Point(point::p0()),
+            geo::Geometry::Point(point::p1()),
+            geo::Geometry::Polygon(polygon::p0()),
+        ];
+        let arr: MixedGeometryArray<i32> = geoms.try_into().unwrap();
+
+        // Single geometries are stored as their multi counterpart
+        let histogram = arr.type_ids_histogram();
+        assert_eq!(histogram.len(), 2);
+        assert_eq!(histogram[&GeometryType::MultiPoint], 2);
+        assert_eq!(histogram[&GeometryType::MultiPolygon], 1);
+
+        let histogram = arr.slice(1, 2).type_ids_histogram();
+        assert_eq!(histogram[&GeometryType::MultiPoint], 1);
+    }
+
+    #[test]
+    fn sliced_arrow_roundtrip() {
+        let mut builder = MutableMixedGeometryArray::<i64>::new();
+        builder.push_point(Some(&point::p0()));
+        builder.push_polygon(Some(&polygon::p0())).unwrap();
+        builder.push_point(Some(&point::p1()));
+        builder.push_line_string(Some(&linestring::ls0())).unwrap();
+        let arr: MixedGeometryArray<i64> = builder.into();
+        let arr = arr.into_coord_type(CoordType::Separated).slice(2, 2);
+        assert_eq!(arr.coord_type(), CoordType::Separated);
+
+        let arrow_array_ref = arr.clone().into_array_ref();
+        let arrow_array = arrow_array_ref
+            .as_any()
+            .downcast_ref::<UnionArray>()
+            .unwrap();
+        let round_trip_arr: MixedGeometryArray<i64> = arrow_array.try_into().unwrap();
+        assert_eq!(round_trip_arr.len(), 2);
+        assert_eq!(round_trip_arr.coord_type(), CoordType::Separated);
+        assert_eq!(
+            round_trip_arr.value_as_geo(0),
+            geo::Geometry::Point(point::p1())
+        );
+        assert_eq!(
+            round_trip_arr.value_as_geo(1),
+            geo::Geometry::LineString(linestring::ls0())
+        );
+
+        let owned = arr.owned_slice(1, 1);
+        assert_eq!(owned.value_as_geo(0), arr.value_as_geo(1));
+    }
 }
@@ -1,12 +1,12 @@
 use crate::array::mixed::array::GeometryType;
 use crate::array::{
-    MixedGeometryArray, MutableLineStringArray, MutableMultiLineStringArray,
+    LineStringCapacity, MixedGeometryArray, MultiLineStringCapacity, MultiPointCapacity,
+    MultiPolygonCapacity, MutableLineStringArray, MutableMultiLineStringArray,
     MutableMultiPointArray, MutableMultiPolygonArray, MutablePointArray, MutablePolygonArray,
-    WKBArray,
+    PolygonCapacity, WKBArray,
 };
 use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::*;
-use crate::io::wkb::reader::geometry::WKBGeometry;
 use crate::scalar::WKB;
 use crate::GeometryArrayTrait;
 use arrow_array::OffsetSizeTrait;
@@ -87,51 +87,28 @@ impl<'a, O: OffsetSizeTrait> MutableMixedGeometryArray<O> {
     }
 
     /// Reserve capacity for at least `additional` more LineStrings.
-    pub fn reserve_line_strings(&mut self, coord_additional: usize, geom_additional: usize) {
-        self.line_strings.reserve(coord_additional, geom_additional);
+    pub fn reserve_line_strings(&mut self, additional: LineStringCapacity) {
+        self.line_strings.reserve(additional)
     }
 
     /// Reserve capacity for at least `additional` more Polygons.
-    pub fn reserve_polygons(
-        &mut self,
-        coord_additional: usize,
-        ring_additional: usize,
-        geom_additional: usize,
-    ) {
-        self.polygons
-            .reserve(coord_additional, ring_additional, geom_additional)
+    pub fn reserve_polygons(&mut self, additional: PolygonCapacity) {
+        self.polygons.reserve(additional)
     }
 
     /// Reserve capacity for at least `additional` more MultiPoints.
-    pub fn reserve_multi_points(&mut self, coord_additional: usize, geom_additional: usize) {
-        self.multi_points.reserve(coord_additional, geom_additional)
+    pub fn reserve_multi_points(&mut self, additional: MultiPointCapacity) {
+        self.multi_points.reserve(additional)
     }
 
     /// Reserve capacity for at least `additional` more MultiLineStrings.
-    pub fn reserve_multi_line_strings(
-        &mut self,
-        coord_additional: usize,
-        ring_additional: usize,
-        geom_additional: usize,
-    ) {
-        self.multi_line_strings
-            .reserve(coord_additional, ring_additional, geom_additional)
+    pub fn reserve_multi_line_strings(&mut self, additional: MultiLineStringCapacity) {
+        self.multi_line_strings.reserve(additional)
     }
 
     /// Reserve capacity for at least `additional` more MultiPolygons.
-    pub fn reserve_multi_polygons(
-        &mut self,
-        coord_additional: usize,
-        ring_additional: usize,
-        polygon_additional: usize,
-        geom_additional: usize,
-    ) {
-        self.multi_polygons.reserve(
-            coord_additional,
-            ring_additional,
-            polygon_additional,
-            geom_additional,
-        )
+    pub fn reserve_multi_polygons(&mut self, additional: MultiPolygonCapacity) {
+        self.multi_polygons.reserve(additional)
     }
 
     // /// The canonical method to create a [`MutableMixedGeometryArray`] out of its internal
@@ -425,22 +402,18 @@ impl<O: OffsetSizeTrait> TryFrom<Vec<geo::Geometry>> for MutableMixedGeometryArr
 impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for MutableMixedGeometryArray<O> {
     type Error = GeoArrowError;
 
+    /// Null WKB values become null points.
     fn try_from(value: WKBArray<O>) -> std::result::Result<Self, Self::Error> {
-        assert_eq!(
-            value.nulls().map_or(0, |validity| validity.null_count()),
-            0,
-            "Parsing a WKBArray with null elements not supported",
-        );
-
         // TODO: do a first pass over WKB array to compute sizes for each geometry type
         let mut result_arr = MutableMixedGeometryArray::new();
+        result_arr.reserve_geometries(value.len());
 
-        let wkb_objects: Vec<WKB<'_, O>> =
-            value.iter().map(|maybe_wkb| maybe_wkb.unwrap()).collect();
-        let wkb_objects2: Vec<WKBGeometry> =
-            wkb_objects.iter().map(|wkb| wkb.to_wkb_object()).collect();
-        for wkb in wkb_objects2 {
-            result_arr.push_geometry(&wkb)?;
+        let wkb_objects: Vec<Option<WKB<'_, O>>> = value.iter().collect();
+        for maybe_wkb in wkb_objects.iter() {
+            match maybe_wkb {
+                Some(wkb) => result_arr.push_geometry(&wkb.to_wkb_object())?,
+                None => result_arr.push_point(None::<&geo::Point>),
+            }
         }
 
         Ok(result_arr)
@@ -2,25 +2,30 @@
 
 pub use binary::{MutableWKBArray, WKBArray};
 pub use coord::{
-    CoordBuffer, CoordType, InterleavedCoordBuffer, MutableCoordBuffer,
-    MutableInterleavedCoordBuffer, MutableSeparatedCoordBuffer, SeparatedCoordBuffer,
+    CoordBuffer, CoordType, Dimension, Float32CoordBuffer, InterleavedCoordBuffer,
+    MutableCoordBuffer, MutableInterleavedCoordBuffer, MutableSeparatedCoordBuffer,
+    SeparatedCoordBuffer,
 };
 pub use geometry::GeometryArray;
-pub use geometrycollection::GeometryCollectionArray;
-pub use linestring::{LineStringArray, MutableLineStringArray};
+pub use geometrycollection::{GeometryCollectionArray, MutableGeometryCollectionArray};
+pub use linestring::{LineStringArray, LineStringCapacity, MutableLineStringArray};
+pub use metadata::{ArrayMetadata, Edges};
 pub use mixed::{MixedGeometryArray, MutableMixedGeometryArray};
-pub use multilinestring::{MultiLineStringArray, MutableMultiLineStringArray};
-pub use multipoint::{MultiPointArray, MutableMultiPointArray};
-pub use multipolygon::{MultiPolygonArray, MutableMultiPolygonArray};
+pub use multilinestring::{
+    MultiLineStringArray, MultiLineStringCapacity, MutableMultiLineStringArray,
+};
+pub use multipoint::{MultiPointArray, MultiPointCapacity, MutableMultiPointArray};
+pub use multipolygon::{MultiPolygonArray, MultiPolygonCapacity, MutableMultiPolygonArray};
 pub use point::{MutablePointArray, PointArray};
-pub use polygon::{MutablePolygonArray, PolygonArray};
-pub use rect::RectArray;
+pub use polygon::{MutablePolygonArray, PolygonArray, PolygonCapacity};
+pub use rect::{MutableRectArray, RectArray};
 
 pub mod binary;
 pub mod coord;
 pub mod geometry;
 pub mod geometrycollection;
 pub mod linestring;
+pub mod metadata;
 pub mod mixed;
 pub mod multilinestring;
 pub mod multipoint;
@@ -29,5 +34,4 @@ pub mod mutable_offset;
 pub mod point;
 pub mod polygon;
 pub mod rect;
-pub mod util;
 pub mod zip_validity;
@@ -1,16 +1,21 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::algorithm::native::bounding_rect::total_bounds_coords;
 use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::mutable_offset::OffsetsBuilder;
-use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
 use crate::array::zip_validity::ZipValidity;
-use crate::array::{CoordBuffer, CoordType, LineStringArray, PolygonArray, WKBArray};
+use crate::array::{
+    ArrayMetadata, CoordBuffer, CoordType, LineStringArray, PolygonArray, WKBArray,
+};
+use crate::buffer::owned_slice_offsets;
+use crate::buffer::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
+use crate::geo_traits::MultiLineStringTrait;
 use crate::scalar::MultiLineString;
 use crate::trait_::GeoArrayAccessor;
-use crate::util::{owned_slice_offsets, owned_slice_validity};
+use crate::util::{offsets_memory_size, owned_slice_validity, validity_memory_size};
 use crate::GeometryArrayTrait;
 use arrow_array::{Array, GenericListArray, LargeListArray, ListArray, OffsetSizeTrait};
 use arrow_buffer::bit_iterator::BitIterator;
@@ -29,6 +34,9 @@ pub struct MultiLineStringArray<O: OffsetSizeTrait> {
     // Always GeoDataType::MultiLineString or GeoDataType::LargeMultiLineString
     data_type: GeoDataType,
 
+    /// Array-level metadata, such as how to interpret edges
+    metadata: ArrayMetadata,
+
     pub coords: CoordBuffer,
 
     /// Offsets into the ring array where each geometry starts
@@ -112,20 +120,55 @@ impl<O: OffsetSizeTrait> MultiLineStringArray<O> {
             &ring_offsets,
             validity.as_ref().map(|v| v.len()),
         )?;
+        // Safety: the invariants were checked above
+        Ok(unsafe { Self::new_unchecked(coords, geom_offsets, ring_offsets, validity) })
+    }
 
+    /// Create a new MultiLineStringArray from parts, without validating them
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(1)`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    ///
+    /// - if the validity is not `None`, its length equals the number of geometries, i.e. one
+    ///   less than the length of `geom_offsets`
+    /// - the last geometry offset equals the number of line strings, i.e. one less than the
+    ///   length of `ring_offsets`
+    /// - the last ring offset equals the number of coordinates
+    ///
+    /// Each line string is read through two levels of offsets, and other Arrow implementations,
+    /// such as consumers of the C Data Interface, don't check either of them, so offsets out of
+    /// range make them read out of bounds. Within this crate, they lead to panics.
+    pub unsafe fn new_unchecked(
+        coords: CoordBuffer,
+        geom_offsets: OffsetBuffer<O>,
+        ring_offsets: OffsetBuffer<O>,
+        validity: Option<NullBuffer>,
+    ) -> Self {
         let coord_type = coords.coord_type();
+        let dim = coords.dim();
         let data_type = match O::IS_LARGE {
-            true => GeoDataType::LargeMultiLineString(coord_type),
-            false => GeoDataType::MultiLineString(coord_type),
+            true => GeoDataType::LargeMultiLineString(coord_type, dim),
+            false => GeoDataType::MultiLineString(coord_type, dim),
         };
 
-        Ok(Self {
+        Self {
             data_type,
+            metadata: Default::default(),
             coords,
             geom_offsets,
             ring_offsets,
             validity,
-        })
+        }
+    }
+
+    /// Replace the [`ArrayMetadata`] of this array.
+    pub fn with_metadata(self, metadata: ArrayMetadata) -> Self {
+        Self { metadata, ..self }
     }
 
     fn vertices_field(&self) -> Arc<Field> {
@@ -166,6 +209,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiLineStringArray<O>
             "ARROW:extension:name".to_string(),
             self.extension_name().to_string(),
         );
+        self.metadata.write_to_field_metadata(&mut metadata);
         Arc::new(Field::new("geometry", self.storage_type(), true).with_metadata(metadata))
     }
 
@@ -173,6 +217,10 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiLineStringArray<O>
         "geoarrow.multilinestring"
     }
 
+    fn metadata(&self) -> &ArrayMetadata {
+        &self.metadata
+    }
+
     fn into_array_ref(self) -> Arc<dyn Array> {
         let vertices_field = self.vertices_field();
         let linestrings_field = self.linestrings_field();
@@ -195,6 +243,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiLineStringArray<O>
     fn with_coords(self, coords: CoordBuffer) -> Self {
         assert_eq!(coords.len(), self.coords.len());
         Self::new(coords, self.geom_offsets, self.ring_offsets, self.validity)
+            .with_metadata(self.metadata)
     }
 
     fn coord_type(&self) -> CoordType {
@@ -208,6 +257,27 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiLineStringArray<O>
             self.ring_offsets,
             self.validity,
         )
+        .with_metadata(self.metadata)
+    }
+
+    fn into_coord_float32(self) -> Self {
+        Self::new(
+            self.coords.into_coord_float32(),
+            self.geom_offsets,
+            self.ring_offsets,
+            self.validity,
+        )
+        .with_metadata(self.metadata)
+    }
+
+    fn into_coord_float64(self) -> Self {
+        Self::new(
+            self.coords.into_coord_float64(),
+            self.geom_offsets,
+            self.ring_offsets,
+            self.validity,
+        )
+        .with_metadata(self.metadata)
     }
 
     /// Returns the number of geometries in this array
@@ -217,12 +287,29 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiLineStringArray<O>
         self.geom_offsets.len() - 1
     }
 
+    fn memory_size(&self) -> usize {
+        self.coords.memory_size()
+            + offsets_memory_size(&self.geom_offsets)
+            + offsets_memory_size(&self.ring_offsets)
+            + validity_memory_size(self.validity.as_ref())
+    }
+
     /// Returns the optional validity.
     #[inline]
     fn validity(&self) -> Option<&NullBuffer> {
         self.validity.as_ref()
     }
 
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        total_bounds_coords(&self.coords, self.validity(), self.len(), |geom_idx| {
+            let (start, end) = self.geom_offsets.start_end(geom_idx);
+            (
+                self.ring_offsets[start].to_usize().unwrap(),
+                self.ring_offsets[end].to_usize().unwrap(),
+            )
+        })
+    }
+
     /// Slices this [`MultiLineStringArray`] in place.
     /// # Panic
     /// This function panics iff `offset + length > self.len()`.
@@ -236,6 +323,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiLineStringArray<O>
         // would be in the wrong location.
         Self {
             data_type: self.data_type.clone(),
+            metadata: self.metadata.clone(),
             coords: self.coords.clone(),
             geom_offsets: self.geom_offsets.slice(offset, length),
             ring_offsets: self.ring_offsets.clone(),
@@ -271,7 +359,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiLineStringArray<O>
 
         let validity = owned_slice_validity(self.nulls(), offset, length);
 
-        Self::new(coords, geom_offsets, ring_offsets, validity)
+        Self::new(coords, geom_offsets, ring_offsets, validity).with_metadata(self.metadata.clone())
     }
 }
 
@@ -285,6 +373,18 @@ impl<'a, O: OffsetSizeTrait> GeoArrayAccessor<'a> for MultiLineStringArray<O> {
 }
 // Implement geometry accessors
 impl<O: OffsetSizeTrait> MultiLineStringArray<O> {
+    /// Iterator over the coordinates of all geometries in this array, in order, as
+    /// [`geo::Coord`]s
+    ///
+    /// Null geometries are stored with empty offsets, so they have no coordinates to yield.
+    pub fn geo_coords_iter(&self) -> impl Iterator<Item = geo::Coord> + '_ {
+        let start_ring = self.geom_offsets[0].to_usize().unwrap();
+        let end_ring = self.geom_offsets.last().to_usize().unwrap();
+        let start = self.ring_offsets[start_ring].to_usize().unwrap();
+        let end = self.ring_offsets[end_ring].to_usize().unwrap();
+        (start..end).map(|i| self.coords.value(i).into())
+    }
+
     /// Iterator over geo Geometry objects, not looking at validity
     pub fn iter_geo_values(&self) -> impl Iterator<Item = geo::MultiLineString> + '_ {
         (0..self.len()).map(|i| self.value_as_geo(i))
@@ -408,6 +508,16 @@ impl<O: OffsetSizeTrait> From<Vec<Option<geo::MultiLineString>>> for MultiLineSt
     }
 }
 
+impl<'a, O: OffsetSizeTrait, G: MultiLineStringTrait<'a, T = f64>> FromIterator<Option<G>>
+    for MultiLineStringArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        iter.into_iter()
+            .collect::<MutableMultiLineStringArray<O>>()
+            .into()
+    }
+}
+
 impl<O: OffsetSizeTrait> From<Vec<geo::MultiLineString>> for MultiLineStringArray<O> {
     fn from(other: Vec<geo::MultiLineString>) -> Self {
         let mut_arr: MutableMultiLineStringArray<O> = other.into();
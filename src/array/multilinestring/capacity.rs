@@ -0,0 +1,16 @@
+/// The number of coordinates, line strings and geometries a [`MutableMultiLineStringArray`] can
+/// hold, or should reserve room for.
+///
+/// [`MutableMultiLineStringArray`]: super::MutableMultiLineStringArray
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MultiLineStringCapacity {
+    pub coord: usize,
+    pub ring: usize,
+    pub geom: usize,
+}
+
+impl MultiLineStringCapacity {
+    pub fn new(coord: usize, ring: usize, geom: usize) -> Self {
+        Self { coord, ring, geom }
+    }
+}
@@ -2,9 +2,11 @@
 //! MultiLineString geometries.
 
 pub use array::MultiLineStringArray;
+pub use capacity::MultiLineStringCapacity;
 pub use iterator::MultiLineStringArrayIter;
 pub use mutable::MutableMultiLineStringArray;
 
 mod array;
+mod capacity;
 pub mod iterator;
 mod mutable;
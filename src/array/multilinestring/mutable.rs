@@ -3,11 +3,11 @@ use std::sync::Arc;
 // use super::array::check;
 use crate::array::mutable_offset::OffsetsBuilder;
 use crate::array::{
-    MultiLineStringArray, MutableCoordBuffer, MutableInterleavedCoordBuffer, MutablePolygonArray,
-    WKBArray,
+    CoordType, Dimension, MultiLineStringArray, MultiLineStringCapacity, MutableCoordBuffer,
+    MutablePolygonArray, WKBArray,
 };
 use crate::error::{GeoArrowError, Result};
-use crate::geo_traits::{CoordTrait, LineStringTrait, MultiLineStringTrait};
+use crate::geo_traits::{LineStringTrait, MultiLineStringTrait};
 use crate::io::wkb::reader::maybe_multi_line_string::WKBMaybeMultiLineString;
 use crate::scalar::WKB;
 use crate::GeometryArrayTrait;
@@ -41,58 +41,75 @@ impl<'a, O: OffsetSizeTrait> MutableMultiLineStringArray<O> {
         MutablePolygonArray::new().into()
     }
 
-    /// Creates a new [`MutableMultiLineStringArray`] with a capacity.
+    /// Creates a new [`MutableMultiLineStringArray`] with the given capacities and no validity.
     pub fn with_capacities(
         coord_capacity: usize,
         ring_capacity: usize,
         geom_capacity: usize,
     ) -> Self {
-        let coords = MutableInterleavedCoordBuffer::with_capacity(coord_capacity);
+        Self::with_capacity(MultiLineStringCapacity::new(
+            coord_capacity,
+            ring_capacity,
+            geom_capacity,
+        ))
+    }
+
+    /// Creates a new [`MutableMultiLineStringArray`] with the given capacity and no validity.
+    pub fn with_capacity(capacity: MultiLineStringCapacity) -> Self {
+        Self::with_capacity_and_coord_type(capacity, CoordType::Interleaved)
+    }
+
+    /// Creates a new [`MutableMultiLineStringArray`] with the given capacity and no validity, storing coordinates
+    /// in the given layout.
+    pub fn with_capacity_and_coord_type(
+        capacity: MultiLineStringCapacity,
+        coord_type: CoordType,
+    ) -> Self {
+        Self::with_capacity_coord_type_and_dim(capacity, coord_type, Dimension::XY)
+    }
+
+    /// Creates a new [`MutableMultiLineStringArray`] with the given capacity and no validity, storing coordinates
+    /// of the given dimension in the given layout.
+    pub fn with_capacity_coord_type_and_dim(
+        capacity: MultiLineStringCapacity,
+        coord_type: CoordType,
+        dim: Dimension,
+    ) -> Self {
         Self {
-            coords: MutableCoordBuffer::Interleaved(coords),
-            geom_offsets: OffsetsBuilder::with_capacity(geom_capacity),
-            ring_offsets: OffsetsBuilder::with_capacity(ring_capacity),
-            validity: NullBufferBuilder::new(geom_capacity),
+            coords: MutableCoordBuffer::with_capacity_and_dim(capacity.coord, coord_type, dim),
+            ring_offsets: OffsetsBuilder::with_capacity(capacity.ring),
+            geom_offsets: OffsetsBuilder::with_capacity(capacity.geom),
+            validity: NullBufferBuilder::new(capacity.geom),
         }
     }
 
-    /// Reserves capacity for at least `additional` more LineStrings to be inserted
-    /// in the given `Vec<T>`. The collection may reserve more space to
-    /// speculatively avoid frequent reallocations. After calling `reserve`,
-    /// capacity will be greater than or equal to `self.len() + additional`.
-    /// Does nothing if capacity is already sufficient.
-    pub fn reserve(
-        &mut self,
-        coord_additional: usize,
-        ring_additional: usize,
-        geom_additional: usize,
-    ) {
-        self.coords.reserve(coord_additional);
-        self.ring_offsets.reserve(ring_additional);
-        self.geom_offsets.reserve(geom_additional);
+    /// Reserves capacity for at least `additional` more MultiLineStrings and their parts. Like
+    /// [`Vec::reserve`], this may over-allocate, so that repeated small reservations grow the
+    /// buffers geometrically. Does nothing if capacity is already sufficient.
+    pub fn reserve(&mut self, additional: MultiLineStringCapacity) {
+        self.coords.reserve(additional.coord);
+        self.ring_offsets.reserve(additional.ring);
+        self.geom_offsets.reserve(additional.geom);
     }
 
-    /// Reserves the minimum capacity for at least `additional` more LineStrings to
-    /// be inserted in the given `Vec<T>`. Unlike [`reserve`], this will not
-    /// deliberately over-allocate to speculatively avoid frequent allocations.
-    /// After calling `reserve_exact`, capacity will be greater than or equal to
-    /// `self.len() + additional`. Does nothing if the capacity is already
-    /// sufficient.
-    ///
-    /// Note that the allocator may give the collection more space than it
-    /// requests. Therefore, capacity can not be relied upon to be precisely
-    /// minimal. Prefer [`reserve`] if future insertions are expected.
+    /// Reserves the minimum capacity for at least `additional` more MultiLineStrings and their parts.
+    /// Unlike [`reserve`], this will not deliberately over-allocate, so calling it once per
+    /// geometry reallocates on every call. Prefer [`reserve`] if future insertions are expected.
     ///
-    /// [`reserve`]: Vec::reserve
-    pub fn reserve_exact(
-        &mut self,
-        coord_additional: usize,
-        ring_additional: usize,
-        geom_additional: usize,
-    ) {
-        self.coords.reserve_exact(coord_additional);
-        self.ring_offsets.reserve(ring_additional);
-        self.geom_offsets.reserve(geom_additional);
+    /// [`reserve`]: Self::reserve
+    pub fn reserve_exact(&mut self, additional: MultiLineStringCapacity) {
+        self.coords.reserve_exact(additional.coord);
+        self.ring_offsets.reserve_exact(additional.ring);
+        self.geom_offsets.reserve_exact(additional.geom);
+    }
+
+    /// The number of coordinates and parts this array can hold without reallocating.
+    pub fn capacity(&self) -> MultiLineStringCapacity {
+        MultiLineStringCapacity {
+            coord: self.coords.capacity(),
+            ring: self.ring_offsets.capacity(),
+            geom: self.geom_offsets.capacity(),
+        }
     }
 
     /// The canonical method to create a [`MutableMultiLineStringArray`] out of its internal
@@ -167,7 +184,7 @@ impl<'a, O: OffsetSizeTrait> MutableMultiLineStringArray<O> {
 
             for coord_idx in 0..line_string.num_coords() {
                 let coord = line_string.coord(coord_idx).unwrap();
-                self.coords.push_xy(coord.x(), coord.y());
+                self.coords.push_coord(coord);
             }
 
             self.validity.append(true);
@@ -205,7 +222,7 @@ impl<'a, O: OffsetSizeTrait> MutableMultiLineStringArray<O> {
 
                 for coord_idx in 0..line_string.num_coords() {
                     let coord = line_string.coord(coord_idx).unwrap();
-                    self.coords.push_xy(coord.x(), coord.y());
+                    self.coords.push_coord(coord);
                 }
             }
 
@@ -239,6 +256,41 @@ impl<'a, O: OffsetSizeTrait> MutableMultiLineStringArray<O> {
         Ok(())
     }
 
+    /// Push each item of `geoms`, which are multi line strings or nulls.
+    ///
+    /// # Panics
+    ///
+    /// - if the offsets overflow `O`
+    fn extend_from_iter(
+        &mut self,
+        geoms: impl Iterator<Item = Option<impl MultiLineStringTrait<'a, T = f64>>>,
+    ) {
+        geoms
+            .into_iter()
+            .try_for_each(|maybe_multi_line_string| {
+                self.push_multi_line_string(maybe_multi_line_string.as_ref())
+            })
+            .unwrap();
+    }
+
+    /// Creates a new [`MutableMultiLineStringArray`] from an iterator of multi line strings, reserving `capacity` up
+    /// front.
+    ///
+    /// If `capacity` covers every item, as when it was computed in a first pass over them, the
+    /// coordinates are copied once with no reallocation.
+    ///
+    /// # Panics
+    ///
+    /// - if the offsets overflow `O`
+    pub fn from_iter_with_capacity(
+        geoms: impl Iterator<Item = Option<impl MultiLineStringTrait<'a, T = f64>>>,
+        capacity: MultiLineStringCapacity,
+    ) -> Self {
+        let mut array = Self::with_capacity(capacity);
+        array.extend_from_iter(geoms);
+        array
+    }
+
     /// Push a raw coordinate to the underlying coordinate array.
     ///
     /// # Safety
@@ -250,6 +302,28 @@ impl<'a, O: OffsetSizeTrait> MutableMultiLineStringArray<O> {
         Ok(())
     }
 
+    /// Push a raw measured coordinate to the underlying coordinate array.
+    ///
+    /// # Safety
+    ///
+    /// This is marked as unsafe because care must be taken to ensure that pushing raw coordinates
+    /// to the array upholds the necessary invariants of the array.
+    pub unsafe fn push_xym(&mut self, x: f64, y: f64, m: f64) -> Result<()> {
+        self.coords.push_xym(x, y, m);
+        Ok(())
+    }
+
+    /// Push a raw coordinate with z and m values to the underlying coordinate array.
+    ///
+    /// # Safety
+    ///
+    /// This is marked as unsafe because care must be taken to ensure that pushing raw coordinates
+    /// to the array upholds the necessary invariants of the array.
+    pub unsafe fn push_xyzm(&mut self, x: f64, y: f64, z: f64, m: f64) -> Result<()> {
+        self.coords.push_xyzm(x, y, z, m);
+        Ok(())
+    }
+
     #[inline]
     pub(crate) fn push_null(&mut self) {
         // NOTE! Only the geom_offsets array needs to get extended, because the next geometry will
@@ -305,20 +379,31 @@ fn second_pass<'a, O: OffsetSizeTrait>(
     coord_capacity: usize,
     ring_capacity: usize,
     geom_capacity: usize,
+    dim: Dimension,
 ) -> MutableMultiLineStringArray<O> {
-    let mut array =
-        MutableMultiLineStringArray::with_capacities(coord_capacity, ring_capacity, geom_capacity);
-
-    geoms
-        .into_iter()
-        .try_for_each(|maybe_multi_line_string| {
-            array.push_multi_line_string(maybe_multi_line_string.as_ref())
-        })
-        .unwrap();
-
+    let capacity = MultiLineStringCapacity::new(coord_capacity, ring_capacity, geom_capacity);
+    let mut array = MutableMultiLineStringArray::with_capacity_coord_type_and_dim(
+        capacity,
+        CoordType::Interleaved,
+        dim,
+    );
+    array.extend_from_iter(geoms);
     array
 }
 
+impl<'a, O: OffsetSizeTrait, G: MultiLineStringTrait<'a, T = f64>> FromIterator<Option<G>>
+    for MutableMultiLineStringArray<O>
+{
+    /// Collect multi line strings into a new array. Use
+    /// [`from_iter_with_capacity`](MutableMultiLineStringArray::from_iter_with_capacity) when the sizes are
+    /// known up front.
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend_from_iter(iter.into_iter());
+        array
+    }
+}
+
 impl<O: OffsetSizeTrait> From<Vec<geo::MultiLineString>> for MutableMultiLineStringArray<O> {
     fn from(geoms: Vec<geo::MultiLineString>) -> Self {
         let (coord_capacity, ring_capacity, geom_capacity) =
@@ -328,6 +413,7 @@ impl<O: OffsetSizeTrait> From<Vec<geo::MultiLineString>> for MutableMultiLineStr
             coord_capacity,
             ring_capacity,
             geom_capacity,
+            Dimension::XY,
         )
     }
 }
@@ -343,6 +429,7 @@ impl<O: OffsetSizeTrait> From<Vec<Option<geo::MultiLineString>>>
             coord_capacity,
             ring_capacity,
             geom_capacity,
+            Dimension::XY,
         )
     }
 }
@@ -358,6 +445,7 @@ impl<O: OffsetSizeTrait> From<bumpalo::collections::Vec<'_, geo::MultiLineString
             coord_capacity,
             ring_capacity,
             geom_capacity,
+            Dimension::XY,
         )
     }
 }
@@ -373,6 +461,7 @@ impl<O: OffsetSizeTrait> From<bumpalo::collections::Vec<'_, Option<geo::MultiLin
             coord_capacity,
             ring_capacity,
             geom_capacity,
+            Dimension::XY,
         )
     }
 }
@@ -392,11 +481,14 @@ impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for MutableMultiLineStringArray<O>
             .collect();
         let (coord_capacity, ring_capacity, geom_capacity) =
             first_pass(wkb_objects2.iter().map(|item| item.as_ref()), value.len());
+        // Keep the z values if any geometry has them
+        let dim = Dimension::widest(wkb_objects2.iter().flatten().map(|geom| geom.dim()));
         Ok(second_pass(
             wkb_objects2.iter().map(|item| item.as_ref()),
             coord_capacity,
             ring_capacity,
             geom_capacity,
+            dim,
         ))
     }
 }
@@ -1,17 +1,20 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use super::MutableMultiPointArray;
+use crate::algorithm::native::bounding_rect::total_bounds_coords;
 use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::mutable_offset::OffsetsBuilder;
-use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
 use crate::array::zip_validity::ZipValidity;
-use crate::array::{CoordBuffer, CoordType, LineStringArray, PointArray, WKBArray};
+use crate::array::{ArrayMetadata, CoordBuffer, CoordType, LineStringArray, PointArray, WKBArray};
+use crate::buffer::owned_slice_offsets;
+use crate::buffer::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
 use crate::datatypes::GeoDataType;
 use crate::error::{GeoArrowError, Result};
+use crate::geo_traits::MultiPointTrait;
 use crate::scalar::MultiPoint;
 use crate::trait_::GeoArrayAccessor;
-use crate::util::{owned_slice_offsets, owned_slice_validity};
+use crate::util::{offsets_memory_size, owned_slice_validity, validity_memory_size};
 use crate::GeometryArrayTrait;
 use arrow_array::{Array, GenericListArray, LargeListArray, ListArray, OffsetSizeTrait};
 use arrow_buffer::bit_iterator::BitIterator;
@@ -27,6 +30,9 @@ pub struct MultiPointArray<O: OffsetSizeTrait> {
     // Always GeoDataType::MultiPoint or GeoDataType::LargeMultiPoint
     data_type: GeoDataType,
 
+    /// Array-level metadata, such as how to interpret edges
+    metadata: ArrayMetadata,
+
     pub coords: CoordBuffer,
 
     /// Offsets into the coordinate array where each geometry starts
@@ -91,19 +97,85 @@ impl<O: OffsetSizeTrait> MultiPointArray<O> {
         validity: Option<NullBuffer>,
     ) -> Result<Self> {
         check(&coords, validity.as_ref().map(|v| v.len()), &geom_offsets)?;
+        // Safety: the invariants were checked above
+        Ok(unsafe { Self::new_unchecked(coords, geom_offsets, validity) })
+    }
 
+    /// Create a new MultiPointArray from parts, without validating them
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(1)`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    ///
+    /// - if the validity is not `None`, its length equals the number of geometries, i.e. one
+    ///   less than the length of `geom_offsets`
+    /// - the last geometry offset equals the number of coordinates, so that every geometry
+    ///   offset indexes into `coords`
+    ///
+    /// Geometry offsets past the end of the coordinates make other Arrow implementations, such as
+    /// consumers of the C Data Interface, read out of bounds when they access the points of a
+    /// multi point. Within this crate, they lead to panics.
+    pub unsafe fn new_unchecked(
+        coords: CoordBuffer,
+        geom_offsets: OffsetBuffer<O>,
+        validity: Option<NullBuffer>,
+    ) -> Self {
         let coord_type = coords.coord_type();
+        let dim = coords.dim();
         let data_type = match O::IS_LARGE {
-            true => GeoDataType::LargeMultiPoint(coord_type),
-            false => GeoDataType::MultiPoint(coord_type),
+            true => GeoDataType::LargeMultiPoint(coord_type, dim),
+            false => GeoDataType::MultiPoint(coord_type, dim),
         };
 
-        Ok(Self {
+        Self {
             data_type,
+            metadata: Default::default(),
             coords,
             geom_offsets,
             validity,
-        })
+        }
+    }
+
+    /// Replace the [`ArrayMetadata`] of this array.
+    pub fn with_metadata(self, metadata: ArrayMetadata) -> Self {
+        Self { metadata, ..self }
+    }
+
+    /// Remove coordinates that exactly repeat an earlier coordinate of the same multipoint.
+    ///
+    /// The first occurrence of each coordinate is kept, in order. Points are only compared within
+    /// one geometry, never across geometries. Null geometries stay null.
+    pub fn dedup_points(&self) -> Self {
+        let mut output_array =
+            MutableMultiPointArray::with_capacities(self.coords.len(), self.len());
+        let mut seen = HashSet::new();
+        for geom_idx in 0..self.len() {
+            if self.is_null(geom_idx) {
+                output_array.push_null();
+                continue;
+            }
+
+            seen.clear();
+            let (start_idx, end_idx) = self.geom_offsets.start_end(geom_idx);
+            for coord_idx in start_idx..end_idx {
+                let (x, y) = (self.coords.get_x(coord_idx), self.coords.get_y(coord_idx));
+                // Adding zero turns -0.0 into 0.0, so that both have the same bits
+                if seen.insert(((x + 0.).to_bits(), (y + 0.).to_bits())) {
+                    // Safety: the geometry's length is pushed below
+                    unsafe { output_array.push_xy(x, y) }.unwrap();
+                }
+            }
+            output_array.try_push_valid().unwrap();
+        }
+
+        let output_array: Self = output_array.into();
+        output_array
+            .into_coord_type(self.coords.coord_type())
+            .with_metadata(self.metadata.clone())
     }
 
     fn vertices_field(&self) -> Arc<Field> {
@@ -137,6 +209,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiPointArray<O> {
             "ARROW:extension:name".to_string(),
             self.extension_name().to_string(),
         );
+        self.metadata.write_to_field_metadata(&mut metadata);
         Arc::new(Field::new("geometry", self.storage_type(), true).with_metadata(metadata))
     }
 
@@ -144,6 +217,10 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiPointArray<O> {
         "geoarrow.multipoint"
     }
 
+    fn metadata(&self) -> &ArrayMetadata {
+        &self.metadata
+    }
+
     fn into_array_ref(self) -> Arc<dyn Array> {
         let vertices_field = self.vertices_field();
         let validity = self.validity;
@@ -158,7 +235,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiPointArray<O> {
 
     fn with_coords(self, coords: CoordBuffer) -> Self {
         assert_eq!(coords.len(), self.coords.len());
-        Self::new(coords, self.geom_offsets, self.validity)
+        Self::new(coords, self.geom_offsets, self.validity).with_metadata(self.metadata)
     }
 
     fn coord_type(&self) -> CoordType {
@@ -171,6 +248,25 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiPointArray<O> {
             self.geom_offsets,
             self.validity,
         )
+        .with_metadata(self.metadata)
+    }
+
+    fn into_coord_float32(self) -> Self {
+        Self::new(
+            self.coords.into_coord_float32(),
+            self.geom_offsets,
+            self.validity,
+        )
+        .with_metadata(self.metadata)
+    }
+
+    fn into_coord_float64(self) -> Self {
+        Self::new(
+            self.coords.into_coord_float64(),
+            self.geom_offsets,
+            self.validity,
+        )
+        .with_metadata(self.metadata)
     }
 
     /// Returns the number of geometries in this array
@@ -180,12 +276,24 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiPointArray<O> {
         self.geom_offsets.len() - 1
     }
 
+    fn memory_size(&self) -> usize {
+        self.coords.memory_size()
+            + offsets_memory_size(&self.geom_offsets)
+            + validity_memory_size(self.validity.as_ref())
+    }
+
     /// Returns the optional validity.
     #[inline]
     fn validity(&self) -> Option<&NullBuffer> {
         self.validity.as_ref()
     }
 
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        total_bounds_coords(&self.coords, self.validity(), self.len(), |geom_idx| {
+            self.geom_offsets.start_end(geom_idx)
+        })
+    }
+
     /// Slices this [`MultiPointArray`] in place.
     /// # Implementation
     /// This operation is `O(1)` as it amounts to increase two ref counts.
@@ -212,6 +320,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiPointArray<O> {
         // would be in the wrong location.
         Self {
             data_type: self.data_type.clone(),
+            metadata: self.metadata.clone(),
             coords: self.coords.clone(),
             geom_offsets: self.geom_offsets.slice(offset, length),
             validity: self.validity.as_ref().map(|v| v.slice(offset, length)),
@@ -237,7 +346,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiPointArray<O> {
 
         let validity = owned_slice_validity(self.nulls(), offset, length);
 
-        Self::new(coords, geom_offsets, validity)
+        Self::new(coords, geom_offsets, validity).with_metadata(self.metadata.clone())
     }
 }
 
@@ -251,6 +360,16 @@ impl<'a, O: OffsetSizeTrait> GeoArrayAccessor<'a> for MultiPointArray<O> {
 }
 // Implement geometry accessors
 impl<O: OffsetSizeTrait> MultiPointArray<O> {
+    /// Iterator over the coordinates of all geometries in this array, in order, as
+    /// [`geo::Coord`]s
+    ///
+    /// Null geometries are stored with empty offsets, so they have no coordinates to yield.
+    pub fn geo_coords_iter(&self) -> impl Iterator<Item = geo::Coord> + '_ {
+        let start = self.geom_offsets[0].to_usize().unwrap();
+        let end = self.geom_offsets.last().to_usize().unwrap();
+        (start..end).map(|i| self.coords.value(i).into())
+    }
+
     /// Iterator over geo Geometry objects, not looking at validity
     pub fn iter_geo_values(&self) -> impl Iterator<Item = geo::MultiPoint> + '_ {
         (0..self.len()).map(|i| self.value_as_geo(i))
@@ -357,6 +476,16 @@ impl<O: OffsetSizeTrait> From<Vec<Option<geo::MultiPoint>>> for MultiPointArray<
     }
 }
 
+impl<'a, O: OffsetSizeTrait, G: MultiPointTrait<'a, T = f64>> FromIterator<Option<G>>
+    for MultiPointArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        iter.into_iter()
+            .collect::<MutableMultiPointArray<O>>()
+            .into()
+    }
+}
+
 impl<O: OffsetSizeTrait> From<Vec<geo::MultiPoint>> for MultiPointArray<O> {
     fn from(other: Vec<geo::MultiPoint>) -> Self {
         let mut_arr: MutableMultiPointArray<O> = other.into();
@@ -393,9 +522,13 @@ impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for MultiPointArray<O> {
 
 /// LineString and MultiPoint have the same layout, so enable conversions between the two to change
 /// the semantic type
+///
+/// This is a reinterpretation of the same buffers, not a geometric operation: each multipoint's
+/// points become the vertices of a line string, in the same order. Coordinates, offsets, validity
+/// and metadata are reused without copying.
 impl<O: OffsetSizeTrait> From<MultiPointArray<O>> for LineStringArray<O> {
     fn from(value: MultiPointArray<O>) -> Self {
-        Self::new(value.coords, value.geom_offsets, value.validity)
+        Self::new(value.coords, value.geom_offsets, value.validity).with_metadata(value.metadata)
     }
 }
 
@@ -510,6 +643,24 @@ mod test {
         assert_eq!(sliced.get_as_geo(0), Some(mp1()));
     }
 
+    #[test]
+    fn dedup_points() {
+        let repeated =
+            geo::MultiPoint::from(vec![(0., 1.), (2., 3.), (0., 1.), (-0., 1.), (4., 5.)]);
+        let arr: MultiPointArray<i32> = vec![Some(repeated), None, Some(mp0())].into();
+        let deduped = arr.dedup_points();
+
+        assert_eq!(deduped.len(), 3);
+        assert_eq!(
+            deduped.get_as_geo(0),
+            Some(geo::MultiPoint::from(vec![(0., 1.), (2., 3.), (4., 5.)]))
+        );
+        assert!(deduped.is_null(1));
+        // Points are not deduplicated across geometries
+        assert_eq!(deduped.get_as_geo(2), Some(mp0()));
+        assert_eq!(deduped.coords.len(), 3 + mp0().0.len());
+    }
+
     #[test]
     fn parse_wkb_geoarrow_interleaved_example() {
         let geom_arr = example_multipoint_interleaved();
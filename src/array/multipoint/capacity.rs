@@ -0,0 +1,15 @@
+/// The number of coordinates and geometries a [`MutableMultiPointArray`] can hold, or should
+/// reserve room for.
+///
+/// [`MutableMultiPointArray`]: super::MutableMultiPointArray
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MultiPointCapacity {
+    pub coord: usize,
+    pub geom: usize,
+}
+
+impl MultiPointCapacity {
+    pub fn new(coord: usize, geom: usize) -> Self {
+        Self { coord, geom }
+    }
+}
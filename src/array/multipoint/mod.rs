@@ -2,9 +2,11 @@
 //! geometries.
 
 pub use array::MultiPointArray;
+pub use capacity::MultiPointCapacity;
 pub use iterator::MultiPointArrayIter;
 pub use mutable::MutableMultiPointArray;
 
 mod array;
+mod capacity;
 pub mod iterator;
 mod mutable;
@@ -3,8 +3,8 @@ use std::sync::Arc;
 // use super::array::check;
 use crate::array::mutable_offset::OffsetsBuilder;
 use crate::array::{
-    MultiPointArray, MutableCoordBuffer, MutableInterleavedCoordBuffer, MutableLineStringArray,
-    WKBArray,
+    CoordType, Dimension, MultiPointArray, MultiPointCapacity, MutableCoordBuffer,
+    MutableLineStringArray, WKBArray,
 };
 use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::{MultiPointTrait, PointTrait};
@@ -32,41 +32,63 @@ impl<'a, O: OffsetSizeTrait> MutableMultiPointArray<O> {
         Self::with_capacities(0, 0)
     }
 
-    /// Creates a new [`MutableMultiPointArray`] with a capacity.
+    /// Creates a new [`MutableMultiPointArray`] with the given capacities and no validity.
     pub fn with_capacities(coord_capacity: usize, geom_capacity: usize) -> Self {
-        let coords = MutableInterleavedCoordBuffer::with_capacity(coord_capacity);
+        Self::with_capacity(MultiPointCapacity::new(coord_capacity, geom_capacity))
+    }
+
+    /// Creates a new [`MutableMultiPointArray`] with the given capacity and no validity.
+    pub fn with_capacity(capacity: MultiPointCapacity) -> Self {
+        Self::with_capacity_and_coord_type(capacity, CoordType::Interleaved)
+    }
+
+    /// Creates a new [`MutableMultiPointArray`] with the given capacity and no validity, storing coordinates
+    /// in the given layout.
+    pub fn with_capacity_and_coord_type(
+        capacity: MultiPointCapacity,
+        coord_type: CoordType,
+    ) -> Self {
+        Self::with_capacity_coord_type_and_dim(capacity, coord_type, Dimension::XY)
+    }
+
+    /// Creates a new [`MutableMultiPointArray`] with the given capacity and no validity, storing coordinates
+    /// of the given dimension in the given layout.
+    pub fn with_capacity_coord_type_and_dim(
+        capacity: MultiPointCapacity,
+        coord_type: CoordType,
+        dim: Dimension,
+    ) -> Self {
         Self {
-            coords: MutableCoordBuffer::Interleaved(coords),
-            geom_offsets: OffsetsBuilder::with_capacity(geom_capacity),
-            validity: NullBufferBuilder::new(geom_capacity),
+            coords: MutableCoordBuffer::with_capacity_and_dim(capacity.coord, coord_type, dim),
+            geom_offsets: OffsetsBuilder::with_capacity(capacity.geom),
+            validity: NullBufferBuilder::new(capacity.geom),
         }
     }
 
-    /// Reserves capacity for at least `additional` more MultiPoints to be inserted
-    /// in the given `Vec<T>`. The collection may reserve more space to
-    /// speculatively avoid frequent reallocations. After calling `reserve`,
-    /// capacity will be greater than or equal to `self.len() + additional`.
-    /// Does nothing if capacity is already sufficient.
-    pub fn reserve(&mut self, coord_additional: usize, geom_additional: usize) {
-        self.coords.reserve(coord_additional);
-        self.geom_offsets.reserve(geom_additional);
+    /// Reserves capacity for at least `additional` more MultiPoints and their parts. Like
+    /// [`Vec::reserve`], this may over-allocate, so that repeated small reservations grow the
+    /// buffers geometrically. Does nothing if capacity is already sufficient.
+    pub fn reserve(&mut self, additional: MultiPointCapacity) {
+        self.coords.reserve(additional.coord);
+        self.geom_offsets.reserve(additional.geom);
     }
 
-    /// Reserves the minimum capacity for at least `additional` more MultiPoints to
-    /// be inserted in the given `Vec<T>`. Unlike [`reserve`], this will not
-    /// deliberately over-allocate to speculatively avoid frequent allocations.
-    /// After calling `reserve_exact`, capacity will be greater than or equal to
-    /// `self.len() + additional`. Does nothing if the capacity is already
-    /// sufficient.
-    ///
-    /// Note that the allocator may give the collection more space than it
-    /// requests. Therefore, capacity can not be relied upon to be precisely
-    /// minimal. Prefer [`reserve`] if future insertions are expected.
+    /// Reserves the minimum capacity for at least `additional` more MultiPoints and their parts.
+    /// Unlike [`reserve`], this will not deliberately over-allocate, so calling it once per
+    /// geometry reallocates on every call. Prefer [`reserve`] if future insertions are expected.
     ///
-    /// [`reserve`]: Vec::reserve
-    pub fn reserve_exact(&mut self, coord_additional: usize, geom_additional: usize) {
-        self.coords.reserve_exact(coord_additional);
-        self.geom_offsets.reserve(geom_additional);
+    /// [`reserve`]: Self::reserve
+    pub fn reserve_exact(&mut self, additional: MultiPointCapacity) {
+        self.coords.reserve_exact(additional.coord);
+        self.geom_offsets.reserve_exact(additional.geom);
+    }
+
+    /// The number of coordinates and parts this array can hold without reallocating.
+    pub fn capacity(&self) -> MultiPointCapacity {
+        MultiPointCapacity {
+            coord: self.coords.capacity(),
+            geom: self.geom_offsets.capacity(),
+        }
     }
 
     /// The canonical method to create a [`MutableMultiPointArray`] out of its internal components.
@@ -115,7 +137,12 @@ impl<'a, O: OffsetSizeTrait> MutableMultiPointArray<O> {
     /// This function errors iff the new last item is larger than what O supports.
     pub fn push_point(&mut self, value: Option<&impl PointTrait<T = f64>>) -> Result<()> {
         if let Some(point) = value {
-            self.coords.push_xy(point.x(), point.y());
+            self.coords.push_xyzm(
+                point.x(),
+                point.y(),
+                point.z().unwrap_or(f64::NAN),
+                point.m().unwrap_or(f64::NAN),
+            );
             self.try_push_length(1)?;
         } else {
             self.push_null();
@@ -137,7 +164,12 @@ impl<'a, O: OffsetSizeTrait> MutableMultiPointArray<O> {
             let num_points = multi_point.num_points();
             for point_idx in 0..num_points {
                 let point = multi_point.point(point_idx).unwrap();
-                self.coords.push_xy(point.x(), point.y());
+                self.coords.push_xyzm(
+                    point.x(),
+                    point.y(),
+                    point.z().unwrap_or(f64::NAN),
+                    point.m().unwrap_or(f64::NAN),
+                );
             }
             self.try_push_length(num_points)?;
         } else {
@@ -146,6 +178,39 @@ impl<'a, O: OffsetSizeTrait> MutableMultiPointArray<O> {
         Ok(())
     }
 
+    /// Push each item of `geoms`, which are multi points or nulls.
+    ///
+    /// # Panics
+    ///
+    /// - if the offsets overflow `O`
+    fn extend_from_iter(
+        &mut self,
+        geoms: impl Iterator<Item = Option<impl MultiPointTrait<'a, T = f64>>>,
+    ) {
+        geoms
+            .into_iter()
+            .try_for_each(|maybe_multi_point| self.push_multi_point(maybe_multi_point.as_ref()))
+            .unwrap();
+    }
+
+    /// Creates a new [`MutableMultiPointArray`] from an iterator of multi points, reserving `capacity` up
+    /// front.
+    ///
+    /// If `capacity` covers every item, as when it was computed in a first pass over them, the
+    /// coordinates are copied once with no reallocation.
+    ///
+    /// # Panics
+    ///
+    /// - if the offsets overflow `O`
+    pub fn from_iter_with_capacity(
+        geoms: impl Iterator<Item = Option<impl MultiPointTrait<'a, T = f64>>>,
+        capacity: MultiPointCapacity,
+    ) -> Self {
+        let mut array = Self::with_capacity(capacity);
+        array.extend_from_iter(geoms);
+        array
+    }
+
     /// Push a raw coordinate to the underlying coordinate array.
     ///
     /// # Safety
@@ -157,6 +222,28 @@ impl<'a, O: OffsetSizeTrait> MutableMultiPointArray<O> {
         Ok(())
     }
 
+    /// Push a raw measured coordinate to the underlying coordinate array.
+    ///
+    /// # Safety
+    ///
+    /// This is marked as unsafe because care must be taken to ensure that pushing raw coordinates
+    /// to the array upholds the necessary invariants of the array.
+    pub unsafe fn push_xym(&mut self, x: f64, y: f64, m: f64) -> Result<()> {
+        self.coords.push_xym(x, y, m);
+        Ok(())
+    }
+
+    /// Push a raw coordinate with z and m values to the underlying coordinate array.
+    ///
+    /// # Safety
+    ///
+    /// This is marked as unsafe because care must be taken to ensure that pushing raw coordinates
+    /// to the array upholds the necessary invariants of the array.
+    pub unsafe fn push_xyzm(&mut self, x: f64, y: f64, z: f64, m: f64) -> Result<()> {
+        self.coords.push_xyzm(x, y, z, m);
+        Ok(())
+    }
+
     fn calculate_added_length(&self) -> Result<usize> {
         let total_length = self.coords.len();
         let offset = self.geom_offsets.last().to_usize().unwrap();
@@ -247,21 +334,40 @@ fn second_pass<'a, O: OffsetSizeTrait>(
     geoms: impl Iterator<Item = Option<impl MultiPointTrait<'a, T = f64> + 'a>>,
     coord_capacity: usize,
     geom_capacity: usize,
+    dim: Dimension,
 ) -> MutableMultiPointArray<O> {
-    let mut array = MutableMultiPointArray::with_capacities(coord_capacity, geom_capacity);
-
-    geoms
-        .into_iter()
-        .try_for_each(|maybe_multi_point| array.push_multi_point(maybe_multi_point.as_ref()))
-        .unwrap();
-
+    let capacity = MultiPointCapacity::new(coord_capacity, geom_capacity);
+    let mut array = MutableMultiPointArray::with_capacity_coord_type_and_dim(
+        capacity,
+        CoordType::Interleaved,
+        dim,
+    );
+    array.extend_from_iter(geoms);
     array
 }
 
+impl<'a, O: OffsetSizeTrait, G: MultiPointTrait<'a, T = f64>> FromIterator<Option<G>>
+    for MutableMultiPointArray<O>
+{
+    /// Collect multi points into a new array. Use
+    /// [`from_iter_with_capacity`](MutableMultiPointArray::from_iter_with_capacity) when the sizes are
+    /// known up front.
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend_from_iter(iter.into_iter());
+        array
+    }
+}
+
 impl<O: OffsetSizeTrait> From<Vec<geo::MultiPoint>> for MutableMultiPointArray<O> {
     fn from(geoms: Vec<geo::MultiPoint>) -> Self {
         let (coord_capacity, geom_capacity) = first_pass(geoms.iter().map(Some), geoms.len());
-        second_pass(geoms.into_iter().map(Some), coord_capacity, geom_capacity)
+        second_pass(
+            geoms.into_iter().map(Some),
+            coord_capacity,
+            geom_capacity,
+            Dimension::XY,
+        )
     }
 }
 
@@ -269,7 +375,12 @@ impl<O: OffsetSizeTrait> From<Vec<Option<geo::MultiPoint>>> for MutableMultiPoin
     fn from(geoms: Vec<Option<geo::MultiPoint>>) -> Self {
         let (coord_capacity, geom_capacity) =
             first_pass(geoms.iter().map(|x| x.as_ref()), geoms.len());
-        second_pass(geoms.into_iter(), coord_capacity, geom_capacity)
+        second_pass(
+            geoms.into_iter(),
+            coord_capacity,
+            geom_capacity,
+            Dimension::XY,
+        )
     }
 }
 
@@ -278,7 +389,12 @@ impl<O: OffsetSizeTrait> From<bumpalo::collections::Vec<'_, geo::MultiPoint>>
 {
     fn from(geoms: bumpalo::collections::Vec<'_, geo::MultiPoint>) -> Self {
         let (coord_capacity, geom_capacity) = first_pass(geoms.iter().map(Some), geoms.len());
-        second_pass(geoms.into_iter().map(Some), coord_capacity, geom_capacity)
+        second_pass(
+            geoms.into_iter().map(Some),
+            coord_capacity,
+            geom_capacity,
+            Dimension::XY,
+        )
     }
 }
 
@@ -288,7 +404,12 @@ impl<O: OffsetSizeTrait> From<bumpalo::collections::Vec<'_, Option<geo::MultiPoi
     fn from(geoms: bumpalo::collections::Vec<'_, Option<geo::MultiPoint>>) -> Self {
         let (coord_capacity, geom_capacity) =
             first_pass(geoms.iter().map(|x| x.as_ref()), geoms.len());
-        second_pass(geoms.into_iter(), coord_capacity, geom_capacity)
+        second_pass(
+            geoms.into_iter(),
+            coord_capacity,
+            geom_capacity,
+            Dimension::XY,
+        )
     }
 }
 
@@ -307,10 +428,13 @@ impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for MutableMultiPointArray<O> {
             .collect();
         let (coord_capacity, geom_capacity) =
             first_pass(wkb_objects2.iter().map(|item| item.as_ref()), value.len());
+        // Keep the z values if any geometry has them
+        let dim = Dimension::widest(wkb_objects2.iter().flatten().map(|geom| geom.dim()));
         Ok(second_pass(
             wkb_objects2.iter().map(|item| item.as_ref()),
             coord_capacity,
             geom_capacity,
+            dim,
         ))
     }
 }
@@ -1,16 +1,19 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::algorithm::native::bounding_rect::total_bounds_coords;
 use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::mutable_offset::OffsetsBuilder;
-use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
 use crate::array::zip_validity::ZipValidity;
-use crate::array::{CoordBuffer, CoordType, PolygonArray, WKBArray};
+use crate::array::{ArrayMetadata, CoordBuffer, CoordType, PolygonArray, WKBArray};
+use crate::buffer::owned_slice_offsets;
+use crate::buffer::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
+use crate::geo_traits::MultiPolygonTrait;
 use crate::scalar::MultiPolygon;
 use crate::trait_::GeoArrayAccessor;
-use crate::util::{owned_slice_offsets, owned_slice_validity};
+use crate::util::{offsets_memory_size, owned_slice_validity, validity_memory_size};
 use crate::GeometryArrayTrait;
 use arrow_array::{Array, GenericListArray, LargeListArray, ListArray, OffsetSizeTrait};
 use arrow_buffer::bit_iterator::BitIterator;
@@ -29,6 +32,9 @@ pub struct MultiPolygonArray<O: OffsetSizeTrait> {
     // Always GeoDataType::MultiPolygon or GeoDataType::LargeMultiPolygon
     data_type: GeoDataType,
 
+    /// Array-level metadata, such as how to interpret edges
+    metadata: ArrayMetadata,
+
     pub coords: CoordBuffer,
 
     /// Offsets into the polygon array where each geometry starts
@@ -133,21 +139,67 @@ impl<O: OffsetSizeTrait> MultiPolygonArray<O> {
             &ring_offsets,
             validity.as_ref().map(|v| v.len()),
         )?;
+        // Safety: the invariants were checked above
+        Ok(unsafe {
+            Self::new_unchecked(
+                coords,
+                geom_offsets,
+                polygon_offsets,
+                ring_offsets,
+                validity,
+            )
+        })
+    }
 
+    /// Create a new MultiPolygonArray from parts, without validating them
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(1)`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    ///
+    /// - if the validity is not `None`, its length equals the number of geometries, i.e. one
+    ///   less than the length of `geom_offsets`
+    /// - the last geometry offset equals the number of polygons, i.e. one less than the length of
+    ///   `polygon_offsets`
+    /// - the last polygon offset equals the number of rings, i.e. one less than the length of
+    ///   `ring_offsets`
+    /// - the last ring offset equals the number of coordinates
+    ///
+    /// Each ring is read through three levels of offsets, and other Arrow implementations, such as
+    /// consumers of the C Data Interface, don't check any of them, so offsets out of range make
+    /// them read out of bounds. Within this crate, they lead to panics.
+    pub unsafe fn new_unchecked(
+        coords: CoordBuffer,
+        geom_offsets: OffsetBuffer<O>,
+        polygon_offsets: OffsetBuffer<O>,
+        ring_offsets: OffsetBuffer<O>,
+        validity: Option<NullBuffer>,
+    ) -> Self {
         let coord_type = coords.coord_type();
+        let dim = coords.dim();
         let data_type = match O::IS_LARGE {
-            true => GeoDataType::LargeMultiPolygon(coord_type),
-            false => GeoDataType::MultiPolygon(coord_type),
+            true => GeoDataType::LargeMultiPolygon(coord_type, dim),
+            false => GeoDataType::MultiPolygon(coord_type, dim),
         };
 
-        Ok(Self {
+        Self {
             data_type,
+            metadata: Default::default(),
             coords,
             geom_offsets,
             polygon_offsets,
             ring_offsets,
             validity,
-        })
+        }
+    }
+
+    /// Replace the [`ArrayMetadata`] of this array.
+    pub fn with_metadata(self, metadata: ArrayMetadata) -> Self {
+        Self { metadata, ..self }
     }
 
     fn vertices_field(&self) -> Arc<Field> {
@@ -197,6 +249,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiPolygonArray<O> {
             "ARROW:extension:name".to_string(),
             self.extension_name().to_string(),
         );
+        self.metadata.write_to_field_metadata(&mut metadata);
         Arc::new(Field::new("geometry", self.storage_type(), true).with_metadata(metadata))
     }
 
@@ -204,6 +257,10 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiPolygonArray<O> {
         "geoarrow.multipolygon"
     }
 
+    fn metadata(&self) -> &ArrayMetadata {
+        &self.metadata
+    }
+
     fn into_array_ref(self) -> Arc<dyn Array> {
         let vertices_field = self.vertices_field();
         let rings_field = self.rings_field();
@@ -240,6 +297,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiPolygonArray<O> {
             self.ring_offsets,
             self.validity,
         )
+        .with_metadata(self.metadata)
     }
 
     fn coord_type(&self) -> CoordType {
@@ -254,6 +312,29 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiPolygonArray<O> {
             self.ring_offsets,
             self.validity,
         )
+        .with_metadata(self.metadata)
+    }
+
+    fn into_coord_float32(self) -> Self {
+        Self::new(
+            self.coords.into_coord_float32(),
+            self.geom_offsets,
+            self.polygon_offsets,
+            self.ring_offsets,
+            self.validity,
+        )
+        .with_metadata(self.metadata)
+    }
+
+    fn into_coord_float64(self) -> Self {
+        Self::new(
+            self.coords.into_coord_float64(),
+            self.geom_offsets,
+            self.polygon_offsets,
+            self.ring_offsets,
+            self.validity,
+        )
+        .with_metadata(self.metadata)
     }
 
     /// Returns the number of geometries in this array
@@ -263,12 +344,32 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiPolygonArray<O> {
         self.geom_offsets.len() - 1
     }
 
+    fn memory_size(&self) -> usize {
+        self.coords.memory_size()
+            + offsets_memory_size(&self.geom_offsets)
+            + offsets_memory_size(&self.polygon_offsets)
+            + offsets_memory_size(&self.ring_offsets)
+            + validity_memory_size(self.validity.as_ref())
+    }
+
     /// Returns the optional validity.
     #[inline]
     fn validity(&self) -> Option<&NullBuffer> {
         self.validity.as_ref()
     }
 
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        total_bounds_coords(&self.coords, self.validity(), self.len(), |geom_idx| {
+            let (start, end) = self.geom_offsets.start_end(geom_idx);
+            let polygon_start = self.polygon_offsets[start].to_usize().unwrap();
+            let polygon_end = self.polygon_offsets[end].to_usize().unwrap();
+            (
+                self.ring_offsets[polygon_start].to_usize().unwrap(),
+                self.ring_offsets[polygon_end].to_usize().unwrap(),
+            )
+        })
+    }
+
     /// Slices this [`MultiPolygonArray`] in place.
     /// # Panic
     /// This function panics iff `offset + length > self.len()`.
@@ -282,6 +383,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiPolygonArray<O> {
         // would be in the wrong location.
         Self {
             data_type: self.data_type.clone(),
+            metadata: self.metadata.clone(),
             coords: self.coords.clone(),
             geom_offsets: self.geom_offsets.slice(offset, length),
             polygon_offsets: self.polygon_offsets.clone(),
@@ -334,6 +436,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for MultiPolygonArray<O> {
             ring_offsets,
             validity,
         )
+        .with_metadata(self.metadata.clone())
     }
 }
 
@@ -354,6 +457,20 @@ impl<'a, O: OffsetSizeTrait> GeoArrayAccessor<'a> for MultiPolygonArray<O> {
 
 // Implement geometry accessors
 impl<O: OffsetSizeTrait> MultiPolygonArray<O> {
+    /// Iterator over the coordinates of all geometries in this array, in order, as
+    /// [`geo::Coord`]s
+    ///
+    /// Null geometries are stored with empty offsets, so they have no coordinates to yield.
+    pub fn geo_coords_iter(&self) -> impl Iterator<Item = geo::Coord> + '_ {
+        let start_polygon = self.geom_offsets[0].to_usize().unwrap();
+        let end_polygon = self.geom_offsets.last().to_usize().unwrap();
+        let start_ring = self.polygon_offsets[start_polygon].to_usize().unwrap();
+        let end_ring = self.polygon_offsets[end_polygon].to_usize().unwrap();
+        let start = self.ring_offsets[start_ring].to_usize().unwrap();
+        let end = self.ring_offsets[end_ring].to_usize().unwrap();
+        (start..end).map(|i| self.coords.value(i).into())
+    }
+
     /// Iterator over geo Geometry objects, not looking at validity
     pub fn iter_geo_values(&self) -> impl Iterator<Item = geo::MultiPolygon> + '_ {
         (0..self.len()).map(|i| self.value_as_geo(i))
@@ -481,6 +598,16 @@ impl<O: OffsetSizeTrait> From<Vec<Option<geo::MultiPolygon>>> for MultiPolygonAr
     }
 }
 
+impl<'a, O: OffsetSizeTrait, G: MultiPolygonTrait<'a, T = f64>> FromIterator<Option<G>>
+    for MultiPolygonArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        iter.into_iter()
+            .collect::<MutableMultiPolygonArray<O>>()
+            .into()
+    }
+}
+
 impl<O: OffsetSizeTrait> From<Vec<geo::MultiPolygon>> for MultiPolygonArray<O> {
     fn from(other: Vec<geo::MultiPolygon>) -> Self {
         let mut_arr: MutableMultiPolygonArray<O> = other.into();
@@ -0,0 +1,22 @@
+/// The number of coordinates, rings, polygons and geometries a [`MutableMultiPolygonArray`] can
+/// hold, or should reserve room for.
+///
+/// [`MutableMultiPolygonArray`]: super::MutableMultiPolygonArray
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MultiPolygonCapacity {
+    pub coord: usize,
+    pub ring: usize,
+    pub polygon: usize,
+    pub geom: usize,
+}
+
+impl MultiPolygonCapacity {
+    pub fn new(coord: usize, ring: usize, polygon: usize, geom: usize) -> Self {
+        Self {
+            coord,
+            ring,
+            polygon,
+            geom,
+        }
+    }
+}
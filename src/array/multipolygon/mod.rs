@@ -2,9 +2,11 @@
 //! geometries.
 
 pub use array::MultiPolygonArray;
+pub use capacity::MultiPolygonCapacity;
 pub use iterator::MultiPolygonArrayIter;
 pub use mutable::MutableMultiPolygonArray;
 
 mod array;
+mod capacity;
 pub mod iterator;
 mod mutable;
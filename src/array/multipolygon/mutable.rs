@@ -3,10 +3,10 @@ use std::sync::Arc;
 // use super::array::check;
 use crate::array::mutable_offset::OffsetsBuilder;
 use crate::array::{
-    MultiPolygonArray, MutableCoordBuffer, MutableInterleavedCoordBuffer, WKBArray,
+    CoordType, Dimension, MultiPolygonArray, MultiPolygonCapacity, MutableCoordBuffer, WKBArray,
 };
 use crate::error::{GeoArrowError, Result};
-use crate::geo_traits::{CoordTrait, LineStringTrait, MultiPolygonTrait, PolygonTrait};
+use crate::geo_traits::{LineStringTrait, MultiPolygonTrait, PolygonTrait};
 use crate::io::wkb::reader::maybe_multipolygon::WKBMaybeMultiPolygon;
 use crate::scalar::WKB;
 use crate::GeometryArrayTrait;
@@ -38,6 +38,9 @@ pub struct MutableMultiPolygonArray<O: OffsetSizeTrait> {
 
     /// Validity is only defined at the geometry level
     pub(crate) validity: NullBufferBuilder,
+
+    /// Whether to close rings whose last coordinate differs from their first
+    pub(crate) close_rings: bool,
 }
 
 impl<'a, O: OffsetSizeTrait> MutableMultiPolygonArray<O> {
@@ -46,64 +49,94 @@ impl<'a, O: OffsetSizeTrait> MutableMultiPolygonArray<O> {
         Self::with_capacities(0, 0, 0, 0)
     }
 
-    /// Creates a new [`MutableMultiPolygonArray`] with a capacity.
+    /// Creates a new [`MutableMultiPolygonArray`] with the given capacities and no validity.
     pub fn with_capacities(
         coord_capacity: usize,
         ring_capacity: usize,
         polygon_capacity: usize,
         geom_capacity: usize,
     ) -> Self {
-        let coords = MutableInterleavedCoordBuffer::with_capacity(coord_capacity);
+        Self::with_capacity(MultiPolygonCapacity::new(
+            coord_capacity,
+            ring_capacity,
+            polygon_capacity,
+            geom_capacity,
+        ))
+    }
+
+    /// Creates a new [`MutableMultiPolygonArray`] with the given capacity and no validity.
+    pub fn with_capacity(capacity: MultiPolygonCapacity) -> Self {
+        Self::with_capacity_and_coord_type(capacity, CoordType::Interleaved)
+    }
+
+    /// Creates a new [`MutableMultiPolygonArray`] with the given capacity and no validity, storing coordinates
+    /// in the given layout.
+    pub fn with_capacity_and_coord_type(
+        capacity: MultiPolygonCapacity,
+        coord_type: CoordType,
+    ) -> Self {
+        Self::with_capacity_coord_type_and_dim(capacity, coord_type, Dimension::XY)
+    }
+
+    /// Creates a new [`MutableMultiPolygonArray`] with the given capacity and no validity, storing coordinates
+    /// of the given dimension in the given layout.
+    pub fn with_capacity_coord_type_and_dim(
+        capacity: MultiPolygonCapacity,
+        coord_type: CoordType,
+        dim: Dimension,
+    ) -> Self {
         Self {
-            coords: MutableCoordBuffer::Interleaved(coords),
-            geom_offsets: OffsetsBuilder::with_capacity(geom_capacity),
-            polygon_offsets: OffsetsBuilder::with_capacity(polygon_capacity),
-            ring_offsets: OffsetsBuilder::with_capacity(ring_capacity),
-            validity: NullBufferBuilder::new(geom_capacity),
+            coords: MutableCoordBuffer::with_capacity_and_dim(capacity.coord, coord_type, dim),
+            ring_offsets: OffsetsBuilder::with_capacity(capacity.ring),
+            polygon_offsets: OffsetsBuilder::with_capacity(capacity.polygon),
+            geom_offsets: OffsetsBuilder::with_capacity(capacity.geom),
+            validity: NullBufferBuilder::new(capacity.geom),
+            close_rings: false,
         }
     }
 
-    /// Reserves capacity for at least `additional` more LineStrings to be inserted
-    /// in the given `Vec<T>`. The collection may reserve more space to
-    /// speculatively avoid frequent reallocations. After calling `reserve`,
-    /// capacity will be greater than or equal to `self.len() + additional`.
-    /// Does nothing if capacity is already sufficient.
-    pub fn reserve(
-        &mut self,
-        coord_additional: usize,
-        ring_additional: usize,
-        polygon_additional: usize,
-        geom_additional: usize,
-    ) {
-        self.coords.reserve(coord_additional);
-        self.ring_offsets.reserve(ring_additional);
-        self.polygon_offsets.reserve(polygon_additional);
-        self.geom_offsets.reserve(geom_additional);
+    /// Set whether to close unclosed rings by appending their first coordinate.
+    ///
+    /// This applies to rings added with [`push_polygon`](Self::push_polygon),
+    /// [`push_multi_polygon`](Self::push_multi_polygon) and when reading through geozero. Off by
+    /// default, so that rings are stored as given.
+    pub fn with_close_rings(self, close_rings: bool) -> Self {
+        Self {
+            close_rings,
+            ..self
+        }
     }
 
-    /// Reserves the minimum capacity for at least `additional` more LineStrings to
-    /// be inserted in the given `Vec<T>`. Unlike [`reserve`], this will not
-    /// deliberately over-allocate to speculatively avoid frequent allocations.
-    /// After calling `reserve_exact`, capacity will be greater than or equal to
-    /// `self.len() + additional`. Does nothing if the capacity is already
-    /// sufficient.
-    ///
-    /// Note that the allocator may give the collection more space than it
-    /// requests. Therefore, capacity can not be relied upon to be precisely
-    /// minimal. Prefer [`reserve`] if future insertions are expected.
+    /// Reserves capacity for at least `additional` more MultiPolygons and their parts. Like
+    /// [`Vec::reserve`], this may over-allocate, so that repeated small reservations grow the
+    /// buffers geometrically. Does nothing if capacity is already sufficient.
+    pub fn reserve(&mut self, additional: MultiPolygonCapacity) {
+        self.coords.reserve(additional.coord);
+        self.ring_offsets.reserve(additional.ring);
+        self.polygon_offsets.reserve(additional.polygon);
+        self.geom_offsets.reserve(additional.geom);
+    }
+
+    /// Reserves the minimum capacity for at least `additional` more MultiPolygons and their parts.
+    /// Unlike [`reserve`], this will not deliberately over-allocate, so calling it once per
+    /// geometry reallocates on every call. Prefer [`reserve`] if future insertions are expected.
     ///
-    /// [`reserve`]: Vec::reserve
-    pub fn reserve_exact(
-        &mut self,
-        coord_additional: usize,
-        ring_additional: usize,
-        polygon_additional: usize,
-        geom_additional: usize,
-    ) {
-        self.coords.reserve_exact(coord_additional);
-        self.ring_offsets.reserve(ring_additional);
-        self.polygon_offsets.reserve(polygon_additional);
-        self.geom_offsets.reserve(geom_additional);
+    /// [`reserve`]: Self::reserve
+    pub fn reserve_exact(&mut self, additional: MultiPolygonCapacity) {
+        self.coords.reserve_exact(additional.coord);
+        self.ring_offsets.reserve_exact(additional.ring);
+        self.polygon_offsets.reserve_exact(additional.polygon);
+        self.geom_offsets.reserve_exact(additional.geom);
+    }
+
+    /// The number of coordinates and parts this array can hold without reallocating.
+    pub fn capacity(&self) -> MultiPolygonCapacity {
+        MultiPolygonCapacity {
+            coord: self.coords.capacity(),
+            ring: self.ring_offsets.capacity(),
+            polygon: self.polygon_offsets.capacity(),
+            geom: self.geom_offsets.capacity(),
+        }
     }
 
     /// The canonical method to create a [`MutableMultiPolygonArray`] out of its internal
@@ -126,20 +159,80 @@ impl<'a, O: OffsetSizeTrait> MutableMultiPolygonArray<O> {
         ring_offsets: OffsetsBuilder<O>,
         validity: NullBufferBuilder,
     ) -> Result<Self> {
-        // check(
-        //     &coords.clone().into(),
-        //     &geom_offsets.clone().into(),
-        //     &polygon_offsets.clone().into(),
-        //     &ring_offsets.clone().into(),
-        //     validity.as_ref().map(|x| x.len()),
-        // )?;
-        Ok(Self {
+        let array = Self {
             coords,
             geom_offsets,
             polygon_offsets,
             ring_offsets,
             validity,
-        })
+            close_rings: false,
+        };
+        array.check()?;
+        Ok(array)
+    }
+
+    /// Check that the three levels of offsets, the coordinates and the validity agree with each
+    /// other.
+    ///
+    /// Each level's last offset must equal the number of items in the level below it: the last
+    /// geometry offset is the number of polygons, the last polygon offset the number of rings,
+    /// and the last ring offset the number of coordinates.
+    ///
+    /// # Errors
+    ///
+    /// - if any level's last offset doesn't match the length of the level below it
+    /// - if the validity doesn't have one value per geometry
+    pub fn check(&self) -> Result<()> {
+        let levels = [
+            (
+                "geometry",
+                self.geom_offsets.last(),
+                "polygons",
+                self.polygon_offsets.len_proxy(),
+            ),
+            (
+                "polygon",
+                self.polygon_offsets.last(),
+                "rings",
+                self.ring_offsets.len_proxy(),
+            ),
+            (
+                "ring",
+                self.ring_offsets.last(),
+                "coordinates",
+                self.coords.len(),
+            ),
+        ];
+        for (level, last_offset, child_name, child_len) in levels {
+            let last_offset = last_offset.to_usize().unwrap();
+            if last_offset != child_len {
+                return Err(GeoArrowError::General(format!(
+                    "The last {} offset is {}, but there are {} {}",
+                    level, last_offset, child_len, child_name
+                )));
+            }
+        }
+
+        if self.validity.len() != self.geom_offsets.len_proxy() {
+            return Err(GeoArrowError::General(format!(
+                "The validity has {} values, but there are {} geometries",
+                self.validity.len(),
+                self.geom_offsets.len_proxy()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Convert into a [`MultiPolygonArray`], after checking that the offsets agree with each
+    /// other.
+    ///
+    /// # Errors
+    ///
+    /// - if [`check`](Self::check) fails
+    pub fn finish(self) -> Result<MultiPolygonArray<O>> {
+        self.check()?;
+        Ok(self.into())
     }
 
     /// Extract the low-level APIs from the [`MutableMultiPolygonArray`].
@@ -173,35 +266,9 @@ impl<'a, O: OffsetSizeTrait> MutableMultiPolygonArray<O> {
 
             // Total number of polygons in this MultiPolygon
             let num_polygons = 1;
-            self.geom_offsets.try_push_usize(num_polygons).unwrap();
-
-            let ext_ring = polygon.exterior().unwrap();
-            for coord_idx in 0..ext_ring.num_coords() {
-                let coord = ext_ring.coord(coord_idx).unwrap();
-                self.coords.push_xy(coord.x(), coord.y());
-            }
-
-            // Total number of rings in this Multipolygon
-            self.polygon_offsets
-                .try_push_usize(polygon.num_interiors() + 1)
-                .unwrap();
-
-            // Number of coords for each ring
-            self.ring_offsets
-                .try_push_usize(ext_ring.num_coords())
-                .unwrap();
+            unsafe { self.try_push_geom_offset(num_polygons)? }
 
-            for int_ring_idx in 0..polygon.num_interiors() {
-                let int_ring = polygon.interior(int_ring_idx).unwrap();
-                self.ring_offsets
-                    .try_push_usize(int_ring.num_coords())
-                    .unwrap();
-
-                for coord_idx in 0..int_ring.num_coords() {
-                    let coord = int_ring.coord(coord_idx).unwrap();
-                    self.coords.push_xy(coord.x(), coord.y());
-                }
-            }
+            self.push_polygon_rings(polygon)?;
         } else {
             self.push_null();
         };
@@ -225,36 +292,7 @@ impl<'a, O: OffsetSizeTrait> MutableMultiPolygonArray<O> {
             // Iterate over polygons
             for polygon_idx in 0..num_polygons {
                 let polygon = multi_polygon.polygon(polygon_idx).unwrap();
-
-                // Here we unwrap the exterior ring because a polygon inside a multi polygon should
-                // never be empty.
-                let ext_ring = polygon.exterior().unwrap();
-                for coord_idx in 0..ext_ring.num_coords() {
-                    let coord = ext_ring.coord(coord_idx).unwrap();
-                    self.coords.push_xy(coord.x(), coord.y());
-                }
-
-                // Total number of rings in this Multipolygon
-                self.polygon_offsets
-                    .try_push_usize(polygon.num_interiors() + 1)
-                    .unwrap();
-
-                // Number of coords for each ring
-                self.ring_offsets
-                    .try_push_usize(ext_ring.num_coords())
-                    .unwrap();
-
-                for int_ring_idx in 0..polygon.num_interiors() {
-                    let int_ring = polygon.interior(int_ring_idx).unwrap();
-                    self.ring_offsets
-                        .try_push_usize(int_ring.num_coords())
-                        .unwrap();
-
-                    for coord_idx in 0..int_ring.num_coords() {
-                        let coord = int_ring.coord(coord_idx).unwrap();
-                        self.coords.push_xy(coord.x(), coord.y());
-                    }
-                }
+                self.push_polygon_rings(&polygon)?;
             }
         } else {
             self.push_null();
@@ -262,6 +300,53 @@ impl<'a, O: OffsetSizeTrait> MutableMultiPolygonArray<O> {
         Ok(())
     }
 
+    /// Push the rings of one polygon of a multi polygon, and its polygon offset.
+    fn push_polygon_rings(&mut self, polygon: &impl PolygonTrait<'a, T = f64>) -> Result<()> {
+        // Total number of rings in this polygon
+        self.polygon_offsets
+            .try_push_usize(polygon.num_interiors() + 1)?;
+
+        // Here we unwrap the exterior ring because a polygon inside a multi polygon should
+        // never be empty.
+        self.push_ring(&polygon.exterior().unwrap())?;
+        for int_ring_idx in 0..polygon.num_interiors() {
+            self.push_ring(&polygon.interior(int_ring_idx).unwrap())?;
+        }
+        Ok(())
+    }
+
+    /// Push a ring's coordinates and its ring offset.
+    fn push_ring(&mut self, ring: &impl LineStringTrait<'a, T = f64>) -> Result<()> {
+        let num_coords = ring.num_coords();
+        self.ring_offsets.try_push_usize(num_coords)?;
+        for coord_idx in 0..num_coords {
+            let coord = ring.coord(coord_idx).unwrap();
+            self.coords.push_coord(coord);
+        }
+        if self.close_rings {
+            self.close_last_ring()?;
+        }
+        Ok(())
+    }
+
+    /// Append the first coordinate of the last ring to its end, if the ring isn't closed.
+    pub(crate) fn close_last_ring(&mut self) -> Result<()> {
+        let (start, end) = self
+            .ring_offsets
+            .start_end(self.ring_offsets.len_proxy() - 1);
+        if start == end || self.coords.coord(start) == self.coords.coord(end - 1) {
+            return Ok(());
+        }
+
+        let first = self.coords.coord(start);
+        let first_z = self.coords.get_z(start).unwrap_or(f64::NAN);
+        let first_m = self.coords.get_m(start).unwrap_or(f64::NAN);
+        self.coords.push_xyzm(first.x, first.y, first_z, first_m);
+        self.ring_offsets.pop();
+        self.ring_offsets.try_push_usize(end - start + 1)?;
+        Ok(())
+    }
+
     /// Push a raw offset to the underlying geometry offsets buffer.
     ///
     /// # Safety
@@ -296,6 +381,41 @@ impl<'a, O: OffsetSizeTrait> MutableMultiPolygonArray<O> {
         Ok(())
     }
 
+    /// Push each item of `geoms`, which are multi polygons or nulls.
+    ///
+    /// # Panics
+    ///
+    /// - if the offsets overflow `O`
+    fn extend_from_iter(
+        &mut self,
+        geoms: impl Iterator<Item = Option<impl MultiPolygonTrait<'a, T = f64>>>,
+    ) {
+        geoms
+            .into_iter()
+            .try_for_each(|maybe_multi_polygon| {
+                self.push_multi_polygon(maybe_multi_polygon.as_ref())
+            })
+            .unwrap();
+    }
+
+    /// Creates a new [`MutableMultiPolygonArray`] from an iterator of multi polygons, reserving `capacity` up
+    /// front.
+    ///
+    /// If `capacity` covers every item, as when it was computed in a first pass over them, the
+    /// coordinates are copied once with no reallocation.
+    ///
+    /// # Panics
+    ///
+    /// - if the offsets overflow `O`
+    pub fn from_iter_with_capacity(
+        geoms: impl Iterator<Item = Option<impl MultiPolygonTrait<'a, T = f64>>>,
+        capacity: MultiPolygonCapacity,
+    ) -> Self {
+        let mut array = Self::with_capacity(capacity);
+        array.extend_from_iter(geoms);
+        array
+    }
+
     /// Push a raw coordinate to the underlying coordinate array.
     ///
     /// # Safety
@@ -307,6 +427,28 @@ impl<'a, O: OffsetSizeTrait> MutableMultiPolygonArray<O> {
         Ok(())
     }
 
+    /// Push a raw measured coordinate to the underlying coordinate array.
+    ///
+    /// # Safety
+    ///
+    /// This is marked as unsafe because care must be taken to ensure that pushing raw coordinates
+    /// to the array upholds the necessary invariants of the array.
+    pub unsafe fn push_xym(&mut self, x: f64, y: f64, m: f64) -> Result<()> {
+        self.coords.push_xym(x, y, m);
+        Ok(())
+    }
+
+    /// Push a raw coordinate with z and m values to the underlying coordinate array.
+    ///
+    /// # Safety
+    ///
+    /// This is marked as unsafe because care must be taken to ensure that pushing raw coordinates
+    /// to the array upholds the necessary invariants of the array.
+    pub unsafe fn push_xyzm(&mut self, x: f64, y: f64, z: f64, m: f64) -> Result<()> {
+        self.coords.push_xyzm(x, y, z, m);
+        Ok(())
+    }
+
     #[inline]
     pub(crate) fn push_empty(&mut self) {
         self.geom_offsets.try_push_usize(0).unwrap();
@@ -393,22 +535,36 @@ fn second_pass<'a, O: OffsetSizeTrait>(
     ring_capacity: usize,
     polygon_capacity: usize,
     geom_capacity: usize,
+    dim: Dimension,
 ) -> MutableMultiPolygonArray<O> {
-    let mut array = MutableMultiPolygonArray::with_capacities(
+    let capacity = MultiPolygonCapacity::new(
         coord_capacity,
         ring_capacity,
         polygon_capacity,
         geom_capacity,
     );
-
-    geoms
-        .into_iter()
-        .try_for_each(|maybe_multi_polygon| array.push_multi_polygon(maybe_multi_polygon.as_ref()))
-        .unwrap();
-
+    let mut array = MutableMultiPolygonArray::with_capacity_coord_type_and_dim(
+        capacity,
+        CoordType::Interleaved,
+        dim,
+    );
+    array.extend_from_iter(geoms);
     array
 }
 
+impl<'a, O: OffsetSizeTrait, G: MultiPolygonTrait<'a, T = f64>> FromIterator<Option<G>>
+    for MutableMultiPolygonArray<O>
+{
+    /// Collect multi polygons into a new array. Use
+    /// [`from_iter_with_capacity`](MutableMultiPolygonArray::from_iter_with_capacity) when the sizes are
+    /// known up front.
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend_from_iter(iter.into_iter());
+        array
+    }
+}
+
 impl<O: OffsetSizeTrait> From<Vec<geo::MultiPolygon>> for MutableMultiPolygonArray<O> {
     fn from(geoms: Vec<geo::MultiPolygon>) -> Self {
         let (coord_capacity, ring_capacity, polygon_capacity, geom_capacity) =
@@ -419,6 +575,7 @@ impl<O: OffsetSizeTrait> From<Vec<geo::MultiPolygon>> for MutableMultiPolygonArr
             ring_capacity,
             polygon_capacity,
             geom_capacity,
+            Dimension::XY,
         )
     }
 }
@@ -433,6 +590,7 @@ impl<O: OffsetSizeTrait> From<Vec<Option<geo::MultiPolygon>>> for MutableMultiPo
             ring_capacity,
             polygon_capacity,
             geom_capacity,
+            Dimension::XY,
         )
     }
 }
@@ -449,6 +607,7 @@ impl<O: OffsetSizeTrait> From<bumpalo::collections::Vec<'_, geo::MultiPolygon>>
             ring_capacity,
             polygon_capacity,
             geom_capacity,
+            Dimension::XY,
         )
     }
 }
@@ -465,6 +624,7 @@ impl<O: OffsetSizeTrait> From<bumpalo::collections::Vec<'_, Option<geo::MultiPol
             ring_capacity,
             polygon_capacity,
             geom_capacity,
+            Dimension::XY,
         )
     }
 }
@@ -484,12 +644,72 @@ impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for MutableMultiPolygonArray<O> {
             .collect();
         let (coord_capacity, ring_capacity, polygon_capacity, geom_capacity) =
             first_pass(wkb_objects2.iter().map(|item| item.as_ref()), value.len());
+        // Keep the z values if any geometry has them
+        let dim = Dimension::widest(wkb_objects2.iter().flatten().map(|geom| geom.dim()));
         Ok(second_pass(
             wkb_objects2.iter().map(|item| item.as_ref()),
             coord_capacity,
             ring_capacity,
             polygon_capacity,
             geom_capacity,
+            dim,
         ))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::multipolygon::{mp0, mp1};
+    use crate::test::polygon::p0;
+    use crate::trait_::GeoArrayAccessor;
+    use crate::GeometryArrayTrait;
+
+    #[test]
+    fn finish_checks_offsets() {
+        let mut builder = MutableMultiPolygonArray::<i32>::new();
+        builder.push_multi_polygon(Some(&mp0())).unwrap();
+        builder.push_multi_polygon(Some(&mp1())).unwrap();
+        assert!(builder.check().is_ok());
+
+        // A geometry that claims a polygon which was never pushed
+        builder.geom_offsets.try_push_usize(1).unwrap();
+        builder.validity.append_non_null();
+        let err = builder.finish().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("The last geometry offset is 5, but there are 4 polygons"));
+    }
+
+    #[test]
+    fn try_new_checks_offsets() {
+        let builder = MutableMultiPolygonArray::<i32>::new();
+        let (coords, geom_offsets, polygon_offsets, mut ring_offsets, validity) =
+            builder.into_inner();
+        ring_offsets.try_push_usize(3).unwrap();
+        assert!(MutableMultiPolygonArray::try_new(
+            coords,
+            geom_offsets,
+            polygon_offsets,
+            ring_offsets,
+            validity
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn push_polygon_records_validity() {
+        let mut builder = MutableMultiPolygonArray::<i32>::new();
+        builder.push_polygon(Some(&p0())).unwrap();
+        builder
+            .push_multi_polygon(None::<&geo::MultiPolygon>)
+            .unwrap();
+        builder.push_multi_polygon(Some(&mp0())).unwrap();
+
+        let arr: MultiPolygonArray<i32> = builder.into();
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr.value_as_geo(0), geo::MultiPolygon::new(vec![p0()]));
+        assert!(arr.is_null(1));
+        assert_eq!(arr.value_as_geo(2), mp0());
+    }
+}
@@ -1,19 +1,23 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::algorithm::native::bounding_rect::total_bounds_coords;
 use crate::algorithm::native::eq::coord_eq_allow_nan;
 use crate::array::zip_validity::ZipValidity;
 use crate::array::{
-    CoordBuffer, CoordType, InterleavedCoordBuffer, MutablePointArray, SeparatedCoordBuffer,
-    WKBArray,
+    ArrayMetadata, CoordBuffer, CoordType, InterleavedCoordBuffer, MutablePointArray,
+    SeparatedCoordBuffer, WKBArray,
 };
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
+use crate::geo_traits::PointTrait;
 use crate::scalar::Point;
 use crate::trait_::GeoArrayAccessor;
-use crate::util::owned_slice_validity;
+use crate::util::{owned_slice_validity, validity_memory_size};
 use crate::GeometryArrayTrait;
-use arrow_array::{Array, ArrayRef, FixedSizeListArray, OffsetSizeTrait, StructArray};
+use arrow_array::{
+    Array, ArrayRef, FixedSizeListArray, Float64Array, OffsetSizeTrait, StructArray,
+};
 use arrow_buffer::bit_iterator::BitIterator;
 use arrow_buffer::NullBuffer;
 use arrow_schema::{DataType, Field};
@@ -21,10 +25,16 @@ use arrow_schema::{DataType, Field};
 /// An immutable array of Point geometries using GeoArrow's in-memory representation.
 ///
 /// This is semantically equivalent to `Vec<Option<Point>>` due to the internal validity bitmap.
+///
+/// An empty point is a valid slot whose coordinates are all NaN, which is also how WKB encodes
+/// `POINT EMPTY`. A null slot is marked in the validity bitmap instead.
 #[derive(Debug, Clone)]
 pub struct PointArray {
     // Always GeoDataType::Point
     data_type: GeoDataType,
+
+    /// Array-level metadata, such as how to interpret edges
+    metadata: ArrayMetadata,
     pub coords: CoordBuffer,
     pub validity: Option<NullBuffer>,
 }
@@ -70,17 +80,61 @@ impl PointArray {
         validity: Option<NullBuffer>,
     ) -> Result<Self, GeoArrowError> {
         check(&coords, validity.as_ref().map(|v| v.len()))?;
-        let data_type = GeoDataType::Point(coords.coord_type());
-        Ok(Self {
+        // Safety: the invariants were checked above
+        Ok(unsafe { Self::new_unchecked(coords, validity) })
+    }
+
+    /// Create a new PointArray from parts, without validating them
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(1)`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that, if the validity is not `None`, its length equals the number
+    /// of coordinates.
+    ///
+    /// A validity shorter than the coordinates makes other Arrow implementations, such as
+    /// consumers of the C Data Interface, read past the end of its bitmap. Within this crate, it
+    /// leads to panics or to the wrong points being null.
+    pub unsafe fn new_unchecked(coords: CoordBuffer, validity: Option<NullBuffer>) -> Self {
+        let data_type = GeoDataType::Point(coords.coord_type(), coords.dim());
+        Self {
             data_type,
+            metadata: Default::default(),
             coords,
             validity,
-        })
+        }
+    }
+
+    /// Replace the [`ArrayMetadata`] of this array.
+    pub fn with_metadata(self, metadata: ArrayMetadata) -> Self {
+        Self { metadata, ..self }
     }
 
     pub fn into_inner(self) -> (CoordBuffer, Option<NullBuffer>) {
         (self.coords, self.validity)
     }
+
+    /// The z values of the points, or `None` if the points have no z values.
+    ///
+    /// Null points are null in the output. This is `O(1)` for separated coordinates, while
+    /// interleaved coordinates are copied out of their buffer.
+    pub fn z_array(&self) -> Option<Float64Array> {
+        let values = match &self.coords {
+            CoordBuffer::Separated(c) => c.z.clone()?,
+            coords => {
+                if !coords.dim().has_z() {
+                    return None;
+                }
+                (0..coords.len())
+                    .map(|i| coords.get_z(i).unwrap())
+                    .collect()
+            }
+        };
+        Some(Float64Array::new(values, self.validity.clone()))
+    }
 }
 
 impl<'a> GeometryArrayTrait<'a> for PointArray {
@@ -102,6 +156,7 @@ impl<'a> GeometryArrayTrait<'a> for PointArray {
             "ARROW:extension:name".to_string(),
             self.extension_name().to_string(),
         );
+        self.metadata.write_to_field_metadata(&mut metadata);
         Arc::new(Field::new("geometry", self.storage_type(), true).with_metadata(metadata))
     }
 
@@ -109,12 +164,16 @@ impl<'a> GeometryArrayTrait<'a> for PointArray {
         "geoarrow.point"
     }
 
+    fn metadata(&self) -> &ArrayMetadata {
+        &self.metadata
+    }
+
     fn into_array_ref(self) -> ArrayRef {
         let validity = self.validity;
         match self.coords {
             CoordBuffer::Interleaved(c) => Arc::new(FixedSizeListArray::new(
                 c.values_field().into(),
-                2,
+                c.dim().size() as i32,
                 Arc::new(c.values_array()),
                 validity,
             )),
@@ -122,12 +181,18 @@ impl<'a> GeometryArrayTrait<'a> for PointArray {
                 let fields = c.values_field();
                 Arc::new(StructArray::new(fields.into(), c.values_array(), validity))
             }
+            CoordBuffer::Float32(c) => Arc::new(FixedSizeListArray::new(
+                c.values_field().into(),
+                c.dim().size() as i32,
+                Arc::new(c.values_array()),
+                validity,
+            )),
         }
     }
 
     fn with_coords(self, coords: CoordBuffer) -> Self {
         assert_eq!(coords.len(), self.coords.len());
-        Self::new(coords, self.validity)
+        Self::new(coords, self.validity).with_metadata(self.metadata)
     }
 
     fn coord_type(&self) -> CoordType {
@@ -136,6 +201,15 @@ impl<'a> GeometryArrayTrait<'a> for PointArray {
 
     fn into_coord_type(self, coord_type: CoordType) -> Self {
         Self::new(self.coords.into_coord_type(coord_type), self.validity)
+            .with_metadata(self.metadata)
+    }
+
+    fn into_coord_float32(self) -> Self {
+        Self::new(self.coords.into_coord_float32(), self.validity).with_metadata(self.metadata)
+    }
+
+    fn into_coord_float64(self) -> Self {
+        Self::new(self.coords.into_coord_float64(), self.validity).with_metadata(self.metadata)
     }
 
     /// Returns the number of geometries in this array
@@ -144,12 +218,22 @@ impl<'a> GeometryArrayTrait<'a> for PointArray {
         self.coords.len()
     }
 
+    fn memory_size(&self) -> usize {
+        self.coords.memory_size() + validity_memory_size(self.validity.as_ref())
+    }
+
     /// Returns the optional validity.
     #[inline]
     fn validity(&self) -> Option<&NullBuffer> {
         self.validity.as_ref()
     }
 
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        total_bounds_coords(&self.coords, self.validity(), self.len(), |geom_idx| {
+            (geom_idx, geom_idx + 1)
+        })
+    }
+
     /// Slices this [`PointArray`] in place.
     /// # Panic
     /// This function panics iff `offset + length > self.len()`.
@@ -161,6 +245,7 @@ impl<'a> GeometryArrayTrait<'a> for PointArray {
         );
         Self {
             data_type: self.data_type.clone(),
+            metadata: self.metadata.clone(),
             coords: self.coords.slice(offset, length),
             validity: self.validity.as_ref().map(|v| v.slice(offset, length)),
         }
@@ -177,7 +262,7 @@ impl<'a> GeometryArrayTrait<'a> for PointArray {
 
         let validity = owned_slice_validity(self.nulls(), offset, length);
 
-        Self::new(coords, validity)
+        Self::new(coords, validity).with_metadata(self.metadata.clone())
     }
 }
 
@@ -192,6 +277,18 @@ impl<'a> GeoArrayAccessor<'a> for PointArray {
 
 // Implement geometry accessors
 impl PointArray {
+    /// Iterator over the coordinates of all geometries in this array, in order, as
+    /// [`geo::Coord`]s
+    ///
+    /// Null points are skipped. A null point still has a slot in the coordinate buffer, holding
+    /// an arbitrary value, whereas null geometries in the other arrays have no coordinates at all,
+    /// so this yields the same coordinates as the other arrays would for the same geometries.
+    pub fn geo_coords_iter(&self) -> impl Iterator<Item = geo::Coord> + '_ {
+        (0..self.len())
+            .filter(|i| self.is_valid(*i))
+            .map(|i| self.coords.value(i).into())
+    }
+
     /// Iterator over geo Geometry objects, not looking at validity
     pub fn iter_geo_values(&self) -> impl Iterator<Item = geo::Point> + '_ {
         (0..self.len()).map(|i| self.value_as_geo(i))
@@ -288,6 +385,12 @@ impl From<Vec<Option<geo::Point>>> for PointArray {
     }
 }
 
+impl<G: PointTrait<T = f64>> FromIterator<Option<G>> for PointArray {
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        iter.into_iter().collect::<MutablePointArray>().into()
+    }
+}
+
 impl From<Vec<geo::Point>> for PointArray {
     fn from(other: Vec<geo::Point>) -> Self {
         let mut_arr: MutablePointArray = other.into();
@@ -357,6 +460,7 @@ impl PartialEq for PointArray {
 
 #[cfg(test)]
 mod test {
+    use crate::array::Dimension;
     use crate::test::geoarrow_data::{
         example_point_interleaved, example_point_separated, example_point_wkb,
     };
@@ -402,6 +506,39 @@ mod test {
         assert_eq!(sliced.get_as_geo(0), Some(p1()));
     }
 
+    #[test]
+    fn z_array() {
+        let coords = InterleavedCoordBuffer::new_with_dim(
+            vec![0., 1., 10., 2., 3., 20., 4., 5., 30.].into(),
+            Dimension::XYZ,
+        );
+        let validity = NullBuffer::from(vec![true, false, true]);
+        let arr = PointArray::new(CoordBuffer::Interleaved(coords), Some(validity));
+
+        let z = arr.z_array().unwrap();
+        assert_eq!(z.value(0), 10.);
+        assert!(z.is_null(1));
+        assert_eq!(z.value(2), 30.);
+
+        let separated = arr.clone().into_coord_type(CoordType::Separated);
+        assert_eq!(separated.z_array().unwrap(), z);
+
+        // The z values survive a round trip through Arrow
+        let round_trip: PointArray = arr.into_array_ref().as_ref().try_into().unwrap();
+        assert_eq!(round_trip.z_array().unwrap(), z);
+
+        let xy: PointArray = vec![p0(), p1()].into();
+        assert!(xy.z_array().is_none());
+        assert!(xy.into_coord_type(CoordType::Separated).z_array().is_none());
+    }
+
+    #[test]
+    fn geo_coords_iter_skips_nulls() {
+        let arr: PointArray = vec![Some(p0()), None, Some(p1())].into();
+        let coords: Vec<geo::Coord> = arr.geo_coords_iter().collect();
+        assert_eq!(coords, vec![p0().0, p1().0]);
+    }
+
     #[ignore = "point file is invalid (https://github.com/geoarrow/geoarrow-data/issues/2)"]
     #[test]
     fn parse_wkb_geoarrow_interleaved_example() {
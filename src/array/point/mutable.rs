@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 // use super::array::check;
-use crate::array::{MutableCoordBuffer, MutableInterleavedCoordBuffer, PointArray, WKBArray};
+use crate::array::{CoordType, Dimension, MutableCoordBuffer, PointArray, WKBArray};
 use crate::error::GeoArrowError;
 use crate::geo_traits::PointTrait;
 use crate::io::wkb::reader::point::WKBPoint;
@@ -28,13 +28,39 @@ impl MutablePointArray {
 
     /// Creates a new [`MutablePointArray`] with a capacity.
     pub fn with_capacity(capacity: usize) -> Self {
-        let coords = MutableInterleavedCoordBuffer::with_capacity(capacity);
+        Self::with_capacity_and_coord_type(capacity, CoordType::Interleaved)
+    }
+
+    /// Creates a new [`MutablePointArray`] with a capacity, storing coordinates in the given
+    /// layout.
+    pub fn with_capacity_and_coord_type(capacity: usize, coord_type: CoordType) -> Self {
+        Self::with_capacity_coord_type_and_dim(capacity, coord_type, Dimension::XY)
+    }
+
+    /// Creates a new [`MutablePointArray`] with the given capacity and no validity, storing coordinates
+    /// of the given dimension in the given layout.
+    pub fn with_capacity_coord_type_and_dim(
+        capacity: usize,
+        coord_type: CoordType,
+        dim: Dimension,
+    ) -> Self {
         Self {
-            coords: MutableCoordBuffer::Interleaved(coords),
+            coords: MutableCoordBuffer::with_capacity_and_dim(capacity, coord_type, dim),
             validity: NullBufferBuilder::new(capacity),
         }
     }
 
+    /// Creates a new [`MutablePointArray`] from an iterator of points, reserving room for
+    /// `capacity` points up front.
+    pub fn from_iter_with_capacity(
+        geoms: impl Iterator<Item = Option<impl PointTrait<T = f64>>>,
+        capacity: usize,
+    ) -> Self {
+        let mut array = Self::with_capacity(capacity);
+        geoms.for_each(|maybe_point| array.push_point(maybe_point.as_ref()));
+        array
+    }
+
     /// Reserves capacity for at least `additional` more points to be inserted
     /// in the given `Vec<T>`. The collection may reserve more space to
     /// speculatively avoid frequent reallocations. After calling `reserve`,
@@ -60,6 +86,11 @@ impl MutablePointArray {
         self.coords.reserve_exact(additional);
     }
 
+    /// The number of points this array can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.coords.capacity()
+    }
+
     /// The canonical method to create a [`MutablePointArray`] out of its internal components.
     ///
     /// # Implementation
@@ -88,7 +119,12 @@ impl MutablePointArray {
     #[inline]
     pub fn push_point(&mut self, value: Option<&impl PointTrait<T = f64>>) {
         if let Some(value) = value {
-            self.coords.push_xy(value.x(), value.y());
+            self.coords.push_xyzm(
+                value.x(),
+                value.y(),
+                value.z().unwrap_or(f64::NAN),
+                value.m().unwrap_or(f64::NAN),
+            );
             self.validity.append(true);
         } else {
             self.coords.push_xy(0., 0.);
@@ -160,6 +196,16 @@ impl From<MutablePointArray> for Arc<dyn Array> {
     }
 }
 
+impl<G: PointTrait<T = f64>> FromIterator<Option<G>> for MutablePointArray {
+    /// Collect points into a new array, reserving room for the lower bound of the iterator's
+    /// size hint.
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let capacity = iter.size_hint().0;
+        Self::from_iter_with_capacity(iter, capacity)
+    }
+}
+
 fn from_coords(
     geoms: impl Iterator<Item = impl PointTrait<T = f64>>,
     geoms_length: usize,
@@ -174,8 +220,13 @@ fn from_coords(
 pub(crate) fn from_nullable_coords(
     geoms: impl Iterator<Item = Option<impl PointTrait<T = f64>>>,
     geoms_length: usize,
+    dim: Dimension,
 ) -> MutablePointArray {
-    let mut mutable_array = MutablePointArray::with_capacity(geoms_length);
+    let mut mutable_array = MutablePointArray::with_capacity_coord_type_and_dim(
+        geoms_length,
+        CoordType::Interleaved,
+        dim,
+    );
     geoms
         .into_iter()
         .for_each(|maybe_point| mutable_array.push_point(maybe_point.as_ref()));
@@ -192,7 +243,7 @@ impl From<Vec<Point>> for MutablePointArray {
 impl From<Vec<Option<Point>>> for MutablePointArray {
     fn from(geoms: Vec<Option<Point>>) -> Self {
         let geoms_length = geoms.len();
-        from_nullable_coords(geoms.into_iter(), geoms_length)
+        from_nullable_coords(geoms.into_iter(), geoms_length, Dimension::XY)
     }
 }
 
@@ -206,7 +257,7 @@ impl From<bumpalo::collections::Vec<'_, Point>> for MutablePointArray {
 impl From<bumpalo::collections::Vec<'_, Option<Point>>> for MutablePointArray {
     fn from(geoms: bumpalo::collections::Vec<'_, Option<Point>>) -> Self {
         let geoms_length = geoms.len();
-        from_nullable_coords(geoms.into_iter(), geoms_length)
+        from_nullable_coords(geoms.into_iter(), geoms_length, Dimension::XY)
     }
 }
 
@@ -225,9 +276,12 @@ impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for MutablePointArray {
             .collect();
 
         let geoms_length = wkb_objects2.len();
+        // Keep the z values if any geometry has them
+        let dim = Dimension::widest(wkb_objects2.iter().flatten().map(|geom| geom.dim()));
         Ok(from_nullable_coords(
             wkb_objects2.iter().map(|item| item.as_ref()),
             geoms_length,
+            dim,
         ))
     }
 }
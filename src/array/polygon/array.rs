@@ -1,15 +1,18 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::algorithm::native::bounding_rect::total_bounds_coords;
 use crate::algorithm::native::eq::offset_buffer_eq;
-use crate::array::util::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
 use crate::array::zip_validity::ZipValidity;
-use crate::array::{CoordBuffer, CoordType, MultiLineStringArray, WKBArray};
+use crate::array::{ArrayMetadata, CoordBuffer, CoordType, MultiLineStringArray, WKBArray};
+use crate::buffer::owned_slice_offsets;
+use crate::buffer::{offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, OffsetBufferUtils};
 use crate::datatypes::GeoDataType;
 use crate::error::GeoArrowError;
+use crate::geo_traits::PolygonTrait;
 use crate::scalar::Polygon;
 use crate::trait_::GeoArrayAccessor;
-use crate::util::{owned_slice_offsets, owned_slice_validity};
+use crate::util::{offsets_memory_size, owned_slice_validity, validity_memory_size};
 use crate::GeometryArrayTrait;
 use arrow_array::{Array, OffsetSizeTrait};
 use arrow_array::{GenericListArray, LargeListArray, ListArray};
@@ -28,6 +31,9 @@ pub struct PolygonArray<O: OffsetSizeTrait> {
     // Always GeoDataType::Polygon or GeoDataType::LargePolygon
     data_type: GeoDataType,
 
+    /// Array-level metadata, such as how to interpret edges
+    metadata: ArrayMetadata,
+
     pub coords: CoordBuffer,
 
     /// Offsets into the ring array where each geometry starts
@@ -111,20 +117,56 @@ impl<O: OffsetSizeTrait> PolygonArray<O> {
             &ring_offsets,
             validity.as_ref().map(|v| v.len()),
         )?;
+        // Safety: the invariants were checked above
+        Ok(unsafe { Self::new_unchecked(coords, geom_offsets, ring_offsets, validity) })
+    }
 
+    /// Create a new PolygonArray from parts, without validating them
+    ///
+    /// # Implementation
+    ///
+    /// This function is `O(1)`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that:
+    ///
+    /// - if the validity is not `None`, its length equals the number of geometries, i.e. one
+    ///   less than the length of `geom_offsets`
+    /// - the last geometry offset equals the number of rings, i.e. one less than the length of
+    ///   `ring_offsets`
+    /// - the last ring offset equals the number of coordinates
+    ///
+    /// Since a polygon's coordinates are found through its rings, a geometry offset past the end
+    /// of `ring_offsets`, or a ring offset past the end of the coordinates, makes other Arrow
+    /// implementations, such as consumers of the C Data Interface, read out of bounds. Within this
+    /// crate, it leads to panics.
+    pub unsafe fn new_unchecked(
+        coords: CoordBuffer,
+        geom_offsets: OffsetBuffer<O>,
+        ring_offsets: OffsetBuffer<O>,
+        validity: Option<NullBuffer>,
+    ) -> Self {
         let coord_type = coords.coord_type();
+        let dim = coords.dim();
         let data_type = match O::IS_LARGE {
-            true => GeoDataType::LargePolygon(coord_type),
-            false => GeoDataType::Polygon(coord_type),
+            true => GeoDataType::LargePolygon(coord_type, dim),
+            false => GeoDataType::Polygon(coord_type, dim),
         };
 
-        Ok(Self {
+        Self {
             data_type,
+            metadata: Default::default(),
             coords,
             geom_offsets,
             ring_offsets,
             validity,
-        })
+        }
+    }
+
+    /// Replace the [`ArrayMetadata`] of this array.
+    pub fn with_metadata(self, metadata: ArrayMetadata) -> Self {
+        Self { metadata, ..self }
     }
 
     fn vertices_field(&self) -> Arc<Field> {
@@ -166,6 +208,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for PolygonArray<O> {
             "ARROW:extension:name".to_string(),
             self.extension_name().to_string(),
         );
+        self.metadata.write_to_field_metadata(&mut metadata);
         Arc::new(Field::new("geometry", self.storage_type(), true).with_metadata(metadata))
     }
 
@@ -173,6 +216,10 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for PolygonArray<O> {
         "geoarrow.polygon"
     }
 
+    fn metadata(&self) -> &ArrayMetadata {
+        &self.metadata
+    }
+
     fn into_array_ref(self) -> Arc<dyn Array> {
         let vertices_field = self.vertices_field();
         let rings_field = self.rings_field();
@@ -195,6 +242,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for PolygonArray<O> {
     fn with_coords(self, coords: CoordBuffer) -> Self {
         assert_eq!(coords.len(), self.coords.len());
         Self::new(coords, self.geom_offsets, self.ring_offsets, self.validity)
+            .with_metadata(self.metadata)
     }
 
     fn coord_type(&self) -> CoordType {
@@ -208,6 +256,27 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for PolygonArray<O> {
             self.ring_offsets,
             self.validity,
         )
+        .with_metadata(self.metadata)
+    }
+
+    fn into_coord_float32(self) -> Self {
+        Self::new(
+            self.coords.into_coord_float32(),
+            self.geom_offsets,
+            self.ring_offsets,
+            self.validity,
+        )
+        .with_metadata(self.metadata)
+    }
+
+    fn into_coord_float64(self) -> Self {
+        Self::new(
+            self.coords.into_coord_float64(),
+            self.geom_offsets,
+            self.ring_offsets,
+            self.validity,
+        )
+        .with_metadata(self.metadata)
     }
 
     /// Returns the number of geometries in this array
@@ -217,12 +286,29 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for PolygonArray<O> {
         self.geom_offsets.len() - 1
     }
 
+    fn memory_size(&self) -> usize {
+        self.coords.memory_size()
+            + offsets_memory_size(&self.geom_offsets)
+            + offsets_memory_size(&self.ring_offsets)
+            + validity_memory_size(self.validity.as_ref())
+    }
+
     /// Returns the optional validity.
     #[inline]
     fn validity(&self) -> Option<&NullBuffer> {
         self.validity.as_ref()
     }
 
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        total_bounds_coords(&self.coords, self.validity(), self.len(), |geom_idx| {
+            let (start, end) = self.geom_offsets.start_end(geom_idx);
+            (
+                self.ring_offsets[start].to_usize().unwrap(),
+                self.ring_offsets[end].to_usize().unwrap(),
+            )
+        })
+    }
+
     /// Slices this [`PolygonArray`] in place.
     /// # Panic
     /// This function panics iff `offset + length > self.len()`.
@@ -236,6 +322,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for PolygonArray<O> {
         // Otherwise the offsets would be in the wrong location.
         Self {
             data_type: self.data_type.clone(),
+            metadata: self.metadata.clone(),
             coords: self.coords.clone(),
             geom_offsets: self.geom_offsets.slice(offset, length),
             ring_offsets: self.ring_offsets.clone(),
@@ -271,7 +358,7 @@ impl<'a, O: OffsetSizeTrait> GeometryArrayTrait<'a> for PolygonArray<O> {
 
         let validity = owned_slice_validity(self.nulls(), offset, length);
 
-        Self::new(coords, geom_offsets, ring_offsets, validity)
+        Self::new(coords, geom_offsets, ring_offsets, validity).with_metadata(self.metadata.clone())
     }
 }
 
@@ -286,6 +373,18 @@ impl<'a, O: OffsetSizeTrait> GeoArrayAccessor<'a> for PolygonArray<O> {
 
 // Implement geometry accessors
 impl<O: OffsetSizeTrait> PolygonArray<O> {
+    /// Iterator over the coordinates of all geometries in this array, in order, as
+    /// [`geo::Coord`]s
+    ///
+    /// Null geometries are stored with empty offsets, so they have no coordinates to yield.
+    pub fn geo_coords_iter(&self) -> impl Iterator<Item = geo::Coord> + '_ {
+        let start_ring = self.geom_offsets[0].to_usize().unwrap();
+        let end_ring = self.geom_offsets.last().to_usize().unwrap();
+        let start = self.ring_offsets[start_ring].to_usize().unwrap();
+        let end = self.ring_offsets[end_ring].to_usize().unwrap();
+        (start..end).map(|i| self.coords.value(i).into())
+    }
+
     /// Iterator over geo Geometry objects, not looking at validity
     pub fn iter_geo_values(&self) -> impl Iterator<Item = geo::Polygon> + '_ {
         (0..self.len()).map(|i| self.value_as_geo(i))
@@ -404,6 +503,14 @@ impl<O: OffsetSizeTrait> From<Vec<Option<geo::Polygon>>> for PolygonArray<O> {
     }
 }
 
+impl<'a, O: OffsetSizeTrait, G: PolygonTrait<'a, T = f64>> FromIterator<Option<G>>
+    for PolygonArray<O>
+{
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        iter.into_iter().collect::<MutablePolygonArray<O>>().into()
+    }
+}
+
 impl<O: OffsetSizeTrait> From<Vec<geo::Polygon>> for PolygonArray<O> {
     fn from(other: Vec<geo::Polygon>) -> Self {
         let mut_arr: MutablePolygonArray<O> = other.into();
@@ -504,6 +611,7 @@ impl<O: OffsetSizeTrait> PartialEq for PolygonArray<O> {
 
 #[cfg(test)]
 mod test {
+    use crate::array::InterleavedCoordBuffer;
     use crate::test::geoarrow_data::{
         example_polygon_interleaved, example_polygon_separated, example_polygon_wkb,
     };
@@ -526,6 +634,29 @@ mod test {
         assert_eq!(arr.get_as_geo(2), None);
     }
 
+    #[test]
+    fn push_polygon_close_rings() {
+        // A triangle whose ring is missing its closing coordinate
+        let coords = InterleavedCoordBuffer::new(vec![0., 0., 4., 0., 4., 4.].into());
+        let unclosed: PolygonArray<i32> = PolygonArray::new(
+            CoordBuffer::Interleaved(coords),
+            OffsetBuffer::new(vec![0, 1].into()),
+            OffsetBuffer::new(vec![0, 3].into()),
+            None,
+        );
+
+        let mut builder = MutablePolygonArray::<i32>::new().with_close_rings(true);
+        builder.push_polygon(Some(&unclosed.value(0))).unwrap();
+        builder.push_polygon(Some(&p0())).unwrap();
+        let closed: PolygonArray<i32> = builder.into();
+
+        // Converting to geo objects would close the rings anyway, so check the stored offsets
+        assert_eq!(closed.ring_offsets.as_ref(), &[0, 4, 9]);
+        assert_eq!(closed.coords.get_x(3), 0.);
+        assert_eq!(closed.coords.get_y(3), 0.);
+        assert_eq!(closed.value_as_geo(1), p0());
+    }
+
     #[test]
     fn slice() {
         let arr: PolygonArray<i64> = vec![p0(), p1()].into();
@@ -0,0 +1,16 @@
+/// The number of coordinates, rings and geometries a [`MutablePolygonArray`] can hold, or should
+/// reserve room for.
+///
+/// [`MutablePolygonArray`]: super::MutablePolygonArray
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PolygonCapacity {
+    pub coord: usize,
+    pub ring: usize,
+    pub geom: usize,
+}
+
+impl PolygonCapacity {
+    pub fn new(coord: usize, ring: usize, geom: usize) -> Self {
+        Self { coord, ring, geom }
+    }
+}
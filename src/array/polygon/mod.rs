@@ -1,11 +1,13 @@
 //! Contains the [`PolygonArray`] and [`MutablePolygonArray`] for arrays of Polygon geometries.
 
 pub use array::PolygonArray;
+pub use capacity::PolygonCapacity;
 pub use iterator::PolygonArrayIter;
 pub use mutable::MutablePolygonArray;
 pub(crate) use util::parse_polygon;
 
 mod array;
+mod capacity;
 pub(crate) mod iterator;
 mod mutable;
 pub(crate) mod util;
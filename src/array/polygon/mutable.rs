@@ -3,11 +3,11 @@ use std::sync::Arc;
 // use super::array::check;
 use crate::array::mutable_offset::OffsetsBuilder;
 use crate::array::{
-    MutableCoordBuffer, MutableInterleavedCoordBuffer, MutableMultiLineStringArray, PolygonArray,
-    WKBArray,
+    CoordType, Dimension, MutableCoordBuffer, MutableMultiLineStringArray, PolygonArray,
+    PolygonCapacity, WKBArray,
 };
 use crate::error::{GeoArrowError, Result};
-use crate::geo_traits::{CoordTrait, LineStringTrait, PolygonTrait};
+use crate::geo_traits::{LineStringTrait, PolygonTrait};
 use crate::io::wkb::reader::polygon::WKBPolygon;
 use crate::scalar::WKB;
 use crate::trait_::GeometryArrayTrait;
@@ -35,6 +35,9 @@ pub struct MutablePolygonArray<O: OffsetSizeTrait> {
 
     /// Validity is only defined at the geometry level
     pub(crate) validity: NullBufferBuilder,
+
+    /// Whether to close rings whose last coordinate differs from their first
+    pub(crate) close_rings: bool,
 }
 
 impl<'a, O: OffsetSizeTrait> MutablePolygonArray<O> {
@@ -43,58 +46,84 @@ impl<'a, O: OffsetSizeTrait> MutablePolygonArray<O> {
         Self::with_capacities(0, 0, 0)
     }
 
-    /// Creates a new [`MutablePolygonArray`] with given capacities and no validity.
+    /// Creates a new [`MutablePolygonArray`] with the given capacities and no validity.
     pub fn with_capacities(
         coord_capacity: usize,
         ring_capacity: usize,
         geom_capacity: usize,
     ) -> Self {
-        let coords = MutableInterleavedCoordBuffer::with_capacity(coord_capacity);
+        Self::with_capacity(PolygonCapacity::new(
+            coord_capacity,
+            ring_capacity,
+            geom_capacity,
+        ))
+    }
+
+    /// Creates a new [`MutablePolygonArray`] with the given capacity and no validity.
+    pub fn with_capacity(capacity: PolygonCapacity) -> Self {
+        Self::with_capacity_and_coord_type(capacity, CoordType::Interleaved)
+    }
+
+    /// Creates a new [`MutablePolygonArray`] with the given capacity and no validity, storing coordinates
+    /// in the given layout.
+    pub fn with_capacity_and_coord_type(capacity: PolygonCapacity, coord_type: CoordType) -> Self {
+        Self::with_capacity_coord_type_and_dim(capacity, coord_type, Dimension::XY)
+    }
+
+    /// Creates a new [`MutablePolygonArray`] with the given capacity and no validity, storing coordinates
+    /// of the given dimension in the given layout.
+    pub fn with_capacity_coord_type_and_dim(
+        capacity: PolygonCapacity,
+        coord_type: CoordType,
+        dim: Dimension,
+    ) -> Self {
         Self {
-            coords: MutableCoordBuffer::Interleaved(coords),
-            geom_offsets: OffsetsBuilder::with_capacity(geom_capacity),
-            ring_offsets: OffsetsBuilder::with_capacity(ring_capacity),
-            validity: NullBufferBuilder::new(geom_capacity),
+            coords: MutableCoordBuffer::with_capacity_and_dim(capacity.coord, coord_type, dim),
+            ring_offsets: OffsetsBuilder::with_capacity(capacity.ring),
+            geom_offsets: OffsetsBuilder::with_capacity(capacity.geom),
+            validity: NullBufferBuilder::new(capacity.geom),
+            close_rings: false,
         }
     }
 
-    /// Reserves capacity for at least `additional` more LineStrings to be inserted
-    /// in the given `Vec<T>`. The collection may reserve more space to
-    /// speculatively avoid frequent reallocations. After calling `reserve`,
-    /// capacity will be greater than or equal to `self.len() + additional`.
-    /// Does nothing if capacity is already sufficient.
-    pub fn reserve(
-        &mut self,
-        coord_additional: usize,
-        ring_additional: usize,
-        geom_additional: usize,
-    ) {
-        self.coords.reserve(coord_additional);
-        self.ring_offsets.reserve(ring_additional);
-        self.geom_offsets.reserve(geom_additional);
+    /// Set whether to close unclosed rings by appending their first coordinate.
+    ///
+    /// This applies to rings added with [`push_polygon`](Self::push_polygon) and when
+    /// reading through geozero. Off by default, so that rings are stored as given.
+    pub fn with_close_rings(self, close_rings: bool) -> Self {
+        Self {
+            close_rings,
+            ..self
+        }
     }
 
-    /// Reserves the minimum capacity for at least `additional` more LineStrings to
-    /// be inserted in the given `Vec<T>`. Unlike [`reserve`], this will not
-    /// deliberately over-allocate to speculatively avoid frequent allocations.
-    /// After calling `reserve_exact`, capacity will be greater than or equal to
-    /// `self.len() + additional`. Does nothing if the capacity is already
-    /// sufficient.
-    ///
-    /// Note that the allocator may give the collection more space than it
-    /// requests. Therefore, capacity can not be relied upon to be precisely
-    /// minimal. Prefer [`reserve`] if future insertions are expected.
+    /// Reserves capacity for at least `additional` more Polygons and their parts. Like
+    /// [`Vec::reserve`], this may over-allocate, so that repeated small reservations grow the
+    /// buffers geometrically. Does nothing if capacity is already sufficient.
+    pub fn reserve(&mut self, additional: PolygonCapacity) {
+        self.coords.reserve(additional.coord);
+        self.ring_offsets.reserve(additional.ring);
+        self.geom_offsets.reserve(additional.geom);
+    }
+
+    /// Reserves the minimum capacity for at least `additional` more Polygons and their parts.
+    /// Unlike [`reserve`], this will not deliberately over-allocate, so calling it once per
+    /// geometry reallocates on every call. Prefer [`reserve`] if future insertions are expected.
     ///
-    /// [`reserve`]: Vec::reserve
-    pub fn reserve_exact(
-        &mut self,
-        coord_additional: usize,
-        ring_additional: usize,
-        geom_additional: usize,
-    ) {
-        self.coords.reserve_exact(coord_additional);
-        self.ring_offsets.reserve(ring_additional);
-        self.geom_offsets.reserve(geom_additional);
+    /// [`reserve`]: Self::reserve
+    pub fn reserve_exact(&mut self, additional: PolygonCapacity) {
+        self.coords.reserve_exact(additional.coord);
+        self.ring_offsets.reserve_exact(additional.ring);
+        self.geom_offsets.reserve_exact(additional.geom);
+    }
+
+    /// The number of coordinates and parts this array can hold without reallocating.
+    pub fn capacity(&self) -> PolygonCapacity {
+        PolygonCapacity {
+            coord: self.coords.capacity(),
+            ring: self.ring_offsets.capacity(),
+            geom: self.geom_offsets.capacity(),
+        }
     }
 
     /// The canonical method to create a [`MutablePolygonArray`] out of its internal components.
@@ -125,6 +154,7 @@ impl<'a, O: OffsetSizeTrait> MutablePolygonArray<O> {
             geom_offsets,
             ring_offsets,
             validity,
+            close_rings: false,
         })
     }
 
@@ -156,33 +186,14 @@ impl<'a, O: OffsetSizeTrait> MutablePolygonArray<O> {
                 return Ok(());
             }
 
-            // - Get exterior ring
-            // - Add exterior ring's # of coords self.ring_offsets
-            // - Push ring's coords to self.coords
-            let ext_ring = polygon.exterior().unwrap();
-            let ext_ring_num_coords = ext_ring.num_coords();
-            self.ring_offsets.try_push_usize(ext_ring_num_coords)?;
-            for coord_idx in 0..ext_ring_num_coords {
-                let coord = ext_ring.coord(coord_idx).unwrap();
-                self.coords.push_xy(coord.x(), coord.y());
-            }
+            self.push_ring(&polygon.exterior().unwrap())?;
 
             // Total number of rings in this polygon
             let num_interiors = polygon.num_interiors();
             self.geom_offsets.try_push_usize(num_interiors + 1)?;
 
-            // For each interior ring:
-            // - Get ring
-            // - Add ring's # of coords to self.ring_offsets
-            // - Push ring's coords to self.coords
             for int_ring_idx in 0..num_interiors {
-                let int_ring = polygon.interior(int_ring_idx).unwrap();
-                let int_ring_num_coords = int_ring.num_coords();
-                self.ring_offsets.try_push_usize(int_ring_num_coords)?;
-                for coord_idx in 0..int_ring_num_coords {
-                    let coord = int_ring.coord(coord_idx).unwrap();
-                    self.coords.push_xy(coord.x(), coord.y());
-                }
+                self.push_ring(&polygon.interior(int_ring_idx).unwrap())?;
             }
 
             self.validity.append(true);
@@ -192,6 +203,38 @@ impl<'a, O: OffsetSizeTrait> MutablePolygonArray<O> {
         Ok(())
     }
 
+    /// Push a ring's coordinates and its ring offset.
+    fn push_ring(&mut self, ring: &impl LineStringTrait<'a, T = f64>) -> Result<()> {
+        let num_coords = ring.num_coords();
+        self.ring_offsets.try_push_usize(num_coords)?;
+        for coord_idx in 0..num_coords {
+            let coord = ring.coord(coord_idx).unwrap();
+            self.coords.push_coord(coord);
+        }
+        if self.close_rings {
+            self.close_last_ring()?;
+        }
+        Ok(())
+    }
+
+    /// Append the first coordinate of the last ring to its end, if the ring isn't closed.
+    pub(crate) fn close_last_ring(&mut self) -> Result<()> {
+        let (start, end) = self
+            .ring_offsets
+            .start_end(self.ring_offsets.len_proxy() - 1);
+        if start == end || self.coords.coord(start) == self.coords.coord(end - 1) {
+            return Ok(());
+        }
+
+        let first = self.coords.coord(start);
+        let first_z = self.coords.get_z(start).unwrap_or(f64::NAN);
+        let first_m = self.coords.get_m(start).unwrap_or(f64::NAN);
+        self.coords.push_xyzm(first.x, first.y, first_z, first_m);
+        self.ring_offsets.pop();
+        self.ring_offsets.try_push_usize(end - start + 1)?;
+        Ok(())
+    }
+
     /// Push a raw offset to the underlying geometry offsets buffer.
     ///
     /// # Safety
@@ -215,6 +258,39 @@ impl<'a, O: OffsetSizeTrait> MutablePolygonArray<O> {
         Ok(())
     }
 
+    /// Push each item of `geoms`, which are polygons or nulls.
+    ///
+    /// # Panics
+    ///
+    /// - if the offsets overflow `O`
+    fn extend_from_iter(
+        &mut self,
+        geoms: impl Iterator<Item = Option<impl PolygonTrait<'a, T = f64>>>,
+    ) {
+        geoms
+            .into_iter()
+            .try_for_each(|maybe_polygon| self.push_polygon(maybe_polygon.as_ref()))
+            .unwrap();
+    }
+
+    /// Creates a new [`MutablePolygonArray`] from an iterator of polygons, reserving `capacity` up
+    /// front.
+    ///
+    /// If `capacity` covers every item, as when it was computed in a first pass over them, the
+    /// coordinates are copied once with no reallocation.
+    ///
+    /// # Panics
+    ///
+    /// - if the offsets overflow `O`
+    pub fn from_iter_with_capacity(
+        geoms: impl Iterator<Item = Option<impl PolygonTrait<'a, T = f64>>>,
+        capacity: PolygonCapacity,
+    ) -> Self {
+        let mut array = Self::with_capacity(capacity);
+        array.extend_from_iter(geoms);
+        array
+    }
+
     /// Push a raw coordinate to the underlying coordinate array.
     ///
     /// # Safety
@@ -226,6 +302,28 @@ impl<'a, O: OffsetSizeTrait> MutablePolygonArray<O> {
         Ok(())
     }
 
+    /// Push a raw measured coordinate to the underlying coordinate array.
+    ///
+    /// # Safety
+    ///
+    /// This is marked as unsafe because care must be taken to ensure that pushing raw coordinates
+    /// to the array upholds the necessary invariants of the array.
+    pub unsafe fn push_xym(&mut self, x: f64, y: f64, m: f64) -> Result<()> {
+        self.coords.push_xym(x, y, m);
+        Ok(())
+    }
+
+    /// Push a raw coordinate with z and m values to the underlying coordinate array.
+    ///
+    /// # Safety
+    ///
+    /// This is marked as unsafe because care must be taken to ensure that pushing raw coordinates
+    /// to the array upholds the necessary invariants of the array.
+    pub unsafe fn push_xyzm(&mut self, x: f64, y: f64, z: f64, m: f64) -> Result<()> {
+        self.coords.push_xyzm(x, y, z, m);
+        Ok(())
+    }
+
     #[inline]
     pub(crate) fn push_empty(&mut self) {
         self.geom_offsets.try_push_usize(0).unwrap();
@@ -292,18 +390,31 @@ fn second_pass<'a, O: OffsetSizeTrait>(
     coord_capacity: usize,
     ring_capacity: usize,
     geom_capacity: usize,
+    dim: Dimension,
 ) -> MutablePolygonArray<O> {
-    let mut array =
-        MutablePolygonArray::with_capacities(coord_capacity, ring_capacity, geom_capacity);
-
-    geoms
-        .into_iter()
-        .try_for_each(|maybe_polygon| array.push_polygon(maybe_polygon.as_ref()))
-        .unwrap();
-
+    let capacity = PolygonCapacity::new(coord_capacity, ring_capacity, geom_capacity);
+    let mut array = MutablePolygonArray::with_capacity_coord_type_and_dim(
+        capacity,
+        CoordType::Interleaved,
+        dim,
+    );
+    array.extend_from_iter(geoms);
     array
 }
 
+impl<'a, O: OffsetSizeTrait, G: PolygonTrait<'a, T = f64>> FromIterator<Option<G>>
+    for MutablePolygonArray<O>
+{
+    /// Collect polygons into a new array. Use
+    /// [`from_iter_with_capacity`](MutablePolygonArray::from_iter_with_capacity) when the sizes are
+    /// known up front.
+    fn from_iter<I: IntoIterator<Item = Option<G>>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend_from_iter(iter.into_iter());
+        array
+    }
+}
+
 impl<O: OffsetSizeTrait> From<Vec<geo::Polygon>> for MutablePolygonArray<O> {
     fn from(geoms: Vec<geo::Polygon>) -> Self {
         let (coord_capacity, ring_capacity, geom_capacity) =
@@ -313,6 +424,7 @@ impl<O: OffsetSizeTrait> From<Vec<geo::Polygon>> for MutablePolygonArray<O> {
             coord_capacity,
             ring_capacity,
             geom_capacity,
+            Dimension::XY,
         )
     }
 }
@@ -326,6 +438,7 @@ impl<O: OffsetSizeTrait> From<Vec<Option<geo::Polygon>>> for MutablePolygonArray
             coord_capacity,
             ring_capacity,
             geom_capacity,
+            Dimension::XY,
         )
     }
 }
@@ -341,6 +454,7 @@ impl<O: OffsetSizeTrait> From<bumpalo::collections::Vec<'_, geo::Polygon>>
             coord_capacity,
             ring_capacity,
             geom_capacity,
+            Dimension::XY,
         )
     }
 }
@@ -355,6 +469,7 @@ impl<O: OffsetSizeTrait> From<bumpalo::collections::Vec<'_, Option<geo::Polygon>
             coord_capacity,
             ring_capacity,
             geom_capacity,
+            Dimension::XY,
         )
     }
 }
@@ -374,11 +489,14 @@ impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for MutablePolygonArray<O> {
             .collect();
         let (coord_capacity, ring_capacity, geom_capacity) =
             first_pass(wkb_objects2.iter().map(|item| item.as_ref()), value.len());
+        // Keep the z values if any geometry has them
+        let dim = Dimension::widest(wkb_objects2.iter().flatten().map(|geom| geom.dim()));
         Ok(second_pass(
             wkb_objects2.iter().map(|item| item.as_ref()),
             coord_capacity,
             ring_capacity,
             geom_capacity,
+            dim,
         ))
     }
 }
@@ -3,8 +3,8 @@ use std::borrow::Cow;
 use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 
-use crate::array::util::OffsetBufferUtils;
 use crate::array::CoordBuffer;
+use crate::buffer::OffsetBufferUtils;
 use crate::trait_::GeoArrayAccessor;
 
 pub(crate) fn parse_polygon<O: OffsetSizeTrait>(
@@ -16,6 +16,11 @@ pub(crate) fn parse_polygon<O: OffsetSizeTrait>(
     // Start and end indices into the ring_offsets buffer
     let (start_geom_idx, end_geom_idx) = polygon_offsets.start_end(i);
 
+    // Empty and null polygons have no rings
+    if start_geom_idx == end_geom_idx {
+        return geo::Polygon::new(geo::LineString::new(vec![]), vec![]);
+    }
+
     // Parse exterior ring first
     let (start_ext_ring_idx, end_ext_ring_idx) = ring_offsets.start_end(start_geom_idx);
     let mut exterior_coords: Vec<geo::Coord> =
@@ -1,16 +1,23 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
 use arrow_array::{Array, FixedSizeListArray, Float64Array};
+use arrow_buffer::bit_iterator::BitIterator;
 use arrow_buffer::{NullBuffer, ScalarBuffer};
 use arrow_schema::{DataType, Field};
 
+use crate::algorithm::native::bounding_rect::total_bounds_rects;
+use crate::algorithm::native::eq::{value_eq_within, EqualsExact};
 use crate::array::rect::MutableRectArray;
-use crate::array::{CoordBuffer, CoordType};
+use crate::array::zip_validity::ZipValidity;
+use crate::array::{CoordBuffer, CoordType, MutablePolygonArray, PolygonArray};
 use crate::datatypes::GeoDataType;
+use crate::error::GeoArrowError;
 use crate::scalar::Rect;
 use crate::trait_::GeoArrayAccessor;
-use crate::util::owned_slice_validity;
+use crate::util::{owned_slice_validity, validity_memory_size};
 use crate::GeometryArrayTrait;
 
 /// Internally this is implemented as a FixedSizeList[4], laid out as minx, miny, maxx, maxy.
@@ -41,6 +48,52 @@ impl RectArray {
     fn outer_type(&self) -> DataType {
         DataType::FixedSizeList(self.inner_field(), 4)
     }
+
+    /// Convert each rectangle to a polygon with a single closed, five-vertex exterior ring.
+    ///
+    /// The ring starts and ends at `(minx, miny)` and has the same vertex order as
+    /// [`geo::Rect::to_polygon`]. Null rectangles become null polygons.
+    pub fn to_polygon_array(&self) -> PolygonArray<i32> {
+        let mut output_array =
+            MutablePolygonArray::with_capacities(self.len() * 5, self.len(), self.len());
+
+        for geom_idx in 0..self.len() {
+            if self.is_null(geom_idx) {
+                output_array.push_null();
+                continue;
+            }
+
+            let minx = self.values[geom_idx * 4];
+            let miny = self.values[geom_idx * 4 + 1];
+            let maxx = self.values[geom_idx * 4 + 2];
+            let maxy = self.values[geom_idx * 4 + 3];
+
+            // Safety: we push exactly one ring of five coordinates for one geometry
+            unsafe {
+                output_array.push_xy(minx, miny).unwrap();
+                output_array.push_xy(minx, maxy).unwrap();
+                output_array.push_xy(maxx, maxy).unwrap();
+                output_array.push_xy(maxx, miny).unwrap();
+                output_array.push_xy(minx, miny).unwrap();
+                output_array.try_push_ring_offset(5).unwrap();
+                output_array.try_push_geom_offset(1).unwrap();
+            }
+        }
+
+        output_array.into()
+    }
+
+    /// Iterator over geo Rect objects, not looking at validity
+    pub fn iter_geo_values(&self) -> impl Iterator<Item = geo::Rect> + '_ {
+        (0..self.len()).map(|i| self.value_as_geo(i))
+    }
+
+    /// Iterator over geo Rect objects, taking into account validity
+    pub fn iter_geo(
+        &self,
+    ) -> ZipValidity<geo::Rect, impl Iterator<Item = geo::Rect> + '_, BitIterator> {
+        ZipValidity::new_with_validity(self.iter_geo_values(), self.nulls())
+    }
 }
 
 impl<'a> GeometryArrayTrait<'a> for RectArray {
@@ -76,7 +129,7 @@ impl<'a> GeometryArrayTrait<'a> for RectArray {
         let values = Float64Array::new(self.values, None);
         Arc::new(FixedSizeListArray::new(
             inner_field,
-            2,
+            4,
             Arc::new(values),
             validity,
         ))
@@ -86,12 +139,24 @@ impl<'a> GeometryArrayTrait<'a> for RectArray {
         unimplemented!()
     }
 
+    /// The four values of each rectangle are stored next to each other, so a [`RectArray`] is
+    /// always interleaved.
     fn coord_type(&self) -> CoordType {
-        unimplemented!()
+        CoordType::Interleaved
     }
 
+    /// A [`RectArray`] has a single layout, so this returns the array unchanged.
     fn into_coord_type(self, _coord_type: CoordType) -> Self {
-        unimplemented!()
+        self
+    }
+
+    /// Rectangles are always stored as `f64`, so this returns the array unchanged.
+    fn into_coord_float32(self) -> Self {
+        self
+    }
+
+    fn into_coord_float64(self) -> Self {
+        self
     }
 
     /// Returns the number of geometries in this array
@@ -100,12 +165,20 @@ impl<'a> GeometryArrayTrait<'a> for RectArray {
         self.values.len() / 4
     }
 
+    fn memory_size(&self) -> usize {
+        std::mem::size_of_val(self.values.as_ref()) + validity_memory_size(self.validity.as_ref())
+    }
+
     /// Returns the optional validity.
     #[inline]
     fn validity(&self) -> Option<&NullBuffer> {
         self.validity.as_ref()
     }
 
+    fn total_bounds(&self) -> Option<geo::Rect> {
+        total_bounds_rects((0..self.len()).filter_map(|i| self.get_as_geo(i)))
+    }
+
     /// Slices this [`RectArray`] in place.
     /// # Panic
     /// This function panics iff `offset + length > self.len()`.
@@ -140,6 +213,56 @@ impl<'a> GeoArrayAccessor<'a> for RectArray {
     }
 }
 
+impl EqualsExact for RectArray {
+    fn equals_exact(&self, other: &Self, epsilon: f64) -> bool {
+        self.validity == other.validity
+            && self.values.len() == other.values.len()
+            && self
+                .values
+                .iter()
+                .zip(other.values.iter())
+                .all(|(left, right)| value_eq_within(*left, *right, epsilon))
+    }
+}
+
+impl TryFrom<&FixedSizeListArray> for RectArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: &FixedSizeListArray) -> Result<Self, Self::Error> {
+        if value.value_length() != 4 {
+            return Err(GeoArrowError::General(format!(
+                "Expected a FixedSizeList of size 4 for RectArray, found size {}",
+                value.value_length()
+            )));
+        }
+        let Some(values) = value.values().as_primitive_opt::<Float64Type>() else {
+            return Err(GeoArrowError::General(
+                "Expected Float64 values for RectArray".to_string(),
+            ));
+        };
+
+        let start = value.value_offset(0) as usize;
+        let values = values.values().slice(start, value.len() * 4);
+        Ok(Self::new(values, value.nulls().cloned()))
+    }
+}
+
+impl TryFrom<&dyn Array> for RectArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: &dyn Array) -> Result<Self, Self::Error> {
+        match value.data_type() {
+            DataType::FixedSizeList(_, _) => {
+                let arr = value.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+                arr.try_into()
+            }
+            _ => Err(GeoArrowError::General(
+                "Invalid data type for RectArray".to_string(),
+            )),
+        }
+    }
+}
+
 impl From<Vec<geo::Rect>> for RectArray {
     fn from(other: Vec<geo::Rect>) -> Self {
         let mut_arr: MutableRectArray = other.into();
@@ -153,3 +276,104 @@ impl From<Vec<Option<geo::Rect>>> for RectArray {
         mut_arr.into()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::broadcasting::BroadcastablePrimitive;
+    use crate::algorithm::geo::{Area, Center, Centroid, Scale, Translate};
+    use crate::array::GeometryArray;
+    use geo::polygon;
+
+    #[test]
+    fn arrow_roundtrip() {
+        let rect_array: RectArray = vec![
+            Some(geo::Rect::new((0., 1.), (2., 3.))),
+            None,
+            Some(geo::Rect::new((-4., -5.), (6., 7.))),
+        ]
+        .into();
+        let field = rect_array.extension_field();
+        assert_eq!(
+            field.metadata().get("ARROW:extension:name").unwrap(),
+            "geoarrow._rect"
+        );
+
+        let arrow_array = rect_array.clone().into_array_ref();
+        assert_eq!(arrow_array.data_type(), field.data_type());
+        assert_eq!(arrow_array.len(), 3);
+
+        let roundtripped: RectArray = arrow_array.as_ref().try_into().unwrap();
+        assert_eq!(roundtripped, rect_array);
+        assert_eq!(
+            roundtripped.get_as_geo(2),
+            Some(geo::Rect::new((-4., -5.), (6., 7.)))
+        );
+
+        let sliced: RectArray = arrow_array.slice(1, 2).as_ref().try_into().unwrap();
+        assert_eq!(sliced, rect_array.slice(1, 2));
+
+        let geometry_array: GeometryArray<i32> =
+            (field.as_ref(), arrow_array.as_ref()).try_into().unwrap();
+        assert!(matches!(geometry_array, GeometryArray::Rect(_)));
+    }
+
+    #[test]
+    fn geometry_array_algorithms() {
+        let rect_array: RectArray = vec![Some(geo::Rect::new((0., 1.), (2., 5.))), None].into();
+        let array: GeometryArray<i32> = rect_array.into();
+
+        let centroids = array.centroid();
+        assert_eq!(centroids.get_as_geo(0), Some(geo::point!(x: 1., y: 3.)));
+        assert!(centroids.is_null(1));
+        assert_eq!(array.center(), centroids);
+
+        let area = array.area();
+        assert_eq!(area.value(0), 8.);
+        assert!(area.is_null(1));
+
+        let GeometryArray::Rect(translated) = array.translate(
+            BroadcastablePrimitive::Scalar(1.),
+            BroadcastablePrimitive::Scalar(-1.),
+        ) else {
+            panic!("translating a rect array should return a rect array");
+        };
+        assert_eq!(
+            translated.get_as_geo(0),
+            Some(geo::Rect::new((1., 0.), (3., 4.)))
+        );
+
+        let GeometryArray::Rect(scaled) = array.scale(BroadcastablePrimitive::Scalar(-2.)) else {
+            panic!("scaling a rect array should return a rect array");
+        };
+        assert_eq!(
+            scaled.get_as_geo(0),
+            Some(geo::Rect::new((-1., -1.), (3., 7.)))
+        );
+        assert!(scaled.is_null(1));
+    }
+
+    #[test]
+    fn to_polygon_array() {
+        let rect_array: RectArray = vec![Some(geo::Rect::new((0., 1.), (2., 3.))), None].into();
+        let polygon_array = rect_array.to_polygon_array();
+
+        assert_eq!(polygon_array.len(), 2);
+        assert!(polygon_array.is_valid(0));
+        assert!(polygon_array.is_null(1));
+        assert_eq!(
+            polygon_array.value_as_geo(0),
+            polygon![
+                (x: 0., y: 1.),
+                (x: 0., y: 3.),
+                (x: 2., y: 3.),
+                (x: 2., y: 1.),
+                (x: 0., y: 1.),
+            ]
+        );
+        assert_eq!(
+            polygon_array.value_as_geo(0),
+            geo::Rect::new((0., 1.), (2., 3.)).to_polygon()
+        );
+    }
+}
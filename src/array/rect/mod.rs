@@ -4,4 +4,4 @@ pub use mutable::MutableRectArray;
 
 pub mod array;
 pub mod iterator;
-mod mutable;
+pub mod mutable;
@@ -38,6 +38,11 @@ impl MutableRectArray {
         self.values.reserve(additional * 4);
     }
 
+    /// The number of rects this array can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.values.capacity() / 4
+    }
+
     // /// Reserves the minimum capacity for at least `additional` more points to
     // /// be inserted in the given `Vec<T>`. Unlike [`reserve`], this will not
     // /// deliberately over-allocate to speculatively avoid frequent allocations.
@@ -19,9 +19,13 @@ where
 {
     /// Creates a new [`ZipValidityIter`].
     /// # Panics
-    /// This function panics if the size_hints of the iterators are different
+    /// This function panics if `validity` has an upper size bound and the size_hints of the
+    /// iterators are different. The size_hints are not compared when `validity` has no upper
+    /// bound, as with arrow's [`BitIterator`].
     pub fn new(values: I, validity: V) -> Self {
-        assert_eq!(values.size_hint(), validity.size_hint());
+        if validity.size_hint().1.is_some() {
+            assert_eq!(values.size_hint(), validity.size_hint());
+        }
         Self { values, validity }
     }
 }
@@ -199,3 +203,21 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validity_without_size_hint() {
+        let validity = NullBuffer::from(vec![true, false, true]);
+        let zipped = ZipValidity::new_with_validity(0..3, Some(&validity));
+        assert_eq!(zipped.collect::<Vec<_>>(), vec![Some(0), None, Some(2)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn different_size_hints() {
+        ZipValidityIter::new(0..3, [true, false].into_iter());
+    }
+}
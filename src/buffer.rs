@@ -0,0 +1,186 @@
+//! Helpers for working with the offset buffers of nested GeoArrow arrays.
+//!
+//! These are the utilities the array types in this crate are built on, for crates that implement
+//! their own array types with the same layout.
+
+use arrow_array::OffsetSizeTrait;
+use arrow_buffer::OffsetBuffer;
+
+use crate::array::mutable_offset::OffsetsBuilder;
+use crate::error::{GeoArrowError, Result};
+
+/// Accessors for [`OffsetBuffer`]s that describe the positions of variable-length items.
+pub trait OffsetBufferUtils<O: OffsetSizeTrait> {
+    /// Returns the length an array with these offsets would be.
+    fn len_proxy(&self) -> usize;
+
+    /// Returns a range (start, end) corresponding to the position `index`
+    ///
+    /// # Panic
+    ///
+    /// This function panics iff `index >= self.len_proxy()`
+    fn start_end(&self, index: usize) -> (usize, usize);
+
+    /// Returns the last offset.
+    fn last(&self) -> &O;
+}
+
+impl<O: OffsetSizeTrait> OffsetBufferUtils<O> for OffsetBuffer<O> {
+    #[inline]
+    fn len_proxy(&self) -> usize {
+        self.len() - 1
+    }
+
+    #[inline]
+    fn start_end(&self, index: usize) -> (usize, usize) {
+        assert!(index < self.len_proxy());
+        let start = self[index].to_usize().unwrap();
+        let end = self[index + 1].to_usize().unwrap();
+        (start, end)
+    }
+
+    #[inline]
+    fn last(&self) -> &O {
+        self.as_ref().last().unwrap()
+    }
+}
+
+/// Returns an iterator with the lengths of the offsets
+#[inline]
+pub fn offset_lengths<O: OffsetSizeTrait>(
+    offsets: &OffsetBuffer<O>,
+) -> impl Iterator<Item = usize> + '_ {
+    offsets
+        .windows(2)
+        .map(|w| (w[1] - w[0]).to_usize().unwrap())
+}
+
+/// Check that `offsets` can index into `values_len` values.
+///
+/// # Errors
+///
+/// - if `offsets` is empty
+/// - if any offset is negative
+/// - if the offsets are not monotonically increasing
+/// - if the last offset is not `values_len`
+pub fn validate_offsets<O: OffsetSizeTrait>(offsets: &[O], values_len: usize) -> Result<()> {
+    let first = offsets.first().ok_or_else(|| {
+        GeoArrowError::General("offsets must contain at least one value".to_string())
+    })?;
+    if first.to_usize().is_none() {
+        return Err(GeoArrowError::General(
+            "offsets must be non-negative".to_string(),
+        ));
+    }
+
+    if let Some(idx) = offsets.windows(2).position(|w| w[0] > w[1]) {
+        return Err(GeoArrowError::General(format!(
+            "offsets must be monotonically increasing, but offset {} is larger than offset {}",
+            idx,
+            idx + 1
+        )));
+    }
+
+    let last = offsets.last().unwrap().to_usize().unwrap();
+    if last != values_len {
+        return Err(GeoArrowError::General(format!(
+            "last offset {} must match the number of values {}",
+            last, values_len
+        )));
+    }
+
+    Ok(())
+}
+
+/// Create an [`OffsetBuffer`] from the lengths of each item.
+///
+/// # Errors
+///
+/// - if the total length is too large for offsets of type `O`
+pub fn offsets_from_lengths<O: OffsetSizeTrait>(
+    lengths: impl IntoIterator<Item = usize>,
+) -> Result<OffsetBuffer<O>> {
+    let lengths = lengths.into_iter();
+    let mut offsets = Vec::with_capacity(lengths.size_hint().0 + 1);
+    offsets.push(O::zero());
+    let mut total: usize = 0;
+    for length in lengths {
+        total = total.checked_add(length).ok_or(GeoArrowError::Overflow)?;
+        offsets.push(O::from_usize(total).ok_or(GeoArrowError::Overflow)?);
+    }
+    Ok(OffsetBuffer::new(offsets.into()))
+}
+
+/// A slice of `offsets` with the items from `offset` to `offset + length`, rebased to start at
+/// zero in a new buffer.
+pub fn owned_slice_offsets<O: OffsetSizeTrait>(
+    offsets: &OffsetBuffer<O>,
+    offset: usize,
+    length: usize,
+) -> OffsetBuffer<O> {
+    // TODO: double check but now that we've moved to arrow-rs it looks like this slice adds 1 for
+    // us.
+    let sliced_offsets = offsets.slice(offset, length);
+
+    let mut new_offsets: OffsetsBuilder<O> = OffsetsBuilder::with_capacity(length);
+
+    for item in offset_lengths(&sliced_offsets) {
+        new_offsets.try_push_usize(item).unwrap();
+    }
+
+    new_offsets.into()
+}
+
+/// Convert `i32` offsets to `i64` offsets.
+pub fn offsets_buffer_i32_to_i64(offsets: &OffsetBuffer<i32>) -> OffsetBuffer<i64> {
+    let i64_offsets = offsets.iter().map(|x| *x as i64).collect::<Vec<_>>();
+    unsafe { OffsetBuffer::new_unchecked(i64_offsets.into()) }
+}
+
+/// Convert `i64` offsets to `i32` offsets.
+///
+/// # Errors
+///
+/// - if the last offset doesn't fit in an `i32`
+pub fn offsets_buffer_i64_to_i32(offsets: &OffsetBuffer<i64>) -> Result<OffsetBuffer<i32>> {
+    i32::try_from(*offsets.last()).map_err(|_| GeoArrowError::Overflow)?;
+
+    let i32_offsets = offsets.iter().map(|x| *x as i32).collect::<Vec<_>>();
+    Ok(unsafe { OffsetBuffer::new_unchecked(i32_offsets.into()) })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validate() {
+        assert!(validate_offsets(&[0i32, 2, 2, 5], 5).is_ok());
+        assert!(validate_offsets(&[0i64], 0).is_ok());
+
+        assert!(validate_offsets::<i32>(&[], 0).is_err());
+        assert!(validate_offsets(&[-1i32, 2], 2).is_err());
+        assert!(validate_offsets(&[0i32, 3, 2], 2).is_err());
+        assert!(validate_offsets(&[0i32, 2, 4], 5).is_err());
+    }
+
+    #[test]
+    fn from_lengths() {
+        let offsets: OffsetBuffer<i32> = offsets_from_lengths([2, 0, 3]).unwrap();
+        assert_eq!(offsets.as_ref(), &[0, 2, 2, 5]);
+        assert_eq!(offset_lengths(&offsets).collect::<Vec<_>>(), vec![2, 0, 3]);
+        assert_eq!(offsets.start_end(2), (2, 5));
+
+        assert!(offsets_from_lengths::<i32>([i32::MAX as usize, 1]).is_err());
+    }
+
+    #[test]
+    fn i64_to_i32() {
+        let offsets = OffsetBuffer::new(vec![0i64, 1, i32::MAX as i64 + 1].into());
+        assert!(offsets_buffer_i64_to_i32(&offsets).is_err());
+
+        let offsets = OffsetBuffer::new(vec![0i64, 1, 3].into());
+        let converted = offsets_buffer_i64_to_i32(&offsets).unwrap();
+        assert_eq!(offsets_buffer_i32_to_i64(&converted).as_ref(), &[0, 1, 3]);
+    }
+}
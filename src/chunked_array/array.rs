@@ -0,0 +1,233 @@
+use arrow_array::{Array, ArrayRef};
+
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+
+/// A geometry array stored as a sequence of chunks of the same array type.
+///
+/// Equality is logical: two chunked arrays are equal if they hold the same geometries in the same
+/// order, however the rows are split into chunks.
+#[derive(Debug, Clone)]
+pub struct ChunkedGeometryArray<G> {
+    chunks: Vec<G>,
+    length: usize,
+}
+
+impl<G: for<'a> GeometryArrayTrait<'a> + Clone> ChunkedGeometryArray<G> {
+    /// Create a chunked array from its chunks.
+    pub fn new(chunks: Vec<G>) -> Self {
+        let length = chunks.iter().map(|chunk| chunk.len()).sum();
+        Self { chunks, length }
+    }
+
+    /// The chunks of this array.
+    pub fn chunks(&self) -> &[G] {
+        &self.chunks
+    }
+
+    pub fn into_inner(self) -> Vec<G> {
+        self.chunks
+    }
+
+    /// Append a chunk after the existing ones.
+    pub fn push_chunk(&mut self, chunk: G) {
+        self.length += chunk.len();
+        self.chunks.push(chunk);
+    }
+
+    /// The total number of geometries across all chunks.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Split the geometries into chunks of `target_rows_per_chunk` rows, where only the last
+    /// chunk may be shorter.
+    ///
+    /// Output chunks that lie within one input chunk are zero-copy slices of it. Chunks that span
+    /// several input chunks are concatenated, and get the default
+    /// [`ArrayMetadata`](crate::array::ArrayMetadata).
+    ///
+    /// # Errors
+    ///
+    /// - if `target_rows_per_chunk` is zero
+    pub fn rechunk(&self, target_rows_per_chunk: usize) -> Result<Self>
+    where
+        G: for<'b> TryFrom<&'b dyn Array, Error = GeoArrowError>,
+    {
+        if target_rows_per_chunk == 0 {
+            return Err(GeoArrowError::General(
+                "Chunks must have at least one row".to_string(),
+            ));
+        }
+
+        let mut chunks = Vec::with_capacity(self.length.div_ceil(target_rows_per_chunk));
+        let mut pieces: Vec<G> = vec![];
+        let mut pieces_len = 0;
+        for chunk in &self.chunks {
+            let mut offset = 0;
+            while offset < chunk.len() {
+                let length = (chunk.len() - offset).min(target_rows_per_chunk - pieces_len);
+                pieces.push(chunk.slice(offset, length));
+                pieces_len += length;
+                offset += length;
+
+                if pieces_len == target_rows_per_chunk {
+                    chunks.push(concat_pieces(std::mem::take(&mut pieces))?);
+                    pieces_len = 0;
+                }
+            }
+        }
+        if !pieces.is_empty() {
+            chunks.push(concat_pieces(pieces)?);
+        }
+
+        Ok(Self::new(chunks))
+    }
+
+    fn array_refs(&self) -> Vec<ArrayRef> {
+        self.chunks
+            .iter()
+            .map(|chunk| chunk.clone().into_array_ref())
+            .collect()
+    }
+}
+
+fn concat_pieces<G>(mut pieces: Vec<G>) -> Result<G>
+where
+    G: for<'a> GeometryArrayTrait<'a> + Clone,
+    G: for<'b> TryFrom<&'b dyn Array, Error = GeoArrowError>,
+{
+    if pieces.len() == 1 {
+        return Ok(pieces.pop().unwrap());
+    }
+
+    let arrays: Vec<ArrayRef> = pieces
+        .into_iter()
+        .map(|piece| piece.into_array_ref())
+        .collect();
+    let arrays: Vec<&dyn Array> = arrays.iter().map(|array| array.as_ref()).collect();
+    G::try_from(arrow::compute::concat(&arrays)?.as_ref())
+}
+
+impl<G: for<'a> GeometryArrayTrait<'a> + Clone> PartialEq for ChunkedGeometryArray<G> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length && chunks_eq(&self.array_refs(), &other.array_refs())
+    }
+}
+
+/// Whether two sequences of chunks hold logically equal rows, regardless of where the chunk
+/// boundaries are.
+///
+/// This walks both sequences at once, comparing zero-copy slices of the overlapping parts of the
+/// current chunks, so nothing is concatenated.
+pub(crate) fn chunks_eq(left: &[ArrayRef], right: &[ArrayRef]) -> bool {
+    let total_len = |chunks: &[ArrayRef]| chunks.iter().map(|c| c.len()).sum::<usize>();
+    if total_len(left) != total_len(right) {
+        return false;
+    }
+
+    let mut left_chunks = left.iter().filter(|chunk| !chunk.is_empty());
+    let mut right_chunks = right.iter().filter(|chunk| !chunk.is_empty());
+    let (mut left_chunk, mut right_chunk) = (left_chunks.next(), right_chunks.next());
+    let (mut left_offset, mut right_offset) = (0, 0);
+    while let (Some(l), Some(r)) = (left_chunk, right_chunk) {
+        let length = (l.len() - left_offset).min(r.len() - right_offset);
+        if l.slice(left_offset, length).to_data() != r.slice(right_offset, length).to_data() {
+            return false;
+        }
+
+        left_offset += length;
+        right_offset += length;
+        if left_offset == l.len() {
+            left_chunk = left_chunks.next();
+            left_offset = 0;
+        }
+        if right_offset == r.len() {
+            right_chunk = right_chunks.next();
+            right_offset = 0;
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{MultiPointArray, PointArray};
+    use crate::test::multipoint::{mp0, mp1};
+    use crate::trait_::GeoArrayAccessor;
+    use geo::point;
+
+    /// Split an array into owned chunks of the given lengths.
+    fn split<G: for<'a> GeometryArrayTrait<'a> + Clone>(
+        array: &G,
+        lengths: &[usize],
+    ) -> ChunkedGeometryArray<G> {
+        let mut offset = 0;
+        let chunks = lengths
+            .iter()
+            .map(|length| {
+                // Owned slices can't be empty
+                let chunk = match length {
+                    0 => array.slice(offset, 0),
+                    _ => array.owned_slice(offset, *length),
+                };
+                offset += length;
+                chunk
+            })
+            .collect();
+        ChunkedGeometryArray::new(chunks)
+    }
+
+    fn points() -> PointArray {
+        (0..10)
+            .map(|i| (i % 3 != 1).then(|| point!(x: i as f64, y: -i as f64)))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    #[test]
+    fn equal_across_chunk_boundaries() {
+        let array = points();
+        let three = split(&array, &[4, 3, 3]);
+        let five = split(&array, &[1, 2, 0, 2, 5]);
+        assert_eq!(three, five);
+
+        let multi_points: MultiPointArray<i32> =
+            vec![Some(mp0()), None, Some(mp1()), Some(mp0()), None].into();
+        assert_eq!(
+            split(&multi_points, &[2, 3]),
+            split(&multi_points, &[1, 1, 1, 1, 1])
+        );
+    }
+
+    #[test]
+    fn not_equal() {
+        let array = points();
+        let mut changed: Vec<Option<geo::Point>> =
+            (0..array.len()).map(|i| array.get_as_geo(i)).collect();
+        changed[6] = None;
+        let changed: PointArray = changed.into();
+
+        assert_ne!(split(&array, &[4, 3, 3]), split(&changed, &[5, 5]));
+        assert_ne!(split(&array, &[10]), split(&array, &[9]));
+    }
+
+    #[test]
+    fn rechunk() {
+        let array = points();
+        let chunked = split(&array, &[1, 2, 0, 2, 5]);
+        let rechunked = chunked.rechunk(4).unwrap();
+
+        let lengths: Vec<usize> = rechunked.chunks().iter().map(|c| c.len()).collect();
+        assert_eq!(lengths, vec![4, 4, 2]);
+        assert_eq!(rechunked, chunked);
+        assert!(rechunked.chunks()[0].is_null(1));
+        assert!(chunked.rechunk(0).is_err());
+    }
+}
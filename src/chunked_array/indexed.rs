@@ -0,0 +1,304 @@
+use std::sync::Arc;
+
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, RTreeObject, AABB};
+
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::trait_::GeoArrayAccessor;
+
+/// An R-tree entry: the bounding box of one geometry and its row in the chunked array.
+type Entry = GeomWithData<Rectangle<[f64; 2]>, usize>;
+
+/// A chunked geometry array with an R-tree of its geometries' bounding boxes, which is updated
+/// incrementally as chunks are appended.
+///
+/// Null and empty geometries are not indexed.
+///
+/// # Concurrency
+///
+/// The array and its index live in an immutable [`IndexSnapshot`] behind an [`Arc`]. Readers call
+/// [`snapshot`](Self::snapshot), which is cheap, and query the snapshot from any thread without
+/// locking. [`append`](Self::append) and [`compact`](Self::compact) publish a new snapshot, which
+/// later calls to `snapshot` return; snapshots that readers already hold are unaffected.
+///
+/// While no older snapshot is alive, writes update the index in place. Otherwise the first write
+/// copies the index, so readers shouldn't hold on to snapshots longer than they need them.
+///
+/// # Compaction
+///
+/// Inserting entries one by one gives a less balanced tree than bulk loading them, so queries get
+/// slower as appends accumulate. [`compact`](Self::compact) bulk loads the index again once the
+/// share of entries inserted since the last bulk load exceeds a threshold. It's cheap to call
+/// when there's nothing to do, so call it periodically, for example after every few appends.
+#[derive(Debug)]
+pub struct IndexedGeometryArray<G> {
+    current: Arc<IndexSnapshot<G>>,
+    compact_threshold: f64,
+}
+
+/// A point-in-time view of an [`IndexedGeometryArray`].
+#[derive(Debug, Clone)]
+pub struct IndexSnapshot<G> {
+    array: ChunkedGeometryArray<G>,
+    tree: RTree<Entry>,
+    /// The number of entries inserted into `tree` since it was last bulk loaded.
+    num_inserted: usize,
+}
+
+impl<G> IndexedGeometryArray<G>
+where
+    G: for<'a> GeoArrayAccessor<'a> + Clone,
+    for<'a> <G as GeoArrayAccessor<'a>>::Item: RTreeObject<Envelope = AABB<[f64; 2]>>,
+{
+    /// Index a chunked array, bulk loading the R-tree.
+    ///
+    /// The compaction threshold defaults to 0.5.
+    pub fn new(array: ChunkedGeometryArray<G>) -> Self {
+        let mut offset = 0;
+        let mut entries = vec![];
+        for chunk in array.chunks() {
+            entries.extend(chunk_entries(chunk, offset));
+            offset += chunk.len();
+        }
+
+        let snapshot = IndexSnapshot {
+            array,
+            tree: RTree::bulk_load(entries),
+            num_inserted: 0,
+        };
+        Self {
+            current: Arc::new(snapshot),
+            compact_threshold: 0.5,
+        }
+    }
+
+    /// Set the [`fragmentation`](IndexSnapshot::fragmentation) above which
+    /// [`compact`](Self::compact) rebuilds the index.
+    pub fn with_compact_threshold(self, compact_threshold: f64) -> Self {
+        Self {
+            compact_threshold,
+            ..self
+        }
+    }
+
+    /// The current state of the array and its index.
+    pub fn snapshot(&self) -> Arc<IndexSnapshot<G>> {
+        self.current.clone()
+    }
+
+    /// Append a chunk to the array, inserting its geometries into the index.
+    ///
+    /// The rows of `chunk` are numbered after the existing rows.
+    pub fn append(&mut self, chunk: G) {
+        let snapshot = Arc::make_mut(&mut self.current);
+        for entry in chunk_entries(&chunk, snapshot.array.len()) {
+            snapshot.tree.insert(entry);
+            snapshot.num_inserted += 1;
+        }
+        snapshot.array.push_chunk(chunk);
+    }
+
+    /// Bulk load the index again if its fragmentation exceeds the compaction threshold.
+    ///
+    /// Returns whether the index was rebuilt.
+    pub fn compact(&mut self) -> bool {
+        if self.current.fragmentation() <= self.compact_threshold {
+            return false;
+        }
+
+        let snapshot = Arc::make_mut(&mut self.current);
+        let entries = snapshot.tree.iter().cloned().collect();
+        snapshot.tree = RTree::bulk_load(entries);
+        snapshot.num_inserted = 0;
+        true
+    }
+
+    /// The rows whose bounding boxes intersect `rect`, in the current snapshot.
+    pub fn query_bbox(&self, rect: &geo::Rect) -> Vec<usize> {
+        self.current.query_bbox(rect)
+    }
+
+    /// The row whose bounding box is nearest to `point`, in the current snapshot.
+    pub fn nearest(&self, point: &geo::Point) -> Option<usize> {
+        self.current.nearest(point)
+    }
+}
+
+impl<G> IndexSnapshot<G> {
+    /// The indexed array.
+    pub fn array(&self) -> &ChunkedGeometryArray<G> {
+        &self.array
+    }
+
+    /// The rows whose bounding boxes intersect `rect`, in ascending order.
+    pub fn query_bbox(&self, rect: &geo::Rect) -> Vec<usize> {
+        let envelope = AABB::from_corners(rect.min().x_y().into(), rect.max().x_y().into());
+        let mut rows: Vec<usize> = self
+            .tree
+            .locate_in_envelope_intersecting(&envelope)
+            .map(|entry| entry.data)
+            .collect();
+        rows.sort_unstable();
+        rows
+    }
+
+    /// The row whose bounding box is nearest to `point`, or `None` if nothing is indexed.
+    ///
+    /// The distance to a bounding box is zero for points inside it. If several rows are equally
+    /// near, any one of them may be returned.
+    pub fn nearest(&self, point: &geo::Point) -> Option<usize> {
+        self.tree
+            .nearest_neighbor(&[point.x(), point.y()])
+            .map(|entry| entry.data)
+    }
+
+    /// The share of indexed entries that were inserted one by one since the index was last bulk
+    /// loaded, between 0 and 1.
+    pub fn fragmentation(&self) -> f64 {
+        if self.tree.size() == 0 {
+            return 0.;
+        }
+
+        self.num_inserted as f64 / self.tree.size() as f64
+    }
+}
+
+/// The index entries of one chunk, whose first row is row `offset` of the chunked array.
+fn chunk_entries<G>(chunk: &G, offset: usize) -> Vec<Entry>
+where
+    G: for<'a> GeoArrayAccessor<'a>,
+    for<'a> <G as GeoArrayAccessor<'a>>::Item: RTreeObject<Envelope = AABB<[f64; 2]>>,
+{
+    (0..chunk.len())
+        .filter_map(|i| {
+            let envelope = chunk.get(i)?.envelope();
+            // Empty geometries have an infinite envelope
+            let finite = [envelope.lower(), envelope.upper()]
+                .iter()
+                .flatten()
+                .all(|value| value.is_finite());
+            finite.then(|| GeomWithData::new(Rectangle::from_aabb(envelope), offset + i))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{LineStringArray, PointArray};
+    use crate::GeometryArrayTrait;
+    use geo::{line_string, point, EuclideanDistance};
+
+    /// A deterministic sequence of coordinates in [0, 100).
+    struct Coords(u64);
+
+    impl Iterator for Coords {
+        type Item = f64;
+
+        fn next(&mut self) -> Option<f64> {
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1);
+            Some((self.0 >> 33) as f64 / (1u64 << 31) as f64 * 100.)
+        }
+    }
+
+    fn point_chunk(coords: &mut Coords, len: usize) -> PointArray {
+        (0..len)
+            .map(|i| {
+                // Every seventh point is null
+                (i % 7 != 3).then(|| point!(x: coords.next().unwrap(), y: coords.next().unwrap()))
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    #[test]
+    fn incremental_matches_from_scratch() {
+        let mut coords = Coords(42);
+        let mut indexed = IndexedGeometryArray::new(ChunkedGeometryArray::new(vec![point_chunk(
+            &mut coords,
+            50,
+        )]));
+
+        for round in 0..1000 {
+            indexed.append(point_chunk(&mut coords, 1 + round % 5));
+            if round % 100 == 0 {
+                indexed.compact();
+            }
+            if round % 25 != 0 {
+                continue;
+            }
+
+            let snapshot = indexed.snapshot();
+            let from_scratch = IndexedGeometryArray::new(snapshot.array().clone());
+            let (x, y) = (coords.next().unwrap(), coords.next().unwrap());
+            let rect = geo::Rect::new((x, y), (x + 10., y + 5.));
+            assert_eq!(snapshot.query_bbox(&rect), from_scratch.query_bbox(&rect));
+
+            // Ties may pick different rows, so compare the distances
+            let query = point!(x: x, y: y);
+            let distance = |index: &IndexedGeometryArray<PointArray>| {
+                let row = index.nearest(&query).unwrap();
+                let (chunk_idx, i) = locate(index.snapshot().array(), row);
+                let point = index.snapshot().array().chunks()[chunk_idx].value_as_geo(i);
+                point.euclidean_distance(&query)
+            };
+            assert_eq!(distance(&indexed), distance(&from_scratch));
+        }
+    }
+
+    /// The chunk and the index within the chunk of a row of a chunked array.
+    fn locate<G: for<'a> GeometryArrayTrait<'a> + Clone>(
+        array: &ChunkedGeometryArray<G>,
+        mut row: usize,
+    ) -> (usize, usize) {
+        for (chunk_idx, chunk) in array.chunks().iter().enumerate() {
+            if row < chunk.len() {
+                return (chunk_idx, row);
+            }
+            row -= chunk.len();
+        }
+        panic!("row out of bounds");
+    }
+
+    #[test]
+    fn compact_and_snapshots() {
+        let mut coords = Coords(7);
+        let mut indexed = IndexedGeometryArray::new(ChunkedGeometryArray::new(vec![point_chunk(
+            &mut coords,
+            14,
+        )]))
+        .with_compact_threshold(0.25);
+        let before = indexed.snapshot();
+
+        indexed.append(point_chunk(&mut coords, 2));
+        assert!(!indexed.compact());
+        indexed.append(point_chunk(&mut coords, 7));
+        assert!(indexed.snapshot().fragmentation() > 0.25);
+        assert!(indexed.compact());
+        assert_eq!(indexed.snapshot().fragmentation(), 0.);
+
+        // The earlier snapshot doesn't see the appended rows
+        let everything = geo::Rect::new((0., 0.), (100., 100.));
+        assert_eq!(before.array().len(), 14);
+        assert_eq!(before.query_bbox(&everything).len(), 12);
+        assert_eq!(indexed.snapshot().array().len(), 23);
+        assert_eq!(indexed.query_bbox(&everything).len(), 20);
+    }
+
+    #[test]
+    fn skips_empty_geometries() {
+        let line_strings: LineStringArray<i32> = vec![
+            Some(line_string![(x: 0., y: 0.), (x: 2., y: 2.)]),
+            Some(line_string![]),
+            None,
+            Some(line_string![(x: 5., y: 0.), (x: 6., y: 1.)]),
+        ]
+        .into();
+        let indexed = IndexedGeometryArray::new(ChunkedGeometryArray::new(vec![line_strings]));
+
+        let everything = geo::Rect::new((-10., -10.), (10., 10.));
+        assert_eq!(indexed.query_bbox(&everything), vec![0, 3]);
+        assert_eq!(indexed.nearest(&point!(x: 1., y: 1.5)), Some(0));
+        assert_eq!(indexed.nearest(&point!(x: 7., y: 0.)), Some(3));
+    }
+}
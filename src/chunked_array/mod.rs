@@ -1 +1,7 @@
-pub mod chunked_array;
+//! Geometry arrays split into multiple chunks, such as the record batches of a table.
+
+pub use array::ChunkedGeometryArray;
+pub use indexed::{IndexSnapshot, IndexedGeometryArray};
+
+pub(crate) mod array;
+pub(crate) mod indexed;
@@ -1,18 +1,23 @@
-use crate::array::CoordType;
+use crate::array::{CoordType, Dimension};
 
+/// The logical type of a geometry array.
+///
+/// Arrays of a single geometry type carry the layout and dimension of their coordinates. Mixed
+/// arrays and geometry collections only carry the layout, as their children may differ in
+/// dimension.
 #[derive(Debug, Clone, PartialEq)]
 pub enum GeoDataType {
-    Point(CoordType),
-    LineString(CoordType),
-    LargeLineString(CoordType),
-    Polygon(CoordType),
-    LargePolygon(CoordType),
-    MultiPoint(CoordType),
-    LargeMultiPoint(CoordType),
-    MultiLineString(CoordType),
-    LargeMultiLineString(CoordType),
-    MultiPolygon(CoordType),
-    LargeMultiPolygon(CoordType),
+    Point(CoordType, Dimension),
+    LineString(CoordType, Dimension),
+    LargeLineString(CoordType, Dimension),
+    Polygon(CoordType, Dimension),
+    LargePolygon(CoordType, Dimension),
+    MultiPoint(CoordType, Dimension),
+    LargeMultiPoint(CoordType, Dimension),
+    MultiLineString(CoordType, Dimension),
+    LargeMultiLineString(CoordType, Dimension),
+    MultiPolygon(CoordType, Dimension),
+    LargeMultiPolygon(CoordType, Dimension),
     Mixed(CoordType),
     LargeMixed(CoordType),
     GeometryCollection(CoordType),
@@ -21,3 +26,23 @@ pub enum GeoDataType {
     LargeWKB,
     Rect,
 }
+
+impl GeoDataType {
+    /// The dimension of the coordinates, for arrays of a single geometry type.
+    pub fn dimension(&self) -> Option<Dimension> {
+        match self {
+            GeoDataType::Point(_, dim)
+            | GeoDataType::LineString(_, dim)
+            | GeoDataType::LargeLineString(_, dim)
+            | GeoDataType::Polygon(_, dim)
+            | GeoDataType::LargePolygon(_, dim)
+            | GeoDataType::MultiPoint(_, dim)
+            | GeoDataType::LargeMultiPoint(_, dim)
+            | GeoDataType::MultiLineString(_, dim)
+            | GeoDataType::LargeMultiLineString(_, dim)
+            | GeoDataType::MultiPolygon(_, dim)
+            | GeoDataType::LargeMultiPolygon(_, dim) => Some(*dim),
+            _ => None,
+        }
+    }
+}
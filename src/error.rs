@@ -38,9 +38,25 @@ pub enum GeoArrowError {
     #[error(transparent)]
     GeosError(#[from] geos::Error),
 
+    #[cfg(feature = "gpx")]
+    #[error(transparent)]
+    GpxError(#[from] gpx::errors::GpxError),
+
     #[cfg(feature = "proj")]
     #[error(transparent)]
     ProjError(#[from] proj::ProjError),
 }
 
 pub type Result<T> = std::result::Result<T, GeoArrowError>;
+
+/// What to do when a single row of an input fails to parse.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Fail the whole operation on the first row that can't be parsed.
+    #[default]
+    Strict,
+
+    /// Set rows that can't be parsed to null and report their error messages alongside the
+    /// output.
+    NullifyAndReport,
+}
@@ -9,6 +9,16 @@ pub trait CoordTrait {
     /// y component of this coord
     fn y(&self) -> Self::T;
 
+    /// z component of this coord, or `None` if the coord only has x and y
+    fn z(&self) -> Option<Self::T> {
+        None
+    }
+
+    /// m (measure) component of this coord, or `None` if the coord isn't measured
+    fn m(&self) -> Option<Self::T> {
+        None
+    }
+
     /// Returns a tuple that contains the x/horizontal & y/vertical component of the coord.
     fn x_y(&self) -> (Self::T, Self::T) {
         (self.x(), self.y())
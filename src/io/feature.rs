@@ -0,0 +1,241 @@
+//! Feature-by-feature access to vector files, for processing them in bounded memory.
+//!
+//! Readers such as [`GeoJsonFeatureIterator`](crate::io::geojson::GeoJsonFeatureIterator) yield
+//! one [`Feature`] at a time, and [`process_features`] streams them to any geozero
+//! [`FeatureProcessor`], such as a GeoJSON or FlatGeobuf writer, without collecting them.
+
+use geozero::{ColumnValue, FeatureProcessor, GeozeroGeometry, PropertyProcessor};
+
+use crate::error::Result;
+
+/// A single feature: an optional geometry and its properties.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Feature {
+    pub geometry: Option<geo::Geometry>,
+    pub properties: PropertyMap,
+}
+
+/// An owned property value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValue {
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    String(String),
+    /// A JSON-formatted string, such as a nested object.
+    Json(String),
+    /// A datetime as an ISO 8601 string.
+    DateTime(String),
+    Binary(Vec<u8>),
+}
+
+impl PropertyValue {
+    /// The value as a boolean, if it is one.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            PropertyValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The value as a signed integer, if it is an integer that fits.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            PropertyValue::Int(value) => Some(*value),
+            PropertyValue::UInt(value) => i64::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+
+    /// The value as a float, if it is numeric.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            PropertyValue::Int(value) => Some(*value as f64),
+            PropertyValue::UInt(value) => Some(*value as f64),
+            PropertyValue::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The value as a string slice, if it is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PropertyValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Borrow the value as a geozero column value, for writing.
+    pub fn as_column_value(&self) -> ColumnValue<'_> {
+        match self {
+            PropertyValue::Bool(value) => ColumnValue::Bool(*value),
+            PropertyValue::Int(value) => ColumnValue::Long(*value),
+            PropertyValue::UInt(value) => ColumnValue::ULong(*value),
+            PropertyValue::Float(value) => ColumnValue::Double(*value),
+            PropertyValue::String(value) => ColumnValue::String(value),
+            PropertyValue::Json(value) => ColumnValue::Json(value),
+            PropertyValue::DateTime(value) => ColumnValue::DateTime(value),
+            PropertyValue::Binary(value) => ColumnValue::Binary(value),
+        }
+    }
+}
+
+impl From<&ColumnValue<'_>> for PropertyValue {
+    fn from(value: &ColumnValue<'_>) -> Self {
+        match value {
+            ColumnValue::Bool(value) => PropertyValue::Bool(*value),
+            ColumnValue::Byte(value) => PropertyValue::Int(*value as i64),
+            ColumnValue::Short(value) => PropertyValue::Int(*value as i64),
+            ColumnValue::Int(value) => PropertyValue::Int(*value as i64),
+            ColumnValue::Long(value) => PropertyValue::Int(*value),
+            ColumnValue::UByte(value) => PropertyValue::UInt(*value as u64),
+            ColumnValue::UShort(value) => PropertyValue::UInt(*value as u64),
+            ColumnValue::UInt(value) => PropertyValue::UInt(*value as u64),
+            ColumnValue::ULong(value) => PropertyValue::UInt(*value),
+            ColumnValue::Float(value) => PropertyValue::Float(*value as f64),
+            ColumnValue::Double(value) => PropertyValue::Float(*value),
+            ColumnValue::String(value) => PropertyValue::String(value.to_string()),
+            ColumnValue::Json(value) => PropertyValue::Json(value.to_string()),
+            ColumnValue::DateTime(value) => PropertyValue::DateTime(value.to_string()),
+            ColumnValue::Binary(value) => PropertyValue::Binary(value.to_vec()),
+        }
+    }
+}
+
+/// The properties of a feature, in the order they were read or inserted.
+///
+/// Null properties are not stored.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PropertyMap(Vec<(String, PropertyValue)>);
+
+impl PropertyMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The value of the property called `name`.
+    pub fn get(&self, name: &str) -> Option<&PropertyValue> {
+        self.0
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Set the value of a property, returning its previous value.
+    ///
+    /// A property that already exists keeps its position.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        value: PropertyValue,
+    ) -> Option<PropertyValue> {
+        let name = name.into();
+        match self.0.iter_mut().find(|(key, _)| *key == name) {
+            Some((_, existing)) => Some(std::mem::replace(existing, value)),
+            None => {
+                self.0.push((name, value));
+                None
+            }
+        }
+    }
+
+    /// Remove a property, returning its value.
+    pub fn remove(&mut self, name: &str) -> Option<PropertyValue> {
+        let position = self.0.iter().position(|(key, _)| key == name)?;
+        Some(self.0.remove(position).1)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &PropertyValue)> {
+        self.0.iter().map(|(key, value)| (key.as_str(), value))
+    }
+}
+
+impl PropertyProcessor for PropertyMap {
+    fn property(
+        &mut self,
+        _idx: usize,
+        name: &str,
+        value: &ColumnValue,
+    ) -> geozero::error::Result<bool> {
+        self.insert(name, value.into());
+        Ok(false)
+    }
+}
+
+/// Apply `f` to each feature and stream the results to `processor`, one feature at a time.
+///
+/// Features for which `f` returns `None` are dropped. Features without a geometry are passed to
+/// the processor without geometry calls. Returns the number of features written.
+///
+/// # Errors
+///
+/// - if reading a feature fails
+/// - if the processor fails
+pub fn process_features<I, P, F>(features: I, processor: &mut P, mut f: F) -> Result<usize>
+where
+    I: IntoIterator<Item = Result<Feature>>,
+    P: FeatureProcessor,
+    F: FnMut(Feature) -> Option<Feature>,
+{
+    processor.dataset_begin(None)?;
+    let mut num_written = 0;
+    for feature in features {
+        let Some(feature) = f(feature?) else {
+            continue;
+        };
+
+        let idx = num_written as u64;
+        processor.feature_begin(idx)?;
+        processor.properties_begin()?;
+        for (i, (name, value)) in feature.properties.iter().enumerate() {
+            processor.property(i, name, &value.as_column_value())?;
+        }
+        processor.properties_end()?;
+        if let Some(geometry) = &feature.geometry {
+            processor.geometry_begin()?;
+            geometry.process_geom(processor)?;
+            processor.geometry_end()?;
+        }
+        processor.feature_end(idx)?;
+        num_written += 1;
+    }
+    processor.dataset_end()?;
+
+    Ok(num_written)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn property_map() {
+        let mut properties = PropertyMap::new();
+        properties
+            .property(0, "name", &ColumnValue::String("a"))
+            .unwrap();
+        properties
+            .property(1, "count", &ColumnValue::UInt(3))
+            .unwrap();
+        assert_eq!(properties.get("name").unwrap().as_str(), Some("a"));
+        assert_eq!(properties.get("count").unwrap().as_i64(), Some(3));
+        assert_eq!(properties.get("count").unwrap().as_f64(), Some(3.));
+
+        let previous = properties.insert("name", PropertyValue::Bool(true));
+        assert_eq!(previous, Some(PropertyValue::String("a".to_string())));
+        let names: Vec<&str> = properties.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, vec!["name", "count"]);
+
+        assert!(properties.remove("name").is_some());
+        assert_eq!(properties.len(), 1);
+    }
+}
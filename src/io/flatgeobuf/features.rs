@@ -0,0 +1,74 @@
+use std::io::{Read, Seek};
+
+use flatgeobuf::{FallibleStreamingIterator, FgbReader};
+use geozero::{FeatureProperties, ToGeo};
+
+use crate::error::Result;
+use crate::io::feature::{Feature, PropertyMap};
+
+/// Iterate over the features of a FlatGeobuf file, reading one feature at a time.
+///
+/// This is the streaming counterpart of [`read_flatgeobuf`](super::read_flatgeobuf), for use
+/// with [`process_features`](crate::io::feature::process_features). The iterator stops after
+/// the first error.
+///
+/// # Errors
+///
+/// - if the file header can't be read
+pub fn flatgeobuf_features<R: Read + Seek>(
+    file: R,
+) -> Result<impl Iterator<Item = Result<Feature>>> {
+    let mut reader = FgbReader::open(file)?.select_all()?;
+
+    let mut done = false;
+    Ok(std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+
+        let result = match reader.next() {
+            Ok(Some(feature)) => {
+                let mut properties = PropertyMap::new();
+                feature
+                    .process_properties(&mut properties)
+                    .and_then(|_| feature.geometry().map(|_| feature.to_geo()).transpose())
+                    .map(|geometry| {
+                        Some(Feature {
+                            geometry,
+                            properties,
+                        })
+                    })
+                    .map_err(Into::into)
+            }
+            Ok(None) => Ok(None),
+            Err(err) => Err(err.into()),
+        };
+        done = !matches!(result, Ok(Some(_)));
+        result.transpose()
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::io::flatgeobuf::read_flatgeobuf;
+    use std::fs::File;
+    use std::io::BufReader;
+
+    #[test]
+    fn iterate_features() {
+        let file = BufReader::new(File::open("fixtures/flatgeobuf/countries.fgb").unwrap());
+        let features: Vec<Feature> = flatgeobuf_features(file)
+            .unwrap()
+            .collect::<Result<_>>()
+            .unwrap();
+        let mut file = BufReader::new(File::open("fixtures/flatgeobuf/countries.fgb").unwrap());
+        let table = read_flatgeobuf(&mut file).unwrap();
+        let num_rows: usize = table.batches().iter().map(|batch| batch.num_rows()).sum();
+        assert_eq!(features.len(), num_rows);
+        assert!(features
+            .iter()
+            .all(|feature| matches!(feature.geometry, Some(geo::Geometry::MultiPolygon(_)))));
+        assert!(features[0].properties.get("name").is_some());
+    }
+}
@@ -1,8 +1,10 @@
 //! Read the [FlatGeobuf](https://flatgeobuf.org/) format.
 
 mod anyvalue;
+mod features;
 mod reader;
 mod writer;
 
-pub use reader::read_flatgeobuf;
+pub use features::flatgeobuf_features;
+pub use reader::{read_flatgeobuf, read_flatgeobuf_with_options};
 pub use writer::{write_flatgeobuf, write_flatgeobuf_with_options};
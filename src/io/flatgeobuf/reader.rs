@@ -23,8 +23,9 @@ use crate::array::MutablePointArray;
 use crate::array::*;
 use crate::error::{GeoArrowError, Result};
 use crate::io::flatgeobuf::anyvalue::AnyMutableArray;
+use crate::io::geozero::simplify::{ReadOptions, SimplifyProcessor, DROPPED_VERTICES_KEY};
 use crate::table::GeoTable;
-use crate::trait_::MutableGeometryArray;
+use crate::trait_::GeometryArrayTrait;
 use arrow_array::builder::{
     BinaryBuilder, BooleanBuilder, Float32Builder, Float64Builder, Int16Builder, Int32Builder,
     Int64Builder, Int8Builder, StringBuilder, UInt16Builder, UInt32Builder, UInt64Builder,
@@ -35,11 +36,12 @@ use arrow_schema::{DataType, Field, Schema, TimeUnit};
 use flatgeobuf::{ColumnType, GeometryType};
 use flatgeobuf::{FgbReader, Header};
 use geozero::{FeatureProcessor, GeomProcessor, PropertyProcessor};
+use std::collections::HashMap;
 use std::io::{Read, Seek};
 use std::sync::Arc;
 
 macro_rules! define_table_builder {
-    ($name:ident, $geo_type:ty) => {
+    ($name:ident, $geo_type:ty, $array_type:ty) => {
         struct $name {
             schema: Arc<Schema>,
             columns: Vec<AnyMutableArray>,
@@ -47,7 +49,7 @@ macro_rules! define_table_builder {
         }
 
         impl $name {
-            pub fn finish(self) -> Result<GeoTable> {
+            pub fn finish(self, metadata: HashMap<String, String>) -> Result<GeoTable> {
                 // Set geometry column after property columns
                 let geometry_column_index = self.columns.len();
 
@@ -58,12 +60,9 @@ macro_rules! define_table_builder {
                 }
 
                 // Add geometry column and geometry field
-                let geometry_column = self.geometry.into_array_ref();
-                let geometry_field = Arc::new(Field::new(
-                    "geometry",
-                    geometry_column.data_type().clone(),
-                    true,
-                ));
+                let geometry: $array_type = self.geometry.into();
+                let (geometry_field, geometry_column) =
+                    geometry.into_array_ref_with_field("geometry");
 
                 columns.push(geometry_column);
 
@@ -71,7 +70,7 @@ macro_rules! define_table_builder {
                 let schema = self.schema;
                 let mut fields: Vec<_> = schema.fields.into_iter().map(|f| f.to_owned()).collect();
                 fields.push(geometry_field);
-                let new_schema = Arc::new(Schema::new(fields));
+                let new_schema = Arc::new(Schema::new(fields).with_metadata(metadata));
 
                 let batch = RecordBatch::try_new(new_schema.clone(), columns)?;
                 GeoTable::try_new(new_schema, vec![batch], geometry_column_index)
@@ -298,15 +297,32 @@ macro_rules! define_table_builder {
     };
 }
 
-define_table_builder!(PointTableBuilder, MutablePointArray);
-define_table_builder!(LineStringTableBuilder, MutableLineStringArray<i32>);
-define_table_builder!(PolygonTableBuilder, MutablePolygonArray<i32>);
-define_table_builder!(MultiPointTableBuilder, MutableMultiPointArray<i32>);
+define_table_builder!(PointTableBuilder, MutablePointArray, PointArray);
+define_table_builder!(
+    LineStringTableBuilder,
+    MutableLineStringArray<i32>,
+    LineStringArray<i32>
+);
+define_table_builder!(
+    PolygonTableBuilder,
+    MutablePolygonArray<i32>,
+    PolygonArray<i32>
+);
+define_table_builder!(
+    MultiPointTableBuilder,
+    MutableMultiPointArray<i32>,
+    MultiPointArray<i32>
+);
 define_table_builder!(
     MultiLineStringTableBuilder,
-    MutableMultiLineStringArray<i32>
+    MutableMultiLineStringArray<i32>,
+    MultiLineStringArray<i32>
+);
+define_table_builder!(
+    MultiPolygonTableBuilder,
+    MutableMultiPolygonArray<i32>,
+    MultiPolygonArray<i32>
 );
-define_table_builder!(MultiPolygonTableBuilder, MutableMultiPolygonArray<i32>);
 
 impl PointTableBuilder {
     pub fn new(
@@ -403,6 +419,17 @@ impl MultiPolygonTableBuilder {
 
 /// Read a FlatGeobuf file to a GeoTable
 pub fn read_flatgeobuf<R: Read + Seek>(file: &mut R) -> Result<GeoTable> {
+    read_flatgeobuf_with_options(file, &ReadOptions::default())
+}
+
+/// Read a FlatGeobuf file to a GeoTable, simplifying geometries as they are read.
+///
+/// If `options` can drop vertices, the total number of dropped vertices is stored in the schema
+/// metadata under [`DROPPED_VERTICES_KEY`].
+pub fn read_flatgeobuf_with_options<R: Read + Seek>(
+    file: &mut R,
+    options: &ReadOptions,
+) -> Result<GeoTable> {
     let mut reader = FgbReader::open(file)?.select_all()?;
 
     let header = reader.header();
@@ -410,41 +437,30 @@ pub fn read_flatgeobuf<R: Read + Seek>(file: &mut R) -> Result<GeoTable> {
 
     let (schema, initialized_columns) = infer_schema_and_init_columns(header, features_count);
 
+    macro_rules! read_table {
+        ($builder:ty) => {{
+            let mut builder = <$builder>::new(schema, initialized_columns, features_count);
+            let mut processor = SimplifyProcessor::new(&mut builder, *options);
+            reader.process_features(&mut processor)?;
+
+            let mut metadata = HashMap::new();
+            if options.is_lossy() {
+                metadata.insert(
+                    DROPPED_VERTICES_KEY.to_string(),
+                    processor.dropped_vertices().to_string(),
+                );
+            }
+            builder.finish(metadata)
+        }};
+    }
+
     match header.geometry_type() {
-        GeometryType::Point => {
-            let mut builder = PointTableBuilder::new(schema, initialized_columns, features_count);
-            reader.process_features(&mut builder)?;
-            builder.finish()
-        }
-        GeometryType::LineString => {
-            let mut builder =
-                LineStringTableBuilder::new(schema, initialized_columns, features_count);
-            reader.process_features(&mut builder)?;
-            builder.finish()
-        }
-        GeometryType::Polygon => {
-            let mut builder = PolygonTableBuilder::new(schema, initialized_columns, features_count);
-            reader.process_features(&mut builder)?;
-            builder.finish()
-        }
-        GeometryType::MultiPoint => {
-            let mut builder =
-                MultiPointTableBuilder::new(schema, initialized_columns, features_count);
-            reader.process_features(&mut builder)?;
-            builder.finish()
-        }
-        GeometryType::MultiLineString => {
-            let mut builder =
-                MultiLineStringTableBuilder::new(schema, initialized_columns, features_count);
-            reader.process_features(&mut builder)?;
-            builder.finish()
-        }
-        GeometryType::MultiPolygon => {
-            let mut builder =
-                MultiPolygonTableBuilder::new(schema, initialized_columns, features_count);
-            reader.process_features(&mut builder)?;
-            builder.finish()
-        }
+        GeometryType::Point => read_table!(PointTableBuilder),
+        GeometryType::LineString => read_table!(LineStringTableBuilder),
+        GeometryType::Polygon => read_table!(PolygonTableBuilder),
+        GeometryType::MultiPoint => read_table!(MultiPointTableBuilder),
+        GeometryType::MultiLineString => read_table!(MultiLineStringTableBuilder),
+        GeometryType::MultiPolygon => read_table!(MultiPolygonTableBuilder),
         // TODO: Parse into a GeometryCollection array and then downcast to a single-typed array if possible.
         GeometryType::Unknown => Err(GeoArrowError::NotYetImplemented(
             "Parsing FlatGeobuf from unknown geometry type not yet supported.".to_string(),
@@ -563,6 +579,39 @@ mod test {
         let _table = read_flatgeobuf(&mut filein).unwrap();
     }
 
+    #[test]
+    fn test_countries_simplified() {
+        let mut filein = BufReader::new(File::open("fixtures/flatgeobuf/countries.fgb").unwrap());
+        let table = read_flatgeobuf(&mut filein).unwrap();
+        let mut filein = BufReader::new(File::open("fixtures/flatgeobuf/countries.fgb").unwrap());
+        let options = ReadOptions {
+            simplify_epsilon: Some(0.1),
+            max_coords_per_geometry: None,
+        };
+        let simplified = read_flatgeobuf_with_options(&mut filein, &options).unwrap();
+
+        let num_coords = |table: &GeoTable| -> usize {
+            table
+                .batches()
+                .iter()
+                .map(|batch| {
+                    let column = batch.column(table.geometry_column_index());
+                    let arr: MultiPolygonArray<i32> = column.as_ref().try_into().unwrap();
+                    arr.coords.len()
+                })
+                .sum()
+        };
+        let dropped_vertices: usize = simplified.schema().metadata()[DROPPED_VERTICES_KEY]
+            .parse()
+            .unwrap();
+        assert!(dropped_vertices > 0);
+        assert_eq!(
+            num_coords(&table) - num_coords(&simplified),
+            dropped_vertices
+        );
+        assert!(!table.schema().metadata().contains_key(DROPPED_VERTICES_KEY));
+    }
+
     #[test]
     fn test_nz_buildings() {
         let mut filein = BufReader::new(
@@ -0,0 +1,358 @@
+//! A pull-based reader of the features of a GeoJSON `FeatureCollection`.
+
+use std::io::{BufRead, BufReader, Read};
+
+use geozero::geojson::GeoJson;
+use geozero::ToGeo;
+use serde_json::Value;
+
+use crate::error::{GeoArrowError, Result};
+use crate::io::feature::{Feature, PropertyMap, PropertyValue};
+
+/// An iterator over the features of a GeoJSON `FeatureCollection`, which holds at most one
+/// feature in memory at a time.
+///
+/// The input is scanned for the top-level `features` array, and each of its features is parsed
+/// on its own as it is reached. Members of the collection after `features` are not read.
+///
+/// Null properties are skipped, and nested objects and arrays are returned as
+/// [`PropertyValue::Json`]. Feature ids are not read.
+pub struct GeoJsonFeatureIterator<R> {
+    reader: BufReader<R>,
+    /// The bytes of the feature being read, reused across features.
+    buf: Vec<u8>,
+    /// Whether the reader is past the opening bracket of the `features` array.
+    in_features: bool,
+    done: bool,
+}
+
+impl<R: Read> GeoJsonFeatureIterator<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            buf: vec![],
+            in_features: false,
+            done: false,
+        }
+    }
+
+    fn next_byte(&mut self) -> Result<Option<u8>> {
+        let byte = self
+            .reader
+            .fill_buf()
+            .map_err(|err| GeoArrowError::General(err.to_string()))?
+            .first()
+            .copied();
+        if byte.is_some() {
+            self.reader.consume(1);
+        }
+        Ok(byte)
+    }
+
+    fn next_byte_or_eof_error(&mut self) -> Result<u8> {
+        self.next_byte()?
+            .ok_or_else(|| GeoArrowError::General("Unexpected end of GeoJSON input".to_string()))
+    }
+
+    /// Advance the reader past the opening bracket of the top-level `features` array.
+    fn find_features(&mut self) -> Result<()> {
+        let mut depth = 0;
+        // The last string read directly inside the top-level object, truncated
+        let mut last_string = vec![];
+        while let Some(byte) = self.next_byte()? {
+            match byte {
+                b'"' => {
+                    last_string.clear();
+                    loop {
+                        match self.next_byte_or_eof_error()? {
+                            b'"' => break,
+                            b'\\' => {
+                                self.next_byte_or_eof_error()?;
+                            }
+                            byte if depth == 1 && last_string.len() <= 8 => last_string.push(byte),
+                            _ => (),
+                        }
+                    }
+                }
+                b'[' if depth == 1 && last_string == b"features" => return Ok(()),
+                b'{' | b'[' => {
+                    if depth == 0 && byte == b'[' {
+                        break;
+                    }
+                    depth += 1;
+                }
+                b'}' | b']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                b',' => last_string.clear(),
+                _ => (),
+            }
+        }
+
+        Err(GeoArrowError::General(
+            "Expected a GeoJSON FeatureCollection with a features array".to_string(),
+        ))
+    }
+
+    /// Read the bytes of the next feature object into `buf`, returning false at the end of the
+    /// `features` array.
+    fn read_feature_bytes(&mut self) -> Result<bool> {
+        let first = loop {
+            match self.next_byte_or_eof_error()? {
+                b']' => return Ok(false),
+                byte if byte == b',' || byte.is_ascii_whitespace() => continue,
+                byte => break byte,
+            }
+        };
+        if first != b'{' {
+            return Err(GeoArrowError::General(format!(
+                "Expected a feature object, found '{}'",
+                first as char
+            )));
+        }
+
+        self.buf.clear();
+        self.buf.push(first);
+        let mut depth = 1;
+        let mut in_string = false;
+        while depth > 0 {
+            let byte = self.next_byte_or_eof_error()?;
+            self.buf.push(byte);
+            match byte {
+                b'\\' if in_string => {
+                    let escaped = self.next_byte_or_eof_error()?;
+                    self.buf.push(escaped);
+                }
+                b'"' => in_string = !in_string,
+                b'{' | b'[' if !in_string => depth += 1,
+                b'}' | b']' if !in_string => depth -= 1,
+                _ => (),
+            }
+        }
+        Ok(true)
+    }
+
+    fn read_feature(&mut self) -> Result<Option<Feature>> {
+        if !self.in_features {
+            self.find_features()?;
+            self.in_features = true;
+        }
+        if !self.read_feature_bytes()? {
+            return Ok(None);
+        }
+
+        let value: Value = serde_json::from_slice(&self.buf)
+            .map_err(|err| GeoArrowError::General(format!("Invalid GeoJSON feature: {}", err)))?;
+        let geometry = match value.get("geometry") {
+            None | Some(Value::Null) => None,
+            Some(geometry) => Some(GeoJson(&geometry.to_string()).to_geo()?),
+        };
+        let mut properties = PropertyMap::new();
+        if let Some(Value::Object(object)) = value.get("properties") {
+            for (name, value) in object {
+                if let Some(value) = property_value(value) {
+                    properties.insert(name.as_str(), value);
+                }
+            }
+        }
+
+        Ok(Some(Feature {
+            geometry,
+            properties,
+        }))
+    }
+}
+
+impl<R: Read> Iterator for GeoJsonFeatureIterator<R> {
+    type Item = Result<Feature>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.read_feature().transpose();
+        // Stop after the last feature or the first error
+        self.done = !matches!(result, Some(Ok(_)));
+        result
+    }
+}
+
+fn property_value(value: &Value) -> Option<PropertyValue> {
+    let value = match value {
+        Value::Null => return None,
+        Value::Bool(value) => PropertyValue::Bool(*value),
+        Value::Number(number) => {
+            if let Some(value) = number.as_i64() {
+                PropertyValue::Int(value)
+            } else if let Some(value) = number.as_u64() {
+                PropertyValue::UInt(value)
+            } else {
+                PropertyValue::Float(number.as_f64()?)
+            }
+        }
+        Value::String(value) => PropertyValue::String(value.clone()),
+        Value::Array(_) | Value::Object(_) => PropertyValue::Json(value.to_string()),
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::io::feature::process_features;
+    use geo::point;
+    use geozero::geojson::GeoJsonWriter;
+
+    #[test]
+    fn read_features() {
+        let input = r#"{
+            "type": "FeatureCollection",
+            "name": "features",
+            "bbox": [0, 0, 1, 1],
+            "features": [
+                {"type": "Feature", "geometry": {"type": "Point", "coordinates": [1, 2]},
+                 "properties": {"name": "a \"}]\" b", "count": 3, "tags": ["x"], "empty": null}},
+                {"type": "Feature", "geometry": null, "properties": {"ratio": 0.5}}
+            ],
+            "extra": {"ignored": true}
+        }"#;
+        let features: Vec<Feature> = GeoJsonFeatureIterator::new(input.as_bytes())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(features.len(), 2);
+
+        assert_eq!(
+            features[0].geometry,
+            Some(geo::Geometry::Point(point!(x: 1., y: 2.)))
+        );
+        let properties = &features[0].properties;
+        assert_eq!(properties.get("name").unwrap().as_str(), Some("a \"}]\" b"));
+        assert_eq!(properties.get("count").unwrap().as_i64(), Some(3));
+        assert_eq!(
+            properties.get("tags"),
+            Some(&PropertyValue::Json(r#"["x"]"#.to_string()))
+        );
+        assert!(properties.get("empty").is_none());
+
+        assert_eq!(features[1].geometry, None);
+        assert_eq!(
+            features[1].properties.get("ratio").unwrap().as_f64(),
+            Some(0.5)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let mut features = GeoJsonFeatureIterator::new(r#"{"type": "Point"}"#.as_bytes());
+        assert!(features.next().unwrap().is_err());
+        assert!(features.next().is_none());
+
+        let truncated = r#"{"type": "FeatureCollection", "features": [{"type": "Feat"#;
+        let mut features = GeoJsonFeatureIterator::new(truncated.as_bytes());
+        assert!(features.next().unwrap().is_err());
+    }
+
+    /// A GeoJSON FeatureCollection of points, generated as it is read.
+    struct GeneratedFeatureCollection {
+        num_features: usize,
+        next_feature: usize,
+        pending: Vec<u8>,
+    }
+
+    impl Read for GeneratedFeatureCollection {
+        fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+            if self.pending.is_empty() {
+                let next = self.next_feature;
+                self.pending = match next {
+                    0 => br#"{"type": "FeatureCollection", "features": ["#.to_vec(),
+                    n if n <= self.num_features => format!(
+                        r#"{}{{"type": "Feature", "properties": {{"id": {}}}, "geometry": {{"type": "Point", "coordinates": [{}, 0.5]}}}}"#,
+                        if n > 1 { "," } else { "" },
+                        n,
+                        n
+                    )
+                    .into_bytes(),
+                    n if n == self.num_features + 1 => b"]}".to_vec(),
+                    _ => return Ok(0),
+                };
+                self.next_feature += 1;
+            }
+
+            let len = out.len().min(self.pending.len());
+            out[..len].copy_from_slice(&self.pending[..len]);
+            self.pending.drain(..len);
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn stream_in_bounded_memory() {
+        let num_features = 100_000;
+        let input = GeneratedFeatureCollection {
+            num_features,
+            next_feature: 0,
+            pending: vec![],
+        };
+
+        let mut features = GeoJsonFeatureIterator::new(input);
+        let mut num_seen = 0;
+        let mut max_buf_capacity = 0;
+        let mut writer = GeoJsonWriter::new(std::io::sink());
+        let num_written = process_features(
+            std::iter::from_fn(|| {
+                let feature = features.next();
+                max_buf_capacity = max_buf_capacity.max(features.buf.capacity());
+                feature
+            }),
+            &mut writer,
+            |feature| {
+                num_seen += 1;
+                Some(feature)
+            },
+        )
+        .unwrap();
+
+        assert_eq!(num_seen, num_features);
+        assert_eq!(num_written, num_features);
+        // Only ever one feature of ~100 bytes is buffered
+        assert!(max_buf_capacity < 1024);
+    }
+
+    #[test]
+    fn identity_roundtrip() {
+        let input = r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {"name": "a", "n": 1},
+             "geometry": {"type": "LineString", "coordinates": [[0, 0], [1, 1]]}},
+            {"type": "Feature", "properties": {"name": "b", "n": 2},
+             "geometry": {"type": "Point", "coordinates": [2, 3]}}
+        ]}"#;
+        let mut output = vec![];
+        let num_written = process_features(
+            GeoJsonFeatureIterator::new(input.as_bytes()),
+            &mut GeoJsonWriter::new(&mut output),
+            |feature| match feature.properties.get("n")?.as_i64()? {
+                1 => Some(feature),
+                _ => None,
+            },
+        )
+        .unwrap();
+        assert_eq!(num_written, 1);
+
+        let features: Vec<Feature> = GeoJsonFeatureIterator::new(output.as_slice())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(features.len(), 1);
+        assert_eq!(
+            features[0].properties.get("name").unwrap().as_str(),
+            Some("a")
+        );
+        assert!(matches!(
+            features[0].geometry,
+            Some(geo::Geometry::LineString(_))
+        ));
+    }
+}
@@ -1,3 +1,10 @@
-pub use writer::write_geojson;
-
+mod features;
+mod reader;
+mod strings;
 mod writer;
+pub use features::GeoJsonFeatureIterator;
+pub use reader::{read_geojson, read_geojson_with_options};
+pub use strings::{
+    from_geojson_strings, from_geojson_strings_lenient, RowErrors, ToGeoJsonStrings,
+};
+pub use writer::write_geojson;
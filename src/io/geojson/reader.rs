@@ -0,0 +1,240 @@
+//! Reader for converting a GeoJSON `FeatureCollection` to a GeoArrow table.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::Arc;
+
+use arrow_array::builder::{BooleanBuilder, Float64Builder, Int64Builder, StringBuilder};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{Field, Schema};
+use geozero::geo_types::GeoWriter;
+use geozero::GeozeroGeometry;
+
+use crate::error::Result;
+use crate::io::feature::PropertyValue;
+use crate::io::geojson::GeoJsonFeatureIterator;
+use crate::io::geozero::simplify::{ReadOptions, SimplifyProcessor, DROPPED_VERTICES_KEY};
+use crate::io::wkt::parse::GeometryArrayBuilder;
+use crate::table::GeoTable;
+use crate::GeometryArrayTrait;
+
+/// Read a GeoJSON `FeatureCollection` to a GeoTable.
+///
+/// The geometry type is inferred from the features, as in
+/// [`from_wkt`](crate::io::wkt::from_wkt), and the geometry column comes after the property
+/// columns. Each property becomes a column of booleans, `Int64`, `Float64` or, for properties with
+/// strings, nested values or mixed types, `Utf8`. Features without a property have a null in its
+/// column.
+pub fn read_geojson<R: Read>(reader: R) -> Result<GeoTable> {
+    read_geojson_with_options(reader, &ReadOptions::default())
+}
+
+/// Read a GeoJSON `FeatureCollection` to a GeoTable, simplifying geometries as they are read.
+///
+/// If `options` can drop vertices, the total number of dropped vertices is stored in the schema
+/// metadata under [`DROPPED_VERTICES_KEY`].
+///
+/// # Errors
+///
+/// - if the input is not a GeoJSON `FeatureCollection`
+/// - if the features have no geometries to infer the geometry type from, or geometries of
+///   unrelated types, such as points and polygons
+pub fn read_geojson_with_options<R: Read>(reader: R, options: &ReadOptions) -> Result<GeoTable> {
+    let mut geometry = GeometryArrayBuilder::<i32>::new(None)?;
+    let mut columns: Vec<(String, Vec<Option<PropertyValue>>)> = vec![];
+    let mut num_rows = 0;
+    let mut dropped_vertices = 0;
+
+    for feature in GeoJsonFeatureIterator::new(reader) {
+        let feature = feature?;
+
+        let geom = match feature.geometry {
+            Some(geom) if options.is_lossy() => {
+                let mut writer = GeoWriter::new();
+                let mut processor = SimplifyProcessor::new(&mut writer, *options);
+                geom.process_geom(&mut processor)?;
+                dropped_vertices += processor.dropped_vertices();
+                writer.take_geometry()
+            }
+            geom => geom,
+        };
+        geometry.push(geom)?;
+
+        for (name, value) in feature.properties.iter() {
+            let column = match columns.iter().position(|(key, _)| key == name) {
+                Some(position) => &mut columns[position].1,
+                None => {
+                    columns.push((name.to_string(), vec![None; num_rows]));
+                    &mut columns.last_mut().unwrap().1
+                }
+            };
+            column.push(Some(value.clone()));
+        }
+        num_rows += 1;
+        for (_, column) in columns.iter_mut() {
+            column.resize(num_rows, None);
+        }
+    }
+
+    let mut fields = Vec::with_capacity(columns.len() + 1);
+    let mut arrays = Vec::with_capacity(columns.len() + 1);
+    for (name, values) in columns {
+        let array = property_array(&values);
+        fields.push(Arc::new(Field::new(name, array.data_type().clone(), true)));
+        arrays.push(array);
+    }
+
+    // Set geometry column after property columns
+    let geometry_column_index = arrays.len();
+    let (geometry_field, geometry_column) =
+        geometry.finish()?.into_array_ref_with_field("geometry");
+    fields.push(geometry_field);
+    arrays.push(geometry_column);
+
+    let mut metadata = HashMap::new();
+    if options.is_lossy() {
+        metadata.insert(
+            DROPPED_VERTICES_KEY.to_string(),
+            dropped_vertices.to_string(),
+        );
+    }
+    let schema = Arc::new(Schema::new(fields).with_metadata(metadata));
+    let batch = RecordBatch::try_new(schema.clone(), arrays)?;
+    GeoTable::try_new(schema, vec![batch], geometry_column_index)
+}
+
+/// Build the array of a property column, of the narrowest type that holds all of its values.
+fn property_array(values: &[Option<PropertyValue>]) -> ArrayRef {
+    let present = || values.iter().flatten();
+    if present().all(|value| matches!(value, PropertyValue::Bool(_))) {
+        let mut builder = BooleanBuilder::with_capacity(values.len());
+        values
+            .iter()
+            .for_each(|value| builder.append_option(value.as_ref().and_then(|v| v.as_bool())));
+        Arc::new(builder.finish())
+    } else if present().all(|value| value.as_i64().is_some()) {
+        let mut builder = Int64Builder::with_capacity(values.len());
+        values
+            .iter()
+            .for_each(|value| builder.append_option(value.as_ref().and_then(|v| v.as_i64())));
+        Arc::new(builder.finish())
+    } else if present().all(|value| value.as_f64().is_some()) {
+        let mut builder = Float64Builder::with_capacity(values.len());
+        values
+            .iter()
+            .for_each(|value| builder.append_option(value.as_ref().and_then(|v| v.as_f64())));
+        Arc::new(builder.finish())
+    } else {
+        let mut builder = StringBuilder::new();
+        for value in values {
+            match value {
+                Some(PropertyValue::String(s) | PropertyValue::Json(s)) => builder.append_value(s),
+                Some(PropertyValue::Bool(v)) => builder.append_value(v.to_string()),
+                Some(PropertyValue::Int(v)) => builder.append_value(v.to_string()),
+                Some(PropertyValue::UInt(v)) => builder.append_value(v.to_string()),
+                Some(PropertyValue::Float(v)) => builder.append_value(v.to_string()),
+                Some(PropertyValue::DateTime(s)) => builder.append_value(s),
+                Some(PropertyValue::Binary(_)) | None => builder.append_null(),
+            }
+        }
+        Arc::new(builder.finish())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::LineStringArray;
+    use arrow_array::{Array, Float64Array, Int64Array, StringArray};
+    use std::fs::File;
+    use std::io::BufReader;
+
+    #[test]
+    fn read_roads() {
+        let table = read_geojson(BufReader::new(
+            File::open("fixtures/roads.geojson").unwrap(),
+        ))
+        .unwrap();
+        let batch = &table.batches()[0];
+        let schema = table.schema();
+        assert_eq!(table.geometry_column_index(), schema.fields().len() - 1);
+
+        let kind = schema.index_of("kind").unwrap();
+        let kind = batch.column(kind).as_any().downcast_ref::<StringArray>();
+        assert_eq!(kind.unwrap().value(0), "path");
+        let sort_key = schema.index_of("sort_key").unwrap();
+        assert!(batch
+            .column(sort_key)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .is_some());
+        // Properties that are null in every feature have no column
+        assert!(schema.index_of("railway").is_err());
+
+        let geometry: LineStringArray<i32> = batch
+            .column(table.geometry_column_index())
+            .as_ref()
+            .try_into()
+            .unwrap();
+        assert_eq!(geometry.len(), batch.num_rows());
+    }
+
+    #[test]
+    fn read_roads_simplified() {
+        let open = || BufReader::new(File::open("fixtures/roads.geojson").unwrap());
+        let table = read_geojson(open()).unwrap();
+        let options = ReadOptions {
+            simplify_epsilon: None,
+            max_coords_per_geometry: Some(2),
+        };
+        let simplified = read_geojson_with_options(open(), &options).unwrap();
+
+        let num_coords = |table: &GeoTable| -> usize {
+            let column = table.batches()[0].column(table.geometry_column_index());
+            let arr: LineStringArray<i32> = column.as_ref().try_into().unwrap();
+            arr.coords.len()
+        };
+        let dropped_vertices: usize = simplified.schema().metadata()[DROPPED_VERTICES_KEY]
+            .parse()
+            .unwrap();
+        assert!(dropped_vertices > 0);
+        assert_eq!(
+            num_coords(&table) - num_coords(&simplified),
+            dropped_vertices
+        );
+        assert!(!table.schema().metadata().contains_key(DROPPED_VERTICES_KEY));
+    }
+
+    #[test]
+    fn mixed_properties() {
+        let input = r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {"a": 1, "b": 1},
+             "geometry": {"type": "Point", "coordinates": [0, 0]}},
+            {"type": "Feature", "properties": {"b": "x", "c": true}, "geometry": null},
+            {"type": "Feature", "properties": {"a": 2},
+             "geometry": {"type": "MultiPoint", "coordinates": [[1, 1], [2, 2]]}}
+        ]}"#;
+        let table = read_geojson(input.as_bytes()).unwrap();
+        let batch = &table.batches()[0];
+        assert_eq!(batch.num_rows(), 3);
+
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(a, &Int64Array::from(vec![Some(1), None, Some(2)]));
+        let b = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(b, &StringArray::from(vec![Some("1"), Some("x"), None]));
+        assert_eq!(batch.column(2).null_count(), 2);
+
+        // A mix of points and multi points is read as multi points
+        let geometry = batch.column(table.geometry_column_index());
+        assert_eq!(geometry.len(), 3);
+        assert!(geometry.is_null(1));
+    }
+}
@@ -0,0 +1,144 @@
+//! Conversion between GeoArrow arrays and string arrays holding one GeoJSON geometry object per
+//! row.
+//!
+//! Note that these operate on bare geometry objects such as `{"type": "Point", ...}`, not on
+//! `Feature` or `FeatureCollection` documents.
+
+use crate::array::{GeometryArray, WKBArray};
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::GeoArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::builder::{GenericBinaryBuilder, GenericStringBuilder};
+use arrow_array::{Array, GenericStringArray, OffsetSizeTrait};
+use geozero::error::GeozeroError;
+use geozero::geojson::GeoJson;
+use geozero::{CoordDimensions, ToJson, ToWkb};
+
+/// The index and parse error of each row that could not be parsed.
+pub type RowErrors = Vec<(usize, GeozeroError)>;
+
+/// Parse a string array of GeoJSON geometry objects into a GeoArrow array, failing on the first
+/// row that cannot be parsed.
+///
+/// Null and empty strings become null geometries. The returned error names the offending row.
+pub fn from_geojson_strings<O: OffsetSizeTrait>(
+    arr: &GenericStringArray<O>,
+) -> Result<GeometryArray<O>> {
+    let mut wkb_builder = GenericBinaryBuilder::<O>::with_capacity(arr.len(), 0);
+
+    for (row, maybe_str) in arr.iter().enumerate() {
+        match parse_geojson_string(maybe_str) {
+            Ok(maybe_wkb) => wkb_builder.append_option(maybe_wkb),
+            Err(err) => {
+                return Err(GeoArrowError::General(format!(
+                    "Failed to parse GeoJSON geometry in row {}: {}",
+                    row, err
+                )))
+            }
+        }
+    }
+
+    WKBArray::new(wkb_builder.finish()).try_into()
+}
+
+/// Parse a string array of GeoJSON geometry objects into a GeoArrow array, setting rows that
+/// cannot be parsed to null.
+///
+/// Null and empty strings become null geometries. Alongside the parsed array, this returns the
+/// index and parse error of each row that failed.
+pub fn from_geojson_strings_lenient<O: OffsetSizeTrait>(
+    arr: &GenericStringArray<O>,
+) -> Result<(GeometryArray<O>, RowErrors)> {
+    let mut wkb_builder = GenericBinaryBuilder::<O>::with_capacity(arr.len(), 0);
+    let mut errors = vec![];
+
+    for (row, maybe_str) in arr.iter().enumerate() {
+        match parse_geojson_string(maybe_str) {
+            Ok(maybe_wkb) => wkb_builder.append_option(maybe_wkb),
+            Err(err) => {
+                wkb_builder.append_null();
+                errors.push((row, err));
+            }
+        }
+    }
+
+    let geometry_array = WKBArray::new(wkb_builder.finish()).try_into()?;
+    Ok((geometry_array, errors))
+}
+
+fn parse_geojson_string(maybe_str: Option<&str>) -> geozero::error::Result<Option<Vec<u8>>> {
+    match maybe_str {
+        Some(s) if !s.trim().is_empty() => Ok(Some(GeoJson(s).to_wkb(CoordDimensions::xy())?)),
+        _ => Ok(None),
+    }
+}
+
+/// Serialize each geometry of an array to a GeoJSON geometry object.
+pub trait ToGeoJsonStrings<O: OffsetSizeTrait> {
+    /// Convert to a string array with one GeoJSON geometry object per row. Null geometries
+    /// become null strings.
+    fn to_geojson_strings(&self) -> Result<GenericStringArray<O>>;
+}
+
+impl<O: OffsetSizeTrait> ToGeoJsonStrings<O> for GeometryArray<O> {
+    fn to_geojson_strings(&self) -> Result<GenericStringArray<O>> {
+        let mut output_array = GenericStringBuilder::<O>::with_capacity(self.len(), 0);
+
+        for maybe_geom in (0..self.len()).map(|i| self.get(i)) {
+            match maybe_geom {
+                Some(geom) => output_array.append_value(geom.to_json()?),
+                None => output_array.append_null(),
+            }
+        }
+
+        Ok(output_array.finish())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow_array::StringArray;
+
+    #[test]
+    fn roundtrip_points() {
+        let input = StringArray::from(vec![
+            Some(r#"{"type": "Point", "coordinates": [1.0, 2.0]}"#),
+            None,
+            Some(""),
+            Some(r#"{"type": "Point", "coordinates": [3.0, 4.0]}"#),
+        ]);
+        let geometry_array = from_geojson_strings(&input).unwrap();
+        let point_array = match &geometry_array {
+            GeometryArray::Point(arr) => arr,
+            _ => panic!("expected point array"),
+        };
+        assert_eq!(point_array.len(), 4);
+        assert!(point_array.is_null(1));
+        assert!(point_array.is_null(2));
+        assert_eq!(point_array.value_as_geo(3), geo::point!(x: 3.0, y: 4.0));
+
+        let output = geometry_array.to_geojson_strings().unwrap();
+        assert!(output.is_null(1));
+        let reparsed = from_geojson_strings(&output).unwrap();
+        assert_eq!(reparsed.value_as_geo(0), geometry_array.value_as_geo(0));
+        assert_eq!(reparsed.value_as_geo(3), geometry_array.value_as_geo(3));
+    }
+
+    #[test]
+    fn strict_and_lenient_errors() {
+        let input = StringArray::from(vec![
+            r#"{"type": "LineString", "coordinates": [[0.0, 1.0], [1.0, 2.0]]}"#,
+            r#"{"type": "LineString", "coordinates": "#,
+        ]);
+
+        let err = from_geojson_strings(&input).unwrap_err();
+        assert!(err.to_string().contains("row 1"));
+
+        let (geometry_array, errors) = from_geojson_strings_lenient(&input).unwrap();
+        assert_eq!(geometry_array.len(), 2);
+        assert!(geometry_array.is_null(1));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 1);
+    }
+}
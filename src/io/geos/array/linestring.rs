@@ -1,7 +1,7 @@
 use arrow_array::OffsetSizeTrait;
 
 use crate::array::linestring::mutable::{first_pass, second_pass};
-use crate::array::{LineStringArray, MutableLineStringArray};
+use crate::array::{Dimension, LineStringArray, MutableLineStringArray};
 use crate::error::GeoArrowError;
 use crate::io::geos::scalar::GEOSLineString;
 
@@ -25,6 +25,7 @@ impl<'a, O: OffsetSizeTrait> TryFrom<Vec<Option<geos::Geometry<'a>>>>
             geos_linestring_objects.into_iter(),
             coord_capacity,
             geom_capacity,
+            Dimension::XY,
         ))
     }
 }
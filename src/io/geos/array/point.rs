@@ -1,5 +1,5 @@
 use crate::array::point::mutable::from_nullable_coords;
-use crate::array::{MutablePointArray, PointArray};
+use crate::array::{Dimension, MutablePointArray, PointArray};
 use crate::error::GeoArrowError;
 use crate::io::geos::scalar::GEOSPoint;
 
@@ -16,6 +16,7 @@ impl<'a> TryFrom<Vec<Option<geos::Geometry<'a>>>> for MutablePointArray {
         Ok(from_nullable_coords(
             geos_linestring_objects.iter().map(|item| item.as_ref()),
             length,
+            Dimension::XY,
         ))
     }
 }
@@ -1,7 +1,7 @@
 use arrow_array::OffsetSizeTrait;
 use bumpalo::collections::CollectIn;
 
-use crate::array::{MutablePolygonArray, PolygonArray};
+use crate::array::{CoordType, MutablePolygonArray, PolygonArray, PolygonCapacity};
 use crate::error::{GeoArrowError, Result};
 use crate::io::geos::scalar::GEOSPolygon;
 use geos::Geom;
@@ -99,9 +99,10 @@ fn second_pass<'a, O: OffsetSizeTrait>(
     coord_capacity: usize,
     ring_capacity: usize,
     geom_capacity: usize,
+    coord_type: CoordType,
 ) -> MutablePolygonArray<O> {
-    let mut array =
-        MutablePolygonArray::with_capacities(coord_capacity, ring_capacity, geom_capacity);
+    let capacity = PolygonCapacity::new(coord_capacity, ring_capacity, geom_capacity);
+    let mut array = MutablePolygonArray::with_capacity_and_coord_type(capacity, coord_type);
 
     geoms
         .into_iter()
@@ -111,10 +112,12 @@ fn second_pass<'a, O: OffsetSizeTrait>(
     array
 }
 
-impl<O: OffsetSizeTrait> TryFrom<Vec<Option<geos::Geometry<'_>>>> for MutablePolygonArray<O> {
-    type Error = GeoArrowError;
-
-    fn try_from(value: Vec<Option<geos::Geometry<'_>>>) -> Result<Self> {
+impl<O: OffsetSizeTrait> MutablePolygonArray<O> {
+    /// Build an array from GEOS polygons, storing coordinates in the given layout.
+    pub(crate) fn from_geos(
+        value: Vec<Option<geos::Geometry<'_>>>,
+        coord_type: CoordType,
+    ) -> Result<Self> {
         let length = value.len();
         // TODO: don't use new_unchecked
         let geos_objects: Vec<Option<GEOSPolygon>> = value
@@ -128,10 +131,19 @@ impl<O: OffsetSizeTrait> TryFrom<Vec<Option<geos::Geometry<'_>>>> for MutablePol
             coord_capacity,
             ring_capacity,
             geom_capacity,
+            coord_type,
         ))
     }
 }
 
+impl<O: OffsetSizeTrait> TryFrom<Vec<Option<geos::Geometry<'_>>>> for MutablePolygonArray<O> {
+    type Error = GeoArrowError;
+
+    fn try_from(value: Vec<Option<geos::Geometry<'_>>>) -> Result<Self> {
+        Self::from_geos(value, CoordType::Interleaved)
+    }
+}
+
 impl<O: OffsetSizeTrait> TryFrom<Vec<Option<geos::Geometry<'_>>>> for PolygonArray<O> {
     type Error = GeoArrowError;
 
@@ -163,6 +175,7 @@ impl<'a, O: OffsetSizeTrait> TryFrom<bumpalo::collections::Vec<'a, Option<geos::
             coord_capacity,
             ring_capacity,
             geom_capacity,
+            CoordType::Interleaved,
         ))
     }
 }
@@ -9,6 +9,7 @@ impl TryFrom<CoordBuffer> for CoordSeq<'_> {
         match value {
             CoordBuffer::Separated(cb) => cb.try_into(),
             CoordBuffer::Interleaved(cb) => cb.try_into(),
+            CoordBuffer::Float32(cb) => cb.to_float64().try_into(),
         }
     }
 }
@@ -1,7 +1,7 @@
 use arrow_array::OffsetSizeTrait;
 use geozero::{GeomProcessor, GeozeroGeometry};
 
-use crate::array::{LineStringArray, MutableLineStringArray};
+use crate::array::{LineStringArray, LineStringCapacity, MutableLineStringArray};
 use crate::io::geozero::scalar::linestring::process_line_string;
 use crate::trait_::GeoArrayAccessor;
 use crate::GeometryArrayTrait;
@@ -47,7 +47,10 @@ impl<T: GeozeroGeometry, O: OffsetSizeTrait> ToGeoArrowLineStringArray<O> for T
 #[allow(unused_variables)]
 impl<O: OffsetSizeTrait> GeomProcessor for MutableLineStringArray<O> {
     fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
-        self.reserve(0, size);
+        self.reserve(LineStringCapacity {
+            geom: size,
+            ..Default::default()
+        });
         Ok(())
     }
 
@@ -70,7 +73,10 @@ impl<O: OffsetSizeTrait> GeomProcessor for MutableLineStringArray<O> {
         size: usize,
         idx: usize,
     ) -> geozero::error::Result<()> {
-        self.reserve(size, 0);
+        self.reserve(LineStringCapacity {
+            coord: size,
+            ..Default::default()
+        });
         self.try_push_length(size).unwrap();
         Ok(())
     }
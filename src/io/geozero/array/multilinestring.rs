@@ -1,7 +1,7 @@
 use arrow_array::OffsetSizeTrait;
 use geozero::{GeomProcessor, GeozeroGeometry};
 
-use crate::array::{MultiLineStringArray, MutableMultiLineStringArray};
+use crate::array::{MultiLineStringArray, MultiLineStringCapacity, MutableMultiLineStringArray};
 use crate::io::geozero::scalar::multilinestring::process_multi_line_string;
 use crate::trait_::GeoArrayAccessor;
 use crate::GeometryArrayTrait;
@@ -52,7 +52,10 @@ impl<T: GeozeroGeometry, O: OffsetSizeTrait> ToGeoArrowMultiLineStringArray<O> f
 impl<O: OffsetSizeTrait> GeomProcessor for MutableMultiLineStringArray<O> {
     fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
         // reserve `size` geometries
-        self.reserve(0, 0, size);
+        self.reserve(MultiLineStringCapacity {
+            geom: size,
+            ..Default::default()
+        });
         Ok(())
     }
 
@@ -72,7 +75,10 @@ impl<O: OffsetSizeTrait> GeomProcessor for MutableMultiLineStringArray<O> {
     // Here, size is the number of LineStrings in the MultiLineString
     fn multilinestring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
         // reserve `size` line strings
-        self.reserve(0, size, 0);
+        self.reserve(MultiLineStringCapacity {
+            ring: size,
+            ..Default::default()
+        });
 
         // # Safety:
         // This upholds invariants because we separately update the ring offsets in
@@ -91,7 +97,10 @@ impl<O: OffsetSizeTrait> GeomProcessor for MutableMultiLineStringArray<O> {
         // So if tagged, we need to update the geometry offsets array.
         if tagged {
             // reserve 1 line strings
-            self.reserve(0, 1, 0);
+            self.reserve(MultiLineStringCapacity {
+                ring: 1,
+                ..Default::default()
+            });
 
             // # Safety:
             // This upholds invariants because we separately update the ring offsets in
@@ -100,7 +109,10 @@ impl<O: OffsetSizeTrait> GeomProcessor for MutableMultiLineStringArray<O> {
         }
 
         // reserve `size` coordinates
-        self.reserve(size, 0, 0);
+        self.reserve(MultiLineStringCapacity {
+            coord: size,
+            ..Default::default()
+        });
 
         // # Safety:
         // This upholds invariants because we separately update the geometry offsets in
@@ -1,4 +1,4 @@
-use crate::array::{MultiPointArray, MutableMultiPointArray};
+use crate::array::{MultiPointArray, MultiPointCapacity, MutableMultiPointArray};
 use crate::io::geozero::scalar::multipoint::process_multi_point;
 use crate::trait_::GeoArrayAccessor;
 use crate::GeometryArrayTrait;
@@ -46,7 +46,10 @@ impl<T: GeozeroGeometry, O: OffsetSizeTrait> ToGeoArrowMultiPointArray<O> for T
 #[allow(unused_variables)]
 impl<O: OffsetSizeTrait> GeomProcessor for MutableMultiPointArray<O> {
     fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
-        self.reserve(0, size);
+        self.reserve(MultiPointCapacity {
+            geom: size,
+            ..Default::default()
+        });
         Ok(())
     }
 
@@ -59,7 +62,10 @@ impl<O: OffsetSizeTrait> GeomProcessor for MutableMultiPointArray<O> {
     }
 
     fn point_begin(&mut self, idx: usize) -> geozero::error::Result<()> {
-        self.reserve(1, 0);
+        self.reserve(MultiPointCapacity {
+            coord: 1,
+            ..Default::default()
+        });
         self.try_push_length(1).unwrap();
         Ok(())
     }
@@ -69,7 +75,10 @@ impl<O: OffsetSizeTrait> GeomProcessor for MutableMultiPointArray<O> {
     }
 
     fn multipoint_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
-        self.reserve(size, 0);
+        self.reserve(MultiPointCapacity {
+            coord: size,
+            ..Default::default()
+        });
         self.try_push_length(size).unwrap();
         Ok(())
     }
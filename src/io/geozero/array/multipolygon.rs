@@ -1,7 +1,7 @@
 use arrow_array::OffsetSizeTrait;
 use geozero::{GeomProcessor, GeozeroGeometry};
 
-use crate::array::{MultiPolygonArray, MutableMultiPolygonArray};
+use crate::array::{MultiPolygonArray, MultiPolygonCapacity, MutableMultiPolygonArray};
 use crate::io::geozero::scalar::multipolygon::process_multi_polygon;
 use crate::trait_::GeoArrayAccessor;
 use crate::GeometryArrayTrait;
@@ -48,7 +48,10 @@ impl<T: GeozeroGeometry, O: OffsetSizeTrait> ToGeoArrowMultiPolygonArray<O> for
 impl<O: OffsetSizeTrait> GeomProcessor for MutableMultiPolygonArray<O> {
     fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
         // reserve `size` geometries
-        self.reserve(0, 0, 0, size);
+        self.reserve(MultiPolygonCapacity {
+            geom: size,
+            ..Default::default()
+        });
         Ok(())
     }
 
@@ -67,7 +70,10 @@ impl<O: OffsetSizeTrait> GeomProcessor for MutableMultiPolygonArray<O> {
 
     fn multipolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
         // reserve `size` polygons
-        self.reserve(0, 0, size, 0);
+        self.reserve(MultiPolygonCapacity {
+            polygon: size,
+            ..Default::default()
+        });
 
         // # Safety:
         // This upholds invariants because we separately update the ring offsets in
@@ -85,7 +91,10 @@ impl<O: OffsetSizeTrait> GeomProcessor for MutableMultiPolygonArray<O> {
         // > An untagged Polygon is part of a MultiPolygon
         if tagged {
             // reserve 1 polygon
-            self.reserve(0, 0, 1, 0);
+            self.reserve(MultiPolygonCapacity {
+                polygon: 1,
+                ..Default::default()
+            });
 
             // # Safety:
             // This upholds invariants because we separately update the ring offsets in
@@ -94,7 +103,10 @@ impl<O: OffsetSizeTrait> GeomProcessor for MutableMultiPolygonArray<O> {
         }
 
         // reserve `size` rings
-        self.reserve(0, size, 0, 0);
+        self.reserve(MultiPolygonCapacity {
+            ring: size,
+            ..Default::default()
+        });
 
         // # Safety:
         // This upholds invariants because we separately update the geometry offsets in
@@ -112,7 +124,10 @@ impl<O: OffsetSizeTrait> GeomProcessor for MutableMultiPolygonArray<O> {
         assert!(!tagged);
 
         // reserve `size` coordinates
-        self.reserve(size, 0, 0, 0);
+        self.reserve(MultiPolygonCapacity {
+            coord: size,
+            ..Default::default()
+        });
 
         // # Safety:
         // This upholds invariants because we separately update the ring offsets in
@@ -120,6 +135,13 @@ impl<O: OffsetSizeTrait> GeomProcessor for MutableMultiPolygonArray<O> {
         unsafe { self.try_push_ring_offset(size).unwrap() }
         Ok(())
     }
+
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        if self.close_rings {
+            self.close_last_ring().unwrap();
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
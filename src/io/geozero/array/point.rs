@@ -60,7 +60,7 @@ impl GeomProcessor for MutablePointArray {
     }
 
     fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
-        self.reserve_exact(size);
+        self.reserve(size);
         Ok(())
     }
 
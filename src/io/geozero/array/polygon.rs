@@ -1,4 +1,4 @@
-use crate::array::{MutablePolygonArray, PolygonArray};
+use crate::array::{MutablePolygonArray, PolygonArray, PolygonCapacity};
 use crate::io::geozero::scalar::polygon::process_polygon;
 use crate::trait_::GeoArrayAccessor;
 use crate::GeometryArrayTrait;
@@ -47,7 +47,10 @@ impl<T: GeozeroGeometry, O: OffsetSizeTrait> ToGeoArrowPolygonArray<O> for T {
 impl<O: OffsetSizeTrait> GeomProcessor for MutablePolygonArray<O> {
     fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
         // reserve `size` geometries
-        self.reserve(0, 0, size);
+        self.reserve(PolygonCapacity {
+            geom: size,
+            ..Default::default()
+        });
         Ok(())
     }
 
@@ -72,7 +75,10 @@ impl<O: OffsetSizeTrait> GeomProcessor for MutablePolygonArray<O> {
         idx: usize,
     ) -> geozero::error::Result<()> {
         // reserve `size` rings
-        self.reserve(0, size, 0);
+        self.reserve(PolygonCapacity {
+            ring: size,
+            ..Default::default()
+        });
 
         // # Safety:
         // This upholds invariants because we separately update the ring offsets in
@@ -88,7 +94,10 @@ impl<O: OffsetSizeTrait> GeomProcessor for MutablePolygonArray<O> {
         idx: usize,
     ) -> geozero::error::Result<()> {
         // reserve `size` coordinates
-        self.reserve(size, 0, 0);
+        self.reserve(PolygonCapacity {
+            coord: size,
+            ..Default::default()
+        });
 
         // # Safety:
         // This upholds invariants because we separately update the geometry offsets in
@@ -96,6 +105,13 @@ impl<O: OffsetSizeTrait> GeomProcessor for MutablePolygonArray<O> {
         unsafe { self.try_push_ring_offset(size).unwrap() }
         Ok(())
     }
+
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        if self.close_rings {
+            self.close_last_ring().unwrap();
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -129,4 +145,40 @@ mod test {
         assert_eq!(multi_point_array.value_as_geo(1), p1());
         Ok(())
     }
+
+    #[test]
+    fn from_geozero_reserves_size_hints() -> Result<()> {
+        let geo = Geometry::GeometryCollection(
+            vec![p0(), p1()]
+                .into_iter()
+                .map(Geometry::Polygon)
+                .collect(),
+        );
+        let mutable_array: MutablePolygonArray<i32> = geo.to_mutable_line_string_array()?;
+        let capacity = mutable_array.capacity();
+        assert!(capacity.geom >= 2);
+        assert!(capacity.ring >= 3);
+        assert!(capacity.coord >= 15);
+        Ok(())
+    }
+
+    #[test]
+    fn from_geozero_close_rings() -> Result<()> {
+        // Converting to geo objects would close the rings anyway, so check the stored offsets
+        let wkt = geozero::wkt::WktStr("POLYGON((0 0,4 0,4 4,0 4),(1 1,2 1,2 2,1 1))");
+
+        let mut mutable_array = MutablePolygonArray::<i32>::new().with_close_rings(true);
+        wkt.process_geom(&mut mutable_array)?;
+        let array: PolygonArray<i32> = mutable_array.into();
+        // Already closed rings are unchanged
+        assert_eq!(array.ring_offsets.as_ref(), &[0, 5, 9]);
+        assert_eq!(array.coords.get_x(4), 0.);
+        assert_eq!(array.coords.get_y(4), 0.);
+
+        let mut mutable_array = MutablePolygonArray::<i32>::new();
+        wkt.process_geom(&mut mutable_array)?;
+        let array: PolygonArray<i32> = mutable_array.into();
+        assert_eq!(array.ring_offsets.as_ref(), &[0, 4, 8]);
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 pub mod array;
 pub mod scalar;
+pub(crate) mod simplify;
 pub mod table;
 
 pub use array::ToGeoArrowLineStringArray;
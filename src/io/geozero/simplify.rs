@@ -0,0 +1,399 @@
+//! Simplification of geometries while they are being read.
+
+use geo::{Coord, LineString, Simplify};
+use geozero::error::Result;
+use geozero::{ColumnValue, CoordDimensions, FeatureProcessor, GeomProcessor, PropertyProcessor};
+
+/// The schema metadata key under which readers report the number of vertices dropped by
+/// [`ReadOptions`].
+pub const DROPPED_VERTICES_KEY: &str = "geoarrow:dropped_vertices";
+
+/// Options for reducing the size of geometries while reading them.
+///
+/// Both options apply to each line string and polygon ring separately, before its coordinates are
+/// added to the output array, so full-resolution geometries are never all held in memory at once.
+/// Line strings keep at least 2 vertices and rings keep at least 4, so rings stay closed.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ReadOptions {
+    /// Simplify with the Ramer–Douglas–Peucker algorithm, using this epsilon in units of the
+    /// coordinates.
+    pub simplify_epsilon: Option<f64>,
+
+    /// Keep at most this many evenly-spaced vertices, including the first and last, after
+    /// simplification.
+    pub max_coords_per_geometry: Option<usize>,
+}
+
+impl ReadOptions {
+    /// Whether these options can drop any vertices.
+    pub fn is_lossy(&self) -> bool {
+        self.simplify_epsilon.is_some() || self.max_coords_per_geometry.is_some()
+    }
+
+    /// Reduce the vertices of one line string or ring.
+    fn reduce(&self, coords: Vec<Coord>, is_ring: bool) -> Vec<Coord> {
+        let min_coords = if is_ring { 4 } else { 2 };
+        if coords.len() <= min_coords {
+            return coords;
+        }
+
+        let mut coords = coords;
+        if let Some(epsilon) = self.simplify_epsilon {
+            let simplified = LineString::new(coords.clone()).simplify(&epsilon).0;
+            // Simplification can collapse a ring entirely, in which case we keep the original
+            if simplified.len() >= min_coords {
+                coords = simplified;
+            }
+        }
+
+        if let Some(max_coords) = self.max_coords_per_geometry {
+            let max_coords = max_coords.max(min_coords);
+            if coords.len() > max_coords {
+                // Since there are more coords than max_coords, these indices are distinct and
+                // include the first and last coord
+                let last = coords.len() - 1;
+                coords = (0..max_coords)
+                    .map(|i| coords[i * last / (max_coords - 1)])
+                    .collect();
+            }
+        }
+
+        coords
+    }
+}
+
+/// A [`GeomProcessor`] adapter that applies [`ReadOptions`] to each line string and ring before
+/// passing it on to the wrapped processor.
+///
+/// Coordinates of a line string are buffered until its end, because the wrapped processor must
+/// be told the final number of vertices up front. All other events are passed through unchanged.
+pub(crate) struct SimplifyProcessor<'a, P> {
+    inner: &'a mut P,
+    options: ReadOptions,
+    /// `tagged` and `idx` of the line string currently being buffered
+    line_string: Option<(bool, usize)>,
+    in_polygon: bool,
+    coords: Vec<Coord>,
+    dropped_vertices: usize,
+}
+
+impl<'a, P: GeomProcessor> SimplifyProcessor<'a, P> {
+    pub fn new(inner: &'a mut P, options: ReadOptions) -> Self {
+        Self {
+            inner,
+            options,
+            line_string: None,
+            in_polygon: false,
+            coords: vec![],
+            dropped_vertices: 0,
+        }
+    }
+
+    /// The total number of vertices dropped so far.
+    pub fn dropped_vertices(&self) -> usize {
+        self.dropped_vertices
+    }
+
+    fn buffer_xy(&mut self, x: f64, y: f64) -> bool {
+        if self.line_string.is_some() {
+            self.coords.push(Coord { x, y });
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<P: GeomProcessor> GeomProcessor for SimplifyProcessor<'_, P> {
+    fn dimensions(&self) -> CoordDimensions {
+        self.inner.dimensions()
+    }
+
+    fn multi_dim(&self) -> bool {
+        self.inner.multi_dim()
+    }
+
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.inner.srid(srid)
+    }
+
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        if self.buffer_xy(x, y) {
+            return Ok(());
+        }
+        self.inner.xy(x, y, idx)
+    }
+
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        // Only x and y survive simplification, like in the GeoArrow arrays we read into
+        if self.buffer_xy(x, y) {
+            return Ok(());
+        }
+        self.inner.coordinate(x, y, z, m, t, tm, idx)
+    }
+
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        self.inner.empty_point(idx)
+    }
+
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        self.inner.point_begin(idx)
+    }
+
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.point_end(idx)
+    }
+
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.multipoint_begin(size, idx)
+    }
+
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.multipoint_end(idx)
+    }
+
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.line_string = Some((tagged, idx));
+        self.coords.clear();
+        self.coords.reserve(size);
+        Ok(())
+    }
+
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.line_string = None;
+        let coords = std::mem::take(&mut self.coords);
+        let original_len = coords.len();
+        let coords = self.options.reduce(coords, self.in_polygon);
+        self.dropped_vertices += original_len - coords.len();
+
+        self.inner.linestring_begin(tagged, coords.len(), idx)?;
+        for (coord_idx, coord) in coords.iter().enumerate() {
+            self.inner.xy(coord.x, coord.y, coord_idx)?;
+        }
+        self.inner.linestring_end(tagged, idx)
+    }
+
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.multilinestring_begin(size, idx)
+    }
+
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.multilinestring_end(idx)
+    }
+
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.inner.polygon_begin(tagged, size, idx)
+    }
+
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        self.inner.polygon_end(tagged, idx)
+    }
+
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.multipolygon_begin(size, idx)
+    }
+
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.multipolygon_end(idx)
+    }
+
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.geometrycollection_begin(size, idx)
+    }
+
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.geometrycollection_end(idx)
+    }
+
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.circularstring_begin(size, idx)
+    }
+
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.circularstring_end(idx)
+    }
+
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.compoundcurve_begin(size, idx)
+    }
+
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.compoundcurve_end(idx)
+    }
+
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.curvepolygon_begin(size, idx)
+    }
+
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.curvepolygon_end(idx)
+    }
+
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.multicurve_begin(size, idx)
+    }
+
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.multicurve_end(idx)
+    }
+
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.multisurface_begin(size, idx)
+    }
+
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.multisurface_end(idx)
+    }
+
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.inner.triangle_begin(tagged, size, idx)
+    }
+
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.inner.triangle_end(tagged, idx)
+    }
+
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.polyhedralsurface_begin(size, idx)
+    }
+
+    fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.polyhedralsurface_end(idx)
+    }
+
+    fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.inner.tin_begin(size, idx)
+    }
+
+    fn tin_end(&mut self, idx: usize) -> Result<()> {
+        self.inner.tin_end(idx)
+    }
+}
+
+impl<P: GeomProcessor + PropertyProcessor> PropertyProcessor for SimplifyProcessor<'_, P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.inner.property(idx, name, value)
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for SimplifyProcessor<'_, P> {
+    fn dataset_begin(&mut self, name: Option<&str>) -> Result<()> {
+        self.inner.dataset_begin(name)
+    }
+
+    fn dataset_end(&mut self) -> Result<()> {
+        self.inner.dataset_end()
+    }
+
+    fn feature_begin(&mut self, idx: u64) -> Result<()> {
+        self.inner.feature_begin(idx)
+    }
+
+    fn feature_end(&mut self, idx: u64) -> Result<()> {
+        self.inner.feature_end(idx)
+    }
+
+    fn properties_begin(&mut self) -> Result<()> {
+        self.inner.properties_begin()
+    }
+
+    fn properties_end(&mut self) -> Result<()> {
+        self.inner.properties_end()
+    }
+
+    fn geometry_begin(&mut self) -> Result<()> {
+        self.inner.geometry_begin()
+    }
+
+    fn geometry_end(&mut self) -> Result<()> {
+        self.inner.geometry_end()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{MutablePolygonArray, PolygonArray};
+    use crate::trait_::GeoArrayAccessor;
+    use geozero::GeozeroGeometry;
+
+    /// A closed ring approximating a circle with `n` vertices.
+    fn circle(n: usize) -> geo::Polygon {
+        let mut coords: Vec<Coord> = (0..n)
+            .map(|i| {
+                let angle = i as f64 / n as f64 * std::f64::consts::TAU;
+                Coord {
+                    x: angle.cos(),
+                    y: angle.sin(),
+                }
+            })
+            .collect();
+        coords.push(coords[0]);
+        geo::Polygon::new(LineString::new(coords), vec![])
+    }
+
+    fn read(polygon: &geo::Polygon, options: ReadOptions) -> (PolygonArray<i32>, usize) {
+        let mut array = MutablePolygonArray::<i32>::new();
+        let mut processor = SimplifyProcessor::new(&mut array, options);
+        geo::Geometry::Polygon(polygon.clone())
+            .process_geom(&mut processor)
+            .unwrap();
+        let dropped_vertices = processor.dropped_vertices();
+        (array.into(), dropped_vertices)
+    }
+
+    #[test]
+    fn simplify_ring() {
+        let polygon = circle(1000);
+        let (arr, dropped_vertices) = read(
+            &polygon,
+            ReadOptions {
+                simplify_epsilon: Some(0.01),
+                ..Default::default()
+            },
+        );
+
+        let ring = arr.value_as_geo(0).exterior().clone();
+        assert!(ring.0.len() < 100);
+        assert!(ring.0.len() >= 4);
+        assert!(ring.is_closed());
+        assert_eq!(dropped_vertices, 1001 - ring.0.len());
+    }
+
+    #[test]
+    fn decimate_ring() {
+        let polygon = circle(1000);
+        let (arr, dropped_vertices) = read(
+            &polygon,
+            ReadOptions {
+                max_coords_per_geometry: Some(2),
+                ..Default::default()
+            },
+        );
+
+        // Rings keep at least 4 vertices, regardless of the limit
+        let ring = arr.value_as_geo(0).exterior().clone();
+        assert_eq!(ring.0.len(), 4);
+        assert!(ring.is_closed());
+        assert_eq!(dropped_vertices, 1001 - 4);
+    }
+
+    #[test]
+    fn no_options_is_lossless() {
+        let polygon = circle(100);
+        let (arr, dropped_vertices) = read(&polygon, ReadOptions::default());
+        assert_eq!(arr.value_as_geo(0), polygon);
+        assert_eq!(dropped_vertices, 0);
+    }
+}
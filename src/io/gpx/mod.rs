@@ -0,0 +1,5 @@
+//! Read the [GPX](https://www.topografix.com/gpx.asp) format.
+
+mod reader;
+
+pub use reader::read_gpx;
@@ -0,0 +1,244 @@
+//! Reader for converting GPX files to GeoArrow arrays
+
+use crate::array::{CoordType, Dimension, MixedGeometryArray, MutableMixedGeometryArray};
+use crate::datatypes::GeoDataType;
+use crate::error::Result;
+use crate::io::wkt::parse::GeometryArrayBuilder;
+use crate::table::GeoTable;
+use crate::GeometryArrayTrait;
+use arrow_array::builder::{Float64Builder, ListBuilder, StringBuilder};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{DataType, Field, FieldRef, Schema};
+use gpx::Waypoint;
+use std::io::Read;
+use std::sync::Arc;
+
+/// Read the waypoints, routes and tracks of a GPX document into a [`GeoTable`].
+///
+/// Each waypoint, each route and each track segment becomes one row, in that order. Waypoints are
+/// points and routes and track segments are linestrings. When a document has both, the geometry
+/// column is a mixed geometry array. Otherwise it is a point or linestring array.
+///
+/// The table has one row per geometry with the columns:
+///
+/// - `kind` (`Utf8`): `"waypoint"`, `"route"` or `"track"`
+/// - `name` (`Utf8`): the name of the waypoint, route or track, if any
+/// - `elevation` (`List<Float64>`): the elevation of each vertex
+/// - `time` (`List<Utf8>`): the RFC 3339 timestamp of each vertex
+/// - `geometry`: the waypoint, route or track segment
+pub fn read_gpx(reader: impl Read) -> Result<GeoTable> {
+    let gpx = gpx::read(reader)?;
+
+    let mut geometries = vec![];
+    let mut kind = StringBuilder::new();
+    let mut name = StringBuilder::new();
+    let mut elevation = ListBuilder::new(Float64Builder::new());
+    let mut time = ListBuilder::new(StringBuilder::new());
+
+    let mut push_row =
+        |row_kind: &str, row_name: Option<&str>, geom: geo::Geometry, points: &[Waypoint]| {
+            kind.append_value(row_kind);
+            name.append_option(row_name);
+            for point in points {
+                elevation.values().append_option(point.elevation);
+                time.values()
+                    .append_option(point.time.as_ref().and_then(|t| t.format().ok()));
+            }
+            elevation.append(true);
+            time.append(true);
+            geometries.push(geom);
+        };
+
+    for waypoint in gpx.waypoints.iter() {
+        let geom = geo::Geometry::Point(waypoint.point());
+        let points = std::slice::from_ref(waypoint);
+        push_row("waypoint", waypoint.name.as_deref(), geom, points);
+    }
+
+    let line_string = |points: &[Waypoint]| {
+        geo::Geometry::LineString(geo::LineString::from_iter(
+            points.iter().map(|point| point.point().0),
+        ))
+    };
+
+    for route in gpx.routes.iter() {
+        let geom = line_string(&route.points);
+        push_row("route", route.name.as_deref(), geom, &route.points);
+    }
+
+    for track in gpx.tracks.iter() {
+        for segment in track.segments.iter() {
+            let geom = line_string(&segment.points);
+            push_row("track", track.name.as_deref(), geom, &segment.points);
+        }
+    }
+
+    let has_lines = !gpx.routes.is_empty() || gpx.tracks.iter().any(|t| !t.segments.is_empty());
+    let (geometry_field, geometry) = if !gpx.waypoints.is_empty() && has_lines {
+        let mut builder = MutableMixedGeometryArray::<i32>::new();
+        for geom in geometries.iter() {
+            builder.push_geometry(geom)?;
+        }
+        MixedGeometryArray::from(builder).into_array_ref_with_field("geometry")
+    } else {
+        let target = if has_lines {
+            GeoDataType::LineString(CoordType::Interleaved, Dimension::XY)
+        } else {
+            GeoDataType::Point(CoordType::Interleaved, Dimension::XY)
+        };
+        let mut builder = GeometryArrayBuilder::<i32>::new(Some(&target))?;
+        for geom in geometries {
+            builder.push(Some(geom))?;
+        }
+        builder.finish()?.into_array_ref_with_field("geometry")
+    };
+
+    let fields: Vec<FieldRef> = vec![
+        Arc::new(Field::new("kind", DataType::Utf8, false)),
+        Arc::new(Field::new("name", DataType::Utf8, true)),
+        Arc::new(Field::new(
+            "elevation",
+            DataType::List(Arc::new(Field::new("item", DataType::Float64, true))),
+            false,
+        )),
+        Arc::new(Field::new(
+            "time",
+            DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+            false,
+        )),
+        geometry_field,
+    ];
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(kind.finish()),
+        Arc::new(name.finish()),
+        Arc::new(elevation.finish()),
+        Arc::new(time.finish()),
+        geometry,
+    ];
+    let schema = Arc::new(Schema::new(fields));
+    let batch = RecordBatch::try_new(schema.clone(), columns)?;
+    GeoTable::try_new(schema, vec![batch], 4)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{LineStringArray, PointArray};
+    use crate::trait_::GeoArrayAccessor;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Float64Type;
+    use arrow_array::{Array, UnionArray};
+    use geo::{line_string, point};
+
+    const TRACK: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="geoarrow" xmlns="http://www.topografix.com/GPX/1/1">
+  <trk>
+    <name>Morning run</name>
+    <trkseg>
+      <trkpt lat="45.0" lon="-111.0"><ele>1500.0</ele></trkpt>
+      <trkpt lat="45.1" lon="-111.1"><ele>1510.5</ele></trkpt>
+      <trkpt lat="45.2" lon="-111.2"><ele>1520.0</ele></trkpt>
+    </trkseg>
+  </trk>
+</gpx>"#;
+
+    #[test]
+    fn read_track() {
+        let table = read_gpx(TRACK.as_bytes()).unwrap();
+        let batch = &table.batches()[0];
+        let geometry = batch.column(table.geometry_column_index());
+        let line_strings = LineStringArray::<i32>::try_from(geometry.as_ref()).unwrap();
+        assert_eq!(line_strings.len(), 1);
+        assert_eq!(
+            line_strings.value_as_geo(0),
+            line_string![
+                (x: -111.0, y: 45.0),
+                (x: -111.1, y: 45.1),
+                (x: -111.2, y: 45.2),
+            ]
+        );
+
+        assert_eq!(batch.num_rows(), 1);
+        assert_eq!(batch.column(0).as_string::<i32>().value(0), "track");
+        assert_eq!(batch.column(1).as_string::<i32>().value(0), "Morning run");
+        let elevation = batch.column(2).as_list::<i32>().value(0);
+        assert_eq!(
+            elevation.as_primitive::<Float64Type>().values().as_ref(),
+            &[1500.0, 1510.5, 1520.0]
+        );
+    }
+
+    #[test]
+    fn read_waypoints_routes_and_tracks() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="geoarrow" xmlns="http://www.topografix.com/GPX/1/1">
+  <wpt lat="45.5" lon="-111.5"><ele>1600.0</ele><name>Summit</name></wpt>
+  <rte>
+    <rtept lat="45.0" lon="-111.0"/>
+    <rtept lat="45.5" lon="-111.5"/>
+  </rte>
+  <trk>
+    <trkseg>
+      <trkpt lat="45.0" lon="-111.0"/>
+      <trkpt lat="45.1" lon="-111.1"/>
+    </trkseg>
+  </trk>
+</gpx>"#;
+        let table = read_gpx(input.as_bytes()).unwrap();
+        let batch = &table.batches()[0];
+        let geometry = batch.column(table.geometry_column_index());
+        let geometry = geometry.as_any().downcast_ref::<UnionArray>().unwrap();
+        let DataType::Union(union_fields, _) = geometry.data_type() else {
+            panic!("expected a union array");
+        };
+        let row_types: Vec<&str> = (0..geometry.len())
+            .map(|i| {
+                let (_, field) = union_fields
+                    .iter()
+                    .find(|(type_id, _)| *type_id == geometry.type_id(i))
+                    .unwrap();
+                field.metadata()["ARROW:extension:name"].as_str()
+            })
+            .collect();
+        assert_eq!(
+            row_types,
+            vec![
+                "geoarrow.point",
+                "geoarrow.linestring",
+                "geoarrow.linestring"
+            ]
+        );
+        let point = PointArray::try_from(geometry.value(0).as_ref()).unwrap();
+        assert_eq!(point.value_as_geo(0), point!(x: -111.5, y: 45.5));
+        let track = LineStringArray::<i32>::try_from(geometry.value(2).as_ref()).unwrap();
+        assert_eq!(
+            track.value_as_geo(0),
+            line_string![(x: -111.0, y: 45.0), (x: -111.1, y: 45.1)]
+        );
+
+        let kind = batch.column(0).as_string::<i32>();
+        assert_eq!(
+            kind.iter().collect::<Vec<_>>(),
+            vec![Some("waypoint"), Some("route"), Some("track")]
+        );
+        assert_eq!(batch.column(1).as_string::<i32>().value(0), "Summit");
+        let elevation = batch.column(2).as_list::<i32>().value(0);
+        assert_eq!(
+            elevation.as_primitive::<Float64Type>().values().as_ref(),
+            &[1600.0]
+        );
+    }
+
+    #[test]
+    fn read_waypoints_only() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="geoarrow" xmlns="http://www.topografix.com/GPX/1/1">
+  <wpt lat="45.5" lon="-111.5"><name>Summit</name></wpt>
+</gpx>"#;
+        let table = read_gpx(input.as_bytes()).unwrap();
+        let batch = &table.batches()[0];
+        let geometry = batch.column(table.geometry_column_index());
+        assert!(PointArray::try_from(geometry.as_ref()).is_ok());
+        assert_eq!(batch.num_rows(), 1);
+    }
+}
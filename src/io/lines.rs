@@ -0,0 +1,408 @@
+//! Read files with one geometry per line, as WKT or as hex-encoded WKB.
+//!
+//! Lines are read one at a time, and each geometry is pushed into a growable mutable array as
+//! soon as it is parsed, so the size of the input doesn't need to be known up front. Besides the
+//! output, only the current line and its geometry are held in memory. When the geometry type is
+//! inferred rather than given, the rows read before the type widens, e.g. from points to multi
+//! points, are copied once more at the end to cast them to the final type. Compressed input can
+//! be read by passing a decompressing reader, such as a `flate2::read::GzDecoder` wrapped in a
+//! [`BufReader`](std::io::BufReader).
+
+use std::io::BufRead;
+
+use arrow_array::builder::StringBuilder;
+use arrow_array::{OffsetSizeTrait, StringArray};
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+use crate::array::GeometryArray;
+use crate::datatypes::GeoDataType;
+use crate::error::{ErrorPolicy, GeoArrowError, Result};
+use crate::io::wkt::parse::{parse_wkt, GeometryArrayBuilder};
+
+/// Read a GeoArrow array from newline-delimited WKT, one geometry per line.
+///
+/// Lines that are empty or only whitespace are skipped, and produce no row. Both `\n` and `\r\n`
+/// line endings are accepted.
+///
+/// If `target` is `None`, the geometry type is inferred from the data, where a mix of single and
+/// multi geometries of the same kind becomes the multi type, and a mix that includes geometry
+/// collections becomes a geometry collection array. Otherwise all geometries are converted to the
+/// given type.
+///
+/// With [`ErrorPolicy::Strict`], the first line that fails to parse or to convert to `target`
+/// fails the whole read. With [`ErrorPolicy::NullifyAndReport`], such lines become null
+/// geometries and the second return value holds one error message per row, naming the line, and
+/// null for rows that parsed successfully.
+///
+/// # Errors
+///
+/// - if reading from `reader` fails
+/// - if a line fails to parse under [`ErrorPolicy::Strict`]
+/// - if `target` is `None` and the input has no geometries, or geometries of unrelated types
+pub fn read_wkt_lines<O: OffsetSizeTrait>(
+    reader: impl BufRead,
+    target: Option<GeoDataType>,
+    error_policy: ErrorPolicy,
+) -> Result<(GeometryArray<O>, Option<StringArray>)> {
+    read_lines(reader, target, error_policy, "WKT", |line| {
+        let line = std::str::from_utf8(line).map_err(|err| err.to_string())?;
+        parse_wkt(line)
+    })
+}
+
+/// Read a GeoArrow array from newline-delimited, hex-encoded WKB, one geometry per line.
+///
+/// Upper and lower case hex digits are accepted. Each decoded geometry must be well-formed 2D WKB
+/// of a point, line string, polygon, one of their multi types or a geometry collection, with no
+/// trailing bytes.
+///
+/// Blank lines, `target` and `error_policy` are handled as in [`read_wkt_lines`].
+///
+/// # Errors
+///
+/// - if reading from `reader` fails
+/// - if a line fails to parse under [`ErrorPolicy::Strict`]
+/// - if `target` is `None` and the input has no geometries, or geometries of unrelated types
+pub fn read_wkb_hex_lines<O: OffsetSizeTrait>(
+    reader: impl BufRead,
+    target: Option<GeoDataType>,
+    error_policy: ErrorPolicy,
+) -> Result<(GeometryArray<O>, Option<StringArray>)> {
+    read_lines(reader, target, error_policy, "WKB", |line| {
+        let wkb = decode_hex(line)?;
+        parse_wkb(&wkb)
+    })
+}
+
+/// Parse each non-blank line of `reader` with `parse`, and push the geometries into an array of
+/// type `target`.
+fn read_lines<O: OffsetSizeTrait>(
+    mut reader: impl BufRead,
+    target: Option<GeoDataType>,
+    error_policy: ErrorPolicy,
+    format: &str,
+    parse: impl Fn(&[u8]) -> std::result::Result<geo::Geometry, String>,
+) -> Result<(GeometryArray<O>, Option<StringArray>)> {
+    let mut builder = GeometryArrayBuilder::<O>::new(target.as_ref())?;
+    let mut error_builder = StringBuilder::new();
+    let mut line = vec![];
+    let mut line_number = 0;
+    loop {
+        line.clear();
+        let num_read = reader
+            .read_until(b'\n', &mut line)
+            .map_err(|err| GeoArrowError::General(err.to_string()))?;
+        if num_read == 0 {
+            break;
+        }
+        line_number += 1;
+
+        let Some(start) = line.iter().position(|byte| !byte.is_ascii_whitespace()) else {
+            continue;
+        };
+        let end = line
+            .iter()
+            .rposition(|byte| !byte.is_ascii_whitespace())
+            .unwrap();
+        let trimmed = &line[start..=end];
+
+        let pushed =
+            parse(trimmed).and_then(|geom| builder.push(Some(geom)).map_err(|err| err.to_string()));
+        match pushed {
+            Ok(()) => error_builder.append_null(),
+            Err(message) => match error_policy {
+                ErrorPolicy::Strict => {
+                    return Err(GeoArrowError::General(format!(
+                        "Failed to parse {} geometry on line {}: {}",
+                        format, line_number, message
+                    )))
+                }
+                ErrorPolicy::NullifyAndReport => {
+                    builder.push(None)?;
+                    error_builder.append_value(format!("line {}: {}", line_number, message));
+                }
+            },
+        }
+    }
+
+    let errors = match error_policy {
+        ErrorPolicy::Strict => None,
+        ErrorPolicy::NullifyAndReport => Some(error_builder.finish()),
+    };
+    Ok((builder.finish()?, errors))
+}
+
+fn decode_hex(hex: &[u8]) -> std::result::Result<Vec<u8>, String> {
+    if hex.len() % 2 != 0 {
+        return Err("Hex string has an odd number of digits".to_string());
+    }
+
+    let digit = |byte: u8| match byte {
+        b'0'..=b'9' => Ok(byte - b'0'),
+        b'a'..=b'f' => Ok(byte - b'a' + 10),
+        b'A'..=b'F' => Ok(byte - b'A' + 10),
+        _ => Err(format!("Invalid hex digit '{}'", byte.escape_ascii())),
+    };
+    hex.chunks_exact(2)
+        .map(|pair| Ok(digit(pair[0])? << 4 | digit(pair[1])?))
+        .collect()
+}
+
+/// Parse `wkb`, which must hold exactly one geometry.
+fn parse_wkb(wkb: &[u8]) -> std::result::Result<geo::Geometry, String> {
+    let mut reader = WkbReader {
+        wkb,
+        offset: 0,
+        big_endian: false,
+    };
+    let geom = reader.read_geometry()?;
+    if reader.offset != wkb.len() {
+        return Err(format!(
+            "{} trailing bytes after WKB geometry",
+            wkb.len() - reader.offset
+        ));
+    }
+    Ok(geom)
+}
+
+/// Reads 2D WKB geometries, checking every length against the buffer so that malformed input
+/// gives an error instead of a panic.
+struct WkbReader<'a> {
+    wkb: &'a [u8],
+    offset: usize,
+    /// The byte order of the geometry being read
+    big_endian: bool,
+}
+
+impl WkbReader<'_> {
+    fn take(&mut self, len: usize) -> std::result::Result<&[u8], String> {
+        let bytes = self
+            .wkb
+            .get(self.offset..self.offset + len)
+            .ok_or_else(|| "WKB geometry is truncated".to_string())?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn read_u32(&mut self) -> std::result::Result<u32, String> {
+        let big_endian = self.big_endian;
+        let bytes = self.take(4)?;
+        Ok(match big_endian {
+            true => BigEndian::read_u32(bytes),
+            false => LittleEndian::read_u32(bytes),
+        })
+    }
+
+    fn read_f64(&mut self) -> std::result::Result<f64, String> {
+        let big_endian = self.big_endian;
+        let bytes = self.take(8)?;
+        Ok(match big_endian {
+            true => BigEndian::read_f64(bytes),
+            false => LittleEndian::read_f64(bytes),
+        })
+    }
+
+    /// Read a count of items that take at least `min_size` bytes each, checking that they can
+    /// fit in the rest of the buffer before anything is allocated for them.
+    fn read_count(&mut self, min_size: usize) -> std::result::Result<usize, String> {
+        let count = self.read_u32()? as usize;
+        if count.saturating_mul(min_size) > self.wkb.len() - self.offset {
+            return Err("WKB geometry is truncated".to_string());
+        }
+        Ok(count)
+    }
+
+    /// Read the byte order and geometry type that start every geometry.
+    fn read_header(&mut self) -> std::result::Result<u32, String> {
+        let byte_order = self.take(1)?[0];
+        if byte_order > 1 {
+            return Err(format!("Invalid WKB byte order {}", byte_order));
+        }
+        self.big_endian = byte_order == 0;
+        self.read_u32()
+    }
+
+    fn read_geometry(&mut self) -> std::result::Result<geo::Geometry, String> {
+        Ok(match self.read_header()? {
+            1 => self.read_point()?.into(),
+            2 => self.read_line_string()?.into(),
+            3 => self.read_polygon()?.into(),
+            4 => geo::MultiPoint(self.read_members(1, Self::read_point)?).into(),
+            5 => geo::MultiLineString(self.read_members(2, Self::read_line_string)?).into(),
+            6 => geo::MultiPolygon(self.read_members(3, Self::read_polygon)?).into(),
+            7 => {
+                let count = self.read_count(5)?;
+                let geoms = (0..count)
+                    .map(|_| self.read_geometry())
+                    .collect::<std::result::Result<_, _>>()?;
+                geo::Geometry::GeometryCollection(geo::GeometryCollection(geoms))
+            }
+            geometry_type => {
+                return Err(format!("Unsupported WKB geometry type {}", geometry_type))
+            }
+        })
+    }
+
+    /// Read the members of a multi geometry, which must all be of `expected_type`.
+    fn read_members<T>(
+        &mut self,
+        expected_type: u32,
+        read: impl Fn(&mut Self) -> std::result::Result<T, String>,
+    ) -> std::result::Result<Vec<T>, String> {
+        let count = self.read_count(5)?;
+        (0..count)
+            .map(|_| {
+                let geometry_type = self.read_header()?;
+                if geometry_type != expected_type {
+                    return Err(format!(
+                        "Expected WKB geometry type {} inside a multi geometry, found {}",
+                        expected_type, geometry_type
+                    ));
+                }
+                read(self)
+            })
+            .collect()
+    }
+
+    fn read_coord(&mut self) -> std::result::Result<geo::Coord, String> {
+        Ok(geo::coord! { x: self.read_f64()?, y: self.read_f64()? })
+    }
+
+    fn read_point(&mut self) -> std::result::Result<geo::Point, String> {
+        Ok(self.read_coord()?.into())
+    }
+
+    fn read_line_string(&mut self) -> std::result::Result<geo::LineString, String> {
+        let count = self.read_count(16)?;
+        (0..count).map(|_| self.read_coord()).collect()
+    }
+
+    fn read_polygon(&mut self) -> std::result::Result<geo::Polygon, String> {
+        let count = self.read_count(4)?;
+        let mut rings = (0..count)
+            .map(|_| self.read_line_string())
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .into_iter();
+        let exterior = rings.next().unwrap_or_else(|| geo::LineString(vec![]));
+        Ok(geo::Polygon::new(exterior, rings.collect()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::io::wkb::writer::geometry::write_geometry_as_wkb;
+    use crate::trait_::GeoArrayAccessor;
+    use crate::GeometryArrayTrait;
+    use arrow_array::Array;
+    use geo::point;
+
+    /// 10,000 lines of line strings, with a malformed line every 1,000 lines, a blank line every
+    /// 2,500 lines and Windows line endings on every third line.
+    fn wkt_fixture() -> String {
+        let mut input = String::new();
+        for i in 0..10_000 {
+            match i {
+                i if i % 1000 == 500 => input.push_str("LINESTRING (0 0, 1"),
+                i if i % 2500 == 1 => input.push_str("   "),
+                i => input.push_str(&format!("LINESTRING ({} 0, {} 1)", i, i)),
+            }
+            input.push_str(if i % 3 == 0 { "\r\n" } else { "\n" });
+        }
+        input
+    }
+
+    #[test]
+    fn wkt_lines() {
+        let input = wkt_fixture();
+        let err = read_wkt_lines::<i32>(input.as_bytes(), None, ErrorPolicy::Strict).unwrap_err();
+        assert!(err.to_string().contains("on line 501"));
+
+        let (geometry_array, errors) =
+            read_wkt_lines::<i32>(input.as_bytes(), None, ErrorPolicy::NullifyAndReport).unwrap();
+        let errors = errors.unwrap();
+        assert_eq!(geometry_array.len(), 10_000 - 4);
+        assert_eq!(geometry_array.null_count(), 10);
+        assert_eq!(errors.len() - errors.null_count(), 10);
+
+        let GeometryArray::LineString(line_string_array) = geometry_array else {
+            panic!("expected linestring array");
+        };
+        // Line 502 is row 500, as line 2 was blank
+        assert!(line_string_array.is_null(499));
+        assert!(errors.value(499).starts_with("line 501: "));
+        let expected: geo::LineString = vec![(501., 0.), (501., 1.)].into();
+        assert_eq!(line_string_array.value_as_geo(500), expected);
+        assert!(errors.is_null(500));
+    }
+
+    fn hex(geom: &geo::Geometry) -> String {
+        let mut wkb = vec![];
+        write_geometry_as_wkb(&mut wkb, geom).unwrap();
+        wkb.iter().map(|byte| format!("{:02X}", byte)).collect()
+    }
+
+    #[test]
+    fn wkb_hex_lines() {
+        let point = hex(&point!(x: 1., y: 2.).into());
+        let multi_point = hex(&geo::MultiPoint::new(vec![point!(x: 3., y: 4.)]).into());
+        let input = [
+            point.clone(),
+            String::new(),
+            multi_point.to_lowercase(),
+            "01XY".to_string(),
+            point[..point.len() - 2].to_string(),
+            format!("{}00", point),
+            point,
+        ]
+        .join("\n");
+
+        let (geometry_array, errors) =
+            read_wkb_hex_lines::<i32>(input.as_bytes(), None, ErrorPolicy::NullifyAndReport)
+                .unwrap();
+        let errors = errors.unwrap();
+        assert_eq!(geometry_array.len(), 6);
+        let GeometryArray::MultiPoint(multi_point_array) = geometry_array else {
+            panic!("expected multipoint array");
+        };
+        assert_eq!(
+            multi_point_array.value_as_geo(1),
+            geo::MultiPoint::new(vec![point!(x: 3., y: 4.)])
+        );
+        assert!(errors.value(2).contains("Invalid hex digit 'X'"));
+        assert!(errors.value(3).contains("truncated"));
+        assert!(errors.value(4).contains("trailing bytes"));
+        assert_eq!(multi_point_array.null_count(), 3);
+    }
+
+    #[test]
+    fn geometry_collections() {
+        let collection = geo::Geometry::GeometryCollection(geo::GeometryCollection(vec![
+            point!(x: 1., y: 2.).into(),
+        ]));
+
+        let input = "POINT (3 4)\nGEOMETRYCOLLECTION (POINT (1 2))";
+        let (geometry_array, _) =
+            read_wkt_lines::<i32>(input.as_bytes(), None, ErrorPolicy::Strict).unwrap();
+        let GeometryArray::GeometryCollection(collection_array) = geometry_array else {
+            panic!("expected geometry collection array");
+        };
+        assert_eq!(collection_array.len(), 2);
+        assert_eq!(
+            geo::Geometry::GeometryCollection(collection_array.value_as_geo(1)),
+            collection
+        );
+
+        // Little-endian collections of one member, as the WKB writer doesn't write collections
+        let collection_hex = format!("010700000001000000{}", hex(&point!(x: 1., y: 2.).into()));
+        let nested_hex = format!("010700000001000000{}", collection_hex);
+        let input = [collection_hex, nested_hex].join("\n");
+        let (geometry_array, errors) =
+            read_wkb_hex_lines::<i32>(input.as_bytes(), None, ErrorPolicy::NullifyAndReport)
+                .unwrap();
+        let errors = errors.unwrap();
+        assert_eq!(geometry_array.len(), 2);
+        assert!(geometry_array.is_valid(0));
+        assert!(errors.value(1).contains("nested"));
+    }
+}
@@ -1,5 +1,7 @@
 #[cfg(feature = "csv")]
 pub mod csv;
+#[cfg(feature = "geozero")]
+pub mod feature;
 #[cfg(feature = "flatgeobuf")]
 pub mod flatgeobuf;
 #[cfg(feature = "geozero")]
@@ -8,6 +10,14 @@ pub mod geojson;
 pub(crate) mod geos;
 #[cfg(feature = "geozero")]
 pub(crate) mod geozero;
+#[cfg(feature = "gpx")]
+pub mod gpx;
+pub mod lines;
 #[cfg(feature = "parquet")]
 pub mod parquet;
+pub mod record_batch;
 pub mod wkb;
+pub mod wkt;
+
+#[cfg(feature = "geozero")]
+pub use self::geozero::simplify::{ReadOptions, DROPPED_VERTICES_KEY};
@@ -0,0 +1,375 @@
+//! Helpers for finding and checking the geometry columns of Arrow record batches.
+
+use arrow_array::RecordBatch;
+use arrow_schema::{DataType, Field};
+
+use crate::array::{CoordType, Dimension};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+
+/// The layouts accepted for coordinates, for error messages.
+const COORDS_LAYOUT: &str = "FixedSizeList<Float64 or Float32, 2 to 4> (interleaved) or \
+    Struct<x: Float64, y: Float64[, z: Float64][, m: Float64]> (separated)";
+
+/// Find the geometry columns of a record batch by their `ARROW:extension:name` metadata.
+///
+/// Returns the index and type of each column whose extension name is a GeoArrow type. Columns
+/// with a GeoArrow extension name whose storage type doesn't match it are skipped; use
+/// [`diagnose_geometry_field`] to find out why.
+pub fn geometry_columns(batch: &RecordBatch) -> Vec<(usize, GeoDataType)> {
+    batch
+        .schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .filter_map(|(column_idx, field)| {
+            geo_data_type(field)
+                .ok()
+                .map(|data_type| (column_idx, data_type))
+        })
+        .collect()
+}
+
+/// Check that a field describes a geometry column this crate can read, and return its type.
+///
+/// # Errors
+///
+/// The error message names the column, and describes what would need to change:
+///
+/// - if the field has no `ARROW:extension:name`, with the GeoArrow types its layout matches
+/// - if the extension name is not a GeoArrow type
+/// - if the storage type doesn't match the extension name, with the expected and found layouts,
+///   including coordinates that aren't `Float64` or interleaved `Float32`
+pub fn diagnose_geometry_field(field: &Field) -> Result<GeoDataType> {
+    let Some(extension_name) = field.metadata().get("ARROW:extension:name") else {
+        let candidates = match field.data_type() {
+            DataType::Binary | DataType::LargeBinary => vec!["geoarrow.wkb"],
+            data_type => match list_depth(data_type) {
+                Some(0) => vec!["geoarrow.point"],
+                Some(1) => vec!["geoarrow.linestring", "geoarrow.multipoint"],
+                Some(2) => vec!["geoarrow.polygon", "geoarrow.multilinestring"],
+                Some(3) => vec!["geoarrow.multipolygon"],
+                _ => vec![],
+            },
+        };
+        let suggestion = match candidates.as_slice() {
+            [] => "Its layout doesn't match any GeoArrow type.".to_string(),
+            [name] => format!(
+                "Its layout matches {}; set ARROW:extension:name to \"{}\".",
+                name, name
+            ),
+            names => format!(
+                "Its layout matches {}; set ARROW:extension:name to the one it holds.",
+                names.join(" or ")
+            ),
+        };
+        return Err(GeoArrowError::General(format!(
+            "Column \"{}\" has no ARROW:extension:name metadata, found layout {}. {}",
+            field.name(),
+            layout(field.data_type()),
+            suggestion
+        )));
+    };
+
+    geo_data_type(field).map_err(|expected| {
+        let mut message = format!(
+            "Column \"{}\" is {}, which expects {}, but found layout {}.",
+            field.name(),
+            extension_name,
+            expected,
+            layout(field.data_type())
+        );
+        if has_non_float64_coords(field.data_type()) {
+            message.push_str(
+                " Coordinates must be Float64, or Float32 if interleaved; cast them before reading.",
+            );
+        }
+        GeoArrowError::General(message)
+    })
+}
+
+/// The GeoArrow type of a field, or a description of the layout its extension name expects.
+fn geo_data_type(field: &Field) -> std::result::Result<GeoDataType, String> {
+    let extension_name = field
+        .metadata()
+        .get("ARROW:extension:name")
+        .map(String::as_str);
+    let storage_type = field.data_type();
+    let large = matches!(storage_type, DataType::LargeList(_));
+
+    let nested = |depth: usize| {
+        let expected = || {
+            let layout = "List<".repeat(depth) + "coords" + &">".repeat(depth);
+            format!("{}, where coords are {}", layout, COORDS_LAYOUT)
+        };
+        nested_coord_type(storage_type, depth).ok_or_else(expected)
+    };
+    let data_type = match extension_name {
+        Some("geoarrow.point") => {
+            let (coord_type, dim) =
+                nested_coord_type(storage_type, 0).ok_or_else(|| COORDS_LAYOUT.to_string())?;
+            GeoDataType::Point(coord_type, dim)
+        }
+        Some("geoarrow.linestring") => {
+            let (coord_type, dim) = nested(1)?;
+            match large {
+                true => GeoDataType::LargeLineString(coord_type, dim),
+                false => GeoDataType::LineString(coord_type, dim),
+            }
+        }
+        Some("geoarrow.polygon") => {
+            let (coord_type, dim) = nested(2)?;
+            match large {
+                true => GeoDataType::LargePolygon(coord_type, dim),
+                false => GeoDataType::Polygon(coord_type, dim),
+            }
+        }
+        Some("geoarrow.multipoint") => {
+            let (coord_type, dim) = nested(1)?;
+            match large {
+                true => GeoDataType::LargeMultiPoint(coord_type, dim),
+                false => GeoDataType::MultiPoint(coord_type, dim),
+            }
+        }
+        Some("geoarrow.multilinestring") => {
+            let (coord_type, dim) = nested(2)?;
+            match large {
+                true => GeoDataType::LargeMultiLineString(coord_type, dim),
+                false => GeoDataType::MultiLineString(coord_type, dim),
+            }
+        }
+        Some("geoarrow.multipolygon") => {
+            let (coord_type, dim) = nested(3)?;
+            match large {
+                true => GeoDataType::LargeMultiPolygon(coord_type, dim),
+                false => GeoDataType::MultiPolygon(coord_type, dim),
+            }
+        }
+        Some("geoarrow.mixed") => {
+            let expected = || "a Union of GeoArrow geometry types".to_string();
+            let DataType::Union(fields, _) = storage_type else {
+                return Err(expected());
+            };
+            // Take the coord type from the point child, which has no offsets
+            let (coord_type, _) = fields
+                .iter()
+                .find_map(|(_, field)| nested_coord_type(field.data_type(), 0))
+                .ok_or_else(expected)?;
+            let large = fields
+                .iter()
+                .any(|(_, field)| matches!(field.data_type(), DataType::LargeList(_)));
+            match large {
+                true => GeoDataType::LargeMixed(coord_type),
+                false => GeoDataType::Mixed(coord_type),
+            }
+        }
+        Some("geoarrow.geometrycollection") => {
+            let expected = || "a List of a Union of GeoArrow geometry types".to_string();
+            let Some(DataType::Union(fields, _)) =
+                list_child(storage_type).map(|child| child.data_type())
+            else {
+                return Err(expected());
+            };
+            let (coord_type, _) = fields
+                .iter()
+                .find_map(|(_, field)| nested_coord_type(field.data_type(), 0))
+                .ok_or_else(expected)?;
+            match large {
+                true => GeoDataType::LargeGeometryCollection(coord_type),
+                false => GeoDataType::GeometryCollection(coord_type),
+            }
+        }
+        Some("geoarrow.wkb") => match storage_type {
+            DataType::Binary => GeoDataType::WKB,
+            DataType::LargeBinary => GeoDataType::LargeWKB,
+            _ => return Err("Binary or LargeBinary".to_string()),
+        },
+        Some("geoarrow._rect") => GeoDataType::Rect,
+        _ => return Err("a GeoArrow extension name, such as geoarrow.point".to_string()),
+    };
+    Ok(data_type)
+}
+
+/// The child field of a list or large list type.
+fn list_child(data_type: &DataType) -> Option<&Field> {
+    match data_type {
+        DataType::List(field) | DataType::LargeList(field) => Some(field),
+        _ => None,
+    }
+}
+
+/// The coord type and dimension of Float64 coordinates, or interleaved Float32 coordinates,
+/// nested inside `depth` lists.
+fn nested_coord_type(data_type: &DataType, depth: usize) -> Option<(CoordType, Dimension)> {
+    if depth > 0 {
+        return nested_coord_type(list_child(data_type)?.data_type(), depth - 1);
+    }
+
+    match data_type {
+        DataType::FixedSizeList(field, size)
+            if matches!(field.data_type(), DataType::Float64 | DataType::Float32) =>
+        {
+            let dim =
+                Dimension::from_interleaved_field(field.name(), usize::try_from(*size).ok()?)?;
+            Some((CoordType::Interleaved, dim))
+        }
+        DataType::Struct(fields)
+            if fields
+                .iter()
+                .all(|field| field.data_type() == &DataType::Float64) =>
+        {
+            let dim =
+                Dimension::from_separated_fields(fields.iter().map(|field| field.name().as_str()))?;
+            Some((CoordType::Separated, dim))
+        }
+        _ => None,
+    }
+}
+
+/// The number of lists around coordinates of any numeric type, if `data_type` is such a nesting.
+fn list_depth(data_type: &DataType) -> Option<usize> {
+    match data_type {
+        DataType::List(field) | DataType::LargeList(field) => {
+            list_depth(field.data_type()).map(|depth| depth + 1)
+        }
+        DataType::FixedSizeList(field, 2..=4) if field.data_type().is_numeric() => Some(0),
+        DataType::Struct(fields)
+            if matches!(fields.len(), 2..=4)
+                && fields.iter().all(|field| field.data_type().is_numeric()) =>
+        {
+            Some(0)
+        }
+        _ => None,
+    }
+}
+
+/// Whether `data_type` nests coordinates of a numeric type this crate doesn't read.
+fn has_non_float64_coords(data_type: &DataType) -> bool {
+    list_depth(data_type).is_some_and(|depth| nested_coord_type(data_type, depth).is_none())
+}
+
+/// A compact description of a storage type, such as `List<FixedSizeList<Float64, 2>>`.
+fn layout(data_type: &DataType) -> String {
+    match data_type {
+        DataType::List(field) => format!("List<{}>", layout(field.data_type())),
+        DataType::LargeList(field) => format!("LargeList<{}>", layout(field.data_type())),
+        DataType::FixedSizeList(field, size) => {
+            format!("FixedSizeList<{}, {}>", layout(field.data_type()), size)
+        }
+        DataType::Struct(fields) => {
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|field| format!("{}: {}", field.name(), layout(field.data_type())))
+                .collect();
+            format!("Struct<{}>", fields.join(", "))
+        }
+        data_type => data_type.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::test::polygon::p_array;
+    use crate::GeometryArrayTrait;
+    use arrow_array::{ArrayRef, Int32Array};
+    use arrow_schema::Schema;
+
+    #[test]
+    fn finds_geometry_column() {
+        let (geometry_field, geometry) = p_array().into_array_ref_with_field("geometry");
+        let ids: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let schema = Schema::new(vec![
+            Arc::new(Field::new("id", DataType::Int32, false)),
+            geometry_field,
+        ]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![ids, geometry]).unwrap();
+
+        assert_eq!(
+            geometry_columns(&batch),
+            vec![(
+                1,
+                GeoDataType::Polygon(CoordType::Interleaved, Dimension::XY)
+            )]
+        );
+    }
+
+    fn coords(value_type: DataType) -> DataType {
+        DataType::FixedSizeList(Arc::new(Field::new("xy", value_type, false)), 2)
+    }
+
+    fn list(data_type: DataType) -> DataType {
+        DataType::List(Arc::new(Field::new("item", data_type, true)))
+    }
+
+    fn field(data_type: DataType, extension_name: Option<&str>) -> Field {
+        let metadata = extension_name
+            .map(|name| HashMap::from([("ARROW:extension:name".to_string(), name.to_string())]))
+            .unwrap_or_default();
+        Field::new("geom", data_type, true).with_metadata(metadata)
+    }
+
+    #[test]
+    fn diagnose() {
+        let polygon = field(
+            list(list(coords(DataType::Float64))),
+            Some("geoarrow.polygon"),
+        );
+        assert_eq!(
+            diagnose_geometry_field(&polygon).unwrap(),
+            GeoDataType::Polygon(CoordType::Interleaved, Dimension::XY)
+        );
+
+        let xyz_line_string = field(
+            list(DataType::FixedSizeList(
+                Arc::new(Field::new("xyz", DataType::Float64, false)),
+                3,
+            )),
+            Some("geoarrow.linestring"),
+        );
+        assert_eq!(
+            diagnose_geometry_field(&xyz_line_string).unwrap(),
+            GeoDataType::LineString(CoordType::Interleaved, Dimension::XYZ)
+        );
+
+        let missing_name = field(list(coords(DataType::Float64)), None);
+        let message = diagnose_geometry_field(&missing_name)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("\"geom\" has no ARROW:extension:name"));
+        assert!(message.contains("geoarrow.linestring or geoarrow.multipoint"));
+
+        let float32 = field(list(coords(DataType::Float32)), Some("geoarrow.linestring"));
+        assert_eq!(
+            diagnose_geometry_field(&float32).unwrap(),
+            GeoDataType::LineString(CoordType::Interleaved, Dimension::XY)
+        );
+
+        // 32-bit coordinates are only read interleaved
+        let separated_float32 = field(
+            list(DataType::Struct(
+                vec![
+                    Field::new("x", DataType::Float32, false),
+                    Field::new("y", DataType::Float32, false),
+                ]
+                .into(),
+            )),
+            Some("geoarrow.linestring"),
+        );
+        let message = diagnose_geometry_field(&separated_float32)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("found layout List<Struct<x: Float32, y: Float32>>"));
+        assert!(message.contains("Coordinates must be Float64"));
+
+        let wrong_nesting = field(list(coords(DataType::Float64)), Some("geoarrow.polygon"));
+        let message = diagnose_geometry_field(&wrong_nesting)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("expects List<List<coords>>"));
+        assert!(!message.contains("Coordinates must be Float64"));
+    }
+}
@@ -2,6 +2,7 @@ use std::io::Cursor;
 
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
 
+use crate::array::Dimension;
 use crate::geo_traits::{CoordTrait, PointTrait};
 use crate::io::wkb::reader::geometry::Endianness;
 
@@ -12,7 +13,7 @@ const F64_WIDTH: u64 = 8;
 /// Note that according to the WKB specification this is called `Point`, which is **not** the same
 /// as a `WKBPoint`. In particular, a `WKBPoint` has framing that includes the byte order and
 /// geometry type of the WKB buffer. In contrast, this `Point` is the building block of two f64
-/// numbers that can occur within any geometry type.
+/// numbers, or three for XYZ geometries, that can occur within any geometry type.
 ///
 /// See page 65 of <https://portal.ogc.org/files/?artifact_id=25355>.
 #[derive(Debug, Clone, Copy)]
@@ -30,33 +31,48 @@ pub struct WKBCoord<'a> {
     /// types. I.e. the `WKBLineString` has a header, then the number of points, then a sequence of
     /// `Point` objects.
     offset: u64,
+
+    /// The dimension of this coordinate, which is that of the geometry it belongs to
+    dim: Dimension,
 }
 
 impl<'a> WKBCoord<'a> {
-    pub fn new(buf: &'a [u8], byte_order: Endianness, offset: u64) -> Self {
+    pub fn new(buf: &'a [u8], byte_order: Endianness, offset: u64, dim: Dimension) -> Self {
         Self {
             buf,
             byte_order,
             offset,
+            dim,
         }
     }
 
-    fn get_x(&self) -> f64 {
+    fn get_nth_unchecked(&self, n: u64) -> f64 {
         let mut reader = Cursor::new(self.buf);
-        reader.set_position(self.offset);
+        reader.set_position(self.offset + n * F64_WIDTH);
         match self.byte_order {
             Endianness::BigEndian => reader.read_f64::<BigEndian>().unwrap(),
             Endianness::LittleEndian => reader.read_f64::<LittleEndian>().unwrap(),
         }
     }
 
+    fn get_x(&self) -> f64 {
+        self.get_nth_unchecked(0)
+    }
+
     fn get_y(&self) -> f64 {
-        let mut reader = Cursor::new(self.buf);
-        reader.set_position(self.offset + F64_WIDTH);
-        match self.byte_order {
-            Endianness::BigEndian => reader.read_f64::<BigEndian>().unwrap(),
-            Endianness::LittleEndian => reader.read_f64::<LittleEndian>().unwrap(),
-        }
+        self.get_nth_unchecked(1)
+    }
+
+    fn get_z(&self) -> Option<f64> {
+        self.dim
+            .z_index()
+            .map(|index| self.get_nth_unchecked(index as u64))
+    }
+
+    fn get_m(&self) -> Option<f64> {
+        self.dim
+            .m_index()
+            .map(|index| self.get_nth_unchecked(index as u64))
     }
 
     /// The number of bytes in this object
@@ -64,8 +80,12 @@ impl<'a> WKBCoord<'a> {
     /// Note that this is not the same as the length of the underlying buffer
     #[allow(dead_code)]
     pub fn size(&self) -> u64 {
-        // A 2D WKBCoord is just two f64s
-        16
+        Self::size_of(self.dim)
+    }
+
+    /// The number of bytes in a coordinate of the given dimension: one f64 per value
+    pub fn size_of(dim: Dimension) -> u64 {
+        dim.size() as u64 * F64_WIDTH
     }
 }
 
@@ -79,6 +99,14 @@ impl<'a> CoordTrait for WKBCoord<'a> {
     fn y(&self) -> Self::T {
         self.get_y()
     }
+
+    fn z(&self) -> Option<Self::T> {
+        self.get_z()
+    }
+
+    fn m(&self) -> Option<Self::T> {
+        self.get_m()
+    }
 }
 
 impl<'a> PointTrait for WKBCoord<'a> {
@@ -91,4 +119,12 @@ impl<'a> PointTrait for WKBCoord<'a> {
     fn y(&self) -> Self::T {
         self.get_y()
     }
+
+    fn z(&self) -> Option<Self::T> {
+        self.get_z()
+    }
+
+    fn m(&self) -> Option<Self::T> {
+        self.get_m()
+    }
 }
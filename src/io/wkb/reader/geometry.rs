@@ -3,6 +3,7 @@ use std::io::Cursor;
 use arrow_array::OffsetSizeTrait;
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
 
+use crate::array::Dimension;
 use crate::geo_traits::GeometryTrait;
 use crate::io::wkb::reader::geometry_collection::WKBGeometryCollection;
 use crate::io::wkb::reader::rect::WKBRect;
@@ -14,27 +15,7 @@ use crate::scalar::WKB;
 
 impl<'a, O: OffsetSizeTrait> WKB<'a, O> {
     pub fn to_wkb_object(&'a self) -> WKBGeometry<'a> {
-        let buf = self.arr.value(self.geom_index);
-        let mut reader = Cursor::new(buf);
-        let byte_order = reader.read_u8().unwrap();
-        let geometry_type = match byte_order {
-            0 => reader.read_u32::<BigEndian>().unwrap(),
-            1 => reader.read_u32::<LittleEndian>().unwrap(),
-            _ => panic!("Unexpected byte order."),
-        };
-
-        match geometry_type {
-            1 => WKBGeometry::Point(WKBPoint::new(buf, byte_order.into(), 0)),
-            2 => WKBGeometry::LineString(WKBLineString::new(buf, byte_order.into(), 0)),
-            3 => WKBGeometry::Polygon(WKBPolygon::new(buf, byte_order.into(), 0)),
-            4 => WKBGeometry::MultiPoint(WKBMultiPoint::new(buf, byte_order.into())),
-            5 => WKBGeometry::MultiLineString(WKBMultiLineString::new(buf, byte_order.into())),
-            6 => WKBGeometry::MultiPolygon(WKBMultiPolygon::new(buf, byte_order.into())),
-            7 => {
-                WKBGeometry::GeometryCollection(WKBGeometryCollection::new(buf, byte_order.into()))
-            }
-            _ => panic!("Unexpected geometry type"),
-        }
+        WKBGeometry::new(self.arr.value(self.geom_index))
     }
 
     pub fn get_wkb_geometry_type(&'a self) -> WKBGeometryType {
@@ -46,6 +27,7 @@ impl<'a, O: OffsetSizeTrait> WKB<'a, O> {
             1 => reader.read_u32::<LittleEndian>().unwrap(),
             _ => panic!("Unexpected byte order."),
         };
+        let (geometry_type, _) = split_geometry_type(geometry_type);
         geometry_type.try_into().unwrap()
     }
 
@@ -57,6 +39,32 @@ impl<'a, O: OffsetSizeTrait> WKB<'a, O> {
     }
 }
 
+/// Split a WKB geometry type code into the code of its 2D type, from 1 to 7, and its dimension.
+///
+/// Both ISO WKB, which adds 1000 to the code of an XYZ geometry, 2000 to that of an XYM geometry
+/// and 3000 to that of an XYZM geometry, and EWKB, which sets the `0x80000000` flag for z values
+/// and the `0x40000000` flag for m values instead, are read.
+///
+/// # Panics
+///
+/// - if the code is not a known ISO WKB code
+pub(crate) fn split_geometry_type(type_code: u32) -> (u32, Dimension) {
+    const EWKB_Z_FLAG: u32 = 0x8000_0000;
+    const EWKB_M_FLAG: u32 = 0x4000_0000;
+    if type_code & (EWKB_Z_FLAG | EWKB_M_FLAG) != 0 {
+        let dim = Dimension::from_flags(type_code & EWKB_Z_FLAG != 0, type_code & EWKB_M_FLAG != 0);
+        return (type_code & !(EWKB_Z_FLAG | EWKB_M_FLAG), dim);
+    }
+
+    match type_code / 1000 {
+        0 => (type_code, Dimension::XY),
+        1 => (type_code % 1000, Dimension::XYZ),
+        2 => (type_code % 1000, Dimension::XYM),
+        3 => (type_code % 1000, Dimension::XYZM),
+        _ => panic!("Unsupported WKB geometry type {}", type_code),
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Endianness {
     BigEndian,
@@ -95,6 +103,59 @@ pub enum WKBGeometry<'a> {
 }
 
 impl<'a> WKBGeometry<'a> {
+    /// Parse the WKB geometry that starts at the beginning of `buf`.
+    ///
+    /// `buf` may extend past the end of the geometry, as it does for the members of a
+    /// GeometryCollection.
+    pub fn new(buf: &'a [u8]) -> Self {
+        let mut reader = Cursor::new(buf);
+        let byte_order = reader.read_u8().unwrap();
+        let geometry_type = match byte_order {
+            0 => reader.read_u32::<BigEndian>().unwrap(),
+            1 => reader.read_u32::<LittleEndian>().unwrap(),
+            _ => panic!("Unexpected byte order."),
+        };
+        let (geometry_type, dim) = split_geometry_type(geometry_type);
+        let byte_order: Endianness = byte_order.into();
+
+        match geometry_type {
+            1 => WKBGeometry::Point(WKBPoint::new(buf, byte_order, 0, dim)),
+            2 => WKBGeometry::LineString(WKBLineString::new(buf, byte_order, 0, dim)),
+            3 => WKBGeometry::Polygon(WKBPolygon::new(buf, byte_order, 0, dim)),
+            4 => WKBGeometry::MultiPoint(WKBMultiPoint::new(buf, byte_order, dim)),
+            5 => WKBGeometry::MultiLineString(WKBMultiLineString::new(buf, byte_order, dim)),
+            6 => WKBGeometry::MultiPolygon(WKBMultiPolygon::new(buf, byte_order, dim)),
+            7 => WKBGeometry::GeometryCollection(WKBGeometryCollection::new(buf, byte_order, dim)),
+            _ => panic!("Unexpected geometry type"),
+        }
+    }
+
+    /// The dimension of this geometry, as given by its WKB geometry type
+    pub fn dim(&self) -> Dimension {
+        match self {
+            WKBGeometry::Point(geom) => geom.dim(),
+            WKBGeometry::LineString(geom) => geom.dim(),
+            WKBGeometry::Polygon(geom) => geom.dim(),
+            WKBGeometry::MultiPoint(geom) => geom.dim(),
+            WKBGeometry::MultiLineString(geom) => geom.dim(),
+            WKBGeometry::MultiPolygon(geom) => geom.dim(),
+            WKBGeometry::GeometryCollection(geom) => geom.dim(),
+        }
+    }
+
+    /// The number of bytes in this object, including any header
+    pub fn size(&self) -> u64 {
+        match self {
+            WKBGeometry::Point(geom) => geom.size(),
+            WKBGeometry::LineString(geom) => geom.size(),
+            WKBGeometry::Polygon(geom) => geom.size(),
+            WKBGeometry::MultiPoint(geom) => geom.size(),
+            WKBGeometry::MultiLineString(geom) => geom.size(),
+            WKBGeometry::MultiPolygon(geom) => geom.size(),
+            WKBGeometry::GeometryCollection(geom) => geom.size(),
+        }
+    }
+
     pub fn into_point(self) -> WKBPoint<'a> {
         match self {
             WKBGeometry::Point(geom) => geom,
@@ -1,19 +1,67 @@
-use crate::geo_traits::GeometryCollectionTrait;
-use crate::io::wkb::reader::geometry::{Endianness, WKBGeometry};
+use std::io::Cursor;
 use std::iter::Cloned;
 use std::slice::Iter;
 
-/// Not yet implemented but required for WKBGeometry
-#[derive(Debug, Clone, Copy)]
-#[allow(dead_code)]
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+
+use crate::array::Dimension;
+use crate::geo_traits::GeometryCollectionTrait;
+use crate::io::wkb::reader::geometry::{Endianness, WKBGeometry};
+
+const HEADER_BYTES: u64 = 5;
+
+#[derive(Debug, Clone)]
 pub struct WKBGeometryCollection<'a> {
-    buf: &'a [u8],
-    byte_order: Endianness,
+    /// A WKBGeometry object for each member of the collection
+    pub(crate) geometries: Vec<WKBGeometry<'a>>,
+
+    dim: Dimension,
 }
 
 impl<'a> WKBGeometryCollection<'a> {
-    pub fn new(buf: &'a [u8], byte_order: Endianness) -> Self {
-        Self { buf, byte_order }
+    pub fn new(buf: &'a [u8], byte_order: Endianness, dim: Dimension) -> Self {
+        let mut reader = Cursor::new(buf);
+        reader.set_position(HEADER_BYTES);
+        let num_geometries = match byte_order {
+            Endianness::BigEndian => reader.read_u32::<BigEndian>().unwrap().try_into().unwrap(),
+            Endianness::LittleEndian => reader
+                .read_u32::<LittleEndian>()
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        };
+
+        // - 1: byteOrder
+        // - 4: wkbType
+        // - 4: numGeometries
+        let mut geometry_offset = 1 + 4 + 4;
+        let mut geometries = Vec::with_capacity(num_geometries);
+        for _ in 0..num_geometries {
+            // Each member carries its own header, so parse it from where it starts
+            let geometry = WKBGeometry::new(&buf[geometry_offset..]);
+            geometry_offset += geometry.size() as usize;
+            geometries.push(geometry);
+        }
+
+        Self { geometries, dim }
+    }
+
+    /// The dimension of this collection, as given by its WKB geometry type
+    pub fn dim(&self) -> Dimension {
+        self.dim
+    }
+
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
+    pub fn size(&self) -> u64 {
+        // - 1: byteOrder
+        // - 4: wkbType
+        // - 4: numGeometries
+        // - size of each member geometry
+        self.geometries
+            .iter()
+            .fold(1 + 4 + 4, |acc, geometry| acc + geometry.size())
     }
 }
 
@@ -23,14 +71,14 @@ impl<'a> GeometryCollectionTrait<'a> for WKBGeometryCollection<'a> {
     type Iter = Cloned<Iter<'a, Self::ItemType>>;
 
     fn num_geometries(&self) -> usize {
-        todo!()
+        self.geometries.len()
     }
 
-    fn geometry(&self, _i: usize) -> Option<Self::ItemType> {
-        todo!()
+    fn geometry(&self, i: usize) -> Option<Self::ItemType> {
+        self.geometries.get(i).cloned()
     }
 
     fn geometries(&'a self) -> Self::Iter {
-        todo!()
+        self.geometries.iter().cloned()
     }
 }
@@ -4,6 +4,7 @@ use std::slice::Iter;
 
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
 
+use crate::array::Dimension;
 use crate::geo_traits::LineStringTrait;
 use crate::io::wkb::reader::coord::WKBCoord;
 use crate::io::wkb::reader::geometry::Endianness;
@@ -29,10 +30,12 @@ pub struct WKBLinearRing<'a> {
 
     /// The number of points in this linear ring
     num_points: usize,
+
+    dim: Dimension,
 }
 
 impl<'a> WKBLinearRing<'a> {
-    pub fn new(buf: &'a [u8], byte_order: Endianness, offset: u64) -> Self {
+    pub fn new(buf: &'a [u8], byte_order: Endianness, offset: u64, dim: Dimension) -> Self {
         let mut reader = Cursor::new(buf);
         reader.set_position(offset);
         let num_points = match byte_order {
@@ -49,6 +52,7 @@ impl<'a> WKBLinearRing<'a> {
             byte_order,
             offset,
             num_points,
+            dim,
         }
     }
 
@@ -57,13 +61,13 @@ impl<'a> WKBLinearRing<'a> {
     /// Note that this is not the same as the length of the underlying buffer
     pub fn size(&self) -> u64 {
         // - 4: numPoints
-        // - 2 * 8 * self.num_points: two f64s for each coordinate
-        4 + (2 * 8 * self.num_points as u64)
+        // - self.num_points coordinates of two or three f64s
+        4 + (WKBCoord::size_of(self.dim) * self.num_points as u64)
     }
 
     /// The offset into this buffer of any given coordinate
     pub fn coord_offset(&self, i: u64) -> u64 {
-        self.offset + 4 + (2 * 8 * i)
+        self.offset + 4 + (WKBCoord::size_of(self.dim) * i)
     }
 }
 
@@ -77,7 +81,7 @@ impl<'a> LineStringTrait<'a> for WKBLinearRing<'a> {
     }
 
     fn coord(&self, i: usize) -> Option<Self::ItemType> {
-        if i > (self.num_points) {
+        if i >= self.num_points {
             return None;
         }
 
@@ -85,6 +89,7 @@ impl<'a> LineStringTrait<'a> for WKBLinearRing<'a> {
             self.buf,
             self.byte_order,
             self.coord_offset(i.try_into().unwrap()),
+            self.dim,
         );
         Some(coord)
     }
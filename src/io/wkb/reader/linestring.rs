@@ -5,6 +5,7 @@ use std::slice::Iter;
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
 
 use crate::algorithm::native::eq::{line_string_eq, multi_line_string_eq};
+use crate::array::Dimension;
 use crate::geo_traits::{LineStringTrait, MultiLineStringTrait};
 use crate::io::wkb::reader::coord::WKBCoord;
 use crate::io::wkb::reader::geometry::Endianness;
@@ -22,10 +23,12 @@ pub struct WKBLineString<'a> {
     /// This offset will be 0 for a single WKBLineString but it will be non zero for a
     /// WKBLineString contained within a WKBMultiLineString
     offset: u64,
+
+    dim: Dimension,
 }
 
 impl<'a> WKBLineString<'a> {
-    pub fn new(buf: &'a [u8], byte_order: Endianness, offset: u64) -> Self {
+    pub fn new(buf: &'a [u8], byte_order: Endianness, offset: u64, dim: Dimension) -> Self {
         let mut reader = Cursor::new(buf);
         reader.set_position(HEADER_BYTES + offset);
         let num_points = match byte_order {
@@ -42,6 +45,7 @@ impl<'a> WKBLineString<'a> {
             byte_order,
             num_points,
             offset,
+            dim,
         }
     }
 
@@ -52,13 +56,18 @@ impl<'a> WKBLineString<'a> {
         // - 1: byteOrder
         // - 4: wkbType
         // - 4: numPoints
-        // - 2 * 8 * self.num_points: two f64s for each coordinate
-        1 + 4 + 4 + (2 * 8 * self.num_points as u64)
+        // - self.num_points coordinates of two or three f64s
+        1 + 4 + 4 + (WKBCoord::size_of(self.dim) * self.num_points as u64)
     }
 
     /// The offset into this buffer of any given coordinate
     pub fn coord_offset(&self, i: u64) -> u64 {
-        self.offset + 1 + 4 + 4 + (2 * 8 * i)
+        self.offset + 1 + 4 + 4 + (WKBCoord::size_of(self.dim) * i)
+    }
+
+    /// The dimension of this line string
+    pub fn dim(&self) -> Dimension {
+        self.dim
     }
 
     /// Check if this WKBLineString has equal coordinates as some other LineString object
@@ -82,7 +91,7 @@ impl<'a> LineStringTrait<'a> for WKBLineString<'a> {
     }
 
     fn coord(&self, i: usize) -> Option<Self::ItemType> {
-        if i > (self.num_coords()) {
+        if i >= self.num_coords() {
             return None;
         }
 
@@ -90,6 +99,7 @@ impl<'a> LineStringTrait<'a> for WKBLineString<'a> {
             self.buf,
             self.byte_order,
             self.coord_offset(i.try_into().unwrap()),
+            self.dim,
         );
         Some(coord)
     }
@@ -109,12 +119,12 @@ impl<'a> LineStringTrait<'a> for &WKBLineString<'a> {
     }
 
     fn coord(&self, i: usize) -> Option<Self::ItemType> {
-        if i > (self.num_coords()) {
+        if i >= self.num_coords() {
             return None;
         }
 
-        let offset = self.offset + 1 + 4 + 4 + (2 * 8 * i as u64);
-        let coord = WKBCoord::new(self.buf, self.byte_order, offset);
+        let offset = self.coord_offset(i.try_into().unwrap());
+        let coord = WKBCoord::new(self.buf, self.byte_order, offset, self.dim);
         Some(coord)
     }
 
@@ -133,7 +143,7 @@ impl<'a> MultiLineStringTrait<'a> for WKBLineString<'a> {
     }
 
     fn line(&self, i: usize) -> Option<Self::ItemType> {
-        if i > self.num_lines() {
+        if i >= self.num_lines() {
             return None;
         }
 
@@ -155,7 +165,7 @@ impl<'a> MultiLineStringTrait<'a> for &WKBLineString<'a> {
     }
 
     fn line(&self, i: usize) -> Option<Self::ItemType> {
-        if i > self.num_lines() {
+        if i >= self.num_lines() {
             return None;
         }
 
@@ -179,7 +189,7 @@ mod test {
         let buf = geo::Geometry::LineString(geom.clone())
             .to_wkb(CoordDimensions::xy())
             .unwrap();
-        let wkb_geom = WKBLineString::new(&buf, Endianness::LittleEndian, 0);
+        let wkb_geom = WKBLineString::new(&buf, Endianness::LittleEndian, 0, Dimension::XY);
 
         assert!(wkb_geom.equals_line_string(geom));
     }
@@ -190,7 +200,7 @@ mod test {
         let buf = geo::Geometry::LineString(geom.clone())
             .to_wkb(CoordDimensions::xy())
             .unwrap();
-        let wkb_geom = WKBLineString::new(&buf, Endianness::LittleEndian, 0);
+        let wkb_geom = WKBLineString::new(&buf, Endianness::LittleEndian, 0, Dimension::XY);
 
         assert_eq!(wkb_geom.size(), buf.len() as u64);
     }
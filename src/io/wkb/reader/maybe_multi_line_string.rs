@@ -1,4 +1,5 @@
 use crate::algorithm::native::eq::multi_line_string_eq;
+use crate::array::Dimension;
 use crate::geo_traits::MultiLineStringTrait;
 use crate::io::wkb::reader::linestring::WKBLineString;
 use crate::io::wkb::reader::multilinestring::WKBMultiLineString;
@@ -15,6 +16,14 @@ pub enum WKBMaybeMultiLineString<'a> {
 }
 
 impl<'a> WKBMaybeMultiLineString<'a> {
+    /// The dimension of this geometry
+    pub fn dim(&self) -> Dimension {
+        match self {
+            WKBMaybeMultiLineString::LineString(geom) => geom.dim(),
+            WKBMaybeMultiLineString::MultiLineString(geom) => geom.dim(),
+        }
+    }
+
     /// Check if this has equal coordinates as some other MultiLineString object
     pub fn equals_multi_line_string(&self, other: impl MultiLineStringTrait<'a, T = f64>) -> bool {
         multi_line_string_eq(self, other)
@@ -93,6 +102,7 @@ mod test {
             &buf,
             Endianness::LittleEndian,
             0,
+            Dimension::XY,
         ));
 
         assert!(wkb_geom.equals_multi_line_string(geo::MultiLineString(vec![geom])));
@@ -107,6 +117,7 @@ mod test {
         let wkb_geom = WKBMaybeMultiLineString::MultiLineString(WKBMultiLineString::new(
             &buf,
             Endianness::LittleEndian,
+            Dimension::XY,
         ));
 
         assert!(wkb_geom.equals_multi_line_string(geom));
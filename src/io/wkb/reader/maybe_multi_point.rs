@@ -1,4 +1,5 @@
 use crate::algorithm::native::eq::multi_point_eq;
+use crate::array::Dimension;
 use crate::geo_traits::MultiPointTrait;
 use crate::io::wkb::reader::multipoint::WKBMultiPoint;
 use crate::io::wkb::reader::point::WKBPoint;
@@ -17,6 +18,14 @@ pub enum WKBMaybeMultiPoint<'a> {
 }
 
 impl<'a> WKBMaybeMultiPoint<'a> {
+    /// The dimension of this geometry
+    pub fn dim(&self) -> Dimension {
+        match self {
+            WKBMaybeMultiPoint::Point(geom) => geom.dim(),
+            WKBMaybeMultiPoint::MultiPoint(geom) => geom.dim(),
+        }
+    }
+
     /// Check if this has equal coordinates as some other MultiPoint object
     pub fn equals_multi_point(&self, other: impl MultiPointTrait<'a, T = f64>) -> bool {
         multi_point_eq(self, other)
@@ -91,7 +100,12 @@ mod test {
         let buf = geo::Geometry::Point(geom)
             .to_wkb(CoordDimensions::xy())
             .unwrap();
-        let wkb_geom = WKBMaybeMultiPoint::Point(WKBPoint::new(&buf, Endianness::LittleEndian, 0));
+        let wkb_geom = WKBMaybeMultiPoint::Point(WKBPoint::new(
+            &buf,
+            Endianness::LittleEndian,
+            0,
+            Dimension::XY,
+        ));
 
         assert!(wkb_geom.equals_multi_point(geo::MultiPoint(vec![geom])));
     }
@@ -102,8 +116,11 @@ mod test {
         let buf = geo::Geometry::MultiPoint(geom.clone())
             .to_wkb(CoordDimensions::xy())
             .unwrap();
-        let wkb_geom =
-            WKBMaybeMultiPoint::MultiPoint(WKBMultiPoint::new(&buf, Endianness::LittleEndian));
+        let wkb_geom = WKBMaybeMultiPoint::MultiPoint(WKBMultiPoint::new(
+            &buf,
+            Endianness::LittleEndian,
+            Dimension::XY,
+        ));
 
         assert!(wkb_geom.equals_multi_point(geom));
     }
@@ -1,4 +1,5 @@
 use crate::algorithm::native::eq::multi_polygon_eq;
+use crate::array::Dimension;
 use crate::geo_traits::MultiPolygonTrait;
 use crate::io::wkb::reader::multipolygon::WKBMultiPolygon;
 use crate::io::wkb::reader::polygon::WKBPolygon;
@@ -15,6 +16,14 @@ pub enum WKBMaybeMultiPolygon<'a> {
 }
 
 impl<'a> WKBMaybeMultiPolygon<'a> {
+    /// The dimension of this geometry
+    pub fn dim(&self) -> Dimension {
+        match self {
+            WKBMaybeMultiPolygon::Polygon(geom) => geom.dim(),
+            WKBMaybeMultiPolygon::MultiPolygon(geom) => geom.dim(),
+        }
+    }
+
     /// Check if this has equal coordinates as some other MultiPolygon object
     pub fn equals_multi_polygon(&self, other: impl MultiPolygonTrait<'a, T = f64>) -> bool {
         multi_polygon_eq(self, other)
@@ -89,8 +98,12 @@ mod test {
         let buf = geo::Geometry::Polygon(geom.clone())
             .to_wkb(CoordDimensions::xy())
             .unwrap();
-        let wkb_geom =
-            WKBMaybeMultiPolygon::Polygon(WKBPolygon::new(&buf, Endianness::LittleEndian, 0));
+        let wkb_geom = WKBMaybeMultiPolygon::Polygon(WKBPolygon::new(
+            &buf,
+            Endianness::LittleEndian,
+            0,
+            Dimension::XY,
+        ));
 
         assert!(wkb_geom.equals_multi_polygon(geo::MultiPolygon(vec![geom])));
     }
@@ -104,6 +117,7 @@ mod test {
         let wkb_geom = WKBMaybeMultiPolygon::MultiPolygon(WKBMultiPolygon::new(
             &buf,
             Endianness::LittleEndian,
+            Dimension::XY,
         ));
 
         assert!(wkb_geom.equals_multi_polygon(geom));
@@ -5,6 +5,7 @@ use std::slice::Iter;
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
 
 use crate::algorithm::native::eq::multi_line_string_eq;
+use crate::array::Dimension;
 use crate::geo_traits::MultiLineStringTrait;
 use crate::io::wkb::reader::geometry::Endianness;
 use crate::io::wkb::reader::linestring::WKBLineString;
@@ -15,10 +16,12 @@ const HEADER_BYTES: u64 = 5;
 pub struct WKBMultiLineString<'a> {
     /// A WKBLineString object for each of the internal line strings
     wkb_line_strings: Vec<WKBLineString<'a>>,
+
+    dim: Dimension,
 }
 
 impl<'a> WKBMultiLineString<'a> {
-    pub fn new(buf: &'a [u8], byte_order: Endianness) -> Self {
+    pub fn new(buf: &'a [u8], byte_order: Endianness, dim: Dimension) -> Self {
         let mut reader = Cursor::new(buf);
         reader.set_position(HEADER_BYTES);
         let num_line_strings = match byte_order {
@@ -36,12 +39,20 @@ impl<'a> WKBMultiLineString<'a> {
         let mut line_string_offset = 1 + 4 + 4;
         let mut wkb_line_strings = Vec::with_capacity(num_line_strings);
         for _ in 0..num_line_strings {
-            let ls = WKBLineString::new(buf, byte_order, line_string_offset);
+            let ls = WKBLineString::new(buf, byte_order, line_string_offset, dim);
             wkb_line_strings.push(ls);
             line_string_offset += ls.size();
         }
 
-        Self { wkb_line_strings }
+        Self {
+            wkb_line_strings,
+            dim,
+        }
+    }
+
+    /// The dimension of this multi line string
+    pub fn dim(&self) -> Dimension {
+        self.dim
     }
 
     /// The number of bytes in this object, including any header
@@ -51,7 +62,7 @@ impl<'a> WKBMultiLineString<'a> {
         // - 1: byteOrder
         // - 4: wkbType
         // - 4: numPoints
-        // - WKBPoint::size() * self.num_points: the size of each WKBPoint for each point
+        // - size of each line string
         self.wkb_line_strings
             .iter()
             .fold(1 + 4 + 4, |acc, ls| acc + ls.size())
@@ -73,7 +84,7 @@ impl<'a> MultiLineStringTrait<'a> for WKBMultiLineString<'a> {
     }
 
     fn line(&self, i: usize) -> Option<Self::ItemType> {
-        if i > self.num_lines() {
+        if i >= self.num_lines() {
             return None;
         }
 
@@ -95,7 +106,7 @@ impl<'a> MultiLineStringTrait<'a> for &WKBMultiLineString<'a> {
     }
 
     fn line(&self, i: usize) -> Option<Self::ItemType> {
-        if i > self.num_lines() {
+        if i >= self.num_lines() {
             return None;
         }
 
@@ -119,7 +130,7 @@ mod test {
         let buf = geo::Geometry::MultiLineString(geom.clone())
             .to_wkb(CoordDimensions::xy())
             .unwrap();
-        let wkb_geom = WKBMultiLineString::new(&buf, Endianness::LittleEndian);
+        let wkb_geom = WKBMultiLineString::new(&buf, Endianness::LittleEndian, Dimension::XY);
 
         assert!(wkb_geom.equals_multi_line_string(geom));
     }
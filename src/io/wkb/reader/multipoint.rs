@@ -5,6 +5,7 @@ use std::slice::Iter;
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
 
 use crate::algorithm::native::eq::multi_point_eq;
+use crate::array::Dimension;
 use crate::geo_traits::MultiPointTrait;
 use crate::io::wkb::reader::geometry::Endianness;
 use crate::io::wkb::reader::point::WKBPoint;
@@ -16,10 +17,12 @@ pub struct WKBMultiPoint<'a> {
 
     /// The number of points in this multi point
     num_points: usize,
+
+    dim: Dimension,
 }
 
 impl<'a> WKBMultiPoint<'a> {
-    pub fn new(buf: &'a [u8], byte_order: Endianness) -> Self {
+    pub fn new(buf: &'a [u8], byte_order: Endianness, dim: Dimension) -> Self {
         // TODO: assert WKB type?
         let mut reader = Cursor::new(buf);
         // Set reader to after 1-byte byteOrder and 4-byte wkbType
@@ -37,6 +40,7 @@ impl<'a> WKBMultiPoint<'a> {
             buf,
             byte_order,
             num_points,
+            dim,
         }
     }
 
@@ -47,13 +51,18 @@ impl<'a> WKBMultiPoint<'a> {
         // - 1: byteOrder
         // - 4: wkbType
         // - 4: numPoints
-        // - WKBPoint::size() * self.num_points: the size of each WKBPoint for each point
-        1 + 4 + 4 + (WKBPoint::size() * self.num_points as u64)
+        // - WKBPoint::size_of(self.dim) * self.num_points: the size of each WKBPoint
+        1 + 4 + 4 + (WKBPoint::size_of(self.dim) * self.num_points as u64)
     }
 
     /// The offset into this buffer of any given WKBPoint
     pub fn point_offset(&self, i: u64) -> u64 {
-        1 + 4 + 4 + (WKBPoint::size() * i)
+        1 + 4 + 4 + (WKBPoint::size_of(self.dim) * i)
+    }
+
+    /// The dimension of this multi point
+    pub fn dim(&self) -> Dimension {
+        self.dim
     }
 
     /// Check if this WKBMultiPoint has equal coordinates as some other MultiPoint object
@@ -72,7 +81,7 @@ impl<'a> MultiPointTrait<'a> for WKBMultiPoint<'a> {
     }
 
     fn point(&self, i: usize) -> Option<Self::ItemType> {
-        if i > self.num_points() {
+        if i >= self.num_points() {
             return None;
         }
 
@@ -80,6 +89,7 @@ impl<'a> MultiPointTrait<'a> for WKBMultiPoint<'a> {
             self.buf,
             self.byte_order,
             self.point_offset(i.try_into().unwrap()),
+            self.dim,
         ))
     }
 
@@ -98,7 +108,7 @@ impl<'a> MultiPointTrait<'a> for &WKBMultiPoint<'a> {
     }
 
     fn point(&self, i: usize) -> Option<Self::ItemType> {
-        if i > self.num_points() {
+        if i >= self.num_points() {
             return None;
         }
 
@@ -106,6 +116,7 @@ impl<'a> MultiPointTrait<'a> for &WKBMultiPoint<'a> {
             self.buf,
             self.byte_order,
             self.point_offset(i.try_into().unwrap()),
+            self.dim,
         ))
     }
 
@@ -126,7 +137,7 @@ mod test {
         let buf = geo::Geometry::MultiPoint(geom.clone())
             .to_wkb(CoordDimensions::xy())
             .unwrap();
-        let wkb_geom = WKBMultiPoint::new(&buf, Endianness::LittleEndian);
+        let wkb_geom = WKBMultiPoint::new(&buf, Endianness::LittleEndian, Dimension::XY);
 
         assert!(wkb_geom.equals_multi_point(geom));
     }
@@ -5,6 +5,7 @@ use std::slice::Iter;
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
 
 use crate::algorithm::native::eq::multi_polygon_eq;
+use crate::array::Dimension;
 use crate::geo_traits::MultiPolygonTrait;
 use crate::io::wkb::reader::geometry::Endianness;
 use crate::io::wkb::reader::polygon::WKBPolygon;
@@ -25,10 +26,12 @@ pub struct WKBMultiPolygon<'a> {
     // // polygon_offsets: Vec<usize>,
     /// A WKBPolygon object for each of the internal line strings
     wkb_polygons: Vec<WKBPolygon<'a>>,
+
+    dim: Dimension,
 }
 
 impl<'a> WKBMultiPolygon<'a> {
-    pub fn new(buf: &'a [u8], byte_order: Endianness) -> Self {
+    pub fn new(buf: &'a [u8], byte_order: Endianness, dim: Dimension) -> Self {
         let mut reader = Cursor::new(buf);
         reader.set_position(HEADER_BYTES);
         let num_polygons = match byte_order {
@@ -46,12 +49,30 @@ impl<'a> WKBMultiPolygon<'a> {
         let mut polygon_offset = 1 + 4 + 4;
         let mut wkb_polygons = Vec::with_capacity(num_polygons);
         for _ in 0..num_polygons {
-            let polygon = WKBPolygon::new(buf, byte_order, polygon_offset);
+            let polygon = WKBPolygon::new(buf, byte_order, polygon_offset, dim);
             polygon_offset += polygon.size();
             wkb_polygons.push(polygon);
         }
 
-        Self { wkb_polygons }
+        Self { wkb_polygons, dim }
+    }
+
+    /// The dimension of this multi polygon
+    pub fn dim(&self) -> Dimension {
+        self.dim
+    }
+
+    /// The number of bytes in this object, including any header
+    ///
+    /// Note that this is not the same as the length of the underlying buffer
+    pub fn size(&self) -> u64 {
+        // - 1: byteOrder
+        // - 4: wkbType
+        // - 4: numPolygons
+        // - size of each polygon
+        self.wkb_polygons
+            .iter()
+            .fold(1 + 4 + 4, |acc, polygon| acc + polygon.size())
     }
 
     /// Check if this WKBMultiLineString has equal coordinates as some other MultiLineString object
@@ -70,7 +91,7 @@ impl<'a> MultiPolygonTrait<'a> for WKBMultiPolygon<'a> {
     }
 
     fn polygon(&self, i: usize) -> Option<Self::ItemType> {
-        if i > self.num_polygons() {
+        if i >= self.num_polygons() {
             return None;
         }
 
@@ -92,7 +113,7 @@ impl<'a> MultiPolygonTrait<'a> for &WKBMultiPolygon<'a> {
     }
 
     fn polygon(&self, i: usize) -> Option<Self::ItemType> {
-        if i > self.num_polygons() {
+        if i >= self.num_polygons() {
             return None;
         }
 
@@ -116,7 +137,7 @@ mod test {
         let buf = geo::Geometry::MultiPolygon(geom.clone())
             .to_wkb(CoordDimensions::xy())
             .unwrap();
-        let wkb_geom = WKBMultiPolygon::new(&buf, Endianness::LittleEndian);
+        let wkb_geom = WKBMultiPolygon::new(&buf, Endianness::LittleEndian, Dimension::XY);
 
         assert!(wkb_geom.equals_multi_polygon(geom));
     }
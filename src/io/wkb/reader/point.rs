@@ -1,41 +1,53 @@
 use crate::algorithm::native::eq::point_eq;
+use crate::array::Dimension;
 use crate::geo_traits::{CoordTrait, MultiPointTrait, PointTrait};
 use crate::io::wkb::reader::coord::WKBCoord;
 use crate::io::wkb::reader::geometry::Endianness;
 use std::iter::Cloned;
 use std::slice::Iter;
 
-/// A 2D Point in WKB
+/// A Point in WKB, with or without z and m values
 ///
 /// See page 66 of <https://portal.ogc.org/files/?artifact_id=25355>.
 #[derive(Debug, Clone, Copy)]
 pub struct WKBPoint<'a> {
     /// The coordinate inside this WKBPoint
     coord: WKBCoord<'a>,
+
+    dim: Dimension,
 }
 
 impl<'a> WKBPoint<'a> {
-    pub fn new(buf: &'a [u8], byte_order: Endianness, offset: u64) -> Self {
+    pub fn new(buf: &'a [u8], byte_order: Endianness, offset: u64, dim: Dimension) -> Self {
         // The space of the byte order + geometry type
         let offset = offset + 5;
-        let coord = WKBCoord::new(buf, byte_order, offset);
-        Self { coord }
+        let coord = WKBCoord::new(buf, byte_order, offset, dim);
+        Self { coord, dim }
     }
 
     /// The number of bytes in this object, including any header
     ///
     /// Note that this is not the same as the length of the underlying buffer
-    pub fn size() -> u64 {
+    pub fn size(&self) -> u64 {
+        Self::size_of(self.dim)
+    }
+
+    /// The number of bytes in a WKBPoint of the given dimension, including its header
+    pub fn size_of(dim: Dimension) -> u64 {
         // - 1: byteOrder
         // - 4: wkbType
-        // - 4: numPoints
-        // - 2 * 8: two f64s
-        1 + 4 + (2 * 8)
+        // - 8 per value: two to four f64s
+        1 + 4 + WKBCoord::size_of(dim)
+    }
+
+    /// The dimension of this point
+    pub fn dim(&self) -> Dimension {
+        self.dim
     }
 
     /// Check if this WKBPoint has equal coordinates as some other Point object
     pub fn equals_point(&self, other: impl PointTrait<T = f64>) -> bool {
-        // TODO: how is an empty point stored in WKB?
+        // An empty point is stored as NaN coordinates, which point_eq treats as equal
         point_eq(self, other, true)
     }
 }
@@ -50,6 +62,14 @@ impl<'a> PointTrait for WKBPoint<'a> {
     fn y(&self) -> Self::T {
         CoordTrait::y(&self.coord)
     }
+
+    fn z(&self) -> Option<Self::T> {
+        CoordTrait::z(&self.coord)
+    }
+
+    fn m(&self) -> Option<Self::T> {
+        CoordTrait::m(&self.coord)
+    }
 }
 
 impl<'a> PointTrait for &WKBPoint<'a> {
@@ -62,6 +82,14 @@ impl<'a> PointTrait for &WKBPoint<'a> {
     fn y(&self) -> Self::T {
         CoordTrait::y(&self.coord)
     }
+
+    fn z(&self) -> Option<Self::T> {
+        CoordTrait::z(&self.coord)
+    }
+
+    fn m(&self) -> Option<Self::T> {
+        CoordTrait::m(&self.coord)
+    }
 }
 
 impl<'a> MultiPointTrait<'a> for WKBPoint<'a> {
@@ -74,7 +102,7 @@ impl<'a> MultiPointTrait<'a> for WKBPoint<'a> {
     }
 
     fn point(&self, i: usize) -> Option<Self::ItemType> {
-        if i > self.num_points() {
+        if i >= self.num_points() {
             return None;
         }
 
@@ -96,7 +124,7 @@ impl<'a> MultiPointTrait<'a> for &WKBPoint<'a> {
     }
 
     fn point(&self, i: usize) -> Option<Self::ItemType> {
-        if i > self.num_points() {
+        if i >= self.num_points() {
             return None;
         }
 
@@ -120,7 +148,7 @@ mod test {
         let buf = geo::Geometry::Point(point)
             .to_wkb(CoordDimensions::xy())
             .unwrap();
-        let wkb_point = WKBPoint::new(&buf, Endianness::LittleEndian, 0);
+        let wkb_point = WKBPoint::new(&buf, Endianness::LittleEndian, 0, Dimension::XY);
 
         assert!(wkb_point.equals_point(point));
     }
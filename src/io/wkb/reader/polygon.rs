@@ -5,30 +5,23 @@ use std::slice::Iter;
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
 
 use crate::algorithm::native::eq::polygon_eq;
+use crate::array::Dimension;
 use crate::geo_traits::{MultiPolygonTrait, PolygonTrait};
 use crate::io::wkb::reader::geometry::Endianness;
 use crate::io::wkb::reader::linearring::WKBLinearRing;
 
-const WKB_POLYGON_TYPE: u32 = 3;
-
 #[derive(Debug, Clone)]
 pub struct WKBPolygon<'a> {
     wkb_linear_rings: Vec<WKBLinearRing<'a>>,
+
+    dim: Dimension,
 }
 
 impl<'a> WKBPolygon<'a> {
-    pub fn new(buf: &'a [u8], byte_order: Endianness, offset: u64) -> Self {
+    pub fn new(buf: &'a [u8], byte_order: Endianness, offset: u64, dim: Dimension) -> Self {
         let mut reader = Cursor::new(buf);
-        reader.set_position(1 + offset);
-
-        // Assert that this is indeed a 2D Polygon
-        assert_eq!(
-            WKB_POLYGON_TYPE,
-            match byte_order {
-                Endianness::BigEndian => reader.read_u32::<BigEndian>().unwrap(),
-                Endianness::LittleEndian => reader.read_u32::<LittleEndian>().unwrap(),
-            }
-        );
+        // Skip the byte order and the geometry type, which was read by the caller
+        reader.set_position(1 + 4 + offset);
 
         let num_rings = match byte_order {
             Endianness::BigEndian => reader.read_u32::<BigEndian>().unwrap().try_into().unwrap(),
@@ -46,12 +39,20 @@ impl<'a> WKBPolygon<'a> {
         let mut ring_offset = offset + 1 + 4 + 4;
         let mut wkb_linear_rings = Vec::with_capacity(num_rings);
         for _ in 0..num_rings {
-            let polygon = WKBLinearRing::new(buf, byte_order, ring_offset);
+            let polygon = WKBLinearRing::new(buf, byte_order, ring_offset, dim);
             wkb_linear_rings.push(polygon);
             ring_offset += polygon.size();
         }
 
-        Self { wkb_linear_rings }
+        Self {
+            wkb_linear_rings,
+            dim,
+        }
+    }
+
+    /// The dimension of this polygon
+    pub fn dim(&self) -> Dimension {
+        self.dim
     }
 
     /// The number of bytes in this object, including any header
@@ -100,7 +101,7 @@ impl<'a> PolygonTrait<'a> for WKBPolygon<'a> {
     }
 
     fn interior(&self, i: usize) -> Option<Self::ItemType> {
-        if i > self.num_interiors() {
+        if i >= self.num_interiors() {
             return None;
         }
 
@@ -135,7 +136,7 @@ impl<'a> PolygonTrait<'a> for &WKBPolygon<'a> {
     }
 
     fn interior(&self, i: usize) -> Option<Self::ItemType> {
-        if i > self.num_interiors() {
+        if i >= self.num_interiors() {
             return None;
         }
 
@@ -157,7 +158,7 @@ impl<'a> MultiPolygonTrait<'a> for WKBPolygon<'a> {
     }
 
     fn polygon(&self, i: usize) -> Option<Self::ItemType> {
-        if i > self.num_polygons() {
+        if i >= self.num_polygons() {
             return None;
         }
 
@@ -179,7 +180,7 @@ impl<'a> MultiPolygonTrait<'a> for &WKBPolygon<'a> {
     }
 
     fn polygon(&self, i: usize) -> Option<Self::ItemType> {
-        if i > self.num_polygons() {
+        if i >= self.num_polygons() {
             return None;
         }
 
@@ -205,7 +206,7 @@ mod test {
         let buf = geo::Geometry::Polygon(geom.clone())
             .to_wkb(CoordDimensions::xy())
             .unwrap();
-        let wkb_geom = WKBPolygon::new(&buf, Endianness::LittleEndian, 0);
+        let wkb_geom = WKBPolygon::new(&buf, Endianness::LittleEndian, 0, Dimension::XY);
 
         assert!(wkb_geom.equals_polygon(geom));
     }
@@ -2,7 +2,7 @@
 
 use num_enum::TryFromPrimitive;
 
-#[derive(Debug, Eq, PartialEq, TryFromPrimitive)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, TryFromPrimitive)]
 #[repr(u32)]
 pub enum WKBGeometryType {
     Point = 1,
@@ -1,4 +1,4 @@
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
 use crate::geo_traits::{GeometryTrait, GeometryType};
 use crate::io::wkb::writer::linestring::{line_string_wkb_size, write_line_string_as_wkb};
 use crate::io::wkb::writer::multilinestring::{
@@ -6,7 +6,7 @@ use crate::io::wkb::writer::multilinestring::{
 };
 use crate::io::wkb::writer::multipoint::{multi_point_wkb_size, write_multi_point_as_wkb};
 use crate::io::wkb::writer::multipolygon::{multi_polygon_wkb_size, write_multi_polygon_as_wkb};
-use crate::io::wkb::writer::point::{write_point_as_wkb, POINT_WKB_SIZE};
+use crate::io::wkb::writer::point::{point_wkb_size, write_point_as_wkb};
 use crate::io::wkb::writer::polygon::{polygon_wkb_size, write_polygon_as_wkb};
 use std::io::Write;
 
@@ -14,7 +14,7 @@ use std::io::Write;
 pub fn geometry_wkb_size<'a>(geom: &'a impl GeometryTrait<'a>) -> usize {
     use GeometryType::*;
     match geom.as_type() {
-        Point(_) => POINT_WKB_SIZE,
+        Point(p) => point_wkb_size(p),
         LineString(ls) => line_string_wkb_size(ls),
         Polygon(p) => polygon_wkb_size(p),
         MultiPoint(mp) => multi_point_wkb_size(mp),
@@ -37,21 +37,49 @@ pub fn write_geometry_as_wkb<'a, W: Write>(
         MultiPoint(mp) => write_multi_point_as_wkb(writer, mp),
         MultiLineString(ml) => write_multi_line_string_as_wkb(writer, ml),
         MultiPolygon(mp) => write_multi_polygon_as_wkb(writer, mp),
-        _ => todo!(),
+        GeometryCollection(_) | Rect(_) => Err(GeoArrowError::NotYetImplemented(
+            "Writing GeometryCollection and Rect geometries to WKB".to_string(),
+        )),
     }
 }
 
-// #[cfg(test)]
-// mod test {
-//     use super::*;
-//     use crate::test::multilinestring::{ml0, ml1};
+/// Append any geometry implementing [`GeometryTrait`] to `out`, encoded as little-endian WKB.
+///
+/// This lets types outside this crate be serialized without first building an array.
+///
+/// # Errors
+///
+/// - if the geometry is a GeometryCollection or Rect, which can't be written yet. Nothing is
+///   appended to `out` in that case.
+pub fn write_wkb_geom<'a, G: GeometryTrait<'a, T = f64>>(
+    geom: &'a G,
+    out: &mut Vec<u8>,
+) -> Result<()> {
+    write_geometry_as_wkb(out, geom)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{PolygonArray, WKBArray};
+    use crate::test::polygon::p1;
+    use crate::trait_::GeoArrayAccessor;
+    use arrow_array::BinaryArray;
 
-//     #[test]
-//     fn round_trip() {
-//         let orig_arr: MultiLineStringArray<i32> = vec![Some(ml0()), Some(ml1()), None].into();
-//         let wkb_arr: WKBArray<i32> = (&orig_arr).into();
-//         let new_arr: MultiLineStringArray<i32> = wkb_arr.try_into().unwrap();
+    #[test]
+    fn polygon_round_trip() {
+        let geom = geo::Geometry::Polygon(p1());
+        let mut out = vec![];
+        write_wkb_geom(&geom, &mut out).unwrap();
+        assert_eq!(out.len(), geometry_wkb_size(&geom));
 
-//         assert_eq!(orig_arr, new_arr);
-//     }
-// }
+        let wkb_arr: WKBArray<i32> = BinaryArray::from_vec(vec![out.as_slice()]).into();
+        let polygon_arr: PolygonArray<i32> = wkb_arr.try_into().unwrap();
+        assert_eq!(polygon_arr.value_as_geo(0), p1());
+
+        let rect = geo::Geometry::Rect(geo::Rect::new((0., 0.), (1., 1.)));
+        let mut out = vec![];
+        assert!(write_wkb_geom(&rect, &mut out).is_err());
+        assert!(out.is_empty());
+    }
+}
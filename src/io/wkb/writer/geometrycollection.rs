@@ -80,17 +80,41 @@ impl<A: OffsetSizeTrait, B: OffsetSizeTrait> From<&GeometryCollectionArray<A>> f
     }
 }
 
-// #[cfg(test)]
-// mod test {
-//     use super::*;
-//     use crate::test::multipolygon::{mp0, mp1};
-
-//     #[test]
-//     fn round_trip() {
-//         let orig_arr: GeometryCollectionArray<i32> = vec![Some(mp0()), Some(mp1()), None].into();
-//         let wkb_arr: WKBArray<i32> = (&orig_arr).into();
-//         let new_arr: GeometryCollectionArray<i32> = wkb_arr.try_into().unwrap();
-
-//         assert_eq!(orig_arr, new_arr);
-//     }
-// }
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::geo_traits::GeometryCollectionTrait;
+    use crate::test::geometrycollection::{gc0, gc1};
+    use crate::trait_::GeoArrayAccessor;
+    use arrow_array::BinaryArray;
+
+    #[test]
+    fn round_trip() {
+        let orig_arr: GeometryCollectionArray<i32> =
+            vec![Some(gc0()), Some(gc1()), None].try_into().unwrap();
+        let wkb_arr: WKBArray<i32> = (&orig_arr).into();
+        let new_arr: GeometryCollectionArray<i32> = wkb_arr.try_into().unwrap();
+
+        assert_eq!(
+            orig_arr.iter_geo().collect::<Vec<_>>(),
+            new_arr.iter_geo().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn round_trip_empty() {
+        // GEOMETRYCOLLECTION EMPTY
+        let empty = vec![1, 7, 0, 0, 0, 0, 0, 0, 0];
+        let wkb_arr: WKBArray<i32> =
+            BinaryArray::from_opt_vec(vec![Some(empty.as_slice()), None]).into();
+
+        let arr: GeometryCollectionArray<i32> = wkb_arr.try_into().unwrap();
+        assert!(arr.is_valid(0));
+        assert_eq!(arr.value(0).num_geometries(), 0);
+        assert!(arr.is_null(1));
+
+        let new_arr: WKBArray<i32> = (&arr).into();
+        assert_eq!(new_arr.value(0).as_ref(), empty.as_slice());
+        assert!(new_arr.is_null(1));
+    }
+}
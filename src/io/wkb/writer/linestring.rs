@@ -1,8 +1,9 @@
 use crate::array::mutable_offset::OffsetsBuilder;
 use crate::array::{LineStringArray, WKBArray};
 use crate::error::Result;
-use crate::geo_traits::{CoordTrait, LineStringTrait};
+use crate::geo_traits::LineStringTrait;
 use crate::io::wkb::reader::geometry::Endianness;
+use crate::io::wkb::writer::{line_string_dimension, wkb_type_code, write_coord};
 use crate::trait_::GeometryArrayTrait;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
 use byteorder::{LittleEndian, WriteBytesExt};
@@ -10,7 +11,7 @@ use std::io::{Cursor, Write};
 
 /// The byte length of a WKBLineString
 pub fn line_string_wkb_size<'a>(geom: &impl LineStringTrait<'a>) -> usize {
-    1 + 4 + 4 + (geom.num_coords() * 16)
+    1 + 4 + 4 + (geom.num_coords() * 8 * line_string_dimension(geom).size())
 }
 
 /// Write a LineString geometry to a Writer encoded as WKB
@@ -21,8 +22,11 @@ pub fn write_line_string_as_wkb<'a, W: Write>(
     // Byte order
     writer.write_u8(Endianness::LittleEndian.into()).unwrap();
 
-    // wkbType = 2
-    writer.write_u32::<LittleEndian>(2).unwrap();
+    // wkbType = 2, plus 1000 with z and 2000 with m
+    let dim = line_string_dimension(geom);
+    writer
+        .write_u32::<LittleEndian>(wkb_type_code(2, dim))
+        .unwrap();
 
     // numPoints
     writer
@@ -31,8 +35,7 @@ pub fn write_line_string_as_wkb<'a, W: Write>(
 
     for coord_idx in 0..geom.num_coords() {
         let coord = geom.coord(coord_idx).unwrap();
-        writer.write_f64::<LittleEndian>(coord.x()).unwrap();
-        writer.write_f64::<LittleEndian>(coord.y()).unwrap();
+        write_coord(&mut writer, &coord, dim).unwrap();
     }
 
     Ok(())
@@ -71,7 +74,13 @@ impl<A: OffsetSizeTrait, B: OffsetSizeTrait> From<&LineStringArray<A>> for WKBAr
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::array::{
+        CoordBuffer, CoordType, Dimension, InterleavedCoordBuffer, SeparatedCoordBuffer,
+    };
     use crate::test::linestring::{ls0, ls1};
+    use crate::trait_::GeoArrayAccessor;
+    use arrow_array::BinaryArray;
+    use arrow_buffer::OffsetBuffer;
 
     #[test]
     fn round_trip() {
@@ -82,6 +91,68 @@ mod test {
         assert_eq!(orig_arr, new_arr);
     }
 
+    #[test]
+    fn round_trip_xyz() {
+        let coords = InterleavedCoordBuffer::new_with_dim(
+            vec![0., 1., 2., 3., 4., 5., 6., 7., 8.].into(),
+            Dimension::XYZ,
+        );
+        let geom_offsets = OffsetBuffer::new(vec![0, 2, 3].into());
+        let orig_arr: LineStringArray<i32> =
+            LineStringArray::new(CoordBuffer::Interleaved(coords), geom_offsets, None);
+
+        let wkb_arr: WKBArray<i32> = (&orig_arr).into();
+        // Type code 1002, then two coordinates of three ordinates
+        assert_eq!(wkb_arr.value(0).as_ref()[1..5], 1002u32.to_le_bytes());
+        assert_eq!(wkb_arr.value(0).as_ref().len(), 1 + 4 + 4 + 2 * 24);
+
+        let new_arr: LineStringArray<i32> = wkb_arr.try_into().unwrap();
+        assert_eq!(new_arr.coords.dim(), Dimension::XYZ);
+        assert_eq!(new_arr.coords.get_z(1), Some(5.));
+        assert_eq!(orig_arr, new_arr);
+    }
+
+    #[test]
+    fn round_trip_xym() {
+        let coords = SeparatedCoordBuffer::new_with_zm(
+            vec![0., 3., 6.].into(),
+            vec![1., 4., 7.].into(),
+            None,
+            Some(vec![2., 5., 8.].into()),
+        );
+        let geom_offsets = OffsetBuffer::new(vec![0, 2, 3].into());
+        let orig_arr: LineStringArray<i32> =
+            LineStringArray::new(CoordBuffer::Separated(coords), geom_offsets, None);
+
+        let wkb_arr: WKBArray<i32> = (&orig_arr).into();
+        // Type code 2002, then two coordinates of three ordinates
+        assert_eq!(wkb_arr.value(0).as_ref()[1..5], 2002u32.to_le_bytes());
+        assert_eq!(wkb_arr.value(0).as_ref().len(), 1 + 4 + 4 + 2 * 24);
+
+        let new_arr: LineStringArray<i32> = wkb_arr.try_into().unwrap();
+        assert_eq!(new_arr.coords.dim(), Dimension::XYM);
+        assert_eq!(new_arr.coords.get_z(1), None);
+        assert_eq!(new_arr.coords.get_m(1), Some(5.));
+        assert_eq!(orig_arr.into_coord_type(CoordType::Interleaved), new_arr);
+    }
+
+    #[test]
+    fn parse_ewkb_xyzm() {
+        // EWKB sets a flag for each of z and m instead of adding to the type code
+        let mut ewkb = vec![1];
+        ewkb.extend((2u32 | 0x8000_0000 | 0x4000_0000).to_le_bytes());
+        ewkb.extend(1u32.to_le_bytes());
+        for value in [1., 2., 3., 4.] {
+            ewkb.extend(f64::to_le_bytes(value));
+        }
+        let wkb_arr: WKBArray<i32> = BinaryArray::from_vec(vec![ewkb.as_slice()]).into();
+
+        let arr: LineStringArray<i32> = wkb_arr.try_into().unwrap();
+        assert_eq!(arr.coords.dim(), Dimension::XYZM);
+        assert_eq!(arr.coords.get_z(0), Some(3.));
+        assert_eq!(arr.coords.get_m(0), Some(4.));
+    }
+
     // // TODO: parsing WKBArray<i64> into LineStringArray<i32> not yet implemented
     // fn round_trip_to_i64() {
     //     let orig_arr: LineStringArray<i32> = vec![Some(ls0()), Some(ls1()), None].into();
@@ -90,4 +161,21 @@ mod test {
 
     //     assert_eq!(orig_arr, new_arr);
     // }
+
+    #[test]
+    fn round_trip_empty() {
+        // LINESTRING EMPTY
+        let empty = vec![1, 2, 0, 0, 0, 0, 0, 0, 0];
+        let wkb_arr: WKBArray<i32> =
+            BinaryArray::from_opt_vec(vec![Some(empty.as_slice()), None]).into();
+
+        let arr: LineStringArray<i32> = wkb_arr.try_into().unwrap();
+        assert!(arr.is_valid(0));
+        assert_eq!(arr.value(0).num_coords(), 0);
+        assert!(arr.is_null(1));
+
+        let new_arr: WKBArray<i32> = (&arr).into();
+        assert_eq!(new_arr.value(0).as_ref(), empty.as_slice());
+        assert!(new_arr.is_null(1));
+    }
 }
@@ -0,0 +1,105 @@
+use crate::array::mutable_offset::OffsetsBuilder;
+use crate::array::{MixedGeometryArray, WKBArray};
+use crate::io::wkb::writer::geometry::{geometry_wkb_size, write_geometry_as_wkb};
+use crate::trait_::GeoArrayAccessor;
+use crate::GeometryArrayTrait;
+use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
+use std::io::Cursor;
+
+impl<A: OffsetSizeTrait, B: OffsetSizeTrait> From<&MixedGeometryArray<A>> for WKBArray<B> {
+    fn from(value: &MixedGeometryArray<A>) -> Self {
+        // Mixed arrays have no validity of their own, so nulls come from the children
+        let nulls = value.logical_nulls();
+        let is_valid = |i: usize| nulls.as_ref().map_or(true, |nulls| nulls.is_valid(i));
+
+        let mut offsets: OffsetsBuilder<B> = OffsetsBuilder::with_capacity(value.len());
+
+        // First pass: calculate binary array offsets
+        for i in 0..value.len() {
+            if is_valid(i) {
+                offsets
+                    .try_push_usize(geometry_wkb_size(&value.value(i)))
+                    .unwrap();
+            } else {
+                offsets.extend_constant(1);
+            }
+        }
+
+        let values = {
+            let values = Vec::with_capacity(offsets.last().to_usize().unwrap());
+            let mut writer = Cursor::new(values);
+
+            for i in (0..value.len()).filter(|i| is_valid(*i)) {
+                write_geometry_as_wkb(&mut writer, &value.value(i)).unwrap();
+            }
+
+            writer.into_inner()
+        };
+
+        let binary_arr = GenericBinaryArray::new(offsets.into(), values.into(), nulls);
+        WKBArray::new(binary_arr)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::{linestring, point, polygon};
+    use arrow_array::BinaryArray;
+
+    #[test]
+    fn round_trip() {
+        let geoms: Vec<geo::Geometry> = vec![
+            geo::Geometry::Point(point::p0()),
+            geo::Geometry::LineString(linestring::ls0()),
+            geo::Geometry::Polygon(polygon::p0()),
+        ];
+        let orig_arr: MixedGeometryArray<i32> = geoms.try_into().unwrap();
+        let wkb_arr: WKBArray<i32> = (&orig_arr).into();
+        let new_arr: MixedGeometryArray<i32> = wkb_arr.try_into().unwrap();
+
+        assert_eq!(new_arr.len(), orig_arr.len());
+        for i in 0..orig_arr.len() {
+            assert_eq!(new_arr.value_as_geo(i), orig_arr.value_as_geo(i));
+        }
+    }
+
+    #[test]
+    fn round_trip_with_null() {
+        let wkb_arr: WKBArray<i64> = vec![
+            Some(geo::Geometry::LineString(linestring::ls0())),
+            None,
+            Some(geo::Geometry::Point(point::p1())),
+        ]
+        .into();
+        let mixed_arr: MixedGeometryArray<i64> = wkb_arr.clone().try_into().unwrap();
+        assert!(mixed_arr.logical_nulls().unwrap().is_null(1));
+
+        let round_trip: WKBArray<i64> = (&mixed_arr).into();
+        assert_eq!(round_trip.get_as_geo(0), wkb_arr.get_as_geo(0));
+        assert!(round_trip.is_null(1));
+        assert_eq!(round_trip.get_as_geo(2), wkb_arr.get_as_geo(2));
+    }
+
+    #[test]
+    fn round_trip_empty() {
+        // POLYGON EMPTY and MULTIPOINT EMPTY next to a null
+        let empty_polygon = [1, 3, 0, 0, 0, 0, 0, 0, 0];
+        let empty_multi_point = [1, 4, 0, 0, 0, 0, 0, 0, 0];
+        let wkb_arr: WKBArray<i32> = BinaryArray::from_opt_vec(vec![
+            Some(empty_polygon.as_slice()),
+            None,
+            Some(empty_multi_point.as_slice()),
+        ])
+        .into();
+
+        let mixed_arr: MixedGeometryArray<i32> = wkb_arr.try_into().unwrap();
+        let nulls = mixed_arr.logical_nulls().unwrap();
+        assert!(nulls.is_valid(0) && nulls.is_null(1) && nulls.is_valid(2));
+
+        let round_trip: WKBArray<i32> = (&mixed_arr).into();
+        assert_eq!(round_trip.value(0).as_ref(), empty_polygon.as_slice());
+        assert!(round_trip.is_null(1));
+        assert_eq!(round_trip.value(2).as_ref(), empty_multi_point.as_slice());
+    }
+}
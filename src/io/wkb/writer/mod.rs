@@ -1,8 +1,52 @@
 pub mod geometry;
 pub mod geometrycollection;
 pub mod linestring;
+pub mod mixed;
 pub mod multilinestring;
 pub mod multipoint;
 pub mod multipolygon;
 pub mod point;
 pub mod polygon;
+
+pub use geometry::write_wkb_geom;
+
+use crate::array::Dimension;
+use crate::geo_traits::{CoordTrait, LineStringTrait};
+use byteorder::{LittleEndian, WriteBytesExt};
+use std::io::Write;
+
+/// The ISO WKB type code of `geometry_type` (1 for Point through 7 for GeometryCollection) in
+/// dimension `dim`.
+pub(crate) fn wkb_type_code(geometry_type: u32, dim: Dimension) -> u32 {
+    match dim {
+        Dimension::XY => geometry_type,
+        Dimension::XYZ => geometry_type + 1000,
+        Dimension::XYM => geometry_type + 2000,
+        Dimension::XYZM => geometry_type + 3000,
+    }
+}
+
+/// The dimension a line string is written in, taken from its first coordinate. Empty line strings
+/// are written as XY.
+pub(crate) fn line_string_dimension<'a>(geom: &impl LineStringTrait<'a>) -> Dimension {
+    geom.coord(0).map_or(Dimension::XY, |coord| {
+        Dimension::from_flags(coord.z().is_some(), coord.m().is_some())
+    })
+}
+
+/// Write the ordinates of `coord` that `dim` has. A missing z or m value is written as NaN.
+pub(crate) fn write_coord<W: Write>(
+    mut writer: W,
+    coord: &impl CoordTrait<T = f64>,
+    dim: Dimension,
+) -> std::io::Result<()> {
+    writer.write_f64::<LittleEndian>(coord.x())?;
+    writer.write_f64::<LittleEndian>(coord.y())?;
+    if dim.has_z() {
+        writer.write_f64::<LittleEndian>(coord.z().unwrap_or(f64::NAN))?;
+    }
+    if dim.has_m() {
+        writer.write_f64::<LittleEndian>(coord.m().unwrap_or(f64::NAN))?;
+    }
+    Ok(())
+}
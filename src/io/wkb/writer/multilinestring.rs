@@ -1,9 +1,10 @@
 use crate::array::mutable_offset::OffsetsBuilder;
-use crate::array::{MultiLineStringArray, WKBArray};
+use crate::array::{Dimension, MultiLineStringArray, WKBArray};
 use crate::error::Result;
 use crate::geo_traits::MultiLineStringTrait;
 use crate::io::wkb::reader::geometry::Endianness;
 use crate::io::wkb::writer::linestring::{line_string_wkb_size, write_line_string_as_wkb};
+use crate::io::wkb::writer::{line_string_dimension, wkb_type_code};
 use crate::trait_::GeometryArrayTrait;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
 use byteorder::{LittleEndian, WriteBytesExt};
@@ -28,8 +29,13 @@ pub fn write_multi_line_string_as_wkb<'a, W: Write>(
     // Byte order
     writer.write_u8(Endianness::LittleEndian.into()).unwrap();
 
-    // wkbType = 5
-    writer.write_u32::<LittleEndian>(5).unwrap();
+    // wkbType = 5, plus 1000 with z and 2000 with m
+    let dim = geom.line(0).map_or(Dimension::XY, |line_string| {
+        line_string_dimension(&line_string)
+    });
+    writer
+        .write_u32::<LittleEndian>(wkb_type_code(5, dim))
+        .unwrap();
 
     // numPoints
     writer
@@ -79,7 +85,10 @@ impl<A: OffsetSizeTrait, B: OffsetSizeTrait> From<&MultiLineStringArray<A>> for
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::geo_traits::MultiLineStringTrait;
     use crate::test::multilinestring::{ml0, ml1};
+    use crate::trait_::GeoArrayAccessor;
+    use arrow_array::BinaryArray;
 
     #[test]
     fn round_trip() {
@@ -89,4 +98,21 @@ mod test {
 
         assert_eq!(orig_arr, new_arr);
     }
+
+    #[test]
+    fn round_trip_empty() {
+        // MULTILINESTRING EMPTY
+        let empty = vec![1, 5, 0, 0, 0, 0, 0, 0, 0];
+        let wkb_arr: WKBArray<i32> =
+            BinaryArray::from_opt_vec(vec![Some(empty.as_slice()), None]).into();
+
+        let arr: MultiLineStringArray<i32> = wkb_arr.try_into().unwrap();
+        assert!(arr.is_valid(0));
+        assert_eq!(arr.value(0).num_lines(), 0);
+        assert!(arr.is_null(1));
+
+        let new_arr: WKBArray<i32> = (&arr).into();
+        assert_eq!(new_arr.value(0).as_ref(), empty.as_slice());
+        assert!(new_arr.is_null(1));
+    }
 }
@@ -1,9 +1,10 @@
 use crate::array::mutable_offset::OffsetsBuilder;
-use crate::array::{MultiPointArray, WKBArray};
+use crate::array::{Dimension, MultiPointArray, WKBArray};
 use crate::error::Result;
 use crate::geo_traits::MultiPointTrait;
 use crate::io::wkb::reader::geometry::Endianness;
-use crate::io::wkb::writer::point::{write_point_as_wkb, POINT_WKB_SIZE};
+use crate::io::wkb::writer::point::{point_dimension, point_wkb_size, write_point_as_wkb};
+use crate::io::wkb::writer::wkb_type_code;
 use crate::trait_::GeometryArrayTrait;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
 use byteorder::{LittleEndian, WriteBytesExt};
@@ -11,7 +12,12 @@ use std::io::{Cursor, Write};
 
 /// The byte length of a WKBMultiPoint
 pub fn multi_point_wkb_size<'a>(geom: &impl MultiPointTrait<'a>) -> usize {
-    1 + 4 + 4 + (geom.num_points() * POINT_WKB_SIZE)
+    let mut sum = 1 + 4 + 4;
+    for point_idx in 0..geom.num_points() {
+        sum += point_wkb_size(&geom.point(point_idx).unwrap());
+    }
+
+    sum
 }
 
 /// Write a MultiPoint geometry to a Writer encoded as WKB
@@ -22,8 +28,13 @@ pub fn write_multi_point_as_wkb<'a, W: Write>(
     // Byte order
     writer.write_u8(Endianness::LittleEndian.into()).unwrap();
 
-    // wkbType = 4
-    writer.write_u32::<LittleEndian>(4).unwrap();
+    // wkbType = 4, plus 1000 with z and 2000 with m
+    let dim = geom
+        .point(0)
+        .map_or(Dimension::XY, |point| point_dimension(&point));
+    writer
+        .write_u32::<LittleEndian>(wkb_type_code(4, dim))
+        .unwrap();
 
     // numPoints
     writer
@@ -71,7 +82,10 @@ impl<A: OffsetSizeTrait, B: OffsetSizeTrait> From<&MultiPointArray<A>> for WKBAr
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::geo_traits::MultiPointTrait;
     use crate::test::multipoint::{mp0, mp1};
+    use crate::trait_::GeoArrayAccessor;
+    use arrow_array::BinaryArray;
 
     #[test]
     fn round_trip() {
@@ -81,4 +95,21 @@ mod test {
 
         assert_eq!(orig_arr, new_arr);
     }
+
+    #[test]
+    fn round_trip_empty() {
+        // MULTIPOINT EMPTY
+        let empty = vec![1, 4, 0, 0, 0, 0, 0, 0, 0];
+        let wkb_arr: WKBArray<i32> =
+            BinaryArray::from_opt_vec(vec![Some(empty.as_slice()), None]).into();
+
+        let arr: MultiPointArray<i32> = wkb_arr.try_into().unwrap();
+        assert!(arr.is_valid(0));
+        assert_eq!(arr.value(0).num_points(), 0);
+        assert!(arr.is_null(1));
+
+        let new_arr: WKBArray<i32> = (&arr).into();
+        assert_eq!(new_arr.value(0).as_ref(), empty.as_slice());
+        assert!(new_arr.is_null(1));
+    }
 }
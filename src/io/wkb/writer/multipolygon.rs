@@ -1,9 +1,10 @@
 use crate::array::mutable_offset::OffsetsBuilder;
-use crate::array::{MultiPolygonArray, WKBArray};
+use crate::array::{Dimension, MultiPolygonArray, WKBArray};
 use crate::error::Result;
-use crate::geo_traits::MultiPolygonTrait;
+use crate::geo_traits::{MultiPolygonTrait, PolygonTrait};
 use crate::io::wkb::reader::geometry::Endianness;
 use crate::io::wkb::writer::polygon::{polygon_wkb_size, write_polygon_as_wkb};
+use crate::io::wkb::writer::{line_string_dimension, wkb_type_code};
 use crate::trait_::GeometryArrayTrait;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
 use byteorder::{LittleEndian, WriteBytesExt};
@@ -28,8 +29,14 @@ pub fn write_multi_polygon_as_wkb<'a, W: Write>(
     // Byte order
     writer.write_u8(Endianness::LittleEndian.into()).unwrap();
 
-    // wkbType = 6
-    writer.write_u32::<LittleEndian>(6).unwrap();
+    // wkbType = 6, plus 1000 with z and 2000 with m
+    let dim = geom
+        .polygon(0)
+        .and_then(|polygon| polygon.exterior())
+        .map_or(Dimension::XY, |ring| line_string_dimension(&ring));
+    writer
+        .write_u32::<LittleEndian>(wkb_type_code(6, dim))
+        .unwrap();
 
     // numPolygons
     writer
@@ -79,7 +86,10 @@ impl<A: OffsetSizeTrait, B: OffsetSizeTrait> From<&MultiPolygonArray<A>> for WKB
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::geo_traits::MultiPolygonTrait;
     use crate::test::multipolygon::{mp0, mp1};
+    use crate::trait_::GeoArrayAccessor;
+    use arrow_array::BinaryArray;
 
     #[test]
     fn round_trip() {
@@ -89,4 +99,21 @@ mod test {
 
         assert_eq!(orig_arr, new_arr);
     }
+
+    #[test]
+    fn round_trip_empty() {
+        // MULTIPOLYGON EMPTY
+        let empty = vec![1, 6, 0, 0, 0, 0, 0, 0, 0];
+        let wkb_arr: WKBArray<i32> =
+            BinaryArray::from_opt_vec(vec![Some(empty.as_slice()), None]).into();
+
+        let arr: MultiPolygonArray<i32> = wkb_arr.try_into().unwrap();
+        assert!(arr.is_valid(0));
+        assert_eq!(arr.value(0).num_polygons(), 0);
+        assert!(arr.is_null(1));
+
+        let new_arr: WKBArray<i32> = (&arr).into();
+        assert_eq!(new_arr.value(0).as_ref(), empty.as_slice());
+        assert!(new_arr.is_null(1));
+    }
 }
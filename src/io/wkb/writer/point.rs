@@ -1,26 +1,48 @@
 use crate::array::mutable_offset::OffsetsBuilder;
-use crate::array::{PointArray, WKBArray};
+use crate::array::{Dimension, PointArray, WKBArray};
 use crate::error::Result;
 use crate::geo_traits::PointTrait;
 use crate::io::wkb::reader::geometry::Endianness;
+use crate::io::wkb::writer::wkb_type_code;
 use crate::trait_::GeometryArrayTrait;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
 use byteorder::{LittleEndian, WriteBytesExt};
 use std::io::{Cursor, Write};
 
-/// The byte length of a WKBPoint
+/// The byte length of a two-dimensional WKBPoint
 pub const POINT_WKB_SIZE: usize = 1 + 4 + 8 + 8;
 
+/// The dimension a point is written in
+pub(crate) fn point_dimension(geom: &impl PointTrait) -> Dimension {
+    Dimension::from_flags(geom.z().is_some(), geom.m().is_some())
+}
+
+/// The byte length of a WKBPoint
+pub fn point_wkb_size(geom: &impl PointTrait) -> usize {
+    1 + 4 + 8 * point_dimension(geom).size()
+}
+
 /// Write a Point geometry to a Writer encoded as WKB
+///
+/// WKB has no count to mark an empty point, so `POINT EMPTY` is written as NaN coordinates.
 pub fn write_point_as_wkb<W: Write>(mut writer: W, geom: &impl PointTrait<T = f64>) -> Result<()> {
     // Byte order
     writer.write_u8(Endianness::LittleEndian.into()).unwrap();
 
-    // wkbType = 1
-    writer.write_u32::<LittleEndian>(1).unwrap();
+    // wkbType = 1, plus 1000 with z and 2000 with m
+    let dim = point_dimension(geom);
+    writer
+        .write_u32::<LittleEndian>(wkb_type_code(1, dim))
+        .unwrap();
 
     writer.write_f64::<LittleEndian>(geom.x()).unwrap();
     writer.write_f64::<LittleEndian>(geom.y()).unwrap();
+    if let Some(z) = geom.z() {
+        writer.write_f64::<LittleEndian>(z).unwrap();
+    }
+    if let Some(m) = geom.m() {
+        writer.write_f64::<LittleEndian>(m).unwrap();
+    }
 
     Ok(())
 }
@@ -33,7 +55,7 @@ impl<O: OffsetSizeTrait> From<&PointArray> for WKBArray<O> {
 
         let validity = value.nulls().cloned();
         // only allocate space for a WKBPoint for non-null items
-        let values_len = non_null_count * POINT_WKB_SIZE;
+        let values_len = non_null_count * (1 + 4 + 8 * value.coords.dim().size());
         let mut offsets: OffsetsBuilder<O> = OffsetsBuilder::with_capacity(value.len());
 
         let values = {
@@ -43,7 +65,7 @@ impl<O: OffsetSizeTrait> From<&PointArray> for WKBArray<O> {
             for maybe_geom in value.iter() {
                 if let Some(geom) = maybe_geom {
                     write_point_as_wkb(&mut writer, &geom).unwrap();
-                    offsets.try_push_usize(POINT_WKB_SIZE).unwrap();
+                    offsets.try_push_usize(point_wkb_size(&geom)).unwrap();
                 } else {
                     offsets.extend_constant(1);
                 }
@@ -61,6 +83,8 @@ impl<O: OffsetSizeTrait> From<&PointArray> for WKBArray<O> {
 mod test {
     use super::*;
     use crate::test::point::{p0, p1, p2};
+    use crate::trait_::GeoArrayAccessor;
+    use arrow_array::BinaryArray;
 
     #[test]
     fn round_trip() {
@@ -80,4 +104,24 @@ mod test {
 
         assert_eq!(orig_arr, new_arr);
     }
+
+    #[test]
+    fn round_trip_empty() {
+        // POINT EMPTY, which by convention has NaN coordinates
+        let mut empty = vec![1, 1, 0, 0, 0];
+        empty.extend_from_slice(&f64::NAN.to_le_bytes());
+        empty.extend_from_slice(&f64::NAN.to_le_bytes());
+        let wkb_arr: WKBArray<i32> =
+            BinaryArray::from_opt_vec(vec![Some(empty.as_slice()), None]).into();
+
+        let arr: PointArray = wkb_arr.try_into().unwrap();
+        assert!(arr.is_valid(0));
+        let point = arr.value(0);
+        assert!(point.x().is_nan() && point.y().is_nan());
+        assert!(arr.is_null(1));
+
+        let new_arr: WKBArray<i32> = (&arr).into();
+        assert_eq!(new_arr.value(0).as_ref(), empty.as_slice());
+        assert!(new_arr.is_null(1));
+    }
 }
@@ -1,8 +1,9 @@
 use crate::array::mutable_offset::OffsetsBuilder;
-use crate::array::{PolygonArray, WKBArray};
+use crate::array::{Dimension, PolygonArray, WKBArray};
 use crate::error::Result;
-use crate::geo_traits::{CoordTrait, LineStringTrait, PolygonTrait};
+use crate::geo_traits::{LineStringTrait, PolygonTrait};
 use crate::io::wkb::reader::geometry::Endianness;
+use crate::io::wkb::writer::{line_string_dimension, wkb_type_code, write_coord};
 use crate::trait_::GeometryArrayTrait;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
 use byteorder::{LittleEndian, WriteBytesExt};
@@ -12,13 +13,16 @@ use std::io::{Cursor, Write};
 pub fn polygon_wkb_size<'a>(geom: &impl PolygonTrait<'a>) -> usize {
     let mut sum = 1 + 4 + 4;
 
-    // TODO: support empty polygons where this will panic
-    let ext_ring = geom.exterior().unwrap();
-    sum += 4 + (ext_ring.num_coords() * 16);
+    // An empty polygon is written with no rings
+    let Some(ext_ring) = geom.exterior() else {
+        return sum;
+    };
+    let coord_size = 8 * line_string_dimension(&ext_ring).size();
+    sum += 4 + (ext_ring.num_coords() * coord_size);
 
     for int_ring_idx in 0..geom.num_interiors() {
         let int_ring = geom.interior(int_ring_idx).unwrap();
-        sum += 4 + (int_ring.num_coords() * 16);
+        sum += 4 + (int_ring.num_coords() * coord_size);
     }
 
     sum
@@ -32,25 +36,35 @@ pub fn write_polygon_as_wkb<'a, W: Write>(
     // Byte order
     writer.write_u8(Endianness::LittleEndian.into()).unwrap();
 
-    // wkbType = 3
-    writer.write_u32::<LittleEndian>(3).unwrap();
+    let ext_ring = geom.exterior();
+
+    // wkbType = 3, plus 1000 with z and 2000 with m
+    let dim = ext_ring
+        .as_ref()
+        .map_or(Dimension::XY, line_string_dimension);
+    writer
+        .write_u32::<LittleEndian>(wkb_type_code(3, dim))
+        .unwrap();
+
+    // POLYGON EMPTY has zero rings
+    let Some(ext_ring) = ext_ring else {
+        writer.write_u32::<LittleEndian>(0).unwrap();
+        return Ok(());
+    };
 
     // numRings
-    // TODO: support empty polygons where this will panic
     let num_rings = 1 + geom.num_interiors();
     writer
         .write_u32::<LittleEndian>(num_rings.try_into().unwrap())
         .unwrap();
 
-    let ext_ring = geom.exterior().unwrap();
     writer
         .write_u32::<LittleEndian>(ext_ring.num_coords().try_into().unwrap())
         .unwrap();
 
     for coord_idx in 0..ext_ring.num_coords() {
         let coord = ext_ring.coord(coord_idx).unwrap();
-        writer.write_f64::<LittleEndian>(coord.x()).unwrap();
-        writer.write_f64::<LittleEndian>(coord.y()).unwrap();
+        write_coord(&mut writer, &coord, dim).unwrap();
     }
 
     for int_ring_idx in 0..geom.num_interiors() {
@@ -61,8 +75,7 @@ pub fn write_polygon_as_wkb<'a, W: Write>(
 
         for coord_idx in 0..int_ring.num_coords() {
             let coord = int_ring.coord(coord_idx).unwrap();
-            writer.write_f64::<LittleEndian>(coord.x()).unwrap();
-            writer.write_f64::<LittleEndian>(coord.y()).unwrap();
+            write_coord(&mut writer, &coord, dim).unwrap();
         }
     }
 
@@ -104,6 +117,7 @@ mod test {
     use super::*;
     use crate::test::polygon::{p0, p1};
     use crate::trait_::GeoArrayAccessor;
+    use arrow_array::BinaryArray;
     use geozero::{CoordDimensions, ToWkb};
 
     #[test]
@@ -133,4 +147,21 @@ mod test {
 
     //     assert_eq!(orig_arr, new_arr);
     // }
+
+    #[test]
+    fn round_trip_empty() {
+        // POLYGON EMPTY, which has no rings
+        let empty = vec![1, 3, 0, 0, 0, 0, 0, 0, 0];
+        let wkb_arr: WKBArray<i32> =
+            BinaryArray::from_opt_vec(vec![Some(empty.as_slice()), None]).into();
+
+        let arr: PolygonArray<i32> = wkb_arr.try_into().unwrap();
+        assert!(arr.is_valid(0));
+        assert!(arr.value(0).exterior().is_none());
+        assert!(arr.is_null(1));
+
+        let new_arr: WKBArray<i32> = (&arr).into();
+        assert_eq!(new_arr.value(0).as_ref(), empty.as_slice());
+        assert!(new_arr.is_null(1));
+    }
 }
@@ -0,0 +1,77 @@
+//! Read and write the [Well-Known Text](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry)
+//! format.
+
+use crate::array::GeometryArray;
+use crate::error::{GeoArrowError, Result};
+use crate::io::wkt::parse::{parse_wkt, GeometryArrayBuilder};
+
+pub(crate) mod parse;
+#[cfg(feature = "rayon")]
+mod reader;
+mod writer;
+
+#[cfg(feature = "rayon")]
+pub use reader::from_wkt_parallel;
+pub use writer::{write_wkt, WktWriterOptions};
+
+/// Parse a single WKT string into an array of length 1, typed after the parsed geometry.
+///
+/// ```
+/// use geoarrow2::array::GeometryArray;
+/// use geoarrow2::io::wkt::from_wkt;
+/// use geoarrow2::GeometryArrayTrait;
+///
+/// let array = from_wkt("POINT (1 2)").unwrap();
+/// assert!(matches!(array, GeometryArray::Point(_)));
+/// assert_eq!(array.len(), 1);
+/// ```
+///
+/// # Errors
+///
+/// - if the string isn't valid WKT
+/// - if the geometry is a `GEOMETRYCOLLECTION` that holds another collection
+pub fn from_wkt(s: &str) -> Result<GeometryArray<i32>> {
+    let geom = parse_wkt(s).map_err(|message| {
+        GeoArrowError::General(format!("Failed to parse WKT geometry: {}", message))
+    })?;
+    let mut builder = GeometryArrayBuilder::new(None)?;
+    builder.push(Some(geom))?;
+    builder.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::GeoArrayAccessor;
+    use crate::GeometryArrayTrait;
+    use geo::polygon;
+
+    #[test]
+    fn single_polygon() {
+        let array = from_wkt("POLYGON((0 0,1 0,1 1,0 1,0 0))").unwrap();
+        let polygon_array = match array {
+            GeometryArray::Polygon(arr) => arr,
+            _ => panic!("Expected a polygon array"),
+        };
+        assert_eq!(polygon_array.len(), 1);
+        assert_eq!(
+            polygon_array.value_as_geo(0),
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)]
+        );
+    }
+
+    #[test]
+    fn geometry_collection() {
+        let array = from_wkt("GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(0 0,1 1))").unwrap();
+        let GeometryArray::GeometryCollection(collection_array) = array else {
+            panic!("Expected a geometry collection array");
+        };
+        assert_eq!(collection_array.value_as_geo(0).0.len(), 2);
+    }
+
+    #[test]
+    fn invalid_wkt() {
+        assert!(from_wkt("POLYGON((0 0,1 0").is_err());
+        assert!(from_wkt("GEOMETRYCOLLECTION(GEOMETRYCOLLECTION(POINT(1 2)))").is_err());
+    }
+}
@@ -0,0 +1,197 @@
+//! Parsing and array building shared by the WKT and line-delimited readers.
+
+use arrow_array::OffsetSizeTrait;
+use wkt::TryFromWkt;
+
+use crate::algorithm::native::concat::{common_type, ChunkType, GeometryBuilder};
+use crate::algorithm::native::{concat_promoting, PromotedGeometryArray, PromotionPolicy};
+use crate::array::{CoordType, GeometryArray};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::GeometryArrayTrait;
+
+/// Parse a WKT string to a geometry, or return why it couldn't be parsed.
+pub(crate) fn parse_wkt(s: &str) -> std::result::Result<geo::Geometry, String> {
+    geo::Geometry::<f64>::try_from_wkt_str(s).map_err(|err| err.to_string())
+}
+
+/// Builds a geometry array from geometries pushed one at a time, either of a target type or of
+/// the type inferred from the geometries.
+///
+/// With a target, every geometry goes straight into one mutable array of that type. Otherwise
+/// geometries go into a mutable array of the type of the first geometry, until one doesn't fit.
+/// That array is then finished as a run, and a new one is started with the common type of both,
+/// or with the type of the new geometry if there is none. The runs are concatenated with
+/// [`concat_promoting`] at the end, so a mix of single and multi geometries becomes the multi
+/// type, and only a run of each widening is ever copied.
+pub(crate) struct GeometryArrayBuilder<O: OffsetSizeTrait> {
+    /// The coordinate type of the target, if there is one
+    target_coord_type: Option<CoordType>,
+    runs: Vec<GeometryArray<O>>,
+    current: Option<GeometryBuilder<O>>,
+    /// Nulls pushed while there is no current builder, which go in front of the next one
+    leading_nulls: usize,
+}
+
+impl<O: OffsetSizeTrait> GeometryArrayBuilder<O> {
+    /// Create a builder for `target`, or for the type inferred from the data if `target` is
+    /// `None`. Only the geometry kind and coordinate type of `target` are used.
+    ///
+    /// # Errors
+    ///
+    /// - if `target` is a mixed, WKB or rect type
+    pub(crate) fn new(target: Option<&GeoDataType>) -> Result<Self> {
+        let (current, target_coord_type) = match target {
+            Some(target) => {
+                let (chunk_type, coord_type) = target_type(target)?;
+                (Some(GeometryBuilder::new(chunk_type, 0)), Some(coord_type))
+            }
+            None => (None, None),
+        };
+        Ok(Self {
+            target_coord_type,
+            runs: vec![],
+            current,
+            leading_nulls: 0,
+        })
+    }
+
+    /// Add a geometry, or a null, to the end of the array.
+    ///
+    /// # Errors
+    ///
+    /// - if the geometry can't be cast to the target type. Nothing is added in this case.
+    /// - if the geometry is a collection with a nested collection. Nothing is added in this case.
+    /// - if the array becomes too large for offsets of type `O`
+    pub(crate) fn push(&mut self, geom: Option<geo::Geometry>) -> Result<()> {
+        let Some(geom) = geom else {
+            match &mut self.current {
+                Some(current) => current.push(None)?,
+                None => self.leading_nulls += 1,
+            }
+            return Ok(());
+        };
+
+        let geom_type = ChunkType::of_geometry(&geom);
+        if let Some(current) = &self.current {
+            // With a target the builder never changes type, and rejects the geometry instead
+            if self.target_coord_type.is_none() && !current.chunk_type().can_hold(geom_type) {
+                let chunk_type =
+                    common_type(&[current.chunk_type(), geom_type]).unwrap_or(geom_type);
+                let previous = self.current.replace(GeometryBuilder::new(chunk_type, 0));
+                self.runs.extend(previous.map(GeometryBuilder::finish));
+            }
+        } else {
+            self.current = Some(self.null_builder(geom_type)?);
+        }
+        self.current.as_mut().unwrap().push(Some(geom))
+    }
+
+    /// Add the rows of `other` to the end of this array.
+    ///
+    /// # Errors
+    ///
+    /// - if the array becomes too large for offsets of type `O`
+    #[cfg(feature = "rayon")]
+    pub(crate) fn append(&mut self, other: Self) -> Result<()> {
+        for _ in 0..other.leading_nulls {
+            self.push(None)?;
+        }
+        let other_runs = other.into_runs()?;
+        let Some(first_run) = other_runs.first() else {
+            return Ok(());
+        };
+        if self.current.is_none() && self.leading_nulls > 0 {
+            // Nulls of no known type yet take the type of the next run
+            self.current = Some(self.null_builder(ChunkType::of(first_run))?);
+        }
+        self.runs
+            .extend(self.current.take().map(GeometryBuilder::finish));
+        self.runs.extend(other_runs);
+        Ok(())
+    }
+
+    /// A builder of the given type that holds the leading nulls.
+    fn null_builder(&mut self, chunk_type: ChunkType) -> Result<GeometryBuilder<O>> {
+        let mut builder = GeometryBuilder::new(chunk_type, self.leading_nulls);
+        for _ in 0..std::mem::take(&mut self.leading_nulls) {
+            builder.push(None)?;
+        }
+        Ok(builder)
+    }
+
+    fn into_runs(mut self) -> Result<Vec<GeometryArray<O>>> {
+        if self.current.is_none() && self.leading_nulls > 0 {
+            // Nulls after an appended builder take the type of the run before them
+            if let Some(last_run) = self.runs.last() {
+                self.current = Some(self.null_builder(ChunkType::of(last_run))?);
+            }
+        }
+        let mut runs = self.runs;
+        runs.extend(self.current.map(GeometryBuilder::finish));
+        Ok(runs)
+    }
+
+    /// # Errors
+    ///
+    /// - without a target, if there were no geometries to infer the type from
+    /// - without a target, if the geometries have unrelated types, such as points and polygons
+    /// - if the output is too large for offsets of type `O`
+    pub(crate) fn finish(self) -> Result<GeometryArray<O>> {
+        let target_coord_type = self.target_coord_type.clone();
+        let runs = self.into_runs()?;
+        if runs.is_empty() {
+            return Err(GeoArrowError::General(
+                "Cannot infer the geometry type of an input without geometries".to_string(),
+            ));
+        }
+
+        let geometry_array = match concat_promoting(&runs, PromotionPolicy::Mixed)? {
+            PromotedGeometryArray::Geometry(arr) => arr,
+            PromotedGeometryArray::Mixed(_) | PromotedGeometryArray::WKB(_) => {
+                return Err(GeoArrowError::General(
+                    "Cannot store geometries of unrelated types, such as points and polygons, in \
+                     one array"
+                        .to_string(),
+                ))
+            }
+        };
+        Ok(match target_coord_type {
+            Some(coord_type) => geometry_array.into_coord_type(coord_type),
+            None => geometry_array,
+        })
+    }
+}
+
+/// The geometry kind and coordinate type of a target type.
+fn target_type(target: &GeoDataType) -> Result<(ChunkType, CoordType)> {
+    let (chunk_type, coord_type) = match target {
+        GeoDataType::Point(coord_type, _) => (ChunkType::Point, coord_type),
+        GeoDataType::LineString(coord_type, _) | GeoDataType::LargeLineString(coord_type, _) => {
+            (ChunkType::LineString, coord_type)
+        }
+        GeoDataType::Polygon(coord_type, _) | GeoDataType::LargePolygon(coord_type, _) => {
+            (ChunkType::Polygon, coord_type)
+        }
+        GeoDataType::MultiPoint(coord_type, _) | GeoDataType::LargeMultiPoint(coord_type, _) => {
+            (ChunkType::MultiPoint, coord_type)
+        }
+        GeoDataType::MultiLineString(coord_type, _)
+        | GeoDataType::LargeMultiLineString(coord_type, _) => {
+            (ChunkType::MultiLineString, coord_type)
+        }
+        GeoDataType::MultiPolygon(coord_type, _)
+        | GeoDataType::LargeMultiPolygon(coord_type, _) => (ChunkType::MultiPolygon, coord_type),
+        GeoDataType::GeometryCollection(coord_type)
+        | GeoDataType::LargeGeometryCollection(coord_type) => {
+            (ChunkType::GeometryCollection, coord_type)
+        }
+        _ => {
+            return Err(GeoArrowError::NotYetImplemented(format!(
+                "Parsing to {:?}",
+                target
+            )))
+        }
+    };
+    Ok((chunk_type, coord_type.clone()))
+}
@@ -0,0 +1,234 @@
+use arrow_array::builder::StringBuilder;
+use arrow_array::{Array, GenericStringArray, OffsetSizeTrait, StringArray};
+use rayon::prelude::*;
+
+use crate::array::GeometryArray;
+use crate::datatypes::GeoDataType;
+use crate::error::{ErrorPolicy, GeoArrowError, Result};
+use crate::io::wkt::parse::{parse_wkt, GeometryArrayBuilder};
+
+/// The minimum number of rows handed to a single worker.
+const MIN_CHUNK_SIZE: usize = 1024;
+
+/// Parse a WKT string array into a GeoArrow array, in parallel.
+///
+/// The input is split into chunks that are parsed on the rayon thread pool into mutable arrays,
+/// which are then concatenated.
+///
+/// If `target` is `None`, the geometry type is inferred from the data, where a mix of single and
+/// multi geometries of the same kind becomes the multi type, and a mix that includes geometry
+/// collections becomes a geometry collection array. Otherwise all geometries are converted to the
+/// given type, with single geometries promoted to their multi counterpart where needed. Only the
+/// geometry kind and coordinate type of `target` are used; the offset size is taken from the
+/// input.
+///
+/// With [`ErrorPolicy::Strict`], the first row that fails to parse or to convert to `target`
+/// fails the whole batch. With [`ErrorPolicy::NullifyAndReport`], such rows become null
+/// geometries and the second return value holds one error message per row, null for rows that
+/// parsed successfully.
+///
+/// Null input rows are null geometries.
+///
+/// # Errors
+///
+/// - if a row fails to parse under [`ErrorPolicy::Strict`]
+/// - if `target` is `None` and the input has no geometries, or geometries of unrelated types
+/// - if `target` is a mixed, WKB or rect type
+pub fn from_wkt_parallel<O: OffsetSizeTrait>(
+    arr: &GenericStringArray<O>,
+    target: Option<GeoDataType>,
+    error_policy: ErrorPolicy,
+) -> Result<(GeometryArray<O>, Option<StringArray>)> {
+    let chunk_size = (arr.len() / rayon::current_num_threads()).max(MIN_CHUNK_SIZE);
+    let num_chunks = arr.len().div_ceil(chunk_size).max(1);
+
+    // The results are kept in chunk order, so that in strict mode the error is always that of
+    // the first failing row, whichever chunk fails first
+    let chunks: Vec<Result<(GeometryArrayBuilder<O>, StringArray)>> = (0..num_chunks)
+        .into_par_iter()
+        .map(|chunk_idx| {
+            let offset = chunk_idx * chunk_size;
+            let length = chunk_size.min(arr.len() - offset);
+            parse_chunk(
+                &arr.slice(offset, length),
+                offset,
+                target.as_ref(),
+                error_policy,
+            )
+        })
+        .collect();
+
+    let mut builder = GeometryArrayBuilder::new(target.as_ref())?;
+    let mut errors = StringBuilder::with_capacity(arr.len(), 0);
+    for chunk in chunks {
+        let (chunk_builder, chunk_errors) = chunk?;
+        builder.append(chunk_builder)?;
+        chunk_errors
+            .iter()
+            .for_each(|error| errors.append_option(error));
+    }
+
+    let errors = match error_policy {
+        ErrorPolicy::Strict => None,
+        ErrorPolicy::NullifyAndReport => Some(errors.finish()),
+    };
+    Ok((builder.finish()?, errors))
+}
+
+/// Parse one chunk of WKT strings into a mutable array, alongside the error message of each row.
+///
+/// `offset` is the index of the chunk's first row in the full input, used in error messages.
+fn parse_chunk<O: OffsetSizeTrait>(
+    arr: &GenericStringArray<O>,
+    offset: usize,
+    target: Option<&GeoDataType>,
+    error_policy: ErrorPolicy,
+) -> Result<(GeometryArrayBuilder<O>, StringArray)> {
+    let mut builder = GeometryArrayBuilder::new(target)?;
+    let mut error_builder = StringBuilder::new();
+
+    for (i, maybe_str) in arr.iter().enumerate() {
+        let pushed = maybe_str
+            .map(parse_wkt)
+            .transpose()
+            .and_then(|geom| builder.push(geom).map_err(|err| err.to_string()));
+        match pushed {
+            Ok(()) => error_builder.append_null(),
+            Err(message) => match error_policy {
+                ErrorPolicy::Strict => {
+                    return Err(GeoArrowError::General(format!(
+                        "Failed to parse WKT geometry in row {}: {}",
+                        offset + i,
+                        message
+                    )))
+                }
+                ErrorPolicy::NullifyAndReport => {
+                    builder.push(None)?;
+                    error_builder.append_value(message);
+                }
+            },
+        }
+    }
+
+    Ok((builder, error_builder.finish()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{CoordType, Dimension};
+    use crate::trait_::GeoArrayAccessor;
+    use crate::GeometryArrayTrait;
+
+    #[test]
+    fn five_percent_corrupted() {
+        let num_rows = 10_000;
+        let input: StringArray = (0..num_rows)
+            .map(|i| {
+                if i % 20 == 7 {
+                    Some("LINESTRING (0 0, 1".to_string())
+                } else if i % 20 == 13 {
+                    None
+                } else {
+                    Some(format!("LINESTRING ({} 0, {} 1)", i, i))
+                }
+            })
+            .collect();
+
+        let err = from_wkt_parallel(&input, None, ErrorPolicy::Strict).unwrap_err();
+        assert!(err.to_string().contains("in row 7: "));
+
+        let (geometry_array, errors) =
+            from_wkt_parallel(&input, None, ErrorPolicy::NullifyAndReport).unwrap();
+        let errors = errors.unwrap();
+        assert_eq!(geometry_array.len(), num_rows);
+        assert_eq!(errors.len(), num_rows);
+        assert_eq!(geometry_array.null_count(), num_rows / 10);
+        assert_eq!(errors.len() - errors.null_count(), num_rows / 20);
+
+        let line_string_array = match geometry_array {
+            GeometryArray::LineString(arr) => arr,
+            _ => panic!("expected linestring array"),
+        };
+        for i in 0..num_rows {
+            if i % 20 == 7 {
+                assert!(line_string_array.is_null(i));
+                assert!(errors.is_valid(i));
+            } else if i % 20 == 13 {
+                assert!(line_string_array.is_null(i));
+                assert!(errors.is_null(i));
+            } else {
+                let expected: geo::LineString = vec![(i as f64, 0.), (i as f64, 1.)].into();
+                assert_eq!(line_string_array.value_as_geo(i), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn target_type() {
+        let input = StringArray::from(vec!["POINT (1 2)", "MULTIPOINT ((3 4), (5 6))"]);
+        let (geometry_array, errors) = from_wkt_parallel(
+            &input,
+            Some(GeoDataType::MultiPoint(CoordType::Separated, Dimension::XY)),
+            ErrorPolicy::Strict,
+        )
+        .unwrap();
+        assert!(errors.is_none());
+        assert!(matches!(
+            geometry_array.data_type(),
+            GeoDataType::MultiPoint(CoordType::Separated, Dimension::XY)
+        ));
+
+        let point_type = GeoDataType::Point(CoordType::Interleaved, Dimension::XY);
+        let err =
+            from_wkt_parallel(&input, Some(point_type.clone()), ErrorPolicy::Strict).unwrap_err();
+        assert!(err.to_string().contains("in row 1: "));
+
+        // A geometry that doesn't fit the target fails only its own row
+        let (geometry_array, errors) =
+            from_wkt_parallel(&input, Some(point_type), ErrorPolicy::NullifyAndReport).unwrap();
+        let errors = errors.unwrap();
+        assert!(matches!(geometry_array, GeometryArray::Point(_)));
+        assert!(geometry_array.is_valid(0));
+        assert!(geometry_array.is_null(1));
+        assert!(errors.is_null(0));
+        assert!(errors.is_valid(1));
+    }
+
+    #[test]
+    fn chunks_of_nulls() {
+        // The first and last chunks hold only nulls, which take the type of the points
+        let input: StringArray = (0..4100)
+            .map(|i| {
+                (2500..3000)
+                    .contains(&i)
+                    .then(|| format!("POINT ({} 0)", i))
+            })
+            .collect();
+        let (geometry_array, _) = from_wkt_parallel(&input, None, ErrorPolicy::Strict).unwrap();
+        let GeometryArray::Point(point_array) = geometry_array else {
+            panic!("expected point array");
+        };
+        assert_eq!(point_array.len(), 4100);
+        assert_eq!(point_array.null_count(), 3600);
+        assert_eq!(point_array.value_as_geo(2500), geo::point!(x: 2500., y: 0.));
+    }
+
+    #[test]
+    fn mixed_types() {
+        let input = StringArray::from(vec![
+            Some("POINT (1 2)"),
+            None,
+            Some("GEOMETRYCOLLECTION (POINT (3 4))"),
+        ]);
+        let (geometry_array, _) = from_wkt_parallel(&input, None, ErrorPolicy::Strict).unwrap();
+        let GeometryArray::GeometryCollection(collection_array) = geometry_array else {
+            panic!("expected geometry collection array");
+        };
+        assert_eq!(collection_array.len(), 3);
+        assert!(collection_array.is_null(1));
+
+        let input = StringArray::from(vec!["POINT (1 2)", "POLYGON ((0 0, 1 0, 1 1, 0 0))"]);
+        assert!(from_wkt_parallel(&input, None, ErrorPolicy::Strict).is_err());
+    }
+}
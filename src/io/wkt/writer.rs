@@ -0,0 +1,271 @@
+use std::fmt::Write;
+
+use arrow_array::builder::StringBuilder;
+use arrow_array::StringArray;
+use geo::{Coord, Geometry, LineString, Polygon};
+
+use crate::algorithm::native::PrecisionStats;
+use crate::trait_::GeoArrayAccessor;
+
+/// Options for [`write_wkt`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WktWriterOptions {
+    /// The number of decimals written for each coordinate.
+    pub precision: usize,
+    /// Whether to drop zeros at the end of the decimals, and the decimal point if no decimals
+    /// remain, so that `1.50` is written as `1.5` and `2.00` as `2`.
+    pub trim_trailing_zeros: bool,
+}
+
+impl WktWriterOptions {
+    /// Options that write coordinates with as few decimals as possible without losing any
+    /// precision, picked from the [`PrecisionStats`] of the array to write.
+    ///
+    /// ```
+    /// use geoarrow2::algorithm::native::CoordinatePrecision;
+    /// use geoarrow2::array::PointArray;
+    /// use geoarrow2::io::wkt::{write_wkt, WktWriterOptions};
+    /// use geo::point;
+    ///
+    /// let array: PointArray = vec![point!(x: 1.5, y: 2.125)].into();
+    /// let options = WktWriterOptions::lossless(&array.coordinate_precision_stats());
+    /// assert_eq!(write_wkt(&array, &options).value(0), "POINT(1.5 2.125)");
+    /// ```
+    pub fn lossless(stats: &PrecisionStats) -> Self {
+        Self {
+            precision: stats.max_decimal_places,
+            trim_trailing_zeros: true,
+        }
+    }
+}
+
+/// Write each geometry of an array as a WKT string.
+///
+/// Coordinates are rounded to [`precision`](WktWriterOptions::precision) decimals, which makes
+/// the output smaller than that of the [`wkt`] crate at the cost of exact round trips. Null
+/// geometries are null strings, and points with NaN coordinates are written as `POINT EMPTY`.
+pub fn write_wkt<A>(array: &A, options: &WktWriterOptions) -> StringArray
+where
+    A: for<'a> GeoArrayAccessor<'a>,
+    for<'a> <A as GeoArrayAccessor<'a>>::ItemGeo: Into<Geometry>,
+{
+    let mut output_array = StringBuilder::with_capacity(array.len(), 0);
+    let mut buf = String::new();
+    for i in 0..array.len() {
+        match array.get_as_geo(i) {
+            Some(geometry) => {
+                buf.clear();
+                write_geometry(&mut buf, &geometry.into(), options);
+                output_array.append_value(&buf);
+            }
+            None => output_array.append_null(),
+        }
+    }
+    output_array.finish()
+}
+
+fn write_geometry(buf: &mut String, geometry: &Geometry, options: &WktWriterOptions) {
+    match geometry {
+        Geometry::Point(point) if point.x().is_nan() && point.y().is_nan() => {
+            buf.push_str("POINT EMPTY")
+        }
+        Geometry::Point(point) => {
+            buf.push_str("POINT(");
+            write_coord(buf, &point.0, options);
+            buf.push(')');
+        }
+        Geometry::Line(line) => {
+            buf.push_str("LINESTRING");
+            write_line_string(buf, &LineString::new(vec![line.start, line.end]), options);
+        }
+        Geometry::LineString(line_string) => {
+            buf.push_str("LINESTRING");
+            write_line_string(buf, line_string, options);
+        }
+        Geometry::Polygon(polygon) => {
+            buf.push_str("POLYGON");
+            write_polygon(buf, polygon, options);
+        }
+        Geometry::Rect(rect) => {
+            buf.push_str("POLYGON");
+            write_polygon(buf, &rect.to_polygon(), options);
+        }
+        Geometry::Triangle(triangle) => {
+            buf.push_str("POLYGON");
+            write_polygon(buf, &triangle.to_polygon(), options);
+        }
+        Geometry::MultiPoint(multi_point) => {
+            buf.push_str("MULTIPOINT");
+            write_list(buf, &multi_point.0, |buf, point| {
+                buf.push('(');
+                write_coord(buf, &point.0, options);
+                buf.push(')');
+            });
+        }
+        Geometry::MultiLineString(multi_line_string) => {
+            buf.push_str("MULTILINESTRING");
+            write_list(buf, &multi_line_string.0, |buf, line_string| {
+                write_line_string(buf, line_string, options)
+            });
+        }
+        Geometry::MultiPolygon(multi_polygon) => {
+            buf.push_str("MULTIPOLYGON");
+            write_list(buf, &multi_polygon.0, |buf, polygon| {
+                write_polygon(buf, polygon, options)
+            });
+        }
+        Geometry::GeometryCollection(collection) => {
+            buf.push_str("GEOMETRYCOLLECTION");
+            write_list(buf, &collection.0, |buf, geometry| {
+                write_geometry(buf, geometry, options)
+            });
+        }
+    }
+}
+
+/// Write `(item,item,...)`, or ` EMPTY` if there are no items.
+fn write_list<T>(buf: &mut String, items: &[T], mut write_item: impl FnMut(&mut String, &T)) {
+    if items.is_empty() {
+        buf.push_str(" EMPTY");
+        return;
+    }
+
+    buf.push('(');
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            buf.push(',');
+        }
+        write_item(buf, item);
+    }
+    buf.push(')');
+}
+
+fn write_line_string(buf: &mut String, line_string: &LineString, options: &WktWriterOptions) {
+    write_list(buf, &line_string.0, |buf, coord| {
+        write_coord(buf, coord, options)
+    });
+}
+
+fn write_polygon(buf: &mut String, polygon: &Polygon, options: &WktWriterOptions) {
+    if polygon.exterior().0.is_empty() {
+        buf.push_str(" EMPTY");
+        return;
+    }
+
+    buf.push('(');
+    write_line_string(buf, polygon.exterior(), options);
+    for interior in polygon.interiors() {
+        buf.push(',');
+        write_line_string(buf, interior, options);
+    }
+    buf.push(')');
+}
+
+fn write_coord(buf: &mut String, coord: &Coord, options: &WktWriterOptions) {
+    write_number(buf, coord.x, options);
+    buf.push(' ');
+    write_number(buf, coord.y, options);
+}
+
+fn write_number(buf: &mut String, value: f64, options: &WktWriterOptions) {
+    let start = buf.len();
+    write!(buf, "{:.*}", options.precision, value).unwrap();
+    if options.trim_trailing_zeros && buf[start..].contains('.') {
+        let trimmed_len = buf.trim_end_matches('0').trim_end_matches('.').len();
+        buf.truncate(trimmed_len);
+    }
+    // Rounding small negative numbers gives "-0"
+    if buf[start..]
+        .trim_start_matches('-')
+        .trim_matches(['0', '.'])
+        .is_empty()
+    {
+        buf.truncate(start);
+        write!(buf, "{:.*}", options.precision, 0.).unwrap();
+        if options.trim_trailing_zeros {
+            buf.truncate(start + 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{MixedGeometryArray, PointArray};
+    use arrow_array::Array;
+    use geo::{line_string, point, polygon};
+
+    #[test]
+    fn precision() {
+        let points: PointArray = vec![Some(point!(x: 1.23456, y: -0.001)), None].into();
+        let options = WktWriterOptions {
+            precision: 2,
+            trim_trailing_zeros: false,
+        };
+        let wkt = write_wkt(&points, &options);
+        assert_eq!(wkt.value(0), "POINT(1.23 0.00)");
+        assert!(wkt.is_null(1));
+
+        let options = WktWriterOptions {
+            precision: 3,
+            trim_trailing_zeros: true,
+        };
+        let wkt = write_wkt(&points, &options);
+        assert_eq!(wkt.value(0), "POINT(1.235 -0.001)");
+    }
+
+    #[test]
+    fn lossless_round_trip() {
+        use crate::algorithm::native::CoordinatePrecision;
+        use crate::array::LineStringArray;
+        use crate::io::wkt::from_wkt;
+
+        // Coordinates with up to 4 decimals, most of which are not exact in binary
+        let line_strings: Vec<geo::LineString> = (0..20)
+            .map(|i| {
+                let x = (i * 1234) as f64 / 1e4;
+                let y = -(i * 17) as f64 / 1e2;
+                line_string![(x: x, y: y), (x: 1000. + x, y: 0.3)]
+            })
+            .collect();
+        let array: LineStringArray<i32> = line_strings.clone().into();
+
+        let stats = array.coordinate_precision_stats();
+        assert_eq!(stats.max_decimal_places, 4);
+        let wkt = write_wkt(&array, &WktWriterOptions::lossless(&stats));
+        for (i, line_string) in line_strings.iter().enumerate() {
+            let parsed = match from_wkt(wkt.value(i)).unwrap() {
+                crate::array::GeometryArray::LineString(arr) => arr.value_as_geo(0),
+                _ => panic!("Expected a line string array"),
+            };
+            assert_eq!(&parsed, line_string);
+        }
+    }
+
+    #[test]
+    fn geometry_types() {
+        let geometries: MixedGeometryArray<i32> = vec![
+            geo::Geometry::LineString(line_string![(x: 0., y: 0.5), (x: 10., y: 2.)]),
+            geo::Geometry::Polygon(polygon!(
+                exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 0.)],
+                interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 1.)]],
+            )),
+            geo::Geometry::MultiPoint(vec![point!(x: 1.25, y: 2.)].into()),
+            geo::Geometry::MultiLineString(geo::MultiLineString::new(vec![])),
+        ]
+        .try_into()
+        .unwrap();
+        let options = WktWriterOptions {
+            precision: 1,
+            trim_trailing_zeros: true,
+        };
+        let wkt = write_wkt(&geometries, &options);
+        assert_eq!(wkt.value(0), "MULTILINESTRING((0 0.5,10 2))");
+        assert_eq!(
+            wkt.value(1),
+            "MULTIPOLYGON(((0 0,4 0,4 4,0 0),(1 1,2 1,2 2,1 1)))"
+        );
+        assert_eq!(wkt.value(2), "MULTIPOINT((1.2 2))");
+        assert_eq!(wkt.value(3), "MULTILINESTRING EMPTY");
+    }
+}
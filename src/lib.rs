@@ -5,10 +5,13 @@ pub use trait_::GeometryArrayTrait;
 
 pub mod algorithm;
 pub mod array;
+pub mod buffer;
+pub mod chunked_array;
 pub mod datatypes;
 pub mod error;
 pub mod geo_traits;
 pub mod io;
+pub mod prelude;
 pub mod scalar;
 pub mod table;
 #[cfg(test)]
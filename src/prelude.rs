@@ -0,0 +1,48 @@
+//! Re-exports of the array types and the traits needed to work with them.
+//!
+//! ```
+//! use geoarrow2::prelude::*;
+//!
+//! let points: PointArray = vec![geo::point!(x: 0., y: 0.), geo::point!(x: 1., y: 1.)].into();
+//! let centroids = points.centroid();
+//! assert_eq!(centroids.len(), 2);
+//! ```
+//!
+//! This includes every trait in [`algorithm::geo`][crate::algorithm::geo] and
+//! [`algorithm::native`][crate::algorithm::native]. Traits backed by optional dependencies, like
+//! those in [`algorithm::geos`][crate::algorithm], are not included because their names overlap
+//! with the [`geo`]-backed traits, and neither are the conversion traits in [`io`][crate::io].
+//!
+//! [`HasDimensions::is_empty`] and [`GeometryArrayTrait::is_empty`] share a name, so with both in
+//! scope it must be called with fully qualified syntax, e.g. `HasDimensions::is_empty(&arr)`.
+
+pub use crate::algorithm::broadcasting::{
+    BroadcastableGeometry, BroadcastableLineString, BroadcastableMultiLineString,
+    BroadcastableMultiPoint, BroadcastableMultiPolygon, BroadcastablePoint, BroadcastablePolygon,
+    BroadcastablePrimitive, BroadcastableVec,
+};
+pub use crate::algorithm::geo::{
+    AffineOps, AggregateConvexHull, Area, BoundingRect, Center, Centroid, ChaikinSmoothing,
+    ChamberlainDuquetteArea, Contains, ConvexHull, Densify, EuclideanDistance,
+    EuclideanDistanceToPoint, EuclideanLength, GeodesicArea, GeodesicLength, HasDimensions,
+    HaversineLength, Intersects, Length, LineInterpolatePoint, LineLocatePoint, MinimumRotatedRect,
+    Perimeter, RemoveRepeatedPoints, Rotate, Scale, Simplify, SimplifyVw, Skew, TrackOps,
+    Translate, ValidateWinding, VincentyLength, Within,
+};
+pub use crate::algorithm::native::{
+    Clean, Concat, CoordTransform, CoordinatePrecision, DetectAxisOrder, DistanceMatrix,
+    EqualsExact, Filter, FindDefects, FlattenCollections, FlipWinding, MultiParts, OffsetCurve,
+    OutputOptions, SortByKey, SwapXY, Take, TransformCoords, TypeIds, WebMercator,
+};
+pub use crate::algorithm::rstar::RTree;
+pub use crate::array::{
+    CoordBuffer, CoordType, GeometryArray, GeometryCollectionArray, InterleavedCoordBuffer,
+    LineStringArray, MixedGeometryArray, MultiLineStringArray, MultiPointArray, MultiPolygonArray,
+    MutableLineStringArray, MutableMixedGeometryArray, MutableMultiLineStringArray,
+    MutableMultiPointArray, MutableMultiPolygonArray, MutablePointArray, MutablePolygonArray,
+    MutableWKBArray, PointArray, PolygonArray, RectArray, SeparatedCoordBuffer, WKBArray,
+};
+pub use crate::trait_::{
+    FromArrow, GeoArrayAccessor, GeometryArrayTrait, GeometryScalarTrait, IntoArrow,
+    MutableGeometryArray,
+};
@@ -1,13 +1,14 @@
 use rstar::{RTreeObject, AABB};
 
 use crate::geo_traits::CoordTrait;
-use crate::scalar::{InterleavedCoord, SeparatedCoord};
+use crate::scalar::{Float32Coord, InterleavedCoord, SeparatedCoord};
 use crate::trait_::GeometryScalarTrait;
 
 #[derive(Debug, Clone)]
 pub enum Coord<'a> {
     Separated(SeparatedCoord<'a>),
     Interleaved(InterleavedCoord<'a>),
+    Float32(Float32Coord<'a>),
 }
 
 impl<'a> GeometryScalarTrait<'a> for Coord<'a> {
@@ -28,6 +29,7 @@ impl From<&Coord<'_>> for geo::Coord {
     fn from(value: &Coord) -> Self {
         match value {
             Coord::Separated(c) => c.into(),
+            Coord::Float32(c) => c.into(),
             Coord::Interleaved(c) => c.into(),
         }
     }
@@ -43,6 +45,7 @@ impl From<&Coord<'_>> for geo::Point {
     fn from(value: &Coord) -> Self {
         match value {
             Coord::Separated(c) => c.into(),
+            Coord::Float32(c) => c.into(),
             Coord::Interleaved(c) => c.into(),
         }
     }
@@ -55,6 +58,7 @@ impl RTreeObject for Coord<'_> {
         match self {
             Coord::Interleaved(c) => c.envelope(),
             Coord::Separated(c) => c.envelope(),
+            Coord::Float32(c) => c.envelope(),
         }
     }
 }
@@ -84,6 +88,7 @@ impl CoordTrait for Coord<'_> {
         match self {
             Coord::Interleaved(c) => c.x(),
             Coord::Separated(c) => c.x(),
+            Coord::Float32(c) => c.x(),
         }
     }
 
@@ -91,6 +96,23 @@ impl CoordTrait for Coord<'_> {
         match self {
             Coord::Interleaved(c) => c.y(),
             Coord::Separated(c) => c.y(),
+            Coord::Float32(c) => c.y(),
+        }
+    }
+
+    fn z(&self) -> Option<Self::T> {
+        match self {
+            Coord::Interleaved(c) => c.z(),
+            Coord::Separated(c) => c.z(),
+            Coord::Float32(c) => c.z(),
+        }
+    }
+
+    fn m(&self) -> Option<Self::T> {
+        match self {
+            Coord::Interleaved(c) => c.m(),
+            Coord::Separated(c) => c.m(),
+            Coord::Float32(c) => c.m(),
         }
     }
 }
@@ -102,6 +124,7 @@ impl CoordTrait for &Coord<'_> {
         match self {
             Coord::Interleaved(c) => c.x(),
             Coord::Separated(c) => c.x(),
+            Coord::Float32(c) => c.x(),
         }
     }
 
@@ -109,6 +132,23 @@ impl CoordTrait for &Coord<'_> {
         match self {
             Coord::Interleaved(c) => c.y(),
             Coord::Separated(c) => c.y(),
+            Coord::Float32(c) => c.y(),
+        }
+    }
+
+    fn z(&self) -> Option<Self::T> {
+        match self {
+            Coord::Interleaved(c) => c.z(),
+            Coord::Separated(c) => c.z(),
+            Coord::Float32(c) => c.z(),
+        }
+    }
+
+    fn m(&self) -> Option<Self::T> {
+        match self {
+            Coord::Interleaved(c) => c.m(),
+            Coord::Separated(c) => c.m(),
+            Coord::Float32(c) => c.m(),
         }
     }
 }
@@ -0,0 +1,3 @@
+pub mod scalar;
+
+pub use scalar::Float32Coord;
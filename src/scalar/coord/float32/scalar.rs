@@ -0,0 +1,113 @@
+use arrow_buffer::ScalarBuffer;
+use rstar::{RTreeObject, AABB};
+
+use crate::algorithm::native::eq::coord_eq;
+use crate::array::Dimension;
+use crate::geo_traits::CoordTrait;
+use crate::trait_::GeometryScalarTrait;
+
+/// A coordinate stored as 32-bit floats, whose values are read as `f64`.
+#[derive(Debug, Clone)]
+pub struct Float32Coord<'a> {
+    pub coords: &'a ScalarBuffer<f32>,
+    pub i: usize,
+
+    /// The dimension of the coordinates in `coords`, which sets their stride
+    pub dim: Dimension,
+}
+
+impl Float32Coord<'_> {
+    fn get(&self, index: usize) -> f64 {
+        f64::from(self.coords[self.i * self.dim.size() + index])
+    }
+}
+
+impl<'a> GeometryScalarTrait<'a> for Float32Coord<'a> {
+    type ScalarGeo = geo::Coord;
+
+    fn to_geo(&self) -> Self::ScalarGeo {
+        self.into()
+    }
+}
+
+impl From<Float32Coord<'_>> for geo::Coord {
+    fn from(value: Float32Coord) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<&Float32Coord<'_>> for geo::Coord {
+    fn from(value: &Float32Coord) -> Self {
+        geo::Coord {
+            x: value.x(),
+            y: value.y(),
+        }
+    }
+}
+
+impl From<Float32Coord<'_>> for geo::Point {
+    fn from(value: Float32Coord<'_>) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<&Float32Coord<'_>> for geo::Point {
+    fn from(value: &Float32Coord<'_>) -> Self {
+        let coord: geo::Coord = value.into();
+        coord.into()
+    }
+}
+
+impl RTreeObject for Float32Coord<'_> {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_point([self.x(), self.y()])
+    }
+}
+
+impl PartialEq for Float32Coord<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        coord_eq(self, other)
+    }
+}
+
+impl CoordTrait for Float32Coord<'_> {
+    type T = f64;
+
+    fn x(&self) -> Self::T {
+        self.get(0)
+    }
+
+    fn y(&self) -> Self::T {
+        self.get(1)
+    }
+
+    fn z(&self) -> Option<Self::T> {
+        self.dim.z_index().map(|index| self.get(index))
+    }
+
+    fn m(&self) -> Option<Self::T> {
+        self.dim.m_index().map(|index| self.get(index))
+    }
+}
+
+impl CoordTrait for &Float32Coord<'_> {
+    type T = f64;
+
+    fn x(&self) -> Self::T {
+        self.get(0)
+    }
+
+    fn y(&self) -> Self::T {
+        self.get(1)
+    }
+
+    fn z(&self) -> Option<Self::T> {
+        self.dim.z_index().map(|index| self.get(index))
+    }
+
+    fn m(&self) -> Option<Self::T> {
+        self.dim.m_index().map(|index| self.get(index))
+    }
+}
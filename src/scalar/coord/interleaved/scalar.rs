@@ -2,6 +2,7 @@ use arrow_buffer::ScalarBuffer;
 use rstar::{RTreeObject, AABB};
 
 use crate::algorithm::native::eq::coord_eq;
+use crate::array::Dimension;
 use crate::geo_traits::CoordTrait;
 use crate::scalar::SeparatedCoord;
 use crate::trait_::GeometryScalarTrait;
@@ -10,6 +11,9 @@ use crate::trait_::GeometryScalarTrait;
 pub struct InterleavedCoord<'a> {
     pub coords: &'a ScalarBuffer<f64>,
     pub i: usize,
+
+    /// The dimension of the coordinates in `coords`, which sets their stride
+    pub dim: Dimension,
 }
 
 impl<'a> GeometryScalarTrait<'a> for InterleavedCoord<'a> {
@@ -72,11 +76,23 @@ impl CoordTrait for InterleavedCoord<'_> {
     type T = f64;
 
     fn x(&self) -> Self::T {
-        *self.coords.get(self.i * 2).unwrap()
+        *self.coords.get(self.i * self.dim.size()).unwrap()
     }
 
     fn y(&self) -> Self::T {
-        *self.coords.get(self.i * 2 + 1).unwrap()
+        *self.coords.get(self.i * self.dim.size() + 1).unwrap()
+    }
+
+    fn z(&self) -> Option<Self::T> {
+        self.dim
+            .z_index()
+            .map(|index| *self.coords.get(self.i * self.dim.size() + index).unwrap())
+    }
+
+    fn m(&self) -> Option<Self::T> {
+        self.dim
+            .m_index()
+            .map(|index| *self.coords.get(self.i * self.dim.size() + index).unwrap())
     }
 }
 
@@ -84,11 +100,23 @@ impl CoordTrait for &InterleavedCoord<'_> {
     type T = f64;
 
     fn x(&self) -> Self::T {
-        *self.coords.get(self.i * 2).unwrap()
+        *self.coords.get(self.i * self.dim.size()).unwrap()
     }
 
     fn y(&self) -> Self::T {
-        *self.coords.get(self.i * 2 + 1).unwrap()
+        *self.coords.get(self.i * self.dim.size() + 1).unwrap()
+    }
+
+    fn z(&self) -> Option<Self::T> {
+        self.dim
+            .z_index()
+            .map(|index| *self.coords.get(self.i * self.dim.size() + index).unwrap())
+    }
+
+    fn m(&self) -> Option<Self::T> {
+        self.dim
+            .m_index()
+            .map(|index| *self.coords.get(self.i * self.dim.size() + index).unwrap())
     }
 }
 
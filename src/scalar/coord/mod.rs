@@ -1,7 +1,9 @@
 pub mod combined;
+pub mod float32;
 pub mod interleaved;
 pub mod separated;
 
 pub use combined::Coord;
+pub use float32::Float32Coord;
 pub use interleaved::InterleavedCoord;
 pub use separated::SeparatedCoord;
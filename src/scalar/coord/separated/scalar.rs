@@ -9,6 +9,8 @@ use rstar::{RTreeObject, AABB};
 pub struct SeparatedCoord<'a> {
     pub x: &'a ScalarBuffer<f64>,
     pub y: &'a ScalarBuffer<f64>,
+    pub z: Option<&'a ScalarBuffer<f64>>,
+    pub m: Option<&'a ScalarBuffer<f64>>,
     pub i: usize,
 }
 
@@ -77,6 +79,14 @@ impl CoordTrait for SeparatedCoord<'_> {
     fn y(&self) -> Self::T {
         self.y[self.i]
     }
+
+    fn z(&self) -> Option<Self::T> {
+        self.z.map(|z| z[self.i])
+    }
+
+    fn m(&self) -> Option<Self::T> {
+        self.m.map(|m| m[self.i])
+    }
 }
 
 impl CoordTrait for &SeparatedCoord<'_> {
@@ -89,6 +99,14 @@ impl CoordTrait for &SeparatedCoord<'_> {
     fn y(&self) -> Self::T {
         self.y[self.i]
     }
+
+    fn z(&self) -> Option<Self::T> {
+        self.z.map(|z| z[self.i])
+    }
+
+    fn m(&self) -> Option<Self::T> {
+        self.m.map(|m| m[self.i])
+    }
 }
 
 #[cfg(test)]
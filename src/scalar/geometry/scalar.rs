@@ -15,6 +15,7 @@ pub enum Geometry<'a, O: OffsetSizeTrait> {
     MultiPoint(crate::scalar::MultiPoint<'a, O>),
     MultiLineString(crate::scalar::MultiLineString<'a, O>),
     MultiPolygon(crate::scalar::MultiPolygon<'a, O>),
+    GeometryCollection(crate::scalar::GeometryCollection<'a, O>),
     Rect(crate::scalar::Rect<'a>),
 }
 
@@ -29,6 +30,7 @@ impl<'a, O: OffsetSizeTrait> GeometryScalarTrait<'a> for Geometry<'a, O> {
             Geometry::MultiPoint(g) => geo::Geometry::MultiPoint(g.into()),
             Geometry::MultiLineString(g) => geo::Geometry::MultiLineString(g.into()),
             Geometry::MultiPolygon(g) => geo::Geometry::MultiPolygon(g.into()),
+            Geometry::GeometryCollection(g) => geo::Geometry::GeometryCollection(g.into()),
             Geometry::Rect(g) => geo::Geometry::Rect(g.into()),
         }
     }
@@ -67,7 +69,7 @@ impl<'a, O: OffsetSizeTrait> GeometryTrait<'a> for Geometry<'a, O> {
             Geometry::MultiPoint(p) => GeometryType::MultiPoint(p),
             Geometry::MultiLineString(p) => GeometryType::MultiLineString(p),
             Geometry::MultiPolygon(p) => GeometryType::MultiPolygon(p),
-            // Geometry::GeometryCollection(p) => GeometryType::GeometryCollection(p),
+            Geometry::GeometryCollection(p) => GeometryType::GeometryCollection(p),
             Geometry::Rect(p) => GeometryType::Rect(p),
         }
     }
@@ -84,6 +86,7 @@ impl<O: OffsetSizeTrait> RTreeObject for Geometry<'_, O> {
             Geometry::MultiPoint(geom) => geom.envelope(),
             Geometry::MultiLineString(geom) => geom.envelope(),
             Geometry::MultiPolygon(geom) => geom.envelope(),
+            Geometry::GeometryCollection(geom) => geom.envelope(),
             Geometry::Rect(geom) => geom.envelope(),
         }
     }
@@ -98,7 +101,41 @@ impl<O: OffsetSizeTrait> From<Geometry<'_, O>> for geo::Geometry {
             Geometry::MultiPoint(geom) => geom.into(),
             Geometry::MultiLineString(geom) => geom.into(),
             Geometry::MultiPolygon(geom) => geom.into(),
+            Geometry::GeometryCollection(geom) => geo::Geometry::GeometryCollection(geom.into()),
             Geometry::Rect(geom) => geom.into(),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{MixedGeometryArray, MutableMixedGeometryArray};
+    use crate::test::{linestring, point, polygon};
+    use crate::trait_::GeoArrayAccessor;
+
+    #[test]
+    fn mixed_array_variants() {
+        let mut builder = MutableMixedGeometryArray::<i32>::new();
+        builder.push_point(Some(&point::p0()));
+        builder.push_line_string(Some(&linestring::ls0())).unwrap();
+        builder.push_polygon(Some(&polygon::p0())).unwrap();
+        let arr: MixedGeometryArray<i32> = builder.into();
+
+        assert!(matches!(arr.value(0), Geometry::Point(_)));
+        assert!(matches!(arr.value(1), Geometry::LineString(_)));
+        assert!(matches!(arr.value(2), Geometry::Polygon(_)));
+
+        let geom = arr.value(1);
+        match geom.as_type() {
+            GeometryType::LineString(line_string) => {
+                assert_eq!(geo::LineString::from(line_string), linestring::ls0())
+            }
+            _ => panic!("Expected a LineString"),
+        }
+        assert_eq!(
+            geo::Geometry::from(arr.value(2)),
+            geo::Geometry::Polygon(polygon::p0())
+        );
+    }
+}
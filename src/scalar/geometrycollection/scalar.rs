@@ -1,5 +1,5 @@
-use crate::array::util::OffsetBufferUtils;
 use crate::array::MixedGeometryArray;
+use crate::buffer::OffsetBufferUtils;
 use crate::geo_traits::GeometryCollectionTrait;
 use crate::scalar::geometrycollection::GeometryCollectionIterator;
 use crate::scalar::Geometry;
@@ -7,7 +7,7 @@ use crate::trait_::GeoArrayAccessor;
 use crate::trait_::GeometryScalarTrait;
 use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
-use rstar::{RTreeObject, AABB};
+use rstar::{Envelope, RTreeObject, AABB};
 
 /// An Arrow equivalent of a GeometryCollection
 #[derive(Debug, Clone)]
@@ -44,7 +44,7 @@ impl<'a, O: OffsetSizeTrait> GeometryCollectionTrait<'a> for GeometryCollection<
 
     fn geometry(&self, i: usize) -> Option<Self::ItemType> {
         let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        if i > (end - start) {
+        if i >= (end - start) {
             return None;
         }
 
@@ -74,8 +74,15 @@ impl<O: OffsetSizeTrait> RTreeObject for GeometryCollection<'_, O> {
     type Envelope = AABB<[f64; 2]>;
 
     fn envelope(&self) -> Self::Envelope {
-        todo!()
-        // let (lower, upper) = bounding_rect_multilinestring(self);
-        // AABB::from_corners(lower, upper)
+        (0..self.num_geometries())
+            .map(|i| self.geometry(i).unwrap().envelope())
+            .fold(AABB::new_empty(), |acc, envelope| acc.merged(&envelope))
+    }
+}
+
+impl<O: OffsetSizeTrait> PartialEq for GeometryCollection<'_, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.num_geometries() == other.num_geometries()
+            && (0..self.num_geometries()).all(|i| self.geometry(i) == other.geometry(i))
     }
 }
@@ -1,4 +1,3 @@
-use crate::geo_traits::LineStringTrait;
 use crate::scalar::{LineString, Point};
 use arrow_array::OffsetSizeTrait;
 
@@ -1,7 +1,7 @@
 use crate::algorithm::native::bounding_rect::bounding_rect_linestring;
 use crate::algorithm::native::eq::line_string_eq;
-use crate::array::util::OffsetBufferUtils;
 use crate::array::{CoordBuffer, LineStringArray};
+use crate::buffer::OffsetBufferUtils;
 use crate::geo_traits::LineStringTrait;
 use crate::scalar::Point;
 use crate::trait_::GeometryScalarTrait;
@@ -82,6 +82,31 @@ impl<'a, O: OffsetSizeTrait> LineString<'a, O> {
             owned.geom_index,
         )
     }
+
+    /// The number of coordinates in this line string.
+    pub fn num_coords(&self) -> usize {
+        let (start, end) = self.geom_offsets.start_end(self.geom_index);
+        end - start
+    }
+
+    /// The `i`th coordinate of this line string, or `None` if `i` is out of bounds.
+    pub fn coord(&self, i: usize) -> Option<Point<'a>> {
+        let (start, end) = self.geom_offsets.start_end(self.geom_index);
+        if i >= (end - start) {
+            return None;
+        }
+
+        Some(Point::new(self.coords.clone(), start + i))
+    }
+
+    /// An iterator over the `(x, y)` coordinates of this line string.
+    ///
+    /// Unlike [`iter`](Self::iter), this reads the coordinates straight from the buffer without
+    /// creating a [`Point`] for each.
+    pub fn coords_iter(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        let (start, end) = self.geom_offsets.start_end(self.geom_index);
+        (start..end).map(|i| (self.coords.get_x(i), self.coords.get_y(i)))
+    }
 }
 
 impl<'a, O: OffsetSizeTrait> GeometryScalarTrait<'a> for LineString<'a, O> {
@@ -102,17 +127,11 @@ impl<'a, O: OffsetSizeTrait> LineStringTrait<'a> for LineString<'a, O> {
     }
 
     fn num_coords(&self) -> usize {
-        let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        end - start
+        LineString::num_coords(self)
     }
 
     fn coord(&self, i: usize) -> Option<Self::ItemType> {
-        let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        if i > (end - start) {
-            return None;
-        }
-
-        Some(Point::new(self.coords.clone(), start + i))
+        LineString::coord(self, i)
     }
 }
 
@@ -126,17 +145,11 @@ impl<'a, O: OffsetSizeTrait> LineStringTrait<'a> for &LineString<'a, O> {
     }
 
     fn num_coords(&self) -> usize {
-        let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        end - start
+        LineString::num_coords(self)
     }
 
     fn coord(&self, i: usize) -> Option<Self::ItemType> {
-        let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        if i > (end - start) {
-            return None;
-        }
-
-        Some(Point::new(self.coords.clone(), start + i))
+        LineString::coord(self, i)
     }
 }
 
@@ -183,6 +196,7 @@ impl<O: OffsetSizeTrait> PartialEq for LineString<'_, O> {
 #[cfg(test)]
 mod test {
     use crate::array::LineStringArray;
+    use crate::geo_traits::{CoordTrait, LineStringTrait};
     use crate::test::linestring::{ls0, ls1};
     use crate::trait_::GeoArrayAccessor;
 
@@ -195,4 +209,32 @@ mod test {
         assert_eq!(arr1.value(0), arr2.value(0));
         assert_ne!(arr1.value(1), arr2.value(1));
     }
+
+    #[test]
+    fn coord_accessors() {
+        let arr: LineStringArray<i32> = vec![ls0(), ls1()].into();
+        let line_string = arr.value(1);
+        let expected: Vec<(f64, f64)> = ls1().coords().map(|c| c.x_y()).collect();
+
+        assert_eq!(line_string.num_coords(), expected.len());
+        assert_eq!(line_string.coords_iter().collect::<Vec<_>>(), expected);
+        assert_eq!(line_string.coord(1).unwrap().coords(), expected[1]);
+        assert!(line_string.coord(expected.len()).is_none());
+    }
+
+    /// The second coordinate of any line string, read through the trait
+    fn second_coord<'a>(line_string: &impl LineStringTrait<'a, T = f64>) -> Option<(f64, f64)> {
+        line_string.coord(1).map(|c| c.x_y())
+    }
+
+    #[test]
+    fn coord_via_trait() {
+        let arr: LineStringArray<i32> = vec![ls0(), ls1()].into();
+        assert_eq!(second_coord(&ls1()), Some((5., 6.)));
+        assert_eq!(second_coord(&arr.value(1)), Some((5., 6.)));
+
+        let line_string = arr.value(0);
+        assert_eq!(LineStringTrait::num_coords(&line_string), 2);
+        assert!(LineStringTrait::coord(&line_string, 2).is_none());
+    }
 }
@@ -2,7 +2,7 @@
 //! at a specific index.
 
 pub use binary::WKB;
-pub use coord::{Coord, InterleavedCoord, SeparatedCoord};
+pub use coord::{Coord, Float32Coord, InterleavedCoord, SeparatedCoord};
 pub use geometry::Geometry;
 pub use geometrycollection::GeometryCollection;
 pub use linestring::{LineString, OwnedLineString};
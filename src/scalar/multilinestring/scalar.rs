@@ -1,7 +1,7 @@
 use crate::algorithm::native::bounding_rect::bounding_rect_multilinestring;
 use crate::algorithm::native::eq::multi_line_string_eq;
-use crate::array::util::OffsetBufferUtils;
 use crate::array::{CoordBuffer, MultiLineStringArray};
+use crate::buffer::OffsetBufferUtils;
 use crate::geo_traits::MultiLineStringTrait;
 use crate::scalar::multilinestring::MultiLineStringIterator;
 use crate::scalar::LineString;
@@ -124,7 +124,7 @@ impl<'a, O: OffsetSizeTrait> MultiLineStringTrait<'a> for MultiLineString<'a, O>
 
     fn line(&self, i: usize) -> Option<Self::ItemType> {
         let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        if i > (end - start) {
+        if i >= (end - start) {
             return None;
         }
 
@@ -152,7 +152,7 @@ impl<'a, O: OffsetSizeTrait> MultiLineStringTrait<'a> for &MultiLineString<'a, O
 
     fn line(&self, i: usize) -> Option<Self::ItemType> {
         let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        if i > (end - start) {
+        if i >= (end - start) {
             return None;
         }
 
@@ -1,7 +1,7 @@
 use crate::algorithm::native::bounding_rect::bounding_rect_multipoint;
 use crate::algorithm::native::eq::multi_point_eq;
-use crate::array::util::OffsetBufferUtils;
 use crate::array::{CoordBuffer, MultiPointArray};
+use crate::buffer::OffsetBufferUtils;
 use crate::geo_traits::MultiPointTrait;
 use crate::scalar::multipoint::MultiPointIterator;
 use crate::scalar::Point;
@@ -109,7 +109,7 @@ impl<'a, O: OffsetSizeTrait> MultiPointTrait<'a> for MultiPoint<'a, O> {
 
     fn point(&self, i: usize) -> Option<Self::ItemType> {
         let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        if i > (end - start) {
+        if i >= (end - start) {
             return None;
         }
 
@@ -133,7 +133,7 @@ impl<'a, O: OffsetSizeTrait> MultiPointTrait<'a> for &MultiPoint<'a, O> {
 
     fn point(&self, i: usize) -> Option<Self::ItemType> {
         let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        if i > (end - start) {
+        if i >= (end - start) {
             return None;
         }
 
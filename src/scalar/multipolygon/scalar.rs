@@ -1,7 +1,7 @@
 use crate::algorithm::native::bounding_rect::bounding_rect_multipolygon;
 use crate::algorithm::native::eq::multi_polygon_eq;
-use crate::array::util::OffsetBufferUtils;
 use crate::array::{CoordBuffer, MultiPolygonArray};
+use crate::buffer::OffsetBufferUtils;
 use crate::geo_traits::MultiPolygonTrait;
 use crate::scalar::multipolygon::MultiPolygonIterator;
 use crate::scalar::Polygon;
@@ -143,7 +143,7 @@ impl<'a, O: OffsetSizeTrait> MultiPolygonTrait<'a> for MultiPolygon<'a, O> {
 
     fn polygon(&self, i: usize) -> Option<Self::ItemType> {
         let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        if i > (end - start) {
+        if i >= (end - start) {
             return None;
         }
 
@@ -173,7 +173,7 @@ impl<'a, O: OffsetSizeTrait> MultiPolygonTrait<'a> for &MultiPolygon<'a, O> {
 
     fn polygon(&self, i: usize) -> Option<Self::ItemType> {
         let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        if i > (end - start) {
+        if i >= (end - start) {
             return None;
         }
 
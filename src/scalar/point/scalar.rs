@@ -68,6 +68,36 @@ impl<'a> Point<'a> {
         let owned = self.into_owned();
         (owned.coords.into_owned(), owned.geom_index)
     }
+
+    /// The x coordinate of this point.
+    pub fn x(&self) -> f64 {
+        self.coords.get_x(self.geom_index)
+    }
+
+    /// The y coordinate of this point.
+    pub fn y(&self) -> f64 {
+        self.coords.get_y(self.geom_index)
+    }
+
+    /// The z coordinate of this point, or `None` if its array has no z values.
+    pub fn z(&self) -> Option<f64> {
+        self.coords.get_z(self.geom_index)
+    }
+
+    /// The m (measure) value of this point, or `None` if its array isn't measured.
+    pub fn m(&self) -> Option<f64> {
+        self.coords.get_m(self.geom_index)
+    }
+
+    /// The coordinates of this point as an `(x, y)` tuple.
+    pub fn coords(&self) -> (f64, f64) {
+        (self.x(), self.y())
+    }
+
+    /// The coordinates of this point as an `[x, y]` array.
+    pub fn to_array(&self) -> [f64; 2] {
+        [self.x(), self.y()]
+    }
 }
 
 impl<'a> GeometryScalarTrait<'a> for Point<'a> {
@@ -82,11 +112,19 @@ impl PointTrait for Point<'_> {
     type T = f64;
 
     fn x(&self) -> f64 {
-        self.coords.get_x(self.geom_index)
+        Point::x(self)
     }
 
     fn y(&self) -> f64 {
-        self.coords.get_y(self.geom_index)
+        Point::y(self)
+    }
+
+    fn z(&self) -> Option<f64> {
+        Point::z(self)
+    }
+
+    fn m(&self) -> Option<f64> {
+        Point::m(self)
     }
 }
 
@@ -94,11 +132,19 @@ impl PointTrait for &Point<'_> {
     type T = f64;
 
     fn x(&self) -> f64 {
-        self.coords.get_x(self.geom_index)
+        Point::x(self)
     }
 
     fn y(&self) -> f64 {
-        self.coords.get_y(self.geom_index)
+        Point::y(self)
+    }
+
+    fn z(&self) -> Option<f64> {
+        Point::z(self)
+    }
+
+    fn m(&self) -> Option<f64> {
+        Point::m(self)
     }
 }
 
@@ -106,11 +152,19 @@ impl CoordTrait for Point<'_> {
     type T = f64;
 
     fn x(&self) -> Self::T {
-        self.coords.get_x(self.geom_index)
+        Point::x(self)
     }
 
     fn y(&self) -> Self::T {
-        self.coords.get_y(self.geom_index)
+        Point::y(self)
+    }
+
+    fn z(&self) -> Option<f64> {
+        Point::z(self)
+    }
+
+    fn m(&self) -> Option<f64> {
+        Point::m(self)
     }
 }
 
@@ -122,7 +176,7 @@ impl From<Point<'_>> for geo::Point {
 
 impl From<&Point<'_>> for geo::Point {
     fn from(value: &Point<'_>) -> Self {
-        geo::Point::new(PointTrait::x(&value), PointTrait::y(&value))
+        geo::Point::new(value.x(), value.y())
     }
 }
 
@@ -134,10 +188,8 @@ impl From<Point<'_>> for geo::Coord {
 
 impl From<&Point<'_>> for geo::Coord {
     fn from(value: &Point<'_>) -> Self {
-        geo::Coord {
-            x: PointTrait::x(&value),
-            y: PointTrait::y(&value),
-        }
+        let (x, y) = value.coords();
+        geo::Coord { x, y }
     }
 }
 
@@ -182,4 +234,15 @@ mod test {
 
         assert_eq!(arr1.value(0), arr2.value(0));
     }
+
+    #[test]
+    fn coord_accessors() {
+        let buf = CoordBuffer::Separated((vec![0., 1.], vec![3., 4.]).try_into().unwrap());
+        let arr = PointArray::new(buf, None);
+        let point = arr.value(1);
+        assert_eq!(point.x(), 1.);
+        assert_eq!(point.y(), 4.);
+        assert_eq!(point.coords(), (1., 4.));
+        assert_eq!(point.to_array(), [1., 4.]);
+    }
 }
@@ -1,4 +1,3 @@
-use crate::geo_traits::PolygonTrait;
 use crate::scalar::{LineString, Polygon};
 use arrow_array::OffsetSizeTrait;
 
@@ -1,8 +1,8 @@
 use crate::algorithm::native::bounding_rect::bounding_rect_polygon;
 use crate::algorithm::native::eq::polygon_eq;
 use crate::array::polygon::parse_polygon;
-use crate::array::util::OffsetBufferUtils;
 use crate::array::{CoordBuffer, PolygonArray};
+use crate::buffer::OffsetBufferUtils;
 use crate::geo_traits::PolygonTrait;
 use crate::scalar::polygon::iterator::PolygonInteriorIterator;
 use crate::scalar::LineString;
@@ -98,6 +98,42 @@ impl<'a, O: OffsetSizeTrait> Polygon<'a, O> {
             owned.geom_index,
         )
     }
+
+    /// The exterior ring of this polygon, or `None` if the polygon is empty.
+    ///
+    /// The ring borrows the same buffers as this polygon.
+    pub fn exterior(&self) -> Option<LineString<'a, O>> {
+        let (start, end) = self.geom_offsets.start_end(self.geom_index);
+        if start == end {
+            None
+        } else {
+            Some(LineString::new(
+                self.coords.clone(),
+                self.ring_offsets.clone(),
+                start,
+            ))
+        }
+    }
+
+    /// The number of interior rings of this polygon.
+    pub fn num_interiors(&self) -> usize {
+        let (start, end) = self.geom_offsets.start_end(self.geom_index);
+        (end - start).saturating_sub(1)
+    }
+
+    /// The `i`th interior ring of this polygon, or `None` if `i` is out of bounds.
+    pub fn interior(&self, i: usize) -> Option<LineString<'a, O>> {
+        if i >= self.num_interiors() {
+            return None;
+        }
+
+        let (start, _) = self.geom_offsets.start_end(self.geom_index);
+        Some(LineString::new(
+            self.coords.clone(),
+            self.ring_offsets.clone(),
+            start + 1 + i,
+        ))
+    }
 }
 
 impl<'a, O: OffsetSizeTrait> GeometryScalarTrait<'a> for Polygon<'a, O> {
@@ -114,16 +150,7 @@ impl<'a, O: OffsetSizeTrait> PolygonTrait<'a> for Polygon<'a, O> {
     type Iter = PolygonInteriorIterator<'a, O>;
 
     fn exterior(&self) -> Option<Self::ItemType> {
-        let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        if start == end {
-            None
-        } else {
-            Some(LineString::new(
-                self.coords.clone(),
-                self.ring_offsets.clone(),
-                start,
-            ))
-        }
+        Polygon::exterior(self)
     }
 
     fn interiors(&'a self) -> Self::Iter {
@@ -131,21 +158,11 @@ impl<'a, O: OffsetSizeTrait> PolygonTrait<'a> for Polygon<'a, O> {
     }
 
     fn num_interiors(&self) -> usize {
-        let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        end - start - 1
+        Polygon::num_interiors(self)
     }
 
     fn interior(&self, i: usize) -> Option<Self::ItemType> {
-        let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        if i > (end - start - 1) {
-            return None;
-        }
-
-        Some(LineString::new(
-            self.coords.clone(),
-            self.ring_offsets.clone(),
-            start + 1 + i,
-        ))
+        Polygon::interior(self, i)
     }
 }
 
@@ -155,16 +172,7 @@ impl<'a, O: OffsetSizeTrait> PolygonTrait<'a> for &Polygon<'a, O> {
     type Iter = PolygonInteriorIterator<'a, O>;
 
     fn exterior(&self) -> Option<Self::ItemType> {
-        let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        if start == end {
-            None
-        } else {
-            Some(LineString::new(
-                self.coords.clone(),
-                self.ring_offsets.clone(),
-                start,
-            ))
-        }
+        Polygon::exterior(self)
     }
 
     fn interiors(&'a self) -> Self::Iter {
@@ -172,21 +180,11 @@ impl<'a, O: OffsetSizeTrait> PolygonTrait<'a> for &Polygon<'a, O> {
     }
 
     fn num_interiors(&self) -> usize {
-        let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        end - start - 1
+        Polygon::num_interiors(self)
     }
 
     fn interior(&self, i: usize) -> Option<Self::ItemType> {
-        let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        if i > (end - start - 1) {
-            return None;
-        }
-
-        Some(LineString::new(
-            self.coords.clone(),
-            self.ring_offsets.clone(),
-            start + 1 + i,
-        ))
+        Polygon::interior(self, i)
     }
 }
 
@@ -232,7 +230,7 @@ impl<O: OffsetSizeTrait> PartialEq for Polygon<'_, O> {
 mod test {
     use crate::array::PolygonArray;
     use crate::test::polygon::{p0, p1};
-    use crate::trait_::GeoArrayAccessor;
+    use crate::trait_::{GeoArrayAccessor, GeometryScalarTrait};
 
     /// Test Eq where the current index is true but another index is false
     #[test]
@@ -243,4 +241,23 @@ mod test {
         assert_eq!(arr1.value(0), arr2.value(0));
         assert_ne!(arr1.value(1), arr2.value(1));
     }
+
+    #[test]
+    fn ring_accessors() {
+        let arr: PolygonArray<i32> = vec![p0(), p1()].into();
+        let polygon = arr.value(1);
+        let expected = p1();
+
+        let exterior = polygon.exterior().unwrap();
+        let exterior_coords: Vec<(f64, f64)> =
+            expected.exterior().coords().map(|c| c.x_y()).collect();
+        assert_eq!(exterior.coords_iter().collect::<Vec<_>>(), exterior_coords);
+
+        assert_eq!(polygon.num_interiors(), expected.interiors().len());
+        for (i, ring) in expected.interiors().iter().enumerate() {
+            let interior = polygon.interior(i).unwrap();
+            assert_eq!(interior.to_geo(), *ring);
+        }
+        assert!(polygon.interior(expected.interiors().len()).is_none());
+    }
 }
@@ -0,0 +1,464 @@
+use std::collections::BTreeSet;
+
+use arrow::compute::{cast, max, min};
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use arrow_array::{new_empty_array, Array, ArrayRef, OffsetSizeTrait};
+use arrow_schema::{DataType, Field};
+use geo::Rect;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::algorithm::native::bounding_rect::total_bounds_rects;
+use crate::array::{GeometryArray, WKBArray};
+use crate::datatypes::GeoDataType;
+use crate::error::{GeoArrowError, Result};
+use crate::geo_traits::{
+    GeometryCollectionTrait, LineStringTrait, MultiLineStringTrait, MultiPointTrait,
+    MultiPolygonTrait, PolygonTrait,
+};
+use crate::io::wkb::reader::geometry::WKBGeometry;
+use crate::scalar::Geometry;
+use crate::table::GeoTable;
+use crate::trait_::GeoArrayAccessor;
+use crate::GeometryArrayTrait;
+
+/// A summary of a [`GeoTable`], as returned by [`GeoTable::describe`].
+///
+/// It serializes to a JSON object with `serde_json`. Data types are written as strings, and
+/// bounds as `[minx, miny, maxx, maxy]`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GeoTableSummary {
+    pub num_rows: usize,
+    pub geometry: GeometryColumnSummary,
+    /// Summaries of the other columns, in schema order.
+    pub attributes: Vec<AttributeColumnSummary>,
+}
+
+/// A summary of the geometry column of a [`GeoTable`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GeometryColumnSummary {
+    pub name: String,
+    #[serde(serialize_with = "serialize_debug")]
+    pub data_type: GeoDataType,
+    /// The `crs` key of the column's extension metadata, if any.
+    pub crs: Option<Value>,
+    pub null_count: usize,
+    /// The number of non-null geometries without any coordinates.
+    pub empty_count: usize,
+    /// The names of the geometry types present, such as `"Polygon"`, sorted alphabetically.
+    pub geometry_types: Vec<String>,
+    /// The bounds of all geometries, or `None` if no geometry has coordinates.
+    #[serde(serialize_with = "serialize_bounds")]
+    pub total_bounds: Option<Rect>,
+    pub num_coords: usize,
+    /// The mean number of coordinates of the non-null geometries, or `None` if all are null.
+    pub mean_coords_per_geometry: Option<f64>,
+    /// The memory size of the column's Arrow buffers, in bytes.
+    pub memory_size: usize,
+}
+
+/// A summary of an attribute column of a [`GeoTable`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AttributeColumnSummary {
+    pub name: String,
+    #[serde(serialize_with = "serialize_display")]
+    pub data_type: DataType,
+    pub null_count: usize,
+    /// The minimum value as a float, for numeric columns with at least one non-null value.
+    pub min: Option<f64>,
+    /// The maximum value as a float, for numeric columns with at least one non-null value.
+    pub max: Option<f64>,
+}
+
+fn serialize_debug<S: Serializer>(
+    value: &impl std::fmt::Debug,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.collect_str(&format_args!("{:?}", value))
+}
+
+fn serialize_display<S: Serializer>(
+    value: &impl std::fmt::Display,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+fn serialize_bounds<S: Serializer>(
+    bounds: &Option<Rect>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    bounds
+        .map(|rect| [rect.min().x, rect.min().y, rect.max().x, rect.max().y])
+        .serialize(serializer)
+}
+
+impl GeoTable {
+    /// Summarize the geometry column and the attribute columns of this table.
+    ///
+    /// The geometry column may be any GeoArrow type, including WKB.
+    ///
+    /// # Errors
+    ///
+    /// - if the geometry column is not a GeoArrow array, or its extension metadata is invalid
+    pub fn describe(&self) -> Result<GeoTableSummary> {
+        let field = self.schema.field(self.geometry_column_index);
+        let large_offsets = matches!(
+            field.data_type(),
+            DataType::LargeList(_) | DataType::LargeBinary
+        );
+        let geometry = if large_offsets {
+            describe_geometry_column::<i64>(self, field)?
+        } else {
+            describe_geometry_column::<i32>(self, field)?
+        };
+
+        let attributes = self
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(column_idx, _)| *column_idx != self.geometry_column_index)
+            .map(|(column_idx, field)| describe_attribute_column(self, column_idx, field))
+            .collect::<Result<_>>()?;
+
+        Ok(GeoTableSummary {
+            num_rows: self.batches.iter().map(|batch| batch.num_rows()).sum(),
+            geometry,
+            attributes,
+        })
+    }
+}
+
+fn describe_geometry_column<O: OffsetSizeTrait>(
+    table: &GeoTable,
+    field: &Field,
+) -> Result<GeometryColumnSummary>
+where
+    GeometryArray<O>: for<'a> TryFrom<(&'a Field, &'a dyn Array), Error = GeoArrowError>,
+    WKBArray<O>: for<'a> TryFrom<&'a dyn Array, Error = GeoArrowError>,
+{
+    let is_wkb = field
+        .metadata()
+        .get("ARROW:extension:name")
+        .is_some_and(|name| name == "geoarrow.wkb");
+    let crs = match field.metadata().get("ARROW:extension:metadata") {
+        Some(metadata) => {
+            let metadata: Value = serde_json::from_str(metadata).map_err(|err| {
+                GeoArrowError::General(format!("Invalid extension metadata: {}", err))
+            })?;
+            metadata.get("crs").cloned()
+        }
+        None => None,
+    };
+
+    // A table without batches still has the data type of its schema
+    let columns: Vec<ArrayRef> = if table.batches.is_empty() {
+        vec![new_empty_array(field.data_type())]
+    } else {
+        table
+            .batches
+            .iter()
+            .map(|batch| batch.column(table.geometry_column_index).clone())
+            .collect()
+    };
+
+    let mut data_type = None;
+    let mut geometries = GeometryStats::default();
+    let mut memory_size = 0;
+    for column in columns {
+        memory_size += column.get_array_memory_size();
+        if is_wkb {
+            let array = WKBArray::<O>::try_from(column.as_ref())?;
+            data_type = Some(array.data_type().clone());
+            geometries.update_wkb(&array)?;
+        } else {
+            let array = GeometryArray::<O>::try_from((field, column.as_ref()))?;
+            data_type = Some(array.data_type().clone());
+            geometries.update_native(&array);
+        }
+    }
+
+    let num_valid = geometries.num_rows - geometries.null_count;
+    Ok(GeometryColumnSummary {
+        name: field.name().clone(),
+        data_type: data_type.unwrap(),
+        crs,
+        null_count: geometries.null_count,
+        empty_count: geometries.empty_count,
+        geometry_types: geometries.geometry_types.into_iter().collect(),
+        total_bounds: geometries.total_bounds,
+        num_coords: geometries.num_coords,
+        mean_coords_per_geometry: (num_valid > 0)
+            .then(|| geometries.num_coords as f64 / num_valid as f64),
+        memory_size,
+    })
+}
+
+fn describe_attribute_column(
+    table: &GeoTable,
+    column_idx: usize,
+    field: &Field,
+) -> Result<AttributeColumnSummary> {
+    let mut summary = AttributeColumnSummary {
+        name: field.name().clone(),
+        data_type: field.data_type().clone(),
+        null_count: 0,
+        min: None,
+        max: None,
+    };
+
+    for batch in &table.batches {
+        let column = batch.column(column_idx);
+        summary.null_count += column.null_count();
+        if field.data_type().is_numeric() {
+            let values = cast(column, &DataType::Float64)?;
+            let values = values.as_primitive::<Float64Type>();
+            summary.min = [summary.min, min(values)]
+                .into_iter()
+                .flatten()
+                .reduce(f64::min);
+            summary.max = [summary.max, max(values)]
+                .into_iter()
+                .flatten()
+                .reduce(f64::max);
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Running statistics over the arrays of a geometry column.
+///
+/// Bounds and geometry types come from the array-level kernels, and coordinates are counted
+/// through the geometry access traits, so that no row is converted to a `geo` object.
+#[derive(Default)]
+struct GeometryStats {
+    num_rows: usize,
+    null_count: usize,
+    empty_count: usize,
+    geometry_types: BTreeSet<String>,
+    total_bounds: Option<Rect>,
+    num_coords: usize,
+}
+
+impl GeometryStats {
+    fn update_native<O: OffsetSizeTrait>(&mut self, array: &GeometryArray<O>) {
+        self.update_array(array);
+        if array.len() > array.null_count() {
+            let geometry_type = match array {
+                GeometryArray::Point(_) => "Point",
+                GeometryArray::LineString(_) => "LineString",
+                GeometryArray::Polygon(_) => "Polygon",
+                GeometryArray::MultiPoint(_) => "MultiPoint",
+                GeometryArray::MultiLineString(_) => "MultiLineString",
+                GeometryArray::MultiPolygon(_) => "MultiPolygon",
+                GeometryArray::GeometryCollection(_) => "GeometryCollection",
+                GeometryArray::Rect(_) => "Rect",
+            };
+            self.geometry_types.insert(geometry_type.to_string());
+        }
+        (0..array.len())
+            .filter_map(|i| array.get(i))
+            .for_each(|geometry| self.update_coords(num_coords(&geometry)));
+    }
+
+    fn update_wkb<O: OffsetSizeTrait>(&mut self, array: &WKBArray<O>) -> Result<()> {
+        self.update_array(array);
+        for geometry_type in array.type_histogram()?.into_keys() {
+            self.geometry_types.insert(format!("{:?}", geometry_type));
+        }
+        (0..array.len())
+            .filter_map(|i| array.get(i))
+            .for_each(|wkb| self.update_coords(wkb_num_coords(&wkb.to_wkb_object())));
+        Ok(())
+    }
+
+    fn update_array<'a>(&mut self, array: &impl GeometryArrayTrait<'a>) {
+        self.num_rows += array.len();
+        self.null_count += array.null_count();
+        self.total_bounds =
+            total_bounds_rects(self.total_bounds.into_iter().chain(array.total_bounds()));
+    }
+
+    /// Count the coordinates of one non-null geometry.
+    fn update_coords(&mut self, num_coords: usize) {
+        self.num_coords += num_coords;
+        if num_coords == 0 {
+            self.empty_count += 1;
+        }
+    }
+}
+
+/// The number of coordinates of a native geometry. A rectangle counts its four corners.
+fn num_coords<O: OffsetSizeTrait>(geometry: &Geometry<'_, O>) -> usize {
+    match geometry {
+        Geometry::Point(_) => 1,
+        Geometry::LineString(g) => g.num_coords(),
+        Geometry::Polygon(g) => polygon_num_coords(g),
+        Geometry::MultiPoint(g) => g.num_points(),
+        Geometry::MultiLineString(g) => multi_line_string_num_coords(g),
+        Geometry::MultiPolygon(g) => multi_polygon_num_coords(g),
+        // Collection members are matched directly, since as_type would need them to outlive the
+        // collection
+        Geometry::GeometryCollection(g) => (0..g.num_geometries())
+            .map(|i| num_coords(&g.geometry(i).unwrap()))
+            .sum(),
+        Geometry::Rect(_) => 4,
+    }
+}
+
+/// The number of coordinates of a parsed WKB geometry.
+fn wkb_num_coords(geometry: &WKBGeometry) -> usize {
+    match geometry {
+        WKBGeometry::Point(_) => 1,
+        WKBGeometry::LineString(g) => g.num_coords(),
+        WKBGeometry::Polygon(g) => polygon_num_coords(g),
+        WKBGeometry::MultiPoint(g) => g.num_points(),
+        WKBGeometry::MultiLineString(g) => multi_line_string_num_coords(g),
+        WKBGeometry::MultiPolygon(g) => multi_polygon_num_coords(g),
+        WKBGeometry::GeometryCollection(g) => g.geometries.iter().map(wkb_num_coords).sum(),
+    }
+}
+
+fn polygon_num_coords<'a>(polygon: &impl PolygonTrait<'a>) -> usize {
+    let exterior = polygon.exterior().map_or(0, |ring| ring.num_coords());
+    let interiors: usize = (0..polygon.num_interiors())
+        .map(|i| polygon.interior(i).unwrap().num_coords())
+        .sum();
+    exterior + interiors
+}
+
+fn multi_line_string_num_coords<'a>(multi_line_string: &impl MultiLineStringTrait<'a>) -> usize {
+    (0..multi_line_string.num_lines())
+        .map(|i| multi_line_string.line(i).unwrap().num_coords())
+        .sum()
+}
+
+fn multi_polygon_num_coords<'a>(multi_polygon: &impl MultiPolygonTrait<'a>) -> usize {
+    (0..multi_polygon.num_polygons())
+        .map(|i| polygon_num_coords(&multi_polygon.polygon(i).unwrap()))
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::PolygonArray;
+    use arrow_array::{Int32Array, StringArray};
+    use arrow_schema::FieldRef;
+    use geo::{line_string, point, polygon};
+    use serde_json::json;
+
+    #[test]
+    fn describe_polygons() {
+        // Two batches, so that the statistics are combined across them
+        let batch =
+            |polygons: Vec<Option<geo::Polygon>>, ids: Vec<Option<i32>>, names: Vec<&str>| {
+                let polygons: PolygonArray<i32> = polygons.into();
+                let ids: ArrayRef = Arc::new(Int32Array::from(ids));
+                let names: ArrayRef = Arc::new(StringArray::from(names));
+                let columns = vec![
+                    (
+                        Arc::new(Field::new("id", DataType::Int32, true)) as FieldRef,
+                        ids,
+                    ),
+                    polygons.into_array_ref_with_field("geometry"),
+                    (
+                        Arc::new(Field::new("name", DataType::Utf8, false)) as FieldRef,
+                        names,
+                    ),
+                ];
+                GeoTable::from_arrays(columns, 1).unwrap().into_inner()
+            };
+        let (schema, mut batches, _) = batch(
+            vec![
+                Some(polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 0., y: 0.)]),
+                None,
+            ],
+            vec![Some(3), None],
+            vec!["a", "b"],
+        );
+        let (_, other_batches, _) = batch(
+            vec![Some(
+                polygon![(x: -1., y: 3.), (x: 0., y: 3.), (x: 0., y: 4.), (x: -1., y: 3.)],
+            )],
+            vec![Some(-2)],
+            vec!["c"],
+        );
+        batches.extend(other_batches);
+        let table = GeoTable::try_new(schema, batches, 1).unwrap();
+
+        let summary = table.describe().unwrap();
+        assert_eq!(summary.num_rows, 3);
+
+        let geometry = &summary.geometry;
+        assert!(matches!(geometry.data_type, GeoDataType::Polygon(..)));
+        assert_eq!(geometry.crs, None);
+        assert_eq!(geometry.null_count, 1);
+        assert_eq!(geometry.empty_count, 0);
+        assert_eq!(geometry.geometry_types, vec!["Polygon"]);
+        assert_eq!(geometry.total_bounds, Some(Rect::new((-1., 0.), (2., 4.))));
+        assert_eq!(geometry.num_coords, 8);
+        assert_eq!(geometry.mean_coords_per_geometry, Some(4.));
+        assert!(geometry.memory_size > 0);
+
+        assert_eq!(summary.attributes.len(), 2);
+        let id = &summary.attributes[0];
+        assert_eq!(id.name, "id");
+        assert_eq!(id.null_count, 1);
+        assert_eq!((id.min, id.max), (Some(-2.), Some(3.)));
+        let name = &summary.attributes[1];
+        assert_eq!((name.min, name.max), (None, None));
+
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["geometry"]["total_bounds"], json!([-1., 0., 2., 4.]));
+        assert_eq!(json["attributes"][0]["data_type"], "Int32");
+    }
+
+    #[test]
+    fn describe_wkb() {
+        let wkb: WKBArray<i32> = vec![
+            Some(geo::Geometry::Point(point!(x: 1., y: 2.))),
+            Some(geo::Geometry::LineString(
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+            )),
+            Some(geo::Geometry::Point(point!(x: 3., y: 4.))),
+            Some(geo::Geometry::MultiPoint(geo::MultiPoint(vec![]))),
+            None,
+        ]
+        .into();
+        let (field, array) = wkb.into_array_ref_with_field("geometry");
+        let metadata = HashMap::from([
+            (
+                "ARROW:extension:name".to_string(),
+                "geoarrow.wkb".to_string(),
+            ),
+            (
+                "ARROW:extension:metadata".to_string(),
+                r#"{"crs": "OGC:CRS84"}"#.to_string(),
+            ),
+        ]);
+        let field = Arc::new(field.as_ref().clone().with_metadata(metadata));
+        let table = GeoTable::from_arrays(vec![(field, array)], 0).unwrap();
+
+        let summary = table.describe().unwrap();
+        let geometry = &summary.geometry;
+        assert_eq!(geometry.data_type, GeoDataType::WKB);
+        assert_eq!(geometry.crs, Some(json!("OGC:CRS84")));
+        assert_eq!(geometry.null_count, 1);
+        assert_eq!(geometry.empty_count, 1);
+        assert_eq!(
+            geometry.geometry_types,
+            vec!["LineString", "MultiPoint", "Point"]
+        );
+        assert_eq!(geometry.total_bounds, Some(Rect::new((0., 0.), (3., 4.))));
+        assert_eq!(geometry.num_coords, 4);
+        assert_eq!(geometry.mean_coords_per_geometry, Some(1.));
+        assert!(summary.attributes.is_empty());
+    }
+}
@@ -0,0 +1,399 @@
+use std::sync::Arc;
+
+use arrow::compute::{concat, take};
+use arrow_array::{new_empty_array, Array, ArrayRef, Float64Array, RecordBatch, UInt64Array};
+use arrow_schema::{DataType, Field, FieldRef, Schema};
+use geo::{BoundingRect, EuclideanDistance};
+use rstar::primitives::{GeomWithData, Rectangle};
+use rstar::{RTree, AABB};
+
+use crate::array::{GeometryArray, WKBArray};
+use crate::error::Result;
+use crate::table::GeoTable;
+use crate::trait_::GeoArrayAccessor;
+use crate::GeometryArrayTrait;
+
+impl GeoTable {
+    /// Attach the attributes of the nearest geometry in `other` to each row of this table.
+    ///
+    /// The result has the columns of this table followed by the attribute columns of `other`,
+    /// whose values are taken from the row of `other` with the geometry nearest to each row's
+    /// geometry. Column names are kept as they are, even if they clash. If `distance_col` is
+    /// given, the planar Euclidean distance to the nearest geometry is appended as a `Float64`
+    /// column with that name.
+    ///
+    /// Rows with a null or empty geometry, or whose nearest geometry is further away than
+    /// `max_distance`, get nulls in the joined columns. If several geometries of `other` are
+    /// equally near, the first one wins. Null and empty geometries of `other` are never matched.
+    ///
+    /// When both geometry columns only hold points, the points of `other` are indexed directly.
+    /// Otherwise the bounding boxes of `other` are indexed, and candidates are compared by their
+    /// exact distance.
+    ///
+    /// # Errors
+    ///
+    /// - if either geometry column is not a GeoArrow array
+    pub fn join_nearest(
+        &self,
+        other: &GeoTable,
+        max_distance: Option<f64>,
+        distance_col: Option<&str>,
+    ) -> Result<GeoTable> {
+        let left = geometry_parts(self)?;
+        let right = geometry_parts(other)?;
+        let index = NearestIndex::new(&left, &right);
+        let matches: Vec<Option<(usize, f64)>> = left
+            .iter()
+            .map(|parts| index.nearest(parts, &right, max_distance))
+            .collect();
+
+        let other_columns: Vec<(FieldRef, ArrayRef)> = other
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(column_idx, _)| *column_idx != other.geometry_column_index)
+            .map(|(column_idx, field)| {
+                let chunks: Vec<&dyn Array> = other
+                    .batches
+                    .iter()
+                    .map(|batch| batch.column(column_idx).as_ref())
+                    .collect();
+                let column = if chunks.is_empty() {
+                    new_empty_array(field.data_type())
+                } else {
+                    concat(&chunks)?
+                };
+                let field: FieldRef = Arc::new(field.as_ref().clone().with_nullable(true));
+                Ok((field, column))
+            })
+            .collect::<Result<_>>()?;
+
+        let mut fields: Vec<FieldRef> = self.schema.fields().iter().cloned().collect();
+        fields.extend(other_columns.iter().map(|(field, _)| field.clone()));
+        if let Some(name) = distance_col {
+            fields.push(Arc::new(Field::new(name, DataType::Float64, true)));
+        }
+        let schema = Arc::new(Schema::new_with_metadata(
+            fields,
+            self.schema.metadata().clone(),
+        ));
+
+        let mut offset = 0;
+        let mut batches = Vec::with_capacity(self.batches.len());
+        for batch in &self.batches {
+            let batch_matches = &matches[offset..offset + batch.num_rows()];
+            offset += batch.num_rows();
+
+            let indices: UInt64Array = batch_matches
+                .iter()
+                .map(|m| m.map(|(row, _)| row as u64))
+                .collect();
+            let mut columns = batch.columns().to_vec();
+            for (_, column) in &other_columns {
+                columns.push(take(column.as_ref(), &indices, None)?);
+            }
+            if distance_col.is_some() {
+                let distances: Float64Array = batch_matches
+                    .iter()
+                    .map(|m| m.map(|(_, distance)| distance))
+                    .collect();
+                columns.push(Arc::new(distances));
+            }
+            batches.push(RecordBatch::try_new(schema.clone(), columns)?);
+        }
+
+        GeoTable::try_new(schema, batches, self.geometry_column_index)
+    }
+}
+
+/// A non-empty piece of a geometry that geo can measure distances to and from.
+enum Part {
+    Point(geo::Point),
+    LineString(geo::LineString),
+    Polygon(geo::Polygon),
+}
+
+/// A geometry split into parts, and its bounding box. `None` for null and empty geometries.
+type GeometryParts = Option<(Vec<Part>, geo::Rect)>;
+
+fn push_parts(geometry: &geo::Geometry, parts: &mut Vec<Part>) {
+    match geometry {
+        geo::Geometry::Point(point) => parts.push(Part::Point(*point)),
+        geo::Geometry::Line(line) => parts.push(Part::LineString((*line).into())),
+        geo::Geometry::LineString(line_string) if !line_string.0.is_empty() => {
+            parts.push(Part::LineString(line_string.clone()))
+        }
+        geo::Geometry::Polygon(polygon) if !polygon.exterior().0.is_empty() => {
+            parts.push(Part::Polygon(polygon.clone()))
+        }
+        geo::Geometry::MultiPoint(multi_point) => {
+            parts.extend(multi_point.iter().map(|point| Part::Point(*point)))
+        }
+        geo::Geometry::MultiLineString(multi_line_string) => parts.extend(
+            multi_line_string
+                .iter()
+                .filter(|line_string| !line_string.0.is_empty())
+                .map(|line_string| Part::LineString(line_string.clone())),
+        ),
+        geo::Geometry::MultiPolygon(multi_polygon) => parts.extend(
+            multi_polygon
+                .iter()
+                .filter(|polygon| !polygon.exterior().0.is_empty())
+                .map(|polygon| Part::Polygon(polygon.clone())),
+        ),
+        geo::Geometry::GeometryCollection(collection) => collection
+            .iter()
+            .for_each(|geometry| push_parts(geometry, parts)),
+        geo::Geometry::Rect(rect) => parts.push(Part::Polygon(rect.to_polygon())),
+        geo::Geometry::Triangle(triangle) => parts.push(Part::Polygon(triangle.to_polygon())),
+        geo::Geometry::LineString(_) | geo::Geometry::Polygon(_) => (),
+    }
+}
+
+fn part_distance(a: &Part, b: &Part) -> f64 {
+    match (a, b) {
+        (Part::Point(a), Part::Point(b)) => a.euclidean_distance(b),
+        (Part::Point(a), Part::LineString(b)) => a.euclidean_distance(b),
+        (Part::Point(a), Part::Polygon(b)) => a.euclidean_distance(b),
+        (Part::LineString(a), Part::Point(b)) => a.euclidean_distance(b),
+        (Part::LineString(a), Part::LineString(b)) => a.euclidean_distance(b),
+        (Part::LineString(a), Part::Polygon(b)) => a.euclidean_distance(b),
+        (Part::Polygon(a), Part::Point(b)) => a.euclidean_distance(b),
+        (Part::Polygon(a), Part::LineString(b)) => a.euclidean_distance(b),
+        (Part::Polygon(a), Part::Polygon(b)) => a.euclidean_distance(b),
+    }
+}
+
+/// The distance between the nearest parts of two geometries.
+fn distance(a: &[Part], b: &[Part]) -> f64 {
+    a.iter()
+        .flat_map(|a| b.iter().map(move |b| part_distance(a, b)))
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Split each geometry of the table's geometry column into parts.
+fn geometry_parts(table: &GeoTable) -> Result<Vec<GeometryParts>> {
+    let field = table.schema.field(table.geometry_column_index);
+    let large_offsets = matches!(
+        field.data_type(),
+        DataType::LargeList(_) | DataType::LargeBinary
+    );
+    let is_wkb = field
+        .metadata()
+        .get("ARROW:extension:name")
+        .is_some_and(|name| name == "geoarrow.wkb");
+
+    let mut geometries = vec![];
+    for batch in &table.batches {
+        let column = batch.column(table.geometry_column_index).as_ref();
+        match (is_wkb, large_offsets) {
+            (true, false) => {
+                let array = WKBArray::<i32>::try_from(column)?;
+                geometries.extend((0..array.len()).map(|i| split(array.get_as_geo(i))));
+            }
+            (true, true) => {
+                let array = WKBArray::<i64>::try_from(column)?;
+                geometries.extend((0..array.len()).map(|i| split(array.get_as_geo(i))));
+            }
+            (false, false) => {
+                let array = GeometryArray::<i32>::try_from((field, column))?;
+                geometries.extend((0..array.len()).map(|i| split(array.get_as_geo(i))));
+            }
+            (false, true) => {
+                let array = GeometryArray::<i64>::try_from((field, column))?;
+                geometries.extend((0..array.len()).map(|i| split(array.get_as_geo(i))));
+            }
+        }
+    }
+    Ok(geometries)
+}
+
+fn split(geometry: Option<geo::Geometry>) -> GeometryParts {
+    let geometry = geometry?;
+    let rect = geometry.bounding_rect()?;
+    let mut parts = vec![];
+    push_parts(&geometry, &mut parts);
+    (!parts.is_empty()).then_some((parts, rect))
+}
+
+/// An R-tree over the geometries of the table being joined, whose entries are the geometry's row.
+enum NearestIndex {
+    /// Both sides only have points, so the tree holds the points and its distances are exact.
+    Points(RTree<GeomWithData<[f64; 2], usize>>),
+    /// The tree holds bounding boxes, which only bound the distances from below.
+    Envelopes(RTree<GeomWithData<Rectangle<[f64; 2]>, usize>>),
+}
+
+impl NearestIndex {
+    fn new(left: &[GeometryParts], right: &[GeometryParts]) -> Self {
+        let only_points = |geometries: &[GeometryParts]| {
+            geometries
+                .iter()
+                .flatten()
+                .all(|(parts, _)| parts.len() == 1 && matches!(parts[0], Part::Point(_)))
+        };
+
+        let rows = right
+            .iter()
+            .enumerate()
+            .filter_map(|(row, geometry)| Some((row, geometry.as_ref()?)));
+        if only_points(left) && only_points(right) {
+            let entries = rows
+                .map(|(row, (_, rect))| GeomWithData::new([rect.min().x, rect.min().y], row))
+                .collect();
+            NearestIndex::Points(RTree::bulk_load(entries))
+        } else {
+            let entries = rows
+                .map(|(row, (_, rect))| {
+                    let envelope =
+                        AABB::from_corners(rect.min().x_y().into(), rect.max().x_y().into());
+                    GeomWithData::new(Rectangle::from_aabb(envelope), row)
+                })
+                .collect();
+            NearestIndex::Envelopes(RTree::bulk_load(entries))
+        }
+    }
+
+    /// The row and distance of the geometry in `right` nearest to `query`, preferring the lowest
+    /// row on ties.
+    fn nearest(
+        &self,
+        query: &GeometryParts,
+        right: &[GeometryParts],
+        max_distance: Option<f64>,
+    ) -> Option<(usize, f64)> {
+        let (query_parts, query_rect) = query.as_ref()?;
+        let max_distance = max_distance.unwrap_or(f64::INFINITY);
+        let closer = |candidate: (usize, f64), best: Option<(usize, f64)>| match best {
+            None => true,
+            Some((row, distance)) => {
+                candidate.1 < distance || (candidate.1 == distance && candidate.0 < row)
+            }
+        };
+
+        let mut best: Option<(usize, f64)> = None;
+        match self {
+            NearestIndex::Points(tree) => {
+                let query_point = [query_rect.min().x, query_rect.min().y];
+                for (entry, distance_2) in tree.nearest_neighbor_iter_with_distance_2(&query_point)
+                {
+                    let distance = distance_2.sqrt();
+                    if distance > max_distance || best.is_some_and(|(_, d)| distance > d) {
+                        break;
+                    }
+                    if closer((entry.data, distance), best) {
+                        best = Some((entry.data, distance));
+                    }
+                }
+            }
+            NearestIndex::Envelopes(tree) => {
+                let exact = |row: usize| {
+                    let (parts, _) = right[row].as_ref().unwrap();
+                    distance(query_parts, parts)
+                };
+
+                // The geometry with the nearest bounding box to the query's center bounds the
+                // search radius, and every geometry within that radius has a bounding box that
+                // intersects the query's bounding box grown by it.
+                let center = query_rect.center().x_y().into();
+                let radius = exact(tree.nearest_neighbor(&center)?.data).min(max_distance);
+                let search = AABB::from_corners(
+                    [query_rect.min().x - radius, query_rect.min().y - radius],
+                    [query_rect.max().x + radius, query_rect.max().y + radius],
+                );
+                for entry in tree.locate_in_envelope_intersecting(&search) {
+                    let distance = exact(entry.data);
+                    if distance <= max_distance && closer((entry.data, distance), best) {
+                        best = Some((entry.data, distance));
+                    }
+                }
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::{LineStringArray, PointArray};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Float64Type;
+    use arrow_array::StringArray;
+    use geo::{line_string, point};
+
+    fn table(geometry: (FieldRef, ArrayRef), names: Vec<&str>) -> GeoTable {
+        let names: ArrayRef = Arc::new(StringArray::from(names));
+        let name_field = Arc::new(Field::new("name", DataType::Utf8, false));
+        GeoTable::from_arrays(vec![geometry, (name_field, names)], 0).unwrap()
+    }
+
+    #[test]
+    fn snap_points_to_lines() {
+        let stops: PointArray = vec![
+            Some(point!(x: 1., y: 0.5)),
+            None,
+            Some(point!(x: 9.5, y: 5.)),
+            Some(point!(x: 50., y: 50.)),
+        ]
+        .into();
+        let stops = table(
+            stops.into_array_ref_with_field("geometry"),
+            vec!["a", "b", "c", "d"],
+        );
+        let roads: LineStringArray<i32> = vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            line_string![(x: 0., y: 1.), (x: 10., y: 1.)],
+            line_string![(x: 10., y: 0.), (x: 10., y: 10.)],
+        ]
+        .into();
+        let roads = table(
+            roads.into_array_ref_with_field("geometry"),
+            vec!["main", "second", "cross"],
+        );
+
+        let joined = stops
+            .join_nearest(&roads, Some(5.), Some("distance"))
+            .unwrap();
+        let names: Vec<&str> = joined
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect();
+        assert_eq!(names, vec!["geometry", "name", "name", "distance"]);
+
+        let batch = &joined.batches()[0];
+        // The first stop is equally near the first two roads
+        let road_names = batch.column(2).as_string::<i32>();
+        let road_names: Vec<Option<&str>> = road_names.iter().collect();
+        assert_eq!(road_names, vec![Some("main"), None, Some("cross"), None]);
+        let distances = batch.column(3).as_primitive::<Float64Type>();
+        let distances: Vec<Option<f64>> = distances.iter().collect();
+        assert_eq!(distances, vec![Some(0.5), None, Some(0.5), None]);
+    }
+
+    #[test]
+    fn point_to_point() {
+        let left: PointArray = vec![point!(x: 0., y: 0.), point!(x: 4., y: 4.)].into();
+        let left = table(left.into_array_ref_with_field("geometry"), vec!["x", "y"]);
+        let right: PointArray = vec![
+            point!(x: 3., y: 4.),
+            point!(x: 1., y: 0.),
+            point!(x: 0., y: 1.),
+        ]
+        .into();
+        let right = table(
+            right.into_array_ref_with_field("geometry"),
+            vec!["p", "q", "r"],
+        );
+
+        let joined = left.join_nearest(&right, None, None).unwrap();
+        assert_eq!(joined.schema().fields().len(), 3);
+        let batch = &joined.batches()[0];
+        let names: Vec<Option<&str>> = batch.column(2).as_string::<i32>().iter().collect();
+        // q and r tie for the first point, and q comes first
+        assert_eq!(names, vec![Some("q"), Some("p")]);
+    }
+}
@@ -1,10 +1,22 @@
 //! Abstractions for Arrow tables. Useful for dataset IO where data will have geometries and
 //! attributes.
 
-use arrow_array::RecordBatch;
-use arrow_schema::SchemaRef;
+use std::sync::Arc;
 
-use crate::error::Result;
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{FieldRef, Schema, SchemaRef};
+
+use crate::algorithm::native::axis_order::{
+    sample_non_lon_lat_coords, AxisOrder, AxisOrderGuess, DetectAxisOrder,
+};
+use crate::array::GeometryArray;
+use crate::chunked_array::array::chunks_eq;
+use crate::error::{GeoArrowError, Result};
+
+mod describe;
+mod join;
+
+pub use describe::{AttributeColumnSummary, GeoTableSummary, GeometryColumnSummary};
 
 #[derive(Debug)]
 pub struct GeoTable {
@@ -27,6 +39,31 @@ impl GeoTable {
         })
     }
 
+    /// Create a table with a single batch from named columns, such as those returned by
+    /// [`into_array_ref_with_field`](crate::GeometryArrayTrait::into_array_ref_with_field).
+    ///
+    /// # Errors
+    ///
+    /// - if `geometry_column_index` is out of bounds
+    /// - if the arrays have different lengths or don't match the data types of their fields
+    pub fn from_arrays(
+        fields_and_arrays: Vec<(FieldRef, ArrayRef)>,
+        geometry_column_index: usize,
+    ) -> Result<Self> {
+        if geometry_column_index >= fields_and_arrays.len() {
+            return Err(GeoArrowError::General(format!(
+                "Geometry column index {} is out of bounds for {} columns",
+                geometry_column_index,
+                fields_and_arrays.len()
+            )));
+        }
+
+        let (fields, columns): (Vec<_>, Vec<_>) = fields_and_arrays.into_iter().unzip();
+        let schema: SchemaRef = Arc::new(Schema::new(fields));
+        let batch = RecordBatch::try_new(schema.clone(), columns)?;
+        Self::try_new(schema, vec![batch], geometry_column_index)
+    }
+
     pub fn into_inner(self) -> (SchemaRef, Vec<RecordBatch>, usize) {
         (self.schema, self.batches, self.geometry_column_index)
     }
@@ -42,4 +79,167 @@ impl GeoTable {
     pub fn geometry_column_index(&self) -> usize {
         self.geometry_column_index
     }
+
+    /// Check that the geometry column plausibly holds longitude/latitude coordinates.
+    ///
+    /// This errors if the coordinates only make sense as latitude/longitude, or don't fit either
+    /// order at all, with some of the offending coordinates in the message. Data whose axis order
+    /// can't be told apart, such as data where every value is within [-90, 90], passes. See
+    /// [`DetectAxisOrder`] for the heuristic.
+    pub fn assert_lon_lat(&self) -> Result<()> {
+        let field = self.schema.field(self.geometry_column_index);
+        for batch in &self.batches {
+            let column = batch.column(self.geometry_column_index);
+            let geometry_array: GeometryArray<i64> = (field, column.as_ref()).try_into()?;
+
+            let problem = match geometry_array.detect_axis_order() {
+                AxisOrderGuess::Confident(AxisOrder::LonLat) | AxisOrderGuess::Ambiguous => {
+                    continue
+                }
+                AxisOrderGuess::Confident(AxisOrder::LatLon) => {
+                    "appear to be in latitude/longitude order; use swap_xy to fix them"
+                }
+                AxisOrderGuess::NotGeographic => "are not longitude/latitude values",
+            };
+
+            let samples = sample_non_lon_lat_coords(&geometry_array, 5)
+                .into_iter()
+                .map(|(x, y)| format!("({}, {})", x, y))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(GeoArrowError::General(format!(
+                "Coordinates in column {} {}. Offending coordinates include: {}",
+                field.name(),
+                problem,
+                samples
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Tables are equal if they have the same schema, geometry column and rows, even when the rows are
+/// split into batches differently.
+impl PartialEq for GeoTable {
+    fn eq(&self, other: &Self) -> bool {
+        if self.schema != other.schema || self.geometry_column_index != other.geometry_column_index
+        {
+            return false;
+        }
+
+        (0..self.schema.fields().len()).all(|column_idx| {
+            let columns = |table: &GeoTable| -> Vec<ArrayRef> {
+                table
+                    .batches
+                    .iter()
+                    .map(|batch| batch.column(column_idx).clone())
+                    .collect()
+            };
+            chunks_eq(&columns(self), &columns(other))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::native::axis_order::SwapXY;
+    use crate::array::PointArray;
+    use crate::GeometryArrayTrait;
+    use arrow_array::Int32Array;
+    use arrow_schema::{DataType, Field};
+
+    fn table(points: PointArray) -> GeoTable {
+        GeoTable::from_arrays(vec![points.into_array_ref_with_field("geometry")], 0).unwrap()
+    }
+
+    #[test]
+    fn from_arrays() {
+        let points: PointArray = vec![geo::point!(x: 1., y: 2.), geo::point!(x: 3., y: 4.)].into();
+        let ids: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let id_field: FieldRef = Arc::new(Field::new("id", DataType::Int32, false));
+
+        let columns = vec![
+            (id_field.clone(), ids.clone()),
+            points.clone().into_array_ref_with_field("geom"),
+        ];
+        let table = GeoTable::from_arrays(columns, 1).unwrap();
+        let field = table.schema().field(1);
+        assert_eq!(field.name(), "geom");
+        assert_eq!(
+            field.metadata().get("ARROW:extension:name").unwrap(),
+            "geoarrow.point"
+        );
+        assert_eq!(table.batches()[0].num_rows(), 2);
+
+        assert!(GeoTable::from_arrays(vec![(id_field, ids)], 1).is_err());
+    }
+
+    #[test]
+    fn assert_lon_lat() {
+        // Oslo and Anchorage
+        let points: PointArray = vec![
+            geo::point!(x: 10.75, y: 59.91),
+            geo::point!(x: -149.9, y: 61.22),
+        ]
+        .into();
+        assert!(table(points.clone()).assert_lon_lat().is_ok());
+
+        let err = table(points.swap_xy()).assert_lon_lat().unwrap_err();
+        assert!(err.to_string().contains("latitude/longitude order"));
+        assert!(err.to_string().contains("(61.22, -149.9)"));
+
+        // Oslo and Stockholm can't be told apart
+        let points: PointArray = vec![
+            geo::point!(x: 10.75, y: 59.91),
+            geo::point!(x: 18.07, y: 59.33),
+        ]
+        .into();
+        assert!(table(points.swap_xy()).assert_lon_lat().is_ok());
+    }
+
+    #[test]
+    fn eq_across_batch_boundaries() {
+        let points: PointArray = vec![
+            Some(geo::point!(x: 1., y: 2.)),
+            None,
+            Some(geo::point!(x: 3., y: 4.)),
+            None,
+            Some(geo::point!(x: 5., y: 6.)),
+        ]
+        .into();
+        let ids: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(1),
+            Some(2),
+            None,
+            Some(4),
+            None,
+        ]));
+        let id_field: FieldRef = Arc::new(Field::new("id", DataType::Int32, true));
+        let columns = vec![
+            (id_field, ids),
+            points.into_array_ref_with_field("geometry"),
+        ];
+        let (schema, batches, _) = GeoTable::from_arrays(columns, 1).unwrap().into_inner();
+        let batch = &batches[0];
+
+        let split = |lengths: &[usize]| {
+            let mut offset = 0;
+            let batches = lengths
+                .iter()
+                .map(|length| {
+                    offset += length;
+                    batch.slice(offset - length, *length)
+                })
+                .collect();
+            GeoTable::try_new(schema.clone(), batches, 1).unwrap()
+        };
+        assert_eq!(split(&[2, 2, 1]), split(&[1, 3, 1]));
+        assert_eq!(split(&[5]), split(&[1, 1, 0, 3]));
+        assert_ne!(split(&[5]), split(&[4]));
+
+        let other_geometry_column = GeoTable::try_new(schema.clone(), batches.clone(), 0).unwrap();
+        assert_ne!(split(&[5]), other_geometry_column);
+    }
 }
@@ -0,0 +1,23 @@
+use geo::{Geometry, GeometryCollection};
+
+use crate::array::GeometryCollectionArray;
+use crate::test::{linestring, multipolygon, point, polygon};
+
+pub(crate) fn gc0() -> GeometryCollection {
+    GeometryCollection(vec![
+        Geometry::Point(point::p0()),
+        Geometry::LineString(linestring::ls0()),
+    ])
+}
+
+pub(crate) fn gc1() -> GeometryCollection {
+    GeometryCollection(vec![
+        Geometry::Polygon(polygon::p0()),
+        Geometry::MultiPolygon(multipolygon::mp0()),
+        Geometry::Point(point::p1()),
+    ])
+}
+
+pub(crate) fn gc_array() -> GeometryCollectionArray<i32> {
+    vec![gc0(), gc1()].try_into().unwrap()
+}
@@ -2,6 +2,7 @@ pub mod binary;
 pub mod coord;
 pub mod geoarrow_data;
 pub mod geometry;
+pub mod geometrycollection;
 pub mod linestring;
 pub mod multilinestring;
 pub mod multipoint;
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
-use arrow_array::RecordBatch;
-use arrow_schema::{DataType, Field, Schema};
+use arrow_array::ArrayRef;
+use arrow_schema::{DataType, Field, FieldRef};
 use geo::{point, Point};
 
 use crate::array::PointArray;
@@ -36,22 +36,17 @@ pub(crate) fn table() -> GeoTable {
     let u8_array = properties::u8_array();
     let string_array = properties::string_array();
 
-    let fields = vec![
-        Arc::new(Field::new("u8", DataType::UInt8, true)),
-        Arc::new(Field::new("string", DataType::Utf8, true)),
-        point_array.extension_field(),
-    ];
-    let schema = Arc::new(Schema::new(fields));
-
-    let batch = RecordBatch::try_new(
-        schema.clone(),
-        vec![
+    let columns: Vec<(FieldRef, ArrayRef)> = vec![
+        (
+            Arc::new(Field::new("u8", DataType::UInt8, true)),
             Arc::new(u8_array),
+        ),
+        (
+            Arc::new(Field::new("string", DataType::Utf8, true)),
             Arc::new(string_array),
-            point_array.into_array_ref(),
-        ],
-    )
-    .unwrap();
+        ),
+        point_array.into_array_ref_with_field("geometry"),
+    ];
 
-    GeoTable::try_new(schema, vec![batch], 2).unwrap()
+    GeoTable::from_arrays(columns, 2).unwrap()
 }
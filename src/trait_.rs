@@ -1,10 +1,12 @@
 //! Defines [`GeometryArrayTrait`], which all geometry arrays implement.
 
-use crate::array::{CoordBuffer, CoordType};
+use crate::array::metadata::DEFAULT_METADATA;
+use crate::array::*;
 use crate::datatypes::GeoDataType;
-use arrow_array::{Array, ArrayRef};
+use crate::error::{GeoArrowError, Result};
+use arrow_array::{Array, ArrayRef, UnionArray};
 use arrow_buffer::{NullBuffer, NullBufferBuilder};
-use arrow_schema::{DataType, Field};
+use arrow_schema::{DataType, Field, FieldRef};
 use std::any::Any;
 use std::sync::Arc;
 
@@ -57,7 +59,7 @@ pub trait GeometryArrayTrait<'a>: std::fmt::Debug + Send + Sync {
     /// let point = point!(x: 1., y: 2.);
     /// let point_array: PointArray = vec![point].into();
     ///
-    /// assert!(matches!(point_array.data_type(), GeoDataType::Point(_)));
+    /// assert!(matches!(point_array.data_type(), GeoDataType::Point(..)));
     /// ```
     fn data_type(&self) -> &GeoDataType;
 
@@ -70,14 +72,38 @@ pub trait GeometryArrayTrait<'a>: std::fmt::Debug + Send + Sync {
     /// Always returns `DataType::Extension`.
     fn extension_field(&self) -> Arc<Field>;
 
+    /// Get an Arrow [`Field`] for this array named `name`, with the same extension metadata as
+    /// [`extension_field`](Self::extension_field).
+    fn to_field(&self, name: &str, nullable: bool) -> FieldRef {
+        let field = self.extension_field().as_ref().clone();
+        Arc::new(field.with_name(name).with_nullable(nullable))
+    }
+
     /// Get the extension name of this array.
     fn extension_name(&self) -> &str;
 
+    /// Get the [`ArrayMetadata`] of this array.
+    ///
+    /// Arrays that don't carry their own metadata return the default, i.e. planar edges.
+    fn metadata(&self) -> &ArrayMetadata {
+        &DEFAULT_METADATA
+    }
+
     /// Convert this array into an arced [`arrow`] array.
     /// # Implementation
     /// This is `O(1)`.
     fn into_array_ref(self) -> ArrayRef;
 
+    /// Convert this array into an arced [`arrow`] array, together with a nullable field named
+    /// `name` from [`to_field`](Self::to_field).
+    fn into_array_ref_with_field(self, name: &str) -> (FieldRef, ArrayRef)
+    where
+        Self: Sized,
+    {
+        let field = self.to_field(name, true);
+        (field, self.into_array_ref())
+    }
+
     /// Create a new array with replaced coordinates
     ///
     /// This is useful if you want to apply an operation to _every_ coordinate in unison, such as a
@@ -90,9 +116,27 @@ pub trait GeometryArrayTrait<'a>: std::fmt::Debug + Send + Sync {
     /// Cast the coordinate buffer of this geometry array to the given coordinate type.
     fn into_coord_type(self, coord_type: CoordType) -> Self;
 
+    /// Round the coordinates of this geometry array to 32-bit floats, stored interleaved.
+    ///
+    /// This is lossy. [`into_array_ref`](Self::into_array_ref) then writes `Float32` coordinate
+    /// arrays, while scalars and `value_as_geo` still read coordinates as `f64`.
+    fn into_coord_float32(self) -> Self;
+
+    /// Widen 32-bit coordinates of this geometry array back to `f64`.
+    fn into_coord_float64(self) -> Self;
+
     /// The number of geometries contained in this array.
     fn len(&self) -> usize;
 
+    /// The number of bytes used by the coordinates, offsets and validity of this array.
+    ///
+    /// Offsets and validity are counted for the rows this array views. Point, rect and WKB arrays
+    /// slice their values along with their rows, so a slice of one reports less than its parent.
+    /// Arrays with geometry offsets, like [`LineStringArray`](crate::array::LineStringArray),
+    /// don't slice their coordinates: a slice shares and counts the full coordinate buffer of its
+    /// parent.
+    fn memory_size(&self) -> usize;
+
     /// Returns `true` if the array is empty.
     fn is_empty(&self) -> bool {
         self.len() == 0
@@ -135,6 +179,13 @@ pub trait GeometryArrayTrait<'a>: std::fmt::Debug + Send + Sync {
         !self.is_null(i)
     }
 
+    /// The bounding rectangle of all valid geometries in this array, or `None` if the array has
+    /// no valid, non-empty geometries.
+    ///
+    /// Arrays with a single coordinate buffer scan it directly, without creating scalar
+    /// geometries.
+    fn total_bounds(&self) -> Option<geo::Rect>;
+
     /// Returns a zero-copy slice of this array with the indicated offset and length.
     ///
     /// # Panic
@@ -205,6 +256,145 @@ pub trait GeoArrayAccessor<'a>: GeometryArrayTrait<'a> {
     }
 }
 
+/// Convert a geometry array into an Arrow array and the field that describes it.
+///
+/// This is implemented for every geometry array, so that generic code can hand arrays to Arrow
+/// without knowing their type.
+pub trait IntoArrow {
+    /// The field is named `geometry` and carries the GeoArrow extension name and metadata.
+    fn into_arrow(self) -> (ArrayRef, FieldRef);
+}
+
+impl<T: for<'a> GeometryArrayTrait<'a>> IntoArrow for T {
+    fn into_arrow(self) -> (ArrayRef, FieldRef) {
+        let (field, array) = self.into_array_ref_with_field("geometry");
+        (array, field)
+    }
+}
+
+/// Create a geometry array from an Arrow array and the field that describes it, the inverse of
+/// [`IntoArrow`].
+///
+/// Implemented for the arrays that can be imported from Arrow.
+pub trait FromArrow: Sized {
+    /// The array metadata, such as the CRS, is read from the field's extension metadata.
+    ///
+    /// # Errors
+    ///
+    /// - if the field has a GeoArrow extension name of another geometry type
+    /// - if the storage type of `array` doesn't match this geometry type
+    /// - if the extension metadata is invalid
+    fn from_arrow(array: &dyn Array, field: &Field) -> Result<Self>;
+}
+
+/// Check that the field, if it has an extension name, has the expected one.
+fn check_extension_name(field: &Field, expected: &str) -> Result<()> {
+    match field.metadata().get("ARROW:extension:name") {
+        Some(extension_name) if extension_name != expected => Err(GeoArrowError::General(format!(
+            "Expected extension name {}, found {}",
+            expected, extension_name
+        ))),
+        _ => Ok(()),
+    }
+}
+
+macro_rules! from_arrow_impl {
+    ($type:ty, $extension_name:expr) => {
+        impl FromArrow for $type {
+            fn from_arrow(array: &dyn Array, field: &Field) -> Result<Self> {
+                check_extension_name(field, $extension_name)?;
+                let arr: Self = array.try_into()?;
+                Ok(arr.with_metadata(ArrayMetadata::from_field(field)?))
+            }
+        }
+    };
+}
+
+from_arrow_impl!(PointArray, "geoarrow.point");
+from_arrow_impl!(LineStringArray<i32>, "geoarrow.linestring");
+from_arrow_impl!(LineStringArray<i64>, "geoarrow.linestring");
+from_arrow_impl!(PolygonArray<i32>, "geoarrow.polygon");
+from_arrow_impl!(PolygonArray<i64>, "geoarrow.polygon");
+from_arrow_impl!(MultiPointArray<i32>, "geoarrow.multipoint");
+from_arrow_impl!(MultiPointArray<i64>, "geoarrow.multipoint");
+from_arrow_impl!(MultiLineStringArray<i32>, "geoarrow.multilinestring");
+from_arrow_impl!(MultiLineStringArray<i64>, "geoarrow.multilinestring");
+from_arrow_impl!(MultiPolygonArray<i32>, "geoarrow.multipolygon");
+from_arrow_impl!(MultiPolygonArray<i64>, "geoarrow.multipolygon");
+
+// WKB arrays don't carry metadata
+impl FromArrow for WKBArray<i32> {
+    fn from_arrow(array: &dyn Array, field: &Field) -> Result<Self> {
+        check_extension_name(field, "geoarrow.wkb")?;
+        array.try_into()
+    }
+}
+
+impl FromArrow for WKBArray<i64> {
+    fn from_arrow(array: &dyn Array, field: &Field) -> Result<Self> {
+        check_extension_name(field, "geoarrow.wkb")?;
+        array.try_into()
+    }
+}
+
+// Rect arrays don't carry metadata either
+impl FromArrow for RectArray {
+    fn from_arrow(array: &dyn Array, field: &Field) -> Result<Self> {
+        check_extension_name(field, "geoarrow._rect")?;
+        array.try_into()
+    }
+}
+
+// Mixed arrays carry no metadata of their own; their children read theirs
+impl FromArrow for MixedGeometryArray<i32> {
+    fn from_arrow(array: &dyn Array, field: &Field) -> Result<Self> {
+        check_extension_name(field, "geoarrow.mixed")?;
+        array
+            .as_any()
+            .downcast_ref::<UnionArray>()
+            .ok_or_else(|| GeoArrowError::General("Expected a union array".to_string()))?
+            .try_into()
+    }
+}
+
+impl FromArrow for MixedGeometryArray<i64> {
+    fn from_arrow(array: &dyn Array, field: &Field) -> Result<Self> {
+        check_extension_name(field, "geoarrow.mixed")?;
+        array
+            .as_any()
+            .downcast_ref::<UnionArray>()
+            .ok_or_else(|| GeoArrowError::General("Expected a union array".to_string()))?
+            .try_into()
+    }
+}
+
+// Like mixed arrays, geometry collection arrays leave metadata to their members
+impl FromArrow for GeometryCollectionArray<i32> {
+    fn from_arrow(array: &dyn Array, field: &Field) -> Result<Self> {
+        check_extension_name(field, "geoarrow.geometrycollection")?;
+        array.try_into()
+    }
+}
+
+impl FromArrow for GeometryCollectionArray<i64> {
+    fn from_arrow(array: &dyn Array, field: &Field) -> Result<Self> {
+        check_extension_name(field, "geoarrow.geometrycollection")?;
+        array.try_into()
+    }
+}
+
+impl FromArrow for GeometryArray<i32> {
+    fn from_arrow(array: &dyn Array, field: &Field) -> Result<Self> {
+        (field, array).try_into()
+    }
+}
+
+impl FromArrow for GeometryArray<i64> {
+    fn from_arrow(array: &dyn Array, field: &Field) -> Result<Self> {
+        (field, array).try_into()
+    }
+}
+
 pub trait GeometryScalarTrait<'a> {
     /// The [`geo`] scalar object for this geometry array type.
     type ScalarGeo;
@@ -268,3 +458,28 @@ pub trait MutableGeometryArray: std::fmt::Debug + Send + Sync {
 
     fn into_array_ref(self) -> Arc<dyn Array>;
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::polygon::p_array;
+    use serde_json::json;
+
+    #[test]
+    fn polygon_roundtrip() {
+        let arr =
+            p_array().with_metadata(ArrayMetadata::default().with_crs(Some(json!("EPSG:4326"))));
+        let (array, field) = arr.clone().into_arrow();
+        assert_eq!(field.metadata()["ARROW:extension:name"], "geoarrow.polygon");
+
+        let round_tripped = PolygonArray::<i32>::from_arrow(array.as_ref(), &field).unwrap();
+        assert_eq!(round_tripped, arr);
+        assert_eq!(round_tripped.metadata().crs, Some(json!("EPSG:4326")));
+
+        assert!(LineStringArray::<i32>::from_arrow(array.as_ref(), &field).is_err());
+        assert!(matches!(
+            GeometryArray::<i32>::from_arrow(array.as_ref(), &field).unwrap(),
+            GeometryArray::Polygon(_)
+        ));
+    }
+}
@@ -1,27 +1,6 @@
 use arrow_array::OffsetSizeTrait;
 use arrow_buffer::{NullBuffer, NullBufferBuilder, OffsetBuffer};
 
-use crate::array::mutable_offset::OffsetsBuilder;
-use crate::array::util::offset_lengths;
-
-pub(crate) fn owned_slice_offsets<O: OffsetSizeTrait>(
-    offsets: &OffsetBuffer<O>,
-    offset: usize,
-    length: usize,
-) -> OffsetBuffer<O> {
-    // TODO: double check but now that we've moved to arrow-rs it looks like this slice adds 1 for
-    // us.
-    let sliced_offsets = offsets.slice(offset, length);
-
-    let mut new_offsets: OffsetsBuilder<O> = OffsetsBuilder::with_capacity(length);
-
-    for item in offset_lengths(&sliced_offsets) {
-        new_offsets.try_push_usize(item).unwrap();
-    }
-
-    new_offsets.into()
-}
-
 pub(crate) fn owned_slice_validity(
     validity: Option<&NullBuffer>,
     offset: usize,
@@ -40,3 +19,14 @@ pub(crate) fn owned_slice_validity(
         None
     }
 }
+
+/// The number of bytes of an array's offsets, for
+/// [`GeometryArrayTrait::memory_size`](crate::GeometryArrayTrait::memory_size).
+pub(crate) fn offsets_memory_size<O: OffsetSizeTrait>(offsets: &OffsetBuffer<O>) -> usize {
+    std::mem::size_of_val(offsets.as_ref())
+}
+
+/// The number of bytes of the bits of a validity buffer, or 0 if there is none.
+pub(crate) fn validity_memory_size(validity: Option<&NullBuffer>) -> usize {
+    validity.map_or(0, |validity| validity.len().div_ceil(8))
+}